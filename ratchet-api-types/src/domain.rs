@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::enums::*;
@@ -309,6 +310,12 @@ pub struct UnifiedExecution {
     pub duration_ms: Option<i32>,
     pub http_requests: Option<serde_json::Value>,
     pub recording_path: Option<String>,
+    /// Files attached to the execution by the task
+    #[serde(default)]
+    pub attachments: Vec<UnifiedExecutionAttachment>,
+    /// Key/value labels for grouping and filtering executions
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
 
     // Computed fields
     pub can_retry: bool,
@@ -316,6 +323,18 @@ pub struct UnifiedExecution {
     pub progress: Option<f32>,
 }
 
+/// File artifact attached to an execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(SimpleObject))]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UnifiedExecutionAttachment {
+    pub name: String,
+    pub mime_type: String,
+    pub size_bytes: u64,
+    pub created_at: DateTime<Utc>,
+}
+
 /// Unified Job representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(SimpleObject))]
@@ -332,6 +351,16 @@ pub struct UnifiedJob {
     pub scheduled_for: Option<DateTime<Utc>>,
     pub error_message: Option<String>,
     pub output_destinations: Option<Vec<UnifiedOutputDestination>>,
+    /// Schedule that created the job, unset for manually queued jobs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_id: Option<ApiId>,
+    /// Execution the job runs as; set when processing starts, or up front when
+    /// the caller waits on a pre-created execution
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_id: Option<ApiId>,
+    /// Log level the job's task runs at, overriding the task's own `log_level` metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
 }
 
 /// Unified Schedule representation
@@ -351,6 +380,24 @@ pub struct UnifiedSchedule {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub output_destinations: Option<Vec<UnifiedOutputDestination>>,
+    /// Run executions strictly one at a time in trigger order
+    #[serde(default)]
+    pub serial: bool,
+    /// IANA timezone the cron expression is evaluated in, UTC when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// What to do about runs missed while the scheduler was down
+    #[serde(default)]
+    pub misfire_policy: MisfirePolicy,
+    /// Most executions of this schedule allowed to be active at once, unlimited when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<u32>,
+    /// Window in seconds each run is randomly delayed by, so schedules sharing a cron don't fire together
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jitter_seconds: Option<u32>,
+    /// Disabled by a pause of all schedules and re-enabled when they resume
+    #[serde(default)]
+    pub paused: bool,
 }
 
 /// Unified Output Destination representation
@@ -1,3 +1,12 @@
+//! Unified enums that work in both REST and GraphQL
+//!
+//! Every enum serializes its variants in `SCREAMING_SNAKE_CASE` in both APIs:
+//! `ExecutionStatus::Completed` is `"COMPLETED"` in REST JSON bodies and
+//! `COMPLETED` in GraphQL, and `UserRole::ReadOnly` is `READ_ONLY` in both.
+//! The serde and async-graphql renames are spelled out on each enum so the two
+//! forms cannot drift apart. REST query filters additionally accept the
+//! lowercase form, see `ratchet_web::extractors`.
+
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "graphql")]
@@ -6,10 +15,9 @@ use async_graphql::*;
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 
-/// Unified enums that work in both REST and GraphQL
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ExecutionStatus {
@@ -20,8 +28,19 @@ pub enum ExecutionStatus {
     Cancelled,
 }
 
+impl ExecutionStatus {
+    /// Whether the execution has finished and will not change state again
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ExecutionStatus::Completed | ExecutionStatus::Failed | ExecutionStatus::Cancelled
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum JobPriority {
@@ -33,6 +52,7 @@ pub enum JobPriority {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum JobStatus {
@@ -42,10 +62,29 @@ pub enum JobStatus {
     Failed,
     Cancelled,
     Retrying,
+    /// Failed after exhausting its retries; only runs again when requeued
+    DeadLetter,
+}
+
+/// What a schedule does about runs missed while the scheduler was down
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MisfirePolicy {
+    /// Drop missed runs and wait for the next regular run
+    #[default]
+    Skip,
+    /// Run once to catch up, however many runs were missed
+    FireOnce,
+    /// Run every missed run, oldest first
+    FireAll,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OutputFormat {
@@ -57,6 +96,7 @@ pub enum OutputFormat {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum CompressionType {
@@ -66,6 +106,7 @@ pub enum CompressionType {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum HttpMethod {
@@ -78,6 +119,7 @@ pub enum HttpMethod {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WorkerStatusType {
@@ -89,6 +131,7 @@ pub enum WorkerStatusType {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum UserRole {
@@ -100,6 +143,7 @@ pub enum UserRole {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "graphql", derive(Enum))]
+#[cfg_attr(feature = "graphql", graphql(rename_items = "SCREAMING_SNAKE_CASE"))]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ApiKeyPermissions {
@@ -108,3 +152,155 @@ pub enum ApiKeyPermissions {
     ExecuteOnly,
     Admin,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    /// Assert the REST (serde) and GraphQL names of every variant
+    fn assert_names<T>(expected: &[(T, &str)])
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug + Copy,
+    {
+        for (variant, name) in expected {
+            assert_eq!(serde_json::to_value(variant).unwrap(), serde_json::json!(name));
+            assert_eq!(serde_json::from_value::<T>(serde_json::json!(name)).unwrap(), *variant);
+        }
+    }
+
+    #[cfg(feature = "graphql")]
+    fn assert_graphql_names<T>(expected: &[(T, &str)])
+    where
+        T: Enum + PartialEq + Debug,
+    {
+        let items = T::items();
+        assert_eq!(items.len(), expected.len(), "every variant must be covered");
+        for (variant, name) in expected {
+            let item = items.iter().find(|item| item.value == *variant).unwrap();
+            assert_eq!(item.name, *name);
+        }
+    }
+
+    macro_rules! enum_names_test {
+        ($test:ident, $enum:ty, [$(($variant:expr, $name:literal)),+ $(,)?]) => {
+            #[test]
+            fn $test() {
+                let expected: &[($enum, &str)] = &[$(($variant, $name)),+];
+                assert_names(expected);
+                #[cfg(feature = "graphql")]
+                assert_graphql_names(expected);
+            }
+        };
+    }
+
+    enum_names_test!(
+        test_execution_status_names,
+        ExecutionStatus,
+        [
+            (ExecutionStatus::Pending, "PENDING"),
+            (ExecutionStatus::Running, "RUNNING"),
+            (ExecutionStatus::Completed, "COMPLETED"),
+            (ExecutionStatus::Failed, "FAILED"),
+            (ExecutionStatus::Cancelled, "CANCELLED"),
+        ]
+    );
+
+    enum_names_test!(
+        test_job_priority_names,
+        JobPriority,
+        [
+            (JobPriority::Low, "LOW"),
+            (JobPriority::Normal, "NORMAL"),
+            (JobPriority::High, "HIGH"),
+            (JobPriority::Critical, "CRITICAL"),
+        ]
+    );
+
+    enum_names_test!(
+        test_job_status_names,
+        JobStatus,
+        [
+            (JobStatus::Queued, "QUEUED"),
+            (JobStatus::Processing, "PROCESSING"),
+            (JobStatus::Completed, "COMPLETED"),
+            (JobStatus::Failed, "FAILED"),
+            (JobStatus::Cancelled, "CANCELLED"),
+            (JobStatus::Retrying, "RETRYING"),
+            (JobStatus::DeadLetter, "DEAD_LETTER"),
+        ]
+    );
+
+    enum_names_test!(
+        test_misfire_policy_names,
+        MisfirePolicy,
+        [
+            (MisfirePolicy::Skip, "SKIP"),
+            (MisfirePolicy::FireOnce, "FIRE_ONCE"),
+            (MisfirePolicy::FireAll, "FIRE_ALL"),
+        ]
+    );
+
+    enum_names_test!(
+        test_output_format_names,
+        OutputFormat,
+        [
+            (OutputFormat::Json, "JSON"),
+            (OutputFormat::Yaml, "YAML"),
+            (OutputFormat::Csv, "CSV"),
+            (OutputFormat::Xml, "XML"),
+        ]
+    );
+
+    enum_names_test!(
+        test_compression_type_names,
+        CompressionType,
+        [(CompressionType::Gzip, "GZIP"), (CompressionType::Zstd, "ZSTD")]
+    );
+
+    enum_names_test!(
+        test_http_method_names,
+        HttpMethod,
+        [
+            (HttpMethod::Get, "GET"),
+            (HttpMethod::Post, "POST"),
+            (HttpMethod::Put, "PUT"),
+            (HttpMethod::Patch, "PATCH"),
+            (HttpMethod::Delete, "DELETE"),
+        ]
+    );
+
+    enum_names_test!(
+        test_worker_status_type_names,
+        WorkerStatusType,
+        [
+            (WorkerStatusType::Idle, "IDLE"),
+            (WorkerStatusType::Running, "RUNNING"),
+            (WorkerStatusType::Stopping, "STOPPING"),
+            (WorkerStatusType::Error, "ERROR"),
+        ]
+    );
+
+    enum_names_test!(
+        test_user_role_names,
+        UserRole,
+        [
+            (UserRole::Admin, "ADMIN"),
+            (UserRole::User, "USER"),
+            (UserRole::ReadOnly, "READ_ONLY"),
+            (UserRole::Service, "SERVICE"),
+        ]
+    );
+
+    enum_names_test!(
+        test_api_key_permissions_names,
+        ApiKeyPermissions,
+        [
+            (ApiKeyPermissions::Full, "FULL"),
+            (ApiKeyPermissions::ReadOnly, "READ_ONLY"),
+            (ApiKeyPermissions::ExecuteOnly, "EXECUTE_ONLY"),
+            (ApiKeyPermissions::Admin, "ADMIN"),
+        ]
+    );
+}
@@ -15,13 +15,13 @@ pub mod pagination;
 pub use domain::{
     ConnectionTestResult, CreateRepositoryRequest, CreateTaskRequest, PushResult, SyncResult, TaskConflict,
     TaskRepositoryInfo, UnifiedApiKey, UnifiedApiKeyAuth, UnifiedBasicAuth, UnifiedBearerAuth, UnifiedExecution, 
-    UnifiedFilesystemConfig, UnifiedJob, UnifiedOutputDestination, UnifiedRetryPolicy, UnifiedSchedule, 
-    UnifiedSession, UnifiedStdioConfig, UnifiedTask, UnifiedTaskRepository, UnifiedUser, UnifiedWebhookAuth, 
+    UnifiedExecutionAttachment, UnifiedFilesystemConfig, UnifiedJob, UnifiedOutputDestination, UnifiedRetryPolicy,
+    UnifiedSchedule, UnifiedSession, UnifiedStdioConfig, UnifiedTask, UnifiedTaskRepository, UnifiedUser, UnifiedWebhookAuth, 
     UnifiedWebhookConfig, UnifiedWorkerStatus, UpdateRepositoryRequest, UpdateTaskSourceRequest,
 };
 pub use enums::{
-    ApiKeyPermissions, CompressionType, ExecutionStatus, HttpMethod, JobPriority, JobStatus, OutputFormat, UserRole,
-    WorkerStatusType,
+    ApiKeyPermissions, CompressionType, ExecutionStatus, HttpMethod, JobPriority, JobStatus, MisfirePolicy, OutputFormat,
+    UserRole, WorkerStatusType,
 };
 pub use errors::ApiError;
 pub use ids::ApiId;
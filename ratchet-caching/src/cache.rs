@@ -6,7 +6,7 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::time::Duration;
 
-use crate::{CacheResult, CacheStats};
+use crate::{CacheError, CacheResult, CacheStats};
 
 /// Trait for types that can be used as cache keys
 pub trait CacheKey: Clone + Eq + Hash + Debug + Send + Sync {}
@@ -18,6 +18,9 @@ pub trait CacheValue: Clone + Debug + Send + Sync {}
 impl<T> CacheKey for T where T: Clone + Eq + Hash + Debug + Send + Sync {}
 impl<T> CacheValue for T where T: Clone + Debug + Send + Sync {}
 
+/// Key filter used for bulk removal
+pub type KeyPredicate<'a, K> = dyn Fn(&K) -> bool + Send + Sync + 'a;
+
 /// Core cache trait
 #[async_trait]
 pub trait Cache<K: CacheKey + 'static, V: CacheValue + 'static>: Send + Sync {
@@ -41,6 +44,23 @@ pub trait Cache<K: CacheKey + 'static, V: CacheValue + 'static>: Send + Sync {
         Ok(self.get(key).await?.is_some())
     }
 
+    /// Remove every entry whose key matches `predicate`, returning how many were removed
+    ///
+    /// Stores that cannot enumerate their keys return [`CacheError::Unsupported`].
+    async fn remove_matching(&self, _predicate: &KeyPredicate<'_, K>) -> CacheResult<usize> {
+        Err(CacheError::Unsupported("remove_matching".to_string()))
+    }
+
+    /// Remove every entry whose key starts with `prefix`, returning how many were removed
+    async fn remove_prefix(&self, prefix: &str) -> CacheResult<usize>
+    where
+        K: AsRef<str>,
+    {
+        let prefix = prefix.to_string();
+        self.remove_matching(&move |key: &K| key.as_ref().starts_with(&prefix))
+            .await
+    }
+
     /// Clear all entries
     async fn clear(&self) -> CacheResult<()>;
 
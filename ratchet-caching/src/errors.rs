@@ -16,6 +16,14 @@ pub enum CacheError {
     #[error("Key not found in cache")]
     KeyNotFound,
 
+    /// Key could not be parsed into the cache's key type
+    #[error("Invalid cache key: {0}")]
+    InvalidKey(String),
+
+    /// No cache registered under the given name
+    #[error("Cache not found: {0}")]
+    CacheNotFound(String),
+
     /// Serialization error
     #[error("Serialization error: {0}")]
     SerializationError(String),
@@ -36,6 +44,10 @@ pub enum CacheError {
     #[error("Invalid cache configuration: {0}")]
     InvalidConfiguration(String),
 
+    /// Operation not supported by this cache store
+    #[error("Operation not supported by cache store: {0}")]
+    Unsupported(String),
+
     /// Backend-specific error
     #[error("Cache backend error: {0}")]
     BackendError(String),
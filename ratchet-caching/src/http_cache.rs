@@ -163,6 +163,11 @@ impl HttpCache {
     }
 
     /// Cache a response
+    ///
+    /// Without a `custom_ttl`, the entry expires after the response's
+    /// `Cache-Control` `s-maxage`/`max-age` when `honor_cache_control` is enabled,
+    /// falling back to the store's default TTL. Responses marked `no-store` or
+    /// `no-cache` are not cached.
     pub async fn put(
         &self,
         key: HttpCacheKey,
@@ -177,6 +182,16 @@ impl HttpCache {
             )));
         }
 
+        let custom_ttl = match custom_ttl {
+            Some(ttl) => Some(ttl),
+            None if self.config.honor_cache_control => match Self::response_cache_control(&response) {
+                Some(control) if !control.is_cacheable() => return Ok(()),
+                Some(control) => control.s_maxage.or(control.max_age),
+                None => None,
+            },
+            None => None,
+        };
+
         let response = Arc::new(response);
 
         match &self.inner {
@@ -221,6 +236,15 @@ impl HttpCache {
         }
     }
 
+    /// Parse the `Cache-Control` header of a response, if present
+    fn response_cache_control(response: &CachedHttpResponse) -> Option<CacheControl> {
+        response
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("cache-control"))
+            .map(|(_, value)| Self::parse_cache_control(value))
+    }
+
     /// Parse cache control header
     pub fn parse_cache_control(header: &str) -> CacheControl {
         let mut control = CacheControl::default();
@@ -326,6 +350,55 @@ mod tests {
         assert_eq!(ttl, Duration::from_secs(7200)); // s-maxage takes precedence
     }
 
+    #[tokio::test]
+    async fn test_max_age_sets_entry_ttl() {
+        for cache_type in [crate::config::CacheType::Ttl, crate::config::CacheType::Moka] {
+            let config = HttpCacheConfig {
+                enabled: true,
+                max_entries: 100,
+                max_response_size: 1024 * 1024,
+                default_ttl_seconds: 300,
+                honor_cache_control: true,
+                cache_type,
+            };
+            let cache = HttpCache::from_config(config);
+
+            let response = |cache_control: &str| CachedHttpResponse {
+                status_code: 200,
+                headers: HashMap::from([("Cache-Control".to_string(), cache_control.to_string())]),
+                body: b"body".to_vec(),
+                cached_at: Utc::now(),
+                expires_at: None,
+                etag: None,
+                last_modified: None,
+                size_bytes: 4,
+            };
+
+            let short = HttpCacheKey::new("GET", "https://api.example.com/short");
+            let long = HttpCacheKey::new("GET", "https://api.example.com/long");
+            let uncacheable = HttpCacheKey::new("GET", "https://api.example.com/private");
+
+            cache.put(short.clone(), response("max-age=0"), None).await.unwrap();
+            cache
+                .put(long.clone(), response("public, max-age=3600"), None)
+                .await
+                .unwrap();
+            cache
+                .put(uncacheable.clone(), response("no-store"), None)
+                .await
+                .unwrap();
+
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            if let HttpCacheImpl::Moka(inner) = &cache.inner {
+                inner.run_pending_tasks().await;
+            }
+
+            assert!(cache.get(&short).await.unwrap().is_none());
+            assert!(cache.get(&long).await.unwrap().is_some());
+            assert!(cache.get(&uncacheable).await.unwrap().is_none());
+        }
+    }
+
     #[tokio::test]
     async fn test_size_limit() {
         let config = HttpCacheConfig {
@@ -6,6 +6,8 @@
 pub mod cache;
 pub mod config;
 pub mod errors;
+pub mod registry;
+pub mod single_flight;
 pub mod stats;
 pub mod stores;
 
@@ -15,9 +17,11 @@ pub mod result_cache;
 pub mod task_cache;
 
 // Re-export main types
-pub use cache::{Cache, CacheKey, CacheValue};
+pub use cache::{Cache, CacheKey, CacheValue, KeyPredicate};
 pub use config::CacheConfig;
 pub use errors::{CacheError, CacheResult};
+pub use registry::{CacheRegistry, ManagedCache, NamedCacheStats};
+pub use single_flight::{SingleFlight, SingleFlightCache};
 pub use stats::CacheStats;
 
 // Re-export store implementations
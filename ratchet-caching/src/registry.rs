@@ -0,0 +1,172 @@
+//! Registry of named caches for inspection and flushing
+//!
+//! Caches are registered under a name so operators can list their statistics
+//! and clear stale entries at runtime, e.g. through the admin API. Keys are
+//! addressed as strings and parsed into the cache's key type.
+
+use async_trait::async_trait;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::{
+    cache::{Cache, CacheKey, CacheValue},
+    CacheError, CacheResult, CacheStats,
+};
+
+/// Type-erased view of a cache exposing administrative operations
+#[async_trait]
+pub trait ManagedCache: Send + Sync {
+    /// Current statistics
+    async fn stats(&self) -> CacheResult<CacheStats>;
+
+    /// Remove every entry
+    async fn clear(&self) -> CacheResult<()>;
+
+    /// Remove a single entry, returning whether it was present
+    async fn remove_key(&self, key: &str) -> CacheResult<bool>;
+}
+
+/// [`ManagedCache`] adapter for a cache whose keys parse from strings
+struct NamedCache<K, V, C: ?Sized> {
+    cache: Arc<C>,
+    _types: PhantomData<fn(K, V)>,
+}
+
+#[async_trait]
+impl<K, V, C> ManagedCache for NamedCache<K, V, C>
+where
+    K: CacheKey + FromStr + 'static,
+    V: CacheValue + 'static,
+    C: Cache<K, V> + ?Sized + 'static,
+{
+    async fn stats(&self) -> CacheResult<CacheStats> {
+        self.cache.stats().await
+    }
+
+    async fn clear(&self) -> CacheResult<()> {
+        self.cache.clear().await
+    }
+
+    async fn remove_key(&self, key: &str) -> CacheResult<bool> {
+        let key = K::from_str(key).map_err(|_| CacheError::InvalidKey(key.to_string()))?;
+        Ok(self.cache.remove(&key).await?.is_some())
+    }
+}
+
+/// Statistics of a registered cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedCacheStats {
+    pub name: String,
+    pub stats: CacheStats,
+}
+
+/// Named caches available for inspection and flushing
+#[derive(Default)]
+pub struct CacheRegistry {
+    caches: RwLock<BTreeMap<String, Arc<dyn ManagedCache>>>,
+}
+
+impl CacheRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `cache` under `name`, replacing any cache already registered with that name
+    pub fn register<K, V, C>(&self, name: impl Into<String>, cache: Arc<C>)
+    where
+        K: CacheKey + FromStr + 'static,
+        V: CacheValue + 'static,
+        C: Cache<K, V> + ?Sized + 'static,
+    {
+        let cache = NamedCache {
+            cache,
+            _types: PhantomData,
+        };
+        self.register_managed(name, Arc::new(cache));
+    }
+
+    /// Register a store that is not a [`Cache`] but can be inspected and cleared like one
+    pub fn register_managed(&self, name: impl Into<String>, cache: Arc<dyn ManagedCache>) {
+        self.caches.write().insert(name.into(), cache);
+    }
+
+    /// Names of the registered caches, in order
+    pub fn names(&self) -> Vec<String> {
+        self.caches.read().keys().cloned().collect()
+    }
+
+    /// Statistics of every registered cache, ordered by name
+    pub async fn stats(&self) -> CacheResult<Vec<NamedCacheStats>> {
+        let caches: Vec<_> = self
+            .caches
+            .read()
+            .iter()
+            .map(|(name, cache)| (name.clone(), cache.clone()))
+            .collect();
+
+        let mut stats = Vec::with_capacity(caches.len());
+        for (name, cache) in caches {
+            stats.push(NamedCacheStats {
+                name,
+                stats: cache.stats().await?,
+            });
+        }
+        Ok(stats)
+    }
+
+    /// Clear the cache registered as `name`
+    pub async fn flush(&self, name: &str) -> CacheResult<()> {
+        self.get(name)?.clear().await
+    }
+
+    /// Remove `key` from the cache registered as `name`, returning whether it was present
+    pub async fn flush_key(&self, name: &str, key: &str) -> CacheResult<bool> {
+        self.get(name)?.remove_key(key).await
+    }
+
+    fn get(&self, name: &str) -> CacheResult<Arc<dyn ManagedCache>> {
+        self.caches
+            .read()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CacheError::CacheNotFound(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemoryCache;
+
+    #[tokio::test]
+    async fn test_flush_clears_cached_entries() {
+        let registry = CacheRegistry::new();
+        let tasks: Arc<InMemoryCache<String, String>> = Arc::new(InMemoryCache::new());
+        registry.register("tasks", tasks.clone());
+
+        tasks.put("addition".to_string(), "v1".to_string()).await.unwrap();
+        tasks.put("weather".to_string(), "v1".to_string()).await.unwrap();
+
+        assert!(registry.flush_key("tasks", "addition").await.unwrap());
+        assert!(!registry.flush_key("tasks", "addition").await.unwrap());
+        assert_eq!(tasks.get(&"weather".to_string()).await.unwrap().as_deref(), Some("v1"));
+
+        registry.flush("tasks").await.unwrap();
+        assert_eq!(tasks.get(&"weather".to_string()).await.unwrap(), None);
+
+        let stats = registry.stats().await.unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].name, "tasks");
+        assert_eq!(stats[0].stats.entry_count, 0);
+
+        assert!(matches!(
+            registry.flush("missing").await,
+            Err(CacheError::CacheNotFound(_))
+        ));
+    }
+}
@@ -195,12 +195,19 @@ impl ResultCache {
         }
     }
 
+    /// Remove every cached result for a task, across all versions and inputs
+    pub async fn invalidate_task(&self, task_id: &str) -> CacheResult<usize> {
+        let matches_task = |key: &ResultCacheKey| key.task_id == task_id;
+
+        match &self.inner {
+            ResultCacheImpl::Ttl(cache) => cache.remove_matching(&matches_task).await,
+            ResultCacheImpl::Moka(cache) => cache.remove_matching(&matches_task).await,
+        }
+    }
+
     /// Clear results for a specific task
-    pub async fn clear_task(&self, _task_id: &str) -> CacheResult<usize> {
-        // This is a simplified implementation
-        // In production, you'd maintain an index of keys by task_id
-        self.clear().await?;
-        Ok(0)
+    pub async fn clear_task(&self, task_id: &str) -> CacheResult<usize> {
+        self.invalidate_task(task_id).await
     }
 
     /// Get cache statistics
@@ -290,6 +297,35 @@ mod tests {
         assert!(cached.is_none());
     }
 
+    #[tokio::test]
+    async fn test_invalidate_task() {
+        for cache_type in [crate::config::CacheType::Ttl, crate::config::CacheType::Moka] {
+            let cache = ResultCache::from_config(ResultCacheConfig {
+                enabled: true,
+                max_entries: 100,
+                max_result_size: 1024 * 1024,
+                ttl_seconds: 3600,
+                cache_only_success: true,
+                cache_type,
+            });
+
+            let keys = [
+                ResultCacheKey::new("task1", "1.0.0", &serde_json::json!({"x": 1})),
+                ResultCacheKey::new("task1", "1.0.1", &serde_json::json!({"x": 2})),
+                ResultCacheKey::new("task2", "1.0.0", &serde_json::json!({"x": 1})),
+            ];
+            for key in &keys {
+                let result = CachedResult::success(uuid::Uuid::new_v4(), serde_json::json!({}), 10);
+                cache.put(key.clone(), result).await.unwrap();
+            }
+
+            assert_eq!(cache.invalidate_task("task1").await.unwrap(), 2);
+            assert!(cache.get(&keys[0]).await.unwrap().is_none());
+            assert!(cache.get(&keys[1]).await.unwrap().is_none());
+            assert!(cache.get(&keys[2]).await.unwrap().is_some());
+        }
+    }
+
     #[tokio::test]
     async fn test_deterministic_check() {
         let deterministic_task = serde_json::json!({
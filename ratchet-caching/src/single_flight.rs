@@ -0,0 +1,303 @@
+//! Single-flight cache population
+//!
+//! When a hot entry is missing or has expired, every concurrent caller would
+//! otherwise recompute it. Single-flight lets the first caller compute the value
+//! while the others wait for that result instead of starting their own.
+
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::watch;
+
+use crate::{
+    cache::{Cache, CacheKey, CacheValue, KeyPredicate},
+    CacheError, CacheResult, CacheStats,
+};
+
+/// Outcome shared with callers waiting on an in-flight computation
+type FlightResult<V> = Option<Result<V, String>>;
+
+/// Tracks in-flight computations so each key is computed by one caller at a time
+pub struct SingleFlight<K, V> {
+    in_flight: Mutex<HashMap<K, watch::Receiver<FlightResult<V>>>>,
+}
+
+impl<K: CacheKey + 'static, V: CacheValue + 'static> SingleFlight<K, V> {
+    /// Create an empty single-flight group
+    pub fn new() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Number of keys currently being computed
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().len()
+    }
+
+    /// Get `key` from `cache`, computing and storing it on a miss
+    ///
+    /// Only one caller runs `compute` for a given key; concurrent callers await its
+    /// result. If the computing caller fails, waiters receive a
+    /// [`CacheError::BackendError`] carrying the original message. If it is cancelled
+    /// before finishing, one of the waiters takes over the computation.
+    pub async fn get_or_compute<C, F, Fut>(&self, cache: &C, key: K, compute: F) -> CacheResult<V>
+    where
+        C: Cache<K, V> + ?Sized,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CacheResult<V>>,
+    {
+        loop {
+            if let Some(value) = cache.get(&key).await? {
+                return Ok(value);
+            }
+
+            let flight = {
+                let mut in_flight = self.in_flight.lock();
+                match in_flight.get(&key) {
+                    Some(receiver) => Err(receiver.clone()),
+                    None => {
+                        let (sender, receiver) = watch::channel(None);
+                        in_flight.insert(key.clone(), receiver);
+                        Ok(sender)
+                    }
+                }
+            };
+
+            match flight {
+                Ok(sender) => {
+                    let _guard = FlightGuard { group: self, key: &key };
+
+                    // A previous flight may have populated the cache after our first lookup
+                    let result = match cache.get(&key).await? {
+                        Some(value) => Ok(value),
+                        None => match compute().await {
+                            Ok(value) => cache.put(key.clone(), value.clone()).await.map(|_| value),
+                            Err(e) => Err(e),
+                        },
+                    };
+
+                    let shared = match &result {
+                        Ok(value) => Ok(value.clone()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    sender.send_replace(Some(shared));
+                    return result;
+                }
+                Err(mut receiver) => {
+                    let outcome = receiver.wait_for(|outcome| outcome.is_some()).await;
+                    match outcome.as_deref() {
+                        Ok(Some(Ok(value))) => return Ok(value.clone()),
+                        Ok(Some(Err(message))) => {
+                            return Err(CacheError::BackendError(format!(
+                                "Shared computation for key {:?} failed: {}",
+                                key, message
+                            )))
+                        }
+                        // The computing caller was cancelled; retry and possibly take over
+                        _ => continue,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<K: CacheKey + 'static, V: CacheValue + 'static> Default for SingleFlight<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Removes a key from the in-flight map when its computation ends or is cancelled
+struct FlightGuard<'a, K: CacheKey + 'static, V: CacheValue + 'static> {
+    group: &'a SingleFlight<K, V>,
+    key: &'a K,
+}
+
+impl<K: CacheKey + 'static, V: CacheValue + 'static> Drop for FlightGuard<'_, K, V> {
+    fn drop(&mut self) {
+        self.group.in_flight.lock().remove(self.key);
+    }
+}
+
+/// Cache wrapper adding single-flight `get_or_compute` to any [`Cache`]
+pub struct SingleFlightCache<K, V, C> {
+    cache: C,
+    flights: SingleFlight<K, V>,
+}
+
+impl<K, V, C> SingleFlightCache<K, V, C>
+where
+    K: CacheKey + 'static,
+    V: CacheValue + 'static,
+    C: Cache<K, V>,
+{
+    /// Wrap an existing cache
+    pub fn new(cache: C) -> Self {
+        Self {
+            cache,
+            flights: SingleFlight::new(),
+        }
+    }
+
+    /// Get the wrapped cache
+    pub fn inner(&self) -> &C {
+        &self.cache
+    }
+
+    /// Get a value, computing it once across concurrent callers on a miss
+    pub async fn get_or_compute<F, Fut>(&self, key: K, compute: F) -> CacheResult<V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CacheResult<V>>,
+    {
+        self.flights.get_or_compute(&self.cache, key, compute).await
+    }
+}
+
+#[async_trait]
+impl<K, V, C> Cache<K, V> for SingleFlightCache<K, V, C>
+where
+    K: CacheKey + 'static,
+    V: CacheValue + 'static,
+    C: Cache<K, V>,
+{
+    async fn get(&self, key: &K) -> CacheResult<Option<V>> {
+        self.cache.get(key).await
+    }
+
+    async fn put(&self, key: K, value: V) -> CacheResult<()> {
+        self.cache.put(key, value).await
+    }
+
+    async fn put_with_ttl(&self, key: K, value: V, ttl: Duration) -> CacheResult<()> {
+        self.cache.put_with_ttl(key, value, ttl).await
+    }
+
+    async fn remove(&self, key: &K) -> CacheResult<Option<V>> {
+        self.cache.remove(key).await
+    }
+
+    async fn remove_matching(&self, predicate: &KeyPredicate<'_, K>) -> CacheResult<usize> {
+        self.cache.remove_matching(predicate).await
+    }
+
+    async fn clear(&self) -> CacheResult<()> {
+        self.cache.clear().await
+    }
+
+    async fn len(&self) -> CacheResult<usize> {
+        self.cache.len().await
+    }
+
+    async fn stats(&self) -> CacheResult<CacheStats> {
+        self.cache.stats().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stores::{InMemoryCache, MokaCache};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    async fn assert_single_computation<C>(cache: SingleFlightCache<String, u64, C>)
+    where
+        C: Cache<String, u64> + 'static,
+    {
+        let cache = Arc::new(cache);
+        let computations = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..32)
+            .map(|_| {
+                let cache = cache.clone();
+                let computations = computations.clone();
+                tokio::spawn(async move {
+                    cache
+                        .get_or_compute("hot".to_string(), || async move {
+                            computations.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            Ok(42)
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap(), 42);
+        }
+
+        assert_eq!(computations.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.get(&"hot".to_string()).await.unwrap(), Some(42));
+        assert_eq!(cache.flights.in_flight(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_single_flight_inmemory() {
+        assert_single_computation(SingleFlightCache::new(InMemoryCache::new())).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_single_flight_moka() {
+        assert_single_computation(SingleFlightCache::new(MokaCache::new(100))).await;
+    }
+
+    #[tokio::test]
+    async fn test_failed_computation_is_shared_and_not_cached() {
+        let cache = Arc::new(SingleFlightCache::new(InMemoryCache::<String, u64>::new()));
+
+        let leader = {
+            let cache = cache.clone();
+            tokio::spawn(async move {
+                cache
+                    .get_or_compute("key".to_string(), || async {
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        Err(CacheError::BackendError("upstream unavailable".to_string()))
+                    })
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let follower = cache.get_or_compute("key".to_string(), || async { Ok(7) }).await;
+        let message = follower.unwrap_err().to_string();
+        assert!(message.contains("upstream unavailable"));
+        assert!(leader.await.unwrap().is_err());
+
+        // The failure is not cached, so the next caller computes again
+        let value = cache.get_or_compute("key".to_string(), || async { Ok(7) }).await;
+        assert_eq!(value.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_computation_hands_over() {
+        let cache = Arc::new(SingleFlightCache::new(InMemoryCache::<String, u64>::new()));
+
+        let leader = {
+            let cache = cache.clone();
+            tokio::spawn(async move {
+                cache
+                    .get_or_compute("key".to_string(), || async {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        Ok(1)
+                    })
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let follower = {
+            let cache = cache.clone();
+            tokio::spawn(async move { cache.get_or_compute("key".to_string(), || async { Ok(2) }).await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        leader.abort();
+        assert_eq!(follower.await.unwrap().unwrap(), 2);
+    }
+}
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::{
-    cache::{Cache, CacheEntry, CacheKey, CacheValue},
+    cache::{Cache, CacheEntry, CacheKey, CacheValue, KeyPredicate},
     stats::{create_stats_collector, SharedStatsCollector},
     CacheResult, CacheStats,
 };
@@ -117,6 +117,28 @@ impl<K: CacheKey + 'static, V: CacheValue + 'static> Cache<K, V> for InMemoryCac
         }
     }
 
+    async fn contains_key(&self, key: &K) -> CacheResult<bool> {
+        // Peek without recording an access so warm-up checks don't skew stats
+        Ok(self.store.read().get(key).is_some_and(|entry| !entry.is_expired()))
+    }
+
+    async fn remove_matching(&self, predicate: &KeyPredicate<'_, K>) -> CacheResult<usize> {
+        let mut store = self.store.write();
+        let mut removed = 0;
+        store.retain(|key, entry| {
+            if !predicate(key) {
+                return true;
+            }
+            if !entry.is_expired() {
+                removed += 1;
+            }
+            self.stats.record_eviction();
+            false
+        });
+
+        Ok(removed)
+    }
+
     async fn clear(&self) -> CacheResult<()> {
         let mut store = self.store.write();
         let count = store.len();
@@ -214,4 +236,16 @@ mod tests {
         assert_eq!(stats.entry_count, 2);
         assert_eq!(stats.hit_rate, 2.0 / 3.0);
     }
+
+    #[tokio::test]
+    async fn test_remove_prefix() {
+        let cache = InMemoryCache::new();
+        cache.put("task:a:1", 1).await.unwrap();
+        cache.put("task:a:2", 2).await.unwrap();
+        cache.put("task:b:1", 3).await.unwrap();
+
+        assert_eq!(cache.remove_prefix("task:a:").await.unwrap(), 2);
+        assert_eq!(cache.get(&"task:a:1").await.unwrap(), None);
+        assert_eq!(cache.get(&"task:b:1").await.unwrap(), Some(3));
+    }
 }
@@ -231,6 +231,18 @@ impl<K: CacheKey + 'static, V: CacheValue + 'static> Cache<K, V> for LruCache<K,
         }
     }
 
+    async fn contains_key(&self, key: &K) -> CacheResult<bool> {
+        // Peek without promoting the entry so warm-up checks don't change eviction order
+        let Some(index) = self.map.read().get(key).copied() else {
+            return Ok(false);
+        };
+        Ok(self
+            .queue
+            .read()
+            .get(index)
+            .is_some_and(|node| !node.entry.is_expired()))
+    }
+
     async fn clear(&self) -> CacheResult<()> {
         let mut map = self.map.write();
         let mut queue = self.queue.write();
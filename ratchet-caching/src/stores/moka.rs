@@ -2,11 +2,12 @@
 
 use async_trait::async_trait;
 use moka::future::Cache as MokaInner;
+use moka::Expiry;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::{
-    cache::{Cache, CacheKey, CacheValue},
+    cache::{Cache, CacheKey, CacheValue, KeyPredicate},
     stats::{create_stats_collector, SharedStatsCollector},
     CacheResult, CacheStats,
 };
@@ -14,12 +15,41 @@ use crate::{
 /// Moka-based cache wrapper
 pub struct MokaCache<K, V> {
     /// Inner Moka cache
-    inner: MokaInner<K, V>,
+    inner: MokaInner<K, MokaEntry<V>>,
 
     /// Statistics collector
     stats: SharedStatsCollector,
 }
 
+/// Stored value with an optional entry-specific TTL
+#[derive(Clone)]
+struct MokaEntry<V> {
+    value: V,
+    ttl: Option<Duration>,
+}
+
+/// Expiry policy applying an entry's own TTL, or the cache-wide TTL when it has none
+struct MokaExpiry {
+    default_ttl: Option<Duration>,
+}
+
+impl<K, V> Expiry<K, MokaEntry<V>> for MokaExpiry {
+    fn expire_after_create(&self, _key: &K, entry: &MokaEntry<V>, _created_at: Instant) -> Option<Duration> {
+        entry.ttl.or(self.default_ttl)
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &K,
+        entry: &MokaEntry<V>,
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        // Overwriting an entry restarts its expiry with the new entry's TTL
+        entry.ttl.or(self.default_ttl)
+    }
+}
+
 impl<K, V> MokaCache<K, V>
 where
     K: CacheKey + Send + Sync + 'static,
@@ -27,18 +57,18 @@ where
 {
     /// Create a new Moka cache with max capacity
     pub fn new(max_capacity: u64) -> Self {
-        let inner = MokaInner::builder().max_capacity(max_capacity).build();
-
-        Self {
-            inner,
-            stats: create_stats_collector(),
-        }
+        Self::builder().max_capacity(max_capacity).build()
     }
 
     /// Create a new Moka cache with advanced configuration
     pub fn builder() -> MokaCacheBuilder<K, V> {
         MokaCacheBuilder::new()
     }
+
+    /// Apply pending evictions so `len` and `contains_key` reflect the capacity policy
+    pub async fn run_pending_tasks(&self) {
+        self.inner.run_pending_tasks().await;
+    }
 }
 
 #[async_trait]
@@ -50,7 +80,7 @@ where
     async fn get(&self, key: &K) -> CacheResult<Option<V>> {
         let start = std::time::Instant::now();
 
-        let result = self.inner.get(key).await;
+        let result = self.inner.get(key).await.map(|entry| entry.value);
 
         if result.is_some() {
             self.stats.record_hit();
@@ -67,7 +97,7 @@ where
     async fn put(&self, key: K, value: V) -> CacheResult<()> {
         let start = std::time::Instant::now();
 
-        self.inner.insert(key, value).await;
+        self.inner.insert(key, MokaEntry { value, ttl: None }).await;
         self.stats.record_put();
 
         let latency_ns = start.elapsed().as_nanos() as u64;
@@ -76,12 +106,10 @@ where
         Ok(())
     }
 
-    async fn put_with_ttl(&self, key: K, value: V, _ttl: Duration) -> CacheResult<()> {
+    async fn put_with_ttl(&self, key: K, value: V, ttl: Duration) -> CacheResult<()> {
         let start = std::time::Instant::now();
 
-        // Moka doesn't have insert_with_ttl, need to use a builder or invalidate after TTL
-        self.inner.insert(key, value).await;
-        // Note: TTL should be configured at cache creation time in Moka
+        self.inner.insert(key, MokaEntry { value, ttl: Some(ttl) }).await;
         self.stats.record_put();
 
         let latency_ns = start.elapsed().as_nanos() as u64;
@@ -91,7 +119,7 @@ where
     }
 
     async fn remove(&self, key: &K) -> CacheResult<Option<V>> {
-        let result = self.inner.remove(key).await;
+        let result = self.inner.remove(key).await.map(|entry| entry.value);
 
         if result.is_some() {
             self.stats.record_eviction();
@@ -100,6 +128,29 @@ where
         Ok(result)
     }
 
+    async fn contains_key(&self, key: &K) -> CacheResult<bool> {
+        Ok(self.inner.contains_key(key))
+    }
+
+    async fn remove_matching(&self, predicate: &KeyPredicate<'_, K>) -> CacheResult<usize> {
+        let keys: Vec<Arc<K>> = self
+            .inner
+            .iter()
+            .filter(|(key, _)| predicate(key))
+            .map(|(key, _)| key)
+            .collect();
+
+        let mut removed = 0;
+        for key in keys {
+            if self.inner.remove(key.as_ref()).await.is_some() {
+                self.stats.record_eviction();
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
     async fn clear(&self) -> CacheResult<()> {
         let count = self.inner.entry_count() as usize;
 
@@ -183,16 +234,17 @@ where
             builder = builder.max_capacity(capacity);
         }
 
-        if let Some(ttl) = self.time_to_live {
-            builder = builder.time_to_live(ttl);
-        }
+        // The cache-wide TTL is applied by the expiry policy so entries can override it
+        builder = builder.expire_after(MokaExpiry {
+            default_ttl: self.time_to_live,
+        });
 
         if let Some(tti) = self.time_to_idle {
             builder = builder.time_to_idle(tti);
         }
 
         if let Some(weigher) = self.weigher {
-            builder = builder.weigher(move |k: &K, v: &V| weigher(k, v));
+            builder = builder.weigher(move |k: &K, entry: &MokaEntry<V>| weigher(k, &entry.value));
         }
 
         MokaCache {
@@ -263,4 +315,36 @@ mod tests {
         // Check that capacity is respected
         assert!(cache.len().await.unwrap() <= 2);
     }
+
+    #[tokio::test]
+    async fn test_moka_per_entry_ttl() {
+        let cache = MokaCache::builder()
+            .max_capacity(10)
+            .time_to_live(Duration::from_millis(300))
+            .build();
+
+        cache
+            .put_with_ttl("short", "value1", Duration::from_millis(50))
+            .await
+            .unwrap();
+        cache
+            .put_with_ttl("long", "value2", Duration::from_secs(60))
+            .await
+            .unwrap();
+        cache.put("default", "value3").await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cache.inner.run_pending_tasks().await;
+
+        // Only the entry-specific short TTL has elapsed
+        assert_eq!(cache.get(&"short").await.unwrap(), None);
+        assert_eq!(cache.get(&"default").await.unwrap(), Some("value3"));
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        cache.inner.run_pending_tasks().await;
+
+        // The default TTL has now elapsed, but the longer override outlives it
+        assert_eq!(cache.get(&"default").await.unwrap(), None);
+        assert_eq!(cache.get(&"long").await.unwrap(), Some("value2"));
+    }
 }
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crate::{
-    cache::{Cache, CacheEntry, CacheKey, CacheValue},
+    cache::{Cache, CacheEntry, CacheKey, CacheValue, KeyPredicate},
     stats::{create_stats_collector, SharedStatsCollector},
     CacheResult, CacheStats,
 };
@@ -145,6 +145,28 @@ impl<K: CacheKey + 'static, V: CacheValue + 'static> Cache<K, V> for TtlCache<K,
         }
     }
 
+    async fn contains_key(&self, key: &K) -> CacheResult<bool> {
+        // Peek without recording an access so warm-up checks don't skew stats
+        Ok(self.store.read().get(key).is_some_and(|entry| !entry.is_expired()))
+    }
+
+    async fn remove_matching(&self, predicate: &KeyPredicate<'_, K>) -> CacheResult<usize> {
+        let mut store = self.store.write();
+        let mut removed = 0;
+        store.retain(|key, entry| {
+            if !predicate(key) {
+                return true;
+            }
+            if !entry.is_expired() {
+                removed += 1;
+            }
+            self.stats.record_eviction();
+            false
+        });
+
+        Ok(removed)
+    }
+
     async fn clear(&self) -> CacheResult<()> {
         let mut store = self.store.write();
         let count = store.len();
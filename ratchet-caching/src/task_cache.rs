@@ -8,9 +8,12 @@ use std::time::Duration;
 use crate::{
     cache::{Cache, CacheWarmer},
     config::TaskCacheConfig,
+    single_flight::SingleFlight,
     stores::{InMemoryCache, LruCache, MokaCache, TtlCache},
     CacheError, CacheResult, CacheStats,
 };
+use std::collections::HashSet;
+use std::future::Future;
 
 /// Task metadata for caching
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +73,9 @@ pub struct TaskCache {
 
     /// Configuration
     config: TaskCacheConfig,
+
+    /// In-flight loads for `get_or_compute`
+    flights: SingleFlight<String, Arc<CachedTask>>,
 }
 
 /// Inner cache implementation enum
@@ -107,7 +113,11 @@ impl TaskCache {
             }
         };
 
-        Self { inner, config }
+        Self {
+            inner,
+            config,
+            flights: SingleFlight::new(),
+        }
     }
 
     /// Get a task by ID
@@ -120,6 +130,36 @@ impl TaskCache {
         }
     }
 
+    /// Get a task, loading it on a miss
+    ///
+    /// Concurrent callers for the same task share one `load`, so an expired hot entry
+    /// is only recomputed once.
+    pub async fn get_or_compute<F, Fut>(&self, task_id: &str, load: F) -> CacheResult<Arc<CachedTask>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CacheResult<CachedTask>>,
+    {
+        let key = task_id.to_string();
+        let max_memory = self.config.max_memory_mb.checked_mul(1024 * 1024);
+        let load = move || async move {
+            let task = load().await?;
+            if max_memory.is_some_and(|max| task.memory_size > max) {
+                return Err(CacheError::CapacityExceeded(format!(
+                    "Task {} exceeds memory limit",
+                    task_id
+                )));
+            }
+            Ok(Arc::new(task))
+        };
+
+        match &self.inner {
+            TaskCacheImpl::InMemory(cache) => self.flights.get_or_compute(cache, key, load).await,
+            TaskCacheImpl::Lru(cache) => self.flights.get_or_compute(cache, key, load).await,
+            TaskCacheImpl::Ttl(cache) => self.flights.get_or_compute(cache, key, load).await,
+            TaskCacheImpl::Moka(cache) => self.flights.get_or_compute(cache, key, load).await,
+        }
+    }
+
     /// Put a task into cache
     pub async fn put(&self, task_id: String, task: CachedTask) -> CacheResult<()> {
         // Check memory limit
@@ -142,6 +182,60 @@ impl TaskCache {
         }
     }
 
+    /// Bulk-insert tasks, e.g. all enabled tasks at server startup
+    ///
+    /// Entries go through the normal capacity and eviction policy: tasks over the
+    /// memory limit are skipped and earlier entries may be evicted by later ones.
+    /// Returns how many of the offered tasks are present in the cache afterwards.
+    pub async fn warm(&self, tasks: impl IntoIterator<Item = (String, CachedTask)>) -> CacheResult<usize> {
+        let mut offered = HashSet::new();
+
+        for (task_id, task) in tasks {
+            match self.put(task_id.clone(), task).await {
+                Ok(()) => {
+                    offered.insert(task_id);
+                }
+                Err(CacheError::CapacityExceeded(_)) => {
+                    offered.remove(&task_id);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if let TaskCacheImpl::Moka(cache) = &self.inner {
+            cache.run_pending_tasks().await;
+        }
+
+        let mut admitted = 0;
+        for task_id in &offered {
+            if self.contains(task_id).await? {
+                admitted += 1;
+            }
+        }
+
+        Ok(admitted)
+    }
+
+    /// Warm the cache from a future producing the tasks, for lazy loading from storage
+    pub async fn warm_with<F, I>(&self, tasks: F) -> CacheResult<usize>
+    where
+        F: Future<Output = CacheResult<I>>,
+        I: IntoIterator<Item = (String, CachedTask)>,
+    {
+        self.warm(tasks.await?).await
+    }
+
+    /// Check whether a task is cached without counting it as an access
+    pub async fn contains(&self, task_id: &str) -> CacheResult<bool> {
+        let key = task_id.to_string();
+        match &self.inner {
+            TaskCacheImpl::InMemory(cache) => cache.contains_key(&key).await,
+            TaskCacheImpl::Lru(cache) => cache.contains_key(&key).await,
+            TaskCacheImpl::Ttl(cache) => cache.contains_key(&key).await,
+            TaskCacheImpl::Moka(cache) => cache.contains_key(&key).await,
+        }
+    }
+
     /// Remove a task from cache
     pub async fn remove(&self, task_id: &str) -> CacheResult<Option<Arc<CachedTask>>> {
         match &self.inner {
@@ -304,4 +398,112 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), CacheError::CapacityExceeded(_)));
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_get_or_compute_loads_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let config = TaskCacheConfig {
+            enabled: true,
+            max_entries: 10,
+            max_memory_mb: 10,
+            cache_type: CacheType::Moka,
+            ttl_seconds: None,
+        };
+        let cache = Arc::new(TaskCache::from_config(config));
+        let loads = Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..16)
+            .map(|_| {
+                let cache = cache.clone();
+                let loads = loads.clone();
+                tokio::spawn(async move {
+                    cache
+                        .get_or_compute("hot", || async move {
+                            loads.fetch_add(1, Ordering::SeqCst);
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            Ok(CachedTask {
+                                uuid: uuid::Uuid::new_v4(),
+                                name: "hot_task".to_string(),
+                                version: "1.0.0".to_string(),
+                                metadata: serde_json::json!({}),
+                                input_schema: serde_json::json!({}),
+                                output_schema: serde_json::json!({}),
+                                js_content: None,
+                                memory_size: 1024,
+                            })
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().unwrap().name, "hot_task");
+        }
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+    }
+
+    fn warm_task(name: &str, memory_size: usize) -> (String, CachedTask) {
+        (
+            name.to_string(),
+            CachedTask {
+                uuid: uuid::Uuid::new_v4(),
+                name: name.to_string(),
+                version: "1.0.0".to_string(),
+                metadata: serde_json::json!({}),
+                input_schema: serde_json::json!({}),
+                output_schema: serde_json::json!({}),
+                js_content: None,
+                memory_size,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_warm_respects_capacity() {
+        for cache_type in [CacheType::Lru, CacheType::Moka] {
+            let config = TaskCacheConfig {
+                enabled: true,
+                max_entries: 3,
+                max_memory_mb: 1,
+                cache_type,
+                ttl_seconds: None,
+            };
+            let cache = TaskCache::from_config(config);
+
+            let mut tasks: Vec<_> = (0..5).map(|i| warm_task(&format!("task{}", i), 1024)).collect();
+            tasks.push(warm_task("too_large", 2 * 1024 * 1024));
+
+            let admitted = cache.warm(tasks).await.unwrap();
+            assert_eq!(admitted, 3);
+
+            // Warm-up checks don't count as cache hits
+            assert_eq!(cache.stats().await.unwrap().hits, 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warm_with_future() {
+        let config = TaskCacheConfig {
+            enabled: true,
+            max_entries: 10,
+            max_memory_mb: 10,
+            cache_type: CacheType::InMemory,
+            ttl_seconds: None,
+        };
+        let cache = TaskCache::from_config(config);
+
+        let admitted = cache
+            .warm_with(async { Ok(vec![warm_task("a", 1024), warm_task("b", 1024)]) })
+            .await
+            .unwrap();
+        assert_eq!(admitted, 2);
+        assert!(cache.get("a").await.unwrap().is_some());
+
+        let failed = cache
+            .warm_with(async { Err::<Vec<(String, CachedTask)>, _>(CacheError::BackendError("db down".to_string())) })
+            .await;
+        assert!(failed.is_err());
+    }
 }
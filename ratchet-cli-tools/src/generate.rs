@@ -77,13 +77,18 @@ pub fn generate_task(config: TaskGenerationConfig) -> Result<GeneratedTaskInfo>
         .as_deref()
         .unwrap_or("A task that performs a specific operation");
     let task_version = config.version.as_deref().unwrap_or("1.0.0");
+    let task_name = config
+        .path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("my-task");
 
     info!("Creating task files with UUID: {}", task_uuid);
 
     let mut files_created = Vec::new();
 
     // Create metadata.json
-    let metadata = create_metadata_json(task_uuid, task_label, task_description, task_version)?;
+    let metadata = create_metadata_json(task_uuid, task_name, task_label, task_description, task_version)?;
     let metadata_path = config.path.join("metadata.json");
     write_json_file(&metadata_path, &metadata)?;
     files_created.push("metadata.json".to_string());
@@ -129,10 +134,11 @@ pub fn generate_task(config: TaskGenerationConfig) -> Result<GeneratedTaskInfo>
 }
 
 /// Create metadata.json content
-fn create_metadata_json(uuid: Uuid, label: &str, description: &str, version: &str) -> Result<JsonValue> {
+fn create_metadata_json(uuid: Uuid, name: &str, label: &str, description: &str, version: &str) -> Result<JsonValue> {
     debug!("Creating metadata.json with UUID: {}", uuid);
     Ok(json!({
         "uuid": uuid,
+        "name": name,
         "version": version,
         "label": label,
         "description": description
@@ -274,6 +280,7 @@ mod tests {
         // Check metadata file content
         let metadata_content = fs::read_to_string(task_path.join("metadata.json")).unwrap();
         let metadata: JsonValue = serde_json::from_str(&metadata_content).unwrap();
+        assert_eq!(metadata["name"], "custom-task");
         assert_eq!(metadata["label"], "Custom Task");
         assert_eq!(metadata["description"], "A custom task description");
         assert_eq!(metadata["version"], "2.5.0");
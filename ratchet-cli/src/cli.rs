@@ -1,5 +1,6 @@
 //! CLI argument parsing definitions
 
+use crate::output::ColorChoice;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -14,6 +15,10 @@ pub struct Cli {
     #[arg(long, value_name = "LEVEL", global = true)]
     pub log_level: Option<String>,
 
+    /// When to colorize output
+    #[arg(long, value_enum, value_name = "WHEN", global = true, default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+
     /// Run as worker process (internal use)
     #[arg(long, hide = true)]
     pub worker: bool,
@@ -41,6 +46,10 @@ pub enum Commands {
         /// Record execution to directory with timestamp
         #[arg(long, value_name = "PATH")]
         record: Option<PathBuf>,
+
+        /// Output format: json, yaml, pretty (default: pretty on a terminal, json otherwise)
+        #[arg(long, value_name = "FORMAT")]
+        format: Option<String>,
     },
 
     /// Start the Ratchet server
@@ -103,6 +112,10 @@ pub enum Commands {
         /// Path to the file system resource
         #[arg(long, value_name = "STRING")]
         from_fs: String,
+
+        /// Output format: json, yaml, pretty (default: pretty on a terminal, json otherwise)
+        #[arg(long, value_name = "FORMAT")]
+        format: Option<String>,
     },
 
     /// Replay a recorded task execution
@@ -134,6 +147,12 @@ pub enum Commands {
         repo_cmd: RepoCommands,
     },
 
+    /// Stored task management commands
+    Tasks {
+        #[command(subcommand)]
+        tasks_cmd: TasksCommands,
+    },
+
     /// Start an interactive console for Ratchet administration
     Console {
         /// Path to configuration file
@@ -281,18 +300,25 @@ pub enum ConfigCommands {
         /// Path to the configuration file
         #[arg(long, value_name = "PATH")]
         config_file: PathBuf,
+        /// Treat unknown keys as errors instead of warnings
+        #[arg(long)]
+        strict: bool,
     },
 
     /// Generate sample configuration files
     Generate {
         /// Configuration type: dev, production, enterprise, minimal, claude
-        #[arg(long, value_name = "TYPE", default_value = "dev")]
+        #[arg(long, alias = "type", value_name = "TYPE", default_value = "dev")]
         config_type: String,
 
         /// Output file path
         #[arg(long, value_name = "PATH")]
         output: PathBuf,
 
+        /// Output format: yaml, json, toml (default: from the output file extension, else yaml)
+        #[arg(long, value_name = "FORMAT")]
+        format: Option<String>,
+
         /// Overwrite existing file
         #[arg(long)]
         force: bool,
@@ -312,6 +338,31 @@ pub enum ConfigCommands {
         #[arg(long, value_name = "FORMAT", default_value = "yaml")]
         format: String,
     },
+
+    /// Show the settings that differ from the defaults
+    Diff {
+        /// Path to configuration file (optional, uses default loading logic)
+        #[arg(long, value_name = "PATH")]
+        config_file: Option<PathBuf>,
+
+        /// Output format: tree, json, yaml
+        #[arg(long, value_name = "FORMAT", default_value = "tree")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TasksCommands {
+    /// Re-validate all stored tasks and report the ones that fail
+    Revalidate {
+        /// Path to configuration file
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+
+        /// Maximum number of tasks validated at the same time
+        #[arg(long, value_name = "COUNT", default_value = "8")]
+        concurrency: usize,
+    },
 }
 
 #[derive(Subcommand)]
@@ -349,7 +400,7 @@ pub enum RepoCommands {
         #[arg(value_name = "DIR")]
         directory: Option<PathBuf>,
 
-        /// Force regeneration of all metadata
+        /// Regenerate the manifest if it is missing or unreadable
         #[arg(long)]
         force: bool,
     },
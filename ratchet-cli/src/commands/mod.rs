@@ -3,4 +3,6 @@
 // Only include console module for now
 // Other command modules will be added when they are implemented
 pub mod console;
+#[cfg(feature = "registry")]
+pub mod repo;
 pub mod update;
@@ -0,0 +1,448 @@
+//! Task repository commands
+//!
+//! A task repository is a directory of task directories with a
+//! `ratchet-repo.yaml` manifest at its root. The manifest names the repository
+//! and records the tasks it held when its metadata was last refreshed, so
+//! `repo status` can report tasks that were added, changed or removed since.
+
+use anyhow::{Context, Result};
+use ratchet_registry::{FilesystemLoader, TaskLoader, TaskSource};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name of the repository manifest
+pub const MANIFEST_FILE: &str = "ratchet-repo.yaml";
+
+/// Manifest describing a task repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryManifest {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default = "default_version")]
+    pub version: String,
+    #[serde(default = "default_ratchet_version")]
+    pub ratchet_version: String,
+    /// Tasks in the repository when the manifest was last refreshed
+    #[serde(default)]
+    pub tasks: Vec<ManifestTask>,
+}
+
+fn default_version() -> String {
+    "1.0.0".to_string()
+}
+
+fn default_ratchet_version() -> String {
+    ">=0.6.0".to_string()
+}
+
+/// A task recorded in the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestTask {
+    pub name: String,
+    pub version: String,
+    /// Task directory relative to the repository root, with `/` separators
+    pub path: String,
+}
+
+impl RepositoryManifest {
+    /// Read the manifest of the repository in `dir`
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(MANIFEST_FILE);
+        let content = fs::read_to_string(&path).with_context(|| format!("Failed to read {:?}", path))?;
+        serde_yaml::from_str(&content).with_context(|| format!("Invalid repository manifest {:?}", path))
+    }
+
+    /// Write the manifest into the repository in `dir`
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(MANIFEST_FILE);
+        fs::write(&path, serde_yaml::to_string(self)?).with_context(|| format!("Failed to write {:?}", path))
+    }
+}
+
+/// Options for initializing a repository
+#[derive(Debug, Clone, Default)]
+pub struct InitOptions {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub version: String,
+    pub ratchet_version: String,
+    /// Initialize even if the directory is not empty or already has a manifest
+    pub force: bool,
+}
+
+/// Create a repository in `dir`, indexing any tasks already there
+pub async fn init_repository(dir: &Path, options: InitOptions) -> Result<RepositoryManifest> {
+    if dir.join(MANIFEST_FILE).exists() && !options.force {
+        return Err(anyhow::anyhow!(
+            "{:?} is already a task repository. Use --force to re-initialize it",
+            dir
+        ));
+    }
+    if dir.exists() && fs::read_dir(dir)?.next().is_some() && !options.force {
+        return Err(anyhow::anyhow!(
+            "Directory {:?} is not empty. Use --force to initialize it anyway",
+            dir
+        ));
+    }
+
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create repository directory {:?}", dir))?;
+
+    let manifest = RepositoryManifest {
+        name: options.name.unwrap_or_else(|| default_name(dir)),
+        description: options.description,
+        version: options.version,
+        ratchet_version: options.ratchet_version,
+        tasks: discover_tasks(dir).await?,
+    };
+    manifest.save(dir)?;
+
+    Ok(manifest)
+}
+
+/// Repository name derived from the name of its directory
+fn default_name(dir: &Path) -> String {
+    dir.canonicalize()
+        .ok()
+        .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "tasks".to_string())
+}
+
+/// Re-index the tasks of the repository in `dir` into its manifest
+///
+/// With `force`, a missing or unreadable manifest is regenerated from scratch
+/// instead of failing the refresh.
+pub async fn refresh_repository(dir: &Path, force: bool) -> Result<RepositoryManifest> {
+    let mut manifest = match RepositoryManifest::load(dir) {
+        Ok(manifest) => manifest,
+        Err(_) if force => RepositoryManifest {
+            name: default_name(dir),
+            description: None,
+            version: default_version(),
+            ratchet_version: default_ratchet_version(),
+            tasks: Vec::new(),
+        },
+        Err(e) => return Err(e.context("Use --force to regenerate the repository manifest")),
+    };
+    manifest.tasks = discover_tasks(dir).await?;
+    manifest.save(dir)?;
+    Ok(manifest)
+}
+
+/// How a task on disk compares to the manifest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSyncState {
+    /// Matches its manifest entry
+    InSync,
+    /// Name or version differ from its manifest entry
+    Modified,
+    /// On disk but not in the manifest
+    Untracked,
+    /// In the manifest but no longer on disk
+    Missing,
+}
+
+/// Status of one task in a repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStatus {
+    pub name: String,
+    /// Version on disk, or the recorded version of a missing task
+    pub version: String,
+    pub path: String,
+    pub state: TaskSyncState,
+    /// Version recorded in the manifest, when it differs from the one on disk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_version: Option<String>,
+}
+
+/// Status of a repository compared to its manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryStatusReport {
+    pub name: String,
+    pub path: PathBuf,
+    /// Whether the repository has a manifest; without one every task is untracked
+    pub has_manifest: bool,
+    pub tasks: Vec<TaskStatus>,
+}
+
+impl RepositoryStatusReport {
+    /// Tasks that differ from the manifest
+    pub fn out_of_sync(&self) -> impl Iterator<Item = &TaskStatus> {
+        self.tasks.iter().filter(|task| task.state != TaskSyncState::InSync)
+    }
+}
+
+/// Compare the tasks in the repository in `dir` with its manifest
+pub async fn repository_status(name: &str, dir: &Path) -> Result<RepositoryStatusReport> {
+    let manifest = if dir.join(MANIFEST_FILE).exists() {
+        Some(RepositoryManifest::load(dir)?)
+    } else {
+        None
+    };
+
+    let mut recorded: BTreeMap<String, ManifestTask> = manifest
+        .iter()
+        .flat_map(|manifest| manifest.tasks.iter().cloned())
+        .map(|task| (task.path.clone(), task))
+        .collect();
+
+    let mut tasks = Vec::new();
+    for task in discover_tasks(dir).await? {
+        let (state, manifest_version) = match recorded.remove(&task.path) {
+            Some(entry) if entry.name == task.name && entry.version == task.version => (TaskSyncState::InSync, None),
+            Some(entry) => (
+                TaskSyncState::Modified,
+                Some(entry.version).filter(|v| *v != task.version),
+            ),
+            None => (TaskSyncState::Untracked, None),
+        };
+        tasks.push(TaskStatus {
+            name: task.name,
+            version: task.version,
+            path: task.path,
+            state,
+            manifest_version,
+        });
+    }
+    tasks.extend(recorded.into_values().map(|task| TaskStatus {
+        name: task.name,
+        version: task.version,
+        path: task.path,
+        state: TaskSyncState::Missing,
+        manifest_version: None,
+    }));
+    tasks.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(RepositoryStatusReport {
+        has_manifest: manifest.is_some(),
+        name: manifest
+            .map(|manifest| manifest.name)
+            .unwrap_or_else(|| name.to_string()),
+        path: dir.to_path_buf(),
+        tasks,
+    })
+}
+
+/// Tasks in the repository in `dir`, ordered by path
+async fn discover_tasks(dir: &Path) -> Result<Vec<ManifestTask>> {
+    let source = TaskSource::Filesystem {
+        path: dir.to_string_lossy().into_owned(),
+        recursive: true,
+        watch: false,
+    };
+    let discovered = FilesystemLoader::new()
+        .discover_tasks(&source)
+        .await
+        .with_context(|| format!("Failed to discover tasks in {:?}", dir))?;
+
+    let mut tasks: Vec<ManifestTask> = discovered
+        .into_iter()
+        .map(|task| {
+            let location = task
+                .task_ref
+                .source
+                .strip_prefix("file://")
+                .unwrap_or(&task.task_ref.source);
+            let relative = Path::new(location).strip_prefix(dir).unwrap_or(Path::new(location));
+            ManifestTask {
+                name: task.metadata.name,
+                version: task.metadata.version,
+                path: relative
+                    .components()
+                    .map(|part| part.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            }
+        })
+        .collect();
+    tasks.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(tasks)
+}
+
+/// Render status reports as a table, listing every task with `detailed`
+pub fn render_status_table(reports: &[RepositoryStatusReport], detailed: bool) -> String {
+    let mut out = String::new();
+    for report in reports {
+        let out_of_sync = report.out_of_sync().count();
+        out.push_str(&format!(
+            "{} ({}): {} tasks, {} out of sync\n",
+            report.name,
+            report.path.display(),
+            report.tasks.len(),
+            out_of_sync
+        ));
+        if !report.has_manifest {
+            out.push_str(&format!(
+                "  No {} found. Run 'ratchet repo init --force' to create one\n",
+                MANIFEST_FILE
+            ));
+        }
+
+        let shown: Vec<&TaskStatus> = if detailed {
+            report.tasks.iter().collect()
+        } else {
+            report.out_of_sync().collect()
+        };
+        if !shown.is_empty() {
+            out.push_str(&format!(
+                "  {:<10} {:<24} {:<12} {}\n",
+                "STATE", "NAME", "VERSION", "PATH"
+            ));
+        }
+        for task in shown {
+            let state = match task.state {
+                TaskSyncState::InSync => "in sync",
+                TaskSyncState::Modified => "modified",
+                TaskSyncState::Untracked => "untracked",
+                TaskSyncState::Missing => "missing",
+            };
+            let version = match &task.manifest_version {
+                Some(recorded) => format!("{} (was {})", task.version, recorded),
+                None => task.version.clone(),
+            };
+            out.push_str(&format!(
+                "  {:<10} {:<24} {:<12} {}\n",
+                state, task.name, version, task.path
+            ));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_task(dir: &Path, name: &str, version: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join("metadata.json"),
+            serde_json::json!({ "name": name, "version": version, "label": name }).to_string(),
+        )
+        .unwrap();
+        fs::write(dir.join("main.js"), "function main(input) { return input; }").unwrap();
+    }
+
+    fn options() -> InitOptions {
+        InitOptions {
+            name: Some("Test Tasks".to_string()),
+            description: None,
+            version: "1.0.0".to_string(),
+            ratchet_version: ">=0.6.0".to_string(),
+            force: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_creates_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path().join("repo");
+
+        let manifest = init_repository(&repo, options()).await.unwrap();
+        assert_eq!(manifest.name, "Test Tasks");
+        assert!(manifest.tasks.is_empty());
+        assert_eq!(RepositoryManifest::load(&repo).unwrap(), manifest);
+
+        // Initializing again requires --force
+        assert!(init_repository(&repo, options()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_init_non_empty_directory_requires_force() {
+        let temp_dir = TempDir::new().unwrap();
+        write_task(&temp_dir.path().join("tasks/add"), "add", "1.0.0");
+
+        assert!(init_repository(temp_dir.path(), options()).await.is_err());
+
+        let manifest = init_repository(
+            temp_dir.path(),
+            InitOptions {
+                force: true,
+                ..options()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            manifest.tasks,
+            vec![ManifestTask {
+                name: "add".to_string(),
+                version: "1.0.0".to_string(),
+                path: "tasks/add".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_reports_out_of_sync_tasks() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        write_task(&repo.join("tasks/add"), "add", "1.0.0");
+        write_task(&repo.join("tasks/remove"), "remove", "1.0.0");
+        write_task(&repo.join("tasks/same"), "same", "1.0.0");
+        init_repository(
+            repo,
+            InitOptions {
+                force: true,
+                ..options()
+            },
+        )
+        .await
+        .unwrap();
+
+        write_task(&repo.join("tasks/add"), "add", "1.1.0");
+        fs::remove_dir_all(repo.join("tasks/remove")).unwrap();
+        write_task(&repo.join("tasks/new"), "new", "0.1.0");
+
+        let report = repository_status("local", repo).await.unwrap();
+        assert_eq!(report.name, "Test Tasks");
+        let states: Vec<(&str, TaskSyncState)> = report
+            .tasks
+            .iter()
+            .map(|task| (task.name.as_str(), task.state))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                ("add", TaskSyncState::Modified),
+                ("new", TaskSyncState::Untracked),
+                ("remove", TaskSyncState::Missing),
+                ("same", TaskSyncState::InSync),
+            ]
+        );
+        assert_eq!(report.tasks[0].manifest_version.as_deref(), Some("1.0.0"));
+        assert_eq!(report.out_of_sync().count(), 3);
+
+        // Refreshing the manifest brings everything back in sync
+        refresh_repository(repo, false).await.unwrap();
+        let report = repository_status("local", repo).await.unwrap();
+        assert_eq!(report.out_of_sync().count(), 0);
+
+        let table = render_status_table(&[report], true);
+        assert!(table.contains("Test Tasks"));
+        assert!(table.contains("in sync"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_regenerates_broken_manifest_with_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = temp_dir.path();
+        write_task(&repo.join("tasks/add"), "add", "1.0.0");
+
+        // Without a manifest there is nothing to refresh unless forced
+        assert!(refresh_repository(repo, false).await.is_err());
+        let manifest = refresh_repository(repo, true).await.unwrap();
+        assert_eq!(manifest.tasks.len(), 1);
+        assert_eq!(RepositoryManifest::load(repo).unwrap(), manifest);
+
+        fs::write(repo.join(MANIFEST_FILE), "tasks: [not, a, manifest").unwrap();
+        assert!(refresh_repository(repo, false).await.is_err());
+        let manifest = refresh_repository(repo, true).await.unwrap();
+        assert_eq!(manifest.version, "1.0.0");
+        assert_eq!(manifest.tasks[0].path, "tasks/add");
+    }
+}
@@ -16,10 +16,14 @@ use ratchet_http::{HttpClient, HttpManager};
 
 #[cfg(feature = "server")]
 use serde_json::{from_str, json, to_string_pretty, Value as JsonValue};
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, info, warn};
-use tracing_subscriber::EnvFilter;
+use ratchet_logging::TaskLogLevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
 
 #[cfg(feature = "core")]
 use ratchet_core::task::Task as CoreTask;
@@ -28,11 +32,14 @@ use ratchet_core::task::Task as CoreTask;
 use ratchet_runtime::{InMemoryTaskExecutor, TaskExecutor};
 
 #[cfg(feature = "javascript")]
-use ratchet_js::load_and_execute_task;
+use ratchet_js::{
+    load_and_execute_task, load_and_execute_task_with_clock, RecordingClock, ReplayClock, SystemClock, TimerClock,
+};
 
 mod cli;
 mod commands;
-use cli::{Cli, Commands, ConfigCommands, GenerateCommands, RepoCommands};
+mod output;
+use cli::{Cli, Commands, ConfigCommands, GenerateCommands, RepoCommands, TasksCommands};
 
 /// Convert ratchet-storage RepositoryFactory to ratchet_lib RepositoryFactory
 // Legacy repository factory function removed in 0.5.0 - use ratchet-storage directly
@@ -78,6 +85,23 @@ fn load_config(config_path: Option<&PathBuf>) -> Result<RatchetConfig> {
     Ok(config)
 }
 
+/// Storage connection settings for the configured database
+fn storage_database_config(
+    database: &ratchet_config::domains::database::DatabaseConfig,
+) -> ratchet_storage::seaorm::config::DatabaseConfig {
+    let sqlite = &database.database_specific.sqlite;
+    ratchet_storage::seaorm::config::DatabaseConfig {
+        url: database.url.clone(),
+        max_connections: database.max_connections,
+        connection_timeout: database.connection_timeout,
+        busy_retry: ratchet_storage::BusyRetryConfig {
+            max_retries: sqlite.busy_retries,
+            base_delay: std::time::Duration::from_millis(sqlite.busy_retry_base_delay_ms),
+            max_delay: std::time::Duration::from_millis(sqlite.busy_retry_max_delay_ms),
+        },
+    }
+}
+
 /// Show configuration in YAML format
 async fn show_config(config_path: Option<&PathBuf>) -> Result<()> {
     let config = load_config(config_path)?;
@@ -86,6 +110,64 @@ async fn show_config(config_path: Option<&PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Show the settings that differ from the defaults
+async fn diff_config(config_path: Option<&PathBuf>, format: &str) -> Result<()> {
+    // Unlike `load_config`, don't fill in a default server section, so only
+    // settings from the file and environment show up
+    let config = ConfigLoader::new()
+        .load(config_path)
+        .context("Failed to load configuration")?;
+    let changes = config
+        .diff_from_default()
+        .context("Failed to compare configuration with defaults")?;
+
+    match format.to_lowercase().as_str() {
+        "json" => println!("{}", to_string_pretty(&changes)?),
+        "yaml" | "yml" => print!("{}", serde_yaml::to_string(&changes)?),
+        "tree" if changes.is_empty() => println!("Configuration matches the defaults"),
+        "tree" => print!("{}", render_config_diff(&changes)),
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unsupported format: {}. Use tree, json, or yaml",
+                format
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Render configuration changes as a tree of their key paths
+fn render_config_diff(changes: &[ratchet_config::ConfigChange]) -> String {
+    fn describe(value: &JsonValue) -> String {
+        match value {
+            JsonValue::Null => "(unset)".to_string(),
+            value => value.to_string(),
+        }
+    }
+
+    let mut output = String::new();
+    let mut previous: Vec<&str> = Vec::new();
+    for change in changes {
+        let segments: Vec<&str> = change.path.split('.').collect();
+        let (leaf, parents) = segments.split_last().expect("split always yields a segment");
+
+        // Print only the sections not already opened by the previous change
+        let shared = previous.iter().zip(parents).take_while(|(a, b)| a == b).count();
+        for (depth, parent) in parents.iter().enumerate().skip(shared) {
+            output.push_str(&format!("{}{}\n", "  ".repeat(depth), parent));
+        }
+        output.push_str(&format!(
+            "{}{}: {} → {}\n",
+            "  ".repeat(parents.len()),
+            leaf,
+            describe(&change.old),
+            describe(&change.new)
+        ));
+        previous = parents.to_vec();
+    }
+    output
+}
+
 /// Get configuration value by path
 async fn get_config_value(config_path: Option<&PathBuf>, key_path: &str) -> Result<()> {
     let config = load_config(config_path)?;
@@ -187,6 +269,127 @@ async fn list_repositories(config_path: Option<&PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Initialize a task repository
+#[cfg(feature = "registry")]
+async fn init_repository_command(
+    directory: &Path,
+    name: Option<String>,
+    description: Option<String>,
+    version: String,
+    ratchet_version: String,
+    force: bool,
+) -> Result<()> {
+    use commands::repo::{init_repository, InitOptions, MANIFEST_FILE};
+
+    let options = InitOptions {
+        name,
+        description,
+        version,
+        ratchet_version,
+        force,
+    };
+    let manifest = init_repository(directory, options).await?;
+
+    println!("Initialized task repository '{}' in {:?}", manifest.name, directory);
+    println!("  Manifest: {}", MANIFEST_FILE);
+    println!("  Tasks indexed: {}", manifest.tasks.len());
+    Ok(())
+}
+
+#[cfg(not(feature = "registry"))]
+async fn init_repository_command(
+    _directory: &Path,
+    _name: Option<String>,
+    _description: Option<String>,
+    _version: String,
+    _ratchet_version: String,
+    _force: bool,
+) -> Result<()> {
+    Err(anyhow::anyhow!("Task repositories require the 'registry' feature"))
+}
+
+/// Re-index the tasks of a repository into its manifest
+#[cfg(feature = "registry")]
+async fn refresh_repository_command(directory: Option<&Path>, force: bool) -> Result<()> {
+    let directory = directory.unwrap_or(Path::new("."));
+    let manifest = commands::repo::refresh_repository(directory, force).await?;
+    println!(
+        "Refreshed task repository '{}': {} tasks indexed",
+        manifest.name,
+        manifest.tasks.len()
+    );
+    Ok(())
+}
+
+#[cfg(not(feature = "registry"))]
+async fn refresh_repository_command(_directory: Option<&Path>, _force: bool) -> Result<()> {
+    Err(anyhow::anyhow!("Task repositories require the 'registry' feature"))
+}
+
+/// Report tasks that are out of sync with their repository manifests
+///
+/// Checks the filesystem sources configured in `config_path`, or the current
+/// directory when none are configured.
+#[cfg(feature = "registry")]
+async fn repository_status_command(
+    config_path: Option<&PathBuf>,
+    repository: Option<&str>,
+    format: &str,
+    detailed: bool,
+) -> Result<()> {
+    use commands::repo::{render_status_table, repository_status};
+    use ratchet_config::domains::registry::RegistrySourceType;
+
+    let config = load_config(config_path)?;
+    let mut repositories: Vec<(String, PathBuf)> = config
+        .registry
+        .iter()
+        .flat_map(|registry| registry.sources.iter())
+        .filter(|source| source.source_type == RegistrySourceType::Filesystem)
+        .map(|source| {
+            let path = source.uri.strip_prefix("file://").unwrap_or(&source.uri);
+            (source.name.clone(), PathBuf::from(path))
+        })
+        .collect();
+    if repositories.is_empty() {
+        repositories.push(("local".to_string(), PathBuf::from(".")));
+    }
+    if let Some(name) = repository {
+        repositories.retain(|(source, _)| source == name);
+        if repositories.is_empty() {
+            return Err(anyhow::anyhow!("No filesystem repository named '{}' is configured", name));
+        }
+    }
+
+    let mut reports = Vec::new();
+    for (name, path) in &repositories {
+        reports.push(repository_status(name, path).await?);
+    }
+
+    match format.to_lowercase().as_str() {
+        "json" => println!("{}", to_string_pretty(&reports)?),
+        "yaml" | "yml" => print!("{}", serde_yaml::to_string(&reports)?),
+        "table" => print!("{}", render_status_table(&reports, detailed)),
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unsupported format: {}. Use table, json, or yaml",
+                format
+            ))
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "registry"))]
+async fn repository_status_command(
+    _config_path: Option<&PathBuf>,
+    _repository: Option<&str>,
+    _format: &str,
+    _detailed: bool,
+) -> Result<()> {
+    Err(anyhow::anyhow!("Task repositories require the 'registry' feature"))
+}
+
 /// Synchronize repositories to database
 async fn sync_repositories(config_path: Option<&PathBuf>) -> Result<()> {
     use ratchet_storage::seaorm::connection::DatabaseConnection;
@@ -204,11 +407,7 @@ async fn sync_repositories(config_path: Option<&PathBuf>) -> Result<()> {
 
     // Create database connection using SeaORM
     info!("Connecting to database at: {}", server_config.database.url);
-    let storage_db_config = ratchet_storage::seaorm::config::DatabaseConfig {
-        url: server_config.database.url.clone(),
-        max_connections: server_config.database.max_connections,
-        connection_timeout: server_config.database.connection_timeout,
-    };
+    let storage_db_config = storage_database_config(&server_config.database);
 
     let connection = DatabaseConnection::new(storage_db_config)
         .await
@@ -233,6 +432,52 @@ async fn sync_repositories(config_path: Option<&PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Re-validate all stored tasks, reporting the ones that no longer pass
+#[cfg(feature = "server")]
+async fn revalidate_tasks_command(config_path: Option<&PathBuf>, concurrency: usize) -> Result<()> {
+    use ratchet_server::bridges::BridgeTaskValidator;
+    use ratchet_server::services::DirectTaskRepository;
+    use ratchet_storage::seaorm::connection::DatabaseConnection;
+    use ratchet_storage::seaorm::repositories::RepositoryFactory;
+
+    let config = load_config(config_path)?;
+    let server_config = config.server.as_ref().ok_or_else(|| {
+        anyhow::anyhow!("No server configuration found. Database connection required for task re-validation.")
+    })?;
+
+    info!("Connecting to database at: {}", server_config.database.url);
+    let storage_db_config = storage_database_config(&server_config.database);
+    let connection = DatabaseConnection::new(storage_db_config)
+        .await
+        .context("Failed to connect to database")?;
+    let factory = RepositoryFactory::new(connection);
+    let task_repo = DirectTaskRepository::new(Arc::new(factory.task_repository()));
+
+    let report = ratchet_storage::revalidate_tasks(&task_repo, &BridgeTaskValidator::new(), concurrency)
+        .await
+        .context("Failed to re-validate tasks")?;
+
+    println!("Re-validated {} tasks: {} passed, {} failed", report.total, report.validated, report.failures.len());
+    for failure in &report.failures {
+        println!("  ✗ {} ({}) [{}]", failure.name, failure.version, failure.task_id);
+        for error in &failure.errors {
+            println!("      {}", error);
+        }
+    }
+
+    if !report.all_valid() {
+        return Err(anyhow::anyhow!("{} task(s) failed re-validation", report.failures.len()));
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "server"))]
+async fn revalidate_tasks_command(_config_path: Option<&PathBuf>, _concurrency: usize) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "Server feature not enabled. Please compile with --features server"
+    ))
+}
+
 /// Start the MCP (Model Context Protocol) server (legacy wrapper function)
 #[cfg(feature = "mcp-server")]
 #[allow(dead_code)]
@@ -251,7 +496,7 @@ async fn mcp_serve_command(_config_path: Option<&PathBuf>, _transport: &str, _ho
 /// Start the MCP (Model Context Protocol) server with provided config
 #[cfg(feature = "mcp-server")]
 async fn mcp_serve_command_with_config(config: RatchetConfig, transport: &str, host: &str, port: u16) -> Result<()> {
-    use ratchet_execution::{ExecutionBridge, ProcessExecutorConfig};
+    use ratchet_execution::ExecutionConfigAdapter;
     use ratchet_mcp::server::adapter::RatchetMcpAdapterBuilder;
     use ratchet_mcp::{config::McpConfig, config::SimpleTransportType, McpServer};
     use ratchet_storage::seaorm::connection::DatabaseConnection;
@@ -275,18 +520,14 @@ async fn mcp_serve_command_with_config(config: RatchetConfig, transport: &str, h
         limits: Default::default(),
         timeouts: Default::default(),
         tools: Default::default(),
+        progress: config.mcp.as_ref().map(|mcp| mcp.progress.clone()).unwrap_or_default(),
     };
 
     // Create database connection if configured through server config
     let repositories = if let Some(server_config) = &config.server {
         info!("Connecting to database for MCP server");
 
-        // Convert ratchet-config DatabaseConfig to ratchet-storage DatabaseConfig
-        let storage_db_config = ratchet_storage::seaorm::config::DatabaseConfig {
-            url: server_config.database.url.clone(),
-            max_connections: server_config.database.max_connections,
-            connection_timeout: server_config.database.connection_timeout,
-        };
+        let storage_db_config = storage_database_config(&server_config.database);
 
         let connection = DatabaseConnection::new(storage_db_config)
             .await
@@ -298,14 +539,14 @@ async fn mcp_serve_command_with_config(config: RatchetConfig, transport: &str, h
         None
     };
 
-    // Create execution bridge with default configuration
-    let execution_config = ProcessExecutorConfig {
-        worker_count: 4,
-        task_timeout_seconds: 300,
-        restart_on_crash: true,
-        max_restart_attempts: 3,
-    };
-    let execution_bridge = Arc::new(ExecutionBridge::new(execution_config));
+    // Executors follow the execution settings, as they do in the server; plugins
+    // loaded here hook into every execution
+    let plugin_manager = load_mcp_plugins(&config).await;
+    let execution_bridge = Arc::new(
+        ExecutionConfigAdapter::from_execution_config(&config.execution)
+            .with_hooks(plugin_manager.hooks().clone(), config.clone()),
+    );
+    execution_bridge.inner().set_http_config(config.http.clone().into()).await;
 
     // Create MCP adapter with available components
     let adapter_builder = RatchetMcpAdapterBuilder::new().with_bridge_executor(execution_bridge);
@@ -327,9 +568,49 @@ async fn mcp_serve_command_with_config(config: RatchetConfig, transport: &str, h
         .context("Failed to create MCP server")?;
 
     info!("Starting MCP server stdio transport");
-    mcp_server.run_stdio().await.context("MCP server failed to run")?;
+    let result = mcp_server.run_stdio().await.context("MCP server failed to run");
 
-    Ok(())
+    if let Err(e) = plugin_manager.shutdown().await {
+        warn!("Failed to shut down plugins: {}", e);
+    }
+
+    result
+}
+
+/// Load the plugin libraries in the configured plugins directory
+///
+/// The returned manager keeps the libraries mapped; plugins that fail to load
+/// are logged and skipped.
+#[cfg(feature = "mcp-server")]
+async fn load_mcp_plugins(config: &RatchetConfig) -> Arc<ratchet_plugin::PluginManager> {
+    let manager = Arc::new(
+        ratchet_plugin::PluginManagerBuilder::new()
+            .with_system_config(config.clone())
+            .with_auto_discover(false)
+            .with_auto_load(false)
+            .build(),
+    );
+
+    let Some(directory) = &config.plugins.directory else {
+        return manager;
+    };
+    let libraries = match ratchet_plugin::discovery::plugin_libraries(Path::new(directory)).await {
+        Ok(libraries) => libraries,
+        Err(e) => {
+            warn!("Failed to read plugins directory {}: {}", directory, e);
+            return manager;
+        }
+    };
+
+    for path in libraries {
+        let source = path.to_string_lossy();
+        match manager.load_plugin_from_source(&source, JsonValue::Null).await {
+            Ok(plugin_id) => info!("Loaded plugin '{}' from {}", plugin_id, source),
+            Err(e) => warn!("Failed to load plugin {}: {}", source, e),
+        }
+    }
+
+    manager
 }
 
 #[cfg(not(feature = "mcp-server"))]
@@ -415,15 +696,46 @@ async fn generate_completions(shell: clap_complete::Shell, output_dir: Option<&P
     Ok(())
 }
 
+/// Scaffold a new task directory
+fn generate_task_command(
+    path: PathBuf,
+    label: Option<String>,
+    description: Option<String>,
+    version: Option<String>,
+) -> Result<ratchet_cli_tools::GeneratedTaskInfo> {
+    use ratchet_cli_tools::{generate_task, TaskGenerationConfig};
+
+    let mut config = TaskGenerationConfig::new(path);
+    if let Some(label) = label {
+        config = config.with_label(label);
+    }
+    if let Some(description) = description {
+        config = config.with_description(description);
+    }
+    if let Some(version) = version {
+        config = config.with_version(version);
+    }
+
+    let generated = generate_task(config).context("Failed to generate task")?;
+
+    info!(
+        "✅ Generated task '{}' ({}) at {:?}",
+        generated.label, generated.uuid, generated.path
+    );
+    for file in &generated.files_created {
+        info!("  📄 {}", file);
+    }
+    Ok(generated)
+}
+
 /// Generate configuration file template
-async fn generate_config(output_path: Option<&PathBuf>, format: &str) -> Result<()> {
-    let config = RatchetConfig::default();
+async fn generate_config(output_path: Option<&PathBuf>, config_type: &str, format: &str) -> Result<()> {
+    let config = RatchetConfig::preset(config_type)?;
 
     let content = match format.to_lowercase().as_str() {
         "yaml" | "yml" => serde_yaml::to_string(&config)?,
         "json" => serde_json::to_string_pretty(&config)?,
-        "toml" => serde_yaml::to_string(&config) // TOML support would need toml crate
-            .context("Failed to serialize config to TOML")?,
+        "toml" => toml::to_string_pretty(&config).context("Failed to serialize config to TOML")?,
         _ => {
             return Err(anyhow::anyhow!(
                 "Unsupported format: {}. Use yaml, json, or toml",
@@ -452,6 +764,7 @@ async fn execute_task(
     task_path: &str,
     input_data: Option<&str>,
     output_format: &str,
+    color: bool,
 ) -> Result<()> {
     info!("Executing task: {}", task_path);
 
@@ -469,7 +782,9 @@ async fn execute_task(
     let task: CoreTask = from_str(&task).context("Failed to parse task definition")?;
 
     // Execute the task
+    let started = std::time::Instant::now();
     let result = executor.execute(&task, &input).await.context("Task execution failed")?;
+    let duration = started.elapsed();
 
     // Format and display output
     match output_format.to_lowercase().as_str() {
@@ -480,8 +795,7 @@ async fn execute_task(
             println!("{}", serde_yaml::to_string(&result)?);
         }
         "pretty" => {
-            println!("Task execution completed:");
-            println!("  Result: {}", to_string_pretty(&result)?);
+            print!("{}", output::render_task_result(task_path, &result, duration, color));
         }
         _ => {
             return Err(anyhow::anyhow!(
@@ -500,6 +814,7 @@ async fn execute_task(
     _task_path: &str,
     _input_data: Option<&str>,
     _output_format: &str,
+    _color: bool,
 ) -> Result<()> {
     Err(anyhow::anyhow!(
         "Task execution requires both 'runtime' and 'core' features to be enabled"
@@ -589,6 +904,13 @@ async fn validate_task_directory(task_dir: &Path, fix: bool) -> Result<()> {
         }
     }
 
+    // Compile main.js and its imports and check the schemas, without running the task
+    let compile_errors = if metadata_path.exists() && main_js_path.exists() {
+        compile_task_directory(task_dir)
+    } else {
+        Vec::new()
+    };
+
     // Report results
     if !issues.is_empty() {
         warn!("Found {} validation issue(s):", issues.len());
@@ -597,6 +919,17 @@ async fn validate_task_directory(task_dir: &Path, fix: bool) -> Result<()> {
         }
     }
 
+    if !compile_errors.is_empty() {
+        warn!("Found {} problem(s) compiling the task:", compile_errors.len());
+        for error in &compile_errors {
+            warn!("  ❌ {}", error);
+        }
+        return Err(anyhow::anyhow!(
+            "Task validation failed with {} compilation or schema errors",
+            compile_errors.len()
+        ));
+    }
+
     if !fixed_issues.is_empty() {
         info!("Fixed {} issue(s):", fixed_issues.len());
         for fix in &fixed_issues {
@@ -615,6 +948,22 @@ async fn validate_task_directory(task_dir: &Path, fix: bool) -> Result<()> {
     }
 }
 
+/// Compile a task directory without executing it, returning the problems found
+#[cfg(feature = "javascript")]
+fn compile_task_directory(task_dir: &Path) -> Vec<String> {
+    let result = ratchet_js::validate_task_directory(task_dir);
+    for warning in &result.warnings {
+        warn!("  ⚠️ {}", warning);
+    }
+    result.errors.iter().map(ToString::to_string).collect()
+}
+
+#[cfg(not(feature = "javascript"))]
+fn compile_task_directory(task_dir: &Path) -> Vec<String> {
+    debug!("JavaScript support is disabled, skipping compilation of {:?}", task_dir);
+    Vec::new()
+}
+
 /// Validate a single task file (legacy format)
 async fn validate_task_file(task_path: &Path, _fix: bool) -> Result<()> {
     info!("Validating task file: {:?}", task_path);
@@ -912,22 +1261,33 @@ fn validate_schema_file(schema_path: &Path, schema_type: &str) -> Result<()> {
 }
 
 /// Validate a configuration file
-async fn validate_config_file(config_path: &Path) -> Result<()> {
+async fn validate_config_file(config_path: &Path, strict: bool) -> Result<()> {
     info!("Validating configuration file: {:?}", config_path);
 
-    let content = std::fs::read_to_string(config_path).context("Failed to read configuration file")?;
-
-    // Try to parse as YAML first, then JSON
-    let _config: serde_json::Value = if config_path.extension().and_then(|s| s.to_str()) == Some("yaml")
-        || config_path.extension().and_then(|s| s.to_str()) == Some("yml")
-    {
-        serde_yaml::from_str(&content).context("Configuration file is not valid YAML")?
-    } else {
-        serde_json::from_str(&content).context("Configuration file is not valid JSON")?
-    };
-
-    info!("✅ Configuration file is valid");
-    Ok(())
+    // Load the file the way the server would, so includes, interpolation and
+    // domain validation are all checked
+    match ConfigLoader::new().strict(strict).from_file_with_warnings(config_path) {
+        Ok((_, warnings)) => {
+            for warning in &warnings {
+                println!("⚠️  {}", warning);
+            }
+            println!("✅ Configuration file is valid");
+            Ok(())
+        }
+        Err(ratchet_config::ConfigError::UnknownKeys(unknown)) => {
+            for key in &unknown {
+                println!("❌ {}", key);
+            }
+            Err(anyhow::anyhow!(
+                "Configuration file has {} unknown key(s)",
+                unknown.len()
+            ))
+        }
+        Err(e) => {
+            println!("❌ {}", e);
+            Err(anyhow::anyhow!("Configuration file {:?} is invalid", config_path))
+        }
+    }
 }
 
 /// List available tasks
@@ -946,11 +1306,7 @@ async fn list_tasks(config_path: Option<&PathBuf>, format: &str) -> Result<()> {
     })?;
 
     // Create database connection using SeaORM
-    let storage_db_config = ratchet_storage::seaorm::config::DatabaseConfig {
-        url: server_config.database.url.clone(),
-        max_connections: server_config.database.max_connections,
-        connection_timeout: server_config.database.connection_timeout,
-    };
+    let storage_db_config = storage_database_config(&server_config.database);
 
     let connection = DatabaseConnection::new(storage_db_config)
         .await
@@ -1067,6 +1423,7 @@ async fn test_database_connection(database_url: &str) -> Result<()> {
         url: database_url.to_string(),
         max_connections: 1,
         connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
     };
 
     let connection = DatabaseConnection::new(storage_db_config)
@@ -1135,6 +1492,10 @@ async fn test_http_request(_url: &str, _method: &str, _headers: Option<&str>, _b
     ))
 }
 
+/// File in a recording directory holding the task's timer clock readings, in nanoseconds
+#[cfg(feature = "javascript")]
+const RECORDED_TIMERS_FILE: &str = "timers.json";
+
 /// JavaScript execution functions  
 #[cfg(feature = "javascript")]
 async fn execute_js_task(
@@ -1142,6 +1503,8 @@ async fn execute_js_task(
     script_path: &str,
     input_data: Option<&str>,
     output_format: &str,
+    color: bool,
+    clock: Option<Arc<dyn TimerClock>>,
 ) -> Result<()> {
     info!("Executing JavaScript task: {}", script_path);
 
@@ -1152,9 +1515,13 @@ async fn execute_js_task(
     };
 
     // Execute the task directly from filesystem path
-    let result = load_and_execute_task(script_path, input)
-        .await
-        .map_err(|e| anyhow::anyhow!("JavaScript task execution failed: {}", e))?;
+    let started = std::time::Instant::now();
+    let result = match clock {
+        Some(clock) => load_and_execute_task_with_clock(script_path, input, clock).await,
+        None => load_and_execute_task(script_path, input).await,
+    }
+    .map_err(|e| anyhow::anyhow!("JavaScript task execution failed: {}", e))?;
+    let duration = started.elapsed();
 
     // Format and display output
     match output_format.to_lowercase().as_str() {
@@ -1165,8 +1532,7 @@ async fn execute_js_task(
             println!("{}", serde_yaml::to_string(&result)?);
         }
         "pretty" => {
-            println!("JavaScript task execution completed:");
-            println!("  Result: {}", to_string_pretty(&result)?);
+            print!("{}", output::render_task_result(script_path, &result, duration, color));
         }
         _ => {
             return Err(anyhow::anyhow!(
@@ -1185,22 +1551,125 @@ async fn execute_js_task(
     _script_path: &str,
     _input_data: Option<&str>,
     _output_format: &str,
+    _color: bool,
+    _clock: Option<std::convert::Infallible>,
+) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "JavaScript feature not enabled. Please compile with --features javascript"
+    ))
+}
+
+/// Execute a JavaScript task, recording its input, HTTP requests and timer clock
+/// readings to a timestamped session directory under `record_dir`
+#[cfg(feature = "javascript")]
+async fn record_js_task(
+    script_path: &str,
+    input_data: Option<&str>,
+    output_format: &str,
+    color: bool,
+    record_dir: &Path,
+) -> Result<()> {
+    let session_dir = record_dir.join(format!(
+        "ratchet_session_{}",
+        chrono::Utc::now().format("%Y%m%d_%H%M%S")
+    ));
+    std::fs::create_dir_all(&session_dir).context("Failed to create recording directory")?;
+    info!("Recording execution to {:?}", session_dir);
+
+    ratchet_cli_tools::set_recording_dir(session_dir.clone())?;
+    if let Some(data) = input_data {
+        std::fs::write(session_dir.join("input.json"), data).context("Failed to record input")?;
+    }
+
+    let clock = Arc::new(RecordingClock::new(Arc::new(SystemClock::new())));
+    let result = execute_js_task(None, script_path, input_data, output_format, color, Some(clock.clone())).await;
+
+    ratchet_cli_tools::finalize_recording()?;
+    let readings: Vec<u64> = clock
+        .readings()
+        .iter()
+        .map(|reading| reading.as_nanos() as u64)
+        .collect();
+    std::fs::write(
+        session_dir.join(RECORDED_TIMERS_FILE),
+        serde_json::to_string(&readings)?,
+    )
+    .context("Failed to record timers")?;
+
+    result
+}
+
+#[cfg(not(feature = "javascript"))]
+async fn record_js_task(
+    _script_path: &str,
+    _input_data: Option<&str>,
+    _output_format: &str,
+    _color: bool,
+    _record_dir: &Path,
 ) -> Result<()> {
     Err(anyhow::anyhow!(
         "JavaScript feature not enabled. Please compile with --features javascript"
     ))
 }
 
+/// Execute a JavaScript task again with the input and timer clock readings of a recording
+#[cfg(feature = "javascript")]
+async fn replay_js_task(script_path: &str, recording: Option<&Path>, color: bool) -> Result<()> {
+    let Some(recording) = recording else {
+        return execute_js_task(None, script_path, None, "json", color, None).await;
+    };
+    info!("Replaying recording {:?}", recording);
+
+    let input_file = recording.join("input.json");
+    let input_data = if input_file.exists() {
+        Some(std::fs::read_to_string(&input_file).context("Failed to read recorded input")?)
+    } else {
+        None
+    };
+
+    let timers_file = recording.join(RECORDED_TIMERS_FILE);
+    let clock: Option<Arc<dyn TimerClock>> = if timers_file.exists() {
+        let readings: Vec<u64> =
+            from_str(&std::fs::read_to_string(&timers_file).context("Failed to read recorded timers")?)
+                .context("Failed to parse recorded timers")?;
+        Some(Arc::new(ReplayClock::new(
+            readings.into_iter().map(std::time::Duration::from_nanos).collect(),
+        )))
+    } else {
+        warn!(
+            "Recording has no {}, timers will use wall-clock time",
+            RECORDED_TIMERS_FILE
+        );
+        None
+    };
+
+    execute_js_task(None, script_path, input_data.as_deref(), "json", color, clock).await
+}
+
+#[cfg(not(feature = "javascript"))]
+async fn replay_js_task(_script_path: &str, _recording: Option<&Path>, _color: bool) -> Result<()> {
+    Err(anyhow::anyhow!(
+        "JavaScript feature not enabled. Please compile with --features javascript"
+    ))
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize logging
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+    // Initialize logging; task executions may override the level per task
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(TaskLogLevelFilter::new(env_filter)))
         .init();
 
     // Parse command line arguments
     let cli = Cli::parse();
 
+    // Human-oriented output defaults on for terminals only, keeping piped output plain
+    let is_tty = std::io::stdout().is_terminal();
+    cli.color.apply(is_tty);
+    let color = cli.color.enabled(is_tty);
+    let default_format = if is_tty { "pretty" } else { "json" };
+
     // Handle subcommands
     match cli.command {
         Some(Commands::Config { config_cmd }) => match config_cmd {
@@ -1214,34 +1683,48 @@ async fn main() -> Result<()> {
             ConfigCommands::Generate {
                 config_type,
                 output,
-                force: _,
+                format,
+                force,
             } => {
-                generate_config(Some(&output), &config_type).await?;
+                let format = format.unwrap_or_else(|| match output.extension().and_then(|ext| ext.to_str()) {
+                    Some(ext @ ("json" | "toml" | "yml")) => ext.to_string(),
+                    _ => "yaml".to_string(),
+                });
+                if output.exists() && !force {
+                    return Err(anyhow::anyhow!(
+                        "{:?} already exists. Use --force to overwrite it",
+                        output
+                    ));
+                }
+                generate_config(Some(&output), &config_type, &format).await?;
+            }
+            ConfigCommands::Diff { config_file, format } => {
+                diff_config(config_file.as_ref(), &format).await?;
             }
-            ConfigCommands::Validate { config_file } => {
-                validate_config_file(&config_file).await?;
+            ConfigCommands::Validate { config_file, strict } => {
+                validate_config_file(&config_file, strict).await?;
             }
         },
         Some(Commands::Repo { repo_cmd }) => match repo_cmd {
             RepoCommands::Init {
                 directory,
-                name: _,
-                description: _,
-                version: _,
-                ratchet_version: _,
-                force: _,
+                name,
+                description,
+                version,
+                ratchet_version,
+                force,
             } => {
-                info!("Repository initialization not yet implemented for: {:?}", directory);
+                init_repository_command(&directory, name, description, version, ratchet_version, force).await?;
             }
-            RepoCommands::RefreshMetadata { directory: _, force: _ } => {
-                info!("Repository refresh metadata not yet implemented");
+            RepoCommands::RefreshMetadata { directory, force } => {
+                refresh_repository_command(directory.as_deref(), force).await?;
             }
             RepoCommands::Status {
-                detailed: _,
-                repository: _,
-                format: _,
+                detailed,
+                repository,
+                format,
             } => {
-                info!("Repository status not yet implemented");
+                repository_status_command(cli.config.as_ref(), repository.as_deref(), &format, detailed).await?;
             }
             RepoCommands::Verify {
                 repository: _,
@@ -1253,14 +1736,19 @@ async fn main() -> Result<()> {
                 info!("Repository verify not yet implemented");
             }
         },
+        Some(Commands::Tasks { tasks_cmd }) => match tasks_cmd {
+            TasksCommands::Revalidate { config, concurrency } => {
+                revalidate_tasks_command(config.as_ref(), concurrency).await?;
+            }
+        },
         Some(Commands::Generate { generate_cmd }) => match generate_cmd {
             GenerateCommands::Task {
                 path,
-                label: _,
-                description: _,
-                version: _,
+                label,
+                description,
+                version,
             } => {
-                info!("Task generation not yet implemented for: {:?}", path);
+                generate_task_command(path, label, description, version)?;
             }
             GenerateCommands::McpserversJson {
                 name: _,
@@ -1300,18 +1788,24 @@ async fn main() -> Result<()> {
         Some(Commands::RunOnce {
             from_fs,
             input_json,
-            record: _,
+            record,
+            format,
         }) => {
-            execute_js_task(None, &from_fs, input_json.as_deref(), "json").await?;
+            let format = format.as_deref().unwrap_or(default_format);
+            match record {
+                Some(record_dir) => record_js_task(&from_fs, input_json.as_deref(), format, color, &record_dir).await?,
+                None => execute_js_task(None, &from_fs, input_json.as_deref(), format, color, None).await?,
+            }
         }
         Some(Commands::Validate { from_fs, fix }) => {
             validate_task(&from_fs, fix).await?;
         }
-        Some(Commands::Test { from_fs }) => {
-            execute_js_task(None, &from_fs, None, "json").await?;
+        Some(Commands::Test { from_fs, format }) => {
+            let format = format.as_deref().unwrap_or(default_format);
+            execute_js_task(None, &from_fs, None, format, color, None).await?;
         }
-        Some(Commands::Replay { from_fs, recording: _ }) => {
-            execute_js_task(None, &from_fs, None, "json").await?;
+        Some(Commands::Replay { from_fs, recording }) => {
+            replay_js_task(&from_fs, recording.as_deref(), color).await?;
         }
         Some(Commands::Console {
             config,
@@ -1367,3 +1861,95 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_generate_config_toml_round_trips() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("ratchet.toml");
+
+        generate_config(Some(&config_path), "dev", "toml").await.unwrap();
+
+        let content = std::fs::read_to_string(&config_path).unwrap();
+        assert!(toml::from_str::<toml::Table>(&content).is_ok());
+
+        let loaded = ConfigLoader::new().from_file(&config_path).unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(RatchetConfig::preset("dev").unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_render_config_diff_groups_sections() {
+        let mut config = RatchetConfig::default();
+        config.http.timeout = std::time::Duration::from_secs(90);
+        config.http.user_agent = "Prod Agent".to_string();
+        config.execution.max_concurrent_tasks = 12;
+
+        let rendered = render_config_diff(&config.diff_from_default().unwrap());
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "execution");
+        assert!(lines[1].starts_with("  max_concurrent_tasks: "));
+        assert!(lines[1].ends_with(" → 12"));
+        assert_eq!(lines[2], "http");
+        assert_eq!(lines[3], "  timeout: 30 → 90");
+        assert!(lines[4].starts_with("  user_agent: "));
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_validate_config_file_strict_rejects_unknown_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("ratchet.yaml");
+        std::fs::write(&config_path, "http:\n  timeout: 30\n  timout: 60\n").unwrap();
+
+        // Unknown keys only warn by default
+        validate_config_file(&config_path, false).await.unwrap();
+        assert!(validate_config_file(&config_path, true).await.is_err());
+
+        std::fs::write(&config_path, "http:\n  timeout: soon\n").unwrap();
+        assert!(validate_config_file(&config_path, false).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_generate_task_passes_validation() {
+        let temp_dir = TempDir::new().unwrap();
+        let task_path = temp_dir.path().join("generated-task");
+
+        let generated = generate_task_command(
+            task_path.clone(),
+            Some("Greeter".to_string()),
+            Some("Greets its input".to_string()),
+            Some("2.1.0".to_string()),
+        )
+        .unwrap();
+        assert_eq!(generated.path, task_path);
+
+        let metadata: JsonValue = from_str(&std::fs::read_to_string(task_path.join("metadata.json")).unwrap()).unwrap();
+        assert_eq!(metadata["label"], "Greeter");
+        assert_eq!(metadata["description"], "Greets its input");
+        assert_eq!(metadata["version"], "2.1.0");
+
+        validate_task(task_path.to_str().unwrap(), false).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_generate_task_uses_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let task_path = temp_dir.path().join("default-task");
+
+        let generated = generate_task_command(task_path.clone(), None, None, None).unwrap();
+        assert_eq!(generated.label, "My Task");
+        assert_eq!(generated.version, "1.0.0");
+
+        validate_task(task_path.to_str().unwrap(), false).await.unwrap();
+
+        // An existing directory is not overwritten
+        assert!(generate_task_command(task_path, None, None, None).is_err());
+    }
+}
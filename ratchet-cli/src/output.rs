@@ -0,0 +1,139 @@
+//! Human-oriented rendering of task execution results
+
+use clap::ValueEnum;
+use colored::Colorize;
+use serde_json::Value as JsonValue;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// When to colorize terminal output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Colorize when writing to a terminal
+    #[default]
+    Auto,
+    /// Always colorize
+    Always,
+    /// Never colorize
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether output should be colorized, given whether stdout is a terminal
+    pub fn enabled(self, is_tty: bool) -> bool {
+        match self {
+            ColorChoice::Auto => is_tty,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+
+    /// Apply the choice to the `colored` crate, which otherwise decides on its own
+    pub fn apply(self, is_tty: bool) {
+        colored::control::set_override(self.enabled(is_tty));
+    }
+}
+
+/// Render a task result as a status summary followed by indented JSON
+///
+/// Without color the output is plain text, safe to pipe or redirect.
+pub fn render_task_result(task: &str, result: &JsonValue, duration: Duration, color: bool) -> String {
+    let mut out = String::new();
+    let status = paint("✓ completed", color, |s| s.bright_green().bold().to_string());
+    let _ = writeln!(out, "{} {}", paint("Task:", color, |s| s.bold().to_string()), task);
+    let _ = writeln!(out, "{} {}", paint("Status:", color, |s| s.bold().to_string()), status);
+    let _ = writeln!(
+        out,
+        "{} {}",
+        paint("Duration:", color, |s| s.bold().to_string()),
+        format_duration(duration)
+    );
+    let _ = writeln!(out, "{}", paint("Result:", color, |s| s.bold().to_string()));
+    out.push_str("  ");
+    write_json(&mut out, result, 1, color);
+    out.push('\n');
+    out
+}
+
+/// Durations under a second in milliseconds, longer ones in seconds
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+fn paint(text: &str, color: bool, style: impl Fn(&str) -> String) -> String {
+    if color {
+        style(text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Write `value` as JSON indented at `depth`, highlighting keys and scalars by type
+fn write_json(out: &mut String, value: &JsonValue, depth: usize, color: bool) {
+    let inner = "  ".repeat(depth + 1);
+    match value {
+        JsonValue::Object(map) if !map.is_empty() => {
+            out.push_str("{\n");
+            for (index, (key, value)) in map.iter().enumerate() {
+                let key = JsonValue::String(key.clone()).to_string();
+                let _ = write!(
+                    out,
+                    "{}{}: ",
+                    inner,
+                    paint(&key, color, |s| s.bright_blue().to_string())
+                );
+                write_json(out, value, depth + 1, color);
+                out.push_str(if index + 1 < map.len() { ",\n" } else { "\n" });
+            }
+            let _ = write!(out, "{}}}", "  ".repeat(depth));
+        }
+        JsonValue::Array(items) if !items.is_empty() => {
+            out.push_str("[\n");
+            for (index, item) in items.iter().enumerate() {
+                out.push_str(&inner);
+                write_json(out, item, depth + 1, color);
+                out.push_str(if index + 1 < items.len() { ",\n" } else { "\n" });
+            }
+            let _ = write!(out, "{}]", "  ".repeat(depth));
+        }
+        JsonValue::String(_) => out.push_str(&paint(&value.to_string(), color, |s| s.green().to_string())),
+        JsonValue::Number(_) => out.push_str(&paint(&value.to_string(), color, |s| s.yellow().to_string())),
+        JsonValue::Bool(_) => out.push_str(&paint(&value.to_string(), color, |s| s.magenta().to_string())),
+        JsonValue::Null => out.push_str(&paint("null", color, |s| s.dimmed().to_string())),
+        // Empty objects and arrays
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_non_tty_output_is_plain() {
+        let result = json!({"sum": 15, "label": "total", "ok": true, "items": [1, null], "meta": {}});
+        let color = ColorChoice::Auto.enabled(false);
+        let rendered = render_task_result("addition", &result, Duration::from_millis(42), color);
+
+        assert!(!rendered.contains('\u{1b}'), "unexpected ANSI codes in {:?}", rendered);
+        assert!(rendered.contains("Status: ✓ completed"));
+        assert!(rendered.contains("Duration: 42ms"));
+
+        // The result block is valid JSON once the labels are stripped
+        let json_block = rendered.split_once("Result:\n").unwrap().1;
+        assert_eq!(serde_json::from_str::<JsonValue>(json_block).unwrap(), result);
+    }
+
+    #[test]
+    fn test_color_choice() {
+        assert!(ColorChoice::Auto.enabled(true));
+        assert!(!ColorChoice::Auto.enabled(false));
+        assert!(ColorChoice::Always.enabled(false));
+        assert!(!ColorChoice::Never.enabled(true));
+    }
+}
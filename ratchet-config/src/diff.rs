@@ -0,0 +1,79 @@
+//! Differences between configurations
+//!
+//! Used to show which settings a loaded configuration overrides compared to the
+//! defaults. Both configurations are serialized to JSON and compared setting by
+//! setting; sections that are unset on one side compare as `null` leaves, and
+//! lists are compared as a whole.
+
+use crate::domains::RatchetConfig;
+use crate::error::ConfigResult;
+use serde::Serialize;
+use serde_json::{Map, Value as JsonValue};
+
+/// A setting whose value differs between two configurations
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigChange {
+    /// Dotted path of the setting, e.g. `server.database.url`
+    pub path: String,
+    /// Value in the base configuration, `null` when unset
+    pub old: JsonValue,
+    /// Value in the compared configuration, `null` when unset
+    pub new: JsonValue,
+}
+
+impl RatchetConfig {
+    /// Settings in this configuration that differ from [`RatchetConfig::default`]
+    pub fn diff_from_default(&self) -> ConfigResult<Vec<ConfigChange>> {
+        diff_configs(&RatchetConfig::default(), self)
+    }
+}
+
+/// Settings that differ between `base` and `current`, ordered by path
+pub fn diff_configs(base: &RatchetConfig, current: &RatchetConfig) -> ConfigResult<Vec<ConfigChange>> {
+    let mut changes = Vec::new();
+    diff_values(
+        &serde_json::to_value(base)?,
+        &serde_json::to_value(current)?,
+        &mut String::new(),
+        &mut changes,
+    );
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(changes)
+}
+
+fn diff_values(old: &JsonValue, new: &JsonValue, path: &mut String, changes: &mut Vec<ConfigChange>) {
+    if old == new {
+        return;
+    }
+
+    let empty = Map::new();
+    let (old_fields, new_fields) = match (old, new) {
+        (JsonValue::Object(old), JsonValue::Object(new)) => (old, new),
+        (JsonValue::Object(old), JsonValue::Null) => (old, &empty),
+        (JsonValue::Null, JsonValue::Object(new)) => (&empty, new),
+        _ => {
+            changes.push(ConfigChange {
+                path: path.clone(),
+                old: old.clone(),
+                new: new.clone(),
+            });
+            return;
+        }
+    };
+
+    let keys = old_fields.keys().chain(new_fields.keys().filter(|key| !old_fields.contains_key(*key)));
+    for key in keys {
+        let parent_len = path.len();
+        if !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(key);
+        diff_values(
+            old_fields.get(key).unwrap_or(&JsonValue::Null),
+            new_fields.get(key).unwrap_or(&JsonValue::Null),
+            path,
+            changes,
+        );
+        path.truncate(parent_len);
+    }
+}
@@ -0,0 +1,313 @@
+//! System alerting configuration
+
+use crate::error::{ConfigError, ConfigResult};
+use crate::validation::{validate_positive, validate_required_string, validate_url, Validatable};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Health event kinds that can raise alerts
+pub const ALERT_EVENT_KINDS: [&str; 3] = ["circuit_opened", "worker_crash_loop", "destination_unhealthy"];
+
+/// System alerting configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertingConfig {
+    /// Whether alerts are dispatched at all
+    #[serde(default = "crate::domains::utils::default_true")]
+    pub enabled: bool,
+
+    /// Named channels alerts can be sent to
+    #[serde(default = "default_channels")]
+    pub channels: Vec<AlertChannelConfig>,
+
+    /// Routing rules deciding which alerts go to which channel
+    #[serde(default = "default_routes")]
+    pub routes: Vec<AlertRouteConfig>,
+
+    /// Severity overrides keyed by event kind (e.g. `worker_crash_loop: warning`)
+    #[serde(default)]
+    pub severities: HashMap<String, AlertSeverity>,
+
+    /// Worker crash-loop detection
+    #[serde(default)]
+    pub crash_loop: CrashLoopConfig,
+
+    /// Repeats of an event for the same circuit, worker or destination within this window are suppressed
+    #[serde(
+        with = "crate::domains::utils::serde_duration",
+        default = "default_dedup_window"
+    )]
+    pub dedup_window: Duration,
+}
+
+/// Alert severity, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl std::fmt::Display for AlertSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlertSeverity::Info => write!(f, "info"),
+            AlertSeverity::Warning => write!(f, "warning"),
+            AlertSeverity::Critical => write!(f, "critical"),
+        }
+    }
+}
+
+/// A named alert channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertChannelConfig {
+    /// Channel name referenced by routes
+    pub name: String,
+
+    /// Channel type and settings
+    #[serde(flatten)]
+    pub channel: AlertChannelType,
+}
+
+/// Alert channel types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AlertChannelType {
+    /// Write alerts to the application log
+    Log,
+    /// POST alerts as JSON to a webhook
+    Webhook {
+        /// Webhook URL
+        url: String,
+        /// Additional HTTP headers
+        #[serde(default)]
+        headers: HashMap<String, String>,
+        /// Request timeout
+        #[serde(with = "crate::domains::utils::serde_duration", default = "default_webhook_timeout")]
+        timeout: Duration,
+    },
+    /// Deliver alerts through a named output destination
+    Destination {
+        /// Output destination name
+        destination: String,
+    },
+}
+
+/// Routing rule for alerts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRouteConfig {
+    /// Channel to deliver matching alerts to
+    pub channel: String,
+
+    /// Minimum severity an alert needs to be routed
+    #[serde(default = "default_min_severity")]
+    pub min_severity: AlertSeverity,
+
+    /// Event kinds this route applies to (empty matches all)
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
+/// Worker crash-loop detection configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrashLoopConfig {
+    /// Number of crashes within the window that counts as a crash loop
+    #[serde(default = "default_crash_loop_threshold")]
+    pub threshold: u32,
+
+    /// Sliding window for counting crashes
+    #[serde(
+        with = "crate::domains::utils::serde_duration",
+        default = "default_crash_loop_window"
+    )]
+    pub window: Duration,
+}
+
+impl Default for AlertingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            channels: default_channels(),
+            routes: default_routes(),
+            severities: HashMap::new(),
+            crash_loop: CrashLoopConfig::default(),
+            dedup_window: default_dedup_window(),
+        }
+    }
+}
+
+impl Default for CrashLoopConfig {
+    fn default() -> Self {
+        Self {
+            threshold: default_crash_loop_threshold(),
+            window: default_crash_loop_window(),
+        }
+    }
+}
+
+impl Validatable for AlertingConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        let mut names = HashSet::new();
+        for channel in &self.channels {
+            validate_required_string(&channel.name, "channels.name", self.domain_name())?;
+            if !names.insert(channel.name.as_str()) {
+                return Err(ConfigError::DomainError {
+                    domain: self.domain_name().to_string(),
+                    message: format!("Duplicate alert channel name '{}'", channel.name),
+                });
+            }
+
+            match &channel.channel {
+                AlertChannelType::Log => {}
+                AlertChannelType::Webhook { url, timeout, .. } => {
+                    validate_url(url, "channels.url", self.domain_name())?;
+                    validate_positive(timeout.as_secs(), "channels.timeout", self.domain_name())?;
+                }
+                AlertChannelType::Destination { destination } => {
+                    validate_required_string(destination, "channels.destination", self.domain_name())?;
+                }
+            }
+        }
+
+        for route in &self.routes {
+            if !names.contains(route.channel.as_str()) {
+                return Err(ConfigError::DomainError {
+                    domain: self.domain_name().to_string(),
+                    message: format!("Alert route references unknown channel '{}'", route.channel),
+                });
+            }
+            for event in &route.events {
+                validate_event_kind(event, self.domain_name())?;
+            }
+        }
+
+        for event in self.severities.keys() {
+            validate_event_kind(event, self.domain_name())?;
+        }
+
+        validate_positive(self.crash_loop.threshold, "crash_loop.threshold", self.domain_name())?;
+        validate_positive(
+            self.crash_loop.window.as_secs(),
+            "crash_loop.window",
+            self.domain_name(),
+        )?;
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "alerting"
+    }
+}
+
+fn validate_event_kind(event: &str, domain: &str) -> ConfigResult<()> {
+    if ALERT_EVENT_KINDS.contains(&event) {
+        Ok(())
+    } else {
+        Err(ConfigError::DomainError {
+            domain: domain.to_string(),
+            message: format!(
+                "Unknown alert event kind '{}', expected one of: {}",
+                event,
+                ALERT_EVENT_KINDS.join(", ")
+            ),
+        })
+    }
+}
+
+// Default value functions
+fn default_channels() -> Vec<AlertChannelConfig> {
+    vec![AlertChannelConfig {
+        name: "log".to_string(),
+        channel: AlertChannelType::Log,
+    }]
+}
+
+fn default_routes() -> Vec<AlertRouteConfig> {
+    vec![AlertRouteConfig {
+        channel: "log".to_string(),
+        min_severity: default_min_severity(),
+        events: Vec::new(),
+    }]
+}
+
+fn default_min_severity() -> AlertSeverity {
+    AlertSeverity::Warning
+}
+
+fn default_webhook_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+fn default_crash_loop_threshold() -> u32 {
+    3
+}
+
+fn default_crash_loop_window() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_dedup_window() -> Duration {
+    Duration::from_secs(300)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerting_config_defaults() {
+        let config = AlertingConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.channels.len(), 1);
+        assert_eq!(config.routes[0].channel, "log");
+        assert_eq!(config.routes[0].min_severity, AlertSeverity::Warning);
+        assert_eq!(config.crash_loop.threshold, 3);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_alerting_config_deserialization() {
+        let yaml = r#"
+channels:
+  - name: ops
+    type: webhook
+    url: https://alerts.example.com/hook
+  - name: audit
+    type: destination
+    destination: alert-archive
+routes:
+  - channel: ops
+    min_severity: critical
+    events: [worker_crash_loop]
+  - channel: audit
+severities:
+  circuit_opened: critical
+"#;
+        let config: AlertingConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert!(matches!(config.channels[0].channel, AlertChannelType::Webhook { .. }));
+        assert_eq!(config.routes[1].min_severity, AlertSeverity::Warning);
+        assert_eq!(config.severities.get("circuit_opened"), Some(&AlertSeverity::Critical));
+    }
+
+    #[test]
+    fn test_alerting_config_validation() {
+        let mut config = AlertingConfig::default();
+        config.routes[0].channel = "missing".to_string();
+        assert!(config.validate().is_err());
+
+        let mut config = AlertingConfig::default();
+        config.routes[0].events = vec!["disk_full".to_string()];
+        assert!(config.validate().is_err());
+
+        let mut config = AlertingConfig::default();
+        config.channels.push(config.channels[0].clone());
+        assert!(config.validate().is_err());
+
+        assert!(AlertSeverity::Critical > AlertSeverity::Warning);
+    }
+}
@@ -0,0 +1,116 @@
+//! Audit log retention configuration
+
+use crate::error::{ConfigError, ConfigResult};
+use crate::validation::{validate_positive, validate_required_string, Validatable};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Audit log retention configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    /// Number of days audit entries are kept
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+
+    /// What happens to entries older than the retention period
+    #[serde(default)]
+    pub retention_action: AuditRetentionAction,
+
+    /// Output destination expired entries are delivered to before deletion
+    /// (required when `retention_action` is `archive`)
+    #[serde(default)]
+    pub archive_destination: Option<String>,
+
+    /// How often the retention policy is applied
+    #[serde(
+        with = "crate::domains::utils::serde_duration",
+        default = "default_retention_interval"
+    )]
+    pub retention_interval: Duration,
+}
+
+/// Handling of audit entries past the retention period
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditRetentionAction {
+    /// Delete expired entries
+    #[default]
+    Delete,
+    /// Deliver expired entries to the archive destination, then delete them
+    Archive,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: default_retention_days(),
+            retention_action: AuditRetentionAction::default(),
+            archive_destination: None,
+            retention_interval: default_retention_interval(),
+        }
+    }
+}
+
+impl Validatable for AuditConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        validate_positive(self.retention_days, "retention_days", self.domain_name())?;
+        validate_positive(
+            self.retention_interval.as_secs(),
+            "retention_interval",
+            self.domain_name(),
+        )?;
+
+        if self.retention_action == AuditRetentionAction::Archive {
+            let destination = self
+                .archive_destination
+                .as_deref()
+                .ok_or_else(|| ConfigError::DomainError {
+                    domain: self.domain_name().to_string(),
+                    message: "retention_action 'archive' requires an archive_destination".to_string(),
+                })?;
+            validate_required_string(destination, "archive_destination", self.domain_name())?;
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "audit"
+    }
+}
+
+// Default value functions
+fn default_retention_days() -> u32 {
+    365
+}
+
+fn default_retention_interval() -> Duration {
+    Duration::from_secs(3600)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_config_defaults() {
+        let config = AuditConfig::default();
+        assert_eq!(config.retention_days, 365);
+        assert_eq!(config.retention_action, AuditRetentionAction::Delete);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_archive_requires_destination() {
+        let yaml = r#"
+retention_days: 90
+retention_action: archive
+"#;
+        let mut config: AuditConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_err());
+
+        config.archive_destination = Some("audit-archive".to_string());
+        assert!(config.validate().is_ok());
+    }
+}
@@ -1,6 +1,6 @@
 //! Database configuration
 
-use crate::error::ConfigResult;
+use crate::error::{ConfigError, ConfigResult};
 use crate::validation::{validate_positive, validate_required_string, Validatable};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -79,6 +79,18 @@ pub struct SqliteConfig {
     /// Busy timeout in milliseconds
     #[serde(default = "default_sqlite_busy_timeout")]
     pub busy_timeout_ms: u32,
+
+    /// Retries of a query that failed because the database was busy or locked (0 disables retrying)
+    #[serde(default = "default_sqlite_busy_retries")]
+    pub busy_retries: u32,
+
+    /// Delay before the first busy retry in milliseconds; later retries back off exponentially
+    #[serde(default = "default_sqlite_busy_retry_base_delay")]
+    pub busy_retry_base_delay_ms: u64,
+
+    /// Upper bound for a single busy retry delay in milliseconds
+    #[serde(default = "default_sqlite_busy_retry_max_delay")]
+    pub busy_retry_max_delay_ms: u64,
 }
 
 /// PostgreSQL-specific configuration
@@ -175,6 +187,9 @@ impl Default for SqliteConfig {
             synchronous: default_sqlite_synchronous(),
             cache_size_kb: default_sqlite_cache_size(),
             busy_timeout_ms: default_sqlite_busy_timeout(),
+            busy_retries: default_sqlite_busy_retries(),
+            busy_retry_base_delay_ms: default_sqlite_busy_retry_base_delay(),
+            busy_retry_max_delay_ms: default_sqlite_busy_retry_max_delay(),
         }
     }
 }
@@ -275,6 +290,13 @@ impl Validatable for SqliteConfig {
 
         validate_positive(self.busy_timeout_ms, "busy_timeout_ms", self.domain_name())?;
 
+        if self.busy_retries > 0 && self.busy_retry_base_delay_ms > self.busy_retry_max_delay_ms {
+            return Err(ConfigError::DomainError {
+                domain: self.domain_name().to_string(),
+                message: "busy_retry_base_delay_ms cannot be greater than busy_retry_max_delay_ms".to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -373,6 +395,18 @@ fn default_sqlite_busy_timeout() -> u32 {
     5000 // 5 seconds
 }
 
+fn default_sqlite_busy_retries() -> u32 {
+    5
+}
+
+fn default_sqlite_busy_retry_base_delay() -> u64 {
+    50
+}
+
+fn default_sqlite_busy_retry_max_delay() -> u64 {
+    2000 // 2 seconds
+}
+
 fn default_postgres_application_name() -> String {
     "ratchet".to_string()
 }
@@ -426,6 +460,19 @@ mod tests {
         assert!(config.migrations.auto_migrate);
     }
 
+    #[test]
+    fn test_sqlite_busy_retry_validation() {
+        let mut config = SqliteConfig::default();
+        assert_eq!(config.busy_retries, 5);
+        assert!(config.validate().is_ok());
+
+        config.busy_retry_base_delay_ms = 5000;
+        assert!(config.validate().is_err());
+
+        config.busy_retries = 0;
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_database_config_validation() {
         let mut config = DatabaseConfig::default();
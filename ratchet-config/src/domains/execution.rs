@@ -1,10 +1,15 @@
 //! Task execution configuration
 
-use crate::error::ConfigResult;
+use crate::error::{ConfigError, ConfigResult};
 use crate::validation::{validate_positive, validate_required_string, Validatable};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::Duration;
 
+/// Default longest chain of dependent requests an MCP batch may contain
+pub const DEFAULT_MAX_DEPENDENCY_DEPTH: usize = 32;
+
 /// Task execution configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -20,10 +25,21 @@ pub struct ExecutionConfig {
     )]
     pub max_execution_duration: Duration,
 
+    /// Overall time budget of an execution, covering the task run and output delivery
+    #[serde(
+        with = "crate::domains::utils::serde_duration",
+        default = "default_max_total_duration"
+    )]
+    pub max_total_duration: Duration,
+
     /// Whether to validate schemas during execution
     #[serde(default = "crate::domains::utils::default_true")]
     pub validate_schemas: bool,
 
+    /// How task output is checked against its output schema
+    #[serde(default)]
+    pub output_schema_enforcement: OutputSchemaEnforcement,
+
     /// Maximum number of concurrent task executions
     #[serde(default = "default_max_concurrent_tasks")]
     pub max_concurrent_tasks: usize,
@@ -34,6 +50,76 @@ pub struct ExecutionConfig {
         default = "default_timeout_grace_period"
     )]
     pub timeout_grace_period: Duration,
+
+    /// Storage for files attached to executions by tasks
+    #[serde(default)]
+    pub attachments: AttachmentsConfig,
+
+    /// Resource limits applied to each JavaScript task run
+    #[serde(default)]
+    pub limits: ResourceLimitsConfig,
+
+    /// Longest chain of dependent requests an MCP batch may contain
+    #[serde(default = "default_max_dependency_depth")]
+    pub max_dependency_depth: usize,
+}
+
+/// Output schema enforcement policy
+///
+/// Lets development deployments relax output checks that production enforces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputSchemaEnforcement {
+    /// Fail the execution when the output does not match the schema
+    #[default]
+    Strict,
+    /// Log a warning and keep the output
+    Warn,
+    /// Skip output validation
+    Off,
+}
+
+impl FromStr for OutputSchemaEnforcement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "strict" => Ok(OutputSchemaEnforcement::Strict),
+            "warn" => Ok(OutputSchemaEnforcement::Warn),
+            "off" => Ok(OutputSchemaEnforcement::Off),
+            _ => Err(format!("Invalid output schema enforcement: {}", s)),
+        }
+    }
+}
+
+/// Execution attachments configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AttachmentsConfig {
+    /// Directory where attachment files are stored
+    #[serde(default = "default_attachments_directory")]
+    pub directory: PathBuf,
+
+    /// Maximum size of a single attachment in bytes
+    #[serde(default = "default_attachment_max_size_bytes")]
+    pub max_size_bytes: u64,
+}
+
+/// Resource limits of JavaScript task runs, all unlimited unless set
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResourceLimitsConfig {
+    /// Most bytes the worker process's resident memory may grow by during a run
+    ///
+    /// Approximate: the engine doesn't meter its heap, so this measures the whole
+    /// worker process, and executions sharing the worker count against each other.
+    pub max_heap_bytes: Option<u64>,
+
+    /// Most iterations any single loop in a task may run
+    pub max_loop_iterations: Option<u64>,
+
+    /// Deepest nesting of JavaScript calls, the engine default unless set
+    pub max_call_depth: Option<usize>,
 }
 
 /// JavaScript fetch variables configuration
@@ -66,9 +152,23 @@ impl Default for ExecutionConfig {
         Self {
             fetch_variables: FetchVariables::default(),
             max_execution_duration: default_max_execution_duration(),
+            max_total_duration: default_max_total_duration(),
             validate_schemas: true,
+            output_schema_enforcement: OutputSchemaEnforcement::default(),
             max_concurrent_tasks: default_max_concurrent_tasks(),
             timeout_grace_period: default_timeout_grace_period(),
+            attachments: AttachmentsConfig::default(),
+            limits: ResourceLimitsConfig::default(),
+            max_dependency_depth: default_max_dependency_depth(),
+        }
+    }
+}
+
+impl Default for AttachmentsConfig {
+    fn default() -> Self {
+        Self {
+            directory: default_attachments_directory(),
+            max_size_bytes: default_attachment_max_size_bytes(),
         }
     }
 }
@@ -94,6 +194,23 @@ impl Validatable for ExecutionConfig {
             self.domain_name(),
         )?;
 
+        validate_positive(
+            self.max_total_duration.as_secs(),
+            "max_total_duration",
+            self.domain_name(),
+        )?;
+
+        // The overall budget has to leave room for at least one full task run
+        if self.max_total_duration < self.max_execution_duration {
+            return Err(ConfigError::DomainError {
+                domain: self.domain_name().to_string(),
+                message: format!(
+                    "max_total_duration ({:?}) must not be shorter than max_execution_duration ({:?})",
+                    self.max_total_duration, self.max_execution_duration
+                ),
+            });
+        }
+
         validate_positive(
             self.timeout_grace_period.as_secs(),
             "timeout_grace_period",
@@ -101,10 +218,15 @@ impl Validatable for ExecutionConfig {
         )?;
 
         validate_positive(self.max_concurrent_tasks, "max_concurrent_tasks", self.domain_name())?;
+        validate_positive(self.max_dependency_depth, "max_dependency_depth", self.domain_name())?;
 
         // Validate fetch variables
         self.fetch_variables.validate()?;
 
+        self.attachments.validate()?;
+
+        self.limits.validate()?;
+
         Ok(())
     }
 
@@ -113,6 +235,26 @@ impl Validatable for ExecutionConfig {
     }
 }
 
+impl Validatable for ResourceLimitsConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if let Some(bytes) = self.max_heap_bytes {
+            validate_positive(bytes, "max_heap_bytes", self.domain_name())?;
+        }
+        if let Some(iterations) = self.max_loop_iterations {
+            validate_positive(iterations, "max_loop_iterations", self.domain_name())?;
+        }
+        if let Some(depth) = self.max_call_depth {
+            validate_positive(depth, "max_call_depth", self.domain_name())?;
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "execution.limits"
+    }
+}
+
 impl Validatable for FetchVariables {
     fn validate(&self) -> ConfigResult<()> {
         validate_required_string(&self.url_var, "url_var", self.domain_name())?;
@@ -129,19 +271,47 @@ impl Validatable for FetchVariables {
     }
 }
 
+impl Validatable for AttachmentsConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        validate_positive(self.max_size_bytes, "max_size_bytes", self.domain_name())?;
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "execution.attachments"
+    }
+}
+
 // Default value functions
 fn default_max_execution_duration() -> Duration {
     Duration::from_secs(300) // 5 minutes
 }
 
+fn default_max_total_duration() -> Duration {
+    Duration::from_secs(600) // 10 minutes
+}
+
 fn default_max_concurrent_tasks() -> usize {
     10
 }
 
+fn default_max_dependency_depth() -> usize {
+    DEFAULT_MAX_DEPENDENCY_DEPTH
+}
+
 fn default_timeout_grace_period() -> Duration {
     Duration::from_secs(5)
 }
 
+fn default_attachments_directory() -> PathBuf {
+    PathBuf::from("./data/attachments")
+}
+
+fn default_attachment_max_size_bytes() -> u64 {
+    10 * 1024 * 1024 // 10MB
+}
+
 fn default_url_var() -> String {
     "__fetch_url".to_string()
 }
@@ -170,8 +340,36 @@ mod tests {
     fn test_execution_config_defaults() {
         let config = ExecutionConfig::default();
         assert_eq!(config.max_execution_duration, Duration::from_secs(300));
+        assert_eq!(config.max_total_duration, Duration::from_secs(600));
         assert!(config.validate_schemas);
+        assert_eq!(config.output_schema_enforcement, OutputSchemaEnforcement::Strict);
         assert_eq!(config.max_concurrent_tasks, 10);
+        assert_eq!(config.max_dependency_depth, 32);
+    }
+
+    #[test]
+    fn test_total_duration_must_cover_execution_duration() {
+        let config = ExecutionConfig {
+            max_execution_duration: Duration::from_secs(300),
+            max_total_duration: Duration::from_secs(60),
+            ..ExecutionConfig::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = ExecutionConfig {
+            max_total_duration: Duration::from_secs(300),
+            ..config
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_output_schema_enforcement_parsing() {
+        let config: ExecutionConfig = serde_yaml::from_str("output_schema_enforcement: warn").unwrap();
+        assert_eq!(config.output_schema_enforcement, OutputSchemaEnforcement::Warn);
+
+        assert_eq!("OFF".parse(), Ok(OutputSchemaEnforcement::Off));
+        assert!("lenient".parse::<OutputSchemaEnforcement>().is_err());
     }
 
     #[test]
@@ -193,4 +391,26 @@ mod tests {
         vars.url_var = String::new();
         assert!(vars.validate().is_err());
     }
+
+    #[test]
+    fn test_resource_limits_config() {
+        let config: ExecutionConfig = serde_yaml::from_str("limits:\n  max_loop_iterations: 1000000").unwrap();
+        assert_eq!(config.limits.max_loop_iterations, Some(1_000_000));
+        assert_eq!(config.limits.max_heap_bytes, None);
+        assert!(config.validate().is_ok());
+
+        let mut limits = ResourceLimitsConfig::default();
+        limits.max_call_depth = Some(0);
+        assert!(limits.validate().is_err());
+    }
+
+    #[test]
+    fn test_attachments_config_validation() {
+        let mut attachments = AttachmentsConfig::default();
+        assert_eq!(attachments.max_size_bytes, 10 * 1024 * 1024);
+        assert!(attachments.validate().is_ok());
+
+        attachments.max_size_bytes = 0;
+        assert!(attachments.validate().is_err());
+    }
 }
@@ -0,0 +1,74 @@
+//! Feature flag configuration
+
+use crate::error::ConfigResult;
+use crate::validation::Validatable;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Feature flags for toggling optional subsystems
+///
+/// Every flag defaults to enabled so that existing deployments keep their current behaviour.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeatureFlags {
+    /// Allow MCP task executions to stream progress notifications
+    #[serde(default = "crate::domains::utils::default_true")]
+    pub progress_streaming: bool,
+
+    /// Expose the MCP streamable HTTP transport (SSE is always available)
+    #[serde(default = "crate::domains::utils::default_true")]
+    pub mcp_streamable_http: bool,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self {
+            progress_streaming: true,
+            mcp_streamable_http: true,
+        }
+    }
+}
+
+impl FeatureFlags {
+    /// Flag names mapped to their current state, for read-only reporting
+    pub fn as_map(&self) -> BTreeMap<String, bool> {
+        BTreeMap::from([
+            ("progress_streaming".to_string(), self.progress_streaming),
+            ("mcp_streamable_http".to_string(), self.mcp_streamable_http),
+        ])
+    }
+}
+
+impl Validatable for FeatureFlags {
+    fn validate(&self) -> ConfigResult<()> {
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "features"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_flags_default_enabled() {
+        let flags = FeatureFlags::default();
+        assert!(flags.progress_streaming);
+        assert!(flags.mcp_streamable_http);
+        assert!(flags.validate().is_ok());
+    }
+
+    #[test]
+    fn test_feature_flags_partial_deserialization() {
+        let flags: FeatureFlags = serde_yaml::from_str("progress_streaming: false").unwrap();
+        assert!(!flags.progress_streaming);
+        assert!(flags.mcp_streamable_http);
+
+        let map = flags.as_map();
+        assert_eq!(map.get("progress_streaming"), Some(&false));
+        assert_eq!(map.get("mcp_streamable_http"), Some(&true));
+    }
+}
@@ -1,8 +1,9 @@
 //! HTTP client configuration
 
-use crate::error::ConfigResult;
+use crate::error::{ConfigError, ConfigResult};
 use crate::validation::{validate_positive, validate_required_string, Validatable};
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 use std::time::Duration;
 
 /// HTTP client configuration
@@ -25,6 +26,14 @@ pub struct HttpConfig {
     #[serde(default = "crate::domains::utils::default_true")]
     pub verify_ssl: bool,
 
+    /// Maximum response body size in bytes for fetch responses, streamed or not
+    #[serde(default = "default_max_response_size")]
+    pub max_response_size: usize,
+
+    /// Maximum request body size in bytes for fetch requests
+    #[serde(default = "default_max_request_size")]
+    pub max_request_size: usize,
+
     /// Connection pool configuration
     #[serde(default)]
     pub connection_pool: ConnectionPoolConfig,
@@ -32,6 +41,145 @@ pub struct HttpConfig {
     /// Proxy configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy: Option<ProxyConfig>,
+
+    /// Per-host timeout and pool overrides; the first matching rule wins
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host_overrides: Vec<HostOverrideConfig>,
+
+    /// Hosts JavaScript `fetch` may reach
+    #[serde(default)]
+    pub fetch_access: FetchAccessConfig,
+}
+
+/// Allow and deny lists for the hosts JavaScript `fetch` may reach
+///
+/// Patterns are host names, `*.example.com` for subdomains, `*` for every host,
+/// IP addresses or CIDR blocks such as `10.0.0.0/8`. Address patterns only match
+/// URLs that name the address; host names are not resolved.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FetchAccessConfig {
+    /// Hosts that may be reached; an empty list allows every host
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+
+    /// Hosts that may never be reached, taking precedence over `allow`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+}
+
+impl FetchAccessConfig {
+    /// Whether `fetch` may reach `host`
+    pub fn permits(&self, host: &str) -> bool {
+        let matches = |pattern: &String| host_pattern_matches(pattern, host);
+        !self.deny.iter().any(matches) && (self.allow.is_empty() || self.allow.iter().any(matches))
+    }
+
+    /// Whether either list restricts any host
+    pub fn is_restricted(&self) -> bool {
+        !self.allow.is_empty() || !self.deny.is_empty()
+    }
+}
+
+/// Whether `host` matches a fetch access pattern
+fn host_pattern_matches(pattern: &str, host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if let Some((network, prefix)) = parse_cidr(pattern) {
+        return host
+            .parse::<IpAddr>()
+            .is_ok_and(|address| cidr_contains(network, prefix, address));
+    }
+    if let Ok(address) = pattern.parse::<IpAddr>() {
+        return host.parse::<IpAddr>().is_ok_and(|host| host == address);
+    }
+    host_name_matches(pattern, host)
+}
+
+/// Whether `host` matches an exact host name, `*.domain` or `*`, ignoring case
+fn host_name_matches(pattern: &str, host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    let pattern = pattern.to_ascii_lowercase();
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host.len() > domain.len() + 1 && host.ends_with(&format!(".{}", domain)),
+        None => pattern == "*" || pattern == host,
+    }
+}
+
+/// Whether `pattern` is a host name, `*.domain` or `*`
+fn valid_host_name_pattern(pattern: &str) -> bool {
+    let domain = pattern.strip_prefix("*.").unwrap_or(pattern);
+    pattern == "*"
+        || (!domain.is_empty()
+            && !domain.starts_with('.')
+            && !domain.ends_with('.')
+            && domain
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.'))
+}
+
+/// Network address and prefix length of a CIDR block
+fn parse_cidr(pattern: &str) -> Option<(IpAddr, u32)> {
+    let (network, prefix) = pattern.split_once('/')?;
+    let network: IpAddr = network.parse().ok()?;
+    let prefix: u32 = prefix.parse().ok()?;
+    let max_prefix = if network.is_ipv4() { 32 } else { 128 };
+    (prefix <= max_prefix).then_some((network, prefix))
+}
+
+fn cidr_contains(network: IpAddr, prefix: u32, address: IpAddr) -> bool {
+    match (network, address) {
+        (IpAddr::V4(network), IpAddr::V4(address)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(network) & mask == u32::from(address) & mask
+        }
+        (IpAddr::V6(network), IpAddr::V6(address)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(network) & mask == u128::from(address) & mask
+        }
+        _ => false,
+    }
+}
+
+/// Timeout and connection pool overrides for hosts matching a pattern
+///
+/// Settings left unset fall back to the global values.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HostOverrideConfig {
+    /// Host pattern: an exact host name, `*.example.com` for its subdomains, or `*` for every host
+    pub host: String,
+
+    /// Request timeout
+    #[serde(
+        with = "crate::domains::utils::serde_duration_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub timeout: Option<Duration>,
+
+    /// Connection timeout
+    #[serde(
+        with = "crate::domains::utils::serde_duration_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub connect_timeout: Option<Duration>,
+
+    /// Maximum idle connections kept for the host
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_idle_per_host: Option<usize>,
+
+    /// TCP keepalive interval
+    #[serde(
+        with = "crate::domains::utils::serde_duration_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub keepalive: Option<Duration>,
+}
+
+impl HostOverrideConfig {
+    /// Whether the rule applies to `host`, ignoring case
+    pub fn matches(&self, host: &str) -> bool {
+        host_name_matches(&self.host, host)
+    }
 }
 
 /// Connection pool configuration
@@ -65,6 +213,73 @@ pub struct ProxyConfig {
 
     /// No proxy hosts (comma-separated)
     pub no_proxy: Option<String>,
+
+    /// Credentials for proxies that require authentication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<ProxyAuthConfig>,
+}
+
+/// Proxy authentication configuration
+///
+/// Credentials can be given inline or read from environment variables named by
+/// `username_env` and `password_env`, which take precedence when set.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxyAuthConfig {
+    /// Authentication scheme expected by the proxy
+    pub scheme: ProxyAuthScheme,
+
+    /// Proxy username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Proxy password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Environment variable holding the proxy username
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username_env: Option<String>,
+
+    /// Environment variable holding the proxy password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_env: Option<String>,
+}
+
+/// Proxy authentication scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ProxyAuthScheme {
+    /// Send credentials with every request
+    #[default]
+    Basic,
+    /// Answer the proxy's digest challenge (plain HTTP requests only)
+    Digest,
+}
+
+impl ProxyAuthConfig {
+    /// Resolve the username and password, preferring the environment variables
+    pub fn credentials(&self) -> Option<(String, String)> {
+        let from_env = |name: &Option<String>| name.as_ref().and_then(|name| std::env::var(name).ok());
+        let username = from_env(&self.username_env).or_else(|| self.username.clone())?;
+        let password = from_env(&self.password_env)
+            .or_else(|| self.password.clone())
+            .unwrap_or_default();
+        Some((username, password))
+    }
+}
+
+impl std::fmt::Debug for ProxyAuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the password itself
+        f.debug_struct("ProxyAuthConfig")
+            .field("scheme", &self.scheme)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .field("username_env", &self.username_env)
+            .field("password_env", &self.password_env)
+            .finish()
+    }
 }
 
 impl Default for HttpConfig {
@@ -74,8 +289,12 @@ impl Default for HttpConfig {
             max_redirects: default_max_redirects(),
             user_agent: default_user_agent(),
             verify_ssl: true,
+            max_response_size: default_max_response_size(),
+            max_request_size: default_max_request_size(),
             connection_pool: ConnectionPoolConfig::default(),
             proxy: None,
+            host_overrides: Vec::new(),
+            fetch_access: FetchAccessConfig::default(),
         }
     }
 }
@@ -98,6 +317,10 @@ impl Validatable for HttpConfig {
         // Validate user agent
         validate_required_string(&self.user_agent, "user_agent", self.domain_name())?;
 
+        // Validate response size limit
+        validate_positive(self.max_response_size, "max_response_size", self.domain_name())?;
+        validate_positive(self.max_request_size, "max_request_size", self.domain_name())?;
+
         // Validate connection pool
         self.connection_pool.validate()?;
 
@@ -106,6 +329,12 @@ impl Validatable for HttpConfig {
             proxy.validate()?;
         }
 
+        for rule in &self.host_overrides {
+            rule.validate()?;
+        }
+
+        self.fetch_access.validate()?;
+
         Ok(())
     }
 
@@ -114,6 +343,63 @@ impl Validatable for HttpConfig {
     }
 }
 
+impl Validatable for HostOverrideConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if !valid_host_name_pattern(&self.host) {
+            return Err(ConfigError::DomainError {
+                domain: self.domain_name().to_string(),
+                message: format!(
+                    "invalid host pattern '{}': expected a host name, '*.domain' or '*'",
+                    self.host
+                ),
+            });
+        }
+
+        for (value, field) in [
+            (self.timeout, "timeout"),
+            (self.connect_timeout, "connect_timeout"),
+            (self.keepalive, "keepalive"),
+        ] {
+            if let Some(value) = value {
+                validate_positive(value.as_millis(), field, self.domain_name())?;
+            }
+        }
+        if let Some(max_idle) = self.max_idle_per_host {
+            validate_positive(max_idle, "max_idle_per_host", self.domain_name())?;
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "http.host_overrides"
+    }
+}
+
+impl Validatable for FetchAccessConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        for pattern in self.allow.iter().chain(&self.deny) {
+            let valid =
+                parse_cidr(pattern).is_some() || pattern.parse::<IpAddr>().is_ok() || valid_host_name_pattern(pattern);
+            if !valid {
+                return Err(ConfigError::DomainError {
+                    domain: self.domain_name().to_string(),
+                    message: format!(
+                        "invalid host pattern '{}': expected a host name, '*.domain', '*', an IP address or CIDR",
+                        pattern
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "http.fetch_access"
+    }
+}
+
 impl Validatable for ConnectionPoolConfig {
     fn validate(&self) -> ConfigResult<()> {
         validate_positive(self.max_idle_per_host, "max_idle_per_host", self.domain_name())?;
@@ -145,6 +431,15 @@ impl Validatable for ProxyConfig {
             crate::validation::validate_url(url, "https_proxy", self.domain_name())?;
         }
 
+        if let Some(ref auth) = self.auth {
+            if auth.username.is_none() && auth.username_env.is_none() {
+                return Err(ConfigError::DomainError {
+                    domain: self.domain_name().to_string(),
+                    message: "auth requires either username or username_env".to_string(),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -166,6 +461,14 @@ fn default_user_agent() -> String {
     "Ratchet/1.0".to_string()
 }
 
+fn default_max_response_size() -> usize {
+    10 * 1024 * 1024 // 10MB
+}
+
+fn default_max_request_size() -> usize {
+    10 * 1024 * 1024 // 10MB
+}
+
 fn default_max_idle_per_host() -> usize {
     10
 }
@@ -189,6 +492,8 @@ mod tests {
         assert_eq!(config.max_redirects, 10);
         assert_eq!(config.user_agent, "Ratchet/1.0");
         assert!(config.verify_ssl);
+        assert_eq!(config.max_response_size, 10 * 1024 * 1024);
+        assert_eq!(config.max_request_size, 10 * 1024 * 1024);
     }
 
     #[test]
@@ -212,11 +517,106 @@ mod tests {
             http_proxy: Some("http://proxy.example.com:8080".to_string()),
             https_proxy: None,
             no_proxy: None,
+            auth: None,
         };
         assert!(proxy.validate().is_ok());
 
+        // Auth needs a username source
+        proxy.auth = Some(ProxyAuthConfig::default());
+        assert!(proxy.validate().is_err());
+        proxy.auth = None;
+
         // Test invalid URL
         proxy.http_proxy = Some("not-a-url".to_string());
         assert!(proxy.validate().is_err());
     }
+
+    #[test]
+    fn test_host_override_matching_and_validation() {
+        let config = HttpConfig {
+            host_overrides: vec![
+                HostOverrideConfig {
+                    host: "api.internal".to_string(),
+                    timeout: Some(Duration::from_secs(2)),
+                    ..Default::default()
+                },
+                HostOverrideConfig {
+                    host: "*.slow-vendor.com".to_string(),
+                    timeout: Some(Duration::from_secs(120)),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let (internal, vendor) = (&config.host_overrides[0], &config.host_overrides[1]);
+        assert!(internal.matches("API.internal"));
+        assert!(vendor.matches("eu.slow-vendor.com"));
+        assert!(!vendor.matches("slow-vendor.com"));
+        assert!(!internal.matches("example.com") && !vendor.matches("example.com"));
+
+        for pattern in ["", "https://api.internal", "api.*.com", "*."] {
+            let rule = HostOverrideConfig {
+                host: pattern.to_string(),
+                ..Default::default()
+            };
+            assert!(rule.validate().is_err(), "pattern '{}' should be rejected", pattern);
+        }
+    }
+
+    #[test]
+    fn test_fetch_access_allow_and_deny_lists() {
+        assert!(FetchAccessConfig::default().permits("anything.example.com"));
+
+        let access = FetchAccessConfig {
+            allow: vec!["*.example.com".to_string(), "10.0.0.0/8".to_string(), "::1".to_string()],
+            deny: vec!["admin.example.com".to_string(), "10.0.0.1".to_string()],
+        };
+        assert!(access.validate().is_ok());
+
+        assert!(access.permits("api.example.com"));
+        assert!(access.permits("10.20.30.40"));
+        assert!(access.permits("[::1]"));
+        assert!(!access.permits("example.org"));
+        assert!(!access.permits("11.0.0.1"));
+
+        // The deny list wins over a matching allow pattern
+        assert!(!access.permits("ADMIN.example.com"));
+        assert!(!access.permits("10.0.0.1"));
+
+        for pattern in ["", "10.0.0.0/33", "https://example.com", "api.*.com"] {
+            let access = FetchAccessConfig {
+                deny: vec![pattern.to_string()],
+                ..Default::default()
+            };
+            assert!(access.validate().is_err(), "pattern '{}' should be rejected", pattern);
+        }
+    }
+
+    #[test]
+    fn test_proxy_credentials_prefer_environment() {
+        let auth: ProxyAuthConfig = serde_yaml::from_str(
+            r#"
+scheme: digest
+username: inline-user
+password: inline-pass
+password_env: RATCHET_TEST_PROXY_PASSWORD
+"#,
+        )
+        .unwrap();
+        assert_eq!(auth.scheme, ProxyAuthScheme::Digest);
+
+        std::env::set_var("RATCHET_TEST_PROXY_PASSWORD", "env-pass");
+        assert_eq!(
+            auth.credentials(),
+            Some(("inline-user".to_string(), "env-pass".to_string()))
+        );
+        std::env::remove_var("RATCHET_TEST_PROXY_PASSWORD");
+        assert_eq!(
+            auth.credentials(),
+            Some(("inline-user".to_string(), "inline-pass".to_string()))
+        );
+        assert!(!format!("{:?}", auth).contains("inline-pass"));
+    }
 }
@@ -23,6 +23,42 @@ pub struct McpConfig {
     /// Port for SSE transport
     #[serde(default = "default_mcp_port")]
     pub port: u16,
+
+    /// Backpressure of the progress notification channel
+    #[serde(default)]
+    pub progress: ProgressChannelConfig,
+}
+
+/// Behaviour of the progress channel when the consumer falls behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressOverflowPolicy {
+    /// Discard the oldest pending non-final update to make room
+    DropOldest,
+    /// Replace a pending update for the same execution with the newer one
+    #[default]
+    Coalesce,
+}
+
+/// Backpressure configuration for the progress notification channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressChannelConfig {
+    /// Maximum number of pending notifications before the overflow policy applies
+    #[serde(default = "default_progress_channel_capacity")]
+    pub capacity: usize,
+
+    /// What to do with pending updates when the channel is full
+    #[serde(default)]
+    pub overflow_policy: ProgressOverflowPolicy,
+}
+
+impl Default for ProgressChannelConfig {
+    fn default() -> Self {
+        Self {
+            capacity: default_progress_channel_capacity(),
+            overflow_policy: ProgressOverflowPolicy::default(),
+        }
+    }
 }
 
 impl Default for McpConfig {
@@ -32,6 +68,7 @@ impl Default for McpConfig {
             transport: default_mcp_transport(),
             host: default_mcp_host(),
             port: default_mcp_port(),
+            progress: ProgressChannelConfig::default(),
         }
     }
 }
@@ -47,6 +84,8 @@ impl Validatable for McpConfig {
             crate::validation::validate_port_range(self.port, "port", self.domain_name())?;
         }
 
+        crate::validation::validate_positive(self.progress.capacity, "progress.capacity", self.domain_name())?;
+
         Ok(())
     }
 
@@ -68,6 +107,10 @@ fn default_mcp_port() -> u16 {
     8090
 }
 
+fn default_progress_channel_capacity() -> usize {
+    1024
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,5 +136,21 @@ mod tests {
         // Test valid SSE transport
         config.transport = "sse".to_string();
         assert!(config.validate().is_ok());
+
+        // Test empty progress channel
+        config.progress.capacity = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_mcp_progress_config_from_yaml() {
+        let config: McpConfig =
+            serde_yaml::from_str("progress:\n  capacity: 16\n  overflow_policy: drop_oldest\n").unwrap();
+        assert_eq!(config.progress.capacity, 16);
+        assert_eq!(config.progress.overflow_policy, ProgressOverflowPolicy::DropOldest);
+
+        let config: McpConfig = serde_yaml::from_str("port: 9000").unwrap();
+        assert_eq!(config.progress.capacity, 1024);
+        assert_eq!(config.progress.overflow_policy, ProgressOverflowPolicy::Coalesce);
     }
 }
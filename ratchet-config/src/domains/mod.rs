@@ -1,17 +1,21 @@
 //! Domain-specific configuration modules
 
+pub mod alerting;
+pub mod audit;
 pub mod cache;
 pub mod database;
 pub mod execution;
+pub mod features;
 pub mod http;
 pub mod logging;
 pub mod mcp;
 pub mod output;
+pub mod plugins;
 pub mod registry;
 pub mod server;
 pub mod utils;
 
-use crate::error::ConfigResult;
+use crate::error::{ConfigError, ConfigResult};
 use crate::validation::Validatable;
 use serde::{Deserialize, Serialize};
 
@@ -48,6 +52,22 @@ pub struct RatchetConfig {
 
     /// MCP server configuration (optional)
     pub mcp: Option<mcp::McpConfig>,
+
+    /// Feature flags for optional subsystems
+    #[serde(default)]
+    pub features: features::FeatureFlags,
+
+    /// System alerting configuration
+    #[serde(default)]
+    pub alerting: alerting::AlertingConfig,
+
+    /// Audit log retention configuration
+    #[serde(default)]
+    pub audit: audit::AuditConfig,
+
+    /// Dynamic plugin loading configuration
+    #[serde(default)]
+    pub plugins: plugins::PluginsConfig,
 }
 
 impl Default for RatchetConfig {
@@ -61,6 +81,10 @@ impl Default for RatchetConfig {
             server: Some(server::ServerConfig::default()),
             registry: None,
             mcp: Some(mcp::McpConfig::default()),
+            features: features::FeatureFlags::default(),
+            alerting: alerting::AlertingConfig::default(),
+            audit: audit::AuditConfig::default(),
+            plugins: plugins::PluginsConfig::default(),
         }
     }
 }
@@ -74,6 +98,10 @@ impl RatchetConfig {
         self.cache.validate()?;
         self.logging.validate()?;
         self.output.validate()?;
+        self.features.validate()?;
+        self.alerting.validate()?;
+        self.audit.validate()?;
+        self.plugins.validate()?;
 
         if let Some(ref server) = self.server {
             server.validate()?;
@@ -90,6 +118,59 @@ impl RatchetConfig {
         Ok(())
     }
 
+    /// Configuration preset by name: dev, production, enterprise, minimal or claude
+    ///
+    /// `dev` is the default configuration with verbose logging. `minimal` leaves
+    /// out the server and MCP sections. `production` logs JSON and disables
+    /// GraphQL introspection, and `enterprise` adds authentication with the JWT
+    /// secret read from `RATCHET_JWT_SECRET`. `claude` runs the MCP server over
+    /// stdio for Claude Desktop, logging only warnings.
+    pub fn preset(config_type: &str) -> ConfigResult<Self> {
+        let mut config = Self::default();
+        match config_type {
+            "dev" => {
+                config.logging.level = logging::LogLevel::Debug;
+                config.logging.format = logging::LogFormat::Pretty;
+            }
+            "minimal" => {
+                config.server = None;
+                config.mcp = None;
+            }
+            "production" | "enterprise" => {
+                config.logging.format = logging::LogFormat::Json;
+                let server = config.server.get_or_insert_with(Default::default);
+                server.bind_address = "0.0.0.0".to_string();
+                server.graphql.enable_introspection = false;
+                if config_type == "enterprise" {
+                    server.auth = Some(server::AuthConfig {
+                        jwt_secret: "${RATCHET_JWT_SECRET}".to_string(),
+                        token_expiration: std::time::Duration::from_secs(3600),
+                        issuer: "ratchet".to_string(),
+                        audience: "ratchet-api".to_string(),
+                    });
+                }
+            }
+            "claude" => {
+                config.server = None;
+                config.logging.level = logging::LogLevel::Warn;
+                config.mcp = Some(mcp::McpConfig {
+                    transport: "stdio".to_string(),
+                    ..Default::default()
+                });
+            }
+            _ => {
+                return Err(ConfigError::InvalidValue {
+                    path: "config_type".to_string(),
+                    message: format!(
+                        "unknown configuration type '{}', expected dev, production, enterprise, minimal or claude",
+                        config_type
+                    ),
+                })
+            }
+        }
+        Ok(config)
+    }
+
     /// Generate a sample configuration file
     pub fn generate_sample() -> String {
         let config = RatchetConfig::default();
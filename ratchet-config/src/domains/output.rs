@@ -6,14 +6,21 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
 
+/// Default cap on the number of output destinations per job or schedule
+pub const DEFAULT_MAX_OUTPUT_DESTINATIONS: usize = 10;
+
 /// Output destinations configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct OutputConfig {
-    /// Maximum number of concurrent deliveries
+    /// Maximum number of concurrent deliveries across all destinations
     #[serde(default = "default_max_concurrent_deliveries")]
     pub max_concurrent_deliveries: usize,
 
+    /// Maximum number of output destinations a single job or schedule may configure
+    #[serde(default = "default_max_destinations_per_job")]
+    pub max_destinations_per_job: usize,
+
     /// Default timeout for deliveries
     #[serde(with = "crate::domains::utils::serde_duration", default = "default_delivery_timeout")]
     pub default_timeout: Duration,
@@ -26,6 +33,14 @@ pub struct OutputConfig {
     #[serde(default)]
     pub global_destinations: Vec<OutputDestinationTemplate>,
 
+    /// Name of a global destination that executions without their own destinations deliver to
+    #[serde(default)]
+    pub default_destination: Option<String>,
+
+    /// Also deliver to the default destination when an execution configures its own
+    #[serde(default = "crate::domains::utils::default_false")]
+    pub always_use_default_destination: bool,
+
     /// Default retry policy for failed deliveries
     #[serde(default)]
     pub default_retry_policy: RetryPolicyConfig,
@@ -210,9 +225,12 @@ impl Default for OutputConfig {
     fn default() -> Self {
         Self {
             max_concurrent_deliveries: default_max_concurrent_deliveries(),
+            max_destinations_per_job: default_max_destinations_per_job(),
             default_timeout: default_delivery_timeout(),
             validate_on_startup: true,
             global_destinations: Vec::new(),
+            default_destination: None,
+            always_use_default_destination: false,
             default_retry_policy: RetryPolicyConfig::default(),
             formatting: OutputFormattingConfig::default(),
             security: OutputSecurityConfig::default(),
@@ -269,6 +287,12 @@ impl Validatable for OutputConfig {
             self.domain_name(),
         )?;
 
+        validate_positive(
+            self.max_destinations_per_job,
+            "max_destinations_per_job",
+            self.domain_name(),
+        )?;
+
         validate_positive(self.default_timeout.as_secs(), "default_timeout", self.domain_name())?;
 
         self.default_retry_policy.validate()?;
@@ -280,6 +304,15 @@ impl Validatable for OutputConfig {
             template.validate_with_security(&format!("global_destinations[{}]", index), &self.security)?;
         }
 
+        if let Some(ref name) = self.default_destination {
+            if self.default_destination_template().is_none() {
+                return Err(self.validation_error(format!(
+                    "default_destination '{}' does not match any global destination",
+                    name
+                )));
+            }
+        }
+
         Ok(())
     }
 
@@ -288,6 +321,18 @@ impl Validatable for OutputConfig {
     }
 }
 
+impl OutputConfig {
+    /// Global destination template selected as the default destination, if any
+    pub fn default_destination_template(&self) -> Option<&OutputDestinationTemplate> {
+        self.destination_template(self.default_destination.as_ref()?)
+    }
+
+    /// Global destination template with the given name, if any
+    pub fn destination_template(&self, name: &str) -> Option<&OutputDestinationTemplate> {
+        self.global_destinations.iter().find(|template| template.name == name)
+    }
+}
+
 impl Validatable for RetryPolicyConfig {
     fn validate(&self) -> ConfigResult<()> {
         validate_positive(self.max_attempts, "max_attempts", self.domain_name())?;
@@ -586,6 +631,10 @@ fn default_max_concurrent_deliveries() -> usize {
     10
 }
 
+fn default_max_destinations_per_job() -> usize {
+    DEFAULT_MAX_OUTPUT_DESTINATIONS
+}
+
 fn default_delivery_timeout() -> Duration {
     Duration::from_secs(30)
 }
@@ -650,10 +699,36 @@ mod tests {
     fn test_output_config_defaults() {
         let config = OutputConfig::default();
         assert_eq!(config.max_concurrent_deliveries, 10);
+        assert_eq!(config.max_destinations_per_job, 10);
         assert_eq!(config.default_timeout, Duration::from_secs(30));
         assert!(config.validate_on_startup);
     }
 
+    #[test]
+    fn test_default_destination_must_name_global_destination() {
+        let mut config = OutputConfig {
+            global_destinations: vec![OutputDestinationTemplate {
+                name: "audit".to_string(),
+                description: None,
+                destination: OutputDestinationConfigTemplate::Filesystem {
+                    path: "/var/log/ratchet/audit.json".to_string(),
+                    format: "json".to_string(),
+                    permissions: "644".to_string(),
+                    create_dirs: true,
+                    overwrite: true,
+                    backup_existing: false,
+                },
+            }],
+            default_destination: Some("audit".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.default_destination_template().unwrap().name, "audit");
+
+        config.default_destination = Some("missing".to_string());
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_retry_policy_validation() {
         let mut policy = RetryPolicyConfig::default();
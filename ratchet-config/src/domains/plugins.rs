@@ -0,0 +1,99 @@
+//! Plugin configuration
+
+use crate::error::ConfigResult;
+use crate::validation::{validate_required_string, Validatable};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Plugin configuration
+///
+/// Each key under `plugins.config` is the configuration section of the plugin
+/// with that ID, passed to the plugin when it is loaded:
+///
+/// ```yaml
+/// plugins:
+///   directory: ./plugins
+///   config:
+///     metrics-exporter:
+///       endpoint: http://localhost:9091
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginsConfig {
+    /// Directory scanned for plugin libraries (`.so`, `.dll`, `.dylib`) at
+    /// server startup; no plugins are loaded when unset
+    #[serde(default)]
+    pub directory: Option<String>,
+
+    /// Configuration sections by plugin ID
+    #[serde(default)]
+    pub config: BTreeMap<String, serde_json::Value>,
+}
+
+impl PluginsConfig {
+    /// Configuration section of the plugin with the given ID, if any
+    pub fn section(&self, plugin_id: &str) -> Option<&serde_json::Value> {
+        self.config.get(plugin_id)
+    }
+}
+
+impl Validatable for PluginsConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if let Some(directory) = &self.directory {
+            validate_required_string(directory, "directory", self.domain_name())?;
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "plugins"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugins_config_defaults() {
+        let config = PluginsConfig::default();
+        assert!(config.directory.is_none());
+        assert!(config.config.is_empty());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_empty_directory_is_rejected() {
+        let mut config: PluginsConfig = serde_yaml::from_str("directory: ./plugins").unwrap();
+        assert_eq!(config.directory.as_deref(), Some("./plugins"));
+        assert!(config.validate().is_ok());
+
+        config.directory = Some(String::new());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_plugin_sections() {
+        let yaml = r#"
+directory: ./plugins
+config:
+  metrics-exporter:
+    endpoint: http://localhost:9091
+    interval_seconds: 30
+"#;
+        let config: PluginsConfig = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(config.directory.as_deref(), Some("./plugins"));
+        assert_eq!(
+            config.section("metrics-exporter"),
+            Some(&serde_json::json!({"endpoint": "http://localhost:9091", "interval_seconds": 30}))
+        );
+        assert!(config.section("directory").is_none());
+
+        // Plugin sections only come from `config`, so a stray key is not mistaken for one
+        let config: PluginsConfig = serde_yaml::from_str("metrics-exporter: {}").unwrap();
+        assert!(config.section("metrics-exporter").is_none());
+        assert!(config.section("unknown").is_none());
+    }
+}
@@ -54,6 +54,10 @@ pub struct RegistrySourceConfig {
     /// Authentication name (references auth config)
     pub auth_name: Option<String>,
 
+    /// How sync treats tasks changed both in this source and in the database
+    #[serde(default)]
+    pub conflict_resolution: ConflictResolutionStrategy,
+
     /// Source-specific configuration
     #[serde(default)]
     pub config: SourceSpecificConfig,
@@ -75,6 +79,21 @@ pub enum RegistrySourceType {
     S3,
 }
 
+/// Conflict resolution strategy for tasks changed both in the registry and in the database
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolutionStrategy {
+    /// Overwrite the database copy with the registry version
+    #[default]
+    RegistryWins,
+    /// Keep the database copy
+    KeepLocal,
+    /// Keep whichever copy was modified most recently
+    PreferNewer,
+    /// Leave both copies untouched and report the conflict
+    Manual,
+}
+
 /// Source-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -133,6 +152,18 @@ pub struct HttpSourceConfig {
     /// User agent string
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+
+    /// Tasks requested per page from registries that support pagination
+    #[serde(default = "default_http_page_size")]
+    pub page_size: u32,
+
+    /// Only discover tasks carrying all of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Only discover tasks in this category
+    #[serde(default)]
+    pub category: Option<String>,
 }
 
 /// Git source configuration
@@ -140,9 +171,9 @@ pub struct HttpSourceConfig {
 #[serde(default)]
 pub struct GitSourceConfig {
     /// Git branch name (e.g., "main", "master", "develop")
-    /// Also accepts tags or commit hashes
-    #[serde(alias = "git_ref", default = "default_git_branch")]
-    pub branch: String,
+    /// Also accepts tags or commit hashes; the remote's default branch when unset
+    #[serde(alias = "git_ref", default)]
+    pub branch: Option<String>,
 
     /// Subdirectory within repository
     pub subdirectory: Option<String>,
@@ -154,10 +185,6 @@ pub struct GitSourceConfig {
     /// Clone depth for shallow clones
     pub depth: Option<u32>,
 
-    /// Sync strategy
-    #[serde(default)]
-    pub sync_strategy: GitSyncStrategy,
-
     /// Cleanup on error
     #[serde(default = "crate::domains::utils::default_true")]
     pub cleanup_on_error: bool,
@@ -188,21 +215,6 @@ pub struct GitSourceConfig {
     pub keep_history: bool,
 }
 
-/// Git sync strategy
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum GitSyncStrategy {
-    Clone,
-    Fetch,
-    Pull,
-}
-
-impl Default for GitSyncStrategy {
-    fn default() -> Self {
-        Self::Fetch
-    }
-}
-
 /// S3 source configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -302,6 +314,9 @@ impl Default for HttpSourceConfig {
             headers: HashMap::new(),
             verify_ssl: true,
             user_agent: default_user_agent(),
+            page_size: default_http_page_size(),
+            tags: Vec::new(),
+            category: None,
         }
     }
 }
@@ -309,11 +324,10 @@ impl Default for HttpSourceConfig {
 impl Default for GitSourceConfig {
     fn default() -> Self {
         Self {
-            branch: default_git_branch(),
+            branch: None,
             subdirectory: None,
             shallow: true,
             depth: Some(1),
-            sync_strategy: GitSyncStrategy::default(),
             cleanup_on_error: true,
             verify_signatures: false,
             allowed_refs: None,
@@ -398,6 +412,12 @@ impl RegistrySourceConfig {
         match self.source_type {
             RegistrySourceType::Http => {
                 validate_url(&self.uri, "uri", "registry")?;
+                if self.config.http.page_size == 0 {
+                    return Err(crate::error::ConfigError::DomainError {
+                        domain: "registry".to_string(),
+                        message: format!("{}: config.http.page_size must be greater than 0", context),
+                    });
+                }
             }
             RegistrySourceType::Filesystem => {
                 if !self.uri.starts_with("file://") && !std::path::Path::new(&self.uri).exists() {
@@ -569,8 +589,8 @@ fn default_user_agent() -> String {
     "Ratchet Registry Client/1.0".to_string()
 }
 
-fn default_git_branch() -> String {
-    "main".to_string()
+fn default_http_page_size() -> u32 {
+    100
 }
 
 fn default_git_timeout() -> Duration {
@@ -606,6 +626,7 @@ mod tests {
             polling_interval: None,
             enabled: true,
             auth_name: None,
+            conflict_resolution: ConflictResolutionStrategy::default(),
             config: SourceSpecificConfig::default(),
         };
         assert!(source.validate_with_context("test").is_ok());
@@ -618,6 +639,11 @@ mod tests {
         source.name = "test".to_string();
         source.uri = "not-a-url".to_string();
         assert!(source.validate_with_context("test").is_err());
+
+        // Test zero page size for HTTP source
+        source.uri = "https://example.com/registry".to_string();
+        source.config.http.page_size = 0;
+        assert!(source.validate_with_context("test").is_err());
     }
 
     #[test]
@@ -641,6 +667,7 @@ mod tests {
             polling_interval: None,
             enabled: true,
             auth_name: None,
+            conflict_resolution: ConflictResolutionStrategy::default(),
             config: SourceSpecificConfig::default(),
         };
         assert!(s3_source.validate_with_context("test").is_ok());
@@ -653,8 +680,43 @@ mod tests {
             polling_interval: None,
             enabled: true,
             auth_name: None,
+            conflict_resolution: ConflictResolutionStrategy::default(),
             config: SourceSpecificConfig::default(),
         };
         assert!(invalid_s3.validate_with_context("test").is_err());
     }
+
+    #[test]
+    fn test_source_conflict_resolution() {
+        let yaml = r#"
+name: shared-tasks
+uri: https://github.com/acme/tasks.git
+source_type: git
+polling_interval: 300
+conflict_resolution: manual
+"#;
+        let source: RegistrySourceConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(source.conflict_resolution, ConflictResolutionStrategy::Manual);
+
+        let yaml = r#"
+name: local-tasks
+uri: file://./tasks
+polling_interval: 300
+"#;
+        let source: RegistrySourceConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(source.conflict_resolution, ConflictResolutionStrategy::RegistryWins);
+    }
+
+    #[test]
+    fn test_git_branch_defaults_to_remote_default() {
+        let git: GitSourceConfig = serde_yaml::from_str("shallow: true").unwrap();
+        assert_eq!(git.branch, None);
+
+        // An explicit main or master is checked out as given
+        let git: GitSourceConfig = serde_yaml::from_str("branch: master").unwrap();
+        assert_eq!(git.branch.as_deref(), Some("master"));
+
+        let git: GitSourceConfig = serde_yaml::from_str("git_ref: v1.2.0").unwrap();
+        assert_eq!(git.branch.as_deref(), Some("v1.2.0"));
+    }
 }
@@ -3,8 +3,12 @@
 use crate::error::ConfigResult;
 use crate::validation::{validate_positive, validate_required_string, validate_url, Validatable};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
+/// Default maximum request body size, matching axum's extractor default
+pub const DEFAULT_MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
 /// Server configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -33,6 +37,18 @@ pub struct ServerConfig {
     #[serde(default)]
     pub rate_limit: RateLimitConfig,
 
+    /// GraphQL query limits
+    #[serde(default)]
+    pub graphql: GraphQLConfig,
+
+    /// Response compression
+    #[serde(default)]
+    pub compression: CompressionConfig,
+
+    /// Largest request body accepted by the REST API, in bytes
+    #[serde(default = "default_max_request_body_bytes")]
+    pub max_request_body_bytes: usize,
+
     /// TLS configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tls: Option<TlsConfig>,
@@ -90,17 +106,100 @@ pub struct RateLimitConfig {
     #[serde(default = "crate::domains::utils::default_true")]
     pub enabled: bool,
 
-    /// Requests per minute per IP
+    /// Requests per `time_window` for an anonymous client (per IP)
+    ///
+    /// Authenticated roles get fixed multiples of this: readonly 2x, user 4x,
+    /// admin 10x and service keys 20x.
     #[serde(default = "default_requests_per_minute")]
     pub requests_per_minute: u32,
 
-    /// Burst size
+    /// Burst size for an anonymous client, scaled for other roles the same way
     #[serde(default = "default_burst_size")]
     pub burst_size: u32,
 
-    /// Time window for rate limiting
+    /// Window `requests_per_minute` is counted over
     #[serde(with = "crate::domains::utils::serde_duration", default = "default_time_window")]
     pub time_window: Duration,
+
+    /// What requests are counted against
+    #[serde(default)]
+    pub key_by: RateLimitKeyBy,
+
+    /// Quotas for individual clients, overriding their role quota
+    ///
+    /// Keyed by client ID: `key:<api key id>`, `user:<user id>` or `ip:<address>`.
+    #[serde(default)]
+    pub client_quotas: HashMap<String, ClientQuotaConfig>,
+}
+
+/// What the rate limiter counts requests against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitKeyBy {
+    /// The authenticated API key, else the user, else the client IP
+    #[default]
+    Identity,
+    /// The client IP, whoever is authenticated
+    Ip,
+}
+
+/// Rate limit quota for a single client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientQuotaConfig {
+    /// Requests per minute
+    pub requests_per_minute: u32,
+
+    /// Burst size
+    pub burst_size: u32,
+
+    /// Requests per day (unlimited when null)
+    #[serde(default)]
+    pub daily_limit: Option<u32>,
+}
+
+/// GraphQL query limits, enforced before a query is executed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphQLConfig {
+    /// Maximum selection nesting depth (unlimited when null)
+    #[serde(default = "default_graphql_max_depth")]
+    pub max_depth: Option<usize>,
+
+    /// Maximum query complexity (unlimited when null)
+    #[serde(default = "default_graphql_max_complexity")]
+    pub max_complexity: Option<usize>,
+
+    /// Whether schema introspection is allowed
+    #[serde(default = "crate::domains::utils::default_true")]
+    pub enable_introspection: bool,
+}
+
+/// Response compression configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompressionConfig {
+    /// Whether responses are compressed
+    #[serde(default = "crate::domains::utils::default_true")]
+    pub enabled: bool,
+
+    /// Algorithms to use, most preferred first
+    #[serde(default = "default_compression_algorithms")]
+    pub algorithms: Vec<CompressionAlgorithm>,
+
+    /// Responses with a known size below this many bytes are sent uncompressed
+    #[serde(default = "default_compression_min_size_bytes")]
+    pub min_size_bytes: u16,
+}
+
+/// Response compression algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionAlgorithm {
+    /// Brotli (`br`)
+    #[serde(alias = "br")]
+    Brotli,
+    /// Gzip
+    Gzip,
 }
 
 /// TLS configuration
@@ -200,6 +299,9 @@ impl Default for ServerConfig {
             auth: None,
             cors: CorsConfig::default(),
             rate_limit: RateLimitConfig::default(),
+            graphql: GraphQLConfig::default(),
+            compression: CompressionConfig::default(),
+            max_request_body_bytes: default_max_request_body_bytes(),
             tls: None,
         }
     }
@@ -224,6 +326,28 @@ impl Default for RateLimitConfig {
             requests_per_minute: default_requests_per_minute(),
             burst_size: default_burst_size(),
             time_window: default_time_window(),
+            key_by: RateLimitKeyBy::default(),
+            client_quotas: HashMap::new(),
+        }
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            algorithms: default_compression_algorithms(),
+            min_size_bytes: default_compression_min_size_bytes(),
+        }
+    }
+}
+
+impl Default for GraphQLConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: default_graphql_max_depth(),
+            max_complexity: default_graphql_max_complexity(),
+            enable_introspection: true,
         }
     }
 }
@@ -232,10 +356,17 @@ impl Validatable for ServerConfig {
     fn validate(&self) -> ConfigResult<()> {
         validate_required_string(&self.bind_address, "bind_address", self.domain_name())?;
         validate_positive(self.port, "port", self.domain_name())?;
+        validate_positive(
+            self.max_request_body_bytes,
+            "max_request_body_bytes",
+            self.domain_name(),
+        )?;
 
         self.database.validate()?;
         self.cors.validate()?;
         self.rate_limit.validate()?;
+        self.graphql.validate()?;
+        self.compression.validate()?;
 
         if let Some(ref auth) = self.auth {
             auth.validate()?;
@@ -307,6 +438,18 @@ impl Validatable for RateLimitConfig {
             validate_positive(self.requests_per_minute, "requests_per_minute", self.domain_name())?;
             validate_positive(self.burst_size, "burst_size", self.domain_name())?;
             validate_positive(self.time_window.as_secs(), "time_window", self.domain_name())?;
+
+            for quota in self.client_quotas.values() {
+                validate_positive(
+                    quota.requests_per_minute,
+                    "client_quotas.requests_per_minute",
+                    self.domain_name(),
+                )?;
+                validate_positive(quota.burst_size, "client_quotas.burst_size", self.domain_name())?;
+                if let Some(daily_limit) = quota.daily_limit {
+                    validate_positive(daily_limit, "client_quotas.daily_limit", self.domain_name())?;
+                }
+            }
         }
 
         Ok(())
@@ -317,6 +460,37 @@ impl Validatable for RateLimitConfig {
     }
 }
 
+impl Validatable for GraphQLConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if let Some(max_depth) = self.max_depth {
+            validate_positive(max_depth, "max_depth", self.domain_name())?;
+        }
+        if let Some(max_complexity) = self.max_complexity {
+            validate_positive(max_complexity, "max_complexity", self.domain_name())?;
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "server.graphql"
+    }
+}
+
+impl Validatable for CompressionConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if self.enabled && self.algorithms.is_empty() {
+            return Err(self.validation_error("algorithms cannot be empty when compression is enabled"));
+        }
+
+        Ok(())
+    }
+
+    fn domain_name(&self) -> &'static str {
+        "server.compression"
+    }
+}
+
 impl Validatable for TlsConfig {
     fn validate(&self) -> ConfigResult<()> {
         validate_required_string(&self.cert_file, "cert_file", self.domain_name())?;
@@ -404,6 +578,26 @@ fn default_time_window() -> Duration {
     Duration::from_secs(60)
 }
 
+fn default_compression_algorithms() -> Vec<CompressionAlgorithm> {
+    vec![CompressionAlgorithm::Brotli, CompressionAlgorithm::Gzip]
+}
+
+fn default_compression_min_size_bytes() -> u16 {
+    1024
+}
+
+fn default_max_request_body_bytes() -> usize {
+    DEFAULT_MAX_BODY_BYTES
+}
+
+fn default_graphql_max_depth() -> Option<usize> {
+    Some(15)
+}
+
+fn default_graphql_max_complexity() -> Option<usize> {
+    Some(1000)
+}
+
 fn default_min_tls_version() -> String {
     "1.2".to_string()
 }
@@ -418,6 +612,7 @@ mod tests {
         assert_eq!(config.bind_address, "127.0.0.1");
         assert_eq!(config.port, 8080);
         assert!(config.rate_limit.enabled);
+        assert_eq!(config.max_request_body_bytes, 2 * 1024 * 1024);
     }
 
     #[test]
@@ -459,5 +654,71 @@ mod tests {
         rate_limit.enabled = false;
         rate_limit.requests_per_minute = 0; // Should be ok when disabled
         assert!(rate_limit.validate().is_ok());
+
+        // Test zero client quota
+        rate_limit = RateLimitConfig::default();
+        rate_limit.client_quotas.insert(
+            "key:ci".to_string(),
+            ClientQuotaConfig {
+                requests_per_minute: 0,
+                burst_size: 5,
+                daily_limit: None,
+            },
+        );
+        assert!(rate_limit.validate().is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_config_deserializes_client_quotas() {
+        let rate_limit: RateLimitConfig = serde_yaml::from_str(
+            r#"
+key_by: ip
+client_quotas:
+  "key:ci":
+    requests_per_minute: 600
+    burst_size: 50
+"#,
+        )
+        .unwrap();
+        assert_eq!(rate_limit.key_by, RateLimitKeyBy::Ip);
+        assert_eq!(rate_limit.client_quotas["key:ci"].requests_per_minute, 600);
+        assert_eq!(rate_limit.client_quotas["key:ci"].daily_limit, None);
+        assert_eq!(rate_limit.requests_per_minute, 60);
+    }
+
+    #[test]
+    fn test_graphql_config_validation() {
+        let mut graphql = GraphQLConfig::default();
+        assert_eq!(graphql.max_depth, Some(15));
+        assert_eq!(graphql.max_complexity, Some(1000));
+        assert!(graphql.validate().is_ok());
+
+        // Test zero depth
+        graphql.max_depth = Some(0);
+        assert!(graphql.validate().is_err());
+
+        // Test unlimited
+        graphql.max_depth = None;
+        graphql.max_complexity = None;
+        assert!(graphql.validate().is_ok());
+    }
+
+    #[test]
+    fn test_compression_config_validation() {
+        let mut compression: CompressionConfig = serde_yaml::from_str("algorithms: [gzip, br]").unwrap();
+        assert!(compression.enabled);
+        assert_eq!(
+            compression.algorithms,
+            vec![CompressionAlgorithm::Gzip, CompressionAlgorithm::Brotli]
+        );
+        assert_eq!(compression.min_size_bytes, 1024);
+        assert!(compression.validate().is_ok());
+
+        // Test no algorithms
+        compression.algorithms.clear();
+        assert!(compression.validate().is_err());
+
+        compression.enabled = false;
+        assert!(compression.validate().is_ok());
     }
 }
@@ -20,10 +20,30 @@ pub enum ConfigError {
     #[error("Failed to parse JSON: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    /// TOML parsing error
+    #[cfg(feature = "toml")]
+    #[error("Failed to parse TOML: {0}")]
+    TomlError(#[from] toml::de::Error),
+
+    /// Value that does not fit the field it is given for
+    #[error("Invalid configuration at '{path}': {message}")]
+    InvalidValue { path: String, message: String },
+
+    /// Keys the configuration does not recognise, rejected in strict mode
+    #[error(
+        "Unknown configuration keys: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+    )]
+    UnknownKeys(Vec<crate::validation::ConfigWarning>),
+
     /// Validation error
     #[error("Invalid configuration: {0}")]
     ValidationError(String),
 
+    /// Configuration files include each other in a cycle
+    #[error("Circular config include: {0}")]
+    CircularInclude(String),
+
     /// Environment variable error
     #[error("Environment variable error: {0}")]
     EnvError(String),
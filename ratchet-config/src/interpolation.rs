@@ -0,0 +1,257 @@
+//! Environment variable interpolation in configuration values
+//!
+//! String values may reference environment variables as `${VAR}`, or
+//! `${VAR:-default}` to fall back to `default` when `VAR` is unset or empty.
+//! `$${` produces a literal `${`. Keys are never interpolated, and neither is
+//! anything under a `schema` or `*_schema` key, so JSON schema bodies embedded
+//! in a configuration are left as written.
+//!
+//! Interpolation works on the parsed value tree, so every interpolated value
+//! is a string. [`Interpolated`] remembers where they are, letting the loader
+//! turn one into a number or boolean when the field it fills expects that type.
+
+use crate::error::{ConfigError, ConfigResult};
+use serde_yaml::Value as YamlValue;
+
+/// Key paths of the values that were interpolated
+#[derive(Debug, Default)]
+pub struct Interpolated {
+    paths: Vec<Vec<String>>,
+}
+
+impl Interpolated {
+    /// Replace the interpolated string at `path` with the number or boolean
+    /// it spells, returning whether it was replaced
+    ///
+    /// Sequence indices in `path` are written as decimal numbers.
+    pub fn retype(&self, value: &mut YamlValue, path: &[String]) -> bool {
+        if !self.paths.iter().any(|interpolated| interpolated == path) {
+            return false;
+        }
+        let Some(target) = value_at_mut(value, path) else {
+            return false;
+        };
+        match target.as_str().map(serde_yaml::from_str::<YamlValue>) {
+            Some(Ok(scalar @ (YamlValue::Number(_) | YamlValue::Bool(_)))) => {
+                *target = scalar;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Replace environment variable references in every string value of `value`
+pub fn interpolate_env(value: &mut YamlValue) -> ConfigResult<Interpolated> {
+    let mut interpolated = Interpolated::default();
+    let lookup = |name: &str| std::env::var(name).ok();
+    interpolate_value(value, &mut Vec::new(), &mut interpolated, &lookup)?;
+    Ok(interpolated)
+}
+
+fn interpolate_value(
+    value: &mut YamlValue,
+    path: &mut Vec<String>,
+    interpolated: &mut Interpolated,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> ConfigResult<()> {
+    match value {
+        YamlValue::String(text) => {
+            if let Some(result) = interpolate_str(text, lookup).map_err(|e| at_path(path, e))? {
+                *value = YamlValue::String(result);
+                interpolated.paths.push(path.clone());
+            }
+        }
+        YamlValue::Mapping(mapping) => {
+            for (key, value) in mapping.iter_mut() {
+                let key = key.as_str().map(str::to_string).unwrap_or_default();
+                if is_schema_key(&key) {
+                    continue;
+                }
+                path.push(key);
+                interpolate_value(value, path, interpolated, lookup)?;
+                path.pop();
+            }
+        }
+        YamlValue::Sequence(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                path.push(index.to_string());
+                interpolate_value(item, path, interpolated, lookup)?;
+                path.pop();
+            }
+        }
+        YamlValue::Tagged(tagged) => interpolate_value(&mut tagged.value, path, interpolated, lookup)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The value at `path`, following mapping keys and sequence indices
+fn value_at_mut<'a>(value: &'a mut YamlValue, path: &[String]) -> Option<&'a mut YamlValue> {
+    path.iter().try_fold(value, |value, key| match value {
+        YamlValue::Sequence(items) => key.parse::<usize>().ok().and_then(|index| items.get_mut(index)),
+        value => value.get_mut(key.as_str()),
+    })
+}
+
+fn is_schema_key(key: &str) -> bool {
+    key == "schema" || key.ends_with("_schema")
+}
+
+fn at_path(path: &[String], message: String) -> ConfigError {
+    if path.is_empty() {
+        ConfigError::EnvError(message)
+    } else {
+        ConfigError::EnvError(format!("{} in '{}'", message, path.join(".")))
+    }
+}
+
+/// Interpolated `text`, or `None` when it holds no references
+fn interpolate_str(text: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Result<Option<String>, String> {
+    if !text.contains("${") {
+        return Ok(None);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            // `$${` escapes a literal `${`
+            result.push_str(&rest[..start - 1]);
+            result.push_str("${");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        result.push_str(&rest[..start]);
+
+        let end = rest[start..]
+            .find('}')
+            .map(|end| start + end)
+            .ok_or_else(|| format!("Unterminated variable reference in \"{}\"", text))?;
+        let reference = &rest[start + 2..end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+        if !is_variable_name(name) {
+            return Err(format!("Invalid variable reference \"${{{}}}\"", reference));
+        }
+
+        match (
+            lookup(name).filter(|value| !value.is_empty() || default.is_none()),
+            default,
+        ) {
+            (Some(value), _) => result.push_str(&value),
+            (None, Some(default)) => result.push_str(default),
+            (None, None) => return Err(format!("Environment variable {} is not set", name)),
+        }
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+
+    Ok(Some(result))
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "DB_HOST" => Some("db.internal".to_string()),
+            "PORT" => Some("5432".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    fn interpolate(yaml: &str) -> ConfigResult<(YamlValue, Interpolated)> {
+        let mut value = serde_yaml::from_str(yaml).unwrap();
+        let mut interpolated = Interpolated::default();
+        interpolate_value(&mut value, &mut Vec::new(), &mut interpolated, &lookup)?;
+        Ok((value, interpolated))
+    }
+
+    fn path(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_interpolates_variables_and_defaults() {
+        let (value, _) = interpolate(
+            r#"
+url: "postgres://${DB_HOST}:${PORT}/ratchet"
+user: "${DB_USER:-ratchet}"
+password: "${EMPTY:-fallback}"
+port: ${PORT}
+literal: "$${DB_HOST}"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(value["url"], "postgres://db.internal:5432/ratchet");
+        assert_eq!(value["user"], "ratchet");
+        assert_eq!(value["password"], "fallback");
+        assert_eq!(value["port"], "5432");
+        assert_eq!(value["literal"], "${DB_HOST}");
+    }
+
+    #[test]
+    fn test_undefined_variable_is_an_error() {
+        let error = interpolate("server:\n  database:\n    url: \"${MISSING}\"\n").unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("MISSING"));
+        assert!(message.contains("server.database.url"));
+
+        assert!(interpolate("url: \"${DB_HOST\"").is_err());
+        assert!(interpolate("url: \"${not valid}\"").is_err());
+    }
+
+    #[test]
+    fn test_schema_bodies_are_not_interpolated() {
+        let (value, _) = interpolate(
+            r#"
+input_schema:
+  properties:
+    template: { default: "${MISSING}" }
+schema: { pattern: "^\\${[A-Z]+}$" }
+output_schema:
+  default: ${PORT}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(value["input_schema"]["properties"]["template"]["default"], "${MISSING}");
+        assert_eq!(value["output_schema"]["default"], "${PORT}");
+    }
+
+    #[test]
+    fn test_only_interpolated_values_are_retyped() {
+        let (mut value, interpolated) = interpolate(
+            r#"
+port: ${PORT}
+literal_port: "5432"
+ports:
+  - "${PORT}"
+url: "${DB_HOST}"
+"#,
+        )
+        .unwrap();
+
+        assert!(interpolated.retype(&mut value, &path(&["port"])));
+        assert_eq!(value["port"], 5432);
+        assert!(interpolated.retype(&mut value, &path(&["ports", "0"])));
+        assert_eq!(value["ports"][0], 5432);
+
+        assert!(!interpolated.retype(&mut value, &path(&["literal_port"])));
+        assert_eq!(value["literal_port"], "5432");
+        assert!(!interpolated.retype(&mut value, &path(&["url"])));
+        assert_eq!(value["url"], "db.internal");
+    }
+}
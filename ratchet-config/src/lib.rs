@@ -3,7 +3,9 @@
 //! This crate provides modular configuration split by functional domains,
 //! with validation, defaults, and environment variable support.
 
+pub mod diff;
 pub mod error;
+pub mod interpolation;
 pub mod loader;
 pub mod validation;
 
@@ -13,13 +15,15 @@ pub mod validation;
 pub mod domains;
 
 // Re-export main types
+pub use diff::{diff_configs, ConfigChange};
 pub use error::{ConfigError, ConfigResult};
 pub use loader::ConfigLoader;
 
 // Re-export domain configurations
 pub use domains::{
-    cache::CacheConfig, database::DatabaseConfig, execution::ExecutionConfig, http::HttpConfig, logging::LoggingConfig,
-    mcp::McpConfig, output::OutputConfig, registry::RegistryConfig, server::ServerConfig, RatchetConfig,
+    alerting::AlertingConfig, audit::AuditConfig, cache::CacheConfig, database::DatabaseConfig,
+    execution::ExecutionConfig, features::FeatureFlags, http::HttpConfig, logging::LoggingConfig, mcp::McpConfig,
+    output::OutputConfig, plugins::PluginsConfig, registry::RegistryConfig, server::ServerConfig, RatchetConfig,
 };
 
 // Re-export utilities
@@ -2,12 +2,20 @@
 
 use crate::domains::RatchetConfig;
 use crate::error::{ConfigError, ConfigResult};
-use std::path::Path;
+use crate::interpolation::{interpolate_env, Interpolated};
+use crate::validation::{find_unknown_keys, ConfigWarning};
+use serde_yaml::Value as YamlValue;
+use std::path::{Path, PathBuf};
+
+/// Top-level key listing the files a configuration file includes
+const INCLUDES_KEY: &str = "includes";
 
 /// Configuration loader with environment variable support
 pub struct ConfigLoader {
     /// Environment variable prefix
     prefix: String,
+    /// Reject unknown keys instead of warning about them
+    strict: bool,
 }
 
 impl ConfigLoader {
@@ -15,18 +23,58 @@ impl ConfigLoader {
     pub fn new() -> Self {
         Self {
             prefix: "RATCHET".to_string(),
+            strict: false,
         }
     }
 
     /// Create a new config loader with custom prefix
     pub fn with_prefix(prefix: impl Into<String>) -> Self {
-        Self { prefix: prefix.into() }
+        Self {
+            prefix: prefix.into(),
+            strict: false,
+        }
+    }
+
+    /// Fail on unknown keys in configuration files instead of warning about them
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
     }
 
     /// Load configuration from a YAML file with environment overrides
+    ///
+    /// Files with a `.toml` extension are read as TOML, which requires the
+    /// `toml` feature.
+    ///
+    /// A file can list other files under a top-level `includes` key, with paths
+    /// relative to the including file. Included files are deep-merged in order,
+    /// each overriding the ones before it, and the including file overrides them
+    /// all. Environment variables take precedence over every file.
+    ///
+    /// String values can reference environment variables as `${VAR}` or
+    /// `${VAR:-default}`; see [`crate::interpolation`].
+    ///
+    /// Unknown keys are logged as warnings, or rejected in [strict](Self::strict)
+    /// mode.
     pub fn from_file(&self, path: impl AsRef<Path>) -> ConfigResult<RatchetConfig> {
-        let content = std::fs::read_to_string(path)?;
-        let mut config: RatchetConfig = serde_yaml::from_str(&content)?;
+        let (config, warnings) = self.from_file_with_warnings(path)?;
+        for warning in &warnings {
+            log::warn!("Configuration {}", warning);
+        }
+        Ok(config)
+    }
+
+    /// Load configuration from a file like [`from_file`](Self::from_file),
+    /// returning warnings about unknown keys instead of logging them
+    pub fn from_file_with_warnings(&self, path: impl AsRef<Path>) -> ConfigResult<(RatchetConfig, Vec<ConfigWarning>)> {
+        let mut value = Self::load_file_value(path.as_ref(), &mut Vec::new())?;
+        let interpolated = interpolate_env(&mut value)?;
+        let mut config = deserialize_config(&mut value, &interpolated)?;
+
+        let warnings = find_unknown_keys(&value, &serde_yaml::to_value(&config)?);
+        if self.strict && !warnings.is_empty() {
+            return Err(ConfigError::UnknownKeys(warnings));
+        }
 
         // Apply environment variable overrides
         self.apply_env_overrides(&mut config)?;
@@ -34,7 +82,69 @@ impl ConfigLoader {
         // Validate all domains
         config.validate_all()?;
 
-        Ok(config)
+        Ok((config, warnings))
+    }
+
+    /// Read a configuration file and the files it includes into one value
+    ///
+    /// `chain` holds the files currently being included, to detect cycles.
+    fn load_file_value(path: &Path, chain: &mut Vec<PathBuf>) -> ConfigResult<YamlValue> {
+        let canonical = path.canonicalize()?;
+        if let Some(start) = chain.iter().position(|included| *included == canonical) {
+            let cycle: Vec<String> = chain[start..]
+                .iter()
+                .chain(std::iter::once(&canonical))
+                .map(|file| file.display().to_string())
+                .collect();
+            return Err(ConfigError::CircularInclude(cycle.join(" -> ")));
+        }
+
+        let content = std::fs::read_to_string(path)?;
+        let is_toml = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+        let mut value = if is_toml {
+            Self::parse_toml(&content)?
+        } else {
+            serde_yaml::from_str(&content)?
+        };
+
+        let includes = match &mut value {
+            YamlValue::Mapping(mapping) => mapping.remove(INCLUDES_KEY),
+            _ => None,
+        };
+        let Some(includes) = includes else {
+            return Ok(value);
+        };
+        let includes: Vec<PathBuf> = serde_yaml::from_value(includes).map_err(|_| {
+            ConfigError::ValidationError(format!(
+                "'{}' in {} must be a list of file paths",
+                INCLUDES_KEY,
+                path.display()
+            ))
+        })?;
+
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        let mut merged = YamlValue::Mapping(Default::default());
+        chain.push(canonical);
+        for include in includes {
+            let included = Self::load_file_value(&base_dir.join(include), chain)?;
+            merge_values(&mut merged, included);
+        }
+        chain.pop();
+        merge_values(&mut merged, value);
+
+        Ok(merged)
+    }
+
+    #[cfg(feature = "toml")]
+    fn parse_toml(content: &str) -> ConfigResult<YamlValue> {
+        Ok(toml::from_str(content)?)
+    }
+
+    #[cfg(not(feature = "toml"))]
+    fn parse_toml(_content: &str) -> ConfigResult<YamlValue> {
+        Err(ConfigError::ValidationError(
+            "TOML configuration files require the 'toml' feature".to_string(),
+        ))
     }
 
     /// Load configuration from environment variables only
@@ -88,6 +198,12 @@ impl ConfigLoader {
                 .map_err(|e| ConfigError::EnvError(format!("Invalid VALIDATE_SCHEMAS: {}", e)))?;
         }
 
+        if let Ok(enforcement) = self.get_env_var("OUTPUT_SCHEMA_ENFORCEMENT") {
+            config.output_schema_enforcement = enforcement
+                .parse()
+                .map_err(|e| ConfigError::EnvError(format!("Invalid OUTPUT_SCHEMA_ENFORCEMENT: {}", e)))?;
+        }
+
         Ok(())
     }
 
@@ -156,6 +272,12 @@ impl ConfigLoader {
                 .map_err(|e| ConfigError::EnvError(format!("Invalid OUTPUT_MAX_CONCURRENT_DELIVERIES: {}", e)))?;
         }
 
+        if let Ok(max_destinations) = self.get_env_var("OUTPUT_MAX_DESTINATIONS_PER_JOB") {
+            config.max_destinations_per_job = max_destinations
+                .parse()
+                .map_err(|e| ConfigError::EnvError(format!("Invalid OUTPUT_MAX_DESTINATIONS_PER_JOB: {}", e)))?;
+        }
+
         if let Ok(timeout) = self.get_env_var("OUTPUT_DEFAULT_TIMEOUT") {
             let seconds: u64 = timeout
                 .parse()
@@ -217,3 +339,83 @@ impl Default for ConfigLoader {
         Self::new()
     }
 }
+
+/// Deserialize a configuration value, reporting failures with the dotted path
+/// of the offending key and the value found there
+/// Deserialize `value`, turning interpolated strings into numbers or booleans
+/// where the field they fill expects one
+fn deserialize_config(value: &mut YamlValue, interpolated: &Interpolated) -> ConfigResult<RatchetConfig> {
+    loop {
+        let e = match serde_path_to_error::deserialize(&*value) {
+            Ok(config) => return Ok(config),
+            Err(e) => e,
+        };
+        if interpolated.retype(value, &path_keys(e.path())) {
+            continue;
+        }
+
+        let mut message = e.inner().to_string();
+        let found = value_at(value, e.path()).and_then(describe_scalar);
+        if let Some(found) = found.filter(|found| !message.contains(found.as_str())) {
+            message.push_str(&format!(" (found {})", found));
+        }
+        return Err(ConfigError::InvalidValue {
+            path: e.path().to_string(),
+            message,
+        });
+    }
+}
+
+/// The mapping keys and sequence indices along `path`
+fn path_keys(path: &serde_path_to_error::Path) -> Vec<String> {
+    use serde_path_to_error::Segment;
+
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Map { key } => Some(key.clone()),
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Enum { .. } | Segment::Unknown => None,
+        })
+        .collect()
+}
+
+/// The value at `path` within `value`, if the path can be followed
+fn value_at<'a>(value: &'a YamlValue, path: &serde_path_to_error::Path) -> Option<&'a YamlValue> {
+    use serde_path_to_error::Segment;
+
+    path.iter().try_fold(value, |value, segment| match segment {
+        Segment::Map { key } => value.get(key.as_str()),
+        Segment::Seq { index } => value.get(*index),
+        Segment::Enum { .. } | Segment::Unknown => Some(value),
+    })
+}
+
+fn describe_scalar(value: &YamlValue) -> Option<String> {
+    match value {
+        YamlValue::String(text) => Some(format!("\"{}\"", text)),
+        YamlValue::Number(number) => Some(number.to_string()),
+        YamlValue::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    }
+}
+
+/// Deep-merge `overlay` into `base`
+///
+/// Mappings are merged key by key; any other value in `overlay` replaces the one
+/// in `base`. An empty overlay file leaves `base` unchanged.
+fn merge_values(base: &mut YamlValue, overlay: YamlValue) {
+    match (base, overlay) {
+        (_, YamlValue::Null) => {}
+        (YamlValue::Mapping(base), YamlValue::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) if existing.is_mapping() && value.is_mapping() => merge_values(existing, value),
+                    _ => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
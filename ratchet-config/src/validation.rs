@@ -221,6 +221,10 @@ pub fn validate_config(config: &crate::domains::RatchetConfig) -> ConfigResult<(
     config.logging.validate()?;
     config.cache.validate()?;
     config.output.validate()?;
+    config.features.validate()?;
+    config.alerting.validate()?;
+    config.audit.validate()?;
+    config.plugins.validate()?;
 
     // Validate optional domains
     if let Some(server) = &config.server {
@@ -237,3 +241,142 @@ pub fn validate_config(config: &crate::domains::RatchetConfig) -> ConfigResult<(
 
     Ok(())
 }
+
+/// Keys accepted through `#[serde(alias)]`, with the field they stand for
+///
+/// Serde does not expose aliases at runtime, so a new alias must be added here
+/// as well.
+const KEY_ALIASES: &[(&str, &str)] = &[("git_ref", "branch")];
+
+/// A configuration problem that does not stop the configuration loading
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigWarning {
+    /// Dotted path of the offending key, e.g. `server.database.url`
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Find keys in `input` that did not survive deserialization
+///
+/// `known` is the deserialized configuration serialized back to a value; any
+/// key present in `input` but missing from `known` was ignored by serde, which
+/// usually means it is misspelt. Keys with empty values are not reported.
+pub fn find_unknown_keys(input: &serde_yaml::Value, known: &serde_yaml::Value) -> Vec<ConfigWarning> {
+    let mut warnings = Vec::new();
+    collect_unknown_keys(input, known, &mut String::new(), &mut warnings);
+    warnings
+}
+
+fn collect_unknown_keys(
+    input: &serde_yaml::Value,
+    known: &serde_yaml::Value,
+    path: &mut String,
+    warnings: &mut Vec<ConfigWarning>,
+) {
+    use serde_yaml::Value;
+
+    match (input, known) {
+        (Value::Mapping(input), Value::Mapping(known)) => {
+            for (key, value) in input {
+                let Some(key) = key.as_str() else { continue };
+                let parent_len = path.len();
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+
+                let known_key = KEY_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == key)
+                    .map_or(key, |(_, field)| *field);
+                match known.get(known_key) {
+                    Some(known_value) => collect_unknown_keys(value, known_value, path, warnings),
+                    None if !is_empty_value(value) => {
+                        let mut message = "unknown key, ignored".to_string();
+                        if let Some(suggestion) = closest_key(key, known) {
+                            message.push_str(&format!(" (did you mean '{}'?)", suggestion));
+                        }
+                        warnings.push(ConfigWarning {
+                            path: path.clone(),
+                            message,
+                        });
+                    }
+                    None => {}
+                }
+                path.truncate(parent_len);
+            }
+        }
+        (Value::Sequence(input), Value::Sequence(known)) => {
+            for (index, (value, known_value)) in input.iter().zip(known).enumerate() {
+                let parent_len = path.len();
+                path.push_str(&format!("[{}]", index));
+                collect_unknown_keys(value, known_value, path, warnings);
+                path.truncate(parent_len);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_empty_value(value: &serde_yaml::Value) -> bool {
+    match value {
+        serde_yaml::Value::Null => true,
+        serde_yaml::Value::Sequence(items) => items.is_empty(),
+        serde_yaml::Value::Mapping(mapping) => mapping.is_empty(),
+        _ => false,
+    }
+}
+
+/// The key in `known` within two edits of `key`, if any
+fn closest_key<'a>(key: &str, known: &'a serde_yaml::Mapping) -> Option<&'a str> {
+    known
+        .keys()
+        .filter_map(|candidate| candidate.as_str())
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::registry::GitSourceConfig;
+
+    /// Warnings for `yaml` read as a [`GitSourceConfig`]
+    fn git_source_warnings(yaml: &str) -> Vec<ConfigWarning> {
+        let input: serde_yaml::Value = serde_yaml::from_str(yaml).unwrap();
+        let config: GitSourceConfig = serde_yaml::from_value(input.clone()).unwrap();
+        find_unknown_keys(&input, &serde_yaml::to_value(&config).unwrap())
+    }
+
+    #[test]
+    fn test_aliased_keys_are_not_reported_as_unknown() {
+        assert!(git_source_warnings("git_ref: v1.2.0").is_empty());
+        assert!(git_source_warnings("branch: main").is_empty());
+
+        let warnings = git_source_warnings("git_reff: v1.2.0");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "git_reff");
+    }
+}
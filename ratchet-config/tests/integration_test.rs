@@ -11,6 +11,31 @@ fn test_default_config_validation() {
     assert!(config.validate_all().is_ok());
 }
 
+#[test]
+fn test_config_presets() {
+    let dev = RatchetConfig::preset("dev").unwrap();
+    assert_eq!(dev.logging.level, LogLevel::Debug);
+    assert!(dev.validate_all().is_ok());
+
+    let minimal = RatchetConfig::preset("minimal").unwrap();
+    assert!(minimal.server.is_none() && minimal.mcp.is_none());
+
+    let production = RatchetConfig::preset("production").unwrap();
+    assert_eq!(production.logging.format, LogFormat::Json);
+    assert!(!production.server.as_ref().unwrap().graphql.enable_introspection);
+    assert!(production.server.unwrap().auth.is_none());
+
+    let enterprise = RatchetConfig::preset("enterprise").unwrap();
+    let auth = enterprise.server.unwrap().auth.unwrap();
+    assert_eq!(auth.jwt_secret, "${RATCHET_JWT_SECRET}");
+
+    let claude = RatchetConfig::preset("claude").unwrap();
+    assert!(claude.server.is_none());
+    assert_eq!(claude.mcp.unwrap().transport, "stdio");
+
+    assert!(RatchetConfig::preset("staging").is_err());
+}
+
 #[test]
 fn test_config_loader_from_env() {
     let vars = vec![
@@ -207,6 +232,195 @@ fn test_generate_sample_config() {
     assert!(parsed.validate_all().is_ok());
 }
 
+#[test]
+fn test_config_includes_are_merged() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("base.yaml"),
+        r#"
+http:
+  timeout: 45
+  user_agent: "Base Agent"
+execution:
+  max_concurrent_tasks: 5
+"#,
+    )
+    .unwrap();
+    std::fs::create_dir(dir.path().join("overlays")).unwrap();
+    std::fs::write(
+        dir.path().join("overlays/production.yaml"),
+        r#"
+http:
+  timeout: 90
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("ratchet.yaml"),
+        r#"
+includes:
+  - base.yaml
+  - overlays/production.yaml
+execution:
+  max_concurrent_tasks: 10
+"#,
+    )
+    .unwrap();
+
+    with_vars(vec![("RATCHET_LOG_LEVEL", Some("debug"))], || {
+        let config = ConfigLoader::new().from_file(dir.path().join("ratchet.yaml")).unwrap();
+
+        // Later includes override earlier ones, keeping keys they don't set
+        assert_eq!(config.http.timeout, Duration::from_secs(90));
+        assert_eq!(config.http.user_agent, "Base Agent");
+        // The including file overrides its includes
+        assert_eq!(config.execution.max_concurrent_tasks, 10);
+        // Environment variables override every file
+        assert_eq!(config.logging.level, LogLevel::Debug);
+    });
+
+    // Environment variables also win over included values
+    with_vars(vec![("RATCHET_HTTP_TIMEOUT", Some("15"))], || {
+        let config = ConfigLoader::new().from_file(dir.path().join("ratchet.yaml")).unwrap();
+        assert_eq!(config.http.timeout, Duration::from_secs(15));
+    });
+}
+
+#[test]
+fn test_circular_config_includes_are_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.yaml"), "includes: [b.yaml]\n").unwrap();
+    std::fs::write(dir.path().join("b.yaml"), "includes: [a.yaml]\n").unwrap();
+
+    let error = ConfigLoader::new().from_file(dir.path().join("a.yaml")).unwrap_err();
+    match error {
+        ConfigError::CircularInclude(cycle) => {
+            assert!(cycle.contains("a.yaml"));
+            assert!(cycle.contains("b.yaml"));
+        }
+        other => panic!("expected a circular include error, got {:?}", other),
+    }
+
+    // A file including itself is a cycle too
+    std::fs::write(dir.path().join("self.yaml"), "includes: [self.yaml]\n").unwrap();
+    assert!(matches!(
+        ConfigLoader::new().from_file(dir.path().join("self.yaml")),
+        Err(ConfigError::CircularInclude(_))
+    ));
+}
+
+#[test]
+fn test_config_values_interpolate_environment_variables() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ratchet.yaml");
+    std::fs::write(
+        &path,
+        r#"
+http:
+  user_agent: "ratchet/${AGENT_VERSION} (${AGENT_ENV:-dev})"
+execution:
+  max_concurrent_tasks: ${MAX_TASKS}
+"#,
+    )
+    .unwrap();
+
+    with_vars(
+        vec![
+            ("AGENT_VERSION", Some("1.2")),
+            ("AGENT_ENV", None),
+            ("MAX_TASKS", Some("7")),
+        ],
+        || {
+            let config = ConfigLoader::new().from_file(&path).unwrap();
+            assert_eq!(config.http.user_agent, "ratchet/1.2 (dev)");
+            assert_eq!(config.execution.max_concurrent_tasks, 7);
+        },
+    );
+
+    // A variable without a default must be set
+    with_vars(vec![("AGENT_VERSION", None::<&str>), ("MAX_TASKS", Some("7"))], || {
+        let error = ConfigLoader::new().from_file(&path).unwrap_err();
+        assert!(matches!(error, ConfigError::EnvError(ref message) if message.contains("AGENT_VERSION")));
+    });
+
+    // Interpolated values take the type of the field they fill, quoted or not
+    std::fs::write(
+        &path,
+        "http:\n  user_agent: ${AGENT_VERSION}\nexecution:\n  max_concurrent_tasks: \"${MAX_TASKS}\"\n",
+    )
+    .unwrap();
+    with_vars(vec![("AGENT_VERSION", Some("2")), ("MAX_TASKS", Some("3"))], || {
+        let config = ConfigLoader::new().from_file(&path).unwrap();
+        assert_eq!(config.http.user_agent, "2");
+        assert_eq!(config.execution.max_concurrent_tasks, 3);
+    });
+}
+
+#[test]
+fn test_invalid_values_report_their_key_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ratchet.yaml");
+    std::fs::write(&path, "execution:\n  max_concurrent_tasks: lots\n").unwrap();
+
+    match ConfigLoader::new().from_file(&path).unwrap_err() {
+        ConfigError::InvalidValue { path, message } => {
+            assert_eq!(path, "execution.max_concurrent_tasks");
+            assert!(message.contains("\"lots\""), "message: {}", message);
+        }
+        other => panic!("expected an invalid value error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_keys_warn_or_fail_in_strict_mode() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ratchet.yaml");
+    std::fs::write(
+        &path,
+        r#"
+http:
+  timeout: 30
+  user_agnet: "Typo Agent"
+"#,
+    )
+    .unwrap();
+
+    let (config, warnings) = ConfigLoader::new().from_file_with_warnings(&path).unwrap();
+    assert_eq!(config.http.timeout, Duration::from_secs(30));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "http.user_agnet");
+    assert!(warnings[0].message.contains("did you mean 'user_agent'"));
+
+    match ConfigLoader::new().strict(true).from_file(&path).unwrap_err() {
+        ConfigError::UnknownKeys(keys) => assert_eq!(keys[0].path, "http.user_agnet"),
+        other => panic!("expected an unknown keys error, got {:?}", other),
+    }
+
+    // Known keys, including optional sections, produce no warnings
+    std::fs::write(&path, "http:\n  timeout: 30\nserver:\n  port: 9090\n").unwrap();
+    let (_, warnings) = ConfigLoader::new().strict(true).from_file_with_warnings(&path).unwrap();
+    assert!(warnings.is_empty(), "unexpected warnings: {:?}", warnings);
+}
+
+#[test]
+fn test_diff_from_default_lists_overridden_settings() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ratchet.yaml");
+    std::fs::write(&path, "http:\n  timeout: 90\nexecution:\n  max_concurrent_tasks: 12\n").unwrap();
+
+    // A file without settings matches the defaults
+    std::fs::write(dir.path().join("empty.yaml"), "{}\n").unwrap();
+    let empty = ConfigLoader::new().from_file(dir.path().join("empty.yaml")).unwrap();
+    assert!(empty.diff_from_default().unwrap().is_empty());
+
+    let config = ConfigLoader::new().from_file(&path).unwrap();
+    let changes = config.diff_from_default().unwrap();
+    let paths: Vec<&str> = changes.iter().map(|change| change.path.as_str()).collect();
+    assert_eq!(paths, vec!["execution.max_concurrent_tasks", "http.timeout"]);
+    assert_eq!(changes[1].old, serde_json::json!(30));
+    assert_eq!(changes[1].new, serde_json::json!(90));
+}
+
 #[cfg(test)]
 mod output_destination_tests {
     use super::*;
@@ -0,0 +1,176 @@
+//! Structural diffing of JSON values
+//!
+//! Compares an expected and an actual JSON value and reports which paths were
+//! added, removed or changed. Paths are JSON pointers (RFC 6901), e.g.
+//! `/user/address/city` or `/items/0`; the root value is the empty pointer.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// A value present on only one side of a diff
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonDiffEntry {
+    /// JSON pointer to the value
+    pub path: String,
+    /// The value at that path
+    pub value: JsonValue,
+}
+
+/// A value present on both sides of a diff but with different contents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonChange {
+    /// JSON pointer to the value
+    pub path: String,
+    /// Value on the expected side
+    pub expected: JsonValue,
+    /// Value on the actual side
+    pub actual: JsonValue,
+}
+
+/// Structured difference between an expected and an actual JSON value
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JsonDiff {
+    /// Paths only present in the actual value
+    pub added: Vec<JsonDiffEntry>,
+    /// Paths only present in the expected value
+    pub removed: Vec<JsonDiffEntry>,
+    /// Paths present in both with different values
+    pub changed: Vec<JsonChange>,
+}
+
+impl JsonDiff {
+    /// Diff `actual` against `expected`
+    pub fn between(expected: &JsonValue, actual: &JsonValue) -> Self {
+        let mut diff = Self::default();
+        diff.compare(String::new(), expected, actual);
+        diff
+    }
+
+    /// Whether the two values were identical
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Total number of differing paths
+    pub fn len(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+
+    fn compare(&mut self, path: String, expected: &JsonValue, actual: &JsonValue) {
+        match (expected, actual) {
+            (JsonValue::Object(expected), JsonValue::Object(actual)) => {
+                for (key, expected_value) in expected {
+                    let child = format!("{}/{}", path, escape_pointer_token(key));
+                    match actual.get(key) {
+                        Some(actual_value) => self.compare(child, expected_value, actual_value),
+                        None => self.removed.push(JsonDiffEntry {
+                            path: child,
+                            value: expected_value.clone(),
+                        }),
+                    }
+                }
+
+                for (key, actual_value) in actual {
+                    if !expected.contains_key(key) {
+                        self.added.push(JsonDiffEntry {
+                            path: format!("{}/{}", path, escape_pointer_token(key)),
+                            value: actual_value.clone(),
+                        });
+                    }
+                }
+            }
+            (JsonValue::Array(expected), JsonValue::Array(actual)) => {
+                for (index, expected_value) in expected.iter().enumerate() {
+                    let child = format!("{}/{}", path, index);
+                    match actual.get(index) {
+                        Some(actual_value) => self.compare(child, expected_value, actual_value),
+                        None => self.removed.push(JsonDiffEntry {
+                            path: child,
+                            value: expected_value.clone(),
+                        }),
+                    }
+                }
+
+                for (index, actual_value) in actual.iter().enumerate().skip(expected.len()) {
+                    self.added.push(JsonDiffEntry {
+                        path: format!("{}/{}", path, index),
+                        value: actual_value.clone(),
+                    });
+                }
+            }
+            _ if expected != actual => self.changed.push(JsonChange {
+                path,
+                expected: expected.clone(),
+                actual: actual.clone(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// Escape an object key for use as a JSON pointer token
+fn escape_pointer_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_identical_values_have_no_diff() {
+        let value = json!({"a": [1, 2, {"b": null}]});
+        let diff = JsonDiff::between(&value, &value);
+        assert!(diff.is_empty());
+        assert_eq!(diff.len(), 0);
+    }
+
+    #[test]
+    fn test_changed_nested_field() {
+        let expected = json!({"user": {"name": "Ada", "address": {"city": "London", "zip": "N1"}}});
+        let actual = json!({"user": {"name": "Ada", "address": {"city": "Paris", "zip": "N1"}}});
+
+        let diff = JsonDiff::between(&expected, &actual);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.changed,
+            vec![JsonChange {
+                path: "/user/address/city".to_string(),
+                expected: json!("London"),
+                actual: json!("Paris"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_added_removed_and_array_paths() {
+        let expected = json!({"keep": 1, "gone": true, "items": [1, 2, 3], "a/b": "x"});
+        let actual = json!({"keep": 1, "new": "yes", "items": [1, 5], "a/b": 2});
+
+        let diff = JsonDiff::between(&expected, &actual);
+        assert_eq!(
+            diff.added,
+            vec![JsonDiffEntry {
+                path: "/new".to_string(),
+                value: json!("yes"),
+            }]
+        );
+
+        let removed: Vec<_> = diff.removed.iter().map(|entry| entry.path.as_str()).collect();
+        assert!(removed.contains(&"/gone"));
+        assert!(removed.contains(&"/items/2"));
+
+        let changed: Vec<_> = diff.changed.iter().map(|change| change.path.as_str()).collect();
+        assert!(changed.contains(&"/items/1"));
+        assert!(changed.contains(&"/a~1b"));
+    }
+
+    #[test]
+    fn test_type_mismatch_at_root() {
+        let diff = JsonDiff::between(&json!({"a": 1}), &json!([1]));
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].path, "");
+    }
+}
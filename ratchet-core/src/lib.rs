@@ -7,6 +7,8 @@
 pub mod config;
 pub mod error;
 pub mod execution;
+pub mod json_diff;
+pub mod schedule;
 pub mod service;
 pub mod task;
 pub mod types;
@@ -15,6 +17,7 @@ pub mod validation;
 // Re-export commonly used types at the crate root
 pub use error::{RatchetError, Result};
 pub use execution::{Execution, ExecutionId, ExecutionStatus};
+pub use json_diff::JsonDiff;
 pub use service::{ServiceProvider, ServiceRegistry};
 pub use task::{Task, TaskId, TaskMetadata};
 pub use types::{HttpMethod, LogLevel, Priority};
@@ -0,0 +1,249 @@
+//! Cron parsing and schedule previews
+//!
+//! Parses cron expressions in the standard 5-field form or the 6-field form
+//! with leading seconds, and computes upcoming run times without persisting
+//! anything, so a UI can show "runs at 9am, 10am, ..." before a schedule is
+//! saved.
+//!
+//! Also resolves when a queued job should run, spreading jobs due at the same
+//! moment over a jitter window so they don't all start at once.
+
+use crate::error::ValidationError;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use cron::Schedule;
+use std::str::FromStr;
+
+/// Number of occurrences returned when none is requested
+pub const DEFAULT_PREVIEW_COUNT: usize = 5;
+
+/// Largest number of occurrences a preview may return
+pub const MAX_PREVIEW_COUNT: usize = 100;
+
+/// Parse a cron expression with 5 fields (`min hour day month weekday`) or 6
+/// fields (with leading seconds)
+///
+/// A 7th trailing year field is also accepted. Five-field expressions fire at
+/// second 0.
+pub fn parse_cron_expression(cron_expression: &str) -> Result<Schedule, ValidationError> {
+    Schedule::from_str(&normalize_cron_expression(cron_expression)?)
+        .map_err(|e| ValidationError::InvalidFormat(format!("Invalid cron expression '{}': {}", cron_expression, e)))
+}
+
+/// Check a cron expression submitted for a schedule
+///
+/// Applies the general string input checks and then requires the expression
+/// to parse, so the REST and GraphQL APIs accept the same schedules.
+pub fn validate_cron_expression(cron_expression: &str) -> Result<(), ValidationError> {
+    crate::validation::InputValidator::new()
+        .validate_string(cron_expression, "cron_expression")
+        .map_err(|e| ValidationError::InvalidFormat(e.to_string()))?;
+    parse_cron_expression(cron_expression).map(|_| ())
+}
+
+/// Rewrite a cron expression into the form with a leading seconds field
+pub fn normalize_cron_expression(cron_expression: &str) -> Result<String, ValidationError> {
+    let fields: Vec<&str> = cron_expression.split_whitespace().collect();
+    match fields.len() {
+        5 => Ok(format!("0 {}", fields.join(" "))),
+        6 | 7 => Ok(fields.join(" ")),
+        0 => Err(ValidationError::InvalidFormat(
+            "Invalid cron expression: expression is empty".to_string(),
+        )),
+        n => Err(ValidationError::InvalidFormat(format!(
+            "Invalid cron expression '{}': expected 5 fields (minute hour day month weekday) \
+             or 6 fields (second minute hour day month weekday), got {}",
+            cron_expression.trim(),
+            n
+        ))),
+    }
+}
+
+/// Next `count` occurrences of `cron_expression` after `after`, in `timezone`
+///
+/// The timezone is an IANA name such as `Europe/Amsterdam`; `count` is capped
+/// at [`MAX_PREVIEW_COUNT`].
+pub fn preview_schedule(
+    cron_expression: &str,
+    timezone: &str,
+    after: DateTime<Utc>,
+    count: usize,
+) -> Result<Vec<DateTime<Tz>>, ValidationError> {
+    let schedule = parse_cron_expression(cron_expression)?;
+    let timezone = parse_timezone(timezone)?;
+
+    Ok(schedule
+        .after(&after.with_timezone(&timezone))
+        .take(count.min(MAX_PREVIEW_COUNT))
+        .collect())
+}
+
+/// Largest jitter window, in seconds, a job or schedule may use
+pub const MAX_JITTER_SECONDS: u32 = 3600;
+
+/// Check a jitter window is within [`MAX_JITTER_SECONDS`]
+pub fn validate_jitter(jitter_seconds: u32) -> Result<(), ValidationError> {
+    if jitter_seconds > MAX_JITTER_SECONDS {
+        return Err(ValidationError::InvalidFormat(format!(
+            "Jitter must be at most {} seconds, got {}",
+            MAX_JITTER_SECONDS, jitter_seconds
+        )));
+    }
+    Ok(())
+}
+
+/// Delay `at` by a random amount of up to `jitter_seconds`
+pub fn apply_jitter(at: DateTime<Utc>, jitter_seconds: u32) -> DateTime<Utc> {
+    if jitter_seconds == 0 {
+        return at;
+    }
+    let max_millis = u64::from(jitter_seconds.min(MAX_JITTER_SECONDS)) * 1000;
+    at + chrono::Duration::milliseconds(fastrand::u64(0..=max_millis) as i64)
+}
+
+/// When a job created at `now` should run, or `None` to run it right away
+///
+/// The run time is either the absolute `scheduled_for` or `delay_seconds`
+/// after `now`, never both, with up to `jitter_seconds` of random delay added.
+/// Check the jitter with [`validate_jitter`] first; larger windows are capped
+/// at [`MAX_JITTER_SECONDS`].
+pub fn resolve_job_run_time(
+    now: DateTime<Utc>,
+    scheduled_for: Option<DateTime<Utc>>,
+    delay_seconds: Option<u64>,
+    jitter_seconds: Option<u32>,
+) -> Result<Option<DateTime<Utc>>, ValidationError> {
+    let run_at = match (scheduled_for, delay_seconds) {
+        (Some(_), Some(_)) => {
+            return Err(ValidationError::InvalidFormat(
+                "Specify either scheduledFor or delaySeconds, not both".to_string(),
+            ))
+        }
+        (Some(at), None) => Some(at),
+        (None, Some(delay)) => Some(
+            i64::try_from(delay)
+                .ok()
+                .and_then(chrono::Duration::try_seconds)
+                .and_then(|delay| now.checked_add_signed(delay))
+                .ok_or_else(|| ValidationError::InvalidFormat(format!("Delay of {} seconds is too long", delay)))?,
+        ),
+        (None, None) => None,
+    };
+
+    match jitter_seconds.filter(|&jitter| jitter > 0) {
+        Some(jitter) => Ok(Some(apply_jitter(run_at.unwrap_or(now), jitter))),
+        None => Ok(run_at),
+    }
+}
+
+/// Parse an IANA timezone name
+pub fn parse_timezone(timezone: &str) -> Result<Tz, ValidationError> {
+    timezone
+        .trim()
+        .parse()
+        .map_err(|_| ValidationError::InvalidFormat(format!("Unknown timezone: {}", timezone)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_preview_follows_timezone_across_dst() {
+        // Friday 8 March 2024; New York moves to daylight saving time on Sunday 10 March
+        let after = Utc.with_ymd_and_hms(2024, 3, 8, 0, 0, 0).unwrap();
+
+        let runs = preview_schedule("0 0 9 * * Mon-Fri", "America/New_York", after, 3).unwrap();
+
+        let runs: Vec<String> = runs.iter().map(|run| run.to_rfc3339()).collect();
+        assert_eq!(
+            runs,
+            vec![
+                "2024-03-08T09:00:00-05:00",
+                "2024-03-11T09:00:00-04:00",
+                "2024-03-12T09:00:00-04:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preview_rejects_invalid_input() {
+        let after = Utc::now();
+        assert!(preview_schedule("not a cron", "UTC", after, 3).is_err());
+        assert!(preview_schedule("0 0 9 * * *", "Mars/Olympus_Mons", after, 3).is_err());
+        assert_eq!(
+            preview_schedule("0 * * * * *", "UTC", after, 1000).unwrap().len(),
+            MAX_PREVIEW_COUNT
+        );
+    }
+
+    #[test]
+    fn test_parse_accepts_five_and_six_fields() {
+        let after = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+
+        let five = parse_cron_expression("*/15 9 * * Mon").unwrap();
+        let six = parse_cron_expression("0 */15 9 * * Mon").unwrap();
+        let five_runs: Vec<_> = five.after(&after).take(3).collect();
+        let six_runs: Vec<_> = six.after(&after).take(3).collect();
+        assert_eq!(five_runs, six_runs);
+        assert_eq!(five_runs[0], Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap());
+
+        let err = parse_cron_expression("* * *").unwrap_err().to_string();
+        assert!(err.contains("expected 5 fields"), "{}", err);
+        assert!(err.contains("got 3"), "{}", err);
+        assert!(parse_cron_expression("   ").is_err());
+        assert!(parse_cron_expression("61 * * * *").is_err());
+    }
+
+    #[test]
+    fn test_validate_cron_expression() {
+        assert!(validate_cron_expression("0 9 * * Mon-Fri").is_ok());
+        assert!(validate_cron_expression("").is_err());
+        assert!(validate_cron_expression("not a cron").is_err());
+        assert!(validate_cron_expression(&format!("0 0 * * * {}", "*".repeat(20_000))).is_err());
+    }
+
+    #[test]
+    fn test_preview_five_field_expression_in_utc() {
+        let after = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let runs = preview_schedule("0 0 * * *", "UTC", after, 3).unwrap();
+        assert_eq!(runs.len(), 3);
+        assert_eq!(
+            runs[0].with_timezone(&Utc),
+            Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap()
+        );
+        assert!(runs
+            .windows(2)
+            .all(|pair| pair[1] - pair[0] == chrono::Duration::days(1)));
+
+        assert!(preview_schedule("every day", "UTC", after, 3).is_err());
+    }
+
+    #[test]
+    fn test_resolve_job_run_time() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 5, 1, 13, 0, 0).unwrap();
+
+        assert_eq!(resolve_job_run_time(now, None, None, None).unwrap(), None);
+        assert_eq!(resolve_job_run_time(now, Some(at), None, None).unwrap(), Some(at));
+        assert_eq!(resolve_job_run_time(now, None, Some(3600), Some(0)).unwrap(), Some(at));
+        assert!(resolve_job_run_time(now, Some(at), Some(60), None).is_err());
+        assert!(resolve_job_run_time(now, None, Some(u64::MAX), None).is_err());
+        assert!(validate_jitter(MAX_JITTER_SECONDS).is_ok());
+        assert!(validate_jitter(MAX_JITTER_SECONDS + 1).is_err());
+
+        // Jitter spreads runs over the window after the requested time
+        let runs: Vec<_> = (0..50)
+            .map(|_| resolve_job_run_time(now, Some(at), None, Some(30)).unwrap().unwrap())
+            .collect();
+        assert!(runs
+            .iter()
+            .all(|run| *run >= at && *run <= at + chrono::Duration::seconds(30)));
+        assert!(runs.iter().any(|run| *run != runs[0]), "jittered runs should differ");
+
+        // Jitter alone delays a job that would otherwise run right away
+        let run = resolve_job_run_time(now, None, None, Some(10)).unwrap().unwrap();
+        assert!(run >= now && run <= now + chrono::Duration::seconds(10));
+    }
+}
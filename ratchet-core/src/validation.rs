@@ -17,6 +17,7 @@ pub use input::{InputValidator, ValidationError as InputValidationError};
 
 use crate::error::{RatchetError, ValidationError};
 use jsonschema::Draft;
+pub use ratchet_config::domains::execution::OutputSchemaEnforcement;
 use serde_json::Value as JsonValue;
 use std::fs;
 use std::path::Path;
@@ -72,6 +73,44 @@ pub fn validate_json(data: &JsonValue, schema: &JsonValue) -> ValidationResult<(
     Ok(())
 }
 
+/// Check that a JSON schema is itself valid and can be compiled
+///
+/// # Returns
+/// * `Ok(())` if the schema compiles
+/// * `Err(RatchetError::Validation)` if it does not
+pub fn validate_schema(schema: &JsonValue) -> ValidationResult<()> {
+    jsonschema::options()
+        .with_draft(Draft::Draft7)
+        .build(schema)
+        .map(|_| ())
+        .map_err(|e| {
+            RatchetError::Validation(ValidationError::SchemaValidation(format!(
+                "Failed to compile schema: {}",
+                e
+            )))
+        })
+}
+
+/// Check task output against its output schema under an enforcement policy
+///
+/// `Strict` fails on a mismatch, `Warn` logs it and passes, `Off` skips the check.
+pub fn enforce_output_schema(
+    output: &JsonValue,
+    schema: &JsonValue,
+    enforcement: OutputSchemaEnforcement,
+) -> ValidationResult<()> {
+    match enforcement {
+        OutputSchemaEnforcement::Strict => validate_json(output, schema),
+        OutputSchemaEnforcement::Warn => {
+            if let Err(e) = validate_json(output, schema) {
+                tracing::warn!("Task output does not match its schema: {}", e);
+            }
+            Ok(())
+        }
+        OutputSchemaEnforcement::Off => Ok(()),
+    }
+}
+
 /// Parse a JSON schema from a file
 ///
 /// # Arguments
@@ -200,6 +239,23 @@ mod tests {
         assert!(validate_json(&data, &schema).is_ok());
     }
 
+    #[test]
+    fn test_validate_schema() {
+        assert!(validate_schema(&json!({"type": "object", "required": ["name"]})).is_ok());
+        assert!(validate_schema(&json!({"type": 12})).is_err());
+    }
+
+    #[test]
+    fn test_output_schema_enforcement_per_environment() {
+        let schema = json!({"type": "object", "required": ["status"]});
+        let output = json!({"result": 42});
+
+        // Production enforces the schema, development only warns or skips it
+        assert!(enforce_output_schema(&output, &schema, OutputSchemaEnforcement::Strict).is_err());
+        assert!(enforce_output_schema(&output, &schema, OutputSchemaEnforcement::Warn).is_ok());
+        assert!(enforce_output_schema(&output, &schema, OutputSchemaEnforcement::Off).is_ok());
+    }
+
     #[test]
     fn test_validate_json_failure() {
         let data = json!({"age": "not a number"});
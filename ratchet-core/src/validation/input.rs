@@ -59,6 +59,28 @@ pub enum ValidationError {
     InvalidEncoding,
 }
 
+impl ValidationError {
+    /// Stable code identifying the kind of failure, for API clients
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationError::SizeTooLarge { .. } => "SIZE_TOO_LARGE",
+            ValidationError::StringTooLong { .. } => "STRING_TOO_LONG",
+            ValidationError::ArrayTooLarge { .. } => "ARRAY_TOO_LARGE",
+            ValidationError::NestingTooDeep { .. } => "NESTING_TOO_DEEP",
+            ValidationError::TooManyKeys { .. } => "TOO_MANY_KEYS",
+            ValidationError::RequiredField { .. } => "REQUIRED",
+            ValidationError::InvalidFormat { .. } => "INVALID_FORMAT",
+            ValidationError::InvalidValue { .. } => "INVALID_VALUE",
+            ValidationError::UnsafePath { .. } => "UNSAFE_PATH",
+            ValidationError::InvalidUrl { .. } => "INVALID_URL",
+            ValidationError::BlockedUrl { .. } => "BLOCKED_URL",
+            ValidationError::InvalidJson { .. } => "INVALID_JSON",
+            ValidationError::PotentialInjection => "POTENTIAL_INJECTION",
+            ValidationError::InvalidEncoding => "INVALID_ENCODING",
+        }
+    }
+}
+
 /// Comprehensive input validator
 #[derive(Debug, Clone)]
 pub struct InputValidator {
@@ -207,16 +229,13 @@ impl InputValidator {
                     });
                 }
 
-                // Use the cron library for proper validation
-                use std::str::FromStr;
-
-                // Try to parse with cron library (same one used in storage)
-                match cron::Schedule::from_str(input) {
+                // Accepts 5-field and 6-field (with seconds) expressions, like the scheduler
+                match crate::schedule::parse_cron_expression(input) {
                     Ok(_) => return Ok(()),
                     Err(e) => {
                         return Err(ValidationError::InvalidFormat {
                             field: "cron_expression".to_string(),
-                            reason: format!("Invalid cron expression: {}", e),
+                            reason: e.to_string(),
                         })
                     }
                 }
@@ -39,10 +39,24 @@ impl ExecutionBridge {
             task_timeout_seconds: timeout_seconds,
             restart_on_crash: true,
             max_restart_attempts: 3,
+            attachments: None,
+            output_schema_enforcement: Default::default(),
         };
         Self::new(config)
     }
 
+    /// Run the task hooks in `registry` around every execution
+    ///
+    /// See [`ProcessTaskExecutor::with_hooks`].
+    pub fn with_hooks(
+        mut self,
+        registry: std::sync::Arc<ratchet_plugin::HookRegistry>,
+        system_config: ratchet_config::RatchetConfig,
+    ) -> Self {
+        self.inner = self.inner.with_hooks(registry, system_config);
+        self
+    }
+
     /// Get the underlying ProcessTaskExecutor (for advanced usage)
     pub fn inner(&self) -> &ProcessTaskExecutor {
         &self.inner
@@ -77,9 +91,15 @@ impl TaskExecutor for ExecutionBridge {
             .parse()
             .map_err(|_| ExecutionError::TaskExecutionError(format!("Invalid task_id format: {}", task_id)))?;
 
-        // Convert execution context
-        let ipc_context =
-            context.map(|_| IpcExecutionContext::new(Uuid::new_v4(), None, Uuid::new_v4(), "1.0.0".to_string()));
+        // Convert execution context, keeping the stored execution's UUID so attachments land under it
+        let ipc_context = context.map(|ctx| {
+            let execution_id = ctx.execution_id.unwrap_or_else(Uuid::new_v4);
+            let ipc_context = IpcExecutionContext::new(execution_id, None, Uuid::new_v4(), "1.0.0".to_string());
+            match ctx.log_level {
+                Some(log_level) => ipc_context.with_log_level(log_level),
+                None => ipc_context,
+            }
+        });
 
         // Use the direct execution method which should be Send
         let task_path = format!("/bridge-task/{}", task_id);
@@ -128,7 +148,7 @@ fn convert_execution_result(result: TaskExecutionResult) -> ExecutionResult {
     ExecutionResult {
         output: result.output.unwrap_or(JsonValue::Null),
         execution_time_ms: result.duration_ms as u64,
-        logs: vec![],                // TaskExecutionResult doesn't provide logs
+        logs: result.logs,
         trace: result.error_details, // Use error_details as trace data
         status,
     }
@@ -145,6 +165,11 @@ impl ExecutionConfigAdapter {
             task_timeout_seconds: config.max_execution_duration.as_secs(),
             restart_on_crash: true,
             max_restart_attempts: 3,
+            attachments: Some(
+                ratchet_storage::AttachmentStore::new(&config.attachments.directory)
+                    .with_max_size(config.attachments.max_size_bytes),
+            ),
+            output_schema_enforcement: config.output_schema_enforcement,
         };
         ExecutionBridge::new(executor_config)
     }
@@ -161,6 +186,8 @@ impl ExecutionConfigAdapter {
             task_timeout_seconds: 30,
             restart_on_crash: false,
             max_restart_attempts: 0,
+            attachments: None,
+            output_schema_enforcement: Default::default(),
         };
         ExecutionBridge::new(config)
     }
@@ -179,6 +206,8 @@ mod tests {
             task_timeout_seconds: 60,
             restart_on_crash: true,
             max_restart_attempts: 3,
+            attachments: None,
+            output_schema_enforcement: Default::default(),
         };
 
         let bridge = ExecutionBridge::new(config);
@@ -224,6 +253,10 @@ mod tests {
             started_at: start,
             completed_at: end,
             duration_ms: 1500,
+            logs: vec![ratchet_interfaces::logging::LogEvent::new(
+                ratchet_interfaces::logging::LogLevel::Info,
+                "processed 3 items",
+            )],
         };
 
         let converted = convert_execution_result(success_result);
@@ -231,6 +264,8 @@ mod tests {
         assert_eq!(converted.execution_time_ms, 1500);
         assert_eq!(converted.output, json!({"result": "success"}));
         assert!(converted.trace.is_some());
+        assert_eq!(converted.logs.len(), 1);
+        assert_eq!(converted.logs[0].message, "processed 3 items");
 
         // Test failed result conversion
         let failed_result = TaskExecutionResult {
@@ -241,6 +276,7 @@ mod tests {
             started_at: start,
             completed_at: end,
             duration_ms: 500,
+            logs: Vec::new(),
         };
 
         let converted = convert_execution_result(failed_result);
@@ -24,6 +24,9 @@ pub enum ExecutionError {
     #[error("Task execution error: {0}")]
     TaskExecutionError(String),
 
+    #[error("Task execution error: {message}")]
+    TaskScriptError { message: String, details: JsonValue },
+
     #[error("Task validation error: {0}")]
     ValidationError(String),
 
@@ -39,11 +42,34 @@ pub enum ExecutionError {
     #[error("Worker error: {0}")]
     WorkerError(String),
 
+    #[error("Worker crashed: {0}")]
+    WorkerCrashed(String),
+
     #[error("Timeout error: {0}")]
     TimeoutError(String),
 
     #[error("Configuration error: {0}")]
     ConfigurationError(String),
+
+    #[error("Execution vetoed by hook: {0}")]
+    HookVetoed(String),
+}
+
+impl ExecutionError {
+    /// Structured detail of the failure, such as a JavaScript stack trace
+    pub fn details(&self) -> Option<JsonValue> {
+        match self {
+            Self::TaskScriptError { details, .. } => Some(details.clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether running the task again could succeed
+    ///
+    /// A hook veto would only be repeated, so vetoed jobs are not retried.
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, Self::HookVetoed(_))
+    }
 }
 
 // Convert from storage errors
@@ -15,7 +15,7 @@ pub mod worker;
 pub use error::{ExecutionError, ExecutionResult};
 pub use executor::{LocalExecutionContext, TaskExecutor};
 pub use process::{ProcessExecutorConfig, ProcessTaskExecutor};
-pub use worker::{WorkerConfig, WorkerProcess, WorkerProcessManager, WorkerProcessStatus};
+pub use worker::{CrashListener, WorkerConfig, WorkerProcess, WorkerProcessManager, WorkerProcessStatus};
 
 // Re-export bridge types for interface compatibility
 pub use bridge::{ExecutionBridge, ExecutionConfigAdapter};
@@ -23,5 +23,5 @@ pub use bridge::{ExecutionBridge, ExecutionConfigAdapter};
 // Re-export IPC types for backward compatibility
 pub use ipc::{
     CoordinatorMessage, ExecutionContext as IpcExecutionContext, MessageEnvelope, StdioTransport, TaskExecutionResult,
-    TaskValidationResult, WorkerMessage, WorkerStatus,
+    TaskSource, TaskValidationResult, WorkerMessage, WorkerStatus,
 };
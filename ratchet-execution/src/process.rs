@@ -1,23 +1,129 @@
 //! Process-based task executor implementation
+//!
+//! # Task hooks
+//!
+//! An executor given a [`HookRegistry`] with [`ProcessTaskExecutor::with_hooks`]
+//! runs its task hooks around every execution, in [`HookPriority`] order:
+//!
+//! - `pre_execute` hooks run before the task is sent to a worker and may rewrite
+//!   its input. The first hook that returns an error stops the chain and vetoes
+//!   the execution: the task never runs and the caller gets
+//!   [`ExecutionError::HookVetoed`].
+//! - `post_execute` hooks, then `on_success` or `on_failure` hooks, run after
+//!   every execution that got past the pre-phase, including ones that failed in
+//!   the worker, with the output or error filled in. Errors from these hooks are
+//!   logged and never change the execution's result; a failing hook does skip the
+//!   hooks after it in the same phase.
+//! - Metrics hooks run last for the same executions and receive its
+//!   [`ExecutionMetrics`]: the worker-reported duration, the wall time the
+//!   executor observed, the outcome, and the output size and log count as
+//!   [`ResourceUsage`]. Workers do not report memory or CPU usage yet, so those
+//!   figures are always `None`. Vetoed executions are reported to the metrics
+//!   hooks too, as failures carrying the veto error and no resource usage. Every
+//!   metrics hook runs even if another fails.
+//!
+//! Hooks run inline on the execution path, so a slow hook delays the task.
+//!
+//! [`HookPriority`]: ratchet_plugin::HookPriority
+//! [`ExecutionMetrics`]: ratchet_plugin::ExecutionMetrics
+//! [`ResourceUsage`]: ratchet_plugin::ResourceUsage
 
 use async_trait::async_trait;
+use ratchet_config::domains::execution::OutputSchemaEnforcement;
+use ratchet_config::RatchetConfig;
+use ratchet_http::HttpConfig;
+use ratchet_interfaces::logging::StructuredLogger;
+use ratchet_js::ResourceLimits;
+use ratchet_plugin::hooks::TaskExecutionData;
+use ratchet_plugin::{ExecutionMetrics, HookRegistry, PluginContext, ResourceUsage};
+use ratchet_storage::AttachmentStore;
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::error::{ExecutionError, ExecutionResult};
 use crate::executor::TaskExecutor;
-use crate::ipc::{CoordinatorMessage, ExecutionContext as IpcExecutionContext, TaskExecutionResult, WorkerMessage};
-use crate::worker::{WorkerConfig, WorkerProcessManager};
+use crate::ipc::{
+    CoordinatorMessage, ExecutionContext as IpcExecutionContext, TaskExecutionResult, TaskSource, WorkerMessage,
+};
+use crate::worker::{CrashListener, WorkerConfig, WorkerProcessManager};
 
 /// Process-based task executor that uses worker processes for task execution
 /// This solves the Send/Sync issues by running JavaScript tasks in separate processes
 pub struct ProcessTaskExecutor {
     worker_manager: Arc<RwLock<WorkerProcessManager>>,
     config: ProcessExecutorConfig,
+    hooks: Option<TaskHooks>,
+}
+
+/// Hook registry run around executions, with the configuration hooks see in their context
+struct TaskHooks {
+    registry: Arc<HookRegistry>,
+    system_config: RatchetConfig,
+}
+
+impl TaskHooks {
+    /// Run the post-execution hooks for a finished execution, logging their failures
+    async fn run_post_execution(&self, task_id: i32, context: &mut PluginContext, data: &mut TaskExecutionData) {
+        if let Err(e) = self.registry.execute_post_execution_hooks(context, data).await {
+            warn!("Post-execution hook failed for task {}: {}", task_id, e);
+        }
+
+        let outcome = if data.success == Some(true) {
+            self.registry.execute_success_hooks(context, data).await
+        } else {
+            self.registry.execute_failure_hooks(context, data).await
+        };
+        if let Err(e) = outcome {
+            warn!("Completion hook failed for task {}: {}", task_id, e);
+        }
+    }
+
+    /// Report a finished execution to the metrics hooks, logging their failures
+    ///
+    /// Measuring the output serializes it, so metrics are only gathered when a metrics hook is registered.
+    async fn report_metrics(
+        &self,
+        task_id: i32,
+        context: &PluginContext,
+        data: &TaskExecutionData,
+        result: &Result<TaskExecutionResult, ExecutionError>,
+        wall_time_ms: u64,
+    ) {
+        if !self.registry.has_metrics_hooks().await {
+            return;
+        }
+
+        let metrics = execution_metrics(data, result, wall_time_ms);
+        if let Err(e) = self.registry.execute_metrics_hooks(context, &metrics).await {
+            warn!("Metrics hook failed for task {}: {}", task_id, e);
+        }
+    }
+}
+
+/// Metrics for an execution whose post-execution hooks have run
+fn execution_metrics(
+    data: &TaskExecutionData,
+    result: &Result<TaskExecutionResult, ExecutionError>,
+    wall_time_ms: u64,
+) -> ExecutionMetrics {
+    let mut metrics = ExecutionMetrics::from_execution_data(data).with_wall_time(wall_time_ms);
+    if let Ok(task_result) = result {
+        metrics.completed_at = task_result.completed_at;
+        metrics = metrics.with_resource_usage(ResourceUsage {
+            output_bytes: task_result
+                .output
+                .as_ref()
+                .and_then(|output| serde_json::to_vec(output).ok())
+                .map(|bytes| bytes.len() as u64),
+            log_entries: Some(task_result.logs.len() as u64),
+            ..Default::default()
+        });
+    }
+    metrics
 }
 
 /// Configuration for the process executor
@@ -27,6 +133,10 @@ pub struct ProcessExecutorConfig {
     pub task_timeout_seconds: u64,
     pub restart_on_crash: bool,
     pub max_restart_attempts: u32,
+    /// Store for files attached by tasks; attachments are discarded when unset
+    pub attachments: Option<AttachmentStore>,
+    /// How task output is checked against its output schema
+    pub output_schema_enforcement: OutputSchemaEnforcement,
 }
 
 impl Default for ProcessExecutorConfig {
@@ -36,6 +146,8 @@ impl Default for ProcessExecutorConfig {
             task_timeout_seconds: 300, // 5 minutes
             restart_on_crash: true,
             max_restart_attempts: 3,
+            attachments: None,
+            output_schema_enforcement: OutputSchemaEnforcement::default(),
         }
     }
 }
@@ -51,11 +163,17 @@ impl ProcessTaskExecutor {
             health_check_interval_seconds: 30,
             task_timeout_seconds: config.task_timeout_seconds,
             worker_idle_timeout_seconds: Some(3600), // 1 hour
+            attachments: config.attachments.clone(),
+            output_schema_enforcement: config.output_schema_enforcement,
         };
 
         let worker_manager = Arc::new(RwLock::new(WorkerProcessManager::new(worker_config)));
 
-        Self { worker_manager, config }
+        Self {
+            worker_manager,
+            config,
+            hooks: None,
+        }
     }
 
     /// Create a new executor with default configuration
@@ -63,6 +181,38 @@ impl ProcessTaskExecutor {
         Self::new(ProcessExecutorConfig::default())
     }
 
+    /// Run the task hooks in `registry` around every execution
+    ///
+    /// `system_config` is exposed to the hooks through their [`PluginContext`].
+    /// See the [module documentation](self) for the guarantees hooks get.
+    pub fn with_hooks(mut self, registry: Arc<HookRegistry>, system_config: RatchetConfig) -> Self {
+        self.hooks = Some(TaskHooks {
+            registry,
+            system_config,
+        });
+        self
+    }
+
+    /// Send the console output of tasks to `logger` as they run
+    pub async fn set_logger(&self, logger: Arc<dyn StructuredLogger>) {
+        self.worker_manager.write().await.set_logger(logger);
+    }
+
+    /// Tell `listener` the worker id every time a task crashes its worker
+    pub async fn set_crash_listener(&self, listener: CrashListener) {
+        self.worker_manager.write().await.set_crash_listener(listener);
+    }
+
+    /// Configure the HTTP client used by the `fetch` of the tasks this executor runs
+    pub async fn set_http_config(&self, config: HttpConfig) {
+        self.worker_manager.write().await.set_http_config(config);
+    }
+
+    /// Limit the resources each task run by this executor may use
+    pub async fn set_limits(&self, limits: ResourceLimits) {
+        self.worker_manager.write().await.set_limits(limits);
+    }
+
     /// Start the worker processes
     pub async fn start(&self) -> Result<(), ExecutionError> {
         info!("Starting ProcessTaskExecutor with {} workers", self.config.worker_count);
@@ -92,6 +242,9 @@ impl ProcessTaskExecutor {
     }
 
     /// Execute a task directly without database dependencies
+    ///
+    /// Registered task hooks run before and after the task, and metrics hooks
+    /// are told its outcome.
     pub async fn execute_task_direct(
         &self,
         task_id: i32,
@@ -100,11 +253,103 @@ impl ProcessTaskExecutor {
         execution_context: Option<IpcExecutionContext>,
     ) -> Result<TaskExecutionResult, ExecutionError> {
         debug!("Executing task {} directly at path: {}", task_id, task_path);
+        self.execute(task_id, task_path, None, input_data, execution_context)
+            .await
+    }
 
-        let correlation_id = Uuid::new_v4();
+    /// Execute a task whose code is given instead of resolved from a path
+    ///
+    /// Used for tasks stored in the database. Hooks run as for
+    /// [`execute_task_direct`](Self::execute_task_direct).
+    pub async fn execute_task_source(
+        &self,
+        task_id: i32,
+        task_source: TaskSource,
+        input_data: JsonValue,
+        execution_context: Option<IpcExecutionContext>,
+    ) -> Result<TaskExecutionResult, ExecutionError> {
+        debug!("Executing task {} ({}) from source", task_id, task_source.name);
+        let task_path = format!("/tasks/{}", task_source.name);
+        self.execute(task_id, task_path, Some(task_source), input_data, execution_context)
+            .await
+    }
+
+    /// Run the hooks around a task execution
+    async fn execute(
+        &self,
+        task_id: i32,
+        task_path: String,
+        task_source: Option<TaskSource>,
+        input_data: JsonValue,
+        execution_context: Option<IpcExecutionContext>,
+    ) -> Result<TaskExecutionResult, ExecutionError> {
         let exec_context = execution_context
             .unwrap_or_else(|| IpcExecutionContext::new(Uuid::new_v4(), None, Uuid::new_v4(), "1.0.0".to_string()));
 
+        let Some(hooks) = &self.hooks else {
+            return self
+                .run_task(task_id, task_path, task_source, input_data, exec_context)
+                .await;
+        };
+
+        let execution_id = Uuid::parse_str(&exec_context.execution_id).unwrap_or_else(|_| Uuid::new_v4());
+        let mut context = PluginContext::new(execution_id, JsonValue::Null, hooks.system_config.clone());
+        let mut data = TaskExecutionData::new(task_id.to_string(), input_data)
+            .with_metadata("task_path", JsonValue::from(task_path.as_str()))
+            .with_metadata("execution_id", JsonValue::from(exec_context.execution_id.as_str()))
+            .with_metadata("task_version", JsonValue::from(exec_context.task_version.as_str()));
+        if let Some(job_id) = &exec_context.job_id {
+            data = data.with_metadata("job_id", JsonValue::from(job_id.as_str()));
+        }
+
+        if let Err(e) = hooks
+            .registry
+            .execute_pre_execution_hooks(&mut context, &mut data)
+            .await
+        {
+            warn!("Task {} vetoed by pre-execution hook: {}", task_id, e);
+            let result = Err(ExecutionError::HookVetoed(e.to_string()));
+            data = data.with_result(false, 0).with_error(e.to_string());
+            hooks.report_metrics(task_id, &context, &data, &result, 0).await;
+            return result;
+        }
+
+        let start_time = Instant::now();
+        let result = self
+            .run_task(task_id, task_path, task_source, data.input.clone(), exec_context)
+            .await;
+        let duration_ms = start_time.elapsed().as_millis() as u64;
+
+        data = match &result {
+            Ok(task_result) => {
+                data.output = task_result.output.clone();
+                let data = data.with_result(task_result.success, task_result.duration_ms as u64);
+                match &task_result.error_message {
+                    Some(error) if !task_result.success => data.with_error(error.as_str()),
+                    _ => data,
+                }
+            }
+            Err(e) => data.with_result(false, duration_ms).with_error(e.to_string()),
+        };
+        hooks.run_post_execution(task_id, &mut context, &mut data).await;
+
+        hooks
+            .report_metrics(task_id, &context, &data, &result, duration_ms)
+            .await;
+
+        result
+    }
+
+    /// Send a task to a worker and wait for its result
+    async fn run_task(
+        &self,
+        task_id: i32,
+        task_path: String,
+        task_source: Option<TaskSource>,
+        input_data: JsonValue,
+        exec_context: IpcExecutionContext,
+    ) -> Result<TaskExecutionResult, ExecutionError> {
+        let correlation_id = Uuid::new_v4();
         let message = WorkerMessage::ExecuteTask {
             job_id: 0, // Direct execution has no job
             task_id,
@@ -112,6 +357,7 @@ impl ProcessTaskExecutor {
             input_data,
             execution_context: exec_context,
             correlation_id,
+            task_source,
         };
 
         // Get worker manager and send task to a worker
@@ -421,6 +667,8 @@ mod tests {
             task_timeout_seconds: 60,
             restart_on_crash: false,
             max_restart_attempts: 1,
+            attachments: None,
+            output_schema_enforcement: Default::default(),
         };
 
         let executor = ProcessTaskExecutor::new(config);
@@ -449,4 +697,180 @@ mod tests {
 
         executor.stop().await.unwrap();
     }
+
+    /// Records the phases it runs in, optionally vetoing executions
+    struct RecordingHook {
+        veto: bool,
+        calls: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl RecordingHook {
+        fn new(veto: bool) -> Arc<Self> {
+            Arc::new(Self {
+                veto,
+                calls: std::sync::Mutex::new(Vec::new()),
+            })
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl ratchet_plugin::Hook for RecordingHook {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        async fn execute(
+            &self,
+            _context: &mut PluginContext,
+            _data: &mut JsonValue,
+        ) -> ratchet_plugin::PluginResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ratchet_plugin::TaskHook for RecordingHook {
+        async fn pre_execute(
+            &self,
+            _context: &mut PluginContext,
+            data: &mut TaskExecutionData,
+        ) -> ratchet_plugin::PluginResult<()> {
+            self.calls.lock().unwrap().push(format!("pre:{}", data.input));
+            if self.veto {
+                return Err(ratchet_plugin::PluginError::hook_execution_failed(
+                    "recording",
+                    "not allowed",
+                ));
+            }
+            Ok(())
+        }
+
+        async fn post_execute(
+            &self,
+            _context: &mut PluginContext,
+            data: &mut TaskExecutionData,
+        ) -> ratchet_plugin::PluginResult<()> {
+            self.calls.lock().unwrap().push(format!("post:{:?}", data.success));
+            Ok(())
+        }
+
+        async fn on_failure(
+            &self,
+            _context: &mut PluginContext,
+            data: &mut TaskExecutionData,
+        ) -> ratchet_plugin::PluginResult<()> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("failure:{}", data.error.is_some()));
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ratchet_plugin::MetricsHook for RecordingHook {
+        async fn on_execution_metrics(
+            &self,
+            _context: &PluginContext,
+            metrics: &ratchet_plugin::ExecutionMetrics,
+        ) -> ratchet_plugin::PluginResult<()> {
+            self.calls.lock().unwrap().push(format!(
+                "metrics:{}:{}:{}",
+                metrics.task_id,
+                metrics.success,
+                metrics.error.is_some()
+            ));
+            Ok(())
+        }
+    }
+
+    async fn executor_with_hook(hook: Arc<RecordingHook>) -> ProcessTaskExecutor {
+        let registry = Arc::new(HookRegistry::new());
+        registry.register_task_hook(hook, "test-plugin").await.unwrap();
+        ProcessTaskExecutor::with_defaults().with_hooks(registry, RatchetConfig::default())
+    }
+
+    #[tokio::test]
+    async fn test_pre_execution_hook_can_veto_execution() {
+        let hook = RecordingHook::new(true);
+        let executor = executor_with_hook(hook.clone()).await;
+
+        let result = executor
+            .execute_task_direct(1, "/test/task".to_string(), json!({"n": 1}), None)
+            .await;
+
+        assert!(matches!(result, Err(ExecutionError::HookVetoed(_))));
+        assert!(!result.unwrap_err().is_retryable());
+        // Vetoed executions never reach the post-execution phase
+        assert_eq!(hook.calls(), vec![r#"pre:{"n":1}"#.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_hooks_see_vetoed_executions() {
+        let hook = RecordingHook::new(true);
+        let registry = Arc::new(HookRegistry::new());
+        registry.register_task_hook(hook.clone(), "test-plugin").await.unwrap();
+        registry
+            .register_metrics_hook(hook.clone(), "test-plugin")
+            .await
+            .unwrap();
+        let executor = ProcessTaskExecutor::with_defaults().with_hooks(registry, RatchetConfig::default());
+
+        let result = executor
+            .execute_task_direct(3, "/test/task".to_string(), json!({"n": 1}), None)
+            .await;
+
+        assert!(matches!(result, Err(ExecutionError::HookVetoed(_))));
+        // The veto skips the post-execution hooks but is still counted as a failure
+        assert_eq!(
+            hook.calls(),
+            vec![r#"pre:{"n":1}"#.to_string(), "metrics:3:false:true".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_metrics_hooks_see_execution_outcome() {
+        let hook = RecordingHook::new(false);
+        let registry = Arc::new(HookRegistry::new());
+        registry.register_task_hook(hook.clone(), "test-plugin").await.unwrap();
+        registry
+            .register_metrics_hook(hook.clone(), "test-plugin")
+            .await
+            .unwrap();
+        // No workers are started, so the execution itself fails
+        let executor = ProcessTaskExecutor::with_defaults().with_hooks(registry, RatchetConfig::default());
+
+        let result = executor
+            .execute_task_direct(7, "/test/task".to_string(), json!({"n": 1}), None)
+            .await;
+
+        assert!(result.is_err());
+        // Metrics are reported after the task hooks have run
+        assert_eq!(hook.calls().last().unwrap(), "metrics:7:false:true");
+    }
+
+    #[tokio::test]
+    async fn test_post_execution_hooks_see_failed_executions() {
+        let hook = RecordingHook::new(false);
+        // No workers are started, so the execution itself fails
+        let executor = executor_with_hook(hook.clone()).await;
+
+        let result = executor
+            .execute_task_direct(1, "/test/task".to_string(), json!({"n": 1}), None)
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            hook.calls(),
+            vec![
+                r#"pre:{"n":1}"#.to_string(),
+                "post:Some(false)".to_string(),
+                "failure:true".to_string(),
+            ]
+        );
+    }
 }
@@ -4,13 +4,18 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{oneshot, Mutex};
-use tracing::{debug, info, warn};
+use tracing::{debug, info, warn, Instrument};
 use uuid::Uuid;
 use serde_json::Value as JsonValue;
 
 use crate::error::ExecutionError;
-use crate::ipc::{CoordinatorMessage, TaskExecutionResult, WorkerMessage, WorkerStatus, ExecutionContext};
-use ratchet_js::{JsTask, JsTaskRunner, ExecutionContext as JsExecutionContext};
+use crate::ipc::{CoordinatorMessage, TaskExecutionResult, TaskSource, WorkerMessage, WorkerStatus, ExecutionContext};
+use ratchet_config::domains::execution::OutputSchemaEnforcement;
+use ratchet_http::{HttpConfig, HttpManager};
+use ratchet_interfaces::logging::StructuredLogger;
+use ratchet_js::{ConsoleCapture, JsTask, JsTaskRunner, ExecutionContext as JsExecutionContext, ResourceLimits};
+use ratchet_js::js_task::JsTaskError;
+use ratchet_storage::AttachmentStore;
 
 /// Configuration for worker processes
 #[derive(Debug, Clone)]
@@ -22,6 +27,10 @@ pub struct WorkerConfig {
     pub health_check_interval_seconds: u64,
     pub task_timeout_seconds: u64,
     pub worker_idle_timeout_seconds: Option<u64>,
+    /// Store for files attached by tasks; attachments are discarded when unset
+    pub attachments: Option<AttachmentStore>,
+    /// How task output is checked against its output schema
+    pub output_schema_enforcement: OutputSchemaEnforcement,
 }
 
 impl Default for WorkerConfig {
@@ -34,6 +43,8 @@ impl Default for WorkerConfig {
             health_check_interval_seconds: 30,
             task_timeout_seconds: 300,               // 5 minutes
             worker_idle_timeout_seconds: Some(3600), // 1 hour
+            attachments: None,
+            output_schema_enforcement: OutputSchemaEnforcement::default(),
         }
     }
 }
@@ -123,12 +134,20 @@ pub struct WorkerStats {
     pub cpu_usage_percent: Option<f32>,
 }
 
+/// Callback told the id of a worker whose task crashed it
+pub type CrashListener = Arc<dyn Fn(&str) + Send + Sync>;
+
 /// Manages a pool of worker processes
 pub struct WorkerProcessManager {
     config: WorkerConfig,
     workers: HashMap<String, WorkerProcess>,
     _pending_tasks: Arc<Mutex<HashMap<Uuid, oneshot::Sender<Result<TaskExecutionResult, ExecutionError>>>>>,
     _task_queue: Arc<Mutex<Vec<WorkerMessage>>>,
+    logger: Option<Arc<dyn StructuredLogger>>,
+    crash_listener: Option<CrashListener>,
+    /// Client behind the `fetch` of every task, sharing connections between executions
+    http: HttpManager,
+    limits: ResourceLimits,
 }
 
 impl WorkerProcessManager {
@@ -139,9 +158,33 @@ impl WorkerProcessManager {
             workers: HashMap::new(),
             _pending_tasks: Arc::new(Mutex::new(HashMap::new())),
             _task_queue: Arc::new(Mutex::new(Vec::new())),
+            logger: None,
+            crash_listener: None,
+            http: HttpManager::new(),
+            limits: ResourceLimits::new(),
         }
     }
 
+    /// Send the console output of tasks to `logger` as well as their execution results
+    pub fn set_logger(&mut self, logger: Arc<dyn StructuredLogger>) {
+        self.logger = Some(logger);
+    }
+
+    /// Tell `listener` every time a task crashes the worker running it
+    pub fn set_crash_listener(&mut self, listener: CrashListener) {
+        self.crash_listener = Some(listener);
+    }
+
+    /// Configure the HTTP client tasks reach the network through with `fetch`
+    pub fn set_http_config(&mut self, config: HttpConfig) {
+        self.http = HttpManager::with_config(config);
+    }
+
+    /// Limit the memory growth, loop iterations and call depth of every task run
+    pub fn set_limits(&mut self, limits: ResourceLimits) {
+        self.limits = limits;
+    }
+
     /// Start all worker processes
     pub async fn start(&mut self) -> Result<(), ExecutionError> {
         info!("Starting {} worker processes", self.config.worker_count);
@@ -192,12 +235,20 @@ impl WorkerProcessManager {
                 task_path,
                 input_data,
                 execution_context,
+                task_source,
                 ..
             } => {
                 let started_at = chrono::Utc::now();
-                
+                let console = match &self.logger {
+                    Some(logger) => ConsoleCapture::new().with_logger(logger.clone()),
+                    None => ConsoleCapture::new(),
+                };
+
                 // Execute the JavaScript task
-                let result = match self.execute_javascript_task(&task_path, input_data, execution_context).await {
+                let result = match self
+                    .execute_javascript_task(&task_path, task_source, input_data, execution_context, console.clone())
+                    .await
+                {
                     Ok(output) => {
                         let completed_at = chrono::Utc::now();
                         let duration_ms = (completed_at - started_at).num_milliseconds() as i32;
@@ -210,20 +261,25 @@ impl WorkerProcessManager {
                             started_at,
                             completed_at,
                             duration_ms,
+                            logs: console.events(),
                         }
                     }
                     Err(error) => {
                         let completed_at = chrono::Utc::now();
                         let duration_ms = (completed_at - started_at).num_milliseconds() as i32;
-                        
+                        if matches!(error, ExecutionError::WorkerCrashed(_)) {
+                            self.record_crash(&worker_id);
+                        }
+
                         TaskExecutionResult {
                             success: false,
                             output: None,
                             error_message: Some(error.to_string()),
-                            error_details: None,
+                            error_details: error.details(),
                             started_at,
                             completed_at,
                             duration_ms,
+                            logs: console.events(),
                         }
                     }
                 };
@@ -286,6 +342,18 @@ impl WorkerProcessManager {
         stats
     }
 
+    /// Count a crash against a worker and notify the crash listener
+    fn record_crash(&mut self, worker_id: &str) {
+        warn!("Worker {} crashed while running a task", worker_id);
+        if let Some(worker) = self.workers.get_mut(worker_id) {
+            worker.restart_count += 1;
+            worker.started_at = chrono::Utc::now();
+        }
+        if let Some(listener) = &self.crash_listener {
+            listener(worker_id);
+        }
+    }
+
     /// Find an available worker
     fn find_available_worker(&self) -> Option<String> {
         self.workers
@@ -311,23 +379,57 @@ impl WorkerProcessManager {
     async fn execute_javascript_task(
         &self,
         task_path: &str,
+        task_source: Option<TaskSource>,
         input_data: JsonValue,
         execution_context: ExecutionContext,
+        console: ConsoleCapture,
     ) -> Result<JsonValue, ExecutionError> {
-        debug!("Executing JavaScript task at path: {}", task_path);
+        // Honour the per-execution log level override for everything logged by this task
+        let span = match execution_context.log_level.as_deref() {
+            Some(level) => tracing::info_span!(
+                "task_execution",
+                task_id = %execution_context.task_id,
+                task_log_level = level
+            ),
+            None => tracing::info_span!("task_execution", task_id = %execution_context.task_id),
+        };
 
-        // For now, handle embedded tasks by checking known embedded task names
-        // In a full implementation, this would integrate with the registry
-        let (task_name, js_content) = self.resolve_task_content(task_path)?;
-        debug!("Resolved task: {} with content length: {}", task_name, js_content.len());
+        self.run_javascript_task(task_path, task_source, input_data, execution_context, console)
+            .instrument(span)
+            .await
+    }
 
-        // Create JavaScript task
-        let js_task = JsTask {
-            name: task_name.clone(),
-            content: js_content.clone(),
-            input_schema: None, // TODO: Load from registry if available
-            output_schema: None, // TODO: Load from registry if available
+    /// Run a JavaScript task inside the current task execution span
+    async fn run_javascript_task(
+        &self,
+        task_path: &str,
+        task_source: Option<TaskSource>,
+        input_data: JsonValue,
+        execution_context: ExecutionContext,
+        console: ConsoleCapture,
+    ) -> Result<JsonValue, ExecutionError> {
+        debug!("Executing JavaScript task at path: {}", task_path);
+
+        // Tasks from the database ship their code with the request; otherwise
+        // only embedded tasks can be resolved from their path
+        let js_task = match task_source {
+            Some(source) => JsTask {
+                name: source.name,
+                content: source.content,
+                input_schema: source.input_schema,
+                output_schema: source.output_schema,
+            },
+            None => {
+                let (task_name, js_content) = self.resolve_task_content(task_path)?;
+                JsTask {
+                    name: task_name,
+                    content: js_content,
+                    input_schema: None,
+                    output_schema: None,
+                }
+            }
         };
+        debug!("Resolved task: {} with content length: {}", js_task.name, js_task.content.len());
 
         // Create execution context for JavaScript
         let js_context = Some(JsExecutionContext {
@@ -335,24 +437,85 @@ impl WorkerProcessManager {
             task_id: execution_context.task_id.clone(),
             task_version: execution_context.task_version.clone(),
             job_id: execution_context.job_id.clone(),
+            fetch_access: Some(self.http.config().fetch_access.clone()),
         });
 
-        // Execute the task in a separate thread to avoid Send issues with Boa
-        let result = tokio::task::spawn_blocking(move || {
-            let runner = JsTaskRunner::new();
-            // Use the sync blocking execution since we're in a blocking task
-            tokio::runtime::Handle::current().block_on(async move {
-                runner.execute_task(&js_task, input_data, js_context).await
+        // Execute the task in a separate thread to avoid Send issues with Boa. The
+        // thread gets a stack deep enough for the engine's call depth limit, so
+        // runaway recursion fails the task instead of overflowing the worker's stack
+        let task_span = tracing::Span::current();
+        let output_schema_enforcement = self.config.output_schema_enforcement;
+        let http = self.http.clone();
+        let limits = self.limits;
+        let task_timeout = Duration::from_secs(self.config.task_timeout_seconds);
+        let runtime = tokio::runtime::Handle::current();
+        let (result, attachments) = ratchet_js::run_on_task_thread(move || {
+            let _entered = task_span.enter();
+            let runner = JsTaskRunner::new()
+                .with_output_schema_enforcement(output_schema_enforcement)
+                .with_http_manager(http)
+                .with_limits(limits)
+                .with_timeout(task_timeout)
+                .with_console(console);
+            runtime.block_on(async move {
+                runner
+                    .execute_task_with_attachments(&js_task, input_data, js_context)
+                    .await
             })
         })
         .await
-        .map_err(|e| ExecutionError::TaskExecutionError(format!("Task execution failed: {}", e)))?
-        .map_err(|e| ExecutionError::TaskExecutionError(format!("JavaScript execution failed: {}", e)))?;
+        .map_err(|e| ExecutionError::WorkerCrashed(e.to_string()))?
+        .map_err(|e| {
+            let message = format!("JavaScript execution failed: {}", e);
+            match &e {
+                JsTaskError::JsExecutionError(error) => match error.details() {
+                    Some(details) => ExecutionError::TaskScriptError { message, details },
+                    None => ExecutionError::TaskExecutionError(message),
+                },
+                _ => ExecutionError::TaskExecutionError(message),
+            }
+        })?;
 
         debug!("JavaScript task completed successfully");
+        self.store_attachments(&execution_context.execution_id, attachments).await?;
         Ok(result)
     }
 
+    /// Persist the files a task attached to its execution
+    async fn store_attachments(
+        &self,
+        execution_id: &str,
+        attachments: Vec<ratchet_js::TaskAttachment>,
+    ) -> Result<(), ExecutionError> {
+        if attachments.is_empty() {
+            return Ok(());
+        }
+
+        let Some(store) = &self.config.attachments else {
+            warn!(
+                "Discarding {} attachment(s) for execution {}: no attachment store configured",
+                attachments.len(),
+                execution_id
+            );
+            return Ok(());
+        };
+
+        for attachment in attachments {
+            store
+                .put(execution_id, &attachment.name, &attachment.mime_type, &attachment.data)
+                .await
+                .map_err(|e| {
+                    ExecutionError::TaskExecutionError(format!(
+                        "Failed to store attachment '{}': {}",
+                        attachment.name, e
+                    ))
+                })?;
+            debug!("Stored attachment '{}' for execution {}", attachment.name, execution_id);
+        }
+
+        Ok(())
+    }
+
     /// Resolve task content from path/name
     /// This is a simplified implementation that handles embedded tasks
     fn resolve_task_content(&self, task_path: &str) -> Result<(String, String), ExecutionError> {
@@ -540,6 +703,7 @@ function main(input) {
             task_id: "heartbeat".to_string(),
             task_version: "1.0.0".to_string(),
             job_id: None,
+            fetch_access: None,
         });
 
         let runner = JsTaskRunner::new();
@@ -565,6 +729,7 @@ function main(input) {
             job_id: None,
             task_id: "heartbeat".to_string(),
             task_version: "1.0.0".to_string(),
+            log_level: None,
         };
 
         let message = WorkerMessage::ExecuteTask {
@@ -574,6 +739,7 @@ function main(input) {
             input_data: serde_json::json!({}),
             execution_context,
             correlation_id: Uuid::new_v4(),
+            task_source: None,
         };
 
         let result = manager.send_task(message, Duration::from_secs(10)).await;
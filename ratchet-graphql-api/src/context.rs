@@ -1,6 +1,10 @@
 //! GraphQL context types for dependency injection
 
 use crate::events::EventBroadcaster;
+use crate::loaders::TaskLoader;
+use async_graphql::dataloader::DataLoader;
+use ratchet_api_types::ApiError;
+pub use ratchet_config::domains::output::DEFAULT_MAX_OUTPUT_DESTINATIONS;
 use ratchet_interfaces::{RegistryManager, RepositoryFactory, TaskRegistry, TaskValidator};
 use ratchet_mcp::server::adapter::RatchetMcpAdapter;
 use std::sync::Arc;
@@ -14,6 +18,10 @@ pub struct GraphQLContext {
     pub validator: Arc<dyn TaskValidator>,
     pub event_broadcaster: Arc<EventBroadcaster>,
     pub mcp_adapter: Option<Arc<RatchetMcpAdapter>>,
+    /// Batches task lookups from execution `task` fields; add to each request with [`Self::attach`]
+    pub task_loader: Arc<DataLoader<TaskLoader>>,
+    /// Maximum number of output destinations a job may configure
+    pub max_output_destinations: usize,
 }
 
 impl GraphQLContext {
@@ -24,12 +32,14 @@ impl GraphQLContext {
         validator: Arc<dyn TaskValidator>,
     ) -> Self {
         Self {
+            task_loader: create_task_loader(&repositories),
             repositories,
             registry,
             registry_manager,
             validator,
             event_broadcaster: Arc::new(EventBroadcaster::new()),
             mcp_adapter: None,
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
 
@@ -42,12 +52,14 @@ impl GraphQLContext {
         event_broadcaster: Arc<EventBroadcaster>,
     ) -> Self {
         Self {
+            task_loader: create_task_loader(&repositories),
             repositories,
             registry,
             registry_manager,
             validator,
             event_broadcaster,
             mcp_adapter: None,
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
 
@@ -61,14 +73,43 @@ impl GraphQLContext {
         mcp_adapter: Arc<RatchetMcpAdapter>,
     ) -> Self {
         Self {
+            task_loader: create_task_loader(&repositories),
             repositories,
             registry,
             registry_manager,
             validator,
             event_broadcaster,
             mcp_adapter: Some(mcp_adapter),
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
+
+    /// Add this context and the loaders its resolvers use to a request
+    pub fn attach(&self, request: async_graphql::Request) -> async_graphql::Request {
+        request.data(self.task_loader.clone()).data(self.clone())
+    }
+
+    /// Limit the number of output destinations accepted per job
+    pub fn with_max_output_destinations(mut self, max_output_destinations: usize) -> Self {
+        self.max_output_destinations = max_output_destinations;
+        self
+    }
+}
+
+/// Loader resolving tasks through a single `find_by_ids` call per batch
+fn create_task_loader(repositories: &Arc<dyn RepositoryFactory>) -> Arc<DataLoader<TaskLoader>> {
+    let repositories = repositories.clone();
+    let loader = TaskLoader::new(move |ids| {
+        let repositories = repositories.clone();
+        async move {
+            repositories
+                .task_repository()
+                .find_by_ids(ids)
+                .await
+                .map_err(|e| ApiError::internal_error(e.to_string()))
+        }
+    });
+    Arc::new(DataLoader::new(loader, tokio::spawn))
 }
 
 /// Configuration for GraphQL setup
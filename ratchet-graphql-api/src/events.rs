@@ -4,7 +4,8 @@
 //! that can be subscribed to via GraphQL subscriptions.
 
 use async_graphql::SimpleObject;
-use futures_util::stream::Stream;
+use futures_util::stream::{self, Stream};
+use ratchet_api_types::UnifiedExecution;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use tokio::sync::broadcast;
@@ -18,7 +19,7 @@ const EVENT_BUFFER_SIZE: usize = 1000;
 /// Event broadcaster for managing subscription streams
 #[derive(Clone)]
 pub struct EventBroadcaster {
-    execution_tx: broadcast::Sender<Execution>,
+    execution_tx: broadcast::Sender<UnifiedExecution>,
     job_tx: broadcast::Sender<Job>,
     worker_tx: broadcast::Sender<Worker>,
 }
@@ -37,8 +38,16 @@ impl EventBroadcaster {
         }
     }
 
+    /// Sender for publishing execution changes from outside the GraphQL layer
+    ///
+    /// The execution lifecycle writes to this so subscribers see status and
+    /// progress changes as they are persisted.
+    pub fn execution_sender(&self) -> broadcast::Sender<UnifiedExecution> {
+        self.execution_tx.clone()
+    }
+
     /// Broadcast an execution event
-    pub fn broadcast_execution(&self, execution: Execution) {
+    pub fn broadcast_execution(&self, execution: UnifiedExecution) {
         match self.execution_tx.send(execution.clone()) {
             Ok(subscriber_count) => {
                 debug!(
@@ -135,14 +144,42 @@ impl Default for EventBroadcaster {
 }
 
 /// Custom stream for execution subscriptions with filtering
+///
+/// Waits on the channel rather than polling it, since dashboards may hold
+/// these subscriptions open for a long time.
 pub struct ExecutionSubscriptionStream {
-    rx: broadcast::Receiver<Execution>,
-    task_id_filter: Option<GraphQLApiId>,
+    inner: Pin<Box<dyn Stream<Item = async_graphql::Result<Execution>> + Send>>,
 }
 
 impl ExecutionSubscriptionStream {
-    pub fn new(rx: broadcast::Receiver<Execution>, task_id_filter: Option<GraphQLApiId>) -> Self {
-        Self { rx, task_id_filter }
+    pub fn new(rx: broadcast::Receiver<UnifiedExecution>, task_id_filter: Option<GraphQLApiId>) -> Self {
+        let inner = stream::unfold(rx, move |mut rx| {
+            let task_id_filter = task_id_filter.clone();
+            async move {
+                loop {
+                    match rx.recv().await {
+                        Ok(execution) => {
+                            // Apply task ID filter if specified
+                            if let Some(ref filter_task_id) = task_id_filter {
+                                if execution.task_id != filter_task_id.0 {
+                                    continue;
+                                }
+                            }
+                            return Some((Ok(Execution::from(execution)), rx));
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            warn!("Execution subscription lagged, skipped {} events", skipped);
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            debug!("Execution broadcast channel closed");
+                            return None;
+                        }
+                    }
+                }
+            }
+        });
+
+        Self { inner: Box::pin(inner) }
     }
 }
 
@@ -150,31 +187,7 @@ impl Stream for ExecutionSubscriptionStream {
     type Item = async_graphql::Result<Execution>;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        match self.rx.try_recv() {
-            Ok(execution) => {
-                // Apply task ID filter if specified
-                if let Some(ref filter_task_id) = self.task_id_filter {
-                    if execution.task_id != filter_task_id.0 {
-                        cx.waker().wake_by_ref(); // Schedule another poll
-                        return Poll::Pending;
-                    }
-                }
-                Poll::Ready(Some(Ok(execution)))
-            }
-            Err(broadcast::error::TryRecvError::Empty) => {
-                cx.waker().wake_by_ref(); // Schedule another poll
-                Poll::Pending
-            }
-            Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
-                warn!("Execution subscription lagged, skipped {} events", skipped);
-                cx.waker().wake_by_ref(); // Schedule another poll
-                Poll::Pending
-            }
-            Err(broadcast::error::TryRecvError::Closed) => {
-                debug!("Execution broadcast channel closed");
-                Poll::Ready(None)
-            }
-        }
+        self.inner.as_mut().poll_next(cx)
     }
 }
 
@@ -6,6 +6,7 @@
 pub mod context;
 pub mod errors;
 pub mod events;
+pub mod loaders;
 pub mod resolvers;
 pub mod schema;
 pub mod types;
@@ -14,6 +15,7 @@ pub mod types;
 pub use context::*;
 pub use errors::*;
 pub use events::*;
+pub use loaders::*;
 pub use resolvers::*;
 pub use schema::*;
 pub use types::*;
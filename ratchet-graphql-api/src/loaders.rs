@@ -0,0 +1,46 @@
+//! DataLoaders for GraphQL fields that resolve related records
+//!
+//! Each loader is handed a batch lookup, usually a repository call. Lookups
+//! made while resolving one response, such as the task of every execution in a
+//! page, are coalesced into a single call.
+
+use async_graphql::dataloader::Loader;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use ratchet_api_types::{ApiError, ApiId, UnifiedTask};
+
+type BatchFuture<T> = Pin<Box<dyn Future<Output = Result<Vec<T>, ApiError>> + Send>>;
+
+/// Loads tasks by ID for [`Execution`](crate::types::Execution)'s `task` field
+#[derive(Clone)]
+pub struct TaskLoader {
+    fetch: Arc<dyn Fn(Vec<ApiId>) -> BatchFuture<UnifiedTask> + Send + Sync>,
+}
+
+impl TaskLoader {
+    /// Create a loader that fetches each batch of task IDs with `fetch`
+    ///
+    /// `fetch` may leave out IDs that don't exist; those resolve to `null`.
+    pub fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: Fn(Vec<ApiId>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<UnifiedTask>, ApiError>> + Send + 'static,
+    {
+        Self {
+            fetch: Arc::new(move |ids| Box::pin(fetch(ids))),
+        }
+    }
+}
+
+impl Loader<ApiId> for TaskLoader {
+    type Value = UnifiedTask;
+    type Error = ApiError;
+
+    async fn load(&self, keys: &[ApiId]) -> Result<HashMap<ApiId, UnifiedTask>, ApiError> {
+        let tasks = (self.fetch)(keys.to_vec()).await?;
+        Ok(tasks.into_iter().map(|task| (task.id.clone(), task)).collect())
+    }
+}
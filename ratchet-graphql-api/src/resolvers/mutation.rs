@@ -1,12 +1,21 @@
 //! GraphQL mutation resolvers
 
 use crate::{context::GraphQLContext, types::*};
-use async_graphql::{Context, Object, Result};
+use async_graphql::{Context, ErrorExtensions, MaybeUndefined, Object, Result};
 use ratchet_api_types::ApiError;
+use ratchet_core::schedule::{parse_timezone, resolve_job_run_time, validate_cron_expression, validate_jitter};
 use ratchet_core::validation::{ErrorSanitizer, InputValidator};
 use serde_json::Value as JsonValue;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
 use tracing::warn;
 
+/// How long `executeTaskSync` waits when no timeout is given
+const DEFAULT_SYNC_TIMEOUT_MS: u64 = 30_000;
+
+/// Upper bound on the `executeTaskSync` timeout
+const MAX_SYNC_TIMEOUT_MS: u64 = 300_000;
+
 /// Root mutation resolver
 pub struct Mutation;
 
@@ -209,6 +218,8 @@ impl Mutation {
             duration_ms: None,
             http_requests: None,
             recording_path: None,
+            attachments: Vec::new(),
+            labels: Default::default(),
             can_retry: false,
             can_cancel: true,
             progress: None,
@@ -221,13 +232,23 @@ impl Mutation {
             .await
             .map_err(|e| ApiError::internal_error(format!("Failed to create execution: {}", e)))?;
 
-        Ok(created_execution)
+        Ok(created_execution.into())
     }
 
     /// Create a new job
     async fn create_job(&self, ctx: &Context<'_>, input: CreateJobInput) -> Result<Job> {
         let context = ctx.data::<GraphQLContext>()?;
 
+        if let Some(jitter_seconds) = input.jitter_seconds {
+            if let Err(jitter_err) = validate_jitter(jitter_seconds) {
+                return Err(ApiError::bad_request(jitter_err.to_string()).into());
+            }
+        }
+
+        let now = chrono::Utc::now();
+        let scheduled_for = resolve_job_run_time(now, input.scheduled_for, input.delay_seconds, input.jitter_seconds)
+            .map_err(|e| ApiError::bad_request(e.to_string()))?;
+
         // Validate that task exists
         let task_repo = context.repositories.task_repository();
         let _task = task_repo
@@ -244,10 +265,13 @@ impl Mutation {
             status: ratchet_api_types::JobStatus::Queued,
             retry_count: 0,
             max_retries: input.max_retries.unwrap_or(3),
-            queued_at: chrono::Utc::now(),
-            scheduled_for: input.scheduled_for,
+            queued_at: now,
+            scheduled_for,
             error_message: None,
             output_destinations: None, // TODO: Add support for output destinations in input
+            schedule_id: None,
+            execution_id: None,
+            log_level: None,
         };
 
         // Create the job using the repository
@@ -283,9 +307,22 @@ impl Mutation {
             return Err(ApiError::bad_request(&sanitized_error.message).into());
         }
 
-        // Validate cron expression format (basic validation)
-        if input.cron_expression.trim().is_empty() {
-            return Err(ApiError::bad_request("Cron expression cannot be empty").into());
+        // Reject cron expressions the scheduler would not be able to run
+        if let Err(cron_err) = validate_cron_expression(&input.cron_expression) {
+            warn!("Invalid cron expression in GraphQL create_schedule: {}", cron_err);
+            return Err(ApiError::bad_request(cron_err.to_string()).into());
+        }
+
+        if let Some(ref timezone) = input.timezone {
+            if let Err(tz_err) = parse_timezone(timezone) {
+                return Err(ApiError::bad_request(tz_err.to_string()).into());
+            }
+        }
+
+        if let Some(jitter_seconds) = input.jitter_seconds {
+            if let Err(jitter_err) = validate_jitter(jitter_seconds) {
+                return Err(ApiError::bad_request(jitter_err.to_string()).into());
+            }
         }
 
         // Validate description if provided
@@ -310,6 +347,12 @@ impl Mutation {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             output_destinations: None, // GraphQL doesn't support output destinations yet
+            serial: input.serial.unwrap_or(false),
+            timezone: input.timezone,
+            misfire_policy: input.misfire_policy.unwrap_or_default(),
+            max_concurrent: input.max_concurrent.filter(|&max| max > 0),
+            jitter_seconds: input.jitter_seconds.filter(|&jitter| jitter > 0),
+            paused: false,
         };
 
         // Create the schedule using the repository
@@ -344,8 +387,21 @@ impl Mutation {
         }
 
         if let Some(ref cron_expression) = input.cron_expression {
-            if cron_expression.trim().is_empty() {
-                return Err(ApiError::bad_request("Cron expression cannot be empty").into());
+            if let Err(cron_err) = validate_cron_expression(cron_expression) {
+                warn!("Invalid cron expression in GraphQL update_schedule: {}", cron_err);
+                return Err(ApiError::bad_request(cron_err.to_string()).into());
+            }
+        }
+
+        if let MaybeUndefined::Value(ref timezone) = input.timezone {
+            if let Err(tz_err) = parse_timezone(timezone) {
+                return Err(ApiError::bad_request(tz_err.to_string()).into());
+            }
+        }
+
+        if let Some(jitter_seconds) = input.jitter_seconds {
+            if let Err(jitter_err) = validate_jitter(jitter_seconds) {
+                return Err(ApiError::bad_request(jitter_err.to_string()).into());
             }
         }
 
@@ -378,7 +434,33 @@ impl Mutation {
             existing_schedule.next_run = None;
         }
         if let Some(enabled) = input.enabled {
+            // An explicit choice overrides a pending resume of paused schedules
             existing_schedule.enabled = enabled;
+            existing_schedule.paused = false;
+        }
+        if let Some(serial) = input.serial {
+            existing_schedule.serial = serial;
+        }
+        match input.timezone {
+            // Run times shift with the timezone, so let the scheduler recalculate them
+            MaybeUndefined::Value(timezone) => {
+                existing_schedule.timezone = Some(timezone);
+                existing_schedule.next_run = None;
+            }
+            MaybeUndefined::Null => {
+                existing_schedule.timezone = None;
+                existing_schedule.next_run = None;
+            }
+            MaybeUndefined::Undefined => {}
+        }
+        if let Some(misfire_policy) = input.misfire_policy {
+            existing_schedule.misfire_policy = misfire_policy;
+        }
+        if let Some(max_concurrent) = input.max_concurrent {
+            existing_schedule.max_concurrent = Some(max_concurrent).filter(|&max| max > 0);
+        }
+        if let Some(jitter_seconds) = input.jitter_seconds {
+            existing_schedule.jitter_seconds = Some(jitter_seconds).filter(|&jitter| jitter > 0);
         }
 
         // Update timestamp
@@ -598,7 +680,7 @@ impl Mutation {
             .await
             .map_err(|e| ApiError::internal_error(format!("Failed to update execution: {}", e)))?;
 
-        Ok(updated_execution)
+        Ok(updated_execution.into())
     }
 
     /// Delete an execution
@@ -715,6 +797,17 @@ impl Mutation {
     async fn execute_task(&self, ctx: &Context<'_>, input: ExecuteTaskInput) -> Result<Job> {
         let context = ctx.data::<GraphQLContext>()?;
 
+        if let Some(ref destinations) = input.output_destinations {
+            if destinations.len() > context.max_output_destinations {
+                return Err(ApiError::bad_request(format!(
+                    "Maximum of {} output destinations allowed per job, got {}",
+                    context.max_output_destinations,
+                    destinations.len()
+                ))
+                .into());
+            }
+        }
+
         // Convert output destinations from input to UnifiedJob format
         let output_destinations = input.output_destinations.map(|destinations| {
             destinations
@@ -759,6 +852,9 @@ impl Mutation {
             scheduled_for: None,
             error_message: None,
             output_destinations,
+            schedule_id: None,
+            execution_id: None,
+            log_level: None,
         };
 
         // Create the job using the repository
@@ -767,4 +863,129 @@ impl Mutation {
 
         Ok(created_job.into())
     }
+
+    /// Execute a task and wait for the execution to finish
+    ///
+    /// Returns the finished execution with its output. When the execution is still
+    /// running after `timeout_ms`, fails with a `TIMEOUT` error whose `executionId`
+    /// extension can be used to poll for the result later.
+    async fn execute_task_sync(
+        &self,
+        ctx: &Context<'_>,
+        task_id: GraphQLApiId,
+        input: Option<JsonValue>,
+        timeout_ms: Option<i32>,
+    ) -> Result<Execution> {
+        let context = ctx.data::<GraphQLContext>()?;
+
+        let timeout = match timeout_ms {
+            None => DEFAULT_SYNC_TIMEOUT_MS,
+            Some(timeout_ms) if timeout_ms <= 0 => {
+                return Err(ApiError::validation_error("timeoutMs", "must be positive").into());
+            }
+            Some(timeout_ms) => (timeout_ms as u64).min(MAX_SYNC_TIMEOUT_MS),
+        };
+
+        context
+            .repositories
+            .task_repository()
+            .find_by_id(task_id.0.as_i32().unwrap_or(0))
+            .await
+            .map_err(|e| ApiError::internal_error(format!("Failed to fetch task: {}", e)))?
+            .ok_or_else(|| ApiError::bad_request("Task not found"))?;
+
+        let input = input.unwrap_or_else(|| serde_json::json!({}));
+        let validator = InputValidator::new();
+        let input_str =
+            serde_json::to_string(&input).map_err(|e| ApiError::bad_request(format!("Invalid input JSON: {}", e)))?;
+        if let Err(validation_err) = validator.validate_json(&input_str) {
+            warn!("Invalid input JSON in GraphQL execute_task_sync: {}", validation_err);
+            let sanitizer = ErrorSanitizer::default();
+            let sanitized_error = sanitizer.sanitize_error(&validation_err);
+            return Err(ApiError::bad_request(&sanitized_error.message).into());
+        }
+
+        // Subscribe before enqueueing so a fast completion cannot be missed
+        let mut updates = context.event_broadcaster.execution_sender().subscribe();
+
+        let execution_repo = context.repositories.execution_repository();
+        let execution = execution_repo
+            .create(ratchet_api_types::UnifiedExecution {
+                id: ratchet_api_types::ApiId::from_i32(0), // Will be set by database
+                uuid: uuid::Uuid::new_v4(),
+                task_id: task_id.0.clone(),
+                input,
+                output: None,
+                status: ratchet_api_types::ExecutionStatus::Pending,
+                error_message: None,
+                error_details: None,
+                queued_at: chrono::Utc::now(),
+                started_at: None,
+                completed_at: None,
+                duration_ms: None,
+                http_requests: None,
+                recording_path: None,
+                attachments: Vec::new(),
+                labels: Default::default(),
+                can_retry: false,
+                can_cancel: true,
+                progress: None,
+            })
+            .await
+            .map_err(|e| ApiError::internal_error(format!("Failed to create execution: {}", e)))?;
+        let execution_id = execution.id.clone();
+
+        // The job processor runs the job as the execution created above
+        context
+            .repositories
+            .job_repository()
+            .create(ratchet_api_types::UnifiedJob {
+                id: ratchet_api_types::ApiId::from_i32(0), // Will be set by database
+                task_id: task_id.0,
+                priority: ratchet_api_types::JobPriority::Normal,
+                status: ratchet_api_types::JobStatus::Queued,
+                retry_count: 0,
+                max_retries: 0,
+                queued_at: chrono::Utc::now(),
+                scheduled_for: None,
+                error_message: None,
+                output_destinations: None,
+                schedule_id: None,
+                execution_id: Some(execution_id.clone()),
+                log_level: None,
+            })
+            .await
+            .map_err(|e| ApiError::internal_error(format!("Failed to enqueue execution: {}", e)))?;
+
+        let finished = async {
+            loop {
+                match updates.recv().await {
+                    Ok(update) if update.id == execution_id && update.status.is_terminal() => return Ok(update),
+                    Ok(_) => {}
+                    // Missed updates may include ours, so check the stored state
+                    Err(RecvError::Lagged(_)) => {
+                        let current = execution_repo
+                            .find_by_id(execution_id.as_i32().unwrap_or(0))
+                            .await
+                            .map_err(|e| ApiError::internal_error(format!("Failed to fetch execution: {}", e)))?;
+                        if let Some(current) = current.filter(|current| current.status.is_terminal()) {
+                            return Ok(current);
+                        }
+                    }
+                    Err(RecvError::Closed) => {
+                        return Err(ApiError::service_unavailable(Some("Execution updates are unavailable")));
+                    }
+                }
+            }
+        };
+
+        match tokio::time::timeout(Duration::from_millis(timeout), finished).await {
+            Ok(result) => Ok(result?.into()),
+            Err(_) => {
+                let execution_id = execution_id.to_string();
+                Err(async_graphql::Error::from(ApiError::timeout("Task execution"))
+                    .extend_with(|_, e| e.set("executionId", execution_id.clone())))
+            }
+        }
+    }
 }
@@ -1,7 +1,10 @@
 //! GraphQL query resolvers
 
 use crate::{context::GraphQLContext, types::*};
-use async_graphql::{Context, Object, Result};
+use async_graphql::{
+    connection::{Connection, Edge},
+    Context, Error, Object, OutputType, Result,
+};
 use ratchet_api_types::{
     pagination::{ListInput, SortInput},
     ApiId,
@@ -25,63 +28,7 @@ impl Query {
         let context = ctx.data::<GraphQLContext>()?;
         let task_repo = context.repositories.task_repository();
 
-        // Convert GraphQL filters to domain filters with comprehensive mapping
-        let domain_filters = filters
-            .map(|f| TaskFilters {
-                // Basic filters (existing)
-                name: f.name_contains.clone(),
-                enabled: f.enabled,
-                registry_source: f.registry_source,
-                validated_after: f.validated_after,
-
-                // Advanced string filtering
-                name_exact: f.name_exact,
-                name_contains: f.name_contains,
-                name_starts_with: f.name_starts_with,
-                name_ends_with: f.name_ends_with,
-
-                // Version filtering
-                version: f.version,
-                version_in: f.version_in,
-
-                // Extended date filtering
-                created_after: f.created_after,
-                created_before: f.created_before,
-                updated_after: f.updated_after,
-                updated_before: f.updated_before,
-                validated_before: f.validated_before,
-
-                // ID filtering
-                uuid: f.uuid,
-                uuid_in: f.uuid_in,
-                id_in: f.id_in,
-
-                // Advanced boolean filtering
-                has_validation: f.has_validation,
-                in_sync: f.in_sync,
-            })
-            .unwrap_or(TaskFilters {
-                name: None,
-                enabled: None,
-                registry_source: None,
-                validated_after: None,
-                name_exact: None,
-                name_contains: None,
-                name_starts_with: None,
-                name_ends_with: None,
-                version: None,
-                version_in: None,
-                created_after: None,
-                created_before: None,
-                updated_after: None,
-                updated_before: None,
-                validated_before: None,
-                uuid: None,
-                uuid_in: None,
-                id_in: None,
-                has_validation: None,
-                in_sync: None,
-            });
+        let domain_filters = filters.map(TaskFilters::from).unwrap_or_default();
 
         // Create list input with pagination and sorting
         let list_input = ListInput {
@@ -140,69 +87,7 @@ impl Query {
         let context = ctx.data::<GraphQLContext>()?;
         let execution_repo = context.repositories.execution_repository();
 
-        // Convert GraphQL filters to domain filters with comprehensive mapping
-        let domain_filters = filters
-            .map(|f| ExecutionFilters {
-                // Basic filters (existing)
-                task_id: f.task_id.map(|id| id.into()),
-                status: f.status,
-                queued_after: f.queued_after,
-                completed_after: f.completed_after,
-
-                // Advanced ID filtering
-                task_id_in: f.task_id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
-                id_in: f.id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
-
-                // Advanced status filtering
-                status_in: f.status_in.map(|statuses| statuses.into_iter().collect()),
-                status_not: f.status_not,
-
-                // Extended date filtering
-                queued_before: f.queued_before,
-                started_after: f.started_after,
-                started_before: f.started_before,
-                completed_before: f.completed_before,
-
-                // Duration filtering
-                duration_min_ms: f.duration_min_ms,
-                duration_max_ms: f.duration_max_ms,
-
-                // Progress filtering
-                progress_min: f.progress_min,
-                progress_max: f.progress_max,
-                has_progress: f.has_progress,
-
-                // Error filtering
-                has_error: f.has_error,
-                error_message_contains: f.error_message_contains,
-
-                // Advanced boolean filtering
-                can_retry: f.can_retry,
-                can_cancel: f.can_cancel,
-            })
-            .unwrap_or(ExecutionFilters {
-                task_id: None,
-                status: None,
-                queued_after: None,
-                completed_after: None,
-                task_id_in: None,
-                id_in: None,
-                status_in: None,
-                status_not: None,
-                queued_before: None,
-                started_after: None,
-                started_before: None,
-                completed_before: None,
-                duration_min_ms: None,
-                duration_max_ms: None,
-                progress_min: None,
-                progress_max: None,
-                has_progress: None,
-                has_error: None,
-                error_message_contains: None,
-                can_retry: None,
-                can_cancel: None,
-            });
+        let domain_filters = filters.map(ExecutionFilters::from).unwrap_or_default();
 
         // Create list input with pagination and sorting
         let list_input = ListInput {
@@ -216,7 +101,7 @@ impl Query {
         };
 
         let result = execution_repo.find_with_list_input(domain_filters, list_input).await?;
-        let items: Vec<Execution> = result.items.into_iter().collect();
+        let items: Vec<Execution> = result.items.into_iter().map(Execution::from).collect();
         let meta = result.meta;
         Ok(ExecutionList { items, meta })
     }
@@ -228,7 +113,7 @@ impl Query {
 
         let api_id: ApiId = id.into();
         match execution_repo.find_by_id(api_id.as_i32().unwrap_or(0)).await? {
-            Some(execution) => Ok(Some(execution)),
+            Some(execution) => Ok(Some(execution.into())),
             None => Ok(None),
         }
     }
@@ -244,71 +129,7 @@ impl Query {
         let context = ctx.data::<GraphQLContext>()?;
         let job_repo = context.repositories.job_repository();
 
-        // Convert GraphQL filters to domain filters with comprehensive mapping
-        let domain_filters = filters
-            .map(|f| JobFilters {
-                // Basic filters (existing)
-                task_id: f.task_id.map(|id| id.into()),
-                status: f.status,
-                priority: f.priority,
-                queued_after: f.queued_after,
-                scheduled_before: f.scheduled_before,
-
-                // Advanced ID filtering
-                task_id_in: f.task_id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
-                id_in: f.id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
-
-                // Advanced status filtering
-                status_in: f.status_in.map(|statuses| statuses.into_iter().collect()),
-                status_not: f.status_not,
-
-                // Advanced priority filtering
-                priority_in: f.priority_in.map(|priorities| priorities.into_iter().collect()),
-                priority_min: f.priority_min,
-
-                // Extended date filtering
-                queued_before: f.queued_before,
-                scheduled_after: f.scheduled_after,
-
-                // Retry filtering
-                retry_count_min: f.retry_count_min,
-                retry_count_max: f.retry_count_max,
-                max_retries_min: f.max_retries_min,
-                max_retries_max: f.max_retries_max,
-                has_retries_remaining: f.has_retries_remaining,
-
-                // Error filtering
-                has_error: f.has_error,
-                error_message_contains: f.error_message_contains,
-
-                // Scheduling filtering
-                is_scheduled: f.is_scheduled,
-                due_now: f.due_now,
-            })
-            .unwrap_or(JobFilters {
-                task_id: None,
-                status: None,
-                priority: None,
-                queued_after: None,
-                scheduled_before: None,
-                task_id_in: None,
-                id_in: None,
-                status_in: None,
-                status_not: None,
-                priority_in: None,
-                priority_min: None,
-                queued_before: None,
-                scheduled_after: None,
-                retry_count_min: None,
-                retry_count_max: None,
-                max_retries_min: None,
-                max_retries_max: None,
-                has_retries_remaining: None,
-                has_error: None,
-                error_message_contains: None,
-                is_scheduled: None,
-                due_now: None,
-            });
+        let domain_filters = filters.map(JobFilters::from).unwrap_or_default();
 
         // Create list input with pagination and sorting
         let list_input = ListInput {
@@ -350,69 +171,7 @@ impl Query {
         let context = ctx.data::<GraphQLContext>()?;
         let schedule_repo = context.repositories.schedule_repository();
 
-        // Convert GraphQL filters to domain filters with comprehensive mapping
-        let domain_filters = filters
-            .map(|f| ScheduleFilters {
-                // Basic filters (existing)
-                task_id: f.task_id.map(|id| id.into()),
-                enabled: f.enabled,
-                next_run_before: f.next_run_before,
-
-                // Advanced ID filtering
-                task_id_in: f.task_id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
-                id_in: f.id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
-
-                // Name filtering
-                name_contains: f.name_contains,
-                name_exact: f.name_exact,
-                name_starts_with: f.name_starts_with,
-                name_ends_with: f.name_ends_with,
-
-                // Cron expression filtering
-                cron_expression_contains: f.cron_expression_contains,
-                cron_expression_exact: f.cron_expression_exact,
-
-                // Schedule timing filtering
-                next_run_after: f.next_run_after,
-                last_run_after: f.last_run_after,
-                last_run_before: f.last_run_before,
-
-                // Date range filtering
-                created_after: f.created_after,
-                created_before: f.created_before,
-                updated_after: f.updated_after,
-                updated_before: f.updated_before,
-
-                // Advanced filtering
-                has_next_run: f.has_next_run,
-                has_last_run: f.has_last_run,
-                is_due: f.is_due,
-                overdue: f.overdue,
-            })
-            .unwrap_or(ScheduleFilters {
-                task_id: None,
-                enabled: None,
-                next_run_before: None,
-                task_id_in: None,
-                id_in: None,
-                name_contains: None,
-                name_exact: None,
-                name_starts_with: None,
-                name_ends_with: None,
-                cron_expression_contains: None,
-                cron_expression_exact: None,
-                next_run_after: None,
-                last_run_after: None,
-                last_run_before: None,
-                created_after: None,
-                created_before: None,
-                updated_after: None,
-                updated_before: None,
-                has_next_run: None,
-                has_last_run: None,
-                is_due: None,
-                overdue: None,
-            });
+        let domain_filters = filters.map(ScheduleFilters::from).unwrap_or_default();
 
         // Create list input with pagination and sorting
         let list_input = ListInput {
@@ -443,6 +202,122 @@ impl Query {
         }
     }
 
+    /// Page through tasks with Relay-style cursors
+    #[graphql(complexity = "connection_complexity(first, child_complexity)")]
+    async fn tasks_connection(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<TaskFiltersInput>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<TaskConnection> {
+        let context = ctx.data::<GraphQLContext>()?;
+        let limit = page_size(first)?;
+        let after = parse_cursor(after)?;
+        let has_previous = after.is_some();
+
+        let tasks = context
+            .repositories
+            .task_repository()
+            .find_after(filters.map(TaskFilters::from).unwrap_or_default(), after, limit + 1)
+            .await?;
+        Ok(build_connection(
+            tasks,
+            limit,
+            has_previous,
+            |task| task.id.to_string(),
+            |task| task,
+        ))
+    }
+
+    /// Page through executions with Relay-style cursors
+    #[graphql(complexity = "connection_complexity(first, child_complexity)")]
+    async fn executions_connection(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<ExecutionFiltersInput>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<ExecutionConnection> {
+        let context = ctx.data::<GraphQLContext>()?;
+        let limit = page_size(first)?;
+        let after = parse_cursor(after)?;
+        let has_previous = after.is_some();
+
+        let executions = context
+            .repositories
+            .execution_repository()
+            .find_after(
+                filters.map(ExecutionFilters::from).unwrap_or_default(),
+                after,
+                limit + 1,
+            )
+            .await?;
+        Ok(build_connection(
+            executions,
+            limit,
+            has_previous,
+            |execution| execution.id.to_string(),
+            Execution::from,
+        ))
+    }
+
+    /// Page through jobs with Relay-style cursors
+    #[graphql(complexity = "connection_complexity(first, child_complexity)")]
+    async fn jobs_connection(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<JobFiltersInput>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<JobConnection> {
+        let context = ctx.data::<GraphQLContext>()?;
+        let limit = page_size(first)?;
+        let after = parse_cursor(after)?;
+        let has_previous = after.is_some();
+
+        let jobs = context
+            .repositories
+            .job_repository()
+            .find_after(filters.map(JobFilters::from).unwrap_or_default(), after, limit + 1)
+            .await?;
+        Ok(build_connection(
+            jobs,
+            limit,
+            has_previous,
+            |job| job.id.to_string(),
+            Job::from,
+        ))
+    }
+
+    /// Page through schedules with Relay-style cursors
+    #[graphql(complexity = "connection_complexity(first, child_complexity)")]
+    async fn schedules_connection(
+        &self,
+        ctx: &Context<'_>,
+        filters: Option<ScheduleFiltersInput>,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<ScheduleConnection> {
+        let context = ctx.data::<GraphQLContext>()?;
+        let limit = page_size(first)?;
+        let after = parse_cursor(after)?;
+        let has_previous = after.is_some();
+
+        let schedules = context
+            .repositories
+            .schedule_repository()
+            .find_after(filters.map(ScheduleFilters::from).unwrap_or_default(), after, limit + 1)
+            .await?;
+        Ok(build_connection(
+            schedules,
+            limit,
+            has_previous,
+            |schedule| schedule.id.to_string(),
+            |schedule| schedule,
+        ))
+    }
+
     /// Get all workers with optional filtering
     async fn workers(
         &self,
@@ -492,3 +367,53 @@ impl Query {
         })
     }
 }
+
+/// Page size used when a connection query omits `first`
+const DEFAULT_PAGE_SIZE: u64 = 50;
+
+/// Upper bound on `first` for connection queries
+const MAX_PAGE_SIZE: u64 = 100;
+
+/// Resolve the requested page size, clamping it to [`MAX_PAGE_SIZE`]
+fn page_size(first: Option<i32>) -> Result<u64> {
+    match first {
+        None => Ok(DEFAULT_PAGE_SIZE),
+        Some(first) if first < 0 => Err(Error::new("`first` must not be negative")),
+        Some(first) => Ok((first as u64).min(MAX_PAGE_SIZE)),
+    }
+}
+
+/// A connection costs its page size times the cost of the selection on each edge
+fn connection_complexity(first: Option<i32>, child_complexity: usize) -> usize {
+    let page = first.map_or(DEFAULT_PAGE_SIZE, |first| (first.max(0) as u64).min(MAX_PAGE_SIZE));
+    (page as usize).saturating_mul(child_complexity)
+}
+
+/// Parse an `after` cursor, rejecting anything that isn't one handed out by a connection
+fn parse_cursor(after: Option<String>) -> Result<Option<ApiId>> {
+    after
+        .map(|cursor| match cursor.parse::<i32>() {
+            Ok(id) => Ok(ApiId::from_i32(id)),
+            Err(_) => Err(Error::new(format!("Invalid cursor: {}", cursor))),
+        })
+        .transpose()
+}
+
+/// Build a connection from a keyset page fetched with one extra row, which
+/// signals whether another page follows.
+fn build_connection<T, N: OutputType>(
+    mut items: Vec<T>,
+    limit: u64,
+    has_previous: bool,
+    cursor: impl Fn(&T) -> String,
+    node: impl Fn(T) -> N,
+) -> Connection<String, N> {
+    let has_next = items.len() as u64 > limit;
+    items.truncate(limit as usize);
+
+    let mut connection = Connection::new(has_previous, has_next);
+    connection
+        .edges
+        .extend(items.into_iter().map(|item| Edge::new(cursor(&item), node(item))));
+    connection
+}
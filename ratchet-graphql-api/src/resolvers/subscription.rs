@@ -12,13 +12,14 @@ pub struct Subscription;
 impl Subscription {
     /// Subscribe to task execution events
     ///
-    /// Subscribe to real-time execution status updates. Optionally filter by task_id.
+    /// Pushes an execution each time it is queued, starts, reports progress,
+    /// or finishes, so dashboards don't need to poll. Optionally filter by task_id.
     ///
     /// # Arguments
     /// * `task_id` - Optional filter to only receive events for a specific task
     ///
     /// # Returns
-    /// Stream of execution objects as they change status
+    /// Stream of executions in their state after each change
     async fn task_executions(
         &self,
         ctx: &Context<'_>,
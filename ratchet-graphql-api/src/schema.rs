@@ -48,10 +48,18 @@ pub async fn graphql_handler(
     axum::extract::Extension(schema): axum::extract::Extension<RatchetSchema>,
     axum::extract::Json(request): axum::extract::Json<async_graphql::Request>,
 ) -> axum::response::Json<async_graphql::Response> {
-    let response = schema.execute(request.data(context)).await;
+    let response = schema.execute(context.attach(request)).await;
     axum::response::Json(response)
 }
 
+/// WebSocket service serving subscriptions over graphql-ws and graphql-transport-ws
+///
+/// Resolvers read the [`GraphQLContext`] and its loaders from schema data here,
+/// so add `context.task_loader` and `context` with `.data(..)` when subscriptions are served.
+pub fn graphql_subscription_service(schema: RatchetSchema) -> async_graphql_axum::GraphQLSubscription<RatchetSchema> {
+    async_graphql_axum::GraphQLSubscription::new(schema)
+}
+
 /// GraphQL playground handler for development
 pub async fn graphql_playground() -> impl IntoResponse {
     use axum::response::Html;
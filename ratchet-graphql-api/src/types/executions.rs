@@ -1,13 +1,36 @@
 //! GraphQL types for executions
 
-use super::scalars::GraphQLApiId;
-use async_graphql::{InputObject, SimpleObject};
+use super::{scalars::GraphQLApiId, Task};
+use crate::loaders::TaskLoader;
+use async_graphql::{dataloader::DataLoader, ComplexObject, Context, InputObject, Result, SimpleObject};
 use chrono::{DateTime, Utc};
 use ratchet_api_types::{ExecutionStatus, UnifiedExecution};
+use ratchet_interfaces::ExecutionFilters;
 use serde_json::Value as JsonValue;
+use std::sync::Arc;
 
-/// GraphQL Execution type - using UnifiedExecution directly for API consistency
-pub type Execution = UnifiedExecution;
+/// GraphQL Execution type - the fields of UnifiedExecution plus related records
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct Execution {
+    #[graphql(flatten)]
+    pub execution: UnifiedExecution,
+}
+
+impl From<UnifiedExecution> for Execution {
+    fn from(execution: UnifiedExecution) -> Self {
+        Self { execution }
+    }
+}
+
+#[ComplexObject]
+impl Execution {
+    /// Task this execution ran, batched across the response through [`TaskLoader`]
+    async fn task(&self, ctx: &Context<'_>) -> Result<Option<Task>> {
+        let loader = ctx.data::<Arc<DataLoader<TaskLoader>>>()?;
+        Ok(loader.load_one(self.execution.task_id.clone()).await?)
+    }
+}
 
 /// GraphQL ExecutionStatus - using unified ExecutionStatus directly
 pub type ExecutionStatusGraphQL = ExecutionStatus;
@@ -71,6 +94,52 @@ pub struct ExecutionFiltersInput {
     pub can_cancel: Option<bool>,
 }
 
+impl From<ExecutionFiltersInput> for ExecutionFilters {
+    fn from(f: ExecutionFiltersInput) -> Self {
+        Self {
+            // Basic filters (existing)
+            task_id: f.task_id.map(|id| id.into()),
+            status: f.status,
+            queued_after: f.queued_after,
+            completed_after: f.completed_after,
+
+            // Advanced ID filtering
+            task_id_in: f.task_id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
+            id_in: f.id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
+
+            // Advanced status filtering
+            status_in: f.status_in.map(|statuses| statuses.into_iter().collect()),
+            status_not: f.status_not,
+
+            // Extended date filtering
+            queued_before: f.queued_before,
+            started_after: f.started_after,
+            started_before: f.started_before,
+            completed_before: f.completed_before,
+
+            // Duration filtering
+            duration_min_ms: f.duration_min_ms,
+            duration_max_ms: f.duration_max_ms,
+
+            // Progress filtering
+            progress_min: f.progress_min,
+            progress_max: f.progress_max,
+            has_progress: f.has_progress,
+
+            // Error filtering
+            has_error: f.has_error,
+            error_message_contains: f.error_message_contains,
+
+            // Advanced boolean filtering
+            can_retry: f.can_retry,
+            can_cancel: f.can_cancel,
+
+            // Label filtering
+            labels: None,
+        }
+    }
+}
+
 /// Execution statistics
 #[derive(SimpleObject)]
 #[graphql(rename_fields = "camelCase")]
@@ -4,6 +4,7 @@ use super::scalars::GraphQLApiId;
 use async_graphql::{InputObject, SimpleObject};
 use chrono::{DateTime, Utc};
 use ratchet_api_types::{JobPriority, JobStatus, UnifiedJob};
+use ratchet_interfaces::JobFilters;
 
 /// GraphQL Job type with additional fields for GraphQL API
 #[derive(SimpleObject, Clone, Debug)]
@@ -19,6 +20,7 @@ pub struct Job {
     pub scheduled_for: Option<DateTime<Utc>>,
     pub error_message: Option<String>,
     pub output_destinations: Option<Vec<OutputDestination>>,
+    pub execution_id: Option<GraphQLApiId>,
 }
 
 impl From<UnifiedJob> for Job {
@@ -50,6 +52,7 @@ impl From<UnifiedJob> for Job {
             scheduled_for: job.scheduled_for,
             error_message: job.error_message,
             output_destinations,
+            execution_id: job.execution_id.map(Into::into),
         }
     }
 }
@@ -67,6 +70,10 @@ pub struct CreateJobInput {
     pub task_id: GraphQLApiId,
     pub priority: Option<JobPriorityGraphQL>,
     pub scheduled_for: Option<DateTime<Utc>>,
+    /// Run the job this many seconds from now instead of at `scheduledFor`
+    pub delay_seconds: Option<u64>,
+    /// Spread the run time randomly over this many seconds (at most 3600)
+    pub jitter_seconds: Option<u32>,
     pub max_retries: Option<i32>,
 }
 
@@ -122,6 +129,52 @@ pub struct JobFiltersInput {
     pub due_now: Option<bool>, // scheduled_for <= now
 }
 
+impl From<JobFiltersInput> for JobFilters {
+    fn from(f: JobFiltersInput) -> Self {
+        Self {
+            // Basic filters (existing)
+            task_id: f.task_id.map(|id| id.into()),
+            status: f.status,
+            priority: f.priority,
+            queued_after: f.queued_after,
+            scheduled_before: f.scheduled_before,
+
+            // Advanced ID filtering
+            task_id_in: f.task_id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
+            id_in: f.id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
+
+            // Advanced status filtering
+            status_in: f.status_in.map(|statuses| statuses.into_iter().collect()),
+            status_not: f.status_not,
+
+            // Advanced priority filtering
+            priority_in: f.priority_in.map(|priorities| priorities.into_iter().collect()),
+            priority_min: f.priority_min,
+
+            // Extended date filtering
+            queued_before: f.queued_before,
+            scheduled_after: f.scheduled_after,
+            completed_after: None,
+            completed_before: None,
+
+            // Retry filtering
+            retry_count_min: f.retry_count_min,
+            retry_count_max: f.retry_count_max,
+            max_retries_min: f.max_retries_min,
+            max_retries_max: f.max_retries_max,
+            has_retries_remaining: f.has_retries_remaining,
+
+            // Error filtering
+            has_error: f.has_error,
+            error_message_contains: f.error_message_contains,
+
+            // Scheduling filtering
+            is_scheduled: f.is_scheduled,
+            due_now: f.due_now,
+        }
+    }
+}
+
 /// Job statistics
 #[derive(SimpleObject)]
 #[graphql(rename_fields = "camelCase")]
@@ -1,6 +1,6 @@
 //! GraphQL type definitions
 
-use async_graphql::SimpleObject;
+use async_graphql::{connection::Connection, SimpleObject};
 use ratchet_api_types::pagination::PaginationMeta;
 
 pub mod executions;
@@ -49,6 +49,18 @@ pub struct ScheduleList {
     pub meta: PaginationMetaGraphQL,
 }
 
+/// Cursor-paginated task connection
+pub type TaskConnection = Connection<String, Task>;
+
+/// Cursor-paginated execution connection
+pub type ExecutionConnection = Connection<String, Execution>;
+
+/// Cursor-paginated job connection
+pub type JobConnection = Connection<String, Job>;
+
+/// Cursor-paginated schedule connection
+pub type ScheduleConnection = Connection<String, Schedule>;
+
 /// Paginated worker response
 #[derive(SimpleObject)]
 pub struct WorkerList {
@@ -1,9 +1,10 @@
 //! GraphQL types for schedules
 
 use super::scalars::GraphQLApiId;
-use async_graphql::{InputObject, SimpleObject};
+use async_graphql::{InputObject, MaybeUndefined, SimpleObject};
 use chrono::{DateTime, Utc};
-use ratchet_api_types::UnifiedSchedule;
+use ratchet_api_types::{MisfirePolicy, UnifiedSchedule};
+use ratchet_interfaces::ScheduleFilters;
 
 /// GraphQL Schedule type - using UnifiedSchedule directly for API consistency
 pub type Schedule = UnifiedSchedule;
@@ -17,6 +18,11 @@ pub struct CreateScheduleInput {
     pub description: Option<String>,
     pub cron_expression: String,
     pub enabled: Option<bool>,
+    pub serial: Option<bool>,
+    pub timezone: Option<String>,
+    pub misfire_policy: Option<MisfirePolicy>,
+    pub max_concurrent: Option<u32>,
+    pub jitter_seconds: Option<u32>,
 }
 
 /// Input type for updating schedules
@@ -27,6 +33,12 @@ pub struct UpdateScheduleInput {
     pub description: Option<String>,
     pub cron_expression: Option<String>,
     pub enabled: Option<bool>,
+    pub serial: Option<bool>,
+    /// New IANA timezone, or `null` to go back to evaluating the schedule in UTC
+    pub timezone: MaybeUndefined<String>,
+    pub misfire_policy: Option<MisfirePolicy>,
+    pub max_concurrent: Option<u32>,
+    pub jitter_seconds: Option<u32>,
 }
 
 /// Input type for schedule filtering
@@ -70,6 +82,49 @@ pub struct ScheduleFiltersInput {
     pub overdue: Option<bool>, // next_run < now and enabled
 }
 
+impl From<ScheduleFiltersInput> for ScheduleFilters {
+    fn from(f: ScheduleFiltersInput) -> Self {
+        Self {
+            // Basic filters (existing)
+            task_id: f.task_id.map(|id| id.into()),
+            enabled: f.enabled,
+            next_run_before: f.next_run_before,
+
+            // Advanced ID filtering
+            task_id_in: f.task_id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
+            id_in: f.id_in.map(|ids| ids.into_iter().map(|id| id.into()).collect()),
+
+            // Name filtering
+            name_contains: f.name_contains,
+            name_exact: f.name_exact,
+            name_starts_with: f.name_starts_with,
+            name_ends_with: f.name_ends_with,
+
+            // Cron expression filtering
+            cron_expression_contains: f.cron_expression_contains,
+            cron_expression_exact: f.cron_expression_exact,
+
+            // Schedule timing filtering
+            next_run_after: f.next_run_after,
+            last_run_after: f.last_run_after,
+            last_run_before: f.last_run_before,
+
+            // Date range filtering
+            created_after: f.created_after,
+            created_before: f.created_before,
+            updated_after: f.updated_after,
+            updated_before: f.updated_before,
+
+            // Advanced filtering
+            has_next_run: f.has_next_run,
+            has_last_run: f.has_last_run,
+            is_due: f.is_due,
+            overdue: f.overdue,
+            paused: None,
+        }
+    }
+}
+
 /// Schedule statistics
 #[derive(SimpleObject)]
 #[graphql(rename_fields = "camelCase")]
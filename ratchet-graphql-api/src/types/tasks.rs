@@ -3,6 +3,7 @@
 use async_graphql::{InputObject, SimpleObject};
 use chrono::{DateTime, Utc};
 use ratchet_api_types::UnifiedTask;
+use ratchet_interfaces::TaskFilters;
 use serde_json::Value as JsonValue;
 
 /// GraphQL Task type - using UnifiedTask directly for API consistency
@@ -68,6 +69,45 @@ pub struct TaskFiltersInput {
     pub in_sync: Option<bool>,
 }
 
+impl From<TaskFiltersInput> for TaskFilters {
+    fn from(f: TaskFiltersInput) -> Self {
+        Self {
+            // Basic filters (existing)
+            name: f.name_contains.clone(),
+            enabled: f.enabled,
+            registry_source: f.registry_source,
+            validated_after: f.validated_after,
+
+            // Advanced string filtering
+            name_exact: f.name_exact,
+            name_contains: f.name_contains,
+            name_starts_with: f.name_starts_with,
+            name_ends_with: f.name_ends_with,
+            search: None,
+
+            // Version filtering
+            version: f.version,
+            version_in: f.version_in,
+
+            // Extended date filtering
+            created_after: f.created_after,
+            created_before: f.created_before,
+            updated_after: f.updated_after,
+            updated_before: f.updated_before,
+            validated_before: f.validated_before,
+
+            // ID filtering
+            uuid: f.uuid,
+            uuid_in: f.uuid_in,
+            id_in: f.id_in,
+
+            // Advanced boolean filtering
+            has_validation: f.has_validation,
+            in_sync: f.in_sync,
+        }
+    }
+}
+
 /// Task statistics
 #[derive(async_graphql::SimpleObject)]
 #[graphql(rename_fields = "camelCase")]
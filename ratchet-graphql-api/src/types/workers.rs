@@ -19,6 +19,7 @@ pub struct Worker {
 
 /// GraphQL enum for worker status
 #[derive(Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[graphql(rename_items = "SCREAMING_SNAKE_CASE")]
 pub enum WorkerStatusGraphQL {
     Idle,
     Running,
@@ -89,7 +89,7 @@ impl GraphQLTestServer {
 
     /// Execute a GraphQL request with context
     async fn execute_request(&self, request: Request) -> Response {
-        self.schema.execute(request.data(self.context.clone())).await
+        self.schema.execute(self.context.attach(request)).await
     }
 }
 
@@ -211,12 +211,33 @@ mod mocks {
         }
     }
 
+    #[async_trait]
+    impl ratchet_interfaces::KeysetRepository<UnifiedTask, TaskFilters> for MockTaskRepository {
+        async fn find_after(
+            &self,
+            _filters: TaskFilters,
+            after: Option<ApiId>,
+            _limit: u64,
+        ) -> Result<Vec<UnifiedTask>, DatabaseError> {
+            Ok(if after.is_none() {
+                vec![create_test_task()]
+            } else {
+                Vec::new()
+            })
+        }
+    }
+
     #[async_trait]
     impl ratchet_interfaces::TaskRepository for MockTaskRepository {
         async fn find_enabled(&self) -> Result<Vec<UnifiedTask>, DatabaseError> {
             Ok(vec![create_test_task()])
         }
 
+        async fn find_by_ids(&self, ids: Vec<ApiId>) -> Result<Vec<UnifiedTask>, DatabaseError> {
+            let task = create_test_task();
+            Ok(if ids.contains(&task.id) { vec![task] } else { Vec::new() })
+        }
+
         async fn find_by_name(&self, _name: &str) -> Result<Option<UnifiedTask>, DatabaseError> {
             Ok(Some(create_test_task()))
         }
@@ -295,6 +316,18 @@ mod mocks {
             Ok(1)
         }
     }
+    #[async_trait]
+    impl ratchet_interfaces::KeysetRepository<UnifiedExecution, ExecutionFilters> for MockExecutionRepository {
+        async fn find_after(
+            &self,
+            _filters: ExecutionFilters,
+            _after: Option<ApiId>,
+            _limit: u64,
+        ) -> Result<Vec<UnifiedExecution>, DatabaseError> {
+            Ok(Vec::new())
+        }
+    }
+
     #[async_trait]
     impl ratchet_interfaces::ExecutionRepository for MockExecutionRepository {
         async fn find_by_task_id(&self, _task_id: ApiId) -> Result<Vec<UnifiedExecution>, DatabaseError> {
@@ -331,6 +364,24 @@ mod mocks {
         async fn update_progress(&self, _id: ApiId, _progress: f32) -> Result<(), DatabaseError> {
             Ok(())
         }
+        async fn store_logs(
+            &self,
+            _id: ApiId,
+            _logs: Vec<ratchet_interfaces::logging::LogEvent>,
+        ) -> Result<(), DatabaseError> {
+            Ok(())
+        }
+        async fn find_logs(&self, _id: ApiId) -> Result<Vec<ratchet_interfaces::logging::LogEvent>, DatabaseError> {
+            Ok(vec![])
+        }
+        async fn history(
+            &self,
+            _from: chrono::DateTime<Utc>,
+            _to: chrono::DateTime<Utc>,
+            _interval_seconds: i64,
+        ) -> Result<Vec<ratchet_interfaces::ExecutionHistoryBucket>, DatabaseError> {
+            Ok(vec![])
+        }
     }
 
     pub struct MockJobRepository;
@@ -393,6 +444,18 @@ mod mocks {
             Ok(1)
         }
     }
+    #[async_trait]
+    impl ratchet_interfaces::KeysetRepository<UnifiedJob, JobFilters> for MockJobRepository {
+        async fn find_after(
+            &self,
+            _filters: JobFilters,
+            _after: Option<ApiId>,
+            _limit: u64,
+        ) -> Result<Vec<UnifiedJob>, DatabaseError> {
+            Ok(Vec::new())
+        }
+    }
+
     #[async_trait]
     impl ratchet_interfaces::JobRepository for MockJobRepository {
         async fn find_ready_for_processing(&self, _limit: u64) -> Result<Vec<UnifiedJob>, DatabaseError> {
@@ -401,6 +464,9 @@ mod mocks {
         async fn find_by_status(&self, _status: JobStatus) -> Result<Vec<UnifiedJob>, DatabaseError> {
             Ok(vec![create_test_job()])
         }
+        async fn find_active_by_schedule(&self, _schedule_id: ApiId) -> Result<Vec<UnifiedJob>, DatabaseError> {
+            Ok(vec![])
+        }
         async fn mark_processing(&self, _id: ApiId, _execution_id: ApiId) -> Result<(), DatabaseError> {
             Ok(())
         }
@@ -415,12 +481,23 @@ mod mocks {
         ) -> Result<bool, DatabaseError> {
             Ok(true)
         }
+        async fn mark_dead_letter(
+            &self,
+            _id: ApiId,
+            _error: String,
+            _details: Option<serde_json::Value>,
+        ) -> Result<(), DatabaseError> {
+            Ok(())
+        }
         async fn schedule_retry(&self, _id: ApiId, _retry_at: chrono::DateTime<Utc>) -> Result<(), DatabaseError> {
             Ok(())
         }
         async fn cancel(&self, _id: ApiId) -> Result<(), DatabaseError> {
             Ok(())
         }
+        async fn requeue_dead_letter(&self, _id: ApiId) -> Result<bool, DatabaseError> {
+            Ok(true)
+        }
     }
 
     pub struct MockScheduleRepository;
@@ -435,8 +512,11 @@ mod mocks {
         async fn create(&self, entity: UnifiedSchedule) -> Result<UnifiedSchedule, DatabaseError> {
             Ok(entity)
         }
-        async fn find_by_id(&self, _id: i32) -> Result<Option<UnifiedSchedule>, DatabaseError> {
-            Ok(Some(create_test_schedule()))
+        async fn find_by_id(&self, id: i32) -> Result<Option<UnifiedSchedule>, DatabaseError> {
+            match id {
+                PAUSED_SCHEDULE_ID => Ok(Some(create_paused_schedule())),
+                _ => Ok(Some(create_test_schedule())),
+            }
         }
         async fn find_by_uuid(&self, _uuid: Uuid) -> Result<Option<UnifiedSchedule>, DatabaseError> {
             Ok(Some(create_test_schedule()))
@@ -483,6 +563,18 @@ mod mocks {
             Ok(1)
         }
     }
+    #[async_trait]
+    impl ratchet_interfaces::KeysetRepository<UnifiedSchedule, ScheduleFilters> for MockScheduleRepository {
+        async fn find_after(
+            &self,
+            _filters: ScheduleFilters,
+            _after: Option<ApiId>,
+            _limit: u64,
+        ) -> Result<Vec<UnifiedSchedule>, DatabaseError> {
+            Ok(Vec::new())
+        }
+    }
+
     #[async_trait]
     impl ratchet_interfaces::ScheduleRepository for MockScheduleRepository {
         async fn find_enabled(&self) -> Result<Vec<UnifiedSchedule>, DatabaseError> {
@@ -500,6 +592,16 @@ mod mocks {
         async fn set_enabled(&self, _id: ApiId, _enabled: bool) -> Result<(), DatabaseError> {
             Ok(())
         }
+        async fn set_enabled_many(&self, ids: Vec<ApiId>, enabled: bool) -> Result<Vec<UnifiedSchedule>, DatabaseError> {
+            Ok(ids
+                .into_iter()
+                .map(|id| UnifiedSchedule {
+                    id,
+                    enabled,
+                    ..create_test_schedule()
+                })
+                .collect())
+        }
     }
 
     // Mock authentication repositories
@@ -921,6 +1023,8 @@ fn create_test_execution() -> UnifiedExecution {
         duration_ms: Some(1000),
         http_requests: None,
         recording_path: None,
+        attachments: Vec::new(),
+        labels: Default::default(),
         can_retry: false,
         can_cancel: false,
         progress: Some(100.0),
@@ -939,6 +1043,9 @@ fn create_test_job() -> UnifiedJob {
         scheduled_for: None,
         error_message: None,
         output_destinations: None,
+        schedule_id: None,
+        execution_id: None,
+        log_level: None,
     }
 }
 
@@ -955,6 +1062,25 @@ fn create_test_schedule() -> UnifiedSchedule {
         created_at: Utc::now(),
         updated_at: Utc::now(),
         output_destinations: None,
+        serial: false,
+        timezone: None,
+        misfire_policy: Default::default(),
+        max_concurrent: None,
+        jitter_seconds: None,
+        paused: false,
+    }
+}
+
+/// ID the mock schedule repository answers with a schedule disabled by pause-all
+const PAUSED_SCHEDULE_ID: i32 = 2;
+
+fn create_paused_schedule() -> UnifiedSchedule {
+    UnifiedSchedule {
+        id: ApiId::from_i32(PAUSED_SCHEDULE_ID),
+        name: "paused-schedule".to_string(),
+        enabled: false,
+        paused: true,
+        ..create_test_schedule()
     }
 }
 
@@ -1189,6 +1315,160 @@ async fn test_executions_query() {
     assert!(data_str.contains("COMPLETED"));
 }
 
+#[tokio::test]
+async fn test_execution_task_field() {
+    let server = GraphQLTestServer::new().await;
+
+    let query = r#"
+        {
+            executions {
+                items {
+                    id
+                    task {
+                        id
+                        name
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response = server.execute(query).await;
+
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data,
+        value!({ "executions": { "items": [{ "id": "1", "task": { "id": "1", "name": "test-task" } }] } })
+    );
+}
+
+#[tokio::test]
+async fn test_tasks_connection() {
+    let server = GraphQLTestServer::new().await;
+
+    let query = r#"
+        {
+            tasksConnection(first: 10) {
+                edges {
+                    cursor
+                    node {
+                        name
+                    }
+                }
+                pageInfo {
+                    hasNextPage
+                    hasPreviousPage
+                }
+            }
+        }
+    "#;
+
+    let response = server.execute(query).await;
+
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data,
+        value!({
+            "tasksConnection": {
+                "edges": [{ "cursor": "1", "node": { "name": "test-task" } }],
+                "pageInfo": { "hasNextPage": false, "hasPreviousPage": false }
+            }
+        })
+    );
+
+    let response = server
+        .execute(r#"{ tasksConnection(first: -1) { edges { cursor } } }"#)
+        .await;
+    assert!(!response.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_tasks_connection_pages_after_cursor() {
+    let server = GraphQLTestServer::new().await;
+
+    let query = r#"
+        query Page($first: Int, $after: String) {
+            tasksConnection(first: $first, after: $after) {
+                edges {
+                    cursor
+                }
+                pageInfo {
+                    hasNextPage
+                    hasPreviousPage
+                }
+            }
+        }
+    "#;
+
+    // The task left out of an empty page signals that another page follows
+    let response = server
+        .execute_with_variables(query, Variables::from_json(json!({ "first": 0 })))
+        .await;
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data,
+        value!({
+            "tasksConnection": {
+                "edges": [],
+                "pageInfo": { "hasNextPage": true, "hasPreviousPage": false }
+            }
+        })
+    );
+
+    let response = server
+        .execute_with_variables(query, Variables::from_json(json!({ "first": 10, "after": "1" })))
+        .await;
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data,
+        value!({
+            "tasksConnection": {
+                "edges": [],
+                "pageInfo": { "hasNextPage": false, "hasPreviousPage": true }
+            }
+        })
+    );
+
+    let response = server
+        .execute_with_variables(query, Variables::from_json(json!({ "after": "not-a-cursor" })))
+        .await;
+    assert_eq!(response.errors.len(), 1);
+    assert_eq!(response.errors[0].message, "Invalid cursor: not-a-cursor");
+}
+
+#[tokio::test]
+async fn test_task_loader_batches_lookups() {
+    use async_graphql::dataloader::DataLoader;
+    use futures_util::future::join_all;
+    use ratchet_graphql_api::loaders::TaskLoader;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let batches = Arc::new(AtomicUsize::new(0));
+    let loader = {
+        let batches = batches.clone();
+        TaskLoader::new(move |ids: Vec<ApiId>| {
+            batches.fetch_add(1, Ordering::SeqCst);
+            async move {
+                Ok(ids
+                    .into_iter()
+                    .map(|id| UnifiedTask {
+                        id,
+                        ..create_test_task()
+                    })
+                    .collect())
+            }
+        })
+    };
+    let loader = DataLoader::new(loader, tokio::spawn);
+
+    // One lookup per execution row, as the `task` field resolver makes them
+    let lookups = (1..=20).map(|id| loader.load_one(ApiId::from_i32(id % 5 + 1)));
+    let tasks = join_all(lookups).await;
+
+    assert!(tasks.iter().all(|task| matches!(task, Ok(Some(_)))));
+    assert_eq!(batches.load(Ordering::SeqCst), 1);
+}
+
 #[tokio::test]
 async fn test_jobs_query() {
     let server = GraphQLTestServer::new().await;
@@ -1284,6 +1564,120 @@ async fn test_create_task_mutation() {
     assert!(data_str.contains("new-test-task"));
 }
 
+#[tokio::test]
+async fn test_create_schedule_validates_cron_expression() {
+    let server = GraphQLTestServer::new().await;
+
+    let mutation = r#"
+        mutation CreateSchedule($input: CreateScheduleInput!) {
+            createSchedule(input: $input) {
+                name
+                cronExpression
+            }
+        }
+    "#;
+    let input = |cron_expression: &str| {
+        Variables::from_json(json!({
+            "input": {
+                "taskId": "1",
+                "name": "nightly",
+                "cronExpression": cron_expression
+            }
+        }))
+    };
+
+    // Expressions the REST API rejects are rejected here too
+    for invalid in ["not a cron", "* * *", "61 * * * *"] {
+        let response = server.execute_with_variables(mutation, input(invalid)).await;
+        assert_eq!(response.errors.len(), 1, "{} should be rejected", invalid);
+        assert!(
+            response.errors[0].message.contains("cron"),
+            "{}",
+            response.errors[0].message
+        );
+    }
+
+    let response = server.execute_with_variables(mutation, input("0 9 * * Mon-Fri")).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "createSchedule": { "name": "nightly", "cronExpression": "0 9 * * Mon-Fri" } })
+    );
+}
+
+#[tokio::test]
+async fn test_update_schedule_clears_timezone_with_null() {
+    let server = GraphQLTestServer::new().await;
+
+    let mutation = r#"
+        mutation UpdateSchedule($input: UpdateScheduleInput!) {
+            updateSchedule(id: "1", input: $input) {
+                timezone
+                nextRun
+            }
+        }
+    "#;
+    let update = |input: serde_json::Value| {
+        server.execute_with_variables(mutation, Variables::from_json(json!({ "input": input })))
+    };
+
+    let response = update(json!({ "timezone": "Europe/Amsterdam" })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "updateSchedule": { "timezone": "Europe/Amsterdam", "nextRun": null } })
+    );
+
+    // An explicit null clears the timezone and the run times computed in it
+    let response = update(json!({ "timezone": null })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "updateSchedule": { "timezone": null, "nextRun": null } })
+    );
+
+    // Leaving the timezone out keeps the schedule's run times
+    let response = update(json!({ "name": "renamed" })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    let data = response.data.into_json().unwrap();
+    assert!(data["updateSchedule"]["nextRun"].is_string());
+}
+
+#[tokio::test]
+async fn test_update_schedule_enabled_clears_paused() {
+    let server = GraphQLTestServer::new().await;
+
+    let mutation = r#"
+        mutation UpdateSchedule($input: UpdateScheduleInput!) {
+            updateSchedule(id: "2", input: $input) {
+                enabled
+                paused
+            }
+        }
+    "#;
+    let update = |input: serde_json::Value| {
+        server.execute_with_variables(mutation, Variables::from_json(json!({ "input": input })))
+    };
+
+    // Other changes leave a pending resume in place
+    let response = update(json!({ "name": "renamed" })).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data,
+        value!({ "updateSchedule": { "enabled": false, "paused": true } })
+    );
+
+    // Like the REST API, an explicit enable or disable cancels it
+    for enabled in [true, false] {
+        let response = update(json!({ "enabled": enabled })).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data,
+            value!({ "updateSchedule": { "enabled": enabled, "paused": false } })
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_query_depth_limit() {
     let config = GraphQLTestConfig {
@@ -1320,6 +1714,30 @@ async fn test_query_depth_limit() {
     assert!(!response.errors.is_empty());
 }
 
+#[tokio::test]
+async fn test_connection_complexity_limit() {
+    let server = GraphQLTestServer::new().await;
+
+    // A full page of 100 edges costs far more than the test budget of 100
+    let query = r#"
+        {
+            tasksConnection(first: 100) {
+                edges {
+                    cursor
+                    node {
+                        name
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response = server.execute(query).await;
+
+    assert_eq!(response.errors.len(), 1);
+    assert!(response.errors[0].message.contains("too complex"));
+}
+
 #[tokio::test]
 async fn test_invalid_query_syntax() {
     let server = GraphQLTestServer::new().await;
@@ -1357,8 +1775,91 @@ async fn test_query_with_variables_validation() {
     assert!(!response.errors.is_empty());
 }
 
+#[tokio::test]
+async fn test_task_executions_subscription_receives_lifecycle_updates() {
+    use futures_util::StreamExt;
+
+    let server = GraphQLTestServer::new().await;
+    let broadcaster = server.context.event_broadcaster.clone();
+
+    let request = server.context.attach(Request::new(
+        r#"subscription { taskExecutions(taskId: "1") { id taskId status progress } }"#,
+    ));
+    let mut stream = server.schema.execute_stream(request);
+    let next_update = tokio::spawn(async move { stream.next().await });
+
+    // The subscription attaches to the channel once the stream is first polled
+    while broadcaster.execution_subscriber_count() == 0 {
+        tokio::task::yield_now().await;
+    }
+
+    let mut other_task = create_test_execution();
+    other_task.id = ApiId::from_i32(2);
+    other_task.task_id = ApiId::from_i32(2);
+    broadcaster.broadcast_execution(other_task);
+
+    let mut running = create_test_execution();
+    running.status = ExecutionStatus::Running;
+    running.progress = Some(0.5);
+    broadcaster.broadcast_execution(running);
+
+    let response = tokio::time::timeout(std::time::Duration::from_secs(5), next_update)
+        .await
+        .expect("subscription should yield an update")
+        .unwrap()
+        .expect("subscription stream ended early");
+
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data,
+        value!({ "taskExecutions": { "id": "1", "taskId": "1", "status": "RUNNING", "progress": 0.5 } })
+    );
+}
+
+#[tokio::test]
+async fn test_execute_task_sync_returns_finished_execution() {
+    let server = GraphQLTestServer::new().await;
+    let broadcaster = server.context.event_broadcaster.clone();
+
+    // Stand in for the job processor finishing the execution the mutation enqueued
+    tokio::spawn(async move {
+        while broadcaster.execution_subscriber_count() == 0 {
+            tokio::task::yield_now().await;
+        }
+        let mut finished = create_test_execution();
+        finished.id = ApiId::from_i32(0);
+        broadcaster.broadcast_execution(finished);
+    });
+
+    let response = server
+        .execute(r#"mutation { executeTaskSync(taskId: "1", input: {}, timeoutMs: 5000) { id status output } }"#)
+        .await;
+
+    assert!(response.errors.is_empty());
+    assert_eq!(
+        response.data,
+        value!({ "executeTaskSync": { "id": "0", "status": "COMPLETED", "output": { "result": "test" } } })
+    );
+}
+
+#[tokio::test]
+async fn test_execute_task_sync_timeout_returns_execution_id() {
+    let server = GraphQLTestServer::new().await;
+
+    let response = server
+        .execute(r#"mutation { executeTaskSync(taskId: "1", timeoutMs: 50) { id } }"#)
+        .await;
+
+    assert_eq!(response.errors.len(), 1);
+    let extensions = response.errors[0]
+        .extensions
+        .as_ref()
+        .expect("timeout error has extensions");
+    assert_eq!(extensions.get("code"), Some(&value!("TIMEOUT")));
+    assert_eq!(extensions.get("executionId"), Some(&value!("0")));
+}
+
 // TODO: Add tests for:
-// - Subscription functionality
 // - Error handling scenarios
 // - Complex nested queries
 // - Performance under load
@@ -1,7 +1,9 @@
 //! HTTP client implementation
 
-use crate::config::HttpConfig;
-use crate::errors::HttpError;
+use crate::config::{check_fetch_access, FetchAccessConfig, HostSettings, HttpConfig};
+use crate::errors::{BodyDirection, HttpError};
+use crate::mock::{MockRegistry, MockResponse, RequestMatcher};
+use crate::stream::HttpStream;
 use crate::types::HttpMethod;
 use anyhow::Result;
 use chrono::Utc;
@@ -13,6 +15,7 @@ use reqwest::{
 use serde_json::{json, Value as JsonValue};
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
 use tracing::{debug, info, warn};
 
 /// HTTP client trait for making HTTP requests
@@ -24,6 +27,18 @@ pub trait HttpClient: Send + Sync {
         params: Option<&JsonValue>,
         body: Option<&JsonValue>,
     ) -> Result<JsonValue, HttpError>;
+
+    /// Perform an HTTP request, leaving the response body to be read incrementally
+    ///
+    /// The default implementation buffers the response of [`HttpClient::call_http`].
+    async fn open_stream(
+        &self,
+        url: &str,
+        params: Option<&JsonValue>,
+        body: Option<&JsonValue>,
+    ) -> Result<HttpStream, HttpError> {
+        self.call_http(url, params, body).await.map(HttpStream::buffered)
+    }
 }
 
 /// HTTP Manager for handling HTTP requests with mock support
@@ -31,7 +46,10 @@ pub trait HttpClient: Send + Sync {
 pub struct HttpManager {
     offline: bool,
     mocks: HashMap<String, JsonValue>,
+    mock_registry: MockRegistry,
     config: HttpConfig,
+    /// Clients built so far, one per distinct set of host settings; clones share them
+    clients: Arc<Mutex<HashMap<HostSettings, Client>>>,
 }
 
 impl Default for HttpManager {
@@ -52,10 +70,17 @@ impl HttpManager {
         Self {
             offline: false,
             mocks: HashMap::new(),
+            mock_registry: MockRegistry::default(),
             config,
+            clients: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Configuration this manager sends requests with
+    pub fn config(&self) -> &HttpConfig {
+        &self.config
+    }
+
     /// Set offline mode
     pub fn set_offline(&mut self) {
         self.offline = true;
@@ -94,9 +119,19 @@ impl HttpManager {
         Ok(())
     }
 
+    /// Register a canned response for requests accepted by `matcher`
+    ///
+    /// In offline mode registered mocks are consulted in registration order,
+    /// before the mocks keyed by method and URL.
+    pub fn register_mock(&mut self, matcher: RequestMatcher, response: MockResponse) {
+        debug!("Registered HTTP mock for {:?}", matcher);
+        self.mock_registry.register(matcher, response);
+    }
+
     /// Clear all mocks
     pub fn clear_mocks(&mut self) {
         self.mocks.clear();
+        self.mock_registry.clear();
         debug!("Cleared all HTTP mocks");
     }
 }
@@ -116,20 +151,20 @@ impl HttpClient for HttpManager {
         debug!("Request params: {:?}", params);
         debug!("Request body: {:?}", body);
 
-        // Extract method from params or default to GET
-        let method = if let Some(params) = params {
-            if let Some(method_str) = params.get("method").and_then(|m| m.as_str()) {
-                method_str.parse().unwrap_or(HttpMethod::Get)
-            } else {
-                HttpMethod::Get
-            }
-        } else {
-            HttpMethod::Get
-        };
+        let method = request_method(params);
+
+        // Extract headers for mock matching and recording
+        let request_headers = request_headers(params);
 
         // Check if we're in offline mode and return mock data if available
         if self.offline {
             debug!("Offline mode enabled, checking for mock response");
+            let headers = request_headers.clone().unwrap_or_default();
+            if let Some(mock) = self.mock_registry.find(method, url, &headers, body) {
+                debug!("Found registered mock response for {} {}", method, url);
+                return Ok(mock.to_response());
+            }
+
             let mock_key = format!("{}:{}", method.as_str(), url);
 
             if let Some(mock_response) = self.mocks.get(&mock_key) {
@@ -170,41 +205,227 @@ impl HttpClient for HttpManager {
             }
         }
 
+        // Convert body to string for recording
+        let request_body_str = request_body_string(body);
+
         // If no mock data or mock doesn't match, perform a real HTTP request
-        debug!("Creating HTTP client with {}s timeout", self.config.timeout.as_secs());
-        // Create a client with configured settings
-        let client = Client::builder()
-            .timeout(self.config.timeout)
-            .user_agent(&self.config.user_agent)
-            .danger_accept_invalid_certs(!self.config.verify_ssl)
-            .redirect(reqwest::redirect::Policy::limited(self.config.max_redirects as usize))
-            .build()?;
-
-        // Extract headers for recording
-        let request_headers: Option<HashMap<String, String>> = if let Some(params) = params {
-            params.get("headers").and_then(|h| h.as_object()).map(|headers| {
-                headers
-                    .iter()
-                    .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
-                    .collect()
-            })
-        } else {
-            None
+        let response = self
+            .send_request(method, url, params, body, request_body_str.as_deref())
+            .await?;
+
+        // Get status info
+        let status = response.status();
+        let status_code = status.as_u16();
+        let status_text = status.canonical_reason().unwrap_or("Unknown Status");
+
+        info!("HTTP response received: {} {}", status_code, status_text);
+
+        // Collect response headers for recording
+        let response_headers = response_headers(&response);
+
+        let body_bytes = read_limited_body(response, self.config.max_response_size).await?;
+
+        // Try to parse the response as JSON, fall back to text if it fails
+        debug!("Parsing response body");
+        let response_body = match serde_json::from_slice::<JsonValue>(&body_bytes) {
+            Ok(json_data) => {
+                debug!("Successfully parsed response as JSON");
+                json_data
+            }
+            Err(_) => {
+                warn!("Failed to parse response as JSON, falling back to text");
+                let text = String::from_utf8_lossy(&body_bytes).into_owned();
+                debug!("Response parsed as text: {} bytes", text.len());
+                json!(text)
+            }
         };
 
-        // Convert body to string for recording
-        let request_body_str = body.map(|b| {
-            if let Some(s) = b.as_str() {
-                s.to_string()
-            } else {
-                serde_json::to_string(b).unwrap_or_default()
+        // Record the HTTP request if recording is enabled
+        #[cfg(feature = "recording")]
+        {
+            let end_time = Utc::now();
+            let duration_ms = (end_time - start_time).num_milliseconds() as u64;
+
+            if crate::recording::is_recording() {
+                let response_body_str = serde_json::to_string(&response_body).unwrap_or_default();
+                if let Err(e) = crate::recording::record_http_request(
+                    url,
+                    method.as_str(),
+                    request_headers.as_ref(),
+                    request_body_str.as_deref(),
+                    status_code,
+                    Some(&response_headers),
+                    &response_body_str,
+                    start_time,
+                    duration_ms,
+                ) {
+                    warn!("Failed to record HTTP request: {}", e);
+                }
             }
+        }
+
+        // Construct a response object similar to JavaScript's Response
+        debug!("Constructing response object");
+        let result = json!({
+            "ok": status.is_success(),
+            "status": status_code,
+            "statusText": status_text,
+            "headers": response_headers,
+            "body": response_body
         });
 
+        debug!("HTTP call completed successfully");
+        Ok(result)
+    }
+
+    /// Perform an HTTP request, leaving the response body on the connection
+    ///
+    /// Reading more than `max_response_size` bytes of the body fails. Streamed
+    /// requests are not recorded.
+    async fn open_stream(
+        &self,
+        url: &str,
+        params: Option<&JsonValue>,
+        body: Option<&JsonValue>,
+    ) -> Result<HttpStream, HttpError> {
+        // Mock responses are already complete
+        if self.offline {
+            return self.call_http(url, params, body).await.map(HttpStream::buffered);
+        }
+
+        info!("Opening HTTP stream to: {}", url);
+        let request_body_str = request_body_string(body);
+        let response = self
+            .send_request(request_method(params), url, params, body, request_body_str.as_deref())
+            .await?;
+        reject_declared_oversize(&response, self.config.max_response_size)?;
+
+        Ok(HttpStream::live(response, self.config.max_response_size))
+    }
+}
+
+impl HttpManager {
+    /// Send a request with the host's settings, answering a proxy digest challenge once
+    async fn send_request(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        params: Option<&JsonValue>,
+        body: Option<&JsonValue>,
+        request_body_str: Option<&str>,
+    ) -> Result<reqwest::Response, HttpError> {
+        check_fetch_access(&self.config.fetch_access, url)?;
+
+        // Refuse to send oversized bodies
+        if request_body_str.is_some_and(|body| body.len() > self.config.max_request_size) {
+            return Err(HttpError::BodyTooLarge {
+                direction: BodyDirection::Request,
+                limit: self.config.max_request_size,
+            });
+        }
+
+        let host = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(str::to_string));
+        let settings = self.config.settings_for_host(host.as_deref());
+        let client = self.client_for(settings.clone())?;
+
+        // Send the request and get the response
+        debug!("Sending HTTP request");
+        let mut response = Self::build_request(&client, method, url, params, body)?
+            .send()
+            .await
+            .map_err(send_error)?;
+
+        // Answer a digest challenge from the proxy once
+        if response.status() == reqwest::StatusCode::PROXY_AUTHENTICATION_REQUIRED {
+            if let Some(authorization) = self.proxy_digest_authorization(&response, method, url)? {
+                debug!("Retrying request with proxy digest authentication");
+                let client = self.build_client(&settings, Some(&authorization))?;
+                response = Self::build_request(&client, method, url, params, body)?
+                    .send()
+                    .await
+                    .map_err(send_error)?;
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Client for `settings`, reusing its connection pool across requests
+    fn client_for(&self, settings: HostSettings) -> Result<Client, HttpError> {
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(client) = clients.get(&settings) {
+            return Ok(client.clone());
+        }
+        let client = self.build_client(&settings, None)?;
+        clients.insert(settings, client.clone());
+        Ok(client)
+    }
+
+    /// Create a reqwest client with the host's settings and the configured proxy
+    fn build_client(
+        &self,
+        settings: &HostSettings,
+        proxy_digest_authorization: Option<&HeaderValue>,
+    ) -> Result<Client, HttpError> {
+        debug!("Creating HTTP client with {}s timeout", settings.timeout.as_secs());
+        let mut builder = Client::builder()
+            .timeout(settings.timeout)
+            .connect_timeout(settings.connect_timeout)
+            .pool_max_idle_per_host(settings.max_idle_per_host)
+            .pool_idle_timeout(settings.idle_timeout)
+            .tcp_keepalive(settings.keepalive)
+            .user_agent(&self.config.user_agent)
+            .danger_accept_invalid_certs(!self.config.verify_ssl)
+            .redirect(redirect_policy(
+                self.config.max_redirects as usize,
+                self.config.fetch_access.clone(),
+            ));
+
+        if let Some(proxy_config) = &self.config.proxy {
+            for proxy in crate::proxy::build_proxies(proxy_config, proxy_digest_authorization)? {
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Build the `Proxy-Authorization` answer to a proxy's digest challenge
+    fn proxy_digest_authorization(
+        &self,
+        response: &reqwest::Response,
+        method: HttpMethod,
+        url: &str,
+    ) -> Result<Option<HeaderValue>, HttpError> {
+        let Some(proxy_config) = &self.config.proxy else {
+            return Ok(None);
+        };
+        for challenge in response.headers().get_all(reqwest::header::PROXY_AUTHENTICATE) {
+            let Ok(challenge) = challenge.to_str() else {
+                continue;
+            };
+            if let Some(authorization) =
+                crate::proxy::digest_authorization(proxy_config, challenge, method.as_str(), url)?
+            {
+                return Ok(Some(authorization));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Build a request with the task's headers and body
+    fn build_request(
+        client: &Client,
+        method: HttpMethod,
+        url: &str,
+        params: Option<&JsonValue>,
+        body: Option<&JsonValue>,
+    ) -> Result<reqwest::RequestBuilder, HttpError> {
         let reqwest_method = reqwest::Method::from(method);
 
         debug!("Building {} request to {}", method, url);
-        // Build the request
         let mut request = client.request(reqwest_method, url);
 
         // Add headers if provided
@@ -258,81 +479,480 @@ impl HttpClient for HttpManager {
             }
         }
 
-        // Send the request and get the response
-        debug!("Sending HTTP request");
-        let response = request.send().await?;
+        Ok(request)
+    }
+}
 
-        // Get status info
-        let status = response.status();
-        let status_code = status.as_u16();
-        let status_text = status.canonical_reason().unwrap_or("Unknown Status");
+/// Follow at most `max_redirects` redirects, refusing any hop to a host `access` does not permit
+fn redirect_policy(max_redirects: usize, access: FetchAccessConfig) -> reqwest::redirect::Policy {
+    if !access.is_restricted() {
+        return reqwest::redirect::Policy::limited(max_redirects);
+    }
 
-        info!("HTTP response received: {} {}", status_code, status_text);
+    reqwest::redirect::Policy::custom(move |attempt| {
+        if attempt.previous().len() > max_redirects {
+            return attempt.error("too many redirects");
+        }
+        let host = attempt.url().host_str().unwrap_or_default().to_string();
+        if access.permits(&host) {
+            attempt.follow()
+        } else {
+            attempt.error(HttpError::HostNotAllowed { host })
+        }
+    })
+}
 
-        // Collect response headers for recording
-        let response_headers: HashMap<String, String> = response
-            .headers()
-            .iter()
-            .filter_map(|(name, value)| value.to_str().ok().map(|v| (name.to_string(), v.to_string())))
-            .collect();
+/// Surface a redirect refused by the fetch access lists as the refusal itself
+fn send_error(error: reqwest::Error) -> HttpError {
+    let refused_host = std::error::Error::source(&error).and_then(|source| match source.downcast_ref() {
+        Some(HttpError::HostNotAllowed { host }) => Some(host.clone()),
+        _ => None,
+    });
+    match refused_host {
+        Some(host) => HttpError::HostNotAllowed { host },
+        None => HttpError::NetworkError(error),
+    }
+}
 
-        // Try to parse the response as JSON, fall back to text if it fails
-        debug!("Parsing response body");
-        let response_body = match response.json::<JsonValue>().await {
-            Ok(json_data) => {
-                debug!("Successfully parsed response as JSON");
-                json_data
-            }
-            Err(_) => {
-                warn!("Failed to parse response as JSON, falling back to text");
-                // Fall back to text - we need to send a new request since json() consumes the response
-                let text_response = client.request(reqwest::Method::from(method), url).send().await?;
-                let text = text_response.text().await?;
-                debug!("Response parsed as text: {} bytes", text.len());
-                json!(text)
-            }
-        };
+/// HTTP method from the fetch params, defaulting to GET
+fn request_method(params: Option<&JsonValue>) -> HttpMethod {
+    params
+        .and_then(|params| params.get("method"))
+        .and_then(|method| method.as_str())
+        .and_then(|method| method.parse().ok())
+        .unwrap_or(HttpMethod::Get)
+}
 
-        // Record the HTTP request if recording is enabled
-        #[cfg(feature = "recording")]
-        {
-            let end_time = Utc::now();
-            let duration_ms = (end_time - start_time).num_milliseconds() as u64;
+/// Request headers from the fetch params with string values
+fn request_headers(params: Option<&JsonValue>) -> Option<HashMap<String, String>> {
+    params
+        .and_then(|params| params.get("headers"))
+        .and_then(|headers| headers.as_object())
+        .map(|headers| {
+            headers
+                .iter()
+                .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                .collect()
+        })
+}
 
-            if crate::recording::is_recording() {
-                let response_body_str = serde_json::to_string(&response_body).unwrap_or_default();
-                if let Err(e) = crate::recording::record_http_request(
-                    url,
-                    method.as_str(),
-                    request_headers.as_ref(),
-                    request_body_str.as_deref(),
-                    status_code,
-                    Some(&response_headers),
-                    &response_body_str,
-                    start_time,
-                    duration_ms,
-                ) {
-                    warn!("Failed to record HTTP request: {}", e);
-                }
-            }
+/// Request body as sent: strings verbatim, other values as JSON
+fn request_body_string(body: Option<&JsonValue>) -> Option<String> {
+    body.map(|b| {
+        if let Some(s) = b.as_str() {
+            s.to_string()
+        } else {
+            serde_json::to_string(b).unwrap_or_default()
         }
+    })
+}
 
-        // Construct a response object similar to JavaScript's Response
-        debug!("Constructing response object");
-        let result = json!({
-            "ok": status.is_success(),
-            "status": status_code,
-            "statusText": status_text,
-            "headers": response_headers,
-            "body": response_body
+/// Response headers with valid string values
+pub(crate) fn response_headers(response: &reqwest::Response) -> HashMap<String, String> {
+    response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| value.to_str().ok().map(|v| (name.to_string(), v.to_string())))
+        .collect()
+}
+
+/// Reject a response up front when the server declares a body over `limit` bytes
+fn reject_declared_oversize(response: &reqwest::Response, limit: usize) -> Result<(), HttpError> {
+    if response.content_length().is_some_and(|length| length > limit as u64) {
+        return Err(HttpError::BodyTooLarge {
+            direction: BodyDirection::Response,
+            limit,
         });
+    }
+    Ok(())
+}
 
-        debug!("HTTP call completed successfully");
-        Ok(result)
+/// Read a response body, aborting the download once it exceeds `limit` bytes
+async fn read_limited_body(mut response: reqwest::Response, limit: usize) -> Result<Vec<u8>, HttpError> {
+    reject_declared_oversize(&response, limit)?;
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > limit {
+            return Err(HttpError::BodyTooLarge {
+                direction: BodyDirection::Response,
+                limit,
+            });
+        }
+        body.extend_from_slice(&chunk);
     }
+
+    Ok(body)
 }
 
 /// Create a default HttpManager instance for backward compatibility
 pub fn create_http_manager() -> HttpManager {
     HttpManager::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratchet_config::domains::http::{
+        FetchAccessConfig, HostOverrideConfig, ProxyAuthConfig, ProxyAuthScheme, ProxyConfig,
+    };
+    use wiremock::matchers::{header, header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn manager_with_limit(max_response_size: usize) -> HttpManager {
+        HttpManager::with_config(HttpConfig {
+            max_response_size,
+            ..HttpConfig::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_response_exceeding_limit_is_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/large"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(4096)))
+            .mount(&server)
+            .await;
+
+        let manager = manager_with_limit(1024);
+        let result = manager.call_http(&format!("{}/large", server.uri()), None, None).await;
+
+        assert!(matches!(
+            result,
+            Err(HttpError::BodyTooLarge {
+                direction: BodyDirection::Response,
+                limit: 1024
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_request_exceeding_limit_is_not_sent() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/upload"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let manager = HttpManager::with_config(HttpConfig {
+            max_request_size: 1024,
+            ..HttpConfig::default()
+        });
+        let params = json!({"method": "POST"});
+        let body = json!({"data": "x".repeat(4096)});
+        let result = manager
+            .call_http(&format!("{}/upload", server.uri()), Some(&params), Some(&body))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(HttpError::BodyTooLarge {
+                direction: BodyDirection::Request,
+                limit: 1024
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_to_denied_host_is_not_sent() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let allowed = HttpManager::with_config(HttpConfig {
+            fetch_access: FetchAccessConfig {
+                allow: vec!["127.0.0.0/8".to_string()],
+                ..Default::default()
+            },
+            ..HttpConfig::default()
+        });
+        assert!(allowed.call_http(&server.uri(), None, None).await.is_ok());
+
+        let denied = HttpManager::with_config(HttpConfig {
+            fetch_access: FetchAccessConfig {
+                deny: vec!["127.0.0.1".to_string()],
+                ..Default::default()
+            },
+            ..HttpConfig::default()
+        });
+        assert!(matches!(
+            denied.call_http(&server.uri(), None, None).await,
+            Err(HttpError::HostNotAllowed { host }) if host == "127.0.0.1"
+        ));
+        assert!(matches!(
+            denied.open_stream(&server.uri(), None, None).await,
+            Err(HttpError::HostNotAllowed { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_redirect_to_denied_host_is_not_followed() {
+        let server = MockServer::start().await;
+        let port = server.address().port();
+        Mock::given(method("GET"))
+            .and(path("/internal"))
+            .respond_with(ResponseTemplate::new(302).insert_header("location", format!("http://127.0.0.1:{}/ok", port)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/external"))
+            .respond_with(ResponseTemplate::new(302).insert_header("location", format!("http://localhost:{}/ok", port)))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/ok"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let manager = HttpManager::with_config(HttpConfig {
+            fetch_access: FetchAccessConfig {
+                allow: vec!["127.0.0.1".to_string()],
+                ..Default::default()
+            },
+            ..HttpConfig::default()
+        });
+
+        let response = manager
+            .call_http(&format!("{}/internal", server.uri()), None, None)
+            .await
+            .unwrap();
+        assert_eq!(response["status"], 200);
+
+        assert!(matches!(
+            manager.call_http(&format!("{}/external", server.uri()), None, None).await,
+            Err(HttpError::HostNotAllowed { host }) if host == "localhost"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_flag_does_not_lift_response_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/data"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"items": [1, 2, 3]})))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/large"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("x".repeat(4096)))
+            .mount(&server)
+            .await;
+
+        let manager = manager_with_limit(1024);
+
+        let response = manager
+            .call_http(&format!("{}/data", server.uri()), None, None)
+            .await
+            .unwrap();
+        assert_eq!(response["body"], json!({"items": [1, 2, 3]}));
+
+        // Asking for a stream does not lift the limit on a buffered read
+        let params = json!({"stream": true});
+        let result = manager
+            .call_http(&format!("{}/large", server.uri()), Some(&params), None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(HttpError::BodyTooLarge {
+                direction: BodyDirection::Response,
+                limit: 1024
+            })
+        ));
+    }
+
+    fn manager_with_proxy(proxy_uri: String, scheme: ProxyAuthScheme) -> HttpManager {
+        HttpManager::with_config(HttpConfig {
+            proxy: Some(ProxyConfig {
+                http_proxy: Some(proxy_uri),
+                https_proxy: None,
+                no_proxy: None,
+                auth: Some(ProxyAuthConfig {
+                    scheme,
+                    username: Some("user".to_string()),
+                    password: Some("pass".to_string()),
+                    ..ProxyAuthConfig::default()
+                }),
+            }),
+            ..HttpConfig::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_stream_reads_body_within_response_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/events"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("data: x\n\n".repeat(512), "text/event-stream"))
+            .mount(&server)
+            .await;
+
+        let manager = manager_with_limit(8192);
+        let mut stream = manager
+            .open_stream(&format!("{}/events", server.uri()), None, None)
+            .await
+            .unwrap();
+
+        assert!(stream.ok());
+        assert_eq!(stream.headers["content-type"], "text/event-stream");
+        let mut body = Vec::new();
+        while let Some(chunk) = stream.next_chunk().await.unwrap() {
+            body.extend(chunk);
+        }
+        assert_eq!(body.len(), 512 * "data: x\n\n".len());
+    }
+
+    #[tokio::test]
+    async fn test_stream_exceeding_response_limit_is_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/events"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("data: x\n\n".repeat(512), "text/event-stream"))
+            .mount(&server)
+            .await;
+
+        let manager = manager_with_limit(1024);
+        let result = manager
+            .open_stream(&format!("{}/events", server.uri()), None, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(HttpError::BodyTooLarge {
+                direction: BodyDirection::Response,
+                limit: 1024
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_basic_proxy_credentials_are_sent() {
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("proxy-authorization", "Basic dXNlcjpwYXNz"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"proxied": true})))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let manager = manager_with_proxy(proxy.uri(), ProxyAuthScheme::Basic);
+        let response = manager
+            .call_http("http://example.test/resource", None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(response["body"], json!({"proxied": true}));
+    }
+
+    #[tokio::test]
+    async fn test_digest_proxy_challenge_is_answered() {
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header_exists("proxy-authorization"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"proxied": true})))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(407)
+                    .insert_header("proxy-authenticate", r#"Digest realm="corp", nonce="abc", qop="auth""#),
+            )
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let manager = manager_with_proxy(proxy.uri(), ProxyAuthScheme::Digest);
+        let response = manager
+            .call_http("http://example.test/resource", None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(response["body"], json!({"proxied": true}));
+        let requests = proxy.received_requests().await.unwrap();
+        let authorization = requests[1]
+            .headers
+            .get(&"proxy-authorization".into())
+            .unwrap()
+            .iter()
+            .map(|value| value.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        assert!(authorization.starts_with(r#"Digest username="user", realm="corp", nonce="abc""#));
+    }
+
+    #[tokio::test]
+    async fn test_host_override_timeout_applies_to_matching_host() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_millis(300)))
+            .mount(&server)
+            .await;
+        let url = format!("{}/slow", server.uri());
+
+        let with_rule = |host: &str| {
+            HttpManager::with_config(HttpConfig {
+                host_overrides: vec![HostOverrideConfig {
+                    host: host.to_string(),
+                    timeout: Some(std::time::Duration::from_millis(50)),
+                    ..HostOverrideConfig::default()
+                }],
+                ..HttpConfig::default()
+            })
+        };
+
+        let result = with_rule("127.0.0.1").call_http(&url, None, None).await;
+        assert!(matches!(result, Err(HttpError::NetworkError(e)) if e.is_timeout()));
+
+        // Other hosts keep the global timeout
+        let response = with_rule("*.example.com").call_http(&url, None, None).await.unwrap();
+        assert_eq!(response["status"], 200);
+    }
+
+    #[tokio::test]
+    async fn test_clients_are_reused_per_host_settings() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let manager = HttpManager::with_config(HttpConfig {
+            host_overrides: vec![HostOverrideConfig {
+                host: "localhost".to_string(),
+                timeout: Some(std::time::Duration::from_secs(5)),
+                ..HostOverrideConfig::default()
+            }],
+            ..HttpConfig::default()
+        });
+        let clone = manager.clone();
+        let port = server.address().port();
+
+        for path in ["/a", "/b"] {
+            manager
+                .call_http(&format!("{}{}", server.uri(), path), None, None)
+                .await
+                .unwrap();
+        }
+        clone
+            .call_http(&format!("{}/c", server.uri()), None, None)
+            .await
+            .unwrap();
+        assert_eq!(manager.clients.lock().unwrap().len(), 1);
+
+        // A host with its own settings gets its own client
+        clone
+            .call_http(&format!("http://localhost:{}/d", port), None, None)
+            .await
+            .unwrap();
+        assert_eq!(manager.clients.lock().unwrap().len(), 2);
+    }
+}
@@ -1,6 +1,10 @@
 //! HTTP configuration
 
-use ratchet_config::domains::http::HttpConfig as ConfigHttpConfig;
+use crate::errors::HttpError;
+pub use ratchet_config::domains::http::FetchAccessConfig;
+use ratchet_config::domains::http::{
+    ConnectionPoolConfig, HostOverrideConfig, HttpConfig as ConfigHttpConfig, ProxyConfig,
+};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -18,6 +22,74 @@ pub struct HttpConfig {
 
     /// Whether to verify SSL certificates
     pub verify_ssl: bool,
+
+    /// Maximum response body size in bytes, for buffered and streamed responses alike
+    pub max_response_size: usize,
+
+    /// Maximum request body size in bytes; larger requests are not sent
+    #[serde(default = "default_max_request_size")]
+    pub max_request_size: usize,
+
+    /// Proxy for outbound requests, with optional authentication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+
+    /// Connection pool settings applied to every host
+    #[serde(default)]
+    pub connection_pool: ConnectionPoolConfig,
+
+    /// Per-host timeout and pool overrides; the first matching rule wins
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub host_overrides: Vec<HostOverrideConfig>,
+
+    /// Hosts JavaScript `fetch` may reach; every host by default
+    #[serde(default)]
+    pub fetch_access: FetchAccessConfig,
+}
+
+/// Timeout and pool settings in effect for one host
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct HostSettings {
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    pub max_idle_per_host: usize,
+    pub idle_timeout: Duration,
+    pub keepalive: Option<Duration>,
+}
+
+impl HttpConfig {
+    /// Resolve the settings for `host`, applying the first matching override
+    pub(crate) fn settings_for_host(&self, host: Option<&str>) -> HostSettings {
+        let rule = host.and_then(|host| self.host_overrides.iter().find(|rule| rule.matches(host)));
+        HostSettings {
+            timeout: rule.and_then(|rule| rule.timeout).unwrap_or(self.timeout),
+            connect_timeout: rule
+                .and_then(|rule| rule.connect_timeout)
+                .unwrap_or(self.connection_pool.connection_timeout),
+            max_idle_per_host: rule
+                .and_then(|rule| rule.max_idle_per_host)
+                .unwrap_or(self.connection_pool.max_idle_per_host),
+            idle_timeout: self.connection_pool.idle_timeout,
+            keepalive: rule.and_then(|rule| rule.keepalive),
+        }
+    }
+}
+
+/// Refuse URLs whose host `access` does not permit, before any network I/O
+pub fn check_fetch_access(access: &FetchAccessConfig, url: &str) -> Result<(), HttpError> {
+    if !access.is_restricted() {
+        return Ok(());
+    }
+
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(str::to_string))
+        .unwrap_or_default();
+    if access.permits(&host) {
+        Ok(())
+    } else {
+        Err(HttpError::HostNotAllowed { host })
+    }
 }
 
 impl Default for HttpConfig {
@@ -27,6 +99,12 @@ impl Default for HttpConfig {
             max_redirects: 10,
             user_agent: "Ratchet/1.0".to_string(),
             verify_ssl: true,
+            max_response_size: 10 * 1024 * 1024, // 10MB
+            max_request_size: default_max_request_size(),
+            proxy: None,
+            connection_pool: ConnectionPoolConfig::default(),
+            host_overrides: Vec::new(),
+            fetch_access: FetchAccessConfig::default(),
         }
     }
 }
@@ -38,6 +116,16 @@ impl From<ConfigHttpConfig> for HttpConfig {
             max_redirects: config.max_redirects,
             user_agent: config.user_agent,
             verify_ssl: config.verify_ssl,
+            max_response_size: config.max_response_size,
+            max_request_size: config.max_request_size,
+            proxy: config.proxy,
+            connection_pool: config.connection_pool,
+            host_overrides: config.host_overrides,
+            fetch_access: config.fetch_access,
         }
     }
 }
+
+fn default_max_request_size() -> usize {
+    10 * 1024 * 1024 // 10MB
+}
@@ -25,4 +25,26 @@ pub enum HttpError {
 
     #[error("Recording error: {0}")]
     RecordingError(String),
+
+    #[error("{direction} body exceeds the {limit} byte limit")]
+    BodyTooLarge { direction: BodyDirection, limit: usize },
+
+    #[error("Fetch to host '{host}' is not allowed")]
+    HostNotAllowed { host: String },
+}
+
+/// Which side of an exchange a body belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyDirection {
+    Request,
+    Response,
+}
+
+impl std::fmt::Display for BodyDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BodyDirection::Request => write!(f, "Request"),
+            BodyDirection::Response => write!(f, "Response"),
+        }
+    }
 }
@@ -1,11 +1,14 @@
 //! HTTP client functionality for Ratchet
 //!
-//! This crate provides HTTP client capabilities with mock support, request recording,
+//! This crate provides HTTP client capabilities with request-matching mocks, request recording,
 //! and integration with the Ratchet configuration system.
 
 pub mod client;
 pub mod config;
 pub mod errors;
+pub mod mock;
+mod proxy;
+pub mod stream;
 pub mod types;
 
 #[cfg(feature = "recording")]
@@ -13,8 +16,10 @@ pub mod recording;
 
 // Re-export main types for convenience
 pub use client::{HttpClient, HttpManager};
-pub use config::HttpConfig;
-pub use errors::HttpError;
+pub use config::{check_fetch_access, FetchAccessConfig, HttpConfig};
+pub use errors::{BodyDirection, HttpError};
+pub use mock::{MockRegistry, MockResponse, RequestMatcher, UrlMatcher};
+pub use stream::HttpStream;
 pub use types::{HttpMethod, HttpMethodError};
 
 #[cfg(feature = "recording")]
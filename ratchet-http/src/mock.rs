@@ -0,0 +1,327 @@
+//! Request-matching mocks for deterministic tests
+//!
+//! Tests register `(matcher, response)` pairs with [`crate::HttpManager::register_mock`].
+//! In offline mode the manager answers each request with the first registered mock
+//! whose matcher accepts it, without touching the network or a recording file.
+
+use crate::errors::HttpError;
+use crate::types::HttpMethod;
+use regex::Regex;
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+type BodyPredicate = Arc<dyn Fn(Option<&JsonValue>) -> bool + Send + Sync>;
+type HeaderPredicate = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
+/// How a matcher compares the request URL
+#[derive(Debug, Clone)]
+pub enum UrlMatcher {
+    /// The URL must equal the given string
+    Exact(String),
+    /// The URL must match the given pattern
+    Regex(Regex),
+}
+
+impl UrlMatcher {
+    fn matches(&self, url: &str) -> bool {
+        match self {
+            UrlMatcher::Exact(expected) => expected == url,
+            UrlMatcher::Regex(pattern) => pattern.is_match(url),
+        }
+    }
+}
+
+/// Predicate over a request's method, URL, body and headers
+#[derive(Clone)]
+pub struct RequestMatcher {
+    method: Option<HttpMethod>,
+    url: UrlMatcher,
+    body: Option<BodyPredicate>,
+    headers: Vec<(String, HeaderPredicate)>,
+}
+
+impl RequestMatcher {
+    /// Match requests with the given method and exact URL
+    pub fn exact(method: HttpMethod, url: &str) -> Self {
+        Self::new(Some(method), UrlMatcher::Exact(url.to_string()))
+    }
+
+    /// Match requests with the given method and a URL matching `pattern`
+    pub fn regex(method: HttpMethod, pattern: &str) -> Result<Self, HttpError> {
+        let pattern = Regex::new(pattern)
+            .map_err(|e| HttpError::ConfigError(format!("Invalid mock URL pattern '{}': {}", pattern, e)))?;
+        Ok(Self::new(Some(method), UrlMatcher::Regex(pattern)))
+    }
+
+    /// Match requests with any method and the given URL matcher
+    pub fn any_method(url: UrlMatcher) -> Self {
+        Self::new(None, url)
+    }
+
+    fn new(method: Option<HttpMethod>, url: UrlMatcher) -> Self {
+        Self {
+            method,
+            url,
+            body: None,
+            headers: Vec::new(),
+        }
+    }
+
+    /// Require the request body to equal `body`
+    pub fn with_body(self, body: JsonValue) -> Self {
+        self.with_body_matching(move |actual| actual == Some(&body))
+    }
+
+    /// Require the request body to satisfy `predicate`
+    pub fn with_body_matching<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(Option<&JsonValue>) -> bool + Send + Sync + 'static,
+    {
+        self.body = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Require the request header `name` to equal `value`
+    pub fn with_header(self, name: &str, value: &str) -> Self {
+        let value = value.to_string();
+        self.with_header_matching(name, move |actual| actual == value)
+    }
+
+    /// Require the request header `name` to be present and satisfy `predicate`
+    pub fn with_header_matching<F>(mut self, name: &str, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.headers.push((name.to_string(), Arc::new(predicate)));
+        self
+    }
+
+    /// Whether the request satisfies every part of the matcher
+    ///
+    /// Header names are compared case-insensitively.
+    pub fn matches(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: &HashMap<String, String>,
+        body: Option<&JsonValue>,
+    ) -> bool {
+        self.method.is_none_or(|expected| expected == method)
+            && self.url.matches(url)
+            && self.body.as_ref().is_none_or(|predicate| predicate(body))
+            && self.headers.iter().all(|(name, predicate)| {
+                headers
+                    .iter()
+                    .find(|(actual, _)| actual.eq_ignore_ascii_case(name))
+                    .is_some_and(|(_, value)| predicate(value))
+            })
+    }
+}
+
+impl fmt::Debug for RequestMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestMatcher")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("body", &self.body.is_some())
+            .field(
+                "headers",
+                &self.headers.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Canned response returned for a matched request
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    /// Status code
+    pub status: u16,
+    /// Response headers
+    pub headers: HashMap<String, String>,
+    /// Response body
+    pub body: JsonValue,
+}
+
+impl MockResponse {
+    /// Successful response with the given body
+    pub fn json(body: JsonValue) -> Self {
+        Self::status(200).with_body(body)
+    }
+
+    /// Response with the given status code and no body
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            headers: HashMap::new(),
+            body: JsonValue::Null,
+        }
+    }
+
+    /// Set the response body
+    pub fn with_body(mut self, body: JsonValue) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Add a response header
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Response object shaped like the result of [`crate::HttpClient::call_http`]
+    pub fn to_response(&self) -> JsonValue {
+        let status_text = reqwest::StatusCode::from_u16(self.status)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or("Unknown Status");
+
+        json!({
+            "ok": (200..300).contains(&self.status),
+            "status": self.status,
+            "statusText": status_text,
+            "headers": self.headers,
+            "body": self.body,
+        })
+    }
+}
+
+/// Registered mocks, consulted in registration order
+#[derive(Debug, Clone, Default)]
+pub struct MockRegistry {
+    mocks: Vec<(RequestMatcher, MockResponse)>,
+}
+
+impl MockRegistry {
+    /// Register a response for requests accepted by `matcher`
+    pub fn register(&mut self, matcher: RequestMatcher, response: MockResponse) {
+        self.mocks.push((matcher, response));
+    }
+
+    /// Response of the first mock matching the request
+    pub fn find(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        headers: &HashMap<String, String>,
+        body: Option<&JsonValue>,
+    ) -> Option<&MockResponse> {
+        self.mocks
+            .iter()
+            .find(|(matcher, _)| matcher.matches(method, url, headers, body))
+            .map(|(_, response)| response)
+    }
+
+    /// Number of registered mocks
+    pub fn len(&self) -> usize {
+        self.mocks.len()
+    }
+
+    /// Whether no mocks are registered
+    pub fn is_empty(&self) -> bool {
+        self.mocks.is_empty()
+    }
+
+    /// Remove all registered mocks
+    pub fn clear(&mut self) {
+        self.mocks.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpClient, HttpManager};
+
+    fn offline_manager() -> HttpManager {
+        let mut manager = HttpManager::new();
+        manager.set_offline();
+        manager
+    }
+
+    #[tokio::test]
+    async fn test_exact_and_regex_url_matching() {
+        let mut manager = offline_manager();
+        manager.register_mock(
+            RequestMatcher::exact(HttpMethod::Get, "https://api.example.com/users/1"),
+            MockResponse::json(json!({"id": 1})),
+        );
+        manager.register_mock(
+            RequestMatcher::regex(HttpMethod::Get, r"^https://api\.example\.com/users/\d+$").unwrap(),
+            MockResponse::status(404).with_header("x-mock", "regex"),
+        );
+
+        let exact = manager
+            .call_http("https://api.example.com/users/1", None, None)
+            .await
+            .unwrap();
+        assert_eq!(exact["body"], json!({"id": 1}));
+        assert_eq!(exact["ok"], json!(true));
+
+        let pattern = manager
+            .call_http("https://api.example.com/users/42", None, None)
+            .await
+            .unwrap();
+        assert_eq!(pattern["status"], json!(404));
+        assert_eq!(pattern["statusText"], json!("Not Found"));
+        assert_eq!(pattern["headers"]["x-mock"], json!("regex"));
+
+        // The method is part of the match
+        let post = json!({"method": "POST"});
+        assert!(manager
+            .call_http("https://api.example.com/users/1", Some(&post), None)
+            .await
+            .is_err());
+        assert!(RequestMatcher::regex(HttpMethod::Get, "(").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_body_and_header_predicates() {
+        let mut manager = offline_manager();
+        manager.register_mock(
+            RequestMatcher::exact(HttpMethod::Post, "https://api.example.com/orders")
+                .with_header("Authorization", "Bearer secret")
+                .with_body_matching(|body| body.is_some_and(|b| b["quantity"].as_u64() > Some(10))),
+            MockResponse::json(json!({"tier": "bulk"})),
+        );
+        manager.register_mock(
+            RequestMatcher::exact(HttpMethod::Post, "https://api.example.com/orders").with_body(json!({"quantity": 1})),
+            MockResponse::json(json!({"tier": "single"})),
+        );
+
+        let params = json!({"method": "POST", "headers": {"authorization": "Bearer secret"}});
+        let bulk = manager
+            .call_http(
+                "https://api.example.com/orders",
+                Some(&params),
+                Some(&json!({"quantity": 50})),
+            )
+            .await
+            .unwrap();
+        assert_eq!(bulk["body"]["tier"], json!("bulk"));
+
+        let single = manager
+            .call_http(
+                "https://api.example.com/orders",
+                Some(&params),
+                Some(&json!({"quantity": 1})),
+            )
+            .await
+            .unwrap();
+        assert_eq!(single["body"]["tier"], json!("single"));
+
+        // Bulk orders without credentials match neither mock
+        let anonymous = json!({"method": "POST"});
+        assert!(manager
+            .call_http(
+                "https://api.example.com/orders",
+                Some(&anonymous),
+                Some(&json!({"quantity": 50}))
+            )
+            .await
+            .is_err());
+    }
+}
@@ -46,6 +46,9 @@ pub fn set_recording_dir(session_dir: PathBuf) -> Result<()> {
     })
 }
 
+/// Value recorded in place of `Proxy-Authorization` headers
+const REDACTED_HEADER_VALUE: &str = "[REDACTED]";
+
 /// Record an HTTP request/response pair in HAR format
 pub fn record_http_request(
     url: &str,
@@ -67,6 +70,12 @@ pub fn record_http_request(
             headers
                 .iter()
                 .map(|(name, value)| {
+                    // Never write proxy credentials to the HAR file
+                    let value = if name.eq_ignore_ascii_case("proxy-authorization") {
+                        REDACTED_HEADER_VALUE
+                    } else {
+                        value.as_str()
+                    };
                     json!({
                         "name": name,
                         "value": value,
@@ -0,0 +1,119 @@
+//! Incrementally read HTTP responses
+//!
+//! An [`HttpStream`] carries the status and headers of a response whose body is
+//! read chunk by chunk, so large downloads and server-sent events do not have to
+//! be buffered in full. The body is still subject to `max_response_size`: reading
+//! past it fails, however the body is consumed.
+
+use crate::errors::{BodyDirection, HttpError};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+
+/// HTTP response with a body read in chunks
+#[derive(Debug)]
+pub struct HttpStream {
+    /// Status code
+    pub status: u16,
+    /// Canonical reason phrase of the status code
+    pub status_text: String,
+    /// Response headers
+    pub headers: HashMap<String, String>,
+    body: StreamBody,
+}
+
+#[derive(Debug)]
+enum StreamBody {
+    /// Body still on the connection, with the bytes read so far and the limit on them
+    Live {
+        response: reqwest::Response,
+        read: usize,
+        limit: usize,
+    },
+    /// Body already in memory, handed out as a single chunk
+    Buffered(Option<Vec<u8>>),
+}
+
+impl HttpStream {
+    /// Stream the body of a received response, failing once more than `limit` bytes are read
+    pub fn live(response: reqwest::Response, limit: usize) -> Self {
+        let status = response.status();
+        Self {
+            status: status.as_u16(),
+            status_text: status.canonical_reason().unwrap_or("Unknown Status").to_string(),
+            headers: crate::client::response_headers(&response),
+            body: StreamBody::Live {
+                response,
+                read: 0,
+                limit,
+            },
+        }
+    }
+
+    /// Stream a response object as returned by [`crate::HttpClient::call_http`]
+    ///
+    /// String bodies are streamed verbatim, other bodies as JSON. The body was
+    /// already held to the response limit when it was read.
+    pub fn buffered(response: JsonValue) -> Self {
+        let body = match response.get("body") {
+            Some(JsonValue::String(text)) => text.clone().into_bytes(),
+            Some(JsonValue::Null) | None => Vec::new(),
+            Some(value) => serde_json::to_vec(value).unwrap_or_default(),
+        };
+        let headers = response
+            .get("headers")
+            .and_then(|headers| headers.as_object())
+            .map(|headers| {
+                headers
+                    .iter()
+                    .filter_map(|(name, value)| value.as_str().map(|v| (name.clone(), v.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            status: response.get("status").and_then(|s| s.as_u64()).unwrap_or(0) as u16,
+            status_text: response
+                .get("statusText")
+                .and_then(|s| s.as_str())
+                .unwrap_or("Unknown Status")
+                .to_string(),
+            headers,
+            body: StreamBody::Buffered((!body.is_empty()).then_some(body)),
+        }
+    }
+
+    /// Whether the status code indicates success
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Response object without a body, shaped like the result of [`crate::HttpClient::call_http`]
+    pub fn head(&self) -> JsonValue {
+        json!({
+            "ok": self.ok(),
+            "status": self.status,
+            "statusText": self.status_text,
+            "headers": self.headers,
+        })
+    }
+
+    /// Next chunk of the body, or `None` once the body is exhausted
+    pub async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, HttpError> {
+        match &mut self.body {
+            StreamBody::Live { response, read, limit } => {
+                let Some(chunk) = response.chunk().await? else {
+                    return Ok(None);
+                };
+                *read += chunk.len();
+                if *read > *limit {
+                    return Err(HttpError::BodyTooLarge {
+                        direction: BodyDirection::Response,
+                        limit: *limit,
+                    });
+                }
+                Ok(Some(chunk.to_vec()))
+            }
+            StreamBody::Buffered(body) => Ok(body.take()),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Audit log export interface
+//!
+//! Lets API layers export the audit trail without depending on the server
+//! crate that owns the audit log storage.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Error types for audit log operations
+#[derive(Debug, thiserror::Error)]
+pub enum AuditError {
+    #[error("Audit log export failed: {0}")]
+    Export(String),
+}
+
+/// Formats the audit log can be exported in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl AuditExportFormat {
+    /// MIME type of the exported document
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            AuditExportFormat::Json => "application/json",
+            AuditExportFormat::Csv => "text/csv",
+        }
+    }
+
+    /// File extension of the exported document
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AuditExportFormat::Json => "json",
+            AuditExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Export access to the audit log
+#[async_trait]
+pub trait AuditLogExport: Send + Sync {
+    /// Export entries recorded between `from` and `to` (both inclusive, either open-ended)
+    async fn export_range(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        format: AuditExportFormat,
+    ) -> Result<Vec<u8>, AuditError>;
+}
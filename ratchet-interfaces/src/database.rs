@@ -4,6 +4,7 @@
 //! and testing through interface segregation. These traits break circular dependencies
 //! by providing clean contracts that both legacy and new implementations can satisfy.
 
+use crate::logging::LogEvent;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ratchet_api_types::{
@@ -86,12 +87,22 @@ pub trait FilteredRepository<T, F>: CrudRepository<T> {
     async fn count_with_filters(&self, filters: F) -> Result<u64, DatabaseError>;
 }
 
+/// Repository trait for cursor-based (keyset) pagination
+#[async_trait]
+pub trait KeysetRepository<T, F>: FilteredRepository<T, F> {
+    /// Find up to `limit` entities matching `filters` with an ID after `after`, in ascending ID order
+    ///
+    /// Unlike offsets, the database seeks straight to the cursor, so deep
+    /// pages cost the same as the first one.
+    async fn find_after(&self, filters: F, after: Option<ApiId>, limit: u64) -> Result<Vec<T>, DatabaseError>;
+}
+
 // =============================================================================
 // Task Repository
 // =============================================================================
 
 /// Filter criteria for task queries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TaskFilters {
     // Basic filters (existing)
     pub name: Option<String>,
@@ -105,6 +116,9 @@ pub struct TaskFilters {
     pub name_starts_with: Option<String>,
     pub name_ends_with: Option<String>,
 
+    // Free-text search across name, description and tags
+    pub search: Option<String>,
+
     // Version filtering
     pub version: Option<String>,
     pub version_in: Option<Vec<String>>,
@@ -128,10 +142,13 @@ pub struct TaskFilters {
 
 /// Task repository interface
 #[async_trait]
-pub trait TaskRepository: FilteredRepository<UnifiedTask, TaskFilters> {
+pub trait TaskRepository: KeysetRepository<UnifiedTask, TaskFilters> {
     /// Find all enabled tasks
     async fn find_enabled(&self) -> Result<Vec<UnifiedTask>, DatabaseError>;
 
+    /// Find several tasks in one lookup, omitting IDs that don't exist
+    async fn find_by_ids(&self, ids: Vec<ApiId>) -> Result<Vec<UnifiedTask>, DatabaseError>;
+
     /// Find task by name
     async fn find_by_name(&self, name: &str) -> Result<Option<UnifiedTask>, DatabaseError>;
 
@@ -150,7 +167,7 @@ pub trait TaskRepository: FilteredRepository<UnifiedTask, TaskFilters> {
 // =============================================================================
 
 /// Filter criteria for execution queries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ExecutionFilters {
     // Basic filters (existing)
     pub task_id: Option<ApiId>,
@@ -188,11 +205,30 @@ pub struct ExecutionFilters {
     // Advanced boolean filtering
     pub can_retry: Option<bool>,
     pub can_cancel: Option<bool>,
+
+    // Label filtering, all `(key, value)` pairs must match
+    pub labels: Option<Vec<(String, String)>>,
+}
+
+/// Executions queued within one time bucket of the execution history
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionHistoryBucket {
+    /// Start of the bucket
+    pub start: DateTime<Utc>,
+    pub pending: u64,
+    pub running: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub cancelled: u64,
+    /// Duration percentiles of the bucket's finished executions
+    pub duration_p50_ms: Option<i64>,
+    pub duration_p90_ms: Option<i64>,
+    pub duration_p99_ms: Option<i64>,
 }
 
 /// Execution repository interface
 #[async_trait]
-pub trait ExecutionRepository: FilteredRepository<UnifiedExecution, ExecutionFilters> {
+pub trait ExecutionRepository: KeysetRepository<UnifiedExecution, ExecutionFilters> {
     /// Find executions by task ID
     async fn find_by_task_id(&self, task_id: ApiId) -> Result<Vec<UnifiedExecution>, DatabaseError>;
 
@@ -226,6 +262,24 @@ pub trait ExecutionRepository: FilteredRepository<UnifiedExecution, ExecutionFil
 
     /// Update execution progress
     async fn update_progress(&self, id: ApiId, progress: f32) -> Result<(), DatabaseError>;
+
+    /// Store the log events captured while an execution ran, replacing any stored before
+    async fn store_logs(&self, id: ApiId, logs: Vec<LogEvent>) -> Result<(), DatabaseError>;
+
+    /// Log events stored for an execution, oldest first
+    async fn find_logs(&self, id: ApiId) -> Result<Vec<LogEvent>, DatabaseError>;
+
+    /// Execution counts by status and duration percentiles per time bucket
+    ///
+    /// Covers executions queued in `[from, to)`, grouped into buckets of
+    /// `interval_seconds` aligned to the Unix epoch, oldest first. Empty buckets
+    /// are included.
+    async fn history(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval_seconds: i64,
+    ) -> Result<Vec<ExecutionHistoryBucket>, DatabaseError>;
 }
 
 // =============================================================================
@@ -233,7 +287,7 @@ pub trait ExecutionRepository: FilteredRepository<UnifiedExecution, ExecutionFil
 // =============================================================================
 
 /// Filter criteria for job queries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct JobFilters {
     // Basic filters (existing)
     pub task_id: Option<ApiId>,
@@ -257,6 +311,8 @@ pub struct JobFilters {
     // Extended date filtering
     pub queued_before: Option<DateTime<Utc>>,
     pub scheduled_after: Option<DateTime<Utc>>,
+    pub completed_after: Option<DateTime<Utc>>,
+    pub completed_before: Option<DateTime<Utc>>,
 
     // Retry filtering
     pub retry_count_min: Option<i32>,
@@ -276,13 +332,16 @@ pub struct JobFilters {
 
 /// Job repository interface
 #[async_trait]
-pub trait JobRepository: FilteredRepository<UnifiedJob, JobFilters> {
+pub trait JobRepository: KeysetRepository<UnifiedJob, JobFilters> {
     /// Find jobs ready for processing (sorted by priority and queue time)
     async fn find_ready_for_processing(&self, limit: u64) -> Result<Vec<UnifiedJob>, DatabaseError>;
 
     /// Find jobs by status
     async fn find_by_status(&self, status: JobStatus) -> Result<Vec<UnifiedJob>, DatabaseError>;
 
+    /// Find a schedule's jobs that are queued, processing or waiting to retry
+    async fn find_active_by_schedule(&self, schedule_id: ApiId) -> Result<Vec<UnifiedJob>, DatabaseError>;
+
     /// Mark job as processing and link to execution
     async fn mark_processing(&self, id: ApiId, execution_id: ApiId) -> Result<(), DatabaseError>;
 
@@ -297,11 +356,22 @@ pub trait JobRepository: FilteredRepository<UnifiedJob, JobFilters> {
         details: Option<serde_json::Value>,
     ) -> Result<bool, DatabaseError>; // Returns true if can retry
 
+    /// Mark job as failed without retrying it, moving it straight to the dead-letter queue
+    async fn mark_dead_letter(
+        &self,
+        id: ApiId,
+        error: String,
+        details: Option<serde_json::Value>,
+    ) -> Result<(), DatabaseError>;
+
     /// Schedule job for retry
     async fn schedule_retry(&self, id: ApiId, retry_at: DateTime<Utc>) -> Result<(), DatabaseError>;
 
     /// Cancel job
     async fn cancel(&self, id: ApiId) -> Result<(), DatabaseError>;
+
+    /// Requeue a dead-lettered job with a fresh retry budget
+    async fn requeue_dead_letter(&self, id: ApiId) -> Result<bool, DatabaseError>; // Returns false if the job is not dead-lettered
 }
 
 // =============================================================================
@@ -309,7 +379,7 @@ pub trait JobRepository: FilteredRepository<UnifiedJob, JobFilters> {
 // =============================================================================
 
 /// Filter criteria for schedule queries
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ScheduleFilters {
     // Basic filters (existing)
     pub task_id: Option<ApiId>,
@@ -346,11 +416,12 @@ pub struct ScheduleFilters {
     pub has_last_run: Option<bool>,
     pub is_due: Option<bool>,  // next_run <= now
     pub overdue: Option<bool>, // next_run < now and enabled
+    pub paused: Option<bool>,  // disabled by a bulk pause, waiting to be resumed
 }
 
 /// Schedule repository interface
 #[async_trait]
-pub trait ScheduleRepository: FilteredRepository<UnifiedSchedule, ScheduleFilters> {
+pub trait ScheduleRepository: KeysetRepository<UnifiedSchedule, ScheduleFilters> {
     /// Find all enabled schedules
     async fn find_enabled(&self) -> Result<Vec<UnifiedSchedule>, DatabaseError>;
 
@@ -365,6 +436,11 @@ pub trait ScheduleRepository: FilteredRepository<UnifiedSchedule, ScheduleFilter
 
     /// Set schedule enabled status
     async fn set_enabled(&self, id: ApiId, enabled: bool) -> Result<(), DatabaseError>;
+
+    /// Enable or disable several schedules at once, clearing their paused flag
+    ///
+    /// Either every schedule is updated or, if any is missing, none is.
+    async fn set_enabled_many(&self, ids: Vec<ApiId>, enabled: bool) -> Result<Vec<UnifiedSchedule>, DatabaseError>;
 }
 
 // =============================================================================
@@ -3,10 +3,12 @@
 //! Provides the core task execution interfaces that allow different
 //! execution engines to be used interchangeably.
 
+use crate::logging::LogEvent;
 use async_trait::async_trait;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::time::Duration;
+use uuid::Uuid;
 
 /// Core task execution interface
 ///
@@ -58,6 +60,10 @@ pub struct ExecutionContext {
     pub trace_enabled: bool,
     /// Custom metadata for the execution
     pub metadata: HashMap<String, String>,
+    /// Log level override for this execution (e.g. "debug")
+    pub log_level: Option<String>,
+    /// UUID of the stored execution record; attachments are filed under it
+    pub execution_id: Option<Uuid>,
 }
 
 impl Default for ExecutionContext {
@@ -66,6 +72,8 @@ impl Default for ExecutionContext {
             timeout: Some(Duration::from_secs(300)), // 5 minutes default
             trace_enabled: false,
             metadata: HashMap::new(),
+            log_level: None,
+            execution_id: None,
         }
     }
 }
@@ -93,6 +101,18 @@ impl ExecutionContext {
         self.metadata.insert(key.into(), value.into());
         self
     }
+
+    /// Run this execution at the given log level instead of the system default
+    pub fn with_log_level(mut self, log_level: impl Into<String>) -> Self {
+        self.log_level = Some(log_level.into());
+        self
+    }
+
+    /// Run as the stored execution with the given UUID
+    pub fn with_execution_id(mut self, execution_id: Uuid) -> Self {
+        self.execution_id = Some(execution_id);
+        self
+    }
 }
 
 /// Task execution result
@@ -104,8 +124,8 @@ pub struct ExecutionResult {
     pub output: JsonValue,
     /// Total execution time in milliseconds
     pub execution_time_ms: u64,
-    /// Log messages generated during execution
-    pub logs: Vec<String>,
+    /// Log events generated during execution, such as the task's console output
+    pub logs: Vec<LogEvent>,
     /// Optional detailed execution trace
     pub trace: Option<JsonValue>,
     /// Exit status information
@@ -183,16 +203,21 @@ mod tests {
 
     #[test]
     fn test_execution_context_builder() {
+        let execution_id = Uuid::new_v4();
         let context = ExecutionContext::new()
             .with_timeout(Duration::from_secs(60))
             .with_tracing()
             .with_metadata("user_id", "123")
-            .with_metadata("session_id", "abc");
+            .with_metadata("session_id", "abc")
+            .with_log_level("debug")
+            .with_execution_id(execution_id);
 
         assert_eq!(context.timeout, Some(Duration::from_secs(60)));
         assert!(context.trace_enabled);
         assert_eq!(context.metadata["user_id"], "123");
         assert_eq!(context.metadata["session_id"], "abc");
+        assert_eq!(context.log_level.as_deref(), Some("debug"));
+        assert_eq!(context.execution_id, Some(execution_id));
     }
 
     #[test]
@@ -237,7 +262,10 @@ mod tests {
         let result = ExecutionResult {
             output: json!({"result": "success", "value": 42}),
             execution_time_ms: 1500,
-            logs: vec!["Starting task".to_string(), "Task completed".to_string()],
+            logs: vec![
+                LogEvent::new(crate::logging::LogLevel::Info, "Starting task"),
+                LogEvent::new(crate::logging::LogLevel::Info, "Task completed"),
+            ],
             trace: Some(json!({"steps": ["init", "execute", "cleanup"]})),
             status: ExecutionStatus::Success,
         };
@@ -18,22 +18,27 @@
 //! - [`TaskExecutor`] - Core task execution interface
 //! - [`StructuredLogger`] - Logging interface for structured events
 
+pub mod audit;
 pub mod database;
 pub mod execution;
 pub mod logging;
+pub mod plugins;
 pub mod registry;
 pub mod scheduler;
 pub mod service;
 pub mod tasks;
 
 // Re-export commonly used types
+pub use audit::{AuditError, AuditExportFormat, AuditLogExport};
 pub use database::{
-    ApiKeyRepository, CrudRepository, DatabaseError, ExecutionFilters, ExecutionRepository, FilteredRepository,
-    JobFilters, JobRepository, Repository, RepositoryFactory, ScheduleFilters, ScheduleRepository, SessionRepository,
-    TaskFilters, TaskRepository, TransactionContext, TransactionManager, UserFilters, UserRepository,
+    ApiKeyRepository, CrudRepository, DatabaseError, ExecutionFilters, ExecutionHistoryBucket, ExecutionRepository,
+    FilteredRepository, JobFilters, JobRepository, KeysetRepository, Repository, RepositoryFactory, ScheduleFilters,
+    ScheduleRepository, SessionRepository, TaskFilters, TaskRepository, TransactionContext, TransactionManager,
+    UserFilters, UserRepository,
 };
 pub use execution::{ExecutionContext, ExecutionResult, TaskExecutor};
 pub use logging::{LogEvent, LogLevel, StructuredLogger};
+pub use plugins::{LoadedPlugin, PluginInventory, PluginLoadFailure};
 pub use registry::{
     FilesystemRegistry, HttpCredentials, HttpRegistry, RegistryError, RegistryManager, SyncResult, TaskMetadata,
     TaskRegistry, TaskValidator, ValidationResult,
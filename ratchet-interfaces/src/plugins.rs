@@ -0,0 +1,42 @@
+//! Plugin inventory interface
+//!
+//! Lets API layers report which plugins are loaded without depending on the
+//! plugin system itself.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A plugin registered with the server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadedPlugin {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    /// Plugin API version the plugin was built against
+    pub api_version: String,
+    pub plugin_type: String,
+    pub status: String,
+    /// Library the plugin was loaded from, if it was loaded dynamically
+    pub source: Option<String>,
+    pub loaded_at: Option<DateTime<Utc>>,
+    pub error: Option<String>,
+}
+
+/// A plugin library that could not be loaded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginLoadFailure {
+    /// Path of the library
+    pub source: String,
+    pub error: String,
+}
+
+/// Read access to the plugins known to the server
+#[async_trait]
+pub trait PluginInventory: Send + Sync {
+    /// Plugins currently registered
+    async fn loaded_plugins(&self) -> Vec<LoadedPlugin>;
+
+    /// Plugin libraries that failed to load
+    async fn load_failures(&self) -> Vec<PluginLoadFailure>;
+}
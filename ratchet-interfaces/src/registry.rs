@@ -7,6 +7,7 @@
 use async_trait::async_trait;
 // Note: Registry interfaces don't need ratchet_api_types imports currently
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::Path;
 
 /// Task metadata for registry operations
@@ -163,6 +164,12 @@ pub struct ValidationError {
     pub field: Option<String>,
     pub message: String,
     pub code: String,
+    /// 1-based line in the task source the error refers to, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// 1-based column in the task source the error refers to, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<u32>,
 }
 
 /// Validation warning details
@@ -172,3 +179,59 @@ pub struct ValidationWarning {
     pub message: String,
     pub code: String,
 }
+
+impl ValidationResult {
+    /// Result without any errors or warnings
+    pub fn new() -> Self {
+        Self {
+            valid: true,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Record an error, making the result invalid
+    pub fn add_error(&mut self, error: ValidationError) {
+        self.valid = false;
+        self.errors.push(error);
+    }
+}
+
+impl Default for ValidationResult {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValidationError {
+    pub fn new(field: Option<&str>, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.map(str::to_string),
+            message: message.into(),
+            code: code.to_string(),
+            line: None,
+            column: None,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.field, self.line, self.column) {
+            (Some(field), Some(line), Some(column)) => write!(f, "{}:{}:{}: ", field, line, column)?,
+            (Some(field), Some(line), None) => write!(f, "{}:{}: ", field, line)?,
+            (Some(field), _, _) => write!(f, "{}: ", field)?,
+            _ => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
@@ -94,6 +94,25 @@ pub trait SchedulerService: Send + Sync {
     /// execution history and next run time.
     async fn get_schedule_status(&self, schedule_id: ApiId) -> Result<ScheduleStatus, SchedulerError>;
 
+    /// Pause every enabled schedule
+    ///
+    /// Paused schedules are disabled and marked so that `resume_all` can
+    /// restore them. Schedules that were already disabled are left alone.
+    /// Returns the IDs of the schedules that were paused.
+    async fn pause_all(&self) -> Result<Vec<ApiId>, SchedulerError>;
+
+    /// Resume the schedules disabled by `pause_all`
+    ///
+    /// Schedules explicitly enabled or disabled since the pause are not
+    /// touched. Returns the IDs of the schedules that were resumed.
+    async fn resume_all(&self) -> Result<Vec<ApiId>, SchedulerError>;
+
+    /// Enable or disable several schedules at once
+    ///
+    /// Nothing is changed unless every schedule exists. An explicit change
+    /// also cancels any pending resume from `pause_all`.
+    async fn set_enabled(&self, schedule_ids: Vec<ApiId>, enabled: bool) -> Result<(), SchedulerError>;
+
     /// Check if the scheduler is currently running
     fn is_running(&self) -> bool;
 
@@ -0,0 +1,263 @@
+//! Wire encoding of IPC messages
+//!
+//! Every message travels as a single line of JSON. When both ends speak at
+//! least [`COMPRESSION_PROTOCOL_VERSION`], envelopes larger than the configured
+//! threshold are gzipped and sent as a compressed frame instead:
+//!
+//! ```text
+//! {"protocol_version":2,"encoding":"gzip","payload":"<base64 of the gzipped envelope JSON>"}
+//! ```
+//!
+//! The protocol version is negotiated from the envelopes each side receives: a
+//! transport sends with the lower of its own and its peer's version, so workers
+//! speaking version 1 never see a compressed frame.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+use crate::error::IpcError;
+use crate::protocol::{MessageEnvelope, IPC_PROTOCOL_VERSION, MIN_IPC_PROTOCOL_VERSION};
+
+/// First protocol version able to receive compressed frames
+pub const COMPRESSION_PROTOCOL_VERSION: u32 = 2;
+
+/// Compression settings for IPC message bodies
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IpcCompression {
+    /// Whether large messages are compressed when the peer supports it
+    pub enabled: bool,
+
+    /// Minimum serialized message size in bytes before compressing
+    pub threshold_bytes: usize,
+
+    /// Gzip compression level (0-9)
+    pub level: u32,
+}
+
+impl Default for IpcCompression {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold_bytes: 64 * 1024,
+            level: 6,
+        }
+    }
+}
+
+impl IpcCompression {
+    /// Never compress outgoing messages
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            ..Self::default()
+        }
+    }
+
+    /// Compress messages of at least `threshold_bytes`
+    pub fn with_threshold(threshold_bytes: usize) -> Self {
+        Self {
+            threshold_bytes,
+            ..Self::default()
+        }
+    }
+}
+
+/// Body encoding of a compressed frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FrameEncoding {
+    Gzip,
+}
+
+/// Compressed envelope as sent on the wire
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompressedFrame {
+    protocol_version: u32,
+    encoding: FrameEncoding,
+    payload: String,
+}
+
+/// Envelope serialized with an explicit protocol version
+#[derive(Serialize)]
+struct WireEnvelope<'a, T> {
+    protocol_version: u32,
+    timestamp: &'a DateTime<Utc>,
+    message: &'a T,
+}
+
+/// Protocol version to use when talking to a peer
+///
+/// Until the peer's version is known our own version is used, which lets the
+/// first message (the worker's `Ready`) advertise what we support.
+pub fn negotiate_version(peer_version: Option<u32>) -> u32 {
+    peer_version.map_or(IPC_PROTOCOL_VERSION, |peer| peer.min(IPC_PROTOCOL_VERSION))
+}
+
+/// Encode an envelope as a single line (without the trailing newline)
+pub fn encode_message<T: Serialize>(
+    envelope: &MessageEnvelope<T>,
+    protocol_version: u32,
+    compression: &IpcCompression,
+) -> Result<String, IpcError> {
+    let json = serde_json::to_string(&WireEnvelope {
+        protocol_version,
+        timestamp: &envelope.timestamp,
+        message: &envelope.message,
+    })
+    .map_err(|e| IpcError::SerializationError(e.to_string()))?;
+
+    if !compression.enabled
+        || protocol_version < COMPRESSION_PROTOCOL_VERSION
+        || json.len() < compression.threshold_bytes
+    {
+        return Ok(json);
+    }
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::new(compression.level.min(9)));
+    encoder
+        .write_all(json.as_bytes())
+        .and_then(|_| encoder.finish())
+        .map_err(|e| IpcError::CompressionError(e.to_string()))
+        .and_then(|compressed| {
+            serde_json::to_string(&CompressedFrame {
+                protocol_version,
+                encoding: FrameEncoding::Gzip,
+                payload: BASE64.encode(compressed),
+            })
+            .map_err(|e| IpcError::SerializationError(e.to_string()))
+        })
+}
+
+/// Decode a line produced by [`encode_message`], decompressing it if needed
+///
+/// The envelope's protocol version must lie between [`MIN_IPC_PROTOCOL_VERSION`]
+/// and [`IPC_PROTOCOL_VERSION`].
+pub fn decode_message<T: for<'de> Deserialize<'de>>(line: &str) -> Result<MessageEnvelope<T>, IpcError> {
+    // Plain envelopes fail to parse as a frame at their second field
+    let envelope: MessageEnvelope<T> = match serde_json::from_str::<CompressedFrame>(line) {
+        Ok(frame) => {
+            check_version(frame.protocol_version)?;
+            let compressed = BASE64
+                .decode(frame.payload)
+                .map_err(|e| IpcError::CompressionError(e.to_string()))?;
+            let mut json = String::new();
+            GzDecoder::new(compressed.as_slice())
+                .read_to_string(&mut json)
+                .map_err(|e| IpcError::CompressionError(e.to_string()))?;
+            serde_json::from_str(&json).map_err(|e| IpcError::DeserializationError(e.to_string()))?
+        }
+        Err(_) => serde_json::from_str(line).map_err(|e| {
+            // A peer on an unsupported version may send messages we can't parse;
+            // report the version mismatch rather than the parse error
+            peek_version(line)
+                .and_then(|version| check_version(version).err())
+                .unwrap_or_else(|| IpcError::DeserializationError(e.to_string()))
+        })?,
+    };
+
+    check_version(envelope.protocol_version)?;
+    Ok(envelope)
+}
+
+/// Read just the protocol version of a line that doesn't parse as an envelope
+fn peek_version(line: &str) -> Option<u32> {
+    #[derive(Deserialize)]
+    struct VersionProbe {
+        protocol_version: u32,
+    }
+
+    serde_json::from_str::<VersionProbe>(line)
+        .ok()
+        .map(|probe| probe.protocol_version)
+}
+
+fn check_version(version: u32) -> Result<(), IpcError> {
+    if (MIN_IPC_PROTOCOL_VERSION..=IPC_PROTOCOL_VERSION).contains(&version) {
+        Ok(())
+    } else {
+        Err(IpcError::ProtocolVersionMismatch {
+            expected: IPC_PROTOCOL_VERSION,
+            actual: version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::{ExecutionContext, WorkerMessage};
+    use uuid::Uuid;
+
+    fn large_task() -> MessageEnvelope<WorkerMessage> {
+        let rows: Vec<serde_json::Value> = (0..5000)
+            .map(|i| serde_json::json!({"id": i, "name": "row", "tags": ["a", "b", "c"]}))
+            .collect();
+
+        MessageEnvelope::new(WorkerMessage::ExecuteTask {
+            job_id: 1,
+            task_id: 2,
+            task_path: "/tasks/large".to_string(),
+            input_data: serde_json::json!({ "rows": rows }),
+            execution_context: ExecutionContext::new(Uuid::new_v4(), None, Uuid::new_v4(), "1.0.0".to_string()),
+            correlation_id: Uuid::new_v4(),
+            task_source: None,
+        })
+    }
+
+    #[test]
+    fn test_large_payload_is_compressed_in_transit() {
+        let envelope = large_task();
+        let plain = serde_json::to_string(&envelope).unwrap();
+
+        let line = encode_message(&envelope, IPC_PROTOCOL_VERSION, &IpcCompression::default()).unwrap();
+        assert!(line.contains("\"encoding\":\"gzip\""));
+        assert!(line.len() < plain.len() / 4);
+
+        let decoded: MessageEnvelope<WorkerMessage> = decode_message(&line).unwrap();
+        assert_eq!(decoded.protocol_version, IPC_PROTOCOL_VERSION);
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), plain);
+    }
+
+    #[test]
+    fn test_small_and_legacy_messages_are_not_compressed() {
+        let ping = MessageEnvelope::new(WorkerMessage::Ping {
+            correlation_id: Uuid::new_v4(),
+        });
+        let line = encode_message(&ping, IPC_PROTOCOL_VERSION, &IpcCompression::default()).unwrap();
+        assert!(!line.contains("encoding"));
+
+        // A version 1 peer must never receive a compressed frame
+        let line = encode_message(&large_task(), MIN_IPC_PROTOCOL_VERSION, &IpcCompression::default()).unwrap();
+        assert!(!line.contains("encoding"));
+        let legacy: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(legacy["protocol_version"], MIN_IPC_PROTOCOL_VERSION);
+
+        let line = encode_message(&large_task(), IPC_PROTOCOL_VERSION, &IpcCompression::disabled()).unwrap();
+        assert!(!line.contains("encoding"));
+    }
+
+    #[test]
+    fn test_version_negotiation() {
+        assert_eq!(negotiate_version(None), IPC_PROTOCOL_VERSION);
+        assert_eq!(negotiate_version(Some(1)), 1);
+        assert_eq!(negotiate_version(Some(IPC_PROTOCOL_VERSION + 1)), IPC_PROTOCOL_VERSION);
+
+        let mut future = serde_json::to_value(MessageEnvelope::new(WorkerMessage::Shutdown)).unwrap();
+        future["protocol_version"] = serde_json::json!(IPC_PROTOCOL_VERSION + 1);
+        let result = decode_message::<WorkerMessage>(&future.to_string());
+        assert!(matches!(result, Err(IpcError::ProtocolVersionMismatch { .. })));
+
+        // Unknown message shapes from an unsupported version still report the version
+        let line = r#"{"protocol_version":99,"timestamp":"2024-01-01T00:00:00Z","message":{"type":"hello_v99"}}"#;
+        let result = decode_message::<WorkerMessage>(line);
+        assert!(matches!(
+            result,
+            Err(IpcError::ProtocolVersionMismatch { actual: 99, .. })
+        ));
+    }
+}
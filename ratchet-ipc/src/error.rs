@@ -34,6 +34,10 @@ pub enum IpcError {
     #[error("Worker error: {0}")]
     WorkerError(WorkerError),
 
+    /// Message compression or decompression failed
+    #[error("Compression error: {0}")]
+    CompressionError(String),
+
     /// Invalid message format
     #[error("Invalid message format: {0}")]
     InvalidMessage(String),
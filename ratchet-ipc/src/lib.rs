@@ -3,14 +3,16 @@
 //! This crate provides the IPC protocol and transport abstractions used for
 //! communication between the coordinator and worker processes.
 
+pub mod codec;
 pub mod error;
 pub mod protocol;
 pub mod transport;
 
 // Re-export commonly used types
+pub use codec::{decode_message, encode_message, negotiate_version, IpcCompression, COMPRESSION_PROTOCOL_VERSION};
 pub use error::IpcError;
 pub use protocol::{
-    CoordinatorMessage, ExecutionContext, MessageEnvelope, TaskExecutionResult, TaskValidationResult, WorkerError,
-    WorkerMessage, WorkerStatus, IPC_PROTOCOL_VERSION,
+    CoordinatorMessage, ExecutionContext, MessageEnvelope, TaskExecutionResult, TaskSource, TaskValidationResult,
+    WorkerError, WorkerMessage, WorkerStatus, IPC_PROTOCOL_VERSION, MIN_IPC_PROTOCOL_VERSION,
 };
 pub use transport::{IpcTransport, StdioTransport};
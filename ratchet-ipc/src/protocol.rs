@@ -1,13 +1,19 @@
 //! IPC protocol definitions and message types
 
 use chrono::{DateTime, Utc};
+use ratchet_interfaces::logging::LogEvent;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::fmt;
 use uuid::Uuid;
 
 /// IPC protocol version for compatibility checking
-pub const IPC_PROTOCOL_VERSION: u32 = 1;
+///
+/// Version 2 adds compressed frames (see [`crate::codec`]).
+pub const IPC_PROTOCOL_VERSION: u32 = 2;
+
+/// Oldest protocol version still accepted from a peer
+pub const MIN_IPC_PROTOCOL_VERSION: u32 = 1;
 
 /// Execution context passed to JavaScript tasks
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +22,9 @@ pub struct ExecutionContext {
     pub job_id: Option<String>, // Job UUID as string (optional for direct executions)
     pub task_id: String,        // Task UUID as string
     pub task_version: String,   // Task version
+    /// Log level override for this execution (e.g. "debug"); the worker default applies when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
 }
 
 impl ExecutionContext {
@@ -26,8 +35,27 @@ impl ExecutionContext {
             job_id: job_uuid.map(|uuid| uuid.to_string()),
             task_id: task_uuid.to_string(),
             task_version,
+            log_level: None,
         }
     }
+
+    /// Run this execution at the given log level instead of the worker default
+    pub fn with_log_level(mut self, log_level: impl Into<String>) -> Self {
+        self.log_level = Some(log_level.into());
+        self
+    }
+}
+
+/// Code of a task sent along with its execution, for tasks that live in the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSource {
+    pub name: String,
+    /// JavaScript source of the task
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_schema: Option<JsonValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_schema: Option<JsonValue>,
 }
 
 /// Messages sent from coordinator to worker processes
@@ -42,6 +70,9 @@ pub enum WorkerMessage {
         input_data: JsonValue,
         execution_context: ExecutionContext,
         correlation_id: Uuid,
+        /// Task code to run instead of resolving `task_path`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        task_source: Option<TaskSource>,
     },
 
     /// Validate a task
@@ -98,6 +129,9 @@ pub struct TaskExecutionResult {
     pub started_at: DateTime<Utc>,
     pub completed_at: DateTime<Utc>,
     pub duration_ms: i32,
+    /// Console output written by the task
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logs: Vec<LogEvent>,
 }
 
 impl TaskExecutionResult {
@@ -112,6 +146,7 @@ impl TaskExecutionResult {
             started_at,
             completed_at,
             duration_ms,
+            logs: Vec::new(),
         }
     }
 
@@ -131,8 +166,15 @@ impl TaskExecutionResult {
             started_at,
             completed_at,
             duration_ms,
+            logs: Vec::new(),
         }
     }
+
+    /// Attach the console output written by the task
+    pub fn with_logs(mut self, logs: Vec<LogEvent>) -> Self {
+        self.logs = logs;
+        self
+    }
 }
 
 /// Task validation result
@@ -236,6 +278,13 @@ pub enum WorkerError {
 
     /// Message parse error
     MessageParseError { error: String },
+
+    /// Worker speaks an IPC protocol version the coordinator can't talk to
+    IncompatibleProtocol {
+        worker_version: u32,
+        min_supported: u32,
+        max_supported: u32,
+    },
 }
 
 impl fmt::Display for WorkerError {
@@ -259,6 +308,15 @@ impl fmt::Display for WorkerError {
             WorkerError::MessageParseError { error } => {
                 write!(f, "Message parse error: {}", error)
             }
+            WorkerError::IncompatibleProtocol {
+                worker_version,
+                min_supported,
+                max_supported,
+            } => write!(
+                f,
+                "Incompatible IPC protocol: worker speaks version {}, coordinator supports {}-{}",
+                worker_version, min_supported, max_supported
+            ),
         }
     }
 }
@@ -285,7 +343,7 @@ impl<T> MessageEnvelope<T> {
 
     /// Check if protocol version is compatible
     pub fn is_compatible(&self) -> bool {
-        self.protocol_version == IPC_PROTOCOL_VERSION
+        (MIN_IPC_PROTOCOL_VERSION..=IPC_PROTOCOL_VERSION).contains(&self.protocol_version)
     }
 }
 
@@ -305,6 +363,7 @@ mod tests {
         assert_eq!(context.job_id, Some(job_id.to_string()));
         assert_eq!(context.task_id, task_id.to_string());
         assert_eq!(context.task_version, "1.0.0");
+        assert!(context.log_level.is_none());
     }
 
     #[test]
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
 
+use crate::codec::{decode_message, encode_message, negotiate_version, IpcCompression};
 use crate::error::IpcError;
 use crate::protocol::MessageEnvelope;
 
@@ -21,9 +22,14 @@ pub trait IpcTransport: Send + Sync {
 }
 
 /// Stdin/Stdout IPC transport for process communication
+///
+/// Large messages are compressed once the peer has shown, through the protocol
+/// version of a received envelope, that it can decompress them.
 pub struct StdioTransport {
     stdin: tokio::io::Stdin,
     stdout: tokio::io::Stdout,
+    compression: IpcCompression,
+    peer_version: Option<u32>,
 }
 
 impl StdioTransport {
@@ -32,8 +38,21 @@ impl StdioTransport {
         Self {
             stdin: tokio::io::stdin(),
             stdout: tokio::io::stdout(),
+            compression: IpcCompression::default(),
+            peer_version: None,
         }
     }
+
+    /// Use the given compression settings for outgoing messages
+    pub fn with_compression(mut self, compression: IpcCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Protocol version of the peer, once a message has been received
+    pub fn peer_version(&self) -> Option<u32> {
+        self.peer_version
+    }
 }
 
 impl Default for StdioTransport {
@@ -45,7 +64,7 @@ impl Default for StdioTransport {
 #[async_trait]
 impl IpcTransport for StdioTransport {
     async fn send<T: Serialize + Send + Sync>(&mut self, message: &MessageEnvelope<T>) -> Result<(), IpcError> {
-        let json = serde_json::to_string(message).map_err(|e| IpcError::SerializationError(e.to_string()))?;
+        let json = encode_message(message, negotiate_version(self.peer_version), &self.compression)?;
 
         // Send with newline delimiter
         let message_with_newline = format!("{}\n", json);
@@ -78,16 +97,9 @@ impl IpcTransport for StdioTransport {
         // Remove newline
         line.truncate(line.trim_end().len());
 
-        let envelope: MessageEnvelope<T> =
-            serde_json::from_str(&line).map_err(|e| IpcError::DeserializationError(e.to_string()))?;
-
-        // Check protocol version compatibility
-        if envelope.protocol_version != crate::protocol::IPC_PROTOCOL_VERSION {
-            return Err(IpcError::ProtocolVersionMismatch {
-                expected: crate::protocol::IPC_PROTOCOL_VERSION,
-                actual: envelope.protocol_version,
-            });
-        }
+        // Decompresses if needed and checks protocol version compatibility
+        let envelope: MessageEnvelope<T> = decode_message(&line)?;
+        self.peer_version = Some(envelope.protocol_version);
 
         Ok(envelope)
     }
@@ -99,9 +111,14 @@ impl IpcTransport for StdioTransport {
 }
 
 /// Child process transport for parent-child communication
+///
+/// The child is expected to speak first (workers send `Ready`), which tells the
+/// transport which protocol version to use for its own messages.
 pub struct ChildProcessTransport {
     stdin: Option<tokio::process::ChildStdin>,
     stdout: Option<tokio::process::ChildStdout>,
+    compression: IpcCompression,
+    peer_version: Option<u32>,
 }
 
 impl ChildProcessTransport {
@@ -110,8 +127,21 @@ impl ChildProcessTransport {
         Self {
             stdin: Some(stdin),
             stdout: Some(stdout),
+            compression: IpcCompression::default(),
+            peer_version: None,
         }
     }
+
+    /// Use the given compression settings for outgoing messages
+    pub fn with_compression(mut self, compression: IpcCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Protocol version of the peer, once a message has been received
+    pub fn peer_version(&self) -> Option<u32> {
+        self.peer_version
+    }
 }
 
 #[async_trait]
@@ -122,7 +152,7 @@ impl IpcTransport for ChildProcessTransport {
             .as_mut()
             .ok_or_else(|| IpcError::IoError("stdin already closed".to_string()))?;
 
-        let json = serde_json::to_string(message).map_err(|e| IpcError::SerializationError(e.to_string()))?;
+        let json = encode_message(message, negotiate_version(self.peer_version), &self.compression)?;
 
         let message_with_newline = format!("{}\n", json);
         stdin
@@ -155,15 +185,8 @@ impl IpcTransport for ChildProcessTransport {
 
         line.truncate(line.trim_end().len());
 
-        let envelope: MessageEnvelope<T> =
-            serde_json::from_str(&line).map_err(|e| IpcError::DeserializationError(e.to_string()))?;
-
-        if envelope.protocol_version != crate::protocol::IPC_PROTOCOL_VERSION {
-            return Err(IpcError::ProtocolVersionMismatch {
-                expected: crate::protocol::IPC_PROTOCOL_VERSION,
-                actual: envelope.protocol_version,
-            });
-        }
+        let envelope: MessageEnvelope<T> = decode_message(&line)?;
+        self.peer_version = Some(envelope.protocol_version);
 
         Ok(envelope)
     }
@@ -0,0 +1,114 @@
+//! Execution attachments API for JavaScript tasks
+//!
+//! Tasks call `ratchet.attach(name, data, mimeType)` to attach a file artifact to
+//! the current execution. Strings are stored as UTF-8 text, arrays and
+//! `Uint8Array`s as raw bytes and other objects as JSON. The attachments are
+//! collected once the task has finished and handed to the caller for storage.
+
+use boa_engine::{Context, JsError, Source};
+use serde::{Deserialize, Serialize};
+
+use crate::JsExecutionError;
+
+/// Global JavaScript variable collecting attachments during execution
+const ATTACHMENTS_VAR: &str = "__ratchet_attachments";
+
+/// File artifact attached to an execution by a task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskAttachment {
+    /// Attachment name, unique within the execution
+    pub name: String,
+
+    /// MIME type of the content
+    pub mime_type: String,
+
+    /// Raw attachment content
+    pub data: Vec<u8>,
+}
+
+/// Attachment as recorded by the JavaScript side
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAttachment {
+    name: String,
+    mime_type: String,
+    #[serde(flatten)]
+    content: RawContent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "encoding", content = "content", rename_all = "lowercase")]
+enum RawContent {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+/// Register the `ratchet.attach` function in the JavaScript context
+pub fn register_attachments(context: &mut Context) -> Result<(), JsError> {
+    context.eval(Source::from_bytes(
+        r#"
+        var __ratchet_attachments = [];
+        var ratchet = (typeof ratchet === 'object' && ratchet !== null) ? ratchet : {};
+
+        // Attach a file artifact to the current execution
+        ratchet.attach = function(name, data, mimeType) {
+            if (typeof name !== 'string' || name.length === 0) {
+                throw new TypeError('Attachment name must be a non-empty string');
+            }
+            if (mimeType !== undefined && mimeType !== null && typeof mimeType !== 'string') {
+                throw new TypeError('Attachment MIME type must be a string');
+            }
+
+            var attachment;
+            if (typeof data === 'string') {
+                attachment = { encoding: 'text', content: data, mimeType: mimeType || 'text/plain' };
+            } else if (data instanceof Uint8Array || Array.isArray(data)) {
+                attachment = { encoding: 'bytes', content: Array.from(data), mimeType: mimeType || 'application/octet-stream' };
+            } else if (data !== null && typeof data === 'object') {
+                attachment = { encoding: 'text', content: JSON.stringify(data), mimeType: mimeType || 'application/json' };
+            } else {
+                throw new TypeError('Attachment data must be a string, byte array or object');
+            }
+
+            attachment.name = name;
+            __ratchet_attachments.push(attachment);
+        };
+    "#,
+    ))?;
+
+    Ok(())
+}
+
+/// Collect the attachments recorded during execution
+///
+/// When a task attaches the same name more than once the last attachment wins.
+pub fn collect_attachments(context: &mut Context) -> Result<Vec<TaskAttachment>, JsExecutionError> {
+    let json = context
+        .eval(Source::from_bytes(&format!(
+            "JSON.stringify(typeof {var} === 'undefined' ? [] : {var})",
+            var = ATTACHMENTS_VAR
+        )))
+        .and_then(|value| value.to_string(context))
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to read task attachments: {}", e)))?
+        .to_std_string_escaped();
+
+    let raw: Vec<RawAttachment> = serde_json::from_str(&json)
+        .map_err(|e| JsExecutionError::InvalidOutputFormat(format!("Invalid task attachment: {}", e)))?;
+
+    let mut attachments: Vec<TaskAttachment> = Vec::with_capacity(raw.len());
+    for attachment in raw {
+        let data = match attachment.content {
+            RawContent::Text(text) => text.into_bytes(),
+            RawContent::Bytes(bytes) => bytes,
+        };
+
+        attachments.retain(|existing| existing.name != attachment.name);
+        attachments.push(TaskAttachment {
+            name: attachment.name,
+            mime_type: attachment.mime_type,
+            data,
+        });
+    }
+
+    Ok(attachments)
+}
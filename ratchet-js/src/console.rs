@@ -0,0 +1,272 @@
+//! Console capture for JavaScript tasks
+//!
+//! `console.log`, `info`, `warn`, `error`, `debug` and `trace` format their
+//! arguments like a browser console (strings as-is, everything else as JSON) and
+//! hand the line to the native `__ratchet_console_write` function. Each line becomes
+//! a [`LogEvent`] tagged with the task and execution ids, which is sent to the
+//! configured [`StructuredLogger`] and kept in the capture's buffer for the
+//! execution result. The buffer keeps the first lines up to a number of events
+//! and a total message size; lines past either limit only reach the logger.
+
+use boa_engine::{native_function::NativeFunction, Context, JsResult, JsString, JsValue, Source};
+use boa_gc::{Finalize, Trace};
+use ratchet_interfaces::logging::{LogEvent, LogLevel, StructuredLogger};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::JsExecutionError;
+
+/// Global JavaScript function receiving formatted console lines
+const CONSOLE_WRITE_FN: &str = "__ratchet_console_write";
+
+/// Default number of console events kept per execution
+pub const DEFAULT_MAX_CONSOLE_EVENTS: usize = 1000;
+
+/// Default total size in bytes of the console messages kept per execution
+pub const DEFAULT_MAX_CONSOLE_BYTES: usize = 1024 * 1024;
+
+/// Destination of a task's console output
+#[derive(Clone)]
+pub struct ConsoleCapture {
+    buffer: Arc<Mutex<ConsoleBuffer>>,
+    max_events: usize,
+    max_bytes: usize,
+    logger: Option<Arc<dyn StructuredLogger>>,
+    task_id: Option<String>,
+    execution_id: Option<String>,
+}
+
+/// Captured events and what was left out of them
+#[derive(Default)]
+struct ConsoleBuffer {
+    events: Vec<LogEvent>,
+    bytes: usize,
+    dropped: usize,
+}
+
+impl Default for ConsoleCapture {
+    fn default() -> Self {
+        Self {
+            buffer: Arc::default(),
+            max_events: DEFAULT_MAX_CONSOLE_EVENTS,
+            max_bytes: DEFAULT_MAX_CONSOLE_BYTES,
+            logger: None,
+            task_id: None,
+            execution_id: None,
+        }
+    }
+}
+
+impl ConsoleCapture {
+    /// Capture console output into a buffer only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max_events` events totalling `max_bytes` of messages
+    pub fn with_limits(mut self, max_events: usize, max_bytes: usize) -> Self {
+        self.max_events = max_events;
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Also send each console line to `logger` as it is written
+    pub fn with_logger(mut self, logger: Arc<dyn StructuredLogger>) -> Self {
+        self.logger = Some(logger);
+        self
+    }
+
+    /// Tag captured events with the task and execution they came from
+    ///
+    /// The returned capture shares its buffer with `self`.
+    pub fn with_execution(mut self, task_id: &str, execution_id: &str) -> Self {
+        self.task_id = Some(task_id.to_string());
+        self.execution_id = Some(execution_id.to_string());
+        self
+    }
+
+    /// Events captured so far, oldest first
+    ///
+    /// When lines were left out, a final warning says how many.
+    pub fn events(&self) -> Vec<LogEvent> {
+        let buffer = self.buffer.lock().unwrap();
+        let mut events = buffer.events.clone();
+        if buffer.dropped > 0 {
+            events.push(self.event(
+                LogLevel::Warn,
+                format!(
+                    "{} console lines dropped after reaching the capture limit of {} events or {} bytes",
+                    buffer.dropped, self.max_events, self.max_bytes
+                ),
+            ));
+        }
+        events
+    }
+
+    fn write(&self, level: LogLevel, message: String) {
+        let size = message.len();
+        let event = self.event(level, message);
+
+        if let Some(logger) = &self.logger {
+            if logger.should_log(level) {
+                logger.log(event.clone());
+            }
+        }
+
+        // Once a line is dropped all later ones are too, so the buffer stays a prefix of the output
+        let mut buffer = self.buffer.lock().unwrap();
+        if buffer.dropped > 0 || buffer.events.len() >= self.max_events || buffer.bytes + size > self.max_bytes {
+            buffer.dropped += 1;
+            return;
+        }
+        buffer.bytes += size;
+        buffer.events.push(event);
+    }
+
+    /// Console event tagged with the task and execution
+    fn event(&self, level: LogLevel, message: String) -> LogEvent {
+        let mut event = LogEvent::new(level, message)
+            .with_source("javascript")
+            .with_logger("console");
+        if let Some(task_id) = &self.task_id {
+            event = event.with_field("task_id", task_id.as_str());
+        }
+        if let Some(execution_id) = &self.execution_id {
+            event = event
+                .with_field("execution_id", execution_id.as_str())
+                .with_correlation_id(execution_id.as_str());
+        }
+        event
+    }
+}
+
+impl fmt::Debug for ConsoleCapture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsoleCapture")
+            .field("events", &self.buffer.lock().unwrap().events.len())
+            .field("max_events", &self.max_events)
+            .field("max_bytes", &self.max_bytes)
+            .field("logger", &self.logger.is_some())
+            .field("task_id", &self.task_id)
+            .field("execution_id", &self.execution_id)
+            .finish()
+    }
+}
+
+/// Log level of a console method
+fn console_level(method: &str) -> LogLevel {
+    match method {
+        "error" => LogLevel::Error,
+        "warn" => LogLevel::Warn,
+        "debug" => LogLevel::Debug,
+        "trace" => LogLevel::Trace,
+        _ => LogLevel::Info,
+    }
+}
+
+/// Capture owned by the native write function
+#[derive(Trace, Finalize)]
+struct ConsoleWriter {
+    #[unsafe_ignore_trace]
+    capture: ConsoleCapture,
+}
+
+/// Register the `console` object in the JavaScript context, writing to `capture`
+pub fn register_console(context: &mut Context, capture: ConsoleCapture) -> Result<(), JsExecutionError> {
+    context
+        .register_global_callable(
+            JsString::from(CONSOLE_WRITE_FN),
+            2,
+            NativeFunction::from_copy_closure_with_captures(write_console, ConsoleWriter { capture }),
+        )
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register console writer: {}", e)))?;
+
+    context
+        .eval(Source::from_bytes(
+            r#"
+        var console = (function() {
+            function format(args) {
+                return Array.prototype.map.call(args, function(arg) {
+                    if (typeof arg === 'string') {
+                        return arg;
+                    }
+                    if (arg instanceof Error) {
+                        return String(arg);
+                    }
+                    try {
+                        var json = JSON.stringify(arg);
+                        return json === undefined ? String(arg) : json;
+                    } catch (e) {
+                        return String(arg);
+                    }
+                }).join(' ');
+            }
+
+            var console = {};
+            ['log', 'info', 'warn', 'error', 'debug', 'trace'].forEach(function(method) {
+                console[method] = function() {
+                    __ratchet_console_write(method, format(arguments));
+                };
+            });
+            return console;
+        })();
+        "#,
+        ))
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register console API: {}", e)))?;
+
+    Ok(())
+}
+
+/// `__ratchet_console_write(method, message)`
+fn write_console(
+    _this: &JsValue,
+    args: &[JsValue],
+    writer: &ConsoleWriter,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let method = string_arg(args, 0, context)?;
+    let message = string_arg(args, 1, context)?;
+
+    writer.capture.write(console_level(&method), message);
+    Ok(JsValue::undefined())
+}
+
+fn string_arg(args: &[JsValue], index: usize, context: &mut Context) -> JsResult<String> {
+    match args.get(index) {
+        Some(value) => Ok(value.to_string(context)?.to_std_string_escaped()),
+        None => Ok(String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_stops_at_event_limit() {
+        let console = ConsoleCapture::new().with_limits(2, DEFAULT_MAX_CONSOLE_BYTES);
+        for line in ["one", "two", "three", "four"] {
+            console.write(LogLevel::Info, line.to_string());
+        }
+
+        let events = console.events();
+        let messages: Vec<_> = events.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages[..2], ["one", "two"]);
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[2].level, LogLevel::Warn);
+        assert!(events[2].message.starts_with("2 console lines dropped"));
+    }
+
+    #[test]
+    fn test_capture_stops_at_byte_limit() {
+        let console = ConsoleCapture::new().with_limits(DEFAULT_MAX_CONSOLE_EVENTS, 10);
+        console.write(LogLevel::Info, "x".repeat(6));
+        console.write(LogLevel::Info, "y".repeat(6));
+        console.write(LogLevel::Info, "z".repeat(4));
+
+        let messages: Vec<_> = console.events().into_iter().map(|e| e.message).collect();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0], "xxxxxx");
+        assert!(messages[1].starts_with("2 console lines dropped"));
+    }
+}
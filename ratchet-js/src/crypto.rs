@@ -0,0 +1,324 @@
+//! Hashing, HMAC and secure random values for JavaScript tasks
+//!
+//! Tasks get a `crypto` global shaped after the Web Crypto API:
+//!
+//! - `crypto.subtle.digest(algorithm, data)` hashes with SHA-256, SHA-384 or SHA-512.
+//! - `crypto.subtle.importKey("raw", key, { name: "HMAC", hash }, extractable, usages)`
+//!   creates an HMAC key over one of the same hashes. `crypto.subtle.sign`,
+//!   `crypto.subtle.verify` and `crypto.subtle.exportKey("raw", key)` use it.
+//! - `crypto.getRandomValues(typedArray)` fills an integer typed array of at most
+//!   65536 bytes from the operating system's secure random source.
+//! - `crypto.randomUUID()` returns a random version 4 UUID.
+//!
+//! As on the web platform the `subtle` methods return promises, resolving to an
+//! `ArrayBuffer` for digests and signatures. Data may be an `ArrayBuffer`, a typed
+//! array or, unlike the web platform, a string, which is hashed as UTF-8. No other
+//! algorithms are supported; requesting one, like any other invalid input,
+//! throws or rejects with a `DataError`.
+
+use boa_engine::{native_function::NativeFunction, Context, JsNativeError, JsResult, JsString, JsValue, Source};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::JsExecutionError;
+
+/// Global JavaScript function hashing a binary string
+const DIGEST_FN: &str = "__ratchet_crypto_digest";
+
+/// Global JavaScript function computing an HMAC over a binary string
+const HMAC_FN: &str = "__ratchet_crypto_hmac";
+
+/// Global JavaScript function returning secure random bytes as a binary string
+const RANDOM_FN: &str = "__ratchet_crypto_random";
+
+/// Most bytes one `crypto.getRandomValues` call may fill
+pub const MAX_RANDOM_BYTES: usize = 65536;
+
+/// Register the `crypto` object in the JavaScript context
+///
+/// Must run after [`crate::register_encoding`], which defines `TextEncoder`.
+pub fn register_crypto(context: &mut Context) -> Result<(), JsExecutionError> {
+    let natives: [(&str, usize, NativeFunction); 3] = [
+        (DIGEST_FN, 2, NativeFunction::from_fn_ptr(digest)),
+        (HMAC_FN, 3, NativeFunction::from_fn_ptr(hmac)),
+        (RANDOM_FN, 1, NativeFunction::from_fn_ptr(random_bytes)),
+    ];
+    for (name, length, function) in natives {
+        context
+            .register_global_callable(JsString::from(name), length, function)
+            .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register {}: {}", name, e)))?;
+    }
+
+    context
+        .eval(Source::from_bytes(CRYPTO_SCRIPT))
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register crypto API: {}", e)))?;
+
+    Ok(())
+}
+
+/// `__ratchet_crypto_digest(algorithm, data)`
+fn digest(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let algorithm = string_arg(args, 0, context)?;
+    let data = binary_arg(args, 1, context)?;
+
+    let hash = match algorithm.as_str() {
+        "SHA-256" => Sha256::digest(&data).to_vec(),
+        "SHA-384" => Sha384::digest(&data).to_vec(),
+        "SHA-512" => Sha512::digest(&data).to_vec(),
+        other => return Err(unsupported_algorithm(other)),
+    };
+    Ok(binary_string(&hash))
+}
+
+/// `__ratchet_crypto_hmac(algorithm, key, data)`
+fn hmac(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let algorithm = string_arg(args, 0, context)?;
+    let key = binary_arg(args, 1, context)?;
+    let data = binary_arg(args, 2, context)?;
+
+    let signature = match algorithm.as_str() {
+        "SHA-256" => hmac_with::<Hmac<Sha256>>(&key, &data)?,
+        "SHA-384" => hmac_with::<Hmac<Sha384>>(&key, &data)?,
+        "SHA-512" => hmac_with::<Hmac<Sha512>>(&key, &data)?,
+        other => return Err(unsupported_algorithm(other)),
+    };
+    Ok(binary_string(&signature))
+}
+
+fn hmac_with<M: Mac + hmac::digest::KeyInit>(key: &[u8], data: &[u8]) -> JsResult<Vec<u8>> {
+    let mut mac = <M as Mac>::new_from_slice(key)
+        .map_err(|e| JsNativeError::typ().with_message(format!("Invalid HMAC key: {}", e)))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// `__ratchet_crypto_random(length)`
+fn random_bytes(_this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let length = match args.first() {
+        Some(value) => value.to_number(context)?,
+        None => 0.0,
+    };
+    if !(0.0..=MAX_RANDOM_BYTES as f64).contains(&length) || length.fract() != 0.0 {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "Random byte count must be an integer from 0 to {}",
+                MAX_RANDOM_BYTES
+            ))
+            .into());
+    }
+
+    let mut bytes = vec![0u8; length as usize];
+    getrandom::fill(&mut bytes)
+        .map_err(|e| JsNativeError::error().with_message(format!("Secure random source unavailable: {}", e)))?;
+    Ok(binary_string(&bytes))
+}
+
+fn unsupported_algorithm(algorithm: &str) -> boa_engine::JsError {
+    JsNativeError::typ()
+        .with_message(format!("Unsupported algorithm: {}", algorithm))
+        .into()
+}
+
+fn string_arg(args: &[JsValue], index: usize, context: &mut Context) -> JsResult<String> {
+    match args.get(index) {
+        Some(value) => Ok(value.to_string(context)?.to_std_string_escaped()),
+        None => Ok(String::new()),
+    }
+}
+
+/// Bytes of a binary string argument, one byte per character
+fn binary_arg(args: &[JsValue], index: usize, context: &mut Context) -> JsResult<Vec<u8>> {
+    string_arg(args, index, context)?
+        .chars()
+        .map(|c| {
+            u8::try_from(u32::from(c)).map_err(|_| {
+                JsNativeError::typ()
+                    .with_message("Binary string contains a non-byte character")
+                    .into()
+            })
+        })
+        .collect()
+}
+
+/// Binary string holding `bytes`, one character per byte
+fn binary_string(bytes: &[u8]) -> JsValue {
+    let text: String = bytes.iter().map(|&byte| char::from(byte)).collect();
+    JsValue::from(JsString::from(text.as_str()))
+}
+
+const CRYPTO_SCRIPT: &str = r#"
+(function(global) {
+    var HASHES = ['SHA-256', 'SHA-384', 'SHA-512'];
+    var CHUNK = 8192;
+    var MAX_RANDOM_BYTES = 65536;
+    var keyMaterial = new WeakMap();
+
+    function algorithmName(algorithm) {
+        var name = typeof algorithm === 'string' ? algorithm : (algorithm && algorithm.name);
+        return String(name).toUpperCase();
+    }
+
+    function hashName(algorithm) {
+        var name = algorithmName(algorithm);
+        if (HASHES.indexOf(name) < 0) {
+            throw new DataError('Unsupported hash algorithm: ' + (typeof algorithm === 'string' ? algorithm : name));
+        }
+        return name;
+    }
+
+    function toBytes(data, what) {
+        if (typeof data === 'string') {
+            return new TextEncoder().encode(data);
+        }
+        if (data instanceof ArrayBuffer) {
+            return new Uint8Array(data);
+        }
+        if (ArrayBuffer.isView(data)) {
+            return new Uint8Array(data.buffer, data.byteOffset, data.byteLength);
+        }
+        throw new DataError(what + ' must be a string, an ArrayBuffer or a typed array');
+    }
+
+    function toBinary(data, what) {
+        var bytes = toBytes(data, what);
+        var parts = [];
+        for (var i = 0; i < bytes.length; i += CHUNK) {
+            parts.push(String.fromCharCode.apply(null, bytes.subarray(i, i + CHUNK)));
+        }
+        return parts.join('');
+    }
+
+    function fromBinary(binary) {
+        var bytes = new Uint8Array(binary.length);
+        for (var i = 0; i < binary.length; i++) {
+            bytes[i] = binary.charCodeAt(i);
+        }
+        return bytes;
+    }
+
+    // Run `operation` as a promise, so invalid input rejects rather than throws
+    function settle(operation) {
+        return new Promise(function(resolve) {
+            resolve(operation());
+        });
+    }
+
+    function CryptoKey(hash, length, extractable, usages) {
+        this.type = 'secret';
+        this.algorithm = { name: 'HMAC', hash: { name: hash }, length: length };
+        this.extractable = extractable;
+        this.usages = usages;
+    }
+
+    function hmacKey(key, usage) {
+        if (!(key instanceof CryptoKey) || !keyMaterial.has(key)) {
+            throw new DataError('Key must be a CryptoKey created by crypto.subtle.importKey');
+        }
+        if (key.usages.indexOf(usage) < 0) {
+            throw new DataError('Key does not allow ' + usage);
+        }
+        return keyMaterial.get(key);
+    }
+
+    function checkHmac(algorithm) {
+        if (algorithmName(algorithm) !== 'HMAC') {
+            throw new DataError('Unsupported signing algorithm: ' + algorithmName(algorithm));
+        }
+    }
+
+    var subtle = {
+        digest: function(algorithm, data) {
+            return settle(function() {
+                return fromBinary(__ratchet_crypto_digest(hashName(algorithm), toBinary(data, 'Data'))).buffer;
+            });
+        },
+
+        importKey: function(format, keyData, algorithm, extractable, usages) {
+            return settle(function() {
+                if (format !== 'raw') {
+                    throw new DataError('Unsupported key format: ' + format);
+                }
+                checkHmac(algorithm);
+                var hash = hashName(algorithm.hash);
+                var binary = toBinary(keyData, 'Key data');
+                var key = new CryptoKey(hash, binary.length * 8, !!extractable, Array.from(usages || []));
+                keyMaterial.set(key, { hash: hash, binary: binary });
+                return key;
+            });
+        },
+
+        exportKey: function(format, key) {
+            return settle(function() {
+                if (format !== 'raw') {
+                    throw new DataError('Unsupported key format: ' + format);
+                }
+                if (!(key instanceof CryptoKey) || !keyMaterial.has(key)) {
+                    throw new DataError('Key must be a CryptoKey created by crypto.subtle.importKey');
+                }
+                if (!key.extractable) {
+                    throw new DataError('Key is not extractable');
+                }
+                return fromBinary(keyMaterial.get(key).binary).buffer;
+            });
+        },
+
+        sign: function(algorithm, key, data) {
+            return settle(function() {
+                checkHmac(algorithm);
+                var material = hmacKey(key, 'sign');
+                return fromBinary(__ratchet_crypto_hmac(material.hash, material.binary, toBinary(data, 'Data'))).buffer;
+            });
+        },
+
+        verify: function(algorithm, key, signature, data) {
+            return settle(function() {
+                checkHmac(algorithm);
+                var material = hmacKey(key, 'verify');
+                var expected = __ratchet_crypto_hmac(material.hash, material.binary, toBinary(data, 'Data'));
+                var actual = toBinary(signature, 'Signature');
+                if (actual.length !== expected.length) {
+                    return false;
+                }
+                // Compare every byte so the time taken does not reveal the mismatch
+                var difference = 0;
+                for (var i = 0; i < expected.length; i++) {
+                    difference |= actual.charCodeAt(i) ^ expected.charCodeAt(i);
+                }
+                return difference === 0;
+            });
+        }
+    };
+
+    function getRandomValues(array) {
+        if (!ArrayBuffer.isView(array) || array instanceof DataView
+            || array instanceof Float32Array || array instanceof Float64Array) {
+            throw new DataError('crypto.getRandomValues expects an integer typed array');
+        }
+        if (array.byteLength > MAX_RANDOM_BYTES) {
+            throw new DataError('crypto.getRandomValues cannot fill more than ' + MAX_RANDOM_BYTES + ' bytes');
+        }
+        new Uint8Array(array.buffer, array.byteOffset, array.byteLength)
+            .set(fromBinary(__ratchet_crypto_random(array.byteLength)));
+        return array;
+    }
+
+    function randomUUID() {
+        var bytes = getRandomValues(new Uint8Array(16));
+        bytes[6] = (bytes[6] & 0x0F) | 0x40;
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+        var hex = '';
+        for (var i = 0; i < bytes.length; i++) {
+            hex += (bytes[i] < 0x10 ? '0' : '') + bytes[i].toString(16);
+        }
+        return hex.slice(0, 8) + '-' + hex.slice(8, 12) + '-' + hex.slice(12, 16) + '-'
+            + hex.slice(16, 20) + '-' + hex.slice(20);
+    }
+
+    global.CryptoKey = CryptoKey;
+    global.crypto = {
+        subtle: subtle,
+        getRandomValues: getRandomValues,
+        randomUUID: randomUUID
+    };
+})(globalThis);
+"#;
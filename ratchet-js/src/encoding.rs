@@ -0,0 +1,222 @@
+//! Text and base64 encoding for JavaScript tasks
+//!
+//! Tasks get `TextEncoder` and `TextDecoder` for UTF-8, plus `btoa` and `atob`
+//! for base64 over binary strings. Invalid input, such as malformed UTF-8,
+//! non-Latin1 characters passed to `btoa` or corrupt base64 passed to `atob`,
+//! throws a `DataError` that the task can catch; left uncaught it fails the
+//! execution with `JsErrorType::DataError`.
+//!
+//! Unlike the web platform, `TextDecoder` is fatal by default, so corrupt input is
+//! reported rather than silently replaced. `new TextDecoder("utf-8", { fatal: false })`
+//! decodes malformed sequences to U+FFFD instead.
+
+use boa_engine::{Context, Source};
+
+use crate::JsExecutionError;
+
+/// Register `TextEncoder`, `TextDecoder`, `btoa` and `atob` in the JavaScript context
+///
+/// Must run after [`crate::register_error_types`], which defines `DataError`.
+pub fn register_encoding(context: &mut Context) -> Result<(), JsExecutionError> {
+    context
+        .eval(Source::from_bytes(ENCODING_SCRIPT))
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register encoding API: {}", e)))?;
+
+    Ok(())
+}
+
+const ENCODING_SCRIPT: &str = r#"
+(function(global) {
+    var BASE64 = 'ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/';
+
+    function TextEncoder() {
+        this.encoding = 'utf-8';
+    }
+
+    TextEncoder.prototype.encode = function(input) {
+        var text = input === undefined ? '' : String(input);
+        var bytes = [];
+        for (var i = 0; i < text.length; i++) {
+            var code = text.charCodeAt(i);
+            if (code >= 0xD800 && code <= 0xDBFF && i + 1 < text.length) {
+                var low = text.charCodeAt(i + 1);
+                if (low >= 0xDC00 && low <= 0xDFFF) {
+                    code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                    i++;
+                } else {
+                    code = 0xFFFD;
+                }
+            } else if (code >= 0xD800 && code <= 0xDFFF) {
+                // Lone surrogates have no UTF-8 encoding
+                code = 0xFFFD;
+            }
+
+            if (code < 0x80) {
+                bytes.push(code);
+            } else if (code < 0x800) {
+                bytes.push(0xC0 | (code >> 6), 0x80 | (code & 0x3F));
+            } else if (code < 0x10000) {
+                bytes.push(0xE0 | (code >> 12), 0x80 | ((code >> 6) & 0x3F), 0x80 | (code & 0x3F));
+            } else {
+                bytes.push(
+                    0xF0 | (code >> 18),
+                    0x80 | ((code >> 12) & 0x3F),
+                    0x80 | ((code >> 6) & 0x3F),
+                    0x80 | (code & 0x3F)
+                );
+            }
+        }
+        return new Uint8Array(bytes);
+    };
+
+    function TextDecoder(label, options) {
+        var encoding = label === undefined ? 'utf-8' : String(label).trim().toLowerCase();
+        if (encoding !== 'utf-8' && encoding !== 'utf8' && encoding !== 'unicode-1-1-utf-8') {
+            throw new DataError('Unsupported encoding: ' + label);
+        }
+        this.encoding = 'utf-8';
+        this.fatal = !(options && options.fatal === false);
+        this.ignoreBOM = !!(options && options.ignoreBOM);
+    }
+
+    function toBytes(input) {
+        if (input === undefined) {
+            return new Uint8Array(0);
+        }
+        if (input instanceof ArrayBuffer) {
+            return new Uint8Array(input);
+        }
+        if (ArrayBuffer.isView(input)) {
+            return new Uint8Array(input.buffer, input.byteOffset, input.byteLength);
+        }
+        throw new DataError('TextDecoder.decode expects an ArrayBuffer or a typed array');
+    }
+
+    TextDecoder.prototype.decode = function(input) {
+        var bytes = toBytes(input);
+        var fatal = this.fatal;
+        var parts = [];
+        var i = 0;
+
+        if (!this.ignoreBOM && bytes.length >= 3 && bytes[0] === 0xEF && bytes[1] === 0xBB && bytes[2] === 0xBF) {
+            i = 3;
+        }
+
+        function malformed(offset) {
+            if (fatal) {
+                throw new DataError('Invalid UTF-8 data at byte ' + offset);
+            }
+            parts.push('\uFFFD');
+        }
+
+        while (i < bytes.length) {
+            var lead = bytes[i];
+            if (lead < 0x80) {
+                parts.push(String.fromCharCode(lead));
+                i++;
+                continue;
+            }
+
+            var needed;
+            var code;
+            if (lead >= 0xC2 && lead <= 0xDF) {
+                needed = 1;
+                code = lead & 0x1F;
+            } else if (lead >= 0xE0 && lead <= 0xEF) {
+                needed = 2;
+                code = lead & 0x0F;
+            } else if (lead >= 0xF0 && lead <= 0xF4) {
+                needed = 3;
+                code = lead & 0x07;
+            } else {
+                malformed(i);
+                i++;
+                continue;
+            }
+
+            // Bounds of the first continuation byte exclude overlong forms,
+            // surrogates and code points above U+10FFFF
+            var lower = lead === 0xE0 ? 0xA0 : lead === 0xF0 ? 0x90 : 0x80;
+            var upper = lead === 0xED ? 0x9F : lead === 0xF4 ? 0x8F : 0xBF;
+            var seen = 0;
+            while (seen < needed) {
+                var next = bytes[i + 1 + seen];
+                if (i + 1 + seen >= bytes.length || next < lower || next > upper) {
+                    break;
+                }
+                code = (code << 6) | (next & 0x3F);
+                lower = 0x80;
+                upper = 0xBF;
+                seen++;
+            }
+
+            if (seen < needed) {
+                // The offending byte starts the next sequence
+                malformed(i);
+                i += 1 + seen;
+                continue;
+            }
+
+            parts.push(String.fromCodePoint(code));
+            i += 1 + needed;
+        }
+
+        return parts.join('');
+    };
+
+    function latin1(text, index) {
+        var code = text.charCodeAt(index);
+        if (code > 0xFF) {
+            throw new DataError('btoa: character at index ' + index + ' is outside the Latin1 range');
+        }
+        return code;
+    }
+
+    function btoa(data) {
+        var text = String(data);
+        var parts = [];
+        for (var i = 0; i < text.length; i += 3) {
+            var remaining = text.length - i;
+            var triple = (latin1(text, i) << 16)
+                | (remaining > 1 ? latin1(text, i + 1) << 8 : 0)
+                | (remaining > 2 ? latin1(text, i + 2) : 0);
+            parts.push(
+                BASE64.charAt((triple >> 18) & 0x3F)
+                + BASE64.charAt((triple >> 12) & 0x3F)
+                + (remaining > 1 ? BASE64.charAt((triple >> 6) & 0x3F) : '=')
+                + (remaining > 2 ? BASE64.charAt(triple & 0x3F) : '=')
+            );
+        }
+        return parts.join('');
+    }
+
+    function atob(data) {
+        var text = String(data).replace(/[\t\n\f\r ]/g, '');
+        if (text.length % 4 === 0) {
+            text = text.replace(/==?$/, '');
+        }
+        if (text.length % 4 === 1 || /[^A-Za-z0-9+\/]/.test(text)) {
+            throw new DataError('atob: invalid base64 data');
+        }
+
+        var parts = [];
+        var buffer = 0;
+        var bits = 0;
+        for (var i = 0; i < text.length; i++) {
+            buffer = (buffer << 6) | BASE64.indexOf(text.charAt(i));
+            bits += 6;
+            if (bits >= 8) {
+                bits -= 8;
+                parts.push(String.fromCharCode((buffer >> bits) & 0xFF));
+                buffer &= (1 << bits) - 1;
+            }
+        }
+        return parts.join('');
+    }
+
+    global.TextEncoder = TextEncoder;
+    global.TextDecoder = TextDecoder;
+    global.btoa = btoa;
+    global.atob = atob;
+})(globalThis);
+"#;
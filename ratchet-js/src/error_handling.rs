@@ -1,6 +1,11 @@
-use crate::{JsErrorType, JsExecutionError};
-use boa_engine::{Context as BoaContext, Source};
+use crate::limits::{CALL_STACK_EXCEEDED, RESOURCE_LIMIT_EXCEEDED};
+use crate::{JsErrorType, JsExecutionError, JsStackFrame};
+use boa_engine::{
+    native_function::NativeFunction, object::builtins::JsArray, property::PropertyKey, Context as BoaContext, JsError,
+    JsResult, JsString, JsValue, Source,
+};
 use regex;
+use tracing::warn;
 
 /// Configuration for JavaScript error types
 #[derive(Debug, Clone)]
@@ -62,9 +67,17 @@ pub const JS_ERROR_CONFIGS: &[JsErrorConfig] = &[
         default_message: "Data error",
         has_status: false,
     },
+    JsErrorConfig {
+        name: "BodyTooLargeError",
+        default_message: "Body too large",
+        has_status: false,
+    },
 ];
 
 /// Generate JavaScript error class definition for a single error type
+///
+/// Instances are genuine `Error` objects, so an uncaught one is reported as
+/// `Name: message` and parsed back into its [`JsErrorType`].
 pub fn generate_error_class(error_config: &JsErrorConfig) -> String {
     if error_config.has_status {
         // Special case for HttpError which takes status and message
@@ -72,10 +85,12 @@ pub fn generate_error_class(error_config: &JsErrorConfig) -> String {
             r#"
         // {name}
         function {name}(status, message) {{
-            this.name = "{name}";
-            this.status = status;
-            this.message = message || "{default_message}";
-            this.stack = (new Error()).stack;
+            var error = new Error(message || "{default_message}");
+            error.name = "{name}";
+            error.status = status;
+            Object.setPrototypeOf(error, {name}.prototype);
+            __ratchet_attach_stack(error, 1, true);
+            return error;
         }}
         {name}.prototype = Object.create(Error.prototype);
         {name}.prototype.constructor = {name};"#,
@@ -88,9 +103,11 @@ pub fn generate_error_class(error_config: &JsErrorConfig) -> String {
             r#"
         // {name}
         function {name}(message) {{
-            this.name = "{name}";
-            this.message = message || "{default_message}";
-            this.stack = (new Error()).stack;
+            var error = new Error(message || "{default_message}");
+            error.name = "{name}";
+            Object.setPrototypeOf(error, {name}.prototype);
+            __ratchet_attach_stack(error, 1, true);
+            return error;
         }}
         {name}.prototype = Object.create(Error.prototype);
         {name}.prototype.constructor = {name};"#,
@@ -109,8 +126,74 @@ pub fn generate_all_error_classes() -> String {
         .join("\n")
 }
 
+/// Global JavaScript function returning the names of the functions on the call stack
+const STACK_TRACE_FN: &str = "__ratchet_stack_trace";
+
+/// Records a V8-style `stack` on every error constructed by task code
+///
+/// The built-in error constructors are wrapped so that `new Error(...)`, and
+/// subclasses calling it through `super`, capture the call stack. Errors raised
+/// by the engine itself, such as calling `undefined`, carry no stack. A stack the
+/// engine already provides is left alone.
+const STACK_CAPTURE_SCRIPT: &str = r#"
+var __ratchet_attach_stack = (function() {
+    var captured = new WeakSet();
+
+    // Record the stack on `error`, leaving out the innermost `skip` callers;
+    // `replace` recaptures a stack this function recorded earlier
+    function attach(error, skip, replace) {
+        if (error === null || typeof error !== 'object') {
+            return error;
+        }
+        if (Object.prototype.hasOwnProperty.call(error, 'stack') && !(replace && captured.has(error))) {
+            return error;
+        }
+        var frames = __ratchet_stack_trace(skip + 1).map(function(name) {
+            return '\n    at ' + (name || '<anonymous>');
+        });
+        Object.defineProperty(error, 'stack', {
+            value: String(error) + frames.join(''),
+            writable: true,
+            configurable: true,
+            enumerable: false
+        });
+        captured.add(error);
+        return error;
+    }
+
+    var constructors = ['Error', 'TypeError', 'RangeError', 'ReferenceError', 'SyntaxError', 'EvalError', 'URIError'];
+    constructors.forEach(function(name) {
+        globalThis[name] = new Proxy(globalThis[name], {
+            construct: function(target, args, newTarget) {
+                return attach(Reflect.construct(target, args, newTarget), 1, false);
+            },
+            apply: function(target, thisArg, args) {
+                return attach(Reflect.apply(target, thisArg, args), 1, false);
+            }
+        });
+    });
+
+    return attach;
+})();
+"#;
+
 /// Register custom error types in the JavaScript context
+///
+/// Errors constructed by task code from then on record their call stack, which
+/// [`convert_js_error`] reports alongside the error.
 pub fn register_error_types(context: &mut BoaContext) -> Result<(), JsExecutionError> {
+    context
+        .register_global_callable(
+            JsString::from(STACK_TRACE_FN),
+            1,
+            NativeFunction::from_fn_ptr(stack_trace),
+        )
+        .map_err(|e| JsExecutionError::CompileError(format!("Failed to register stack traces: {}", e)))?;
+
+    context
+        .eval(Source::from_bytes(STACK_CAPTURE_SCRIPT))
+        .map_err(|e| JsExecutionError::CompileError(format!("Failed to register stack traces: {}", e)))?;
+
     let error_classes = generate_all_error_classes();
 
     context
@@ -120,6 +203,138 @@ pub fn register_error_types(context: &mut BoaContext) -> Result<(), JsExecutionE
     Ok(())
 }
 
+/// `__ratchet_stack_trace(skip)`: names of the functions on the call stack,
+/// innermost first, leaving out the innermost `skip`
+fn stack_trace(_this: &JsValue, args: &[JsValue], context: &mut BoaContext) -> JsResult<JsValue> {
+    let skip = match args.first() {
+        Some(value) => value.to_u32(context)? as usize,
+        None => 0,
+    };
+    let names: Vec<JsValue> = context
+        .stack_trace()
+        .skip(skip)
+        .map(|frame| JsValue::from(frame.code_block().name().clone()))
+        .collect();
+
+    Ok(JsArray::from_iter(names, context).into())
+}
+
+/// Report an error thrown by the engine for an exceeded runtime limit
+///
+/// Exhausting the loop budget surfaces as `RuntimeError("resource limit exceeded")`,
+/// recursing past the call depth or stack limits as
+/// `RuntimeError("maximum call stack exceeded")`. Other errors yield `None`.
+pub fn resource_limit_error(error: &JsError) -> Option<JsExecutionError> {
+    let native = error.as_native().filter(|native| native.is_runtime_limit())?;
+    warn!("Task exceeded a resource limit: {}", error);
+
+    let message = if native.message().contains("loop iteration") {
+        RESOURCE_LIMIT_EXCEEDED
+    } else {
+        CALL_STACK_EXCEEDED
+    };
+    Some(JsExecutionError::RuntimeError(message.to_string()))
+}
+
+/// Convert an error thrown by task code into an execution error
+///
+/// Exceeded runtime limits are reported by [`resource_limit_error`]. Anything
+/// else becomes a [`JsExecutionError::JsError`] carrying the error's type and,
+/// when one was recorded, its stack trace.
+pub fn convert_js_error(error: &JsError, context: &mut BoaContext) -> JsExecutionError {
+    if let Some(error) = resource_limit_error(error) {
+        return error;
+    }
+
+    let stack = error
+        .as_opaque()
+        .map(|value| thrown_stack(value, context))
+        .unwrap_or_default();
+    thrown_error(error.to_string(), stack)
+}
+
+/// Convert a value thrown or rejected by task code into an execution error
+pub fn convert_thrown_value(value: &JsValue, context: &mut BoaContext) -> JsExecutionError {
+    let message = match value.to_string(context) {
+        Ok(message) => message.to_std_string_escaped(),
+        Err(e) => e.to_string(),
+    };
+    let stack = thrown_stack(value, context);
+    thrown_error(message, stack)
+}
+
+fn thrown_error(message: String, stack: Vec<JsStackFrame>) -> JsExecutionError {
+    JsExecutionError::JsError {
+        error_type: parse_js_error(&message),
+        message,
+        stack,
+    }
+}
+
+/// Stack trace recorded on a thrown value, empty when it has none
+fn thrown_stack(value: &JsValue, context: &mut BoaContext) -> Vec<JsStackFrame> {
+    let Some(object) = value.as_object() else {
+        return Vec::new();
+    };
+    match object.get(PropertyKey::from(JsString::from("stack")), context) {
+        Ok(stack) => stack
+            .as_string()
+            .map(|stack| parse_js_stack(&stack.to_std_string_escaped()))
+            .unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Parse a V8-style stack trace into frames, innermost first
+///
+/// Each `at` line becomes a frame; other lines, such as the leading
+/// `Name: message`, are skipped. Locations are read from frames of the form
+/// `at function (file:line:column)` and `at file:line:column`.
+pub fn parse_js_stack(stack: &str) -> Vec<JsStackFrame> {
+    stack
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("at "))
+        .map(parse_stack_frame)
+        .collect()
+}
+
+fn parse_stack_frame(frame: &str) -> JsStackFrame {
+    let frame = frame.trim();
+    let located = frame
+        .strip_suffix(')')
+        .and_then(|frame| frame.split_once(" ("))
+        .and_then(|(function, location)| Some((function, parse_location(location)?)))
+        .or_else(|| Some(("<anonymous>", parse_location(frame)?)));
+
+    match located {
+        Some((function, (file, line, column))) => JsStackFrame {
+            function: function.to_string(),
+            file: Some(file.to_string()),
+            line: Some(line),
+            column,
+        },
+        None => JsStackFrame {
+            function: frame.to_string(),
+            file: None,
+            line: None,
+            column: None,
+        },
+    }
+}
+
+/// File, line and column of a `file:line[:column]` location
+fn parse_location(location: &str) -> Option<(&str, u32, Option<u32>)> {
+    let (rest, last) = location.rsplit_once(':')?;
+    let last = last.parse().ok()?;
+    match rest.rsplit_once(':') {
+        Some((file, line)) => match line.parse() {
+            Ok(line) => Some((file, line, Some(last))),
+            Err(_) => Some((rest, last, None)),
+        },
+        None => Some((rest, last, None)),
+    }
+}
+
 /// Parse JavaScript error and convert to JsErrorType
 pub fn parse_js_error(error_message: &str) -> JsErrorType {
     // Try to extract error type and message from the error string
@@ -146,6 +361,7 @@ pub fn parse_js_error(error_message: &str) -> JsErrorType {
             "ServiceUnavailableError" => JsErrorType::ServiceUnavailableError(message),
             "TimeoutError" => JsErrorType::TimeoutError(message),
             "DataError" => JsErrorType::DataError(message),
+            "BodyTooLargeError" => JsErrorType::BodyTooLargeError(message),
             _ => JsErrorType::UnknownError(message),
         }
     } else {
@@ -1,14 +1,21 @@
 //! Simplified JavaScript execution without Task dependencies
 
 use crate::{
+    attachments::{collect_attachments, register_attachments, TaskAttachment},
+    console::register_console,
     conversion::{convert_js_result_to_json, prepare_input_argument},
-    error_handling::{parse_js_error, register_error_types},
-    JsExecutionError,
+    encoding::register_encoding,
+    error_handling::{convert_js_error, register_error_types},
+    limits::ResourceMonitor,
+    modules::{evaluate_entry, TaskModuleLoader},
+    timers::{register_timers, settle, TimerSession},
+    ExecutionOptions, JsExecutionError,
 };
-use boa_engine::{property::PropertyKey, Context as BoaContext, JsString, Script, Source};
+use boa_engine::{property::PropertyKey, Context as BoaContext, JsString, JsValue, Module, Script, Source};
 use ratchet_core::validation::{parse_schema, validate_json};
 use serde_json::Value as JsonValue;
 use std::path::Path;
+use std::rc::Rc;
 use tracing::{debug, info};
 
 /// Call a JavaScript function with input data
@@ -18,25 +25,27 @@ pub async fn call_js_function(
     input_data: &JsonValue,
     http_manager: &impl ratchet_http::HttpClient,
 ) -> Result<JsonValue, JsExecutionError> {
-    call_js_function_with_code(context, script, None, input_data, http_manager).await
+    let monitor = ResourceMonitor::default();
+    call_js_function_with_code(context, script, None, input_data, http_manager, None, &monitor).await
 }
 
 /// Internal function to call JavaScript with access to original code for fallback parsing
+///
+/// With a timer session the result is settled first, firing the task's timers.
 async fn call_js_function_with_code(
     context: &mut BoaContext,
     script: &Script,
     js_code: Option<&str>,
     input_data: &JsonValue,
     http_manager: &impl ratchet_http::HttpClient,
+    timers: Option<&TimerSession>,
+    monitor: &ResourceMonitor,
 ) -> Result<JsonValue, JsExecutionError> {
     // Prepare input argument
     let input_arg = prepare_input_argument(context, input_data)?;
 
     // Execute the script first
-    let script_result = script.evaluate(context).map_err(|e| {
-        let parsed_error = parse_js_error(&e.to_string());
-        JsExecutionError::TypedJsError(parsed_error)
-    })?;
+    let script_result = script.evaluate(context).map_err(|e| convert_js_error(&e, context))?;
 
     // Try to get the main function from the global context first
     let main_function_result = context
@@ -52,10 +61,7 @@ async fn call_js_function_with_code(
                 .as_callable()
                 .ok_or_else(|| JsExecutionError::RuntimeError("main is not a function".to_string()))?
                 .call(&boa_engine::JsValue::undefined(), &[input_arg.clone()], context)
-                .map_err(|e| {
-                    let parsed_error = parse_js_error(&e.to_string());
-                    JsExecutionError::TypedJsError(parsed_error)
-                })?;
+                .map_err(|e| convert_js_error(&e, context))?;
 
             // After function execution, check for HTTP fetch calls
             if let Some((url, params, body)) = crate::http_integration::check_fetch_call(context)? {
@@ -70,11 +76,11 @@ async fn call_js_function_with_code(
                     body,
                 )
                 .await?;
-                convert_js_result_to_json(context, js_result)?
+                js_result
             } else {
                 // No fetch calls detected, use the initial result
                 debug!("No fetch calls detected, using initial result");
-                convert_js_result_to_json(context, initial_result)?
+                initial_result
             }
         } else {
             // main exists but is not callable, fall through to anonymous function handling
@@ -87,7 +93,12 @@ async fn call_js_function_with_code(
         handle_anonymous_function_with_http(context, script_result, input_arg, js_code, http_manager).await?
     };
 
-    Ok(result)
+    let result = match timers {
+        Some(session) => settle(context, result, session, monitor).await?,
+        None => result,
+    };
+
+    convert_js_result_to_json(context, result)
 }
 
 /// Handle anonymous function execution with HTTP support
@@ -97,7 +108,7 @@ async fn handle_anonymous_function_with_http(
     input_arg: boa_engine::JsValue,
     js_code: Option<&str>,
     http_manager: &impl ratchet_http::HttpClient,
-) -> Result<JsonValue, JsExecutionError> {
+) -> Result<boa_engine::JsValue, JsExecutionError> {
     debug!(
         "Handling anonymous function with HTTP support. Script result type: {:?}, is_callable: {}, is_undefined: {}",
         script_result.type_of(),
@@ -112,10 +123,7 @@ async fn handle_anonymous_function_with_http(
             .as_callable()
             .ok_or_else(|| JsExecutionError::RuntimeError("Script result is not callable".to_string()))?
             .call(&boa_engine::JsValue::undefined(), &[input_arg.clone()], context)
-            .map_err(|e| {
-                let parsed_error = parse_js_error(&e.to_string());
-                JsExecutionError::TypedJsError(parsed_error)
-            })?;
+            .map_err(|e| convert_js_error(&e, context))?;
 
         // After function execution, check for HTTP fetch calls
         if let Some((url, params, body)) = crate::http_integration::check_fetch_call(context)? {
@@ -133,15 +141,15 @@ async fn handle_anonymous_function_with_http(
                 body,
             )
             .await?;
-            convert_js_result_to_json(context, js_result)
+            Ok(js_result)
         } else {
             // No fetch calls detected, use the initial result
             debug!("No fetch calls detected in anonymous function, using initial result");
-            convert_js_result_to_json(context, initial_result)
+            Ok(initial_result)
         }
     } else if !script_result.is_undefined() && !script_result.is_null() {
         debug!("Using script result directly as value");
-        convert_js_result_to_json(context, script_result)
+        Ok(script_result)
     } else {
         // The script didn't return a function or value, try function expression handling
         handle_function_expression_with_http(context, input_arg, js_code, http_manager).await
@@ -154,7 +162,7 @@ async fn handle_function_expression_with_http(
     input_arg: boa_engine::JsValue,
     js_code: Option<&str>,
     http_manager: &impl ratchet_http::HttpClient,
-) -> Result<JsonValue, JsExecutionError> {
+) -> Result<boa_engine::JsValue, JsExecutionError> {
     debug!("Handling function expression with HTTP support");
 
     if let Some(code) = js_code {
@@ -164,10 +172,7 @@ async fn handle_function_expression_with_http(
         let wrapped_source = Source::from_bytes(&wrapped_code);
         match Script::parse(wrapped_source, None, context) {
             Ok(wrapped_script) => {
-                let wrapped_result = wrapped_script.evaluate(context).map_err(|e| {
-                    let parsed_error = parse_js_error(&e.to_string());
-                    JsExecutionError::TypedJsError(parsed_error)
-                })?;
+                let wrapped_result = wrapped_script.evaluate(context).map_err(|e| convert_js_error(&e, context))?;
 
                 if wrapped_result.is_callable() {
                     debug!("Successfully extracted function from expression");
@@ -176,10 +181,7 @@ async fn handle_function_expression_with_http(
                         .as_callable()
                         .ok_or_else(|| JsExecutionError::RuntimeError("Wrapped result is not callable".to_string()))?
                         .call(&boa_engine::JsValue::undefined(), &[input_arg.clone()], context)
-                        .map_err(|e| {
-                            let parsed_error = parse_js_error(&e.to_string());
-                            JsExecutionError::TypedJsError(parsed_error)
-                        })?;
+                        .map_err(|e| convert_js_error(&e, context))?;
 
                     // After function execution, check for HTTP fetch calls
                     if let Some((url, params, body)) = crate::http_integration::check_fetch_call(context)? {
@@ -197,15 +199,15 @@ async fn handle_function_expression_with_http(
                             body,
                         )
                         .await?;
-                        convert_js_result_to_json(context, js_result)
+                        Ok(js_result)
                     } else {
                         // No fetch calls detected, use the initial result
                         debug!("No fetch calls detected in function expression, using initial result");
-                        convert_js_result_to_json(context, initial_result)
+                        Ok(initial_result)
                     }
                 } else {
                     debug!("Wrapped result is not callable");
-                    convert_js_result_to_json(context, wrapped_result)
+                    Ok(wrapped_result)
                 }
             }
             Err(e) => {
@@ -245,10 +247,7 @@ fn _handle_anonymous_function(
             .as_callable()
             .ok_or_else(|| JsExecutionError::RuntimeError("Script result is not callable".to_string()))?
             .call(&boa_engine::JsValue::undefined(), &[input_arg], context)
-            .map_err(|e| {
-                let parsed_error = parse_js_error(&e.to_string());
-                JsExecutionError::TypedJsError(parsed_error)
-            })?;
+            .map_err(|e| convert_js_error(&e, context))?;
 
         convert_js_result_to_json(context, result)
     } else if !script_result.is_undefined() && !script_result.is_null() {
@@ -267,10 +266,7 @@ fn _handle_anonymous_function(
             let wrapped_source = Source::from_bytes(&wrapped_code);
             match Script::parse(wrapped_source, None, context) {
                 Ok(wrapped_script) => {
-                    let wrapped_result = wrapped_script.evaluate(context).map_err(|e| {
-                        let parsed_error = parse_js_error(&e.to_string());
-                        JsExecutionError::TypedJsError(parsed_error)
-                    })?;
+                    let wrapped_result = wrapped_script.evaluate(context).map_err(|e| convert_js_error(&e, context))?;
                     
                     if wrapped_result.is_callable() {
                         debug!("Successfully extracted function from expression");
@@ -278,10 +274,7 @@ fn _handle_anonymous_function(
                             .as_callable()
                             .ok_or_else(|| JsExecutionError::RuntimeError("Wrapped result is not callable".to_string()))?
                             .call(&boa_engine::JsValue::undefined(), &[input_arg], context)
-                            .map_err(|e| {
-                                let parsed_error = parse_js_error(&e.to_string());
-                                JsExecutionError::TypedJsError(parsed_error)
-                            })?;
+                            .map_err(|e| convert_js_error(&e, context))?;
 
                         convert_js_result_to_json(context, result)
                     } else {
@@ -311,24 +304,37 @@ pub async fn call_js_function_with_context(
     input_data: &JsonValue,
     http_manager: &impl ratchet_http::HttpClient,
     execution_context: &crate::ExecutionContext,
+) -> Result<JsonValue, JsExecutionError> {
+    let monitor = ResourceMonitor::default();
+    call_js_function_with_context_and_timers(
+        context,
+        script,
+        input_data,
+        http_manager,
+        execution_context,
+        None,
+        &monitor,
+    )
+    .await
+}
+
+/// Call a JavaScript function with input data and execution context, settling
+/// the result through the task's timers when a timer session is given
+async fn call_js_function_with_context_and_timers(
+    context: &mut BoaContext,
+    script: &Script,
+    input_data: &JsonValue,
+    http_manager: &impl ratchet_http::HttpClient,
+    execution_context: &crate::ExecutionContext,
+    timers: Option<&TimerSession>,
+    monitor: &ResourceMonitor,
 ) -> Result<JsonValue, JsExecutionError> {
     // Prepare input and context arguments
     let input_arg = prepare_input_argument(context, input_data)?;
-    let context_arg = prepare_input_argument(
-        context,
-        &serde_json::json!({
-            "executionId": execution_context.execution_id,
-            "taskId": execution_context.task_id,
-            "taskVersion": execution_context.task_version,
-            "jobId": execution_context.job_id
-        }),
-    )?;
+    let context_arg = prepare_context_argument(context, execution_context)?;
 
     // Execute the script first to define functions
-    script.evaluate(context).map_err(|e| {
-        let parsed_error = parse_js_error(&e.to_string());
-        JsExecutionError::TypedJsError(parsed_error)
-    })?;
+    script.evaluate(context).map_err(|e| convert_js_error(&e, context))?;
 
     // Get the main function from the global context
     let main_function = context
@@ -345,27 +351,80 @@ pub async fn call_js_function_with_context(
             &input_arg,
             &context_arg,
             http_manager,
+            execution_context.fetch_access.as_ref(),
             url,
             params,
             body,
         )
         .await?;
-        convert_js_result_to_json(context, js_result)?
+        js_result
     } else {
         // Call the main function with both input and context
-        let result = main_function
+        main_function
             .as_callable()
             .ok_or_else(|| JsExecutionError::RuntimeError("main is not a function".to_string()))?
             .call(&boa_engine::JsValue::undefined(), &[input_arg, context_arg], context)
-            .map_err(|e| {
-                let parsed_error = parse_js_error(&e.to_string());
-                JsExecutionError::TypedJsError(parsed_error)
-            })?;
+            .map_err(|e| convert_js_error(&e, context))?
+    };
 
-        convert_js_result_to_json(context, result)?
+    let result = match timers {
+        Some(session) => settle(context, result, session, monitor).await?,
+        None => result,
     };
 
-    Ok(result)
+    convert_js_result_to_json(context, result)
+}
+
+/// Evaluate a task's entry module and call the function it exports, settling
+/// the result through the task's timers
+async fn call_js_module(
+    context: &mut BoaContext,
+    module: &Module,
+    input_data: &JsonValue,
+    execution_context: Option<&crate::ExecutionContext>,
+    timers: &TimerSession,
+    monitor: &ResourceMonitor,
+) -> Result<JsonValue, JsExecutionError> {
+    let main_function = evaluate_entry(module, context)?;
+
+    let mut args = vec![prepare_input_argument(context, input_data)?];
+    if let Some(exec_ctx) = execution_context {
+        args.push(prepare_context_argument(context, exec_ctx)?);
+    }
+
+    let result = main_function
+        .as_callable()
+        .ok_or_else(|| JsExecutionError::RuntimeError("main is not a function".to_string()))?
+        .call(&JsValue::undefined(), &args, context)
+        .map_err(|e| convert_js_error(&e, context))?;
+    let result = settle(context, result, timers, monitor).await?;
+
+    convert_js_result_to_json(context, result)
+}
+
+/// Second argument of the task function, describing the execution
+fn prepare_context_argument(
+    context: &mut BoaContext,
+    execution_context: &crate::ExecutionContext,
+) -> Result<JsValue, JsExecutionError> {
+    prepare_input_argument(
+        context,
+        &serde_json::json!({
+            "executionId": execution_context.execution_id,
+            "taskId": execution_context.task_id,
+            "taskVersion": execution_context.task_version,
+            "jobId": execution_context.job_id
+        }),
+    )
+}
+
+/// Task code compiled for execution
+enum CompiledTask {
+    /// Classic script defining a global `main` function
+    Script(Script),
+
+    /// Entry module of a task split into ES modules
+    Module(Module),
 }
 
 /// Execute JavaScript file with input data
@@ -415,6 +474,57 @@ pub async fn execute_js_with_content(
     http_manager: &impl ratchet_http::HttpClient,
     execution_context: Option<&crate::ExecutionContext>,
 ) -> Result<JsonValue, JsExecutionError> {
+    let (result, attachments) = execute_js_with_attachments(
+        js_code,
+        input_data,
+        input_schema,
+        output_schema,
+        http_manager,
+        execution_context,
+    )
+    .await?;
+
+    if !attachments.is_empty() {
+        debug!("Discarding {} task attachment(s)", attachments.len());
+    }
+
+    Ok(result)
+}
+
+/// Execute JavaScript code directly, returning the output together with any
+/// attachments the task created through `ratchet.attach`
+pub async fn execute_js_with_attachments(
+    js_code: &str,
+    input_data: JsonValue,
+    input_schema: Option<&JsonValue>,
+    output_schema: Option<&JsonValue>,
+    http_manager: &impl ratchet_http::HttpClient,
+    execution_context: Option<&crate::ExecutionContext>,
+) -> Result<(JsonValue, Vec<TaskAttachment>), JsExecutionError> {
+    execute_js_with_options(
+        js_code,
+        input_data,
+        input_schema,
+        output_schema,
+        http_manager,
+        execution_context,
+        &ExecutionOptions::default(),
+    )
+    .await
+}
+
+/// Execute JavaScript code directly like [`execute_js_with_attachments`], driving
+/// the task's timers, capturing its console output and enforcing its resource
+/// limits as set out in `options`
+pub async fn execute_js_with_options(
+    js_code: &str,
+    input_data: JsonValue,
+    input_schema: Option<&JsonValue>,
+    output_schema: Option<&JsonValue>,
+    http_manager: &impl ratchet_http::HttpClient,
+    execution_context: Option<&crate::ExecutionContext>,
+    options: &ExecutionOptions,
+) -> Result<(JsonValue, Vec<TaskAttachment>), JsExecutionError> {
     info!("Executing JavaScript code directly");
     debug!(
         "Input data: {}",
@@ -428,31 +538,114 @@ pub async fn execute_js_with_content(
     }
 
     debug!("Creating JavaScript execution context");
-    // Create a new Boa context for JavaScript execution
-    let mut context = BoaContext::default();
+    // Create a new Boa context for JavaScript execution, serving imports from the
+    // task's modules when it has any
+    let module_loader = options
+        .modules
+        .clone()
+        .map(|modules| Rc::new(TaskModuleLoader::new(modules)));
+    let mut context = match &module_loader {
+        Some(loader) => BoaContext::builder()
+            .module_loader(loader.clone())
+            .build()
+            .map_err(|e| JsExecutionError::ContextError(format!("Failed to create module context: {}", e)))?,
+        None => BoaContext::default(),
+    };
 
     debug!("Registering error types");
     // Register custom error types
     register_error_types(&mut context)?;
 
+    debug!("Registering encoding API");
+    // Register TextEncoder, TextDecoder, btoa and atob
+    register_encoding(&mut context)?;
+
+    debug!("Registering crypto API");
+    // Register crypto.subtle digests and HMAC, getRandomValues and randomUUID
+    #[cfg(feature = "crypto")]
+    crate::crypto::register_crypto(&mut context)?;
+
     debug!("Registering fetch API");
     // Register the fetch API
     #[cfg(feature = "http")]
     crate::fetch::register_fetch(&mut context)
         .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register fetch API: {}", e)))?;
 
+    debug!("Registering attachments API");
+    // Register the ratchet.attach API
+    register_attachments(&mut context)
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register attachments API: {}", e)))?;
+
+    debug!("Registering timers API");
+    // Register setTimeout, setInterval and friends, starting the execution's timer clock
+    let timer_session = register_timers(&mut context, &options.timers)?;
+
+    debug!("Registering console API");
+    // Capture console output, tagged with the execution it belongs to
+    let console = match execution_context {
+        Some(exec_ctx) => options
+            .console
+            .clone()
+            .with_execution(&exec_ctx.task_id, &exec_ctx.execution_id),
+        None => options.console.clone(),
+    };
+    register_console(&mut context, console)?;
+
     debug!("Compiling JavaScript code");
-    // Parse and compile the JavaScript code
-    let source = Source::from_bytes(js_code);
-    let script = Script::parse(source, None, &mut context)
-        .map_err(|e| JsExecutionError::CompilationError(format!("Compilation failed: {}", e)))?;
+    // Parse and compile the JavaScript code, or the entry module of a task with modules
+    let task = match &module_loader {
+        Some(loader) => CompiledTask::Module(loader.parse_entry(&mut context)?),
+        None => {
+            let source = Source::from_bytes(js_code);
+            let script = Script::parse(source, None, &mut context)
+                .map_err(|e| JsExecutionError::CompilationError(format!("Compilation failed: {}", e)))?;
+            CompiledTask::Script(script)
+        }
+    };
+
+    debug!("Applying resource limits");
+    // Bound loop iterations in the engine and start measuring heap growth
+    options.limits.apply(&mut context);
+    let monitor = ResourceMonitor::start(&options.limits);
 
     debug!("Calling JavaScript function");
     // Call the JavaScript function with the input data and execution context
-    let result = if let Some(exec_ctx) = execution_context {
-        call_js_function_with_context(&mut context, &script, &input_data, http_manager, exec_ctx).await?
-    } else {
-        call_js_function_with_code(&mut context, &script, Some(js_code), &input_data, http_manager).await?
+    let result = match (&task, execution_context) {
+        (CompiledTask::Module(module), _) => {
+            call_js_module(
+                &mut context,
+                module,
+                &input_data,
+                execution_context,
+                &timer_session,
+                &monitor,
+            )
+            .await?
+        }
+        (CompiledTask::Script(script), Some(exec_ctx)) => {
+            call_js_function_with_context_and_timers(
+                &mut context,
+                script,
+                &input_data,
+                http_manager,
+                exec_ctx,
+                Some(&timer_session),
+                &monitor,
+            )
+            .await?
+        }
+        (CompiledTask::Script(script), None) => {
+            call_js_function_with_code(
+                &mut context,
+                script,
+                Some(js_code),
+                &input_data,
+                http_manager,
+                Some(&timer_session),
+                &monitor,
+            )
+            .await?
+        }
     };
 
     // Validate output against schema if provided
@@ -461,11 +654,13 @@ pub async fn execute_js_with_content(
         validate_json(&result, schema)?;
     }
 
+    let attachments = collect_attachments(&mut context)?;
+
     info!("JavaScript code execution completed successfully");
     debug!(
         "Output data: {}",
         serde_json::to_string(&result).unwrap_or_else(|_| "<invalid json>".to_string())
     );
 
-    Ok(result)
+    Ok((result, attachments))
 }
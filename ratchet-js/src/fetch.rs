@@ -107,7 +107,10 @@ pub fn register_fetch(context: &mut Context) -> Result<(), JsError> {
                 _internal_fetch_call: true,
                 url: url,
                 params: params,
-                body: body
+                body: body,
+                text: function() { return ""; },
+                json: function() { return {}; },
+                chunks: function() { return []; }
             };
         }
     "#,
@@ -1,9 +1,115 @@
-use crate::error_handling::parse_js_error;
-use crate::JsExecutionError;
+use crate::error_handling::{convert_js_error, resource_limit_error};
+use crate::{JsErrorType, JsExecutionError};
 use boa_engine::{property::PropertyKey, Context as BoaContext, JsString, Source};
 use serde_json::Value as JsonValue;
 use tracing::debug;
 
+/// Fetch replacement returning the stored HTTP result, throwing typed errors for failures
+///
+/// Buffered responses get `text()` and `json()` readers over their body. Streamed
+/// responses (`{ stream: true }`) read the body on demand through
+/// `__fetch_stream_read`: `chunks()` iterates the remaining `Uint8Array` chunks,
+/// while `text()`, `json()` and `body` consume whatever has not been read yet.
+const REPLAY_FETCH_SCRIPT: &str = r#"
+            function __fetch_response(response) {
+                if (!response.streaming) {
+                    response.text = function() {
+                        return typeof response.body === "string" ? response.body : JSON.stringify(response.body);
+                    };
+                    response.json = function() {
+                        return typeof response.body === "string" ? JSON.parse(response.body) : response.body;
+                    };
+                    return response;
+                }
+
+                var read = function(asText) {
+                    try {
+                        return __fetch_stream_read(asText);
+                    } catch (e) {
+                        throw new NetworkError(e.message);
+                    }
+                };
+                var text = null;
+
+                response.chunks = function() {
+                    var iterator = {
+                        next: function() {
+                            var chunk = read(false);
+                            return chunk === null ? { done: true, value: undefined } : { done: false, value: chunk };
+                        }
+                    };
+                    iterator[Symbol.iterator] = function() { return iterator; };
+                    iterator[Symbol.asyncIterator] = function() {
+                        return { next: function() { return Promise.resolve(iterator.next()); } };
+                    };
+                    return iterator;
+                };
+                response.text = function() {
+                    if (text === null) {
+                        text = read(true);
+                    }
+                    return text;
+                };
+                response.json = function() {
+                    return JSON.parse(response.text());
+                };
+                Object.defineProperty(response, "body", {
+                    get: function() {
+                        var body = response.text();
+                        try {
+                            return JSON.parse(body);
+                        } catch (e) {
+                            return body;
+                        }
+                    }
+                });
+                return response;
+            }
+
+
+            // Timers scheduled by the first run must not fire alongside those of the re-run
+            if (typeof __ratchet_timers === "object") {
+                __ratchet_timers.pending = {};
+            }
+
+            fetch = function(url, params, body) {
+                var response = __http_result;
+
+                // Body size limits are enforced before the request or while reading the response
+                if (response.error && response.error.type === "BodyTooLargeError") {
+                    throw new BodyTooLargeError(response.error.message);
+                }
+
+                // Hosts outside the fetch allowlist are refused before any request is made
+                if (response.error && response.error.type === "AuthorizationError") {
+                    throw new AuthorizationError(response.error.message);
+                }
+                
+                // Check if response is OK, throw appropriate errors if not
+                if (!response.ok) {
+                    var status = response.status || 0;
+                    var statusText = response.statusText || "Unknown Status";
+                    
+                    // Map status codes to appropriate error types
+                    if (status === 401) {
+                        throw new AuthenticationError("HTTP " + status + ": " + statusText);
+                    } else if (status === 403) {
+                        throw new AuthorizationError("HTTP " + status + ": " + statusText);
+                    } else if (status === 429) {
+                        throw new RateLimitError("HTTP " + status + ": " + statusText);
+                    } else if (status >= 500 && status < 600) {
+                        throw new ServiceUnavailableError("HTTP " + status + ": " + statusText);
+                    } else if (status >= 400 && status < 500) {
+                        throw new HttpError(status, "HTTP " + status + ": " + statusText);
+                    } else {
+                        throw new NetworkError("HTTP " + status + ": " + statusText);
+                    }
+                }
+                
+                return __fetch_response(response);
+            };
+                "#;
+
 /// Check if fetch API was called and extract parameters
 pub fn check_fetch_call(
     context: &mut BoaContext,
@@ -70,6 +176,76 @@ pub fn check_fetch_call(
     Ok(Some((url, params, body)))
 }
 
+/// Whether the task asked for the response body to be streamed
+fn is_streaming(params: Option<&JsonValue>) -> bool {
+    params
+        .and_then(|p| p.get("stream"))
+        .and_then(|s| s.as_bool())
+        .unwrap_or(false)
+}
+
+/// Perform the fetch, opening a response stream readable from JavaScript when requested
+async fn perform_fetch(
+    context: &mut BoaContext,
+    error_context: &str,
+    http_manager: &impl ratchet_http::HttpClient,
+    fetch_access: Option<&ratchet_http::FetchAccessConfig>,
+    url: &str,
+    params: Option<&JsonValue>,
+    body: Option<&JsonValue>,
+) -> Result<JsonValue, JsExecutionError> {
+    if let Some(Err(error)) = fetch_access.map(|access| ratchet_http::check_fetch_access(access, url)) {
+        return fetch_result(error_context, Err(error));
+    }
+
+    if !is_streaming(params) {
+        return fetch_result(error_context, http_manager.call_http(url, params, body).await);
+    }
+
+    match http_manager.open_stream(url, params, body).await {
+        Ok(stream) => {
+            let mut head = stream.head();
+            head["streaming"] = JsonValue::Bool(true);
+            crate::streaming::register_stream_reader(context, stream)?;
+            Ok(head)
+        }
+        Err(error) => fetch_result(error_context, Err(error)),
+    }
+}
+
+/// Convert a fetch outcome into the result handed to JavaScript
+///
+/// Body size limit violations and refused hosts become an error result so the
+/// task can catch a `BodyTooLargeError` or `AuthorizationError`; other failures
+/// abort execution.
+fn fetch_result(
+    context: &str,
+    result: Result<JsonValue, ratchet_http::HttpError>,
+) -> Result<JsonValue, JsExecutionError> {
+    match result {
+        Ok(response) => Ok(response),
+        Err(error @ ratchet_http::HttpError::BodyTooLarge { .. }) => Ok(serde_json::json!({
+            "ok": false,
+            "status": 0,
+            "statusText": "Body Too Large",
+            "error": {
+                "type": "BodyTooLargeError",
+                "message": error.to_string(),
+            },
+        })),
+        Err(error @ ratchet_http::HttpError::HostNotAllowed { .. }) => Ok(serde_json::json!({
+            "ok": false,
+            "status": 0,
+            "statusText": "Host Not Allowed",
+            "error": {
+                "type": "AuthorizationError",
+                "message": error.to_string(),
+            },
+        })),
+        Err(error) => Err(JsExecutionError::ExecutionError(format!("{}: {}", context, error))),
+    }
+}
+
 /// Handle HTTP fetch processing and inject result back into context
 pub async fn handle_fetch_processing(
     context: &mut BoaContext,
@@ -83,10 +259,16 @@ pub async fn handle_fetch_processing(
     debug!("Making HTTP call to: {}", url);
 
     // Perform the HTTP call
-    let http_result = http_manager
-        .call_http(&url, params.as_ref(), body.as_ref())
-        .await
-        .map_err(|e| JsExecutionError::ExecutionError(format!("HTTP error: {}", e)))?;
+    let http_result = perform_fetch(
+        context,
+        "HTTP error",
+        http_manager,
+        None,
+        &url,
+        params.as_ref(),
+        body.as_ref(),
+    )
+    .await?;
 
     debug!("Injecting HTTP result back into JavaScript context");
 
@@ -110,36 +292,7 @@ pub async fn handle_fetch_processing(
 
     // Replace the fetch function to return the stored result and throw appropriate errors
     context
-        .eval(Source::from_bytes(
-            r#"
-            fetch = function(url, params, body) {
-                var response = __http_result;
-                
-                // Check if response is OK, throw appropriate errors if not
-                if (!response.ok) {
-                    var status = response.status || 0;
-                    var statusText = response.statusText || "Unknown Status";
-                    
-                    // Map status codes to appropriate error types
-                    if (status === 401) {
-                        throw new AuthenticationError("HTTP " + status + ": " + statusText);
-                    } else if (status === 403) {
-                        throw new AuthorizationError("HTTP " + status + ": " + statusText);
-                    } else if (status === 429) {
-                        throw new RateLimitError("HTTP " + status + ": " + statusText);
-                    } else if (status >= 500 && status < 600) {
-                        throw new ServiceUnavailableError("HTTP " + status + ": " + statusText);
-                    } else if (status >= 400 && status < 500) {
-                        throw new HttpError(status, "HTTP " + status + ": " + statusText);
-                    } else {
-                        throw new NetworkError("HTTP " + status + ": " + statusText);
-                    }
-                }
-                
-                return response;
-            };
-        "#,
-        ))
+        .eval(Source::from_bytes(REPLAY_FETCH_SCRIPT))
         .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to replace fetch function: {}", e)))?;
 
     debug!("Re-calling JavaScript function with updated fetch");
@@ -150,11 +303,13 @@ pub async fn handle_fetch_processing(
         .ok_or_else(|| JsExecutionError::ExecutionError("Function is not callable".to_string()))?
         .call(&boa_engine::JsValue::undefined(), &[input_arg.clone()], context)
         .map_err(|e| {
+            if let Some(error) = resource_limit_error(&e) {
+                return error;
+            }
             let error_message = e.to_string();
             // Try to parse as a typed JS error first
             if error_message.contains("Error:") {
-                let parsed_error = parse_js_error(&error_message);
-                JsExecutionError::TypedJsError(parsed_error)
+                convert_js_error(&e, context)
             } else {
                 JsExecutionError::ExecutionError(error_message)
             }
@@ -179,6 +334,7 @@ pub async fn handle_fetch_processing_with_context(
     input_arg: &boa_engine::JsValue,
     context_arg: &boa_engine::JsValue,
     http_manager: &impl ratchet_http::HttpClient,
+    fetch_access: Option<&ratchet_http::FetchAccessConfig>,
     url: String,
     params: Option<JsonValue>,
     body: Option<JsonValue>,
@@ -186,10 +342,16 @@ pub async fn handle_fetch_processing_with_context(
     debug!("Processing HTTP fetch request for URL: {}", url);
 
     // Make the actual HTTP request
-    let response_result = http_manager
-        .call_http(&url, params.as_ref(), body.as_ref())
-        .await
-        .map_err(|e| JsExecutionError::ExecutionError(format!("HTTP request failed: {}", e)))?;
+    let response_result = perform_fetch(
+        context,
+        "HTTP request failed",
+        http_manager,
+        fetch_access,
+        &url,
+        params.as_ref(),
+        body.as_ref(),
+    )
+    .await?;
 
     debug!("HTTP request completed, setting result");
 
@@ -204,36 +366,7 @@ pub async fn handle_fetch_processing_with_context(
 
     // Replace the fetch function to return the stored result and throw appropriate errors
     context
-        .eval(Source::from_bytes(
-            r#"
-            fetch = function(url, params, body) {
-                var response = __http_result;
-                
-                // Check if response is OK, throw appropriate errors if not
-                if (!response.ok) {
-                    var status = response.status || 0;
-                    var statusText = response.statusText || "Unknown Status";
-                    
-                    // Map status codes to appropriate error types
-                    if (status === 401) {
-                        throw new AuthenticationError("HTTP " + status + ": " + statusText);
-                    } else if (status === 403) {
-                        throw new AuthorizationError("HTTP " + status + ": " + statusText);
-                    } else if (status === 429) {
-                        throw new RateLimitError("HTTP " + status + ": " + statusText);
-                    } else if (status >= 500 && status < 600) {
-                        throw new ServiceUnavailableError("HTTP " + status + ": " + statusText);
-                    } else if (status >= 400 && status < 500) {
-                        throw new HttpError(status, "HTTP " + status + ": " + statusText);
-                    } else {
-                        throw new NetworkError("HTTP " + status + ": " + statusText);
-                    }
-                }
-                
-                return response;
-            };
-        "#,
-        ))
+        .eval(Source::from_bytes(REPLAY_FETCH_SCRIPT))
         .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to replace fetch function: {}", e)))?;
 
     debug!("Re-calling JavaScript function with updated fetch and context");
@@ -248,11 +381,13 @@ pub async fn handle_fetch_processing_with_context(
             context,
         )
         .map_err(|e| {
+            if let Some(error) = resource_limit_error(&e) {
+                return error;
+            }
             let error_message = e.to_string();
             // Try to parse as a typed JS error first
             if error_message.contains("Error:") {
-                let parsed_error = parse_js_error(&error_message);
-                JsExecutionError::TypedJsError(parsed_error)
+                convert_js_error(&e, context)
             } else {
                 JsExecutionError::ExecutionError(error_message)
             }
@@ -1,5 +1,11 @@
-use crate::{ExecutionContext, JsExecutionError, JsTask};
+use crate::{
+    ConsoleCapture, ExecutionContext, ExecutionOptions, JsExecutionError, JsTask, ResourceLimits, TaskAttachment,
+    TaskModules, TimerClock,
+};
+use ratchet_core::validation::{enforce_output_schema, OutputSchemaEnforcement};
 use serde_json::Value as JsonValue;
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tracing::debug;
 
@@ -23,6 +29,10 @@ pub enum JsTaskError {
 pub struct JsTaskRunner {
     #[allow(dead_code)]
     http_enabled: bool,
+    output_schema_enforcement: OutputSchemaEnforcement,
+    #[cfg_attr(not(feature = "http"), allow(dead_code))]
+    http_manager: ratchet_http::HttpManager,
+    options: ExecutionOptions,
 }
 
 impl Default for JsTaskRunner {
@@ -36,9 +46,69 @@ impl JsTaskRunner {
     pub fn new() -> Self {
         Self {
             http_enabled: cfg!(feature = "http"),
+            output_schema_enforcement: OutputSchemaEnforcement::default(),
+            http_manager: ratchet_http::HttpManager::new(),
+            options: ExecutionOptions::default(),
         }
     }
 
+    /// Set how task output is checked against the task's output schema
+    pub fn with_output_schema_enforcement(mut self, enforcement: OutputSchemaEnforcement) -> Self {
+        self.output_schema_enforcement = enforcement;
+        self
+    }
+
+    /// Set the HTTP client configuration used by the task's `fetch`, including its host allowlist
+    pub fn with_http_config(mut self, http_config: ratchet_http::HttpConfig) -> Self {
+        self.http_manager = ratchet_http::HttpManager::with_config(http_config);
+        self
+    }
+
+    /// Set the HTTP client used by the task's `fetch`
+    ///
+    /// Runners given clones of one manager share its connection pools.
+    pub fn with_http_manager(mut self, http_manager: ratchet_http::HttpManager) -> Self {
+        self.http_manager = http_manager;
+        self
+    }
+
+    /// Set the clock driving the task's `setTimeout` and `setInterval` timers
+    pub fn with_clock(mut self, clock: Arc<dyn TimerClock>) -> Self {
+        self.options.timers.clock = clock;
+        self
+    }
+
+    /// Set the overall execution timeout that waiting for timers may not exceed
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.options.timers.timeout = timeout;
+        self
+    }
+
+    /// Set where the task's `console` output goes
+    ///
+    /// The capture buffers the output of every execution run through this runner.
+    pub fn with_console(mut self, console: ConsoleCapture) -> Self {
+        self.options.console = console;
+        self
+    }
+
+    /// Set the heap and loop budgets each execution must stay within
+    ///
+    /// A task exceeding them fails with `RuntimeError("resource limit exceeded")`
+    /// instead of running until the execution timeout.
+    pub fn with_limits(mut self, limits: ResourceLimits) -> Self {
+        self.options.limits = limits;
+        self
+    }
+
+    /// Run tasks as ES modules, with `modules` holding the entry module and everything it imports
+    ///
+    /// The task's `content` is ignored in favour of the entry module.
+    pub fn with_modules(mut self, modules: TaskModules) -> Self {
+        self.options.modules = Some(modules);
+        self
+    }
+
     /// Execute a JavaScript task with input data
     pub async fn execute_task(
         &self,
@@ -46,28 +116,49 @@ impl JsTaskRunner {
         input_data: JsonValue,
         execution_context: Option<ExecutionContext>,
     ) -> Result<JsonValue, JsTaskError> {
+        let (result, _attachments) = self
+            .execute_task_with_attachments(task, input_data, execution_context)
+            .await?;
+
+        Ok(result)
+    }
+
+    /// Execute a JavaScript task, returning its output together with the files
+    /// it attached through `ratchet.attach`
+    pub async fn execute_task_with_attachments(
+        &self,
+        task: &JsTask,
+        input_data: JsonValue,
+        execution_context: Option<ExecutionContext>,
+    ) -> Result<(JsonValue, Vec<TaskAttachment>), JsTaskError> {
         debug!("Executing JS task: {}", task.name);
 
-        // Create HTTP manager if HTTP feature is enabled
+        // Use the runner's HTTP manager if HTTP feature is enabled
         #[cfg(feature = "http")]
-        let http_manager = ratchet_http::HttpManager::new();
+        let http_manager = &self.http_manager;
 
         #[cfg(not(feature = "http"))]
-        let http_manager = ();
+        let http_manager = &();
 
-        // Execute the task
-        let result = crate::execution::execute_js_with_content(
+        // Execute the task, checking its output under the runner's enforcement policy
+        let (output, attachments) = crate::execution::execute_js_with_options(
             &task.content,
             input_data,
             task.input_schema.as_ref(),
-            task.output_schema.as_ref(),
-            &http_manager,
+            None,
+            http_manager,
             execution_context.as_ref(),
+            &self.options,
         )
         .await
         .map_err(JsTaskError::from)?;
 
-        Ok(result)
+        if let Some(schema) = &task.output_schema {
+            enforce_output_schema(&output, schema, self.output_schema_enforcement)
+                .map_err(|e| JsTaskError::from(JsExecutionError::from(e)))?;
+        }
+
+        Ok((output, attachments))
     }
 
     /// Execute JavaScript code directly with input data
@@ -81,21 +172,22 @@ impl JsTaskRunner {
     ) -> Result<JsonValue, JsTaskError> {
         debug!("Executing JS code directly");
 
-        // Create HTTP manager if HTTP feature is enabled
+        // Use the runner's HTTP manager if HTTP feature is enabled
         #[cfg(feature = "http")]
-        let http_manager = ratchet_http::HttpManager::new();
+        let http_manager = &self.http_manager;
 
         #[cfg(not(feature = "http"))]
-        let http_manager = ();
+        let http_manager = &();
 
         // Execute the code
-        let result = crate::execution::execute_js_with_content(
+        let (result, _attachments) = crate::execution::execute_js_with_options(
             code,
             input_data,
             input_schema,
             output_schema,
-            &http_manager,
+            http_manager,
             execution_context.as_ref(),
+            &self.options,
         )
         .await
         .map_err(JsTaskError::from)?;
@@ -132,6 +224,7 @@ pub async fn add_two_numbers(num1: i32, num2: i32) -> Result<i32, JsExecutionErr
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratchet_interfaces::logging::LogLevel;
 
     #[tokio::test]
     async fn test_add_two_numbers() {
@@ -166,4 +259,591 @@ mod tests {
         let output = result.unwrap();
         assert_eq!(output["result"], 30);
     }
+
+    #[tokio::test]
+    async fn test_js_task_attachments() {
+        let task = JsTask {
+            name: "attachment_task".to_string(),
+            content: r#"
+                function main(input) {
+                    ratchet.attach("report.csv", "id,value\n1," + input.value + "\n", "text/csv");
+                    ratchet.attach("data.bin", new Uint8Array([0, 1, 255]));
+                    ratchet.attach("summary.json", { total: input.value });
+                    return { attached: 3 };
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let runner = JsTaskRunner::new();
+        let (output, attachments) = runner
+            .execute_task_with_attachments(&task, serde_json::json!({ "value": 42 }), None)
+            .await
+            .unwrap();
+
+        assert_eq!(output["attached"], 3);
+        assert_eq!(attachments.len(), 3);
+        assert_eq!(attachments[0].name, "report.csv");
+        assert_eq!(attachments[0].mime_type, "text/csv");
+        assert_eq!(attachments[0].data, b"id,value\n1,42\n");
+        assert_eq!(attachments[1].mime_type, "application/octet-stream");
+        assert_eq!(attachments[1].data, vec![0, 1, 255]);
+        assert_eq!(attachments[2].mime_type, "application/json");
+        assert_eq!(attachments[2].data, br#"{"total":42}"#);
+    }
+
+    #[tokio::test]
+    async fn test_js_task_timers_follow_manual_clock() {
+        let task = JsTask {
+            name: "timer_task".to_string(),
+            content: r#"
+                async function main(input) {
+                    var events = [];
+                    setTimeout(function() { events.push("late"); }, 50);
+                    setTimeout(function(label) { events.push(label); }, 10, "early");
+                    clearTimeout(setTimeout(function() { events.push("cancelled"); }, 20));
+
+                    await new Promise(function(resolve) {
+                        var ticks = 0;
+                        var interval = setInterval(function() {
+                            ticks += 1;
+                            events.push("tick" + ticks);
+                            if (ticks === input.ticks) {
+                                clearInterval(interval);
+                                resolve();
+                            }
+                        }, 15);
+                    });
+                    return { events: events };
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let clock = Arc::new(crate::ManualClock::new());
+        let runner = JsTaskRunner::new().with_clock(clock.clone());
+        let output = runner
+            .execute_task(&task, serde_json::json!({ "ticks": 3 }), None)
+            .await
+            .unwrap();
+
+        // The task finishes at the third tick, dropping the still-pending "late" timer
+        assert_eq!(
+            output["events"],
+            serde_json::json!(["early", "tick1", "tick2", "tick3"])
+        );
+        assert_eq!(clock.now(), Duration::from_millis(45));
+    }
+
+    /// Clock moving 7ms forward on every reading, standing in for time passing while a task runs
+    #[derive(Debug, Default)]
+    struct DriftingClock(std::sync::Mutex<Duration>);
+
+    impl TimerClock for DriftingClock {
+        fn now(&self) -> Duration {
+            let mut now = self.0.lock().unwrap();
+            *now += Duration::from_millis(7);
+            *now
+        }
+
+        fn wait_until(&self, target: Duration) -> futures::future::BoxFuture<'_, ()> {
+            let mut now = self.0.lock().unwrap();
+            *now = (*now).max(target);
+            Box::pin(std::future::ready(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_js_task_replay_fires_timers_as_recorded() {
+        let task = JsTask {
+            name: "drifting_timer_task".to_string(),
+            content: r#"
+                async function main(input) {
+                    var events = [];
+                    setTimeout(function() { events.push("a"); }, 10);
+                    setTimeout(function() { events.push("b"); }, 5);
+                    await new Promise(function(resolve) { setTimeout(resolve, 30); });
+                    return { events: events };
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        // Time passing between the two calls schedules "b" after "a"
+        let recording = Arc::new(crate::RecordingClock::new(Arc::new(DriftingClock::default())));
+        let recorded = JsTaskRunner::new()
+            .with_clock(recording.clone())
+            .execute_task(&task, serde_json::json!({}), None)
+            .await
+            .unwrap();
+        assert_eq!(recorded["events"], serde_json::json!(["a", "b"]));
+
+        let replayed = JsTaskRunner::new()
+            .with_clock(Arc::new(crate::ReplayClock::new(recording.readings())))
+            .execute_task(&task, serde_json::json!({}), None)
+            .await
+            .unwrap();
+        assert_eq!(replayed, recorded);
+
+        // Without the recorded readings the delays alone decide the order
+        let unrecorded = JsTaskRunner::new()
+            .with_clock(Arc::new(crate::ManualClock::new()))
+            .execute_task(&task, serde_json::json!({}), None)
+            .await
+            .unwrap();
+        assert_eq!(unrecorded["events"], serde_json::json!(["b", "a"]));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_timers_respect_timeout() {
+        let task = JsTask {
+            name: "endless_task".to_string(),
+            content: r#"
+                function main(input) {
+                    return new Promise(function(resolve) {
+                        setInterval(function() {}, 100);
+                    });
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let runner = JsTaskRunner::new()
+            .with_clock(Arc::new(crate::ManualClock::new()))
+            .with_timeout(Duration::from_secs(1));
+        let error = runner
+            .execute_task(&task, serde_json::json!({}), None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            JsTaskError::JsExecutionError(JsExecutionError::TypedJsError(crate::JsErrorType::TimeoutError(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_console_capture() {
+        let task = JsTask {
+            name: "console_task".to_string(),
+            content: r#"
+                function main(input) {
+                    console.log("processing", input.items.length, "items");
+                    console.warn({ skipped: 1 });
+                    console.error(new ValidationError("bad item"));
+                    return { ok: true };
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let console = ConsoleCapture::new();
+        let runner = JsTaskRunner::new().with_console(console.clone());
+        let context = ExecutionContext::new("exec-1".to_string(), "task-7".to_string(), "1.0.0".to_string());
+        runner
+            .execute_task(&task, serde_json::json!({ "items": [1, 2] }), Some(context))
+            .await
+            .unwrap();
+
+        let events = console.events();
+        let lines: Vec<_> = events.iter().map(|e| (e.level, e.message.as_str())).collect();
+        assert_eq!(
+            lines,
+            vec![
+                (LogLevel::Info, "processing 2 items"),
+                (LogLevel::Warn, r#"{"skipped":1}"#),
+                (LogLevel::Error, "ValidationError: bad item"),
+            ]
+        );
+        assert_eq!(events[0].context["task_id"], "task-7");
+        assert_eq!(events[0].context["execution_id"], "exec-1");
+        assert_eq!(events[0].source.as_deref(), Some("javascript"));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_catches_body_too_large() {
+        let task = JsTask {
+            name: "upload_task".to_string(),
+            content: r#"
+                function main(input) {
+                    try {
+                        fetch("http://127.0.0.1:9/upload", { method: "POST" }, { data: "x".repeat(64) });
+                        return { caught: false };
+                    } catch (e) {
+                        return { caught: e instanceof BodyTooLargeError, name: e.name };
+                    }
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+        // The request body is checked against the limit before anything is sent
+        let runner = JsTaskRunner::new().with_http_config(ratchet_http::HttpConfig {
+            max_request_size: 16,
+            ..ratchet_http::HttpConfig::default()
+        });
+
+        let output = runner.execute_task(&task, serde_json::json!({}), None).await.unwrap();
+        assert_eq!(
+            output,
+            serde_json::json!({ "caught": true, "name": "BodyTooLargeError" })
+        );
+    }
+
+    /// Serve one `hello world` response on a local port, returning its URL
+    async fn serve_hello_world() -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/stream", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 11\r\nconnection: close\r\n\r\nhello world")
+                .await
+                .unwrap();
+        });
+        url
+    }
+
+    fn streaming_task() -> JsTask {
+        JsTask {
+            name: "stream_task".to_string(),
+            content: r#"
+                function main(input) {
+                    var response = fetch(input.url, { stream: true });
+                    try {
+                        return { text: response.text() };
+                    } catch (e) {
+                        return { error: e.name };
+                    }
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_js_task_reads_streamed_response() {
+        let url = serve_hello_world().await;
+        let output = JsTaskRunner::new()
+            .execute_task(&streaming_task(), serde_json::json!({ "url": url }), None)
+            .await
+            .unwrap();
+        assert_eq!(output, serde_json::json!({ "text": "hello world" }));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_streaming_on_current_thread_runtime_fails_instead_of_hanging() {
+        let url = serve_hello_world().await;
+        let output = JsTaskRunner::new()
+            .execute_task(&streaming_task(), serde_json::json!({ "url": url }), None)
+            .await
+            .unwrap();
+        assert_eq!(output, serde_json::json!({ "error": "NetworkError" }));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_loop_budget() {
+        let task = |body: &str| JsTask {
+            name: "loop_task".to_string(),
+            content: format!("function main(input) {{ {} }}", body),
+            input_schema: None,
+            output_schema: None,
+        };
+        let runner = JsTaskRunner::new().with_limits(ResourceLimits::new().with_max_loop_iterations(10_000));
+
+        // Loops within the budget run to completion
+        let output = runner
+            .execute_task(
+                &task("var sum = 0; for (var i = 0; i < 1000; i++) { sum += i; } return { sum: sum };"),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(output["sum"], 499500);
+
+        // An infinite loop is aborted, even when the task tries to catch the error
+        let error = runner
+            .execute_task(
+                &task("try { while (true) {} } catch (e) { return { caught: true }; }"),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            JsTaskError::JsExecutionError(JsExecutionError::RuntimeError(ref message))
+                if message == crate::RESOURCE_LIMIT_EXCEEDED
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_infinite_recursion() {
+        let task = |body: &str| JsTask {
+            name: "recursive_task".to_string(),
+            content: format!("function main(input) {{ {} }}", body),
+            input_schema: None,
+            output_schema: None,
+        };
+        let runner = JsTaskRunner::new();
+
+        let error = runner
+            .execute_task(
+                &task("function recurse(n) { return recurse(n + 1) + 1; } return { depth: recurse(0) };"),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            JsTaskError::JsExecutionError(JsExecutionError::RuntimeError(ref message))
+                if message == crate::CALL_STACK_EXCEEDED
+        ));
+
+        // The runner is still usable after the failed execution
+        let output = runner
+            .execute_task(&task("return { ok: true };"), serde_json::json!({}), None)
+            .await
+            .unwrap();
+        assert_eq!(output["ok"], true);
+    }
+
+    #[tokio::test]
+    async fn test_js_task_text_and_base64_encoding() {
+        let task = JsTask {
+            name: "encoding_task".to_string(),
+            content: r#"
+                function main(input) {
+                    var bytes = new TextEncoder().encode(input.text);
+                    var binary = String.fromCharCode.apply(null, bytes);
+                    var decoded = atob(btoa(binary));
+                    var roundTrip = new TextDecoder().decode(
+                        new Uint8Array(decoded.split("").map(function(c) { return c.charCodeAt(0); }))
+                    );
+
+                    var errors = [];
+                    try {
+                        atob("not base64!");
+                    } catch (e) {
+                        errors.push(e.name);
+                    }
+                    try {
+                        new TextDecoder().decode(new Uint8Array([0xC3, 0x28]));
+                    } catch (e) {
+                        errors.push(e.name);
+                    }
+
+                    return {
+                        length: bytes.length,
+                        base64: btoa(binary),
+                        roundTrip: roundTrip,
+                        lenient: new TextDecoder("utf-8", { fatal: false }).decode(new Uint8Array([0x61, 0xFF])),
+                        errors: errors
+                    };
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let output = JsTaskRunner::new()
+            .execute_task(&task, serde_json::json!({ "text": "héllo ✓" }), None)
+            .await
+            .unwrap();
+
+        assert_eq!(output["length"], 10);
+        assert_eq!(output["base64"], "aMOpbGxvIOKckw==");
+        assert_eq!(output["roundTrip"], "héllo ✓");
+        assert_eq!(output["lenient"], "a\u{FFFD}");
+        assert_eq!(output["errors"], serde_json::json!(["DataError", "DataError"]));
+
+        // Uncaught, invalid input fails the execution with a DataError
+        let task = JsTask {
+            name: "invalid_base64".to_string(),
+            content: "function main(input) { return { value: btoa('\\u0100') }; }".to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+        let error = JsTaskRunner::new()
+            .execute_task(&task, serde_json::json!({}), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            JsTaskError::JsExecutionError(JsExecutionError::JsError {
+                error_type: crate::JsErrorType::DataError(_),
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_js_task_error_stack_trace() {
+        let task = |body: &str| JsTask {
+            name: "stack_task".to_string(),
+            content: format!(
+                r#"
+                function parseRecord(record) {{
+                    if (!record.id) {{
+                        throw new ValidationError("record has no id");
+                    }}
+                    return record.id;
+                }}
+
+                function parseAll(records) {{
+                    return records.map(function(record) {{ return parseRecord(record); }});
+                }}
+
+                {}
+                "#,
+                body
+            ),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let functions = |error: &JsTaskError| match error {
+            JsTaskError::JsExecutionError(error) => error
+                .stack()
+                .iter()
+                .map(|frame| frame.function.clone())
+                .collect::<Vec<_>>(),
+            _ => panic!("unexpected error: {}", error),
+        };
+
+        // A thrown error carries the calls leading up to the throw, innermost first
+        let error = JsTaskRunner::new()
+            .execute_task(
+                &task("function main(input) { return { ids: parseAll(input.records) }; }"),
+                serde_json::json!({ "records": [{ "id": 1 }, {}] }),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            JsTaskError::JsExecutionError(JsExecutionError::JsError {
+                error_type: crate::JsErrorType::ValidationError(ref message),
+                ..
+            }) if message == "record has no id"
+        ));
+        let stack = functions(&error);
+        assert_eq!(stack[0], "parseRecord");
+        let parse_all = stack.iter().position(|name| name == "parseAll").unwrap();
+        let main = stack.iter().position(|name| name == "main").unwrap();
+        assert!(parse_all < main);
+
+        let details = match &error {
+            JsTaskError::JsExecutionError(error) => error.details().unwrap(),
+            _ => unreachable!(),
+        };
+        assert_eq!(details["stack"][0]["function"], "parseRecord");
+
+        // So does a rejected promise
+        let error = JsTaskRunner::new()
+            .execute_task(
+                &task("async function main(input) { await null; return { ids: parseAll(input.records) }; }"),
+                serde_json::json!({ "records": [{}] }),
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(functions(&error)[0], "parseRecord");
+    }
+
+    #[cfg(feature = "crypto")]
+    #[tokio::test]
+    async fn test_js_task_crypto_digest_and_hmac() {
+        let task = JsTask {
+            name: "crypto_task".to_string(),
+            content: r#"
+                async function main(input) {
+                    function hex(buffer) {
+                        return Array.from(new Uint8Array(buffer), function(b) {
+                            return (b < 16 ? '0' : '') + b.toString(16);
+                        }).join('');
+                    }
+
+                    var key = await crypto.subtle.importKey(
+                        'raw', 'key', { name: 'HMAC', hash: 'SHA-256' }, false, ['sign', 'verify']);
+                    var signature = await crypto.subtle.sign('HMAC', key, input.message);
+                    var unsupported;
+                    try {
+                        await crypto.subtle.digest('MD5', 'abc');
+                    } catch (e) {
+                        unsupported = e.name;
+                    }
+
+                    return {
+                        sha256: hex(await crypto.subtle.digest('SHA-256', 'abc')),
+                        sha512: hex(await crypto.subtle.digest('SHA-512', new TextEncoder().encode('abc'))),
+                        hmac: hex(signature),
+                        verified: await crypto.subtle.verify('HMAC', key, signature, input.message),
+                        tampered: await crypto.subtle.verify('HMAC', key, signature, input.message + '.'),
+                        unsupported: unsupported,
+                        uuid: crypto.randomUUID(),
+                        random: crypto.getRandomValues(new Uint8Array(32)).length
+                    };
+                }
+            "#
+            .to_string(),
+            input_schema: None,
+            output_schema: None,
+        };
+
+        let output = JsTaskRunner::new()
+            .execute_task(
+                &task,
+                serde_json::json!({ "message": "The quick brown fox jumps over the lazy dog" }),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            output["sha256"],
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            output["sha512"],
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(
+            output["hmac"],
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        assert_eq!(output["verified"], true);
+        assert_eq!(output["tampered"], false);
+        assert_eq!(output["unsupported"], "DataError");
+        assert_eq!(output["random"], 32);
+
+        let uuid = output["uuid"].as_str().unwrap();
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+    }
 }
@@ -3,29 +3,54 @@
 //! This crate provides JavaScript execution capabilities using the Boa engine,
 //! including HTTP fetch API integration, error handling, and schema validation.
 
+pub mod attachments;
+pub mod console;
 pub mod conversion;
+pub mod encoding;
 pub mod error_handling;
 pub mod execution;
 pub mod http_integration;
 pub mod js_task;
+pub mod limits;
+pub mod modules;
+pub mod streaming;
 pub mod task_loader;
+pub mod timers;
 pub mod types;
+pub mod validation;
 
 #[cfg(feature = "http")]
 pub mod fetch;
 
+#[cfg(feature = "crypto")]
+pub mod crypto;
+
 // Re-export main types for convenience
+pub use attachments::{register_attachments, TaskAttachment};
+pub use console::{ConsoleCapture, DEFAULT_MAX_CONSOLE_BYTES, DEFAULT_MAX_CONSOLE_EVENTS};
 pub use conversion::{convert_js_result_to_json, prepare_input_argument};
-pub use error_handling::{parse_js_error, register_error_types};
-pub use execution::{execute_js_file, execute_js_with_content};
+pub use encoding::register_encoding;
+pub use error_handling::{parse_js_error, parse_js_stack, register_error_types};
+pub use execution::{execute_js_file, execute_js_with_attachments, execute_js_with_content, execute_js_with_options};
 pub use js_task::JsTaskRunner;
-pub use task_loader::{load_and_execute_task, FileSystemTask, TaskLoadError};
-pub use types::{ExecutionContext, JsTask};
+pub use limits::{
+    run_on_task_thread, ResourceLimits, CALL_STACK_EXCEEDED, DEFAULT_MAX_CALL_DEPTH, RESOURCE_LIMIT_EXCEEDED,
+    TASK_STACK_SIZE,
+};
+pub use modules::{TaskModuleLoader, TaskModules};
+pub use task_loader::{load_and_execute_task, load_and_execute_task_with_clock, FileSystemTask, TaskLoadError};
+pub use timers::{ManualClock, RecordingClock, ReplayClock, SystemClock, TimerClock, TimerConfig};
+pub use types::{ExecutionContext, ExecutionOptions, JsTask};
+pub use validation::{validate_task, validate_task_directory, validate_task_source};
 
 #[cfg(feature = "http")]
 pub use fetch::register_fetch;
 
+#[cfg(feature = "crypto")]
+pub use crypto::register_crypto;
+
 // JavaScript error types
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// JavaScript error types that can be thrown from JS code
@@ -64,6 +89,9 @@ pub enum JsErrorType {
     #[error("Data error: {0}")]
     DataError(String),
 
+    #[error("Body too large: {0}")]
+    BodyTooLargeError(String),
+
     #[error("HTTP error (status {status}): {message}")]
     HttpError { status: u16, message: String },
 
@@ -113,9 +141,50 @@ pub enum JsExecutionError {
     #[error("Typed JavaScript error: {0:?}")]
     TypedJsError(JsErrorType),
 
-    #[error("JavaScript error: {error_type} - {message}")]
-    JsError { error_type: JsErrorType, message: String },
+    #[error("JavaScript error: {message}")]
+    JsError {
+        error_type: JsErrorType,
+        message: String,
+        stack: Vec<JsStackFrame>,
+    },
 
     #[error("Ratchet error: {0}")]
     RatchetError(#[from] ratchet_core::error::RatchetError),
 }
+
+impl JsExecutionError {
+    /// Stack trace of the JavaScript error behind this failure, innermost frame first
+    pub fn stack(&self) -> &[JsStackFrame] {
+        match self {
+            Self::JsError { stack, .. } => stack,
+            _ => &[],
+        }
+    }
+
+    /// Message and stack trace of a JavaScript error, as stored in an execution's error details
+    pub fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::JsError { message, stack, .. } => Some(serde_json::json!({
+                "message": message,
+                "stack": stack,
+            })),
+            _ => None,
+        }
+    }
+}
+
+/// One frame of a JavaScript stack trace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsStackFrame {
+    /// Function name, `<anonymous>` for anonymous functions
+    pub function: String,
+
+    /// Script the function is defined in, when the engine reports it
+    pub file: Option<String>,
+
+    /// 1-based line, when the engine reports it
+    pub line: Option<u32>,
+
+    /// 1-based column, when the engine reports it
+    pub column: Option<u32>,
+}
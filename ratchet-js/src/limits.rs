@@ -0,0 +1,198 @@
+//! Resource limits for JavaScript tasks
+//!
+//! Two budgets stop a runaway task without taking down the worker running it.
+//! The loop budget caps how many iterations a loop may run. Boa counts them per
+//! call frame from the most recent loop start in that frame, so the budget
+//! applies to each loop rather than to the execution as a whole, and a loop
+//! that keeps starting inner loops can run past it. Boa enforces it as it
+//! executes, so an infinite loop fails as soon as the budget is spent and task
+//! code cannot catch the error.
+//!
+//! Despite its name, the heap budget does not measure the JavaScript heap: Boa
+//! does not meter its own heap, so the budget caps how far the resident memory
+//! (RSS) of the whole process may grow during an execution. Other executions
+//! and threads in the same process count towards that growth. The growth is
+//! sampled whenever control returns to the host: after the task function
+//! returns and between the timers and promise jobs it waits on, so a
+//! synchronous allocation loop is not stopped until it returns.
+//!
+//! Either limit failing aborts the execution with
+//! `JsExecutionError::RuntimeError("resource limit exceeded")`.
+//!
+//! Recursion is bounded by the call depth limit, which Boa also enforces.
+//! Runaway recursion fails with `RuntimeError("maximum call stack exceeded")`
+//! before it can overflow the native stack, provided the task runs on a thread
+//! with at least [`TASK_STACK_SIZE`] of stack: calls that pass through native
+//! functions, such as `Array.prototype.map` callbacks, use the native stack for
+//! every level of JavaScript recursion. [`run_on_task_thread`] starts such a
+//! thread.
+
+use boa_engine::Context;
+use std::sync::Mutex;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tracing::{debug, warn};
+
+use crate::JsExecutionError;
+
+/// Message of the error an execution fails with when it exceeds a limit
+pub const RESOURCE_LIMIT_EXCEEDED: &str = "resource limit exceeded";
+
+/// Message of the error an execution fails with when it recurses too deeply
+pub const CALL_STACK_EXCEEDED: &str = "maximum call stack exceeded";
+
+/// Default limit on nested JavaScript calls
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 512;
+
+/// Native stack size of a thread running tasks at [`DEFAULT_MAX_CALL_DEPTH`]
+pub const TASK_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+/// Run `f` on a new thread with [`TASK_STACK_SIZE`] of stack and wait for its result
+///
+/// Boa contexts are not `Send`, so `f` creates and drives the engine itself; async
+/// work inside it can block on a runtime handle captured beforehand.
+pub async fn run_on_task_thread<F, T>(f: F) -> Result<T, JsExecutionError>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+    std::thread::Builder::new()
+        .name("ratchet-js-task".to_string())
+        .stack_size(TASK_STACK_SIZE)
+        .spawn(move || {
+            let _ = result_tx.send(f());
+        })
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to start task thread: {}", e)))?;
+
+    result_rx
+        .await
+        .map_err(|_| JsExecutionError::ExecutionError("Task thread exited without a result".to_string()))
+}
+
+/// Limits on the resources one execution may use
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Most bytes the process's resident memory may grow by while the task runs
+    pub max_heap_bytes: Option<u64>,
+
+    /// Most iterations a loop may run, counted from its start
+    pub max_loop_iterations: Option<u64>,
+
+    /// Deepest nesting of JavaScript calls, [`DEFAULT_MAX_CALL_DEPTH`] unless set
+    pub max_call_depth: Option<usize>,
+}
+
+impl ResourceLimits {
+    /// No limits beyond the default call depth
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_heap_bytes(mut self, bytes: u64) -> Self {
+        self.max_heap_bytes = Some(bytes);
+        self
+    }
+
+    pub fn with_max_loop_iterations(mut self, iterations: u64) -> Self {
+        self.max_loop_iterations = Some(iterations);
+        self
+    }
+
+    pub fn with_max_call_depth(mut self, depth: usize) -> Self {
+        self.max_call_depth = Some(depth);
+        self
+    }
+
+    /// Apply the limits the engine enforces itself to `context`
+    pub fn apply(&self, context: &mut Context) {
+        let runtime_limits = context.runtime_limits_mut();
+        if let Some(iterations) = self.max_loop_iterations {
+            runtime_limits.set_loop_iteration_limit(iterations);
+        }
+        runtime_limits.set_recursion_limit(self.max_call_depth.unwrap_or(DEFAULT_MAX_CALL_DEPTH));
+    }
+}
+
+/// Memory growth of one execution, created by [`ResourceMonitor::start`]
+///
+/// The default monitor enforces no heap limit.
+#[derive(Debug, Default)]
+pub struct ResourceMonitor {
+    max_heap_bytes: Option<u64>,
+    process: Option<ProcessMemory>,
+}
+
+#[derive(Debug)]
+struct ProcessMemory {
+    pid: Pid,
+    system: Mutex<System>,
+    baseline: u64,
+}
+
+impl ProcessMemory {
+    fn sample(&self) -> Option<u64> {
+        let mut system = self.system.lock().unwrap();
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[self.pid]),
+            true,
+            ProcessRefreshKind::nothing().with_memory(),
+        );
+        system.process(self.pid).map(|process| process.memory())
+    }
+}
+
+impl ResourceMonitor {
+    /// Start measuring the process's memory growth against `limits`
+    pub fn start(limits: &ResourceLimits) -> Self {
+        let process = limits.max_heap_bytes.and_then(|_| {
+            let pid = match sysinfo::get_current_pid() {
+                Ok(pid) => pid,
+                Err(e) => {
+                    warn!("Heap limit disabled, cannot identify the current process: {}", e);
+                    return None;
+                }
+            };
+            let mut process = ProcessMemory {
+                pid,
+                system: Mutex::new(System::new()),
+                baseline: 0,
+            };
+            match process.sample() {
+                Some(baseline) => {
+                    process.baseline = baseline;
+                    Some(process)
+                }
+                None => {
+                    warn!("Heap limit disabled, process memory is unavailable");
+                    None
+                }
+            }
+        });
+
+        Self {
+            max_heap_bytes: limits.max_heap_bytes,
+            process,
+        }
+    }
+
+    /// Fail if the process's memory has grown beyond the heap limit during the execution
+    pub fn check(&self) -> Result<(), JsExecutionError> {
+        let (Some(max), Some(process)) = (self.max_heap_bytes, &self.process) else {
+            return Ok(());
+        };
+        let Some(used) = process.sample() else {
+            return Ok(());
+        };
+
+        let growth = used.saturating_sub(process.baseline);
+        debug!("Process memory growth during execution: {} bytes", growth);
+        if growth > max {
+            warn!(
+                "Task exceeded its heap limit: grew by {} bytes, limit is {}",
+                growth, max
+            );
+            return Err(JsExecutionError::RuntimeError(RESOURCE_LIMIT_EXCEEDED.to_string()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+//! ES module support for JavaScript tasks
+//!
+//! A task whose `main.js` uses `import` or `export` runs as an ES module. Its
+//! imports are resolved when the task is loaded: every module reachable from
+//! `main.js` through relative specifiers such as `./lib/util.js` is read into a
+//! [`TaskModules`] set, keyed by its path within the task directory. At execution
+//! time [`TaskModuleLoader`] serves the engine's imports from that set, so a task
+//! can only import files that were loaded with it.
+//!
+//! Specifiers are resolved against the importing module and must stay inside the
+//! task directory; bare specifiers (`lodash`) and paths escaping the directory
+//! (`../other-task/main.js`) are rejected. The entry module provides the task
+//! function as its `main` export, or failing that its default export.
+
+use boa_engine::{
+    builtins::promise::PromiseState,
+    module::{ModuleLoader, Referrer},
+    Context, JsNativeError, JsResult, JsString, JsValue, Module, Source,
+};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use crate::error_handling::{convert_js_error, convert_thrown_value};
+use crate::JsExecutionError;
+
+/// Sources of a task's ES modules, keyed by their path within the task directory
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskModules {
+    entry: String,
+    sources: BTreeMap<String, String>,
+}
+
+impl TaskModules {
+    /// Module set holding only the entry module at `path`
+    pub fn new(path: impl Into<String>, source: impl Into<String>) -> Self {
+        let entry = path.into();
+        let mut sources = BTreeMap::new();
+        sources.insert(entry.clone(), source.into());
+        Self { entry, sources }
+    }
+
+    /// Add the module at `path`, replacing any module already there
+    pub fn insert(&mut self, path: impl Into<String>, source: impl Into<String>) {
+        self.sources.insert(path.into(), source.into());
+    }
+
+    /// Path of the entry module
+    pub fn entry(&self) -> &str {
+        &self.entry
+    }
+
+    /// Source of the module at `path`
+    pub fn source(&self, path: &str) -> Option<&str> {
+        self.sources.get(path).map(String::as_str)
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.sources.contains_key(path)
+    }
+
+    /// Paths and sources of all modules, in path order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.sources
+            .iter()
+            .map(|(path, source)| (path.as_str(), source.as_str()))
+    }
+}
+
+/// Whether `source` is an ES module, as opposed to a classic script
+pub fn is_module_source(source: &str) -> bool {
+    !import_specifiers(source).is_empty() || regex::Regex::new(r"(?m)^\s*export\s").unwrap().is_match(source)
+}
+
+/// Specifiers of the static `import` and `export ... from` statements in `source`
+pub fn import_specifiers(source: &str) -> Vec<String> {
+    regex::Regex::new(r#"(?m)^\s*(?:import|export)\s(?:[^'";]*?\sfrom\s*)?['"]([^'"]+)['"]"#)
+        .unwrap()
+        .captures_iter(source)
+        .map(|captures| captures[1].to_string())
+        .collect()
+}
+
+/// Path within the task directory that `specifier`, imported by the module at
+/// `referrer`, refers to
+///
+/// Only relative specifiers are supported, and they may not leave the task directory.
+pub fn resolve_specifier(referrer: &str, specifier: &str) -> Result<String, String> {
+    if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+        return Err(format!(
+            "Unsupported import '{}' in {}: only relative imports within the task are supported",
+            specifier, referrer
+        ));
+    }
+    if specifier.contains(['\\', ':']) {
+        return Err(format!("Invalid import '{}' in {}", specifier, referrer));
+    }
+
+    let mut parts: Vec<&str> = referrer.split('/').collect();
+    parts.pop();
+    for part in specifier.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                if parts.pop().is_none() {
+                    return Err(format!(
+                        "Import '{}' in {} escapes the task directory",
+                        specifier, referrer
+                    ));
+                }
+            }
+            part => parts.push(part),
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+/// Module loader serving a task's imports from its [`TaskModules`]
+#[derive(Debug)]
+pub struct TaskModuleLoader {
+    modules: TaskModules,
+    parsed: RefCell<Vec<(String, Module)>>,
+}
+
+impl TaskModuleLoader {
+    pub fn new(modules: TaskModules) -> Self {
+        Self {
+            modules,
+            parsed: RefCell::default(),
+        }
+    }
+
+    /// Parse the task's entry module
+    pub fn parse_entry(&self, context: &mut Context) -> Result<Module, JsExecutionError> {
+        self.parse(self.modules.entry(), context)
+            .map_err(|e| JsExecutionError::CompilationError(format!("Compilation failed: {}", e)))
+    }
+
+    fn parse(&self, path: &str, context: &mut Context) -> JsResult<Module> {
+        if let Some(module) = self.module_at(path) {
+            return Ok(module);
+        }
+
+        let source = self
+            .modules
+            .source(path)
+            .ok_or_else(|| JsNativeError::typ().with_message(format!("Module not found: {}", path)))?;
+        let module = Module::parse(Source::from_bytes(source), None, context)
+            .map_err(|e| JsNativeError::syntax().with_message(format!("Could not parse module {}: {}", path, e)))?;
+
+        self.parsed.borrow_mut().push((path.to_string(), module.clone()));
+        Ok(module)
+    }
+
+    fn module_at(&self, path: &str) -> Option<Module> {
+        self.parsed
+            .borrow()
+            .iter()
+            .find(|(parsed, _)| parsed == path)
+            .map(|(_, module)| module.clone())
+    }
+
+    fn path_of(&self, module: &Module) -> Option<String> {
+        self.parsed
+            .borrow()
+            .iter()
+            .find(|(_, parsed)| parsed == module)
+            .map(|(path, _)| path.clone())
+    }
+}
+
+impl ModuleLoader for TaskModuleLoader {
+    fn load_imported_module(
+        &self,
+        referrer: Referrer,
+        specifier: JsString,
+        finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+        context: &mut Context,
+    ) {
+        let result = (|| {
+            let referrer = match &referrer {
+                Referrer::Module(module) => self.path_of(module),
+                _ => None,
+            }
+            .unwrap_or_else(|| self.modules.entry().to_string());
+
+            let path = resolve_specifier(&referrer, &specifier.to_std_string_escaped())
+                .map_err(|e| JsNativeError::typ().with_message(e))?;
+            self.parse(&path, context)
+        })();
+
+        finish_load(result, context);
+    }
+}
+
+/// Load, link and evaluate the entry `module`, returning the task function it exports
+pub fn evaluate_entry(module: &Module, context: &mut Context) -> Result<JsValue, JsExecutionError> {
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs();
+
+    match promise.state() {
+        PromiseState::Fulfilled(_) => {}
+        PromiseState::Rejected(error) => return Err(convert_thrown_value(&error, context)),
+        PromiseState::Pending => {
+            return Err(JsExecutionError::ExecutionError(
+                "Task module did not finish evaluating".to_string(),
+            ))
+        }
+    }
+
+    let namespace = module.namespace(context);
+    for export in ["main", "default"] {
+        let value = namespace
+            .get(JsString::from(export), context)
+            .map_err(|e| convert_js_error(&e, context))?;
+        if value.is_callable() {
+            return Ok(value);
+        }
+    }
+
+    Err(JsExecutionError::RuntimeError(
+        "Task module must export a main function".to_string(),
+    ))
+}
@@ -0,0 +1,81 @@
+//! Streaming fetch responses for JavaScript tasks
+//!
+//! A fetch made with `{ stream: true }` leaves the response body on the
+//! connection. The task reads it incrementally through `response.chunks()`,
+//! which yields `Uint8Array` chunks, or reads the remainder at once through
+//! `response.text()` and `response.json()`. Each read pulls from the reqwest
+//! byte stream through the native `__fetch_stream_read` function.
+
+use boa_engine::{
+    native_function::NativeFunction, object::builtins::JsUint8Array, Context, JsNativeError, JsResult, JsString,
+    JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use ratchet_http::HttpStream;
+use std::cell::RefCell;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::JsExecutionError;
+
+/// Global JavaScript function reading from the open response stream
+const STREAM_READ_FN: &str = "__fetch_stream_read";
+
+/// Response stream captured by the native read function
+#[derive(Trace, Finalize)]
+struct StreamReader {
+    #[unsafe_ignore_trace]
+    stream: RefCell<HttpStream>,
+}
+
+/// Make `stream` readable from JavaScript through `__fetch_stream_read`
+pub fn register_stream_reader(context: &mut Context, stream: HttpStream) -> Result<(), JsExecutionError> {
+    let reader = StreamReader {
+        stream: RefCell::new(stream),
+    };
+
+    context
+        .register_global_callable(
+            JsString::from(STREAM_READ_FN),
+            1,
+            NativeFunction::from_copy_closure_with_captures(read_stream, reader),
+        )
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register stream reader: {}", e)))
+}
+
+/// `__fetch_stream_read(asText)`: the next chunk as a `Uint8Array` (`null` once
+/// exhausted), or with `asText` the remaining body decoded as UTF-8
+fn read_stream(_this: &JsValue, args: &[JsValue], reader: &StreamReader, context: &mut Context) -> JsResult<JsValue> {
+    let as_text = args.first().is_some_and(JsValue::to_boolean);
+    let mut stream = reader.stream.borrow_mut();
+
+    if as_text {
+        let mut rest = Vec::new();
+        while let Some(chunk) = next_chunk(&mut stream)? {
+            rest.extend(chunk);
+        }
+        return Ok(JsString::from(String::from_utf8_lossy(&rest).as_ref()).into());
+    }
+
+    match next_chunk(&mut stream)? {
+        Some(chunk) => Ok(JsUint8Array::from_iter(chunk, context)?.into()),
+        None => Ok(JsValue::null()),
+    }
+}
+
+/// Wait for the next chunk; JavaScript runs synchronously, so the read blocks the task
+///
+/// The read is driven by the runtime the response came from, from outside its
+/// worker threads. A current-thread runtime cannot make progress while the task
+/// blocks it, so streaming there fails instead of deadlocking.
+fn next_chunk(stream: &mut HttpStream) -> JsResult<Option<Vec<u8>>> {
+    let handle = Handle::try_current()
+        .map_err(|_| JsNativeError::error().with_message("Streaming fetch responses need a Tokio runtime"))?;
+    if handle.runtime_flavor() == RuntimeFlavor::CurrentThread {
+        return Err(JsNativeError::error()
+            .with_message("Streaming fetch responses need a multi-threaded Tokio runtime")
+            .into());
+    }
+
+    tokio::task::block_in_place(|| handle.block_on(stream.next_chunk()))
+        .map_err(|e| JsNativeError::error().with_message(e.to_string()).into())
+}
@@ -2,11 +2,17 @@
 //!
 //! This module provides functionality to load JavaScript tasks from filesystem
 //! directories, compatible with the ratchet task format.
+//!
+//! A `main.js` using `import` or `export` is loaded as an ES module together
+//! with every module it imports, see [`crate::modules`].
 
-use crate::{JsExecutionError, JsTask};
+use crate::modules::{import_specifiers, is_module_source, resolve_specifier};
+use crate::{JsExecutionError, JsTask, TaskModules, TimerClock};
 use serde_json::Value as JsonValue;
+use std::collections::VecDeque;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Errors that can occur when loading tasks
@@ -26,6 +32,9 @@ pub enum TaskLoadError {
 
     #[error("Missing required file: {0}")]
     MissingFile(String),
+
+    #[error("Invalid import: {0}")]
+    InvalidImport(String),
 }
 
 /// Task metadata structure  
@@ -53,6 +62,8 @@ pub struct FileSystemTask {
     pub output_schema: Option<JsonValue>,
     pub metadata: TaskMetadata,
     pub task_path: String,
+    /// `main.js` and the modules it imports, when the task is an ES module
+    pub modules: Option<TaskModules>,
 }
 
 impl FileSystemTask {
@@ -93,6 +104,13 @@ impl FileSystemTask {
 
         let js_content = fs::read_to_string(&main_js_path)?;
 
+        // Resolve the modules main.js imports, if it is an ES module
+        let modules = if is_module_source(&js_content) {
+            Some(Self::load_modules(dir, &js_content)?)
+        } else {
+            None
+        };
+
         // Load optional schema files
         let input_schema = Self::load_schema_file(dir, "input.schema.json")?;
         let output_schema = Self::load_schema_file(dir, "output.schema.json")?;
@@ -104,9 +122,43 @@ impl FileSystemTask {
             output_schema,
             metadata,
             task_path: dir.display().to_string(),
+            modules,
         })
     }
 
+    /// Load every module reachable from `main.js` through its imports
+    fn load_modules(dir: &Path, main_js: &str) -> Result<TaskModules, TaskLoadError> {
+        let root = dir.canonicalize()?;
+        let mut modules = TaskModules::new("main.js", main_js);
+        let mut pending = VecDeque::from(["main.js".to_string()]);
+
+        while let Some(referrer) = pending.pop_front() {
+            let source = modules.source(&referrer).unwrap_or_default().to_string();
+            for specifier in import_specifiers(&source) {
+                let path = resolve_specifier(&referrer, &specifier).map_err(TaskLoadError::InvalidImport)?;
+                if modules.contains(&path) {
+                    continue;
+                }
+
+                // Symlinks may still point outside the task directory
+                let file = root.join(&path).canonicalize().map_err(|_| {
+                    TaskLoadError::InvalidImport(format!("Module '{}' imported by {} not found", specifier, referrer))
+                })?;
+                if !file.starts_with(&root) || !file.is_file() {
+                    return Err(TaskLoadError::InvalidImport(format!(
+                        "Module '{}' imported by {} is not a file in the task directory",
+                        specifier, referrer
+                    )));
+                }
+
+                modules.insert(path.clone(), fs::read_to_string(&file)?);
+                pending.push_back(path);
+            }
+        }
+
+        Ok(modules)
+    }
+
     /// Load optional schema file
     fn load_schema_file(dir: &Path, filename: &str) -> Result<Option<JsonValue>, TaskLoadError> {
         let schema_path = dir.join(filename);
@@ -120,7 +172,19 @@ impl FileSystemTask {
     }
 
     /// Validate the task structure and JavaScript content
+    ///
+    /// The modules of an ES module task are compiled to check their syntax.
     pub fn validate(&self) -> Result<(), JsExecutionError> {
+        if let Some(modules) = &self.modules {
+            let mut context = boa_engine::Context::default();
+            for (path, source) in modules.iter() {
+                boa_engine::Module::parse(boa_engine::Source::from_bytes(source), None, &mut context).map_err(|e| {
+                    JsExecutionError::CompilationError(format!("Compilation of {} failed: {}", path, e))
+                })?;
+            }
+            return Ok(());
+        }
+
         // Basic validation - check if the task has executable content
         // Accept multiple formats:
         // 1. Named function: function main(input) { ... }
@@ -185,14 +249,42 @@ impl FileSystemTask {
 pub async fn load_and_execute_task<P: AsRef<Path>>(
     path: P,
     input_data: JsonValue,
+) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>> {
+    execute_on_task_thread(path, input_data, None).await
+}
+
+/// Load and execute a task from filesystem, driving its timers with `clock`
+pub async fn load_and_execute_task_with_clock<P: AsRef<Path>>(
+    path: P,
+    input_data: JsonValue,
+    clock: Arc<dyn TimerClock>,
+) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>> {
+    execute_on_task_thread(path, input_data, Some(clock)).await
+}
+
+/// Run a task from the filesystem on a thread with the stack its call depth limit needs
+async fn execute_on_task_thread<P: AsRef<Path>>(
+    path: P,
+    input_data: JsonValue,
+    clock: Option<Arc<dyn TimerClock>>,
 ) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>> {
     let fs_task = FileSystemTask::from_fs(path)?;
     fs_task.validate()?;
 
     let js_task = fs_task.to_js_task();
-    let runner = crate::JsTaskRunner::new();
-
-    let result = runner.execute_task(&js_task, input_data, None).await?;
+    let modules = fs_task.modules.clone();
+    let runtime = tokio::runtime::Handle::current();
+    let result = crate::run_on_task_thread(move || {
+        let mut runner = crate::JsTaskRunner::new();
+        if let Some(clock) = clock {
+            runner = runner.with_clock(clock);
+        }
+        if let Some(modules) = modules {
+            runner = runner.with_modules(modules);
+        }
+        runtime.block_on(async move { runner.execute_task(&js_task, input_data, None).await })
+    })
+    .await??;
     Ok(result)
 }
 
@@ -267,6 +359,91 @@ mod tests {
         assert!(task.validate().is_ok());
     }
 
+    fn create_module_task(dir: &Path, main_js: &str) -> std::io::Result<()> {
+        fs::write(
+            dir.join("metadata.json"),
+            r#"{ "label": "Module Task", "version": "1.0.0" }"#,
+        )?;
+        fs::write(dir.join("main.js"), main_js)?;
+
+        fs::create_dir_all(dir.join("lib"))?;
+        fs::write(
+            dir.join("lib/util.js"),
+            r#"
+            import { prefix } from '../shared/format.js';
+
+            export function label(value) {
+                return prefix + value;
+            }
+            "#,
+        )?;
+        fs::create_dir_all(dir.join("shared"))?;
+        fs::write(dir.join("shared/format.js"), "export const prefix = 'item-';")?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_and_execute_module_task() {
+        let temp_dir = TempDir::new().unwrap();
+        let task_dir = temp_dir.path().join("module_task");
+        fs::create_dir(&task_dir).unwrap();
+
+        create_module_task(
+            &task_dir,
+            r#"
+            import { label } from './lib/util.js';
+
+            export function main(input) {
+                return { labels: input.ids.map(label) };
+            }
+            "#,
+        )
+        .unwrap();
+
+        let task = FileSystemTask::from_fs(&task_dir).unwrap();
+        let modules = task.modules.as_ref().unwrap();
+        let paths: Vec<&str> = modules.iter().map(|(path, _)| path).collect();
+        assert_eq!(paths, vec!["lib/util.js", "main.js", "shared/format.js"]);
+        assert!(task.validate().is_ok());
+
+        let input = serde_json::json!({ "ids": [1, 2] });
+        let result = load_and_execute_task(&task_dir, input).await.unwrap();
+        assert_eq!(result["labels"], serde_json::json!(["item-1", "item-2"]));
+    }
+
+    #[test]
+    fn test_module_imports_stay_in_task_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let task_dir = temp_dir.path().join("module_task");
+        fs::create_dir(&task_dir).unwrap();
+        fs::write(temp_dir.path().join("secret.js"), "export const secret = 42;").unwrap();
+
+        create_module_task(
+            &task_dir,
+            r#"
+            import { secret } from '../secret.js';
+
+            export function main(input) {
+                return { secret: secret };
+            }
+            "#,
+        )
+        .unwrap();
+
+        let error = FileSystemTask::from_fs(&task_dir).unwrap_err();
+        assert!(matches!(error, TaskLoadError::InvalidImport(_)));
+
+        // Bare specifiers are not resolved either
+        fs::write(
+            task_dir.join("main.js"),
+            "import _ from 'lodash';\nexport function main(input) { return {}; }",
+        )
+        .unwrap();
+        let error = FileSystemTask::from_fs(&task_dir).unwrap_err();
+        assert!(matches!(error, TaskLoadError::InvalidImport(_)));
+    }
+
     #[tokio::test]
     async fn test_load_and_execute() {
         let temp_dir = TempDir::new().unwrap();
@@ -280,4 +457,35 @@ mod tests {
 
         assert_eq!(result["result"], 8);
     }
+
+    #[tokio::test]
+    async fn test_load_and_execute_deep_recursion_hits_call_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let task_dir = temp_dir.path().join("recursive_task");
+        fs::create_dir(&task_dir).unwrap();
+
+        // Recursing through a native callback uses the native stack at every level,
+        // which overflows a default-sized thread before the call depth limit is reached
+        create_module_task(
+            &task_dir,
+            r#"
+            function recurse(n) { return [n].map(function (m) { return recurse(m + 1); })[0]; }
+
+            export function main(input) {
+                return { depth: recurse(0) };
+            }
+            "#,
+        )
+        .unwrap();
+
+        let error = load_and_execute_task(&task_dir, serde_json::json!({}))
+            .await
+            .unwrap_err();
+        let error = error.downcast::<crate::js_task::JsTaskError>().unwrap();
+        assert!(matches!(
+            *error,
+            crate::js_task::JsTaskError::JsExecutionError(JsExecutionError::RuntimeError(ref message))
+                if message == crate::CALL_STACK_EXCEEDED
+        ));
+    }
 }
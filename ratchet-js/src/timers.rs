@@ -0,0 +1,417 @@
+//! Timer API for JavaScript tasks
+//!
+//! Tasks get `setTimeout`, `setInterval`, `clearTimeout` and `clearInterval`.
+//! Timers are queued on the JavaScript side while the task function runs. Once it
+//! returns, [`settle`] fires them in due order until the task's result is
+//! available: immediately for plain values, or once a returned promise settles, so
+//! `await new Promise(r => setTimeout(r, 100))` works. Timers still pending at that
+//! point are dropped.
+//!
+//! Waiting goes through a [`TimerClock`]. [`SystemClock`] sleeps on the tokio
+//! runtime, while [`ManualClock`] jumps straight to each timer's due time so that
+//! tests see the same timer order and timestamps without depending on wall-clock
+//! time. A [`RecordingClock`] keeps the readings of the clock it wraps, and a
+//! [`ReplayClock`] plays them back so a replayed execution fires its timers as the
+//! recorded one did.
+
+use boa_engine::{
+    native_function::NativeFunction, property::PropertyKey, Context, JsResult, JsString, JsValue, Source,
+};
+use boa_gc::{Finalize, Trace};
+use futures::future::BoxFuture;
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use crate::error_handling::{convert_js_error, convert_thrown_value};
+use crate::limits::ResourceMonitor;
+use crate::{JsErrorType, JsExecutionError};
+
+/// Default limit on how long an execution may wait for its timers
+pub const DEFAULT_TIMER_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Global JavaScript function returning the milliseconds elapsed since the execution started
+const CLOCK_NOW_FN: &str = "__ratchet_clock_now";
+
+/// Source of time for task timers
+pub trait TimerClock: fmt::Debug + Send + Sync {
+    /// Current time, measured from an arbitrary fixed origin
+    fn now(&self) -> Duration;
+
+    /// Wait until [`TimerClock::now`] reaches `target`
+    fn wait_until(&self, target: Duration) -> BoxFuture<'_, ()>;
+}
+
+/// Wall-clock time, waiting on the tokio runtime
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimerClock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn wait_until(&self, target: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(tokio::time::sleep(target.saturating_sub(self.now())))
+    }
+}
+
+/// Virtual time that only moves when a timer is waited for or the clock is advanced
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    now: Mutex<Duration>,
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl TimerClock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+
+    fn wait_until(&self, target: Duration) -> BoxFuture<'_, ()> {
+        let mut now = self.now.lock().unwrap();
+        *now = (*now).max(target);
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// Clock recording every reading of the clock it wraps, for playback by a [`ReplayClock`]
+#[derive(Debug)]
+pub struct RecordingClock {
+    inner: Arc<dyn TimerClock>,
+    readings: Mutex<Vec<Duration>>,
+}
+
+impl RecordingClock {
+    pub fn new(inner: Arc<dyn TimerClock>) -> Self {
+        Self {
+            inner,
+            readings: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Readings taken so far, in order
+    pub fn readings(&self) -> Vec<Duration> {
+        self.readings.lock().unwrap().clone()
+    }
+}
+
+impl TimerClock for RecordingClock {
+    fn now(&self) -> Duration {
+        let now = self.inner.now();
+        self.readings.lock().unwrap().push(now);
+        now
+    }
+
+    fn wait_until(&self, target: Duration) -> BoxFuture<'_, ()> {
+        self.inner.wait_until(target)
+    }
+}
+
+/// Clock returning the readings of a [`RecordingClock`] in order, without waiting
+///
+/// Once the readings run out the clock stays at the last one.
+#[derive(Debug)]
+pub struct ReplayClock {
+    readings: Mutex<VecDeque<Duration>>,
+    last: Mutex<Duration>,
+}
+
+impl ReplayClock {
+    pub fn new(readings: Vec<Duration>) -> Self {
+        Self {
+            readings: Mutex::new(readings.into()),
+            last: Mutex::new(Duration::ZERO),
+        }
+    }
+}
+
+impl TimerClock for ReplayClock {
+    fn now(&self) -> Duration {
+        let mut last = self.last.lock().unwrap();
+        match self.readings.lock().unwrap().pop_front() {
+            Some(reading) => *last = reading,
+            None => debug!("Replayed clock readings exhausted, holding at {:?}", *last),
+        }
+        *last
+    }
+
+    fn wait_until(&self, _target: Duration) -> BoxFuture<'_, ()> {
+        Box::pin(std::future::ready(()))
+    }
+}
+
+/// How task timers are driven
+#[derive(Debug, Clone)]
+pub struct TimerConfig {
+    /// Clock the timers wait on
+    pub clock: Arc<dyn TimerClock>,
+
+    /// Execution time after which waiting for a timer fails with a `TimeoutError`
+    pub timeout: Duration,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self {
+            clock: Arc::new(SystemClock::new()),
+            timeout: DEFAULT_TIMER_TIMEOUT,
+        }
+    }
+}
+
+/// Timer state of one execution, created by [`register_timers`]
+#[derive(Debug, Clone)]
+pub struct TimerSession {
+    clock: Arc<dyn TimerClock>,
+    origin: Duration,
+    timeout: Duration,
+}
+
+impl TimerSession {
+    /// Time elapsed since the execution started
+    fn elapsed(&self) -> Duration {
+        self.clock.now().saturating_sub(self.origin)
+    }
+}
+
+/// Clock captured by the native `__ratchet_clock_now` function
+#[derive(Trace, Finalize)]
+struct ClockHandle {
+    #[unsafe_ignore_trace]
+    session: TimerSession,
+}
+
+/// Register the timer functions in the JavaScript context, starting the execution's clock
+pub fn register_timers(context: &mut Context, config: &TimerConfig) -> Result<TimerSession, JsExecutionError> {
+    let session = TimerSession {
+        clock: config.clock.clone(),
+        origin: config.clock.now(),
+        timeout: config.timeout,
+    };
+
+    context
+        .register_global_callable(
+            JsString::from(CLOCK_NOW_FN),
+            0,
+            NativeFunction::from_copy_closure_with_captures(
+                clock_now,
+                ClockHandle {
+                    session: session.clone(),
+                },
+            ),
+        )
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register timer clock: {}", e)))?;
+
+    context
+        .eval(Source::from_bytes(
+            r#"
+        var __ratchet_timers = { nextId: 1, nextSeq: 1, pending: {} };
+        var __ratchet_settled = null;
+
+        function __ratchet_schedule(callback, delay, args, repeat) {
+            if (typeof callback !== 'function') {
+                throw new TypeError('Timer callback must be a function');
+            }
+            delay = Number(delay);
+            if (!(delay > 0)) {
+                delay = 0;
+            }
+            var id = __ratchet_timers.nextId++;
+            __ratchet_timers.pending[id] = {
+                callback: callback,
+                args: args,
+                delay: delay,
+                repeat: repeat,
+                due: __ratchet_clock_now() + delay,
+                seq: __ratchet_timers.nextSeq++
+            };
+            return id;
+        }
+
+        setTimeout = function(callback, delay) {
+            return __ratchet_schedule(callback, delay, Array.prototype.slice.call(arguments, 2), false);
+        };
+        setInterval = function(callback, delay) {
+            return __ratchet_schedule(callback, delay, Array.prototype.slice.call(arguments, 2), true);
+        };
+        clearTimeout = function(id) {
+            delete __ratchet_timers.pending[id];
+        };
+        clearInterval = clearTimeout;
+
+        // [id, due] of the earliest pending timer, ties going to the first scheduled
+        function __ratchet_next_timer() {
+            var next = null;
+            for (var id in __ratchet_timers.pending) {
+                var timer = __ratchet_timers.pending[id];
+                if (next === null || timer.due < next.due || (timer.due === next.due && timer.seq < next.seq)) {
+                    next = { id: Number(id), due: timer.due, seq: timer.seq };
+                }
+            }
+            return next === null ? null : [next.id, next.due];
+        }
+
+        function __ratchet_fire_timer(id) {
+            var timer = __ratchet_timers.pending[id];
+            if (!timer) {
+                return;
+            }
+            if (timer.repeat) {
+                // Intervals advance by at least 1ms so they cannot starve the timeout
+                timer.due += Math.max(timer.delay, 1);
+                timer.seq = __ratchet_timers.nextSeq++;
+            } else {
+                delete __ratchet_timers.pending[id];
+            }
+            timer.callback.apply(undefined, timer.args);
+        }
+
+        // Record the outcome of a returned promise; false for plain values
+        function __ratchet_track(value) {
+            __ratchet_settled = null;
+            if (value === null || typeof value !== 'object' || typeof value.then !== 'function') {
+                return false;
+            }
+            __ratchet_settled = { state: 'pending' };
+            value.then(
+                function(result) { __ratchet_settled = { state: 'fulfilled', value: result }; },
+                function(error) { __ratchet_settled = { state: 'rejected', error: error }; }
+            );
+            return true;
+        }
+        "#,
+        ))
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to register timers API: {}", e)))?;
+
+    Ok(session)
+}
+
+/// `__ratchet_clock_now()`: milliseconds elapsed since the execution started
+fn clock_now(_this: &JsValue, _args: &[JsValue], handle: &ClockHandle, _context: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::from(handle.session.elapsed().as_secs_f64() * 1000.0))
+}
+
+/// Fire pending timers until `value` is settled, returning the settled value
+///
+/// A promise resolves to its fulfilled value and a rejected promise fails with
+/// the rejection. A promise that can no longer settle because no timers remain is
+/// an error, as is waiting for a timer beyond the session's timeout. The heap
+/// limit of `monitor` is checked on entry and before each timer fires.
+pub async fn settle(
+    context: &mut Context,
+    value: JsValue,
+    session: &TimerSession,
+    monitor: &ResourceMonitor,
+) -> Result<JsValue, JsExecutionError> {
+    monitor.check()?;
+
+    context
+        .global_object()
+        .set(
+            PropertyKey::from(JsString::from("__temp_settle")),
+            value.clone(),
+            true,
+            context,
+        )
+        .map_err(|e| JsExecutionError::ExecutionError(format!("Failed to track result: {}", e)))?;
+    let is_promise = eval(context, "__ratchet_track(__temp_settle)")?.to_boolean();
+
+    if !is_promise {
+        discard_pending(context)?;
+        return Ok(value);
+    }
+
+    loop {
+        context.run_jobs();
+        monitor.check()?;
+
+        let state = eval(context, "__ratchet_settled.state")?
+            .to_string(context)
+            .map_err(|e| JsExecutionError::ExecutionError(e.to_string()))?
+            .to_std_string_escaped();
+
+        match state.as_str() {
+            "fulfilled" => {
+                discard_pending(context)?;
+                return eval(context, "__ratchet_settled.value");
+            }
+            "rejected" => {
+                let error = eval(context, "__ratchet_settled.error")?;
+                return Err(convert_thrown_value(&error, context));
+            }
+            _ => {}
+        }
+
+        let Some((id, due)) = next_timer(context)? else {
+            return Err(JsExecutionError::ExecutionError(
+                "Task promise never settled: no timers or jobs remain".to_string(),
+            ));
+        };
+
+        let due = Duration::from_secs_f64(due.max(0.0) / 1000.0);
+        if due > session.timeout || session.elapsed() > session.timeout {
+            return Err(JsExecutionError::TypedJsError(JsErrorType::TimeoutError(format!(
+                "Timer exceeds the execution timeout of {}ms",
+                session.timeout.as_millis()
+            ))));
+        }
+
+        session.clock.wait_until(session.origin + due).await;
+        debug!("Firing timer {} due at {:?}", id, due);
+        context
+            .eval(Source::from_bytes(&format!("__ratchet_fire_timer({})", id)))
+            .map_err(|e| convert_js_error(&e, context))?;
+    }
+}
+
+/// Id and due time in milliseconds of the earliest pending timer
+fn next_timer(context: &mut Context) -> Result<Option<(u64, f64)>, JsExecutionError> {
+    let next = eval(context, "JSON.stringify(__ratchet_next_timer())")?
+        .to_string(context)
+        .map_err(|e| JsExecutionError::ExecutionError(e.to_string()))?
+        .to_std_string_escaped();
+
+    serde_json::from_str(&next).map_err(|e| JsExecutionError::InvalidOutputFormat(e.to_string()))
+}
+
+/// Drop the timers still pending once the result is available
+fn discard_pending(context: &mut Context) -> Result<(), JsExecutionError> {
+    if next_timer(context)?.is_some() {
+        debug!("Discarding timers still pending after the task completed");
+        eval(context, "__ratchet_timers.pending = {}")?;
+    }
+    Ok(())
+}
+
+fn eval(context: &mut Context, code: &str) -> Result<JsValue, JsExecutionError> {
+    context
+        .eval(Source::from_bytes(code))
+        .map_err(|e| JsExecutionError::ExecutionError(e.to_string()))
+}
@@ -33,6 +33,26 @@ pub struct ExecutionContext {
 
     /// Optional job ID
     pub job_id: Option<String>,
+
+    /// Hosts the task's `fetch` may reach, on top of the HTTP client's own restrictions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_access: Option<ratchet_http::FetchAccessConfig>,
+}
+
+/// Host facilities provided to a task while it runs
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionOptions {
+    /// Clock and timeout driving the task's timers
+    pub timers: crate::TimerConfig,
+
+    /// Destination of the task's console output
+    pub console: crate::ConsoleCapture,
+
+    /// Heap and loop budgets the task may not exceed
+    pub limits: crate::ResourceLimits,
+
+    /// Modules of a task that runs as an ES module, `None` for classic scripts
+    pub modules: Option<crate::TaskModules>,
 }
 
 impl ExecutionContext {
@@ -42,6 +62,7 @@ impl ExecutionContext {
             task_id,
             task_version,
             job_id: None,
+            fetch_access: None,
         }
     }
 
@@ -49,4 +70,9 @@ impl ExecutionContext {
         self.job_id = Some(job_id);
         self
     }
+
+    pub fn with_fetch_access(mut self, fetch_access: ratchet_http::FetchAccessConfig) -> Self {
+        self.fetch_access = Some(fetch_access);
+        self
+    }
 }
@@ -0,0 +1,282 @@
+//! Dry-run validation of JavaScript tasks
+//!
+//! Compiles a task's `main.js`, and any modules it imports, through Boa without
+//! executing it, and checks that its input and output schemas are valid JSON
+//! Schema. Problems are collected into a [`ValidationResult`] rather than
+//! returned as the first error, so callers can report all of them with the file,
+//! line and column they refer to.
+
+use boa_engine::{builtins::promise::PromiseState, Context, Module, Script, Source};
+use ratchet_interfaces::registry::{ValidationError, ValidationResult};
+use serde_json::Value as JsonValue;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::error_handling::convert_thrown_value;
+use crate::modules::{is_module_source, TaskModuleLoader, TaskModules};
+use crate::task_loader::{FileSystemTask, TaskLoadError};
+
+/// Issue code for JavaScript that does not parse or compile
+pub const SYNTAX_ERROR: &str = "SYNTAX_ERROR";
+/// Issue code for imports that cannot be resolved or linked
+pub const IMPORT_ERROR: &str = "IMPORT_ERROR";
+/// Issue code for input or output schemas that are not valid JSON Schema
+pub const INVALID_SCHEMA: &str = "INVALID_SCHEMA";
+/// Issue code for task directories that cannot be loaded
+pub const LOAD_ERROR: &str = "LOAD_ERROR";
+
+/// Error for a Boa error message, located by the `line N, col M` it mentions
+fn engine_error(file: Option<&str>, code: &str, message: String) -> ValidationError {
+    let location = regex::Regex::new(r"line (\d+), col(?:umn)? (\d+)")
+        .unwrap()
+        .captures(&message)
+        .map(|captures| (captures[1].parse().ok(), captures[2].parse().ok()));
+
+    let mut error = ValidationError::new(file, code, message);
+    if let Some((line, column)) = location {
+        error.line = line;
+        error.column = column;
+    }
+    error
+}
+
+/// Validate the task in `path` without executing it
+pub fn validate_task_directory<P: AsRef<Path>>(path: P) -> ValidationResult {
+    match FileSystemTask::from_fs(path) {
+        Ok(task) => validate_task(&task),
+        Err(e) => {
+            let mut result = ValidationResult::new();
+            let code = match e {
+                TaskLoadError::InvalidImport(_) => IMPORT_ERROR,
+                _ => LOAD_ERROR,
+            };
+            result.add_error(ValidationError::new(None, code, e.to_string()));
+            result
+        }
+    }
+}
+
+/// Validate a loaded task without executing it
+pub fn validate_task(task: &FileSystemTask) -> ValidationResult {
+    let mut result = validate_task_source(&task.content, task.modules.as_ref());
+    for (file, schema) in [
+        ("input.schema.json", &task.input_schema),
+        ("output.schema.json", &task.output_schema),
+    ] {
+        if let Some(schema) = schema {
+            validate_task_schema(file, schema, &mut result);
+        }
+    }
+    result
+}
+
+/// Compile a task's source without executing it
+///
+/// With `modules`, the entry module and everything it imports are parsed and
+/// linked. Otherwise `source` is parsed as an ES module when it imports or
+/// exports anything, and compiled as a classic script when it doesn't.
+pub fn validate_task_source(source: &str, modules: Option<&TaskModules>) -> ValidationResult {
+    let mut result = ValidationResult::new();
+    if source.trim().is_empty() {
+        result.add_error(ValidationError::new(
+            Some("main.js"),
+            SYNTAX_ERROR,
+            "Task content cannot be empty",
+        ));
+        return result;
+    }
+
+    match modules {
+        Some(modules) => compile_modules(modules, &mut result),
+        None if is_module_source(source) => parse_module(source, &mut result),
+        None => compile_script(source, &mut result),
+    }
+    result
+}
+
+/// Parse a lone module; its imports are not available to link against
+fn parse_module(source: &str, result: &mut ValidationResult) {
+    let mut context = Context::default();
+    if let Err(e) = Module::parse(Source::from_bytes(source), None, &mut context) {
+        result.add_error(engine_error(Some("main.js"), SYNTAX_ERROR, e.to_string()));
+    }
+}
+
+fn compile_script(source: &str, result: &mut ValidationResult) {
+    let mut context = Context::default();
+    let compiled = Script::parse(Source::from_bytes(source), None, &mut context)
+        .map_err(|e| e.to_string())
+        .and_then(|script| script.codeblock(&mut context).map(|_| ()).map_err(|e| e.to_string()));
+
+    if let Err(message) = compiled {
+        result.add_error(engine_error(Some("main.js"), SYNTAX_ERROR, message));
+    }
+}
+
+fn compile_modules(modules: &TaskModules, result: &mut ValidationResult) {
+    // Parse every module on its own first, so syntax errors name their file
+    let mut context = Context::default();
+    for (path, source) in modules.iter() {
+        if let Err(e) = Module::parse(Source::from_bytes(source), None, &mut context) {
+            result.add_error(engine_error(Some(path), SYNTAX_ERROR, e.to_string()));
+        }
+    }
+    if !result.valid {
+        return;
+    }
+
+    // Load and link the graph to catch missing modules and bindings
+    let loader = Rc::new(TaskModuleLoader::new(modules.clone()));
+    let mut context = match Context::builder().module_loader(loader.clone()).build() {
+        Ok(context) => context,
+        Err(e) => {
+            result.add_error(ValidationError::new(None, IMPORT_ERROR, e.to_string()));
+            return;
+        }
+    };
+    let entry = match loader.parse_entry(&mut context) {
+        Ok(entry) => entry,
+        Err(e) => {
+            result.add_error(ValidationError::new(Some(modules.entry()), SYNTAX_ERROR, e.to_string()));
+            return;
+        }
+    };
+
+    let promise = entry.load(&mut context);
+    context.run_jobs();
+    let linked = match promise.state() {
+        PromiseState::Fulfilled(_) => entry.link(&mut context).map_err(|e| e.to_string()),
+        PromiseState::Rejected(error) => Err(convert_thrown_value(&error, &mut context).to_string()),
+        PromiseState::Pending => Err("Module imports did not finish loading".to_string()),
+    };
+
+    if let Err(message) = linked {
+        result.add_error(engine_error(None, IMPORT_ERROR, message));
+    }
+}
+
+/// Check that `schema`, read from `file`, is itself a valid JSON Schema
+pub fn validate_task_schema(file: &str, schema: &JsonValue, result: &mut ValidationResult) {
+    if let Err(e) = ratchet_core::validation::validate_schema(schema) {
+        result.add_error(ValidationError::new(Some(file), INVALID_SCHEMA, e.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_task(dir: &Path, main_js: &str, input_schema: &str) {
+        fs::write(
+            dir.join("metadata.json"),
+            r#"{ "label": "Validated Task", "version": "1.0.0" }"#,
+        )
+        .unwrap();
+        fs::write(dir.join("main.js"), main_js).unwrap();
+        fs::write(dir.join("input.schema.json"), input_schema).unwrap();
+    }
+
+    #[test]
+    fn test_valid_task_passes() {
+        let temp_dir = TempDir::new().unwrap();
+        create_task(
+            temp_dir.path(),
+            "function main(input) { return { value: input.value * 2 }; }",
+            r#"{ "type": "object", "properties": { "value": { "type": "number" } } }"#,
+        );
+
+        let result = validate_task_directory(temp_dir.path());
+        assert!(result.valid, "unexpected issues: {:?}", result.errors);
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_syntax_error_is_located() {
+        let temp_dir = TempDir::new().unwrap();
+        create_task(
+            temp_dir.path(),
+            "function main(input) {\n    return { value: input.value * };\n}",
+            r#"{ "type": "object" }"#,
+        );
+
+        let result = validate_task_directory(temp_dir.path());
+        assert!(!result.valid);
+        let issue = &result.errors[0];
+        assert_eq!(issue.code, SYNTAX_ERROR);
+        assert_eq!(issue.field.as_deref(), Some("main.js"));
+        assert_eq!(issue.line, Some(2));
+        assert!(issue.column.is_some());
+    }
+
+    #[test]
+    fn test_task_is_not_executed() {
+        let temp_dir = TempDir::new().unwrap();
+        create_task(
+            temp_dir.path(),
+            "throw new Error('top-level code ran');\nfunction main(input) { return input; }",
+            r#"{ "type": "object" }"#,
+        );
+
+        assert!(validate_task_directory(temp_dir.path()).valid);
+    }
+
+    #[test]
+    fn test_invalid_schema_is_reported() {
+        let temp_dir = TempDir::new().unwrap();
+        create_task(
+            temp_dir.path(),
+            "function main(input) { return input; }",
+            r#"{ "type": 12 }"#,
+        );
+
+        let result = validate_task_directory(temp_dir.path());
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].code, INVALID_SCHEMA);
+        assert_eq!(result.errors[0].field.as_deref(), Some("input.schema.json"));
+    }
+
+    #[test]
+    fn test_module_errors_name_their_file() {
+        let temp_dir = TempDir::new().unwrap();
+        create_task(
+            temp_dir.path(),
+            "import { double } from './lib/math.js';\nexport function main(input) { return double(input); }",
+            r#"{ "type": "object" }"#,
+        );
+        fs::create_dir(temp_dir.path().join("lib")).unwrap();
+        fs::write(
+            temp_dir.path().join("lib/math.js"),
+            "export function double(x) {\n  return x *;\n}",
+        )
+        .unwrap();
+
+        let result = validate_task_directory(temp_dir.path());
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].field.as_deref(), Some("lib/math.js"));
+        assert_eq!(result.errors[0].line, Some(2));
+
+        // A binding the imported module does not export fails to link
+        fs::write(
+            temp_dir.path().join("lib/math.js"),
+            "export function triple(x) { return x * 3; }",
+        )
+        .unwrap();
+        let result = validate_task_directory(temp_dir.path());
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].code, IMPORT_ERROR);
+    }
+
+    #[test]
+    fn test_module_source_is_parsed_as_module() {
+        let source = "import { double } from './lib/math.js';\nexport function main(input) { return double(input); }";
+        let result = validate_task_source(source, None);
+        assert!(result.valid, "unexpected issues: {:?}", result.errors);
+
+        let result = validate_task_source("export function main(input) {\n  return input *;\n}", None);
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].code, SYNTAX_ERROR);
+        assert_eq!(result.errors[0].line, Some(2));
+    }
+}
@@ -0,0 +1,85 @@
+//! Severity-based alerting hooks
+//!
+//! A [`SeverityHook`] runs a user-supplied async callback for every logged
+//! event whose error is at or above a severity threshold, e.g. to page someone
+//! on [`ErrorSeverity::Critical`]. Hooks are registered with
+//! [`LoggerBuilder::on_severity`](crate::LoggerBuilder::on_severity) and kept
+//! apart from sinks so alerting never has to parse log output.
+//!
+//! Callbacks run in the background: the logging call never waits on them, and
+//! a callback that fails or panics is reported on stderr without affecting
+//! later log events.
+
+use crate::background::spawn_background;
+use crate::{ErrorInfo, ErrorSeverity, LogEvent};
+use std::future::Future;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Future returned by an alert callback
+pub type AlertFuture = Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send>>;
+
+type AlertCallback = dyn Fn(LogEvent, ErrorInfo) -> AlertFuture + Send + Sync;
+
+/// Callback fired for events at or above a severity threshold
+#[derive(Clone)]
+pub struct SeverityHook {
+    threshold: ErrorSeverity,
+    callback: Arc<AlertCallback>,
+}
+
+impl SeverityHook {
+    pub fn new<F, Fut>(threshold: ErrorSeverity, callback: F) -> Self
+    where
+        F: Fn(LogEvent, ErrorInfo) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        Self {
+            threshold,
+            callback: Arc::new(move |event, error| Box::pin(callback(event, error))),
+        }
+    }
+
+    pub fn threshold(&self) -> ErrorSeverity {
+        self.threshold
+    }
+
+    /// Fire the callback for a matching event without waiting for it
+    ///
+    /// Runs on the current tokio runtime, or on a dedicated thread when logging
+    /// from outside one.
+    pub fn fire(&self, event: &LogEvent) {
+        let Some(error) = event.error.clone().filter(|error| error.severity >= self.threshold) else {
+            return;
+        };
+
+        let future = match catch_unwind(AssertUnwindSafe(|| (self.callback)(event.clone(), error))) {
+            Ok(future) => future,
+            Err(_) => {
+                eprintln!("Alert callback panicked for event: {}", event.message);
+                return;
+            }
+        };
+        let message = event.message.clone();
+
+        spawn_background("ratchet-alert", "alert callback", Self::run(future, message));
+    }
+
+    async fn run(future: AlertFuture, message: String) {
+        // Spawn so a panicking callback is contained in its own task
+        match tokio::spawn(future).await {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => eprintln!("Alert callback failed for event '{}': {}", message, e),
+            Err(e) => eprintln!("Alert callback panicked for event '{}': {}", message, e),
+        }
+    }
+}
+
+impl std::fmt::Debug for SeverityHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SeverityHook")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
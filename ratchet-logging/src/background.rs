@@ -0,0 +1,32 @@
+//! Background tasks started by sinks and hooks
+//!
+//! Loggers are often built before a tokio runtime exists, e.g. at the top of
+//! `main`, so work that needs a runtime falls back to a dedicated thread.
+
+use std::future::Future;
+
+/// Run `future` on the current tokio runtime, or on a new thread named
+/// `thread_name` with its own runtime when called outside of one
+///
+/// Failures to start the task are reported on stderr, naming it as `task`.
+pub(crate) fn spawn_background<F>(thread_name: &str, task: &'static str, future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        handle.spawn(future);
+        return;
+    }
+
+    let spawned = std::thread::Builder::new()
+        .name(thread_name.to_string())
+        .spawn(
+            move || match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime.block_on(future),
+                Err(e) => eprintln!("Failed to create runtime for {}: {}", task, e),
+            },
+        );
+    if let Err(e) = spawned {
+        eprintln!("Failed to spawn {} thread: {}", task, e);
+    }
+}
@@ -1,7 +1,16 @@
-use super::enrichment::{ExecutionContextEnricher, ProcessEnricher, SystemEnricher, TaskContextEnricher};
-use super::sinks::{BufferedSink, ConsoleSink, FileSink};
+use super::enrichment::{
+    ExecutionContextEnricher, ProcessEnricher, RedactionEnricher, SystemEnricher, TaskContextEnricher,
+};
+pub use super::format::LogFormat;
+#[cfg(feature = "patterns")]
+use super::patterns::{ErrorPatternMatcher, PatternSampling};
+use super::sinks::{BufferedSink, ConsoleSink, FileRotation, FileSink, RotationInterval};
+#[cfg(feature = "otlp")]
+use super::sinks::{OtlpConfig, OtlpSink};
 use super::{logger::LogSink, LogLevel, LoggerBuilder};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "patterns")]
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -9,7 +18,7 @@ use std::time::Duration;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct LoggingConfig {
-    /// Minimum log level
+    /// Minimum log level for all sinks, also used by sinks without their own level
     #[serde(default = "default_log_level")]
     pub level: LogLevel,
 
@@ -28,28 +37,33 @@ pub struct LoggingConfig {
     /// Sampling configuration
     #[serde(default)]
     pub sampling: SamplingConfig,
-}
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum LogFormat {
-    Json,
-    Pretty,
+    /// Masking of sensitive field values
+    #[serde(default)]
+    pub redaction: RedactionConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum SinkConfig {
     Console {
-        #[serde(default = "default_log_level")]
-        level: LogLevel,
+        /// Minimum level written by this sink (defaults to the global level)
+        #[serde(default)]
+        level: Option<LogLevel>,
+        /// Output format (defaults to `pretty`, or `jsonl` with `use_json`)
+        #[serde(default)]
+        format: Option<LogFormat>,
         #[serde(default)]
         use_json: bool,
     },
     File {
         path: PathBuf,
-        #[serde(default = "default_log_level")]
-        level: LogLevel,
+        /// Minimum level written by this sink (defaults to the global level)
+        #[serde(default)]
+        level: Option<LogLevel>,
+        /// Output format (defaults to `jsonl`)
+        #[serde(default)]
+        format: Option<LogFormat>,
         #[serde(default)]
         rotation: Option<RotationConfig>,
         #[serde(default)]
@@ -57,23 +71,68 @@ pub enum SinkConfig {
     },
     Database {
         table: String,
+        /// Minimum level written by this sink (defaults to the global level)
+        #[serde(default)]
+        level: Option<LogLevel>,
         #[serde(default)]
         buffer_size: usize,
         #[serde(default)]
         flush_interval: Duration,
     },
+    /// Export to an OpenTelemetry collector over OTLP/HTTP
+    #[cfg(feature = "otlp")]
+    Otlp {
+        /// Minimum level exported by this sink (defaults to the global level)
+        #[serde(default)]
+        level: Option<LogLevel>,
+        #[serde(flatten)]
+        otlp: OtlpConfig,
+    },
+}
+
+impl SinkConfig {
+    /// Minimum level configured for this sink, if any
+    pub fn level(&self) -> Option<LogLevel> {
+        match self {
+            SinkConfig::Console { level, .. } | SinkConfig::File { level, .. } | SinkConfig::Database { level, .. } => {
+                *level
+            }
+            #[cfg(feature = "otlp")]
+            SinkConfig::Otlp { level, .. } => *level,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RotationConfig {
     /// Maximum file size before rotation (e.g., "100MB")
-    pub max_size: String,
-    /// Maximum age before rotation (e.g., "7d")
+    #[serde(default)]
+    pub max_size: Option<String>,
+    /// Rotate when the local hour or date changes
+    #[serde(default)]
+    pub interval: Option<RotationInterval>,
+    /// Maximum age of the active file before rotation (e.g., "7d")
     #[serde(default)]
     pub max_age: Option<String>,
     /// Maximum number of rotated files to keep
     #[serde(default)]
     pub max_files: Option<usize>,
+    /// Gzip rotated files
+    #[serde(default)]
+    pub compress: bool,
+}
+
+impl RotationConfig {
+    /// Convert to the file sink rotation policy
+    pub fn to_policy(&self) -> Result<FileRotation, ConfigError> {
+        Ok(FileRotation {
+            max_size: self.max_size.as_deref().map(parse_size).transpose()?,
+            interval: self.interval,
+            max_age: self.max_age.as_deref().map(parse_duration).transpose()?,
+            max_files: self.max_files,
+            compress: self.compress,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +152,15 @@ pub struct EnrichmentConfig {
     pub execution_context: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactionConfig {
+    /// Mask sensitive field values before they reach any sink
+    pub enabled: bool,
+    /// Field name patterns to mask, `*` matching any characters (case-insensitive)
+    pub fields: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct SamplingConfig {
@@ -106,6 +174,9 @@ pub struct SamplingConfig {
     pub debug_rate: f64,
     /// Sample rate for trace level logs
     pub trace_rate: f64,
+    /// Rate limits for errors matching built-in patterns, keyed by pattern id
+    #[cfg(feature = "patterns")]
+    pub patterns: HashMap<String, PatternSampling>,
 }
 
 impl Default for LoggingConfig {
@@ -114,11 +185,13 @@ impl Default for LoggingConfig {
             level: default_log_level(),
             format: default_format(),
             sinks: vec![SinkConfig::Console {
-                level: LogLevel::Info,
+                level: None,
+                format: None,
                 use_json: false,
             }],
             enrichment: EnrichmentConfig::default(),
             sampling: SamplingConfig::default(),
+            redaction: RedactionConfig::default(),
         }
     }
 }
@@ -134,6 +207,18 @@ impl Default for EnrichmentConfig {
     }
 }
 
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fields: ["password", "secret", "*_token", "api_key", "authorization"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
 impl Default for SamplingConfig {
     fn default() -> Self {
         Self {
@@ -142,11 +227,21 @@ impl Default for SamplingConfig {
             info_rate: 1.0,
             debug_rate: 0.1,
             trace_rate: 0.01,
+            #[cfg(feature = "patterns")]
+            patterns: HashMap::new(),
         }
     }
 }
 
 impl LoggingConfig {
+    /// Effective minimum level for a sink
+    ///
+    /// Sinks without their own level use the global level. The global level is
+    /// applied before any sink, so a sink level below it has no effect.
+    pub fn sink_level(&self, sink: &SinkConfig) -> LogLevel {
+        sink.level().unwrap_or(self.level).max(self.level)
+    }
+
     /// Build a logger from this configuration
     pub fn build_logger(&self) -> Result<Arc<dyn super::StructuredLogger>, ConfigError> {
         let mut builder = LoggerBuilder::new().with_min_level(self.level);
@@ -170,6 +265,16 @@ impl LoggingConfig {
         if self.enrichment.execution_context {
             builder = builder.add_enricher(Box::new(ExecutionContextEnricher::new()));
         }
+        // Redact last so fields added by other enrichers are covered
+        if self.redaction.enabled && !self.redaction.fields.is_empty() {
+            builder = builder.add_enricher(Box::new(RedactionEnricher::new(&self.redaction.fields)));
+        }
+
+        #[cfg(feature = "patterns")]
+        if !self.sampling.patterns.is_empty() {
+            let matcher = ErrorPatternMatcher::with_defaults().with_pattern_sampling(&self.sampling.patterns);
+            builder = builder.with_error_sampling(Arc::new(matcher));
+        }
 
         // TODO: Add sampling wrapper when implemented
 
@@ -178,25 +283,31 @@ impl LoggingConfig {
 
     fn create_sink(&self, config: &SinkConfig) -> Result<Arc<dyn LogSink>, ConfigError> {
         match config {
-            SinkConfig::Console { level, use_json } => {
-                let mut sink = ConsoleSink::new(*level);
-                if *use_json {
+            SinkConfig::Console { format, use_json, .. } => {
+                let mut sink = ConsoleSink::new(self.sink_level(config));
+                if let Some(format) = format {
+                    sink = sink.with_format(*format);
+                } else if *use_json {
                     sink = sink.json_format();
                 }
                 Ok(Arc::new(sink))
             }
             SinkConfig::File {
                 path,
-                level,
+                format,
                 rotation,
                 buffered,
+                ..
             } => {
-                let mut file_sink = FileSink::new(path, *level)
+                let mut file_sink = FileSink::new(path, self.sink_level(config))
                     .map_err(|e| ConfigError::SinkCreation(format!("Failed to create file sink: {}", e)))?;
 
+                if let Some(format) = format {
+                    file_sink = file_sink.with_format(*format);
+                }
+
                 if let Some(rotation_config) = rotation {
-                    let max_size = parse_size(&rotation_config.max_size)?;
-                    file_sink = file_sink.with_rotation(max_size);
+                    file_sink = file_sink.with_rotation_policy(rotation_config.to_policy()?);
                 }
 
                 let sink: Arc<dyn LogSink> = Arc::new(file_sink);
@@ -214,6 +325,8 @@ impl LoggingConfig {
             SinkConfig::Database { .. } => Err(ConfigError::NotImplemented(
                 "Database sink not yet implemented".to_string(),
             )),
+            #[cfg(feature = "otlp")]
+            SinkConfig::Otlp { otlp, .. } => Ok(Arc::new(OtlpSink::new(otlp.clone(), self.sink_level(config)))),
         }
     }
 }
@@ -226,6 +339,9 @@ pub enum ConfigError {
     #[error("Invalid size format: {0}")]
     InvalidSize(String),
 
+    #[error("Invalid duration format: {0}")]
+    InvalidDuration(String),
+
     #[error("Not implemented: {0}")]
     NotImplemented(String),
 }
@@ -247,6 +363,11 @@ fn default_flush_interval() -> Duration {
     Duration::from_secs(5)
 }
 
+fn parse_duration(duration: &str) -> Result<Duration, ConfigError> {
+    humantime_serde::re::humantime::parse_duration(duration.trim())
+        .map_err(|_| ConfigError::InvalidDuration(duration.to_string()))
+}
+
 fn parse_size(size_str: &str) -> Result<u64, ConfigError> {
     let size_str = size_str.trim().to_uppercase();
 
@@ -292,6 +413,8 @@ mod tests {
         assert_eq!(config.level, LogLevel::Info);
         assert!(matches!(config.format, LogFormat::Pretty));
         assert_eq!(config.sinks.len(), 1);
+        assert!(!config.redaction.enabled);
+        assert!(config.redaction.fields.contains(&"*_token".to_string()));
     }
 
     #[test]
@@ -321,5 +444,133 @@ sampling:
         assert_eq!(config.level, LogLevel::Debug);
         assert_eq!(config.sinks.len(), 2);
         assert_eq!(config.sampling.info_rate, 0.5);
+        assert_eq!(config.sinks[0].level(), Some(LogLevel::Warn));
+    }
+
+    #[test]
+    fn test_pattern_sampling_config() {
+        let config: LoggingConfig = serde_yaml::from_str(
+            r#"
+sampling:
+  patterns:
+    db_connection_timeout:
+      max_events: 5
+      interval: 30s
+    rate_limited: {}
+"#,
+        )
+        .unwrap();
+
+        let patterns = &config.sampling.patterns;
+        assert_eq!(
+            patterns["db_connection_timeout"],
+            PatternSampling::new(5, Duration::from_secs(30))
+        );
+        assert_eq!(patterns["rate_limited"], PatternSampling::default());
+        assert!(config.build_logger().is_ok());
+        assert!(LoggingConfig::default().sampling.patterns.is_empty());
+    }
+
+    #[cfg(feature = "otlp")]
+    #[tokio::test]
+    async fn test_otlp_sink_config() {
+        let config: LoggingConfig = serde_yaml::from_str(
+            r#"
+sinks:
+  - type: otlp
+    level: warn
+    endpoint: http://collector:4318
+    service_name: ratchet-worker
+    headers:
+      authorization: Bearer token
+"#,
+        )
+        .unwrap();
+
+        let SinkConfig::Otlp { level, otlp } = &config.sinks[0] else {
+            panic!("expected an otlp sink");
+        };
+        assert_eq!(*level, Some(LogLevel::Warn));
+        assert_eq!(otlp.logs_url(), "http://collector:4318/v1/logs");
+        assert_eq!(otlp.service_name, "ratchet-worker");
+        assert_eq!(otlp.headers["authorization"], "Bearer token");
+        assert_eq!(otlp.batch_size, OtlpConfig::default().batch_size);
+        assert!(config.build_logger().is_ok());
+    }
+
+    #[test]
+    fn test_sink_formats() {
+        let config: LoggingConfig = serde_yaml::from_str(
+            r#"
+sinks:
+  - type: console
+    format: compact
+  - type: console
+    use_json: true
+  - type: console
+  - type: file
+    path: /tmp/ratchet.log
+    format: pretty
+  - type: file
+    path: /tmp/ratchet.log
+"#,
+        )
+        .unwrap();
+
+        let formats: Vec<Option<LogFormat>> = config
+            .sinks
+            .iter()
+            .map(|sink| match sink {
+                SinkConfig::Console { format, .. } | SinkConfig::File { format, .. } => *format,
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            formats,
+            vec![Some(LogFormat::Compact), None, None, Some(LogFormat::Pretty), None]
+        );
+    }
+
+    #[test]
+    fn test_rotation_config() {
+        let rotation: RotationConfig = serde_yaml::from_str(
+            r#"
+max_size: 10MB
+interval: daily
+max_age: 7d
+max_files: 7
+compress: true
+"#,
+        )
+        .unwrap();
+
+        let policy = rotation.to_policy().unwrap();
+        assert_eq!(policy.max_size, Some(10 * 1024 * 1024));
+        assert_eq!(policy.interval, Some(RotationInterval::Daily));
+        assert_eq!(policy.max_age, Some(Duration::from_secs(7 * 24 * 3600)));
+        assert_eq!(policy.max_files, Some(7));
+        assert!(policy.compress);
+
+        let daily_only: RotationConfig = serde_yaml::from_str("interval: daily").unwrap();
+        assert_eq!(daily_only.to_policy().unwrap().max_size, None);
+    }
+
+    #[test]
+    fn test_sink_level_defaults_to_global_level() {
+        let config: LoggingConfig = serde_yaml::from_str(
+            r#"
+level: debug
+sinks:
+  - type: console
+  - type: console
+    level: error
+  - type: console
+    level: trace
+"#,
+        )
+        .unwrap();
+
+        let levels: Vec<LogLevel> = config.sinks.iter().map(|sink| config.sink_level(sink)).collect();
+        assert_eq!(levels, vec![LogLevel::Debug, LogLevel::Error, LogLevel::Debug]);
     }
 }
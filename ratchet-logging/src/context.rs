@@ -1,6 +1,24 @@
+//! Trace context carried across async code
+//!
+//! A [`LogContext`] installed with [`LogContext::scope`] is task-local: it is
+//! visible to everything awaited inside the scope, but not to tasks started
+//! with `tokio::spawn`, which begin without a context. Use [`LogContext::spawn`]
+//! (or wrap the future with [`LogContext::propagate`]) to carry the caller's
+//! context, and with it the trace id and fields such as `request_id`, into
+//! background work.
+//!
+//! Loggers without an explicit context (see `StructuredLogger::with_context`)
+//! apply the current task-local context to each event before the enrichers
+//! run, so enrichers see the propagated trace id and fields and can add to them.
+
 use serde::Serialize;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::task::futures::TaskLocalFuture;
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 tokio::task_local! {
@@ -49,9 +67,12 @@ impl LogContext {
 
     /// Get the current context from task-local storage
     pub fn current() -> Self {
-        CURRENT_CONTEXT
-            .try_with(|ctx| ctx.clone())
-            .unwrap_or_else(|_| Self::new())
+        Self::try_current().unwrap_or_default()
+    }
+
+    /// Get the current context, if a scope is active
+    pub fn try_current() -> Option<Self> {
+        CURRENT_CONTEXT.try_with(|ctx| ctx.clone()).ok()
     }
 
     /// Run a future with this context as current
@@ -62,6 +83,27 @@ impl LogContext {
         CURRENT_CONTEXT.scope(self, f).await
     }
 
+    /// Wrap a future so it runs with the caller's current context
+    ///
+    /// The context is captured when this is called, not when the future is
+    /// first polled. Outside of any scope a fresh context is captured, so all
+    /// events logged by the future still share one trace id.
+    pub fn propagate<F>(future: F) -> ContextScope<F>
+    where
+        F: Future,
+    {
+        future.with_context(Self::current())
+    }
+
+    /// Spawn a task on the tokio runtime that runs with the caller's current context
+    pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        tokio::spawn(Self::propagate(future))
+    }
+
     /// Generate a short span ID
     fn generate_span_id() -> String {
         // Use first 8 bytes of UUID for shorter span IDs
@@ -88,30 +130,61 @@ where
 {
     fn with_context(self, context: LogContext) -> ContextScope<Self> {
         ContextScope {
-            future: self,
-            _context: context,
+            inner: CURRENT_CONTEXT.scope(context, self),
         }
     }
 }
 
-/// Future wrapper that provides log context
+/// Future wrapper that installs a log context while the inner future is polled
 pub struct ContextScope<F> {
-    future: F,
-    _context: LogContext,
+    inner: TaskLocalFuture<LogContext, F>,
 }
 
-impl<F> std::future::Future for ContextScope<F>
+impl<F> Future for ContextScope<F>
 where
-    F: std::future::Future,
+    F: Future,
 {
     type Output = F::Output;
 
-    fn poll(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> std::task::Poll<Self::Output> {
-        let this = unsafe { self.get_unchecked_mut() };
-        let future = unsafe { std::pin::Pin::new_unchecked(&mut this.future) };
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `inner` is structurally pinned, it is never moved out of `self`
+        let inner = unsafe { self.map_unchecked_mut(|scope| &mut scope.inner) };
+        inner.poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spawned_task_inherits_context() {
+        let context = LogContext::new().with_field("request_id", "req-42");
+        let trace_id = context.trace_id.clone();
+
+        let (plain, propagated) = context
+            .scope(async {
+                let plain = tokio::spawn(async { LogContext::try_current() });
+                let propagated = LogContext::spawn(async {
+                    tokio::task::yield_now().await;
+                    LogContext::current()
+                });
+                (plain.await.unwrap(), propagated.await.unwrap())
+            })
+            .await;
+
+        assert!(plain.is_none());
+        assert_eq!(propagated.trace_id, trace_id);
+        assert_eq!(propagated.fields["request_id"], "req-42");
+        assert!(LogContext::try_current().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_with_context_installs_context() {
+        let context = LogContext::new().with_field("job_id", 7);
+        let span_id = context.span_id.clone();
 
-        // This would need proper implementation with task-local storage
-        // For now, just poll the inner future
-        future.poll(cx)
+        let current = async { LogContext::try_current() }.with_context(context).await;
+        assert_eq!(current.map(|ctx| ctx.span_id), Some(span_id));
     }
 }
@@ -1,5 +1,6 @@
 use super::LogEvent;
-use serde_json::json;
+use regex::RegexSet;
+use serde_json::{json, Value as JsonValue};
 use sysinfo::{Pid, System};
 
 /// Trait for log enrichment
@@ -182,3 +183,107 @@ impl Enricher for ExecutionContextEnricher {
         }
     }
 }
+
+/// Replacement for redacted field values
+pub const REDACTED: &str = "***REDACTED***";
+
+/// Enricher that masks the values of sensitive fields
+///
+/// Field names are matched case-insensitively against glob patterns where `*`
+/// matches any run of characters, e.g. `password` or `*_token`. Nested JSON
+/// objects and arrays are searched as well. Add it after other enrichers so
+/// fields they add are covered too.
+pub struct RedactionEnricher {
+    patterns: RegexSet,
+}
+
+impl RedactionEnricher {
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let patterns = patterns.into_iter().map(|pattern| {
+            let glob = pattern
+                .as_ref()
+                .split('*')
+                .map(regex::escape)
+                .collect::<Vec<_>>()
+                .join(".*");
+            format!("(?i)^{}$", glob)
+        });
+
+        Self {
+            // Escaped globs always compile
+            patterns: RegexSet::new(patterns).expect("redaction patterns are escaped"),
+        }
+    }
+
+    fn is_sensitive(&self, name: &str) -> bool {
+        self.patterns.is_match(name)
+    }
+
+    fn redact(&self, value: &mut JsonValue) {
+        match value {
+            JsonValue::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.is_sensitive(key) {
+                        *value = json!(REDACTED);
+                    } else {
+                        self.redact(value);
+                    }
+                }
+            }
+            JsonValue::Array(items) => {
+                for item in items {
+                    self.redact(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Enricher for RedactionEnricher {
+    fn enrich(&self, event: &mut LogEvent) {
+        for (name, value) in event.fields.iter_mut() {
+            if self.is_sensitive(name) {
+                *value = json!(REDACTED);
+            } else {
+                self.redact(value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LogLevel;
+
+    #[test]
+    fn test_redaction_masks_matching_fields_recursively() {
+        let enricher = RedactionEnricher::new(["password", "*_token", "authorization"]);
+        let mut event = LogEvent::new(LogLevel::Info, "calling api")
+            .with_field("password", "hunter2")
+            .with_field("Access_Token", "abc")
+            .with_field("token_count", 3)
+            .with_field(
+                "request",
+                json!({
+                    "headers": {"Authorization": "Bearer abc", "accept": "json"},
+                    "users": [{"name": "ann", "password": "pw"}]
+                }),
+            );
+
+        enricher.enrich(&mut event);
+
+        assert_eq!(event.fields["password"], REDACTED);
+        assert_eq!(event.fields["Access_Token"], REDACTED);
+        assert_eq!(event.fields["token_count"], 3);
+        assert_eq!(event.fields["request"]["headers"]["Authorization"], REDACTED);
+        assert_eq!(event.fields["request"]["headers"]["accept"], "json");
+        assert_eq!(event.fields["request"]["users"][0]["password"], REDACTED);
+        assert_eq!(event.fields["request"]["users"][0]["name"], "ann");
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{LogEvent, LogLevel};
+use colored::{ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a sink renders log events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Indented JSON object per event
+    Json,
+    /// One compact JSON object per line, for log ingestion
+    Jsonl,
+    /// Human-readable multi-line output, colorized on a terminal
+    Pretty,
+    /// Single line with all fields as `key=value`
+    Compact,
+}
+
+impl LogFormat {
+    /// Render an event without a trailing newline
+    ///
+    /// `colorize` only affects the pretty format.
+    pub fn format(&self, event: &LogEvent, colorize: bool) -> String {
+        match self {
+            LogFormat::Json => serde_json::to_string_pretty(event).unwrap_or_else(|_| event.message.clone()),
+            LogFormat::Jsonl => serde_json::to_string(event).unwrap_or_else(|_| event.message.clone()),
+            LogFormat::Pretty => format_pretty(event, colorize),
+            LogFormat::Compact => format_compact(event),
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(LogFormat::Json),
+            "jsonl" => Ok(LogFormat::Jsonl),
+            "pretty" => Ok(LogFormat::Pretty),
+            "compact" => Ok(LogFormat::Compact),
+            _ => Err(format!("Invalid log format: {}", s)),
+        }
+    }
+}
+
+fn paint(text: &str, colorize: bool, color: fn(&str) -> ColoredString) -> String {
+    if colorize {
+        color(text).to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// First eight characters of an id
+fn short_id(id: &str) -> &str {
+    id.char_indices().nth(8).map_or(id, |(end, _)| &id[..end])
+}
+
+fn format_pretty(event: &LogEvent, colorize: bool) -> String {
+    let level_str = match event.level {
+        LogLevel::Trace => paint("TRACE", colorize, |s| s.dimmed()),
+        LogLevel::Debug => paint("DEBUG", colorize, |s| s.blue()),
+        LogLevel::Info => paint("INFO ", colorize, |s| s.green()),
+        LogLevel::Warn => paint("WARN ", colorize, |s| s.yellow()),
+        LogLevel::Error => paint("ERROR", colorize, |s| s.red()),
+    };
+
+    let timestamp = event.timestamp.format("%Y-%m-%d %H:%M:%S%.3f");
+    let mut output = format!("{} {} {}", timestamp, level_str, event.message);
+
+    // Add error details if present
+    if let Some(error) = &event.error {
+        output.push_str(&format!("\n  Error: {} ({})", error.message, error.error_code));
+        output.push_str(&format!("\n  Type: {}", error.error_type));
+        output.push_str(&format!("\n  Retryable: {}", error.is_retryable));
+
+        if !error.suggestions.immediate.is_empty() {
+            output.push_str("\n  Suggestions:");
+            for suggestion in &error.suggestions.immediate {
+                output.push_str(&format!("\n    - {}", suggestion));
+            }
+        }
+    }
+
+    // Add important fields
+    let mut important_fields: Vec<_> = event
+        .fields
+        .iter()
+        .filter(|(k, _)| matches!(k.as_str(), "task_id" | "job_id" | "execution_id" | "error_count"))
+        .collect();
+    important_fields.sort_by_key(|(k, _)| *k);
+
+    if !important_fields.is_empty() {
+        let field_strs: Vec<String> = important_fields.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        output.push_str(&format!(" [{}]", field_strs.join(" ")));
+    }
+
+    // Add trace context
+    if let (Some(trace_id), Some(span_id)) = (&event.trace_id, &event.span_id) {
+        let trace = format!(" trace={} span={}", short_id(trace_id), short_id(span_id));
+        output.push_str(&paint(&trace, colorize, |s| s.dimmed()));
+    }
+
+    output
+}
+
+fn format_compact(event: &LogEvent) -> String {
+    let mut output = format!(
+        "{} {:<5} {}",
+        event.timestamp.format("%H:%M:%S%.3f"),
+        event.level.as_str().to_uppercase(),
+        event.message
+    );
+
+    if let Some(error) = &event.error {
+        output.push_str(&format!(
+            " error={} error_code={} error_type={}",
+            serde_json::Value::from(error.message.as_str()),
+            error.error_code,
+            error.error_type
+        ));
+    }
+
+    let mut fields: Vec<_> = event.fields.iter().collect();
+    fields.sort_by_key(|(k, _)| *k);
+    for (key, value) in fields {
+        output.push_str(&format!(" {}={}", key, value));
+    }
+
+    if let Some(trace_id) = &event.trace_id {
+        output.push_str(&format!(" trace={}", short_id(trace_id)));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorInfo;
+
+    fn event() -> LogEvent {
+        LogEvent::new(LogLevel::Warn, "Task slow")
+            .with_field("task_id", 42)
+            .with_field("attempt", 2)
+            .with_error(ErrorInfo::new("Timeout", "TASK_TIMEOUT", "took too long"))
+            .with_trace_id("0123456789abcdef")
+            .with_span_id("abc")
+    }
+
+    #[test]
+    fn test_jsonl_is_one_line_and_json_is_indented() {
+        let event = event();
+        let line = LogFormat::Jsonl.format(&event, false);
+        assert!(!line.contains('\n'));
+        let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed["message"], "Task slow");
+
+        let json = LogFormat::Json.format(&event, false);
+        assert!(json.contains('\n'));
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&json).unwrap(), parsed);
+    }
+
+    #[test]
+    fn test_pretty_colorizes_only_when_asked() {
+        colored::control::set_override(true);
+        let plain = LogFormat::Pretty.format(&event(), false);
+        let colored = LogFormat::Pretty.format(&event(), true);
+        colored::control::unset_override();
+
+        assert!(!plain.contains('\u{1b}'));
+        assert!(plain.contains("WARN  Task slow"));
+        assert!(plain.contains("[task_id=42]"));
+        assert!(plain.ends_with(" trace=01234567 span=abc"));
+        assert!(colored.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn test_compact_is_single_line_with_all_fields() {
+        let line = LogFormat::Compact.format(&event(), true);
+        assert!(!line.contains('\n'));
+        assert!(line.contains("WARN  Task slow"));
+        assert!(line.contains(r#"error="took too long" error_code=TASK_TIMEOUT"#));
+        assert!(line.contains("attempt=2 task_id=42"));
+        assert!(line.ends_with("trace=01234567"));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("jsonl".parse::<LogFormat>().unwrap(), LogFormat::Jsonl);
+        assert_eq!("Compact".parse::<LogFormat>().unwrap(), LogFormat::Compact);
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+}
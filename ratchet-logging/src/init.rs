@@ -1,8 +1,9 @@
 use super::{init_logger, LoggingConfig};
+use crate::task_level::TaskLogLevelFilter;
 use anyhow::Result;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::{EnvFilter, Layer};
 
 /// Initialize logging from configuration
 pub fn init_logging_from_config(config: &LoggingConfig) -> Result<()> {
@@ -14,9 +15,10 @@ pub fn init_logging_from_config(config: &LoggingConfig) -> Result<()> {
     // Check if only console sink is configured - use tracing for simplicity
     if config.sinks.len() == 1 {
         if let Some(sink) = config.sinks.first() {
-            if let super::config::SinkConfig::Console { level, use_json } = sink {
-                if !use_json {
-                    return init_simple_tracing(&level.to_string());
+            if let super::config::SinkConfig::Console { format, use_json, .. } = sink {
+                // Other formats need the structured logger's formatters
+                if !use_json && matches!(format, None | Some(super::LogFormat::Pretty)) {
+                    return init_simple_tracing(&config.sink_level(sink).to_string());
                 }
             }
         }
@@ -39,7 +41,10 @@ pub fn init_simple_tracing(log_level: &str) -> Result<()> {
         .unwrap_or_else(|_| EnvFilter::new("info"));
 
     // Use try_init to avoid panic if global subscriber already set
-    if let Err(_) = tracing_subscriber::fmt().with_env_filter(env_filter).try_init() {
+    if let Err(_) = tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(TaskLogLevelFilter::new(env_filter)))
+        .try_init()
+    {
         tracing::debug!("Global tracing subscriber already initialized, skipping");
     }
 
@@ -54,15 +59,14 @@ pub fn init_hybrid_logging(config: &LoggingConfig) -> Result<()> {
         .with_thread_ids(true)
         .with_line_number(true);
 
-    // Create env filter
+    // Create env filter, honouring per-task log level overrides
     let env_filter = EnvFilter::try_new(config.level.to_string())
         .or_else(|_| EnvFilter::try_from_default_env())
         .unwrap_or_else(|_| EnvFilter::new("info"));
 
     // Initialize subscriber with try_init to avoid panic if already set
     if let Err(_) = tracing_subscriber::registry()
-        .with(env_filter)
-        .with(console_layer)
+        .with(console_layer.with_filter(TaskLogLevelFilter::new(env_filter)))
         .try_init()
     {
         tracing::debug!("Global tracing subscriber already initialized, skipping");
@@ -7,13 +7,17 @@
 //! - LLM-optimized error reporting
 //! - Distributed tracing context
 
+pub mod alerting;
+mod background;
 pub mod config;
 pub mod context;
 pub mod enrichment;
 pub mod error_info;
 pub mod event;
+pub mod format;
 pub mod init;
 pub mod severity;
+pub mod task_level;
 
 #[cfg(feature = "llm")]
 pub mod llm_export;
@@ -26,20 +30,26 @@ pub mod patterns;
 pub mod sinks;
 
 // Re-export main types for convenience
+pub use alerting::SeverityHook;
 pub use config::{ConfigError, LoggingConfig};
 pub use context::LogContext;
-pub use enrichment::{Enricher, LogEnricher};
+pub use enrichment::{Enricher, LogEnricher, RedactionEnricher};
 pub use error_info::{ErrorInfo, ErrorSuggestions, RelatedError};
 pub use event::{LogEvent, LogLevel};
+pub use format::LogFormat;
 pub use init::{init_hybrid_logging, init_logging_from_config, init_simple_tracing};
 pub use logger::{LoggerBuilder, StructuredLogger};
 pub use severity::ErrorSeverity;
+pub use task_level::{TaskLogLevelFilter, TASK_LOG_LEVEL_FIELD};
 
 #[cfg(feature = "llm")]
 pub use llm_export::{format_markdown_report, LLMErrorReport, LLMExportConfig, LLMExporter};
 
 #[cfg(feature = "patterns")]
-pub use patterns::{ErrorCategory, ErrorPattern, ErrorPatternMatcher, MatchingRule};
+pub use patterns::{
+    ErrorCategory, ErrorPattern, ErrorPatternMatcher, MatchingRule, PatternSampling, SamplingDecision,
+    SuppressedSummary,
+};
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
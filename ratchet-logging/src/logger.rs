@@ -1,5 +1,9 @@
-use super::{Enricher, LogContext, LogEnricher, LogEvent, LogLevel};
+use super::alerting::SeverityHook;
+#[cfg(feature = "patterns")]
+use super::patterns::{ErrorPatternMatcher, SamplingDecision};
+use super::{Enricher, ErrorInfo, ErrorSeverity, LogContext, LogEnricher, LogEvent, LogLevel};
 use async_trait::async_trait;
+use std::future::Future;
 use std::sync::Arc;
 
 #[async_trait(?Send)]
@@ -20,6 +24,9 @@ pub struct DefaultLogger {
     enricher: Arc<LogEnricher>,
     sinks: Vec<Arc<dyn LogSink>>,
     context: Option<LogContext>,
+    alert_hooks: Vec<SeverityHook>,
+    #[cfg(feature = "patterns")]
+    error_sampler: Option<Arc<ErrorPatternMatcher>>,
 }
 
 impl DefaultLogger {
@@ -29,6 +36,9 @@ impl DefaultLogger {
             enricher: Arc::new(LogEnricher::default()),
             sinks,
             context: None,
+            alert_hooks: Vec::new(),
+            #[cfg(feature = "patterns")]
+            error_sampler: None,
         }
     }
 
@@ -36,29 +46,126 @@ impl DefaultLogger {
         self.enricher = enricher;
         self
     }
-}
 
-#[async_trait(?Send)]
-impl StructuredLogger for DefaultLogger {
-    fn log(&self, mut event: LogEvent) {
-        // Skip if below minimum level
+    /// Fire `hook` for events at or above its severity threshold
+    pub fn with_alert_hook(mut self, hook: SeverityHook) -> Self {
+        self.alert_hooks.push(hook);
+        self
+    }
+
+    /// Rate limit error events using the sampling of their matched pattern
+    #[cfg(feature = "patterns")]
+    pub fn with_error_sampling(mut self, matcher: Arc<ErrorPatternMatcher>) -> Self {
+        self.error_sampler = Some(matcher);
+        self
+    }
+
+    /// Apply pattern sampling, returning false if the event should be dropped
+    ///
+    /// Summaries of suppressed errors are written ahead of the event.
+    #[cfg(feature = "patterns")]
+    fn sample(&self, event: &LogEvent) -> bool {
+        let Some(sampler) = &self.error_sampler else {
+            return true;
+        };
+
+        self.flush_summaries();
+
+        match event.error.as_ref().map(|error| sampler.sample(error)) {
+            Some(SamplingDecision::Suppress) => false,
+            Some(SamplingDecision::Emit { summary: Some(summary) }) => {
+                self.dispatch(summary.to_log_event());
+                true
+            }
+            _ => true,
+        }
+    }
+
+    #[cfg(not(feature = "patterns"))]
+    fn sample(&self, _event: &LogEvent) -> bool {
+        true
+    }
+
+    /// Write summaries of sampling intervals that ended without a newer match
+    #[cfg(feature = "patterns")]
+    fn flush_summaries(&self) {
+        if let Some(sampler) = &self.error_sampler {
+            for summary in sampler.take_expired_summaries() {
+                self.dispatch(summary.to_log_event());
+            }
+        }
+    }
+
+    /// Flush sampling summaries every sampling interval, so suppressed errors
+    /// are reported after a flood stops
+    ///
+    /// Runs on the current Tokio runtime for as long as the logger lives.
+    /// Without a runtime, summaries are written ahead of the next logged event.
+    #[cfg(feature = "patterns")]
+    fn spawn_summary_flush(logger: &Arc<Self>) {
+        let Some(period) = logger.error_sampler.as_ref().and_then(|s| s.summary_interval()) else {
+            return;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        if period.is_zero() {
+            return;
+        }
+
+        let logger = Arc::downgrade(logger);
+        handle.spawn(async move {
+            let mut ticks = tokio::time::interval(period);
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            ticks.tick().await;
+            loop {
+                ticks.tick().await;
+                let Some(logger) = logger.upgrade() else {
+                    break;
+                };
+                logger.flush_summaries();
+            }
+        });
+    }
+
+    fn dispatch(&self, mut event: LogEvent) {
         if !event.should_log(self.min_level) {
             return;
         }
 
-        // Apply context if available
-        if let Some(context) = &self.context {
-            event.trace_id = Some(context.trace_id.clone());
-            event.span_id = Some(context.span_id.clone());
-            event.fields.extend(context.fields.clone());
+        // Apply the logger's context, or the task-local one, before enriching
+        if let Some(context) = self.context.clone().or_else(LogContext::try_current) {
+            event.trace_id = Some(context.trace_id);
+            event.span_id = Some(context.span_id);
+            event.fields.extend(context.fields);
         }
 
         // Enrich the event
         self.enricher.enrich(&mut event);
 
-        // Send to all sinks
+        // Send to every sink accepting this level
         for sink in &self.sinks {
-            sink.log(event.clone());
+            if event.should_log(sink.min_level()) {
+                sink.log(event.clone());
+            }
+        }
+
+        for hook in &self.alert_hooks {
+            hook.fire(&event);
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl StructuredLogger for DefaultLogger {
+    fn log(&self, event: LogEvent) {
+        // Skip if below minimum level
+        if !event.should_log(self.min_level) {
+            return;
+        }
+
+        if self.sample(&event) {
+            self.dispatch(event);
         }
     }
 
@@ -68,6 +175,9 @@ impl StructuredLogger for DefaultLogger {
             enricher: self.enricher.clone(),
             sinks: self.sinks.clone(),
             context: Some(context),
+            alert_hooks: self.alert_hooks.clone(),
+            #[cfg(feature = "patterns")]
+            error_sampler: self.error_sampler.clone(),
         })
     }
 
@@ -83,6 +193,13 @@ pub trait LogSink: Send + Sync {
 
     /// Flush any buffered events
     fn flush(&self);
+
+    /// Minimum level accepted by this sink
+    ///
+    /// The logger skips the sink for events below this level.
+    fn min_level(&self) -> LogLevel {
+        LogLevel::Trace
+    }
 }
 
 /// Builder for creating loggers
@@ -90,6 +207,9 @@ pub struct LoggerBuilder {
     min_level: LogLevel,
     sinks: Vec<Arc<dyn LogSink>>,
     enrichers: Vec<Box<dyn Enricher>>,
+    alert_hooks: Vec<SeverityHook>,
+    #[cfg(feature = "patterns")]
+    error_sampler: Option<Arc<ErrorPatternMatcher>>,
 }
 
 impl LoggerBuilder {
@@ -98,6 +218,9 @@ impl LoggerBuilder {
             min_level: LogLevel::Info,
             sinks: Vec::new(),
             enrichers: Vec::new(),
+            alert_hooks: Vec::new(),
+            #[cfg(feature = "patterns")]
+            error_sampler: None,
         }
     }
 
@@ -116,10 +239,41 @@ impl LoggerBuilder {
         self
     }
 
+    /// Run `callback` for logged events with an error at or above `threshold`
+    ///
+    /// The callback receives the enriched event and its error info. It runs in
+    /// the background, and its failures are reported on stderr rather than
+    /// affecting logging.
+    pub fn on_severity<F, Fut>(mut self, threshold: ErrorSeverity, callback: F) -> Self
+    where
+        F: Fn(LogEvent, ErrorInfo) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        self.alert_hooks.push(SeverityHook::new(threshold, callback));
+        self
+    }
+
+    #[cfg(feature = "patterns")]
+    pub fn with_error_sampling(mut self, matcher: Arc<ErrorPatternMatcher>) -> Self {
+        self.error_sampler = Some(matcher);
+        self
+    }
+
     pub fn build(self) -> Arc<dyn StructuredLogger> {
         let enricher = Arc::new(LogEnricher::new(self.enrichers));
-        let logger = DefaultLogger::new(self.min_level, self.sinks).with_enricher(enricher);
-        Arc::new(logger)
+        let mut logger = DefaultLogger::new(self.min_level, self.sinks).with_enricher(enricher);
+        for hook in self.alert_hooks {
+            logger = logger.with_alert_hook(hook);
+        }
+        #[cfg(feature = "patterns")]
+        let logger = match self.error_sampler {
+            Some(matcher) => logger.with_error_sampling(matcher),
+            None => logger,
+        };
+        let logger = Arc::new(logger);
+        #[cfg(feature = "patterns")]
+        DefaultLogger::spawn_summary_flush(&logger);
+        logger
     }
 }
 
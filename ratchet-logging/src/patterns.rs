@@ -1,6 +1,11 @@
-use super::ErrorInfo;
+use super::{ErrorInfo, LogEvent, LogLevel};
+use chrono::{DateTime, Utc};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
 
 /// Error pattern for matching and categorizing errors
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +41,41 @@ pub struct ErrorPattern {
     pub llm_prompts: Vec<String>,
 }
 
+/// Rate limit for errors matching a pattern
+///
+/// At most `max_events` matching errors are logged per `interval`; the rest
+/// are counted and reported in a single summary once the interval ends.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatternSampling {
+    /// Matching errors logged per interval
+    #[serde(default = "default_sampling_max_events")]
+    pub max_events: u32,
+
+    /// Length of a sampling interval
+    #[serde(with = "humantime_serde", default = "default_sampling_interval")]
+    pub interval: Duration,
+}
+
+impl PatternSampling {
+    pub fn new(max_events: u32, interval: Duration) -> Self {
+        Self { max_events, interval }
+    }
+}
+
+impl Default for PatternSampling {
+    fn default() -> Self {
+        Self::new(default_sampling_max_events(), default_sampling_interval())
+    }
+}
+
+fn default_sampling_max_events() -> u32 {
+    10
+}
+
+fn default_sampling_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum ErrorCategory {
@@ -134,14 +174,104 @@ impl MatchingRule {
     }
 }
 
+/// Whether a matched error should be logged
+#[derive(Debug, Clone, PartialEq)]
+pub enum SamplingDecision {
+    /// Log the error, preceded by a summary of the previous interval if it
+    /// suppressed anything
+    Emit { summary: Option<SuppressedSummary> },
+
+    /// Drop the error; it is counted towards the next summary
+    Suppress,
+}
+
+/// Errors suppressed for one pattern during a sampling interval
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuppressedSummary {
+    pub pattern_id: String,
+    pub pattern_name: String,
+    /// Number of errors that were not logged
+    pub suppressed: u64,
+    /// When the first suppressed error occurred
+    pub first_suppressed_at: DateTime<Utc>,
+    /// Message of the first suppressed error
+    pub first_suppressed_message: String,
+    pub interval: Duration,
+}
+
+impl SuppressedSummary {
+    /// Log event reporting the suppressed errors
+    pub fn to_log_event(&self) -> LogEvent {
+        LogEvent::new(
+            LogLevel::Warn,
+            format!(
+                "Suppressed {} similar events matching '{}' in the last {}s",
+                self.suppressed,
+                self.pattern_name,
+                self.interval.as_secs()
+            ),
+        )
+        .with_field("pattern_id", self.pattern_id.clone())
+        .with_field("suppressed_count", self.suppressed)
+        .with_field("first_suppressed_at", self.first_suppressed_at.to_rfc3339())
+        .with_field("first_suppressed_message", self.first_suppressed_message.clone())
+    }
+}
+
+/// Sampling state of one pattern for the current interval
+struct SamplingWindow {
+    started: Instant,
+    interval: Duration,
+    pattern_name: String,
+    emitted: u32,
+    suppressed: u64,
+    first_suppressed: Option<(DateTime<Utc>, String)>,
+}
+
+impl SamplingWindow {
+    fn new(pattern: &ErrorPattern, sampling: &PatternSampling, now: Instant) -> Self {
+        Self {
+            started: now,
+            interval: sampling.interval,
+            pattern_name: pattern.name.clone(),
+            emitted: 0,
+            suppressed: 0,
+            first_suppressed: None,
+        }
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.started) >= self.interval
+    }
+
+    fn summary(self, pattern_id: &str) -> Option<SuppressedSummary> {
+        let (first_suppressed_at, first_suppressed_message) = self.first_suppressed?;
+        Some(SuppressedSummary {
+            pattern_id: pattern_id.to_string(),
+            pattern_name: self.pattern_name,
+            suppressed: self.suppressed,
+            first_suppressed_at,
+            first_suppressed_message,
+            interval: self.interval,
+        })
+    }
+}
+
 /// Error pattern matcher for finding matching patterns
 pub struct ErrorPatternMatcher {
     patterns: Vec<ErrorPattern>,
+    /// Rate limits of sampled patterns, keyed by pattern id
+    sampling: HashMap<String, PatternSampling>,
+    windows: Mutex<HashMap<String, SamplingWindow>>,
 }
 
 impl ErrorPatternMatcher {
     pub fn new(patterns: Vec<ErrorPattern>) -> Self {
-        Self { patterns }
+        Self {
+            patterns,
+            sampling: HashMap::new(),
+            windows: Mutex::new(HashMap::new()),
+        }
     }
 
     /// Load patterns from built-in definitions
@@ -149,6 +279,86 @@ impl ErrorPatternMatcher {
         Self::new(Self::default_patterns())
     }
 
+    /// Set the sampling of patterns by id, ignoring unknown ids
+    pub fn with_pattern_sampling(mut self, sampling: &HashMap<String, PatternSampling>) -> Self {
+        for pattern in &self.patterns {
+            if let Some(config) = sampling.get(&pattern.id) {
+                self.sampling.insert(pattern.id.clone(), config.clone());
+            }
+        }
+        self
+    }
+
+    /// Whether any pattern limits how often it is logged
+    pub fn has_sampling(&self) -> bool {
+        !self.sampling.is_empty()
+    }
+
+    /// Shortest sampling interval, how often expired summaries should be taken
+    pub fn summary_interval(&self) -> Option<Duration> {
+        self.sampling.values().map(|sampling| sampling.interval).min()
+    }
+
+    /// Decide whether an error should be logged under its pattern's sampling
+    ///
+    /// The first `max_events` matches of each interval are emitted; later ones
+    /// are suppressed. Errors without a sampled pattern are always emitted.
+    pub fn sample(&self, error: &ErrorInfo) -> SamplingDecision {
+        self.sample_at(error, Instant::now())
+    }
+
+    fn sample_at(&self, error: &ErrorInfo, now: Instant) -> SamplingDecision {
+        let Some(pattern) = self.match_error(error) else {
+            return SamplingDecision::Emit { summary: None };
+        };
+        let Some(sampling) = self.sampling.get(&pattern.id) else {
+            return SamplingDecision::Emit { summary: None };
+        };
+
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        let mut summary = None;
+        if windows.get(&pattern.id).is_some_and(|w| w.is_expired(now)) {
+            summary = windows.remove(&pattern.id).and_then(|w| w.summary(&pattern.id));
+        }
+
+        let window = windows
+            .entry(pattern.id.clone())
+            .or_insert_with(|| SamplingWindow::new(pattern, sampling, now));
+
+        if window.emitted < sampling.max_events {
+            window.emitted += 1;
+            SamplingDecision::Emit { summary }
+        } else {
+            window.suppressed += 1;
+            window
+                .first_suppressed
+                .get_or_insert_with(|| (Utc::now(), error.message.clone()));
+            SamplingDecision::Suppress
+        }
+    }
+
+    /// Summaries of intervals that have ended without a newer match
+    ///
+    /// Call periodically so suppressed counts are reported even after a flood
+    /// stops.
+    pub fn take_expired_summaries(&self) -> Vec<SuppressedSummary> {
+        self.take_expired_summaries_at(Instant::now())
+    }
+
+    fn take_expired_summaries_at(&self, now: Instant) -> Vec<SuppressedSummary> {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        let expired: Vec<String> = windows
+            .iter()
+            .filter(|(_, w)| w.is_expired(now))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        expired
+            .into_iter()
+            .filter_map(|id| windows.remove(&id).and_then(|w| w.summary(&id)))
+            .collect()
+    }
+
     /// Find the best matching pattern for an error
     pub fn match_error(&self, error: &ErrorInfo) -> Option<&ErrorPattern> {
         self.patterns.iter().filter(|p| p.matches(error)).max_by(|a, b| {
@@ -429,4 +639,88 @@ mod tests {
         assert!(!suggestions.is_empty());
         assert!(suggestions.iter().any(|s| s.contains("ratchet list")));
     }
+
+    #[test]
+    fn test_sampling_limits_matches_per_interval() {
+        let sampling = HashMap::from([(
+            "db_connection_timeout".to_string(),
+            PatternSampling::new(2, Duration::from_secs(10)),
+        )]);
+        let matcher = ErrorPatternMatcher::with_defaults().with_pattern_sampling(&sampling);
+        assert!(matcher.has_sampling());
+
+        let flood = |n: u32| ErrorInfo::new("DatabaseError", "DB_CONN_ERROR", format!("Connection timeout #{}", n));
+        let start = Instant::now();
+
+        assert_eq!(
+            matcher.sample_at(&flood(1), start),
+            SamplingDecision::Emit { summary: None }
+        );
+        assert_eq!(
+            matcher.sample_at(&flood(2), start),
+            SamplingDecision::Emit { summary: None }
+        );
+        for n in 3..=1000 {
+            assert_eq!(matcher.sample_at(&flood(n), start), SamplingDecision::Suppress);
+        }
+
+        // Unsampled patterns and unmatched errors are never suppressed
+        let not_found = ErrorInfo::new("TaskNotFound", "TASK_NOT_FOUND", "Task 'foo' not found");
+        let unknown = ErrorInfo::new("Other", "OTHER", "Something else");
+        for _ in 0..10 {
+            assert_eq!(
+                matcher.sample_at(&not_found, start),
+                SamplingDecision::Emit { summary: None }
+            );
+            assert_eq!(
+                matcher.sample_at(&unknown, start),
+                SamplingDecision::Emit { summary: None }
+            );
+        }
+
+        // The next interval starts with a summary of the previous one
+        let later = start + Duration::from_secs(10);
+        let SamplingDecision::Emit { summary: Some(summary) } = matcher.sample_at(&flood(1001), later) else {
+            panic!("expected the first match of a new interval to carry a summary");
+        };
+        assert_eq!(summary.pattern_id, "db_connection_timeout");
+        assert_eq!(summary.suppressed, 998);
+        assert_eq!(summary.first_suppressed_message, "Connection timeout #3");
+        assert_eq!(
+            summary.to_log_event().fields["suppressed_count"],
+            serde_json::json!(998)
+        );
+    }
+
+    #[test]
+    fn test_expired_summaries_are_reported_after_flood_stops() {
+        let sampling = HashMap::from([(
+            "rate_limited".to_string(),
+            PatternSampling::new(1, Duration::from_secs(5)),
+        )]);
+        let matcher = ErrorPatternMatcher::with_defaults().with_pattern_sampling(&sampling);
+        assert_eq!(matcher.summary_interval(), Some(Duration::from_secs(5)));
+        let error = ErrorInfo::new("HttpError", "RATE_LIMITED", "Too many requests");
+        let start = Instant::now();
+
+        for _ in 0..4 {
+            matcher.sample_at(&error, start);
+        }
+
+        assert!(matcher
+            .take_expired_summaries_at(start + Duration::from_secs(4))
+            .is_empty());
+        let summaries = matcher.take_expired_summaries_at(start + Duration::from_secs(5));
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].suppressed, 3);
+
+        // Reported once, and a fresh interval emits again
+        assert!(matcher
+            .take_expired_summaries_at(start + Duration::from_secs(6))
+            .is_empty());
+        assert_eq!(
+            matcher.sample_at(&error, start + Duration::from_secs(6)),
+            SamplingDecision::Emit { summary: None }
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{logger::LogSink, LogEvent};
+use crate::{logger::LogSink, LogEvent, LogLevel};
 use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -99,6 +99,10 @@ impl LogSink for BufferedSink {
         // Send flush command to background task
         let _ = self.tx.try_send(BufferCommand::Flush);
     }
+
+    fn min_level(&self) -> LogLevel {
+        self.inner_sink.min_level()
+    }
 }
 
 impl Drop for BufferedSink {
@@ -1,16 +1,98 @@
-use crate::{logger::LogSink, LogEvent, LogLevel};
-use chrono::Local;
+use crate::{logger::LogSink, LogEvent, LogFormat, LogLevel};
+use chrono::{DateTime, Local};
+use flate2::{write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::time::Duration;
+
+/// Calendar interval for time-based rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RotationInterval {
+    /// Rotate when the local hour changes
+    Hourly,
+    /// Rotate when the local date changes
+    Daily,
+}
+
+impl RotationInterval {
+    /// Key identifying the interval a timestamp falls into
+    fn period(&self, time: DateTime<Local>) -> String {
+        match self {
+            RotationInterval::Hourly => time.format("%Y%m%d%H").to_string(),
+            RotationInterval::Daily => time.format("%Y%m%d").to_string(),
+        }
+    }
+}
+
+/// Rotation policy for a [`FileSink`]
+///
+/// Size, age and time based rotation can be combined; the file is rotated when
+/// any limit is reached.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileRotation {
+    /// Rotate once the file reaches this many bytes
+    pub max_size: Option<u64>,
+    /// Rotate when the interval changes
+    pub interval: Option<RotationInterval>,
+    /// Rotate once the active file is this old
+    pub max_age: Option<Duration>,
+    /// Number of rotated files to keep, oldest are deleted first
+    pub max_files: Option<usize>,
+    /// Gzip rotated files
+    pub compress: bool,
+}
+
+impl FileRotation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    pub fn interval(mut self, interval: RotationInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.max_files = Some(max_files);
+        self
+    }
+
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+}
+
+/// Active log file guarded by a single lock so rotation and writes never interleave
+struct FileState {
+    writer: BufWriter<File>,
+    size: u64,
+    /// Interval the active file was opened in
+    period: Option<String>,
+    /// When the active file was started
+    opened_at: DateTime<Local>,
+}
 
 pub struct FileSink {
     path: PathBuf,
-    writer: Mutex<BufWriter<File>>,
+    state: Mutex<FileState>,
     min_level: LogLevel,
-    max_size: Option<u64>,
-    current_size: Mutex<u64>,
+    rotation: FileRotation,
+    format: LogFormat,
 }
 
 impl FileSink {
@@ -24,96 +106,390 @@ impl FileSink {
 
         let file = OpenOptions::new().create(true).append(true).open(&path)?;
 
-        let current_size = file.metadata()?.len();
+        let metadata = file.metadata()?;
+        let size = metadata.len();
+        let opened_at = metadata
+            .created()
+            .map(DateTime::<Local>::from)
+            .unwrap_or_else(|_| Local::now());
         let writer = BufWriter::new(file);
 
         Ok(Self {
             path,
-            writer: Mutex::new(writer),
+            state: Mutex::new(FileState {
+                writer,
+                size,
+                period: None,
+                opened_at,
+            }),
             min_level,
-            max_size: None,
-            current_size: Mutex::new(current_size),
+            rotation: FileRotation::default(),
+            format: LogFormat::Jsonl,
         })
     }
 
+    /// Format events are written in (JSON lines by default)
+    pub fn with_format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Rotate the file once it reaches `max_size` bytes
     pub fn with_rotation(mut self, max_size: u64) -> Self {
-        self.max_size = Some(max_size);
+        self.rotation.max_size = Some(max_size);
         self
     }
 
-    fn rotate_if_needed(&self) -> std::io::Result<()> {
-        if let Some(max_size) = self.max_size {
-            let current_size = *self.current_size.lock().unwrap();
-            if current_size >= max_size {
-                self.rotate_file()?;
-            }
+    /// Apply a full rotation policy
+    pub fn with_rotation_policy(mut self, rotation: FileRotation) -> Self {
+        if let Some(interval) = rotation.interval {
+            // An existing file belongs to the interval it was last written in
+            let modified = std::fs::metadata(&self.path)
+                .and_then(|metadata| metadata.modified())
+                .map(DateTime::<Local>::from)
+                .unwrap_or_else(|_| Local::now());
+            self.state.get_mut().unwrap().period = Some(interval.period(modified));
         }
-        Ok(())
+        self.rotation = rotation;
+        self
     }
 
-    fn rotate_file(&self) -> std::io::Result<()> {
-        let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-        let rotated_path = self.path.with_file_name(format!(
-            "{}.{}",
-            self.path.file_stem().unwrap().to_string_lossy(),
-            timestamp
-        ));
+    fn needs_rotation(&self, state: &FileState, now: DateTime<Local>) -> bool {
+        let size_exceeded = self.rotation.max_size.is_some_and(|max_size| state.size >= max_size);
 
-        // Close current file and rename it
-        {
-            let mut writer = self.writer.lock().unwrap();
-            writer.flush()?;
-            drop(writer); // Release the lock before renaming
-        }
+        let age_exceeded = self
+            .rotation
+            .max_age
+            .is_some_and(|max_age| (now - state.opened_at).to_std().is_ok_and(|age| age >= max_age));
 
+        let period_changed = match (&self.rotation.interval, &state.period) {
+            (Some(interval), Some(period)) => interval.period(now) != *period,
+            _ => false,
+        };
+
+        size_exceeded || age_exceeded || period_changed
+    }
+
+    /// Rotate the active file while holding the state lock, returning the rotated file's path
+    ///
+    /// Compressing and pruning rotated files is left to [`finish_rotation`](Self::finish_rotation),
+    /// so writers are not blocked while it runs.
+    fn rotate_file(&self, state: &mut FileState, now: DateTime<Local>) -> std::io::Result<PathBuf> {
+        state.writer.flush()?;
+
+        let rotated_path = self.rotated_path(now);
         std::fs::rename(&self.path, &rotated_path)?;
 
         // Create new file
         let new_file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        state.writer = BufWriter::new(new_file);
+        state.size = 0;
+        state.period = self.rotation.interval.map(|interval| interval.period(now));
+        state.opened_at = now;
+
+        Ok(rotated_path)
+    }
+
+    /// Compress and prune rotated files once the state lock is released
+    fn finish_rotation(&self, rotated_path: &Path) {
+        if self.rotation.compress {
+            if let Err(e) = compress_file(rotated_path) {
+                eprintln!("Failed to compress rotated log file: {}", e);
+            }
+        }
+
+        if let Some(max_files) = self.rotation.max_files {
+            if let Err(e) = self.prune_rotated_files(max_files) {
+                eprintln!("Failed to prune rotated log files: {}", e);
+            }
+        }
+    }
+
+    /// Unused path for a rotated file, e.g. `app.20240101_120000.log`
+    fn rotated_path(&self, now: DateTime<Local>) -> PathBuf {
+        let stem = self.file_stem();
+        let extension = self
+            .path
+            .extension()
+            .map(|ext| format!(".{}", ext.to_string_lossy()))
+            .unwrap_or_default();
+        let timestamp = now.format("%Y%m%d_%H%M%S");
 
-        let mut writer = self.writer.lock().unwrap();
-        *writer = BufWriter::new(new_file);
+        let mut candidate = self.path.with_file_name(format!("{}.{}{}", stem, timestamp, extension));
+        let mut counter = 1;
+        while candidate.exists() || gz_path(&candidate).exists() {
+            candidate = self
+                .path
+                .with_file_name(format!("{}.{}_{:03}{}", stem, timestamp, counter, extension));
+            counter += 1;
+        }
+        candidate
+    }
 
-        let mut current_size = self.current_size.lock().unwrap();
-        *current_size = 0;
+    /// Rotated files belonging to this sink, oldest first
+    pub fn rotated_files(&self) -> std::io::Result<Vec<PathBuf>> {
+        let directory = match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let prefix = format!("{}.", self.file_stem());
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&directory)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+                    name.strip_prefix(&prefix)
+                        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+                })
+            })
+            .collect();
+
+        // Rotated names embed a sortable timestamp
+        files.sort();
+        Ok(files)
+    }
 
+    fn prune_rotated_files(&self, max_files: usize) -> std::io::Result<()> {
+        let files = self.rotated_files()?;
+        let excess = files.len().saturating_sub(max_files);
+        for path in &files[..excess] {
+            std::fs::remove_file(path)?;
+        }
         Ok(())
     }
 
+    fn file_stem(&self) -> String {
+        self.path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
     fn write_event(&self, event: &LogEvent) -> std::io::Result<()> {
-        let json = serde_json::to_string(event)?;
-        let bytes = json.as_bytes();
+        let formatted = self.format.format(event, false);
+        let bytes = formatted.as_bytes();
 
-        let mut writer = self.writer.lock().unwrap();
-        writer.write_all(bytes)?;
-        writer.write_all(b"\n")?;
+        let mut state = self.state.lock().unwrap();
 
-        let mut current_size = self.current_size.lock().unwrap();
-        *current_size += bytes.len() as u64 + 1; // +1 for newline
+        // Check rotation before writing; a failed rotation keeps writing to the current file
+        let now = Local::now();
+        let mut rotated = None;
+        if state.size == 0 {
+            // Nothing to rotate yet, the empty file belongs to the current interval
+            state.period = self.rotation.interval.map(|interval| interval.period(now));
+            state.opened_at = now;
+        } else if self.needs_rotation(&state, now) {
+            match self.rotate_file(&mut state, now) {
+                Ok(rotated_path) => rotated = Some(rotated_path),
+                Err(e) => eprintln!("Failed to rotate log file: {}", e),
+            }
+        }
 
-        Ok(())
+        let written = state
+            .writer
+            .write_all(bytes)
+            .and_then(|()| state.writer.write_all(b"\n"));
+        if written.is_ok() {
+            state.size += bytes.len() as u64 + 1; // +1 for newline
+        }
+        drop(state);
+
+        if let Some(rotated_path) = rotated {
+            self.finish_rotation(&rotated_path);
+        }
+
+        written
     }
 }
 
+/// Path of the gzip-compressed version of a file
+fn gz_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".gz");
+    PathBuf::from(name)
+}
+
+/// Gzip a file in place, replacing it with `<file>.gz`
+fn compress_file(path: &Path) -> std::io::Result<()> {
+    let target = gz_path(path);
+    let mut input = File::open(path)?;
+    let mut encoder = GzEncoder::new(BufWriter::new(File::create(&target)?), Compression::default());
+    std::io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.flush()?;
+    std::fs::remove_file(path)
+}
+
 impl LogSink for FileSink {
     fn log(&self, event: LogEvent) {
         if event.level < self.min_level {
             return;
         }
 
-        // Check rotation before writing
-        if let Err(e) = self.rotate_if_needed() {
-            eprintln!("Failed to rotate log file: {}", e);
-        }
-
         if let Err(e) = self.write_event(&event) {
             eprintln!("Failed to write log event to file: {}", e);
         }
     }
 
     fn flush(&self) {
-        if let Ok(mut writer) = self.writer.lock() {
-            let _ = writer.flush();
+        if let Ok(mut state) = self.state.lock() {
+            let _ = state.writer.flush();
+        }
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+    use tempfile::tempdir;
+
+    fn event(message: &str) -> LogEvent {
+        LogEvent::new(LogLevel::Info, message)
+    }
+
+    fn count_lines(sink: &FileSink) -> usize {
+        let mut lines = std::fs::read_to_string(&sink.path).unwrap().lines().count();
+        for path in sink.rotated_files().unwrap() {
+            let mut content = String::new();
+            if path.extension().is_some_and(|ext| ext == "gz") {
+                GzDecoder::new(File::open(&path).unwrap())
+                    .read_to_string(&mut content)
+                    .unwrap();
+            } else {
+                content = std::fs::read_to_string(&path).unwrap();
+            }
+            lines += content.lines().count();
+        }
+        lines
+    }
+
+    #[test]
+    fn test_configured_format_is_written() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("app.log");
+
+        let sink = FileSink::new(&path, LogLevel::Info).unwrap();
+        sink.log(event("as json"));
+        sink.flush();
+        let sink = FileSink::new(&path, LogLevel::Info)
+            .unwrap()
+            .with_format(LogFormat::Compact);
+        sink.log(event("as compact"));
+        sink.flush();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(serde_json::from_str::<serde_json::Value>(lines[0]).is_ok());
+        assert!(lines[1].contains("INFO  as compact"));
+    }
+
+    #[test]
+    fn test_size_rotation_keeps_every_event() {
+        let dir = tempdir().unwrap();
+        let sink = FileSink::new(dir.path().join("app.log"), LogLevel::Info)
+            .unwrap()
+            .with_rotation_policy(FileRotation::new().max_size(200));
+
+        for i in 0..20 {
+            sink.log(event(&format!("event {}", i)));
+        }
+        sink.flush();
+
+        assert!(!sink.rotated_files().unwrap().is_empty());
+        assert_eq!(count_lines(&sink), 20);
+    }
+
+    #[test]
+    fn test_rotation_retains_max_files_and_compresses() {
+        let dir = tempdir().unwrap();
+        let sink = FileSink::new(dir.path().join("app.log"), LogLevel::Info)
+            .unwrap()
+            .with_rotation_policy(FileRotation::new().max_size(1).max_files(2).compress(true));
+
+        for i in 0..6 {
+            sink.log(event(&format!("event {}", i)));
+        }
+        sink.flush();
+
+        let rotated = sink.rotated_files().unwrap();
+        assert_eq!(rotated.len(), 2);
+        assert!(rotated
+            .iter()
+            .all(|path| path.extension().is_some_and(|ext| ext == "gz")));
+
+        // Two retained files plus the active one, each holding a single event
+        assert_eq!(count_lines(&sink), 3);
+    }
+
+    #[test]
+    fn test_daily_rotation_on_date_change() {
+        let dir = tempdir().unwrap();
+        let sink = FileSink::new(dir.path().join("app.log"), LogLevel::Info)
+            .unwrap()
+            .with_rotation_policy(FileRotation::new().interval(RotationInterval::Daily));
+
+        sink.log(event("event yesterday"));
+        let yesterday = Local.with_ymd_and_hms(2024, 1, 1, 23, 59, 0).unwrap();
+        sink.state.lock().unwrap().period = Some(RotationInterval::Daily.period(yesterday));
+
+        sink.log(event("first event today"));
+        sink.log(event("second event today"));
+        sink.flush();
+
+        assert_eq!(sink.rotated_files().unwrap().len(), 1);
+        assert_eq!(std::fs::read_to_string(&sink.path).unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_age_rotation_once_file_is_old() {
+        let dir = tempdir().unwrap();
+        let sink = FileSink::new(dir.path().join("app.log"), LogLevel::Info)
+            .unwrap()
+            .with_rotation_policy(FileRotation::new().max_age(Duration::from_secs(3600)));
+
+        sink.log(event("first event"));
+        sink.log(event("second event"));
+        assert!(sink.rotated_files().unwrap().is_empty());
+
+        sink.state.lock().unwrap().opened_at = Local::now() - chrono::Duration::hours(2);
+        sink.log(event("event after max age"));
+        sink.flush();
+
+        assert_eq!(sink.rotated_files().unwrap().len(), 1);
+        assert_eq!(std::fs::read_to_string(&sink.path).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_concurrent_writes_during_rotation() {
+        let dir = tempdir().unwrap();
+        let sink = std::sync::Arc::new(
+            FileSink::new(dir.path().join("app.log"), LogLevel::Info)
+                .unwrap()
+                .with_rotation_policy(FileRotation::new().max_size(512)),
+        );
+
+        let handles: Vec<_> = (0..4)
+            .map(|thread| {
+                let sink = sink.clone();
+                std::thread::spawn(move || {
+                    for i in 0..50 {
+                        sink.log(event(&format!("thread {} event {}", thread, i)));
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
         }
+        sink.flush();
+
+        assert_eq!(count_lines(&sink), 200);
     }
 }
@@ -1,7 +1,11 @@
 pub mod buffer;
 pub mod console;
 pub mod file;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 
 pub use buffer::BufferedSink;
 pub use console::ConsoleSink;
-pub use file::FileSink;
+pub use file::{FileRotation, FileSink, RotationInterval};
+#[cfg(feature = "otlp")]
+pub use otlp::{OtlpConfig, OtlpSink};
@@ -0,0 +1,440 @@
+use crate::background::spawn_background;
+use crate::{logger::LogSink, LogEvent, LogLevel};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::interval;
+
+/// Settings for exporting logs to an OpenTelemetry collector
+///
+/// Events are sent as OTLP/HTTP requests with JSON encoding to
+/// `{endpoint}/v1/logs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtlpConfig {
+    /// Collector base URL, e.g. `http://localhost:4318`
+    pub endpoint: String,
+
+    /// Extra request headers, e.g. for authentication
+    pub headers: HashMap<String, String>,
+
+    /// `service.name` resource attribute
+    pub service_name: String,
+
+    /// Events sent per export request
+    pub batch_size: usize,
+
+    /// Longest time an event waits before being exported
+    #[serde(with = "humantime_serde")]
+    pub flush_interval: Duration,
+
+    /// Export request timeout
+    #[serde(with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:4318".to_string(),
+            headers: HashMap::new(),
+            service_name: "ratchet".to_string(),
+            batch_size: 512,
+            flush_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl OtlpConfig {
+    /// URL that log export requests are posted to
+    pub fn logs_url(&self) -> String {
+        let endpoint = self.endpoint.trim_end_matches('/');
+        if endpoint.ends_with("/v1/logs") {
+            endpoint.to_string()
+        } else {
+            format!("{}/v1/logs", endpoint)
+        }
+    }
+}
+
+/// Sink exporting log events to an OpenTelemetry collector over OTLP/HTTP
+///
+/// Events arrive already enriched by the logger, so enriched fields become
+/// OTLP attributes. Trace and span ids from the log context are carried over
+/// when they are valid hex ids.
+pub struct OtlpSink {
+    level: LogLevel,
+    tx: mpsc::Sender<OtlpCommand>,
+}
+
+enum OtlpCommand {
+    Log(Box<LogEvent>),
+    Flush,
+    Shutdown,
+}
+
+impl OtlpSink {
+    /// Create the sink and start its export task
+    ///
+    /// The task runs on the current tokio runtime, or on a dedicated thread
+    /// with its own runtime when the sink is created outside of one.
+    pub fn new(config: OtlpConfig, level: LogLevel) -> Self {
+        let (tx, rx) = mpsc::channel::<OtlpCommand>(config.batch_size.max(1) * 4);
+
+        spawn_background("ratchet-otlp", "OTLP export", Self::run(config, rx));
+
+        Self { level, tx }
+    }
+
+    /// Batch and export events until the sink shuts down
+    async fn run(config: OtlpConfig, mut rx: mpsc::Receiver<OtlpCommand>) {
+        let client = match reqwest::Client::builder().timeout(config.timeout).build() {
+            Ok(client) => client,
+            Err(e) => {
+                eprintln!("Failed to create OTLP client: {}", e);
+                return;
+            }
+        };
+        let mut batch = Vec::with_capacity(config.batch_size);
+        let mut flush_timer = interval(config.flush_interval);
+
+        loop {
+            tokio::select! {
+                _ = flush_timer.tick() => {
+                    Self::export(&client, &config, &mut batch).await;
+                }
+                cmd = rx.recv() => {
+                    match cmd {
+                        Some(OtlpCommand::Log(event)) => {
+                            batch.push(*event);
+                            if batch.len() >= config.batch_size {
+                                Self::export(&client, &config, &mut batch).await;
+                            }
+                        }
+                        Some(OtlpCommand::Flush) => {
+                            Self::export(&client, &config, &mut batch).await;
+                        }
+                        Some(OtlpCommand::Shutdown) | None => {
+                            Self::export(&client, &config, &mut batch).await;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn export(client: &reqwest::Client, config: &OtlpConfig, batch: &mut Vec<LogEvent>) {
+        if batch.is_empty() {
+            return;
+        }
+
+        let body = export_logs_request(&config.service_name, batch);
+        batch.clear();
+
+        let mut request = client.post(config.logs_url()).json(&body);
+        for (name, value) in &config.headers {
+            request = request.header(name, value);
+        }
+
+        match request.send().await {
+            Ok(response) if !response.status().is_success() => {
+                eprintln!("OTLP log export rejected with status {}", response.status());
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("Failed to export logs over OTLP: {}", e),
+        }
+    }
+}
+
+impl LogSink for OtlpSink {
+    fn log(&self, event: LogEvent) {
+        if let Err(mpsc::error::TrySendError::Full(_)) = self.tx.try_send(OtlpCommand::Log(Box::new(event))) {
+            eprintln!("OTLP export queue full, dropping log event");
+        }
+    }
+
+    fn flush(&self) {
+        let _ = self.tx.try_send(OtlpCommand::Flush);
+    }
+
+    fn min_level(&self) -> LogLevel {
+        self.level
+    }
+}
+
+impl Drop for OtlpSink {
+    fn drop(&mut self) {
+        let _ = self.tx.try_send(OtlpCommand::Shutdown);
+    }
+}
+
+/// Build an OTLP `ExportLogsServiceRequest` in its JSON encoding
+pub fn export_logs_request(service_name: &str, events: &[LogEvent]) -> JsonValue {
+    json!({
+        "resourceLogs": [{
+            "resource": {
+                "attributes": [key_value("service.name", &json!(service_name))]
+            },
+            "scopeLogs": [{
+                "scope": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION")
+                },
+                "logRecords": events.iter().map(log_record).collect::<Vec<_>>()
+            }]
+        }]
+    })
+}
+
+/// Convert a log event into an OTLP log record
+pub fn log_record(event: &LogEvent) -> JsonValue {
+    let time = event.timestamp.timestamp_nanos_opt().unwrap_or_default().to_string();
+
+    let mut attributes: Vec<JsonValue> = vec![key_value("logger", &json!(event.logger))];
+    let mut fields: Vec<_> = event.fields.iter().collect();
+    fields.sort_by_key(|(key, _)| *key);
+    attributes.extend(fields.into_iter().map(|(key, value)| key_value(key, value)));
+
+    if let Some(error) = &event.error {
+        attributes.push(key_value("exception.type", &json!(error.error_type)));
+        attributes.push(key_value("exception.message", &json!(error.message)));
+        attributes.push(key_value("error.code", &json!(error.error_code)));
+        if let Some(stack_trace) = &error.stack_trace {
+            attributes.push(key_value("exception.stacktrace", &json!(stack_trace)));
+        }
+    }
+
+    let mut record = json!({
+        "timeUnixNano": time,
+        "observedTimeUnixNano": time,
+        "severityNumber": severity_number(event.level),
+        "severityText": event.level.as_str().to_uppercase(),
+        "body": { "stringValue": event.message },
+        "attributes": attributes,
+    });
+
+    if let Some(trace_id) = event.trace_id.as_deref().and_then(|id| hex_id(id, 32)) {
+        record["traceId"] = json!(trace_id);
+    }
+    if let Some(span_id) = event.span_id.as_deref().and_then(|id| hex_id(id, 16)) {
+        record["spanId"] = json!(span_id);
+    }
+
+    record
+}
+
+/// OTLP severity number for the first severity of each level's range
+fn severity_number(level: LogLevel) -> u32 {
+    match level {
+        LogLevel::Trace => 1,
+        LogLevel::Debug => 5,
+        LogLevel::Info => 9,
+        LogLevel::Warn => 13,
+        LogLevel::Error => 17,
+    }
+}
+
+/// Normalize an id to `len` lowercase hex digits
+///
+/// Context trace ids are UUIDs and span ids may drop leading zeros, so dashes
+/// are removed and short ids are zero padded. Anything else is not a valid
+/// OTLP id.
+fn hex_id(id: &str, len: usize) -> Option<String> {
+    let hex: String = id.chars().filter(|c| *c != '-').collect::<String>().to_lowercase();
+    if hex.is_empty() || hex.len() > len || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    if hex.chars().all(|c| c == '0') {
+        return None;
+    }
+    Some(format!("{:0>len$}", hex, len = len))
+}
+
+fn key_value(key: &str, value: &JsonValue) -> JsonValue {
+    json!({ "key": key, "value": any_value(value) })
+}
+
+/// Convert a JSON value into an OTLP `AnyValue`
+fn any_value(value: &JsonValue) -> JsonValue {
+    match value {
+        JsonValue::Null => json!({}),
+        JsonValue::Bool(b) => json!({ "boolValue": b }),
+        JsonValue::Number(n) => match n.as_i64() {
+            // 64 bit integers are strings in the OTLP JSON encoding
+            Some(i) => json!({ "intValue": i.to_string() }),
+            None => json!({ "doubleValue": n.as_f64() }),
+        },
+        JsonValue::String(s) => json!({ "stringValue": s }),
+        JsonValue::Array(values) => json!({
+            "arrayValue": { "values": values.iter().map(any_value).collect::<Vec<_>>() }
+        }),
+        JsonValue::Object(map) => json!({
+            "kvlistValue": {
+                "values": map.iter().map(|(k, v)| key_value(k, v)).collect::<Vec<_>>()
+            }
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ErrorInfo, LogContext};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn attribute<'a>(record: &'a JsonValue, key: &str) -> Option<&'a JsonValue> {
+        record["attributes"]
+            .as_array()?
+            .iter()
+            .find(|kv| kv["key"] == key)
+            .map(|kv| &kv["value"])
+    }
+
+    #[test]
+    fn test_log_record_conversion() {
+        let context = LogContext::new();
+        let event = LogEvent::new(LogLevel::Error, "Task failed")
+            .with_field("task_name", "fetch")
+            .with_field("attempt", 3)
+            .with_field("hostname", "worker-1")
+            .with_error(ErrorInfo::new("NetworkError", "NET_TIMEOUT", "Request timed out"))
+            .with_trace_id(context.trace_id.clone())
+            .with_span_id(context.span_id.clone());
+
+        let record = log_record(&event);
+
+        assert_eq!(record["severityNumber"], 17);
+        assert_eq!(record["severityText"], "ERROR");
+        assert_eq!(record["body"]["stringValue"], "Task failed");
+        assert_eq!(record["traceId"], context.trace_id.replace('-', ""));
+        assert_eq!(record["spanId"].as_str().unwrap().len(), 16);
+        assert!(record["spanId"].as_str().unwrap().ends_with(&context.span_id));
+        assert_eq!(attribute(&record, "task_name").unwrap()["stringValue"], "fetch");
+        assert_eq!(attribute(&record, "attempt").unwrap()["intValue"], "3");
+        assert_eq!(attribute(&record, "hostname").unwrap()["stringValue"], "worker-1");
+        assert_eq!(
+            attribute(&record, "exception.type").unwrap()["stringValue"],
+            "NetworkError"
+        );
+        assert_eq!(attribute(&record, "error.code").unwrap()["stringValue"], "NET_TIMEOUT");
+    }
+
+    #[test]
+    fn test_invalid_ids_are_omitted() {
+        let mut event = LogEvent::new(LogLevel::Info, "hello");
+        event.trace_id = Some("not-a-trace".to_string());
+        event.span_id = Some("0".to_string());
+        assert_eq!(hex_id("a1b2c3", 16).as_deref(), Some("0000000000a1b2c3"));
+
+        let record = log_record(&event);
+        assert!(record.get("traceId").is_none());
+        assert!(record.get("spanId").is_none());
+        assert_eq!(record["severityNumber"], 9);
+    }
+
+    #[test]
+    fn test_logs_url() {
+        let mut config = OtlpConfig::default();
+        assert_eq!(config.logs_url(), "http://localhost:4318/v1/logs");
+        config.endpoint = "https://otel.example.com/v1/logs/".to_string();
+        assert_eq!(config.logs_url(), "https://otel.example.com/v1/logs");
+    }
+
+    #[tokio::test]
+    async fn test_sink_exports_batches_to_collector() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        let collector = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            let body_start = loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break pos + 4;
+                }
+            };
+            let head = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+            let content_length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|len| len.trim().parse().unwrap())
+                .unwrap();
+            while request.len() < body_start + content_length {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+
+            let body: JsonValue = serde_json::from_slice(&request[body_start..]).unwrap();
+            (head, body)
+        });
+
+        let sink = OtlpSink::new(
+            OtlpConfig {
+                endpoint,
+                headers: HashMap::from([("x-api-key".to_string(), "secret".to_string())]),
+                service_name: "ratchet-test".to_string(),
+                batch_size: 2,
+                ..OtlpConfig::default()
+            },
+            LogLevel::Info,
+        );
+        sink.log(LogEvent::new(LogLevel::Info, "first"));
+        sink.log(LogEvent::new(LogLevel::Warn, "second"));
+
+        let (head, body) = tokio::time::timeout(Duration::from_secs(10), collector)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(head.starts_with("post /v1/logs"));
+        assert!(head.contains("x-api-key: secret"));
+        let resource_logs = &body["resourceLogs"][0];
+        assert_eq!(
+            resource_logs["resource"]["attributes"][0]["value"]["stringValue"],
+            "ratchet-test"
+        );
+        let records = resource_logs["scopeLogs"][0]["logRecords"].as_array().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1]["body"]["stringValue"], "second");
+    }
+
+    #[test]
+    fn test_sink_exports_outside_a_runtime() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        let sink = OtlpSink::new(
+            OtlpConfig {
+                endpoint,
+                batch_size: 1,
+                ..OtlpConfig::default()
+            },
+            LogLevel::Info,
+        );
+        sink.log(LogEvent::new(LogLevel::Info, "no runtime"));
+
+        let (mut socket, _) = listener.accept().unwrap();
+        socket.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0u8; 4096];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = std::io::Read::read(&mut socket, &mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+        std::io::Write::write_all(&mut socket, b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n").unwrap();
+
+        assert!(String::from_utf8_lossy(&request).starts_with("POST /v1/logs"));
+    }
+}
@@ -0,0 +1,301 @@
+//! Per-task log level overrides
+//!
+//! Workers run each execution inside a span carrying a `task_log_level` field
+//! (e.g. `info_span!("task_execution", task_log_level = "debug")`). The
+//! [`TaskLogLevelFilter`] lets events inside such a span through at the task's
+//! level, while everything else keeps using the system default filter.
+//!
+//! Callsite interest is only widened for the levels overrides have actually
+//! asked for, so logging outside task spans keeps the default filter's
+//! caching until a task requests something more verbose.
+
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::subscriber::Interest;
+use tracing::{Level, Metadata, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Filter};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Span field name workers use to request a per-task log level
+pub const TASK_LOG_LEVEL_FIELD: &str = "task_log_level";
+
+/// Level override stored in the extensions of a task execution span
+#[derive(Debug, Clone, Copy)]
+struct TaskLogLevel(LevelFilter);
+
+/// Marks "no override seen yet" in [`TaskLogLevelFilter::quietest`]
+const NO_OVERRIDE: u8 = u8::MAX;
+
+/// Verbosity rank of a level filter, from `OFF` (0) to `TRACE` (5)
+fn filter_rank(filter: LevelFilter) -> u8 {
+    match filter.into_level() {
+        None => 0,
+        Some(level) => level_rank(level),
+    }
+}
+
+/// Verbosity rank of a level, from `ERROR` (1) to `TRACE` (5)
+fn level_rank(level: Level) -> u8 {
+    match level {
+        Level::ERROR => 1,
+        Level::WARN => 2,
+        Level::INFO => 3,
+        Level::DEBUG => 4,
+        Level::TRACE => 5,
+    }
+}
+
+/// Inverse of [`filter_rank`]
+fn rank_filter(rank: u8) -> LevelFilter {
+    match rank {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::ERROR,
+        2 => LevelFilter::WARN,
+        3 => LevelFilter::INFO,
+        4 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Whether a callsite declares the per-task level field, i.e. is a task execution span
+fn is_task_span(meta: &Metadata<'_>) -> bool {
+    meta.is_span() && meta.fields().field(TASK_LOG_LEVEL_FIELD).is_some()
+}
+
+/// Layer filter honouring per-task log level overrides on top of a default filter
+pub struct TaskLogLevelFilter<F> {
+    default: F,
+    /// Rank of the most verbose override seen so far, plus one (0 when none was seen)
+    loudest: AtomicU8,
+    /// Rank of the least verbose override seen so far ([`NO_OVERRIDE`] when none was seen)
+    quietest: AtomicU8,
+}
+
+impl<F> TaskLogLevelFilter<F> {
+    /// Wrap the system default filter
+    pub fn new(default: F) -> Self {
+        Self {
+            default,
+            loudest: AtomicU8::new(0),
+            quietest: AtomicU8::new(NO_OVERRIDE),
+        }
+    }
+
+    /// Record an override level, returning whether it widened the range seen so far
+    fn record_override(&self, level: LevelFilter) -> bool {
+        let rank = filter_rank(level);
+        let louder = self.loudest.fetch_max(rank + 1, Ordering::AcqRel) < rank + 1;
+        let quieter = self.quietest.fetch_min(rank, Ordering::AcqRel) > rank;
+        louder || quieter
+    }
+
+    /// Range of override ranks seen so far as `(quietest, loudest)`
+    fn override_range(&self) -> Option<(u8, u8)> {
+        match self.loudest.load(Ordering::Acquire) {
+            0 => None,
+            loudest => Some((self.quietest.load(Ordering::Acquire), loudest - 1)),
+        }
+    }
+
+    /// Find the innermost task level override for the current span scope
+    fn override_in_scope<S>(cx: &Context<'_, S>) -> Option<LevelFilter>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let current = cx.lookup_current()?;
+        current
+            .scope()
+            .find_map(|span| span.extensions().get::<TaskLogLevel>().map(|level| level.0))
+    }
+}
+
+impl<S, F> Filter<S> for TaskLogLevelFilter<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    F: Filter<S>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        // Task spans always exist so their override can apply, whatever the default level
+        if is_task_span(meta) {
+            return true;
+        }
+
+        match Self::override_in_scope(cx) {
+            Some(level) => level >= *meta.level(),
+            None => self.default.enabled(meta, cx),
+        }
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        if is_task_span(meta) {
+            return Interest::always();
+        }
+
+        let default = self.default.callsite_enabled(meta);
+        let Some((quietest, loudest)) = self.override_range() else {
+            return default;
+        };
+
+        // Only dynamic when some task span could decide differently from the default
+        let rank = level_rank(*meta.level());
+        if default.is_always() && quietest >= rank {
+            Interest::always()
+        } else if default.is_never() && loudest < rank {
+            Interest::never()
+        } else {
+            Interest::sometimes()
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        let default = self.default.max_level_hint().unwrap_or(LevelFilter::TRACE);
+        let loudest = self
+            .override_range()
+            .map_or(LevelFilter::OFF, |(_, loudest)| rank_filter(loudest));
+        // Task spans are created at INFO, so that level must stay reachable
+        Some(default.max(loudest).max(LevelFilter::INFO))
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, cx: Context<'_, S>) {
+        let mut visitor = TaskLevelVisitor(None);
+        attrs.record(&mut visitor);
+        if let Some(level) = visitor.0 {
+            if let Some(span) = cx.span(id) {
+                span.extensions_mut().insert(TaskLogLevel(level));
+            }
+            // Callsite interest cached before this level was seen may now be wrong
+            if self.record_override(level) {
+                tracing::callsite::rebuild_interest_cache();
+            }
+        }
+
+        self.default.on_new_span(attrs, id, cx);
+    }
+
+    fn on_enter(&self, id: &Id, cx: Context<'_, S>) {
+        self.default.on_enter(id, cx);
+    }
+
+    fn on_exit(&self, id: &Id, cx: Context<'_, S>) {
+        self.default.on_exit(id, cx);
+    }
+
+    fn on_close(&self, id: Id, cx: Context<'_, S>) {
+        self.default.on_close(id, cx);
+    }
+}
+
+/// Extracts the `task_log_level` field from span attributes
+struct TaskLevelVisitor(Option<LevelFilter>);
+
+impl Visit for TaskLevelVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == TASK_LOG_LEVEL_FIELD {
+            self.0 = LevelFilter::from_str(value).ok();
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == TASK_LOG_LEVEL_FIELD {
+            self.0 = LevelFilter::from_str(format!("{:?}", value).trim_matches('"')).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::Visit;
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::Layer;
+
+    /// Collects the messages of every event that reaches it
+    struct CaptureLayer(Arc<Mutex<Vec<String>>>);
+
+    impl<S: Subscriber> Layer<S> for CaptureLayer {
+        fn on_event(&self, event: &tracing::Event<'_>, _cx: tracing_subscriber::layer::Context<'_, S>) {
+            struct Message(String);
+            impl Visit for Message {
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    if field.name() == "message" {
+                        self.0 = format!("{:?}", value);
+                    }
+                }
+            }
+
+            let mut message = Message(String::new());
+            event.record(&mut message);
+            self.0.lock().unwrap().push(message.0);
+        }
+    }
+
+    #[test]
+    fn test_debug_override_only_applies_to_its_task() {
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry()
+            .with(CaptureLayer(captured.clone()).with_filter(TaskLogLevelFilter::new(LevelFilter::INFO)));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("task_execution", task_log_level = "debug").in_scope(|| {
+                tracing::debug!("verbose task debug");
+                tracing::trace!("verbose task trace");
+            });
+
+            tracing::info_span!("task_execution").in_scope(|| {
+                tracing::debug!("default task debug");
+                tracing::info!("default task info");
+            });
+
+            tracing::debug!("system debug");
+        });
+
+        assert_eq!(
+            *captured.lock().unwrap(),
+            vec!["verbose task debug".to_string(), "default task info".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_quieter_override_suppresses_info() {
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry()
+            .with(CaptureLayer(captured.clone()).with_filter(TaskLogLevelFilter::new(LevelFilter::INFO)));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("task_execution", task_log_level = "warn").in_scope(|| {
+                tracing::info!("noisy task info");
+                tracing::warn!("noisy task warning");
+            });
+        });
+
+        assert_eq!(*captured.lock().unwrap(), vec!["noisy task warning".to_string()]);
+    }
+
+    /// A single debug callsite, so its cached interest is shared between calls
+    fn debug_message(message: &str) {
+        tracing::debug!("{}", message);
+    }
+
+    #[test]
+    fn test_override_applies_to_callsites_cached_before_it() {
+        let captured = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry()
+            .with(CaptureLayer(captured.clone()).with_filter(TaskLogLevelFilter::new(LevelFilter::WARN)));
+
+        tracing::subscriber::with_default(subscriber, || {
+            debug_message("before any task");
+
+            tracing::info_span!("task_execution", task_log_level = "debug").in_scope(|| {
+                debug_message("inside debug task");
+            });
+
+            debug_message("after the task");
+        });
+
+        assert_eq!(*captured.lock().unwrap(), vec!["inside debug task".to_string()]);
+    }
+}
@@ -1,4 +1,4 @@
-use ratchet_logging::{init_logger, LogLevel, LoggingConfig};
+use ratchet_logging::{init_logger, LogEvent, LogLevel, LoggingConfig};
 use tempfile::tempdir;
 
 #[test]
@@ -81,3 +81,42 @@ sinks:
     let built_logger = config.build_logger().unwrap();
     assert_eq!(built_logger.min_level(), LogLevel::Trace);
 }
+
+#[test]
+fn test_redaction_masks_secrets_before_sinks() {
+    let temp_dir = tempdir().unwrap();
+    let log_path = temp_dir.path().join("redacted.log");
+
+    let yaml_config = format!(
+        r#"
+sinks:
+  - type: file
+    path: {:?}
+enrichment:
+  system_info: false
+  process_info: false
+redaction:
+  enabled: true
+  fields: ["password", "*_token"]
+"#,
+        log_path
+    );
+
+    let config: LoggingConfig = serde_yaml::from_str(&yaml_config).unwrap();
+    let built_logger = config.build_logger().unwrap();
+    built_logger.log(
+        LogEvent::new(LogLevel::Info, "task input")
+            .with_field("user", "ann")
+            .with_field(
+                "input",
+                serde_json::json!({"password": "hunter2", "refresh_token": "abc123"}),
+            ),
+    );
+    drop(built_logger);
+
+    let contents = std::fs::read_to_string(&log_path).unwrap();
+    assert!(contents.contains("***REDACTED***"));
+    assert!(contents.contains("ann"));
+    assert!(!contents.contains("hunter2"));
+    assert!(!contents.contains("abc123"));
+}
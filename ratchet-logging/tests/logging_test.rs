@@ -3,9 +3,11 @@ use ratchet_logging::{
     init_logger, logger,
     logger::LogSink,
     sinks::{ConsoleSink, FileSink},
-    ErrorInfo, ErrorSeverity, LogContext, LogEvent, LogLevel, LoggerBuilder,
+    ErrorInfo, ErrorPatternMatcher, ErrorSeverity, LogContext, LogEvent, LogLevel, LoggerBuilder, PatternSampling,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tempfile::tempdir;
 
 #[test]
@@ -144,3 +146,172 @@ async fn test_async_context_scope() {
 
     assert_eq!(result, 42);
 }
+
+/// Sink recording the events it receives
+struct RecordingSink {
+    min_level: LogLevel,
+    events: std::sync::Mutex<Vec<LogEvent>>,
+}
+
+impl RecordingSink {
+    fn new(min_level: LogLevel) -> Self {
+        Self {
+            min_level,
+            events: std::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    fn levels(&self) -> Vec<LogLevel> {
+        self.events().iter().map(|event| event.level).collect()
+    }
+
+    fn events(&self) -> Vec<LogEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl LogSink for RecordingSink {
+    fn log(&self, event: LogEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+
+    fn flush(&self) {}
+
+    fn min_level(&self) -> LogLevel {
+        self.min_level
+    }
+}
+
+#[test]
+fn test_per_sink_level_filtering() {
+    let file_sink = Arc::new(RecordingSink::new(LogLevel::Debug));
+    let console_sink = Arc::new(RecordingSink::new(LogLevel::Warn));
+    let test_logger = LoggerBuilder::new()
+        .with_min_level(LogLevel::Debug)
+        .add_sink(file_sink.clone())
+        .add_sink(console_sink.clone())
+        .build();
+
+    for level in [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ] {
+        test_logger.log(LogEvent::new(level, "event"));
+    }
+
+    assert_eq!(
+        file_sink.levels(),
+        vec![LogLevel::Debug, LogLevel::Info, LogLevel::Warn, LogLevel::Error]
+    );
+    assert_eq!(console_sink.levels(), vec![LogLevel::Warn, LogLevel::Error]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_error_pattern_sampling_suppresses_floods() {
+    let sampling = HashMap::from([(
+        "db_connection_timeout".to_string(),
+        PatternSampling::new(3, Duration::from_millis(200)),
+    )]);
+    let sink = Arc::new(RecordingSink::new(LogLevel::Trace));
+    let test_logger = LoggerBuilder::new()
+        .with_min_level(LogLevel::Info)
+        .add_sink(sink.clone())
+        .with_error_sampling(Arc::new(
+            ErrorPatternMatcher::with_defaults().with_pattern_sampling(&sampling),
+        ))
+        .build();
+
+    let connection_timeout = || {
+        LogEvent::new(LogLevel::Error, "Database unavailable").with_error(ErrorInfo::new(
+            "DatabaseError",
+            "DB_CONN_ERROR",
+            "Connection timed out",
+        ))
+    };
+
+    for _ in 0..100 {
+        test_logger.log(connection_timeout());
+    }
+    test_logger.log(LogEvent::new(LogLevel::Info, "unrelated"));
+    assert_eq!(
+        sink.levels(),
+        vec![LogLevel::Error, LogLevel::Error, LogLevel::Error, LogLevel::Info]
+    );
+
+    // Once the interval ends the suppressed count is reported without waiting for another event
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    assert_eq!(sink.levels()[4..], [LogLevel::Warn]);
+
+    test_logger.log(connection_timeout());
+    assert_eq!(sink.levels()[4..], [LogLevel::Warn, LogLevel::Error]);
+}
+
+#[tokio::test]
+async fn test_request_context_flows_into_spawned_jobs() {
+    let sink = Arc::new(RecordingSink::new(LogLevel::Trace));
+    let test_logger = Arc::new(LoggerBuilder::new().add_sink(sink.clone()).build());
+
+    let request_context = LogContext::new().with_field("request_id", "req-7");
+    let trace_id = request_context.trace_id.clone();
+
+    let job_logger = test_logger.clone();
+    request_context
+        .scope(async move {
+            LogContext::spawn(async move {
+                job_logger.log(LogEvent::new(LogLevel::Info, "background job finished"));
+            })
+            .await
+            .unwrap();
+        })
+        .await;
+
+    // Outside the scope events carry no trace context
+    test_logger.log(LogEvent::new(LogLevel::Info, "unscoped"));
+
+    let events = sink.events();
+    assert_eq!(events[0].trace_id.as_deref(), Some(trace_id.as_str()));
+    assert_eq!(events[0].fields["request_id"], "req-7");
+    assert!(events[1].trace_id.is_none());
+    assert!(!events[1].fields.contains_key("request_id"));
+}
+
+#[tokio::test]
+async fn test_severity_hook_alerts_on_critical_errors() {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let sink = Arc::new(RecordingSink::new(LogLevel::Trace));
+    let test_logger = LoggerBuilder::new()
+        .add_sink(sink.clone())
+        .on_severity(ErrorSeverity::High, |_event, _error| async {
+            Err(anyhow::anyhow!("pager unreachable"))
+        })
+        .on_severity(ErrorSeverity::Critical, move |event, error| {
+            let tx = tx.clone();
+            async move {
+                tx.send((event.message, error.error_code))?;
+                Ok(())
+            }
+        })
+        .build();
+
+    let error_event = |message: &str, severity| {
+        LogEvent::new(LogLevel::Error, message)
+            .with_error(ErrorInfo::new("StorageError", "DISK_FULL", "No space left").with_severity(severity))
+    };
+    test_logger.log(error_event("disk nearly full", ErrorSeverity::High));
+    test_logger.log(error_event("disk full", ErrorSeverity::Critical));
+    test_logger.log(LogEvent::new(LogLevel::Info, "still logging"));
+
+    let (message, code) = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(message, "disk full");
+    assert_eq!(code, "DISK_FULL");
+    assert!(rx.try_recv().is_err());
+
+    // The failing callback doesn't affect the sinks
+    assert_eq!(sink.levels(), vec![LogLevel::Error, LogLevel::Error, LogLevel::Info]);
+}
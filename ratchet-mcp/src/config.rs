@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
-use crate::{error::McpResult, security::McpAuth};
+use crate::{error::McpResult, security::McpAuth, server::progress::ProgressChannelConfig};
 
 /// Simple transport type for basic configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -48,6 +48,10 @@ pub struct McpConfig {
     /// Tool configuration
     #[serde(default)]
     pub tools: ToolConfig,
+
+    /// Backpressure of the progress notification channel
+    #[serde(default)]
+    pub progress: ProgressChannelConfig,
 }
 
 impl Default for McpConfig {
@@ -60,6 +64,7 @@ impl Default for McpConfig {
             limits: ConnectionLimits::default(),
             timeouts: Timeouts::default(),
             tools: ToolConfig::default(),
+            progress: ProgressChannelConfig::default(),
         }
     }
 }
@@ -842,11 +842,38 @@ pub struct BatchItemResult {
     #[serde(default)]
     pub skipped: bool,
 
+    /// Why the request was skipped
+    #[serde(skip_serializing_if = "Option::is_none", rename = "skipReason", default)]
+    pub skip_reason: Option<BatchSkipReason>,
+
     /// Additional item metadata
     #[serde(flatten)]
     pub metadata: HashMap<String, Value>,
 }
 
+/// Why a batch request was not executed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum BatchSkipReason {
+    /// A dependency failed or was itself skipped
+    #[serde(rename = "dependencyFailed")]
+    DependencyFailed {
+        /// Direct dependency that did not succeed
+        dependency: String,
+        /// Request whose failure blocked this one
+        #[serde(rename = "failedRequest")]
+        failed_request: String,
+    },
+
+    /// An earlier request failed and the batch stops on errors
+    #[serde(rename = "stopOnError")]
+    StopOnError {
+        /// Request whose failure stopped the batch
+        #[serde(rename = "failedRequest")]
+        failed_request: String,
+    },
+}
+
 /// Batch execution statistics
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BatchStats {
@@ -8,9 +8,10 @@ pub use capabilities::{ClientCapabilities, McpCapabilities, ServerCapabilities,
 pub use jsonrpc::{JsonRpcError, JsonRpcErrorCode, JsonRpcRequest, JsonRpcResponse};
 pub use messages::{
     BatchCapability, BatchExecutionMode, BatchItemResult, BatchParams, BatchProgressNotification, BatchRequest,
-    BatchResult, BatchStats, ClientInfo, InitializeParams, InitializeResult, McpMessage, McpMethod, McpNotification,
-    McpRequest, McpResponse, ResourcesListParams, ResourcesListResult, ResourcesReadParams, ResourcesReadResult,
-    ServerInfo, Tool, ToolContent, ToolsCallParams, ToolsCallResult, ToolsListParams, ToolsListResult,
+    BatchResult, BatchSkipReason, BatchStats, ClientInfo, InitializeParams, InitializeResult, McpMessage, McpMethod,
+    McpNotification, McpRequest, McpResponse, ResourcesListParams, ResourcesListResult, ResourcesReadParams,
+    ResourcesReadResult, ServerInfo, Tool, ToolContent, ToolsCallParams, ToolsCallResult, ToolsListParams,
+    ToolsListResult,
 };
 
 use serde::{Deserialize, Serialize};
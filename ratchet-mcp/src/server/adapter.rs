@@ -49,7 +49,16 @@ impl ExecutorType {
 
                 // Convert task_id to string and create execution context if provided
                 let task_id_str = task_id.to_string();
-                let exec_context = context.map(|_| ExecutionContext::new().with_timeout(Duration::from_secs(300)));
+                let exec_context = context.map(|ctx| {
+                    let mut exec_context = ExecutionContext::new().with_timeout(Duration::from_secs(300));
+                    if let Ok(execution_id) = uuid::Uuid::parse_str(&ctx.execution_id) {
+                        exec_context = exec_context.with_execution_id(execution_id);
+                    }
+                    match ctx.log_level {
+                        Some(log_level) => exec_context.with_log_level(log_level),
+                        None => exec_context,
+                    }
+                });
 
                 // Execute using the bridge interface
                 match executor.execute_task(&task_id_str, input_data, exec_context).await {
@@ -72,6 +81,7 @@ impl ExecutorType {
                             started_at,
                             completed_at: now,
                             duration_ms: result.execution_time_ms as i32,
+                            logs: result.logs,
                         })
                     }
                     Err(e) => Err(e),
@@ -215,7 +225,17 @@ impl McpTaskExecutor for RatchetMcpAdapter {
 
         // Create an execution context
         use ratchet_execution::ipc::ExecutionContext;
-        let context = ExecutionContext::new(uuid::Uuid::new_v4(), None, task.uuid, task.version.clone());
+        let mut context = ExecutionContext::new(uuid::Uuid::new_v4(), None, task.uuid, task.version.clone());
+
+        // Tasks can request a more or less verbose log level via `log_level` in their metadata
+        if let Some(log_level) = task
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("log_level"))
+            .and_then(|level| level.as_str())
+        {
+            context = context.with_log_level(log_level);
+        }
 
         // Convert string ID to i32 for legacy execution interface
         // For registry tasks, we'll use a synthetic ID since they're not stored in DB
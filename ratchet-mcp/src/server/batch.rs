@@ -2,8 +2,8 @@
 
 use crate::error::McpError;
 use crate::protocol::{
-    BatchExecutionMode, BatchItemResult, BatchParams, BatchProgressNotification, BatchRequest, BatchResult, BatchStats,
-    JsonRpcError, JsonRpcRequest, JsonRpcResponse,
+    BatchExecutionMode, BatchItemResult, BatchParams, BatchProgressNotification, BatchRequest, BatchResult,
+    BatchSkipReason, BatchStats, JsonRpcError, JsonRpcRequest, JsonRpcResponse,
 };
 use chrono::Utc;
 use serde_json::Value;
@@ -24,10 +24,14 @@ pub type BatchRequestHandler =
 /// Type for progress notification callback
 pub type ProgressCallback = dyn Fn(BatchProgressNotification) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
 
+pub use ratchet_config::domains::execution::DEFAULT_MAX_DEPENDENCY_DEPTH;
+
 /// Batch processor for handling batch operations
 pub struct BatchProcessor {
     /// Maximum batch size allowed
     max_batch_size: u32,
+    /// Maximum length of a dependency chain, counting the requests along it
+    max_dependency_depth: usize,
     /// Maximum parallel executions
     max_parallel: u32,
     /// Default timeout for batch operations
@@ -76,6 +80,7 @@ impl BatchProcessor {
     ) -> Self {
         Self {
             max_batch_size,
+            max_dependency_depth: DEFAULT_MAX_DEPENDENCY_DEPTH,
             max_parallel,
             default_timeout,
             request_handler,
@@ -97,6 +102,7 @@ impl BatchProcessor {
     ) -> Self {
         Self {
             max_batch_size,
+            max_dependency_depth: DEFAULT_MAX_DEPENDENCY_DEPTH,
             max_parallel,
             default_timeout,
             request_handler,
@@ -106,6 +112,14 @@ impl BatchProcessor {
         }
     }
 
+    /// Set the longest chain of dependent requests a batch may contain
+    ///
+    /// Batches with deeper chains are rejected before any request runs.
+    pub fn with_max_dependency_depth(mut self, depth: usize) -> Self {
+        self.max_dependency_depth = depth;
+        self
+    }
+
     /// Process a batch request
     pub async fn process_batch(&self, params: BatchParams) -> Result<BatchResult, McpError> {
         self.process_batch_with_handler(params, &self.request_handler).await
@@ -297,6 +311,9 @@ impl BatchProcessor {
         // Check for circular dependencies
         self.detect_circular_dependencies(&nodes, &edges)?;
 
+        // Reject chains too deep to run
+        self.check_dependency_depth(&nodes)?;
+
         // Build initial ready queue (items with no dependencies)
         let ready_queue: VecDeque<String> = nodes
             .iter()
@@ -332,6 +349,28 @@ impl BatchProcessor {
         Ok(())
     }
 
+    /// Reject dependency chains longer than the maximum depth
+    ///
+    /// Must run after cycle detection, as the depth of a cycle is unbounded.
+    fn check_dependency_depth(&self, nodes: &HashMap<String, BatchItemContext>) -> Result<(), McpError> {
+        let mut depths = HashMap::new();
+
+        for node_id in nodes.keys() {
+            let depth = dependency_depth(node_id, nodes, &mut depths);
+            if depth > self.max_dependency_depth {
+                return Err(McpError::Validation {
+                    field: "dependencies".to_string(),
+                    message: format!(
+                        "Request {} ends a dependency chain of {} requests, exceeding the maximum depth of {}",
+                        node_id, depth, self.max_dependency_depth
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// DFS helper for cycle detection
     fn has_cycle_dfs(
         &self,
@@ -429,6 +468,7 @@ impl BatchProcessor {
                     error: response.error,
                     execution_time_ms: execution_time.as_millis() as u64,
                     skipped: false,
+                    skip_reason: None,
                     metadata: HashMap::new(),
                 };
 
@@ -519,6 +559,7 @@ impl BatchProcessor {
                 error: response.error.clone(),
                 execution_time_ms: execution_time.as_millis() as u64,
                 skipped: false,
+                skip_reason: None,
                 metadata: HashMap::new(),
             };
 
@@ -527,6 +568,7 @@ impl BatchProcessor {
             // Check for error and stop if required
             if params.stop_on_error && response.error.is_some() {
                 warn!("Request {} failed, stopping batch execution", id);
+                let failed_request = id.clone();
                 // Mark remaining requests as skipped
                 for (remaining_id, _) in graph.nodes.iter().skip(index + 1) {
                     results.push(BatchItemResult {
@@ -535,6 +577,9 @@ impl BatchProcessor {
                         error: Some(JsonRpcError::internal_error("Skipped due to previous error")),
                         execution_time_ms: 0,
                         skipped: true,
+                        skip_reason: Some(BatchSkipReason::StopOnError {
+                            failed_request: failed_request.clone(),
+                        }),
                         metadata: HashMap::new(),
                     });
                 }
@@ -618,6 +663,7 @@ impl BatchProcessor {
                                 error: response.error,
                                 execution_time_ms: execution_time.as_millis() as u64,
                                 skipped: false,
+                                skip_reason: None,
                                 metadata: HashMap::new(),
                             };
 
@@ -657,10 +703,19 @@ impl BatchProcessor {
                     Ok(id) => {
                         executing.remove(&id);
 
+                        // Requests downstream of a failure are skipped; independent branches keep running
+                        let mut results_map = results.write().await;
+                        if results_map.get(&id).is_some_and(|result| result.error.is_some()) {
+                            Self::skip_dependents(graph, &id, &mut results_map);
+                        }
+
                         // Add dependents to ready queue if all their dependencies are complete
                         if let Some(dependents) = graph.edges.get(&id) {
                             let completed_set = completed.read().await;
                             for dependent in dependents {
+                                if results_map.contains_key(dependent) {
+                                    continue;
+                                }
                                 if let Some(dependent_context) = graph.nodes.get(dependent) {
                                     let all_deps_complete = dependent_context
                                         .dependencies
@@ -700,6 +755,43 @@ impl BatchProcessor {
         Ok(ordered_results)
     }
 
+    /// Record every request downstream of `failed_id` as skipped
+    fn skip_dependents(graph: &DependencyGraph, failed_id: &str, results: &mut HashMap<String, BatchItemResult>) {
+        let mut blocked: VecDeque<String> = VecDeque::from([failed_id.to_string()]);
+
+        while let Some(dependency) = blocked.pop_front() {
+            for dependent in graph.edges.get(&dependency).into_iter().flatten() {
+                if results.contains_key(dependent) {
+                    continue;
+                }
+
+                warn!(
+                    "Skipping request {} because dependency {} did not succeed",
+                    dependent, dependency
+                );
+                results.insert(
+                    dependent.clone(),
+                    BatchItemResult {
+                        id: dependent.clone(),
+                        result: None,
+                        error: Some(JsonRpcError::internal_error(format!(
+                            "Skipped because dependency {} did not succeed",
+                            dependency
+                        ))),
+                        execution_time_ms: 0,
+                        skipped: true,
+                        skip_reason: Some(BatchSkipReason::DependencyFailed {
+                            dependency: dependency.clone(),
+                            failed_request: failed_id.to_string(),
+                        }),
+                        metadata: HashMap::new(),
+                    },
+                );
+                blocked.push_back(dependent.clone());
+            }
+        }
+    }
+
     /// Execute based on priority and dependencies
     async fn execute_priority_dependency_based(
         &self,
@@ -754,6 +846,32 @@ impl BatchProcessor {
     }
 }
 
+/// Number of requests in the longest dependency chain ending at `node_id`
+fn dependency_depth(
+    node_id: &str,
+    nodes: &HashMap<String, BatchItemContext>,
+    depths: &mut HashMap<String, usize>,
+) -> usize {
+    if let Some(depth) = depths.get(node_id) {
+        return *depth;
+    }
+
+    let depth = nodes
+        .get(node_id)
+        .map(|context| {
+            context
+                .dependencies
+                .iter()
+                .map(|dep| dependency_depth(dep, nodes, depths))
+                .max()
+                .unwrap_or(0)
+                + 1
+        })
+        .unwrap_or(0);
+    depths.insert(node_id.to_string(), depth);
+    depth
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -874,6 +992,86 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_dependency_failure_skips_blocked_branch() {
+        let processor = BatchProcessor::new(
+            100,
+            10,
+            Duration::from_secs(30),
+            Arc::new(|request: JsonRpcRequest| {
+                Box::pin(async move {
+                    let fail = request.params.as_ref().is_some_and(|p| p["fail"] == json!(true));
+                    JsonRpcResponse {
+                        jsonrpc: "2.0".to_string(),
+                        result: (!fail).then(|| json!({"ok": true})),
+                        error: fail.then(|| JsonRpcError::internal_error("boom")),
+                        id: request.id,
+                    }
+                }) as Pin<Box<dyn Future<Output = JsonRpcResponse> + Send>>
+            }),
+            None,
+        );
+
+        let request = |id: &str, dependencies: &[&str], fail: bool| BatchRequest {
+            id: id.to_string(),
+            method: "test_method".to_string(),
+            params: Some(json!({ "fail": fail })),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            timeout_ms: None,
+            priority: 0,
+            metadata: HashMap::new(),
+        };
+
+        // fetch fails, blocking parse and store; the report branch is independent
+        let params = BatchParams {
+            requests: vec![
+                request("fetch", &[], true),
+                request("parse", &["fetch"], false),
+                request("store", &["parse"], false),
+                request("load", &[], false),
+                request("report", &["load"], false),
+            ],
+            execution_mode: BatchExecutionMode::Dependency,
+            max_parallel: Some(2),
+            timeout_ms: None,
+            stop_on_error: false,
+            correlation_token: None,
+            metadata: HashMap::new(),
+        };
+
+        let result = processor.process_batch(params).await.unwrap();
+        let item = |id: &str| result.results.iter().find(|r| r.id == id).unwrap();
+
+        assert!(item("fetch").error.is_some());
+        assert!(!item("fetch").skipped);
+        assert_eq!(
+            item("parse").skip_reason,
+            Some(BatchSkipReason::DependencyFailed {
+                dependency: "fetch".to_string(),
+                failed_request: "fetch".to_string(),
+            })
+        );
+        assert_eq!(
+            item("store").skip_reason,
+            Some(BatchSkipReason::DependencyFailed {
+                dependency: "parse".to_string(),
+                failed_request: "fetch".to_string(),
+            })
+        );
+        for id in ["load", "report"] {
+            assert!(item(id).result.is_some() && !item(id).skipped);
+        }
+
+        assert_eq!(result.stats.total_requests, 5);
+        assert_eq!(result.stats.successful_requests, 2);
+        assert_eq!(result.stats.failed_requests, 1);
+        assert_eq!(result.stats.skipped_requests, 2);
+
+        let serialized = serde_json::to_value(item("store")).unwrap();
+        assert_eq!(serialized["skipReason"]["type"], json!("dependencyFailed"));
+        assert_eq!(serialized["skipReason"]["failedRequest"], json!("fetch"));
+    }
+
     #[tokio::test]
     async fn test_circular_dependency_detection() {
         let processor = create_test_processor();
@@ -974,6 +1172,69 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("exceeds maximum allowed size"));
     }
 
+    #[tokio::test]
+    async fn test_dependency_depth_validation() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let handler_calls = calls.clone();
+        let processor = BatchProcessor::new(
+            100,
+            10,
+            Duration::from_secs(30),
+            Arc::new(move |request: JsonRpcRequest| {
+                handler_calls.fetch_add(1, Ordering::SeqCst);
+                Box::pin(async move {
+                    JsonRpcResponse {
+                        jsonrpc: "2.0".to_string(),
+                        result: Some(json!({})),
+                        error: None,
+                        id: request.id,
+                    }
+                }) as Pin<Box<dyn Future<Output = JsonRpcResponse> + Send>>
+            }),
+            None,
+        )
+        .with_max_dependency_depth(3);
+
+        // step0 <- step1 <- ... <- step{length - 1}, plus an independent request
+        let chain = |length: usize| BatchParams {
+            requests: (0..length)
+                .map(|i| BatchRequest {
+                    id: format!("step{}", i),
+                    method: "test".to_string(),
+                    params: None,
+                    dependencies: if i == 0 { vec![] } else { vec![format!("step{}", i - 1)] },
+                    timeout_ms: None,
+                    priority: 0,
+                    metadata: HashMap::new(),
+                })
+                .chain(std::iter::once(BatchRequest {
+                    id: "standalone".to_string(),
+                    method: "test".to_string(),
+                    params: None,
+                    dependencies: vec![],
+                    timeout_ms: None,
+                    priority: 0,
+                    metadata: HashMap::new(),
+                }))
+                .collect(),
+            execution_mode: BatchExecutionMode::Dependency,
+            max_parallel: None,
+            timeout_ms: None,
+            stop_on_error: false,
+            correlation_token: None,
+            metadata: HashMap::new(),
+        };
+
+        let result = processor.process_batch(chain(4)).await;
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("exceeding the maximum depth of 3"), "{}", error);
+        assert_eq!(calls.load(Ordering::SeqCst), 0, "no request may run");
+
+        let result = processor.process_batch(chain(3)).await.unwrap();
+        assert_eq!(result.stats.successful_requests, 4);
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
     #[tokio::test]
     async fn test_request_deduplication() {
         let processor = BatchProcessor::new_optimized(
@@ -92,7 +92,7 @@ impl McpServer {
     /// Create a new MCP server with adapter
     pub async fn with_adapter(config: crate::config::McpConfig, adapter: RatchetMcpAdapter) -> McpResult<Self> {
         // Create tool registry from adapter
-        let mut tool_registry = RatchetToolRegistry::new();
+        let mut tool_registry = RatchetToolRegistry::new().with_progress_config(config.progress.clone());
         tool_registry.set_executor(Arc::new(adapter));
 
         // Create security components
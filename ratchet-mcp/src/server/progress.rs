@@ -1,9 +1,10 @@
 //! Progress notification handling for streaming task execution
 
 use serde_json::Value;
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{Notify, RwLock};
 use uuid::Uuid;
 
 use crate::protocol::messages::{McpMethod, McpNotification, TaskProgressNotification};
@@ -23,13 +24,99 @@ pub struct ProgressUpdate {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+impl ProgressUpdate {
+    /// Whether this update marks the end of an execution and must always be delivered
+    pub fn is_final(&self) -> bool {
+        self.progress >= 1.0 || matches!(self.step.as_deref(), Some("completed") | Some("failed"))
+    }
+}
+
+pub use ratchet_config::domains::mcp::{ProgressChannelConfig, ProgressOverflowPolicy};
+
+/// Bounded queue shared between the manager and its processing task
+struct ProgressQueue {
+    config: ProgressChannelConfig,
+    pending: Mutex<VecDeque<ProgressNotification>>,
+    notify: Notify,
+    closed: AtomicBool,
+    dropped: AtomicU64,
+}
+
+impl ProgressQueue {
+    fn new(config: ProgressChannelConfig) -> Self {
+        Self {
+            config,
+            pending: Mutex::new(VecDeque::new()),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Enqueue a notification, applying the overflow policy. Final updates are never dropped.
+    fn push(&self, notification: ProgressNotification) {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+
+        let coalesced = self.config.overflow_policy == ProgressOverflowPolicy::Coalesce
+            && !notification.update.is_final()
+            && match pending
+                .iter_mut()
+                .rev()
+                .find(|n| n.execution_id == notification.execution_id && !n.update.is_final())
+            {
+                Some(existing) => {
+                    *existing = notification.clone();
+                    true
+                }
+                None => false,
+            };
+
+        if coalesced {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        } else {
+            pending.push_back(notification);
+        }
+
+        while pending.len() > self.config.capacity.max(1) {
+            match pending.iter().position(|n| !n.update.is_final()) {
+                Some(index) => {
+                    pending.remove(index);
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                // Only final updates are pending; let the queue overflow rather than lose them
+                None => break,
+            }
+        }
+
+        drop(pending);
+        self.notify.notify_one();
+    }
+
+    fn pop(&self) -> Option<ProgressNotification> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).pop_front()
+    }
+
+    fn len(&self) -> usize {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+}
+
 /// Progress notification manager that handles streaming updates for long-running tasks
 pub struct ProgressNotificationManager {
     /// Active progress subscriptions
     subscriptions: Arc<RwLock<HashMap<String, Vec<ProgressSubscription>>>>,
 
-    /// Notification sender channel
-    notification_sender: mpsc::UnboundedSender<ProgressNotification>,
+    /// Bounded notification queue drained by the processing task
+    queue: Arc<ProgressQueue>,
 }
 
 /// Progress subscription details
@@ -97,20 +184,23 @@ struct ProgressNotification {
 impl ProgressNotificationManager {
     /// Create a new progress notification manager
     pub fn new() -> Self {
-        let (notification_sender, notification_receiver) = mpsc::unbounded_channel();
+        Self::with_config(ProgressChannelConfig::default())
+    }
+
+    /// Create a progress notification manager with custom backpressure settings
+    pub fn with_config(config: ProgressChannelConfig) -> Self {
+        let queue = Arc::new(ProgressQueue::new(config));
 
         let subscriptions = Arc::new(RwLock::new(HashMap::new()));
 
         // Start the notification processing task
         let subscriptions_clone = subscriptions.clone();
+        let queue_clone = queue.clone();
         tokio::spawn(async move {
-            Self::process_notifications(subscriptions_clone, notification_receiver).await;
+            Self::process_notifications(subscriptions_clone, queue_clone).await;
         });
 
-        Self {
-            subscriptions,
-            notification_sender,
-        }
+        Self { subscriptions, queue }
     }
 
     /// Subscribe to progress updates for a specific execution
@@ -172,13 +262,25 @@ impl ProgressNotificationManager {
             update,
         };
 
-        self.notification_sender
-            .send(notification)
-            .map_err(|e| format!("Failed to send progress notification: {}", e))?;
+        if self.queue.is_closed() {
+            return Err("Failed to send progress notification: channel closed".to_string());
+        }
+
+        self.queue.push(notification);
 
         Ok(())
     }
 
+    /// Number of notifications waiting to be delivered
+    pub fn pending_notifications(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Number of notifications dropped or coalesced due to backpressure
+    pub fn dropped_notifications(&self) -> u64 {
+        self.queue.dropped.load(Ordering::Relaxed)
+    }
+
     /// Get number of active subscriptions for an execution
     pub async fn get_subscription_count(&self, execution_id: &str) -> usize {
         let subscriptions = self.subscriptions.read().await;
@@ -201,9 +303,18 @@ impl ProgressNotificationManager {
     /// Process notifications and send them to subscribers
     async fn process_notifications(
         subscriptions: Arc<RwLock<HashMap<String, Vec<ProgressSubscription>>>>,
-        mut receiver: mpsc::UnboundedReceiver<ProgressNotification>,
+        queue: Arc<ProgressQueue>,
     ) {
-        while let Some(notification) = receiver.recv().await {
+        loop {
+            let notification = match queue.pop() {
+                Some(notification) => notification,
+                None if queue.is_closed() => break,
+                None => {
+                    queue.notify.notified().await;
+                    continue;
+                }
+            };
+
             let subscriptions_guard = subscriptions.read().await;
 
             if let Some(subs) = subscriptions_guard.get(&notification.execution_id) {
@@ -303,6 +414,12 @@ impl Default for ProgressNotificationManager {
     }
 }
 
+impl Drop for ProgressNotificationManager {
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
 /// Progress tracking helper for tasks
 pub struct TaskProgressTracker {
     execution_id: String,
@@ -557,4 +674,110 @@ mod tests {
             panic!("Expected TaskProgress notification");
         }
     }
+
+    struct SlowTransportConnection {
+        delay: tokio::time::Duration,
+        notifications: Arc<RwLock<Vec<McpNotification>>>,
+    }
+
+    #[async_trait]
+    impl TransportConnection for SlowTransportConnection {
+        async fn send_notification(&self, notification: McpNotification) -> crate::McpResult<()> {
+            tokio::time::sleep(self.delay).await;
+            self.notifications.write().await.push(notification);
+            Ok(())
+        }
+
+        async fn close(&self) -> crate::McpResult<()> {
+            Ok(())
+        }
+    }
+
+    fn progress_update(execution_id: &str, progress: f32) -> ProgressUpdate {
+        ProgressUpdate {
+            execution_id: execution_id.to_string(),
+            task_id: "test-task".to_string(),
+            progress,
+            step: None,
+            step_number: None,
+            total_steps: None,
+            message: None,
+            data: None,
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_backpressure_bounds_slow_consumer() {
+        for policy in [ProgressOverflowPolicy::DropOldest, ProgressOverflowPolicy::Coalesce] {
+            let capacity = 8;
+            let manager = ProgressNotificationManager::with_config(ProgressChannelConfig {
+                capacity,
+                overflow_policy: policy,
+            });
+            let connection = Arc::new(SlowTransportConnection {
+                delay: tokio::time::Duration::from_millis(20),
+                notifications: Arc::new(RwLock::new(Vec::new())),
+            });
+
+            let execution_id = "test-execution-slow";
+            manager
+                .subscribe_to_execution(execution_id.to_string(), connection.clone(), None)
+                .await;
+
+            // Flood the channel far faster than the consumer can drain it
+            for i in 0..10_000 {
+                manager
+                    .send_progress_update(progress_update(execution_id, i as f32 / 10_000.0))
+                    .await
+                    .unwrap();
+                assert!(manager.pending_notifications() <= capacity);
+            }
+            manager
+                .send_progress_update(progress_update(execution_id, 1.0))
+                .await
+                .unwrap();
+            assert!(manager.pending_notifications() <= capacity);
+            assert!(manager.dropped_notifications() > 0);
+
+            // Wait for the queue to drain
+            for _ in 0..100 {
+                if manager.pending_notifications() == 0 {
+                    break;
+                }
+                tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+            let notifications = connection.notifications.read().await;
+            assert!(notifications.len() <= capacity + 2, "policy {:?} delivered too many", policy);
+
+            // The final update is never dropped and is delivered last
+            match &notifications.last().unwrap().method {
+                McpMethod::NotificationsTaskProgress(task_progress) => assert_eq!(task_progress.progress, 1.0),
+                _ => panic!("Expected TaskProgress notification"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_coalesce_keeps_latest_update() {
+        let manager = ProgressNotificationManager::with_config(ProgressChannelConfig {
+            capacity: 4,
+            overflow_policy: ProgressOverflowPolicy::Coalesce,
+        });
+
+        // No subscribers and no yield point, so updates stay queued and collapse into one
+        for progress in [0.1, 0.2, 0.3] {
+            manager
+                .send_progress_update(progress_update("exec", progress))
+                .await
+                .unwrap();
+        }
+        assert_eq!(manager.pending_notifications(), 1);
+        assert_eq!(manager.dropped_notifications(), 2);
+
+        manager.send_progress_update(progress_update("exec", 1.0)).await.unwrap();
+        assert_eq!(manager.pending_notifications(), 2);
+    }
 }
@@ -14,7 +14,9 @@ use crate::protocol::{ToolContent, ToolsCallResult};
 use crate::server::tools::{McpTool, ToolExecutionContext};
 use crate::{McpError, McpResult};
 
+use ratchet_core::JsonDiff;
 use ratchet_http::HttpManager;
+use ratchet_js::ResourceLimits;
 use ratchet_storage::seaorm::entities::executions::{ExecutionStatus, Model as ExecutionModel};
 use ratchet_storage::seaorm::entities::tasks::Model as TaskModel;
 use ratchet_storage::seaorm::repositories::execution_repository::ExecutionRepository;
@@ -227,6 +229,127 @@ fn default_include_traces() -> bool {
     true
 }
 
+/// Task preflight request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreflightTaskRequest {
+    /// Task name or ID
+    #[serde(default)]
+    pub task_id: String,
+
+    /// Sample input for the dry-run (defaults to the first test case input, then `{}`)
+    #[serde(default)]
+    pub input: Option<Value>,
+
+    /// Maximum dry-run time in milliseconds
+    #[serde(default = "default_preflight_timeout")]
+    pub timeout_ms: u64,
+}
+
+impl PreflightTaskRequest {
+    /// Create a preflight request for a task using the default sample input
+    pub fn new(task_id: impl Into<String>) -> Self {
+        Self {
+            task_id: task_id.into(),
+            input: None,
+            timeout_ms: default_preflight_timeout(),
+        }
+    }
+}
+
+fn default_preflight_timeout() -> u64 {
+    30000 // 30 seconds
+}
+
+/// Limits a preflight dry-run executes under
+const PREFLIGHT_LIMITS: ResourceLimits = ResourceLimits {
+    max_heap_bytes: None,
+    max_loop_iterations: Some(1_000_000),
+    max_call_depth: None,
+};
+
+/// Outcome of a single preflight check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreflightCheck {
+    /// Whether the check passed
+    pub passed: bool,
+
+    /// Problems found by the check
+    pub errors: Vec<String>,
+}
+
+impl PreflightCheck {
+    fn from_errors(errors: Vec<String>) -> Self {
+        Self {
+            passed: errors.is_empty(),
+            errors,
+        }
+    }
+}
+
+/// Result of running a task against sample input during preflight
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreflightDryRun {
+    /// Whether the task ran to completion without errors
+    pub passed: bool,
+
+    /// Whether the dry-run was skipped because the task failed to compile
+    pub skipped: bool,
+
+    /// Input the task was run with
+    pub input: Value,
+
+    /// Output produced by the task
+    pub output: Option<Value>,
+
+    /// Error raised while running the task
+    pub error: Option<String>,
+
+    /// Time taken by the dry-run in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Consolidated report produced by a task preflight
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreflightReport {
+    /// Task UUID
+    pub task_id: String,
+
+    /// Task name
+    pub task_name: String,
+
+    /// Whether every stage passed and the task is ready for production
+    pub passed: bool,
+
+    /// Syntax and schema validation
+    pub validation: PreflightCheck,
+
+    /// JavaScript compilation
+    pub compile: PreflightCheck,
+
+    /// Dry-run against sample input
+    pub dry_run: PreflightDryRun,
+}
+
+impl PreflightReport {
+    /// Combine the stage results into a report
+    pub fn new(
+        task_id: String,
+        task_name: String,
+        validation: PreflightCheck,
+        compile: PreflightCheck,
+        dry_run: PreflightDryRun,
+    ) -> Self {
+        Self {
+            passed: validation.passed && compile.passed && dry_run.passed,
+            task_id,
+            task_name,
+            validation,
+            compile,
+            dry_run,
+        }
+    }
+}
+
 /// Task version creation request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskVersionRequest {
@@ -642,10 +765,8 @@ impl TaskDevelopmentService {
         let code = code.to_string();
         let input = input.clone();
         
-        // Execute JavaScript in a blocking task to handle thread safety
-        let result = tokio::task::spawn_blocking(move || {
-            Self::execute_js_sync(&code, input)
-        }).await;
+        // Execute JavaScript on its own thread, with the stack its call depth limit needs
+        let result = ratchet_js::run_on_task_thread(move || Self::execute_js_sync(&code, input, None)).await;
         
         match result {
             Ok(Ok(result)) => Ok(result),
@@ -655,7 +776,7 @@ impl TaskDevelopmentService {
     }
 
     /// Synchronous JavaScript execution without HTTP support
-    fn execute_js_sync(js_code: &str, input_data: Value) -> Result<Value, String> {
+    fn execute_js_sync(js_code: &str, input_data: Value, limits: Option<ResourceLimits>) -> Result<Value, String> {
         use boa_engine::{property::PropertyKey, Context as BoaContext, JsString, Script, Source};
         use ratchet_js::{
             conversion::{convert_js_result_to_json, prepare_input_argument},
@@ -664,6 +785,9 @@ impl TaskDevelopmentService {
         
         // Create context
         let mut context = BoaContext::default();
+        if let Some(limits) = limits {
+            limits.apply(&mut context);
+        }
         
         // Register error types
         if let Err(e) = register_error_types(&mut context) {
@@ -914,6 +1038,114 @@ impl TaskDevelopmentService {
         }))
     }
 
+    /// Run validation, compilation and a dry-run with sample input in one pass
+    ///
+    /// Stage failures are reported in the returned [`PreflightReport`]; an error is
+    /// only returned when the task cannot be found or its tests cannot be loaded.
+    pub async fn preflight_task(&self, request: PreflightTaskRequest) -> McpResult<PreflightReport> {
+        let task = self.find_task(&request.task_id).await?;
+
+        let mut validation_errors = Vec::new();
+        if let Err(e) = self.task_validator.validate_syntax(&task.code) {
+            validation_errors.push(format!("syntax: {}", e));
+        }
+        if !task.input_schema.is_null() {
+            if let Err(e) = self.validate_json_schema(&task.input_schema) {
+                validation_errors.push(format!("input_schema: {}", e));
+            }
+        }
+        if !task.output_schema.is_null() {
+            if let Err(e) = self.validate_json_schema(&task.output_schema) {
+                validation_errors.push(format!("output_schema: {}", e));
+            }
+        }
+
+        let input = match request.input {
+            Some(input) => input,
+            None => self
+                .load_task_tests(&task.name)
+                .await?
+                .into_iter()
+                .next()
+                .map(|test_case| test_case.input)
+                .unwrap_or_else(|| json!({})),
+        };
+
+        log::info!("Running preflight for task: {}", task.name);
+
+        let (compile, dry_run) =
+            Self::preflight_code(&task.code, input, std::time::Duration::from_millis(request.timeout_ms)).await;
+
+        Ok(PreflightReport::new(
+            task.uuid.to_string(),
+            task.name,
+            PreflightCheck::from_errors(validation_errors),
+            compile,
+            dry_run,
+        ))
+    }
+
+    /// Compile task code and dry-run it against sample input
+    ///
+    /// The dry-run is skipped when compilation fails. Boa cannot interrupt a
+    /// running script, so a dry-run that times out keeps its blocking thread
+    /// until the script returns; the loop budget of [`PREFLIGHT_LIMITS`] makes
+    /// sure a runaway loop gives the thread back instead of holding it forever.
+    async fn preflight_code(
+        code: &str,
+        input: Value,
+        timeout: std::time::Duration,
+    ) -> (PreflightCheck, PreflightDryRun) {
+        let compile = {
+            use boa_engine::{Context as BoaContext, Script, Source};
+
+            let mut context = BoaContext::default();
+            match Script::parse(Source::from_bytes(code), None, &mut context) {
+                Ok(_) => PreflightCheck::from_errors(Vec::new()),
+                Err(e) => PreflightCheck::from_errors(vec![format!("JavaScript compilation failed: {}", e)]),
+            }
+        };
+
+        if !compile.passed {
+            let dry_run = PreflightDryRun {
+                passed: false,
+                skipped: true,
+                input,
+                output: None,
+                error: None,
+                duration_ms: 0,
+            };
+            return (compile, dry_run);
+        }
+
+        let started = std::time::Instant::now();
+        let js_code = code.to_string();
+        let js_input = input.clone();
+        let result = tokio::time::timeout(
+            timeout,
+            ratchet_js::run_on_task_thread(move || Self::execute_js_sync(&js_code, js_input, Some(PREFLIGHT_LIMITS))),
+        )
+        .await;
+        let duration_ms = started.elapsed().as_millis() as u64;
+
+        let (output, error) = match result {
+            Ok(Ok(Ok(output))) => (Some(output), None),
+            Ok(Ok(Err(e))) => (None, Some(e)),
+            Ok(Err(e)) => (None, Some(format!("Task execution failed: {}", e))),
+            Err(_) => (None, Some(format!("Dry-run timed out after {}ms", timeout.as_millis()))),
+        };
+
+        let dry_run = PreflightDryRun {
+            passed: error.is_none(),
+            skipped: false,
+            input,
+            output,
+            error,
+            duration_ms,
+        };
+        (compile, dry_run)
+    }
+
     /// Debug task execution
     pub async fn debug_task(&self, request: DebugTaskRequest) -> McpResult<Value> {
         // Find the task
@@ -976,10 +1208,9 @@ impl TaskDevelopmentService {
         let breakpoints = request.breakpoints.clone();
         let step_mode = request.step_mode;
 
-        // Execute in a blocking task to handle thread safety
-        let result = tokio::task::spawn_blocking(move || {
-            Self::debug_js_sync(&code, input.clone(), breakpoints, step_mode)
-        }).await;
+        // Execute on its own thread, with the stack its call depth limit needs
+        let result =
+            ratchet_js::run_on_task_thread(move || Self::debug_js_sync(&code, input, breakpoints, step_mode)).await;
 
         match result {
             Ok(Ok(result)) => Ok(result),
@@ -2300,6 +2531,13 @@ impl TaskDevelopmentService {
                         passed += 1;
                     } else {
                         log::debug!("Test case {} failed - output mismatch", test_case.name);
+
+                        // Structured added/removed/changed paths are easier to read in CI than raw dumps
+                        let diff = test_case
+                            .expected_output
+                            .as_ref()
+                            .map(|expected| JsonDiff::between(expected, &actual_output));
+
                         test_results.push(json!({
                             "name": test_case.name,
                             "status": "failed",
@@ -2308,6 +2546,7 @@ impl TaskDevelopmentService {
                             "input": test_case.input,
                             "actual_output": actual_output,
                             "expected_output": test_case.expected_output,
+                            "diff": diff,
                             "description": test_case.description
                         }));
                         failed += 1;
@@ -5060,3 +5299,66 @@ async fn get_developer_guide_walkthrough() -> Result<String, String> {
     let content = include_str!("../../../docs/MCP_DEVELOPMENT_GUIDE.md");
     Ok(format!("{}{}", header, content))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_preflight_flags_runtime_errors() {
+        let healthy = "function main(input) { return { sum: input.a + input.b }; }";
+        let (compile, dry_run) =
+            TaskDevelopmentService::preflight_code(healthy, json!({"a": 1, "b": 2}), Duration::from_secs(5)).await;
+        assert!(compile.passed);
+        assert!(dry_run.passed);
+        assert_eq!(dry_run.output, Some(json!({"sum": 3})));
+
+        let report = PreflightReport::new(
+            "healthy".to_string(),
+            "healthy".to_string(),
+            PreflightCheck::from_errors(Vec::new()),
+            compile,
+            dry_run,
+        );
+        assert!(report.passed);
+
+        let broken = "function main(input) { return input.missing.field; }";
+        let (compile, dry_run) =
+            TaskDevelopmentService::preflight_code(broken, json!({}), Duration::from_secs(5)).await;
+        assert!(compile.passed);
+        assert!(!dry_run.passed);
+        assert!(!dry_run.skipped);
+        assert!(dry_run.error.is_some());
+
+        let report = PreflightReport::new(
+            "broken".to_string(),
+            "broken".to_string(),
+            PreflightCheck::from_errors(Vec::new()),
+            compile,
+            dry_run,
+        );
+        assert!(!report.passed);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_skips_dry_run_when_compile_fails() {
+        let (compile, dry_run) =
+            TaskDevelopmentService::preflight_code("function main(input) {", json!({}), Duration::from_secs(5)).await;
+        assert!(!compile.passed);
+        assert_eq!(compile.errors.len(), 1);
+        assert!(dry_run.skipped);
+        assert!(!dry_run.passed);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_stops_runaway_loop() {
+        let runaway = "function main(input) { while (true) {} }";
+        let (compile, dry_run) =
+            TaskDevelopmentService::preflight_code(runaway, json!({}), Duration::from_secs(30)).await;
+        assert!(compile.passed);
+        assert!(!dry_run.passed);
+        let error = dry_run.error.unwrap();
+        assert!(!error.contains("timed out"), "loop was not stopped: {}", error);
+    }
+}
@@ -170,6 +170,12 @@ pub struct RatchetToolRegistry {
 
     /// Repository factory for data access
     repositories: Option<Arc<dyn RepositoryFactory>>,
+
+    /// Whether progress streaming is allowed for task executions
+    progress_streaming: bool,
+
+    /// Longest chain of dependent requests a batch may contain
+    max_dependency_depth: usize,
 }
 
 impl RatchetToolRegistry {
@@ -182,6 +188,8 @@ impl RatchetToolRegistry {
             progress_manager: Arc::new(super::progress::ProgressNotificationManager::new()),
             task_dev_service: None,
             repositories: None,
+            progress_streaming: true,
+            max_dependency_depth: super::batch::DEFAULT_MAX_DEPENDENCY_DEPTH,
         };
 
         // Register built-in Ratchet tools
@@ -684,6 +692,24 @@ impl RatchetToolRegistry {
         self
     }
 
+    /// Configure progress channel backpressure
+    pub fn with_progress_config(mut self, config: super::progress::ProgressChannelConfig) -> Self {
+        self.progress_manager = Arc::new(super::progress::ProgressNotificationManager::with_config(config));
+        self
+    }
+
+    /// Enable or disable progress streaming; when disabled `stream_progress` requests run without streaming
+    pub fn with_progress_streaming(mut self, enabled: bool) -> Self {
+        self.progress_streaming = enabled;
+        self
+    }
+
+    /// Set the longest chain of dependent requests `ratchet_batch_execute` accepts
+    pub fn with_max_dependency_depth(mut self, depth: usize) -> Self {
+        self.max_dependency_depth = depth;
+        self
+    }
+
     /// Get the progress manager
     pub fn get_progress_manager(&self) -> Arc<super::progress::ProgressNotificationManager> {
         self.progress_manager.clone()
@@ -825,7 +851,11 @@ impl RatchetToolRegistry {
 
         let trace_enabled = args.get("trace").and_then(|v| v.as_bool()).unwrap_or(true);
 
-        let stream_progress = args.get("stream_progress").and_then(|v| v.as_bool()).unwrap_or(false);
+        let stream_progress_requested = args.get("stream_progress").and_then(|v| v.as_bool()).unwrap_or(false);
+        if stream_progress_requested && !self.progress_streaming {
+            tracing::debug!("Progress streaming is disabled by feature flag, executing task without streaming");
+        }
+        let stream_progress = stream_progress_requested && self.progress_streaming;
 
         // Parse progress filter if provided
         let progress_filter = args
@@ -2064,7 +2094,8 @@ impl RatchetToolRegistry {
                 })
             }),
             None, // progress_callback
-        );
+        )
+        .with_max_dependency_depth(self.max_dependency_depth);
 
         match batch_processor.process_batch(batch_params).await {
             Ok(result) => Ok(ToolsCallResult {
@@ -2169,6 +2200,7 @@ impl RatchetToolRegistry {
             error_message_contains: None,
             can_retry: None,
             can_cancel: None,
+            labels: None,
         };
 
         // Create pagination input
@@ -2315,6 +2347,7 @@ impl RatchetToolRegistry {
                 "failed" => Some(ratchet_api_types::JobStatus::Failed),
                 "cancelled" => Some(ratchet_api_types::JobStatus::Cancelled),
                 "retrying" => Some(ratchet_api_types::JobStatus::Retrying),
+                "dead_letter" => Some(ratchet_api_types::JobStatus::DeadLetter),
                 _ => None,
             }
         } else {
@@ -2352,6 +2385,8 @@ impl RatchetToolRegistry {
             priority_min: None,
             queued_before: None,
             scheduled_after: None,
+            completed_after: None,
+            completed_before: None,
             retry_count_min: None,
             retry_count_max: None,
             max_retries_min: None,
@@ -2518,6 +2553,7 @@ impl RatchetToolRegistry {
             has_last_run: None,
             is_due: ready_to_run, // Map ready_to_run to is_due filter
             overdue: None,
+            paused: None,
         };
 
         // Create pagination input
@@ -2770,6 +2806,68 @@ mod tests {
         }
     }
 
+    /// Executor that records whether the streaming path was used
+    #[derive(Default)]
+    struct StreamingProbeExecutor {
+        streamed: std::sync::atomic::AtomicBool,
+    }
+
+    #[async_trait]
+    impl McpTaskExecutor for StreamingProbeExecutor {
+        async fn execute_task(&self, _task_path: &str, _input: Value) -> Result<Value, String> {
+            Ok(serde_json::json!({"ok": true}))
+        }
+
+        async fn execute_task_with_progress(
+            &self,
+            _task_path: &str,
+            _input: Value,
+            _progress_manager: Option<Arc<crate::server::progress::ProgressNotificationManager>>,
+            _connection: Option<Arc<dyn crate::transport::connection::TransportConnection>>,
+            _filter: Option<crate::server::progress::ProgressFilter>,
+        ) -> Result<(String, Value), String> {
+            self.streamed.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(("exec-1".to_string(), serde_json::json!({"ok": true})))
+        }
+
+        async fn list_tasks(&self, _filter: Option<&str>) -> Result<Vec<McpTaskInfo>, String> {
+            Ok(Vec::new())
+        }
+
+        async fn get_execution_logs(&self, _execution_id: &str, _level: &str, _limit: usize) -> Result<String, String> {
+            Ok(String::new())
+        }
+
+        async fn get_execution_status(&self, _execution_id: &str) -> Result<McpExecutionStatus, String> {
+            Err("not supported".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_streaming_disabled_ignores_stream_request() {
+        let executor = Arc::new(StreamingProbeExecutor::default());
+        let mut registry = RatchetToolRegistry::new().with_progress_streaming(false);
+        registry.set_executor(executor.clone());
+
+        let execution_context = ToolExecutionContext {
+            security: create_test_context(),
+            arguments: Some(serde_json::json!({
+                "task_id": "test-task",
+                "stream_progress": true
+            })),
+            request_id: Some("req-456".to_string()),
+        };
+
+        let result = registry
+            .execute_tool("ratchet_execute_task", execution_context)
+            .await
+            .unwrap();
+
+        assert!(!result.is_error);
+        assert!(!executor.streamed.load(std::sync::atomic::Ordering::SeqCst));
+        assert_eq!(result.metadata.get("streaming"), Some(&serde_json::Value::Bool(false)));
+    }
+
     #[test]
     fn test_mcp_tool_creation() {
         let tool = McpTool::new(
@@ -317,6 +317,7 @@ async fn create_test_adapter() -> RatchetMcpAdapter {
         url: "sqlite::memory:".to_string(),
         max_connections: 1,
         connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
     };
 
     let database = DatabaseConnection::new(db_config.clone())
@@ -351,6 +352,8 @@ async fn create_test_adapter() -> RatchetMcpAdapter {
         task_timeout_seconds: 30,
         restart_on_crash: true,
         max_restart_attempts: 3,
+        attachments: None,
+        output_schema_enforcement: Default::default(),
     };
     let executor = Arc::new(ProcessTaskExecutor::new(executor_config));
 
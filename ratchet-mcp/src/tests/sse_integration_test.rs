@@ -25,6 +25,7 @@ fn create_test_sse_config(port: u16) -> McpConfig {
             health_check_interval: Duration::from_secs(30),
         },
         tools: crate::config::ToolConfig::default(),
+        progress: Default::default(),
     }
 }
 
@@ -254,6 +254,7 @@ fn test_ratchet_config_integration_security() {
         transport: "sse".to_string(),
         host: "127.0.0.1".to_string(), // Should default to localhost
         port: 8080,
+        ..Default::default()
     };
     
     let server_config = McpServerConfig::from_ratchet_config(&mock_config);
@@ -274,6 +275,7 @@ fn test_ratchet_config_integration_security() {
         transport: "unknown".to_string(),
         host: "0.0.0.0".to_string(),
         port: 8080,
+        ..Default::default()
     };
     
     let default_config = McpServerConfig::from_ratchet_config(&unknown_config);
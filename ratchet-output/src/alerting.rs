@@ -0,0 +1,675 @@
+//! System alerting
+//!
+//! Internal health events (a circuit opening, a worker crash-looping, an output
+//! destination becoming unhealthy) are turned into structured [`Alert`]s and
+//! routed by severity and event kind to the configured channels. Channels can
+//! write to the log, post to a webhook, or reuse any output destination.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+use crate::destination::{DeliveryContext, TaskOutput};
+use crate::errors::{ConfigError, DeliveryError};
+use crate::manager::OutputDeliveryManager;
+use crate::{OutputDestinationConfig, RetryPolicy};
+use ratchet_config::domains::alerting::{AlertChannelType, AlertRouteConfig, AlertingConfig, CrashLoopConfig};
+use ratchet_http::HttpMethod;
+
+pub use ratchet_config::domains::alerting::AlertSeverity;
+
+/// Internal health events that can raise alerts
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthEvent {
+    /// A circuit breaker tripped open
+    CircuitOpened { circuit: String, consecutive_failures: u32 },
+    /// A worker crashed repeatedly within the detection window
+    WorkerCrashLoop {
+        worker_id: String,
+        crashes: u32,
+        window: Duration,
+    },
+    /// An output destination is failing deliveries
+    DestinationUnhealthy { destination: String, error: String },
+}
+
+impl HealthEvent {
+    /// Event kind used for routing and severity overrides
+    pub fn kind(&self) -> &'static str {
+        match self {
+            HealthEvent::CircuitOpened { .. } => "circuit_opened",
+            HealthEvent::WorkerCrashLoop { .. } => "worker_crash_loop",
+            HealthEvent::DestinationUnhealthy { .. } => "destination_unhealthy",
+        }
+    }
+
+    /// What the event is about: the circuit, worker or destination name
+    pub fn subject(&self) -> &str {
+        match self {
+            HealthEvent::CircuitOpened { circuit, .. } => circuit,
+            HealthEvent::WorkerCrashLoop { worker_id, .. } => worker_id,
+            HealthEvent::DestinationUnhealthy { destination, .. } => destination,
+        }
+    }
+
+    /// Severity used when the configuration does not override it
+    pub fn default_severity(&self) -> AlertSeverity {
+        match self {
+            HealthEvent::CircuitOpened { .. } => AlertSeverity::Warning,
+            HealthEvent::WorkerCrashLoop { .. } => AlertSeverity::Critical,
+            HealthEvent::DestinationUnhealthy { .. } => AlertSeverity::Warning,
+        }
+    }
+
+    fn describe(&self) -> (String, String, serde_json::Value) {
+        match self {
+            HealthEvent::CircuitOpened {
+                circuit,
+                consecutive_failures,
+            } => (
+                format!("Circuit '{}' opened", circuit),
+                format!(
+                    "Circuit breaker '{}' opened after {} consecutive failures",
+                    circuit, consecutive_failures
+                ),
+                json!({ "circuit": circuit, "consecutive_failures": consecutive_failures }),
+            ),
+            HealthEvent::WorkerCrashLoop {
+                worker_id,
+                crashes,
+                window,
+            } => (
+                format!("Worker '{}' is crash-looping", worker_id),
+                format!(
+                    "Worker '{}' crashed {} times within {}s",
+                    worker_id,
+                    crashes,
+                    window.as_secs()
+                ),
+                json!({ "worker_id": worker_id, "crashes": crashes, "window_seconds": window.as_secs() }),
+            ),
+            HealthEvent::DestinationUnhealthy { destination, error } => (
+                format!("Output destination '{}' is unhealthy", destination),
+                format!("Deliveries to '{}' are failing: {}", destination, error),
+                json!({ "destination": destination, "error": error }),
+            ),
+        }
+    }
+}
+
+/// Structured alert delivered to channels
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: Uuid,
+    pub kind: String,
+    pub severity: AlertSeverity,
+    pub title: String,
+    pub message: String,
+    pub details: serde_json::Value,
+    pub raised_at: DateTime<Utc>,
+}
+
+/// Destination for alerts
+#[async_trait]
+pub trait AlertChannel: Send + Sync {
+    /// Channel name referenced by routes
+    fn name(&self) -> &str;
+
+    /// Send an alert through this channel
+    async fn send(&self, alert: &Alert) -> Result<(), DeliveryError>;
+}
+
+/// Channel that writes alerts to the application log
+pub struct LogAlertChannel {
+    name: String,
+}
+
+impl LogAlertChannel {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+#[async_trait]
+impl AlertChannel for LogAlertChannel {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), DeliveryError> {
+        match alert.severity {
+            AlertSeverity::Critical => {
+                error!(kind = %alert.kind, "ALERT [{}] {}: {}", alert.severity, alert.title, alert.message)
+            }
+            AlertSeverity::Warning => {
+                warn!(kind = %alert.kind, "ALERT [{}] {}: {}", alert.severity, alert.title, alert.message)
+            }
+            AlertSeverity::Info => {
+                info!(kind = %alert.kind, "ALERT [{}] {}: {}", alert.severity, alert.title, alert.message)
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Channel that delivers alerts through a named output destination
+pub struct DestinationAlertChannel {
+    name: String,
+    manager: Arc<OutputDeliveryManager>,
+    destination: String,
+}
+
+impl DestinationAlertChannel {
+    pub fn new(name: impl Into<String>, manager: Arc<OutputDeliveryManager>, destination: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            manager,
+            destination: destination.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AlertChannel for DestinationAlertChannel {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn send(&self, alert: &Alert) -> Result<(), DeliveryError> {
+        let output_data = serde_json::to_value(alert).map_err(|e| DeliveryError::Serialization {
+            format: "json".to_string(),
+            error: e.to_string(),
+        })?;
+
+        let output = TaskOutput {
+            job_id: 0,
+            task_id: 0,
+            execution_id: 0,
+            output_data,
+            metadata: HashMap::from([
+                ("alert_kind".to_string(), json!(alert.kind)),
+                ("alert_severity".to_string(), json!(alert.severity)),
+            ]),
+            completed_at: alert.raised_at,
+            execution_duration: Duration::ZERO,
+        };
+
+        let context = DeliveryContext {
+            task_name: format!("alert:{}", alert.kind),
+            timestamp: alert.raised_at,
+            trace_id: alert.id.to_string(),
+            ..DeliveryContext::default()
+        };
+
+        self.manager
+            .deliver_output(&self.destination, &output, &context)
+            .await
+            .map(|_| ())
+    }
+}
+
+/// Outcome of dispatching a single alert
+#[derive(Debug, Clone)]
+pub struct AlertDispatchReport {
+    pub alert: Alert,
+    pub delivered: Vec<String>,
+    pub failed: Vec<(String, DeliveryError)>,
+    /// The same event was raised for the same subject within the dedup window, so nothing was sent
+    pub suppressed: bool,
+}
+
+/// Routes alerts to channels by severity and event kind
+pub struct AlertDispatcher {
+    enabled: bool,
+    channels: HashMap<String, Arc<dyn AlertChannel>>,
+    routes: Vec<AlertRouteConfig>,
+    severities: HashMap<String, AlertSeverity>,
+    dedup_window: Duration,
+    last_raised: Mutex<HashMap<(&'static str, String), Instant>>,
+}
+
+impl AlertDispatcher {
+    /// Create an enabled dispatcher with no channels, routes or deduplication
+    pub fn new() -> Self {
+        Self {
+            enabled: true,
+            channels: HashMap::new(),
+            routes: Vec::new(),
+            severities: HashMap::new(),
+            dedup_window: Duration::ZERO,
+            last_raised: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Build a dispatcher from configuration
+    ///
+    /// Webhook channels are registered on `manager` as output destinations named `alerting.<channel>`;
+    /// destination channels must refer to a destination already registered on `manager`.
+    pub async fn from_config(
+        config: &AlertingConfig,
+        manager: Arc<OutputDeliveryManager>,
+    ) -> Result<Self, ConfigError> {
+        let mut dispatcher = Self::new();
+        dispatcher.enabled = config.enabled;
+        dispatcher.routes = config.routes.clone();
+        dispatcher.severities = config.severities.clone();
+        dispatcher.dedup_window = config.dedup_window;
+
+        for channel in &config.channels {
+            let alert_channel: Arc<dyn AlertChannel> = match &channel.channel {
+                AlertChannelType::Log => Arc::new(LogAlertChannel::new(&channel.name)),
+                AlertChannelType::Webhook { url, headers, timeout } => {
+                    let destination = format!("alerting.{}", channel.name);
+                    manager
+                        .add_destination(
+                            destination.clone(),
+                            OutputDestinationConfig::Webhook {
+                                url: url.clone(),
+                                method: HttpMethod::Post,
+                                headers: headers.clone(),
+                                timeout: *timeout,
+                                retry_policy: RetryPolicy::default(),
+                                auth: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+                    Arc::new(DestinationAlertChannel::new(
+                        &channel.name,
+                        manager.clone(),
+                        destination,
+                    ))
+                }
+                AlertChannelType::Destination { destination } => Arc::new(DestinationAlertChannel::new(
+                    &channel.name,
+                    manager.clone(),
+                    destination,
+                )),
+            };
+            dispatcher.channels.insert(channel.name.clone(), alert_channel);
+        }
+
+        Ok(dispatcher)
+    }
+
+    /// Register a channel
+    pub fn with_channel(mut self, channel: Arc<dyn AlertChannel>) -> Self {
+        self.channels.insert(channel.name().to_string(), channel);
+        self
+    }
+
+    /// Add a routing rule
+    pub fn with_route(mut self, route: AlertRouteConfig) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Override the severity for an event kind
+    pub fn with_severity(mut self, kind: impl Into<String>, severity: AlertSeverity) -> Self {
+        self.severities.insert(kind.into(), severity);
+        self
+    }
+
+    /// Suppress repeats of an event for the same subject raised within `window`
+    pub fn with_dedup_window(mut self, window: Duration) -> Self {
+        self.dedup_window = window;
+        self
+    }
+
+    /// Whether alerts are dispatched
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Build the alert for a health event, applying severity overrides
+    pub fn alert_for(&self, event: &HealthEvent) -> Alert {
+        let (title, message, details) = event.describe();
+        Alert {
+            id: Uuid::new_v4(),
+            kind: event.kind().to_string(),
+            severity: self
+                .severities
+                .get(event.kind())
+                .copied()
+                .unwrap_or_else(|| event.default_severity()),
+            title,
+            message,
+            details,
+            raised_at: Utc::now(),
+        }
+    }
+
+    /// Raise an alert for a health event
+    ///
+    /// An event of the same kind for the same subject raised again within the dedup
+    /// window is suppressed, so a destination failing every job alerts once per window.
+    pub async fn raise(&self, event: HealthEvent) -> AlertDispatchReport {
+        let alert = self.alert_for(&event);
+        if self.is_duplicate(&event, Instant::now()) {
+            debug!("Suppressing duplicate '{}' alert for '{}'", alert.kind, event.subject());
+            return AlertDispatchReport {
+                alert,
+                delivered: Vec::new(),
+                failed: Vec::new(),
+                suppressed: true,
+            };
+        }
+        self.dispatch(alert).await
+    }
+
+    /// Record that `event` is being raised at `now`; true if it was already raised within the window
+    fn is_duplicate(&self, event: &HealthEvent, now: Instant) -> bool {
+        if self.dedup_window.is_zero() {
+            return false;
+        }
+
+        let mut last_raised = self.last_raised.lock().unwrap_or_else(|e| e.into_inner());
+        last_raised.retain(|_, raised_at| now.duration_since(*raised_at) < self.dedup_window);
+        let key = (event.kind(), event.subject().to_string());
+        if last_raised.contains_key(&key) {
+            return true;
+        }
+        last_raised.insert(key, now);
+        false
+    }
+
+    /// Deliver an alert to every channel with a matching route
+    pub async fn dispatch(&self, alert: Alert) -> AlertDispatchReport {
+        let mut report = AlertDispatchReport {
+            alert,
+            delivered: Vec::new(),
+            failed: Vec::new(),
+            suppressed: false,
+        };
+
+        if !self.enabled {
+            return report;
+        }
+
+        let mut targets: Vec<&str> = Vec::new();
+        for route in &self.routes {
+            let matches_kind = route.events.is_empty() || route.events.contains(&report.alert.kind);
+            if report.alert.severity >= route.min_severity && matches_kind && !targets.contains(&route.channel.as_str())
+            {
+                targets.push(route.channel.as_str());
+            }
+        }
+
+        for target in targets {
+            let Some(channel) = self.channels.get(target) else {
+                warn!("Alert route references unknown channel '{}'", target);
+                continue;
+            };
+
+            match channel.send(&report.alert).await {
+                Ok(()) => report.delivered.push(target.to_string()),
+                Err(e) => {
+                    error!(
+                        "Failed to send alert '{}' to channel '{}': {}",
+                        report.alert.title, target, e
+                    );
+                    report.failed.push((target.to_string(), e));
+                }
+            }
+        }
+
+        report
+    }
+}
+
+impl Default for AlertDispatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detects workers that crash repeatedly within a sliding window
+pub struct CrashLoopDetector {
+    threshold: u32,
+    window: Duration,
+    crashes: HashMap<String, VecDeque<Instant>>,
+}
+
+impl CrashLoopDetector {
+    pub fn new(threshold: u32, window: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            window,
+            crashes: HashMap::new(),
+        }
+    }
+
+    pub fn from_config(config: &CrashLoopConfig) -> Self {
+        Self::new(config.threshold, config.window)
+    }
+
+    /// Record a worker crash; returns a crash-loop event once the threshold is reached
+    ///
+    /// The crash history is cleared after an event so one crash loop raises one alert.
+    pub fn record_crash(&mut self, worker_id: &str) -> Option<HealthEvent> {
+        self.record_crash_at(worker_id, Instant::now())
+    }
+
+    /// Forget the crash history of a worker, e.g. once it is stable again
+    pub fn reset(&mut self, worker_id: &str) {
+        self.crashes.remove(worker_id);
+    }
+
+    fn record_crash_at(&mut self, worker_id: &str, now: Instant) -> Option<HealthEvent> {
+        let history = self.crashes.entry(worker_id.to_string()).or_default();
+        history.push_back(now);
+        while history
+            .front()
+            .is_some_and(|crash| now.duration_since(*crash) > self.window)
+        {
+            history.pop_front();
+        }
+
+        if history.len() as u32 >= self.threshold {
+            let crashes = history.len() as u32;
+            history.clear();
+            Some(HealthEvent::WorkerCrashLoop {
+                worker_id: worker_id.to_string(),
+                crashes,
+                window: self.window,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Channel that records the alerts it receives
+    struct RecordingChannel {
+        name: String,
+        alerts: Mutex<Vec<Alert>>,
+    }
+
+    impl RecordingChannel {
+        fn new(name: &str) -> Arc<Self> {
+            Arc::new(Self {
+                name: name.to_string(),
+                alerts: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl AlertChannel for RecordingChannel {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        async fn send(&self, alert: &Alert) -> Result<(), DeliveryError> {
+            self.alerts.lock().unwrap().push(alert.clone());
+            Ok(())
+        }
+    }
+
+    fn route(channel: &str, min_severity: AlertSeverity, events: &[&str]) -> AlertRouteConfig {
+        AlertRouteConfig {
+            channel: channel.to_string(),
+            min_severity,
+            events: events.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_worker_crash_loop_dispatches_alert() {
+        let pager = RecordingChannel::new("pager");
+        let dispatcher =
+            AlertDispatcher::new()
+                .with_channel(pager.clone())
+                .with_route(route("pager", AlertSeverity::Critical, &[]));
+
+        let mut detector = CrashLoopDetector::new(3, Duration::from_secs(60));
+        let start = Instant::now();
+        assert!(detector.record_crash_at("worker-1", start).is_none());
+        assert!(detector
+            .record_crash_at("worker-1", start + Duration::from_secs(5))
+            .is_none());
+        let event = detector
+            .record_crash_at("worker-1", start + Duration::from_secs(10))
+            .expect("third crash within the window is a crash loop");
+
+        let report = dispatcher.raise(event).await;
+        assert_eq!(report.delivered, vec!["pager".to_string()]);
+
+        let alerts = pager.alerts.lock().unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, "worker_crash_loop");
+        assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+        assert_eq!(alerts[0].details["worker_id"], "worker-1");
+        assert_eq!(alerts[0].details["crashes"], 3);
+    }
+
+    #[test]
+    fn test_crashes_outside_window_are_not_a_loop() {
+        let mut detector = CrashLoopDetector::new(3, Duration::from_secs(60));
+        let start = Instant::now();
+        assert!(detector.record_crash_at("worker-1", start).is_none());
+        assert!(detector
+            .record_crash_at("worker-1", start + Duration::from_secs(50))
+            .is_none());
+        assert!(detector
+            .record_crash_at("worker-1", start + Duration::from_secs(120))
+            .is_none());
+        assert!(detector
+            .record_crash_at("worker-2", start + Duration::from_secs(120))
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_routing_by_severity_and_kind() {
+        let ops = RecordingChannel::new("ops");
+        let pager = RecordingChannel::new("pager");
+        let dispatcher = AlertDispatcher::new()
+            .with_channel(ops.clone())
+            .with_channel(pager.clone())
+            .with_route(route("ops", AlertSeverity::Warning, &[]))
+            .with_route(route("pager", AlertSeverity::Critical, &["worker_crash_loop"]))
+            .with_severity("circuit_opened", AlertSeverity::Critical);
+
+        let report = dispatcher
+            .raise(HealthEvent::CircuitOpened {
+                circuit: "webhooks".to_string(),
+                consecutive_failures: 5,
+            })
+            .await;
+
+        // Escalated to critical, but the pager route only takes crash loops
+        assert_eq!(report.alert.severity, AlertSeverity::Critical);
+        assert_eq!(report.delivered, vec!["ops".to_string()]);
+        assert!(pager.alerts.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_repeated_events_are_deduplicated_per_subject() {
+        let ops = RecordingChannel::new("ops");
+        let dispatcher = AlertDispatcher::new()
+            .with_channel(ops.clone())
+            .with_route(route("ops", AlertSeverity::Warning, &[]))
+            .with_dedup_window(Duration::from_secs(300));
+
+        let unhealthy = |destination: &str| HealthEvent::DestinationUnhealthy {
+            destination: destination.to_string(),
+            error: "connection refused".to_string(),
+        };
+
+        let first = dispatcher.raise(unhealthy("webhook:https://a.example")).await;
+        let repeat = dispatcher.raise(unhealthy("webhook:https://a.example")).await;
+        let other = dispatcher.raise(unhealthy("webhook:https://b.example")).await;
+        assert!(!first.suppressed);
+        assert!(repeat.suppressed);
+        assert!(!other.suppressed);
+        assert_eq!(ops.alerts.lock().unwrap().len(), 2);
+
+        // Once the window has passed the event alerts again
+        let later = Instant::now() + Duration::from_secs(301);
+        assert!(!dispatcher.is_duplicate(&unhealthy("webhook:https://a.example"), later));
+    }
+
+    #[tokio::test]
+    async fn test_disabled_dispatcher_sends_nothing() {
+        let manager = Arc::new(OutputDeliveryManager::new());
+        let config = AlertingConfig {
+            enabled: false,
+            ..AlertingConfig::default()
+        };
+        let dispatcher = AlertDispatcher::from_config(&config, manager).await.unwrap();
+
+        let report = dispatcher
+            .raise(HealthEvent::DestinationUnhealthy {
+                destination: "results".to_string(),
+                error: "timeout".to_string(),
+            })
+            .await;
+        assert!(report.delivered.is_empty());
+        assert!(report.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_channel_from_config() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/alerts"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let config: AlertingConfig = serde_json::from_value(json!({
+            "channels": [{ "name": "ops", "type": "webhook", "url": format!("{}/alerts", server.uri()) }],
+            "routes": [{ "channel": "ops" }]
+        }))
+        .unwrap();
+
+        let dispatcher = AlertDispatcher::from_config(&config, Arc::new(OutputDeliveryManager::new()))
+            .await
+            .unwrap();
+
+        let report = dispatcher
+            .raise(HealthEvent::WorkerCrashLoop {
+                worker_id: "worker-7".to_string(),
+                crashes: 4,
+                window: Duration::from_secs(300),
+            })
+            .await;
+
+        assert_eq!(report.delivered, vec!["ops".to_string()]);
+        assert!(report.failed.is_empty());
+    }
+}
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::errors::{DeliveryError, ValidationError};
 
@@ -53,6 +53,16 @@ pub struct DeliveryContext {
     pub environment: String,
     pub trace_id: String,
     pub template_variables: HashMap<String, String>,
+    /// End of the execution's overall time budget; delivery gives up once it passes
+    pub deadline: Option<Instant>,
+}
+
+impl DeliveryContext {
+    /// Time left before the deadline, `None` when delivery is unbounded
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
 }
 
 impl Default for DeliveryContext {
@@ -65,6 +75,7 @@ impl Default for DeliveryContext {
             environment: String::new(),
             trace_id: String::new(),
             template_variables: HashMap::new(),
+            deadline: None,
         }
     }
 }
@@ -140,8 +140,11 @@ impl WebhookDestination {
                 request = request.json(payload);
             }
 
-            // Set timeout
-            request = request.timeout(self.config.timeout);
+            // Set timeout, bounded by the execution deadline
+            let timeout = context
+                .remaining_time()
+                .map_or(self.config.timeout, |remaining| remaining.min(self.config.timeout));
+            request = request.timeout(timeout);
 
             // Send request
             match request.send().await {
@@ -195,6 +198,13 @@ impl WebhookDestination {
                 }
             }
 
+            // Retrying is pointless when the deadline passes before the next attempt
+            if context.remaining_time().is_some_and(|remaining| remaining <= delay) {
+                return Err(DeliveryError::DeadlineExceeded {
+                    destination: "webhook".to_string(),
+                });
+            }
+
             // Wait before retry
             tokio::time::sleep(delay).await;
 
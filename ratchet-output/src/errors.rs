@@ -33,6 +33,9 @@ pub enum DeliveryError {
     #[error("Maximum retry attempts exceeded for {destination} ({attempts} attempts)")]
     MaxRetriesExceeded { destination: String, attempts: u32 },
 
+    #[error("Execution deadline exceeded before delivery to {destination} completed")]
+    DeadlineExceeded { destination: String },
+
     #[error("Task join error: {error}")]
     TaskJoin { error: String },
 
@@ -51,6 +51,7 @@
 //! # }
 //! ```
 
+pub mod alerting;
 pub mod destination;
 pub mod destinations;
 pub mod errors;
@@ -58,15 +59,17 @@ pub mod manager;
 pub mod metrics;
 pub mod template;
 
+pub use alerting::{Alert, AlertChannel, AlertDispatcher, AlertSeverity, CrashLoopDetector, HealthEvent};
 pub use destination::{DeliveryContext, DeliveryResult, OutputDestination, TaskOutput};
 pub use destinations::{FilesystemDestination, StdStream, StdioConfig, StdioDestination, WebhookDestination};
 pub use errors::{ConfigError, DeliveryError, ValidationError};
-pub use manager::{OutputDeliveryManager, TestResult};
+pub use manager::{OutputDeliveryManager, TestResult, DEFAULT_MAX_CONCURRENT_DELIVERIES};
 pub use template::TemplateEngine;
 
 // Re-export HttpMethod from ratchet-http for consistency
 pub use ratchet_http::HttpMethod;
 
+use ratchet_config::domains::output::{OutputDestinationConfigTemplate, WebhookAuthConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -136,6 +139,87 @@ pub enum OutputDestinationConfig {
     },
 }
 
+impl TryFrom<&OutputDestinationConfigTemplate> for OutputDestinationConfig {
+    type Error = ConfigError;
+
+    /// Build a destination configuration from a config-file destination template
+    fn try_from(template: &OutputDestinationConfigTemplate) -> Result<Self, Self::Error> {
+        let invalid = |field: &str, value: &str| ConfigError::InvalidValue {
+            field: field.to_string(),
+            value: value.to_string(),
+        };
+
+        Ok(match template {
+            OutputDestinationConfigTemplate::Filesystem {
+                path,
+                format,
+                permissions,
+                create_dirs,
+                overwrite,
+                backup_existing,
+            } => OutputDestinationConfig::Filesystem {
+                path: path.clone(),
+                format: serde_json::from_value(serde_json::Value::String(format.clone()))
+                    .map_err(|_| invalid("format", format))?,
+                permissions: u32::from_str_radix(permissions, 8).map_err(|_| invalid("permissions", permissions))?,
+                create_dirs: *create_dirs,
+                overwrite: *overwrite,
+                backup_existing: *backup_existing,
+            },
+            OutputDestinationConfigTemplate::Webhook {
+                url,
+                method,
+                headers,
+                timeout_seconds,
+                content_type,
+                auth,
+            } => OutputDestinationConfig::Webhook {
+                url: url.clone(),
+                method: method.parse().map_err(|_| invalid("method", method))?,
+                headers: headers.clone(),
+                timeout: Duration::from_secs(*timeout_seconds),
+                retry_policy: RetryPolicy::default(),
+                auth: auth.as_ref().map(|auth| match auth {
+                    WebhookAuthConfig::Bearer { token } => WebhookAuth::Bearer { token: token.clone() },
+                    WebhookAuthConfig::Basic { username, password } => WebhookAuth::Basic {
+                        username: username.clone(),
+                        password: password.clone(),
+                    },
+                    WebhookAuthConfig::ApiKey { header, value } => WebhookAuth::ApiKey {
+                        header: header.clone(),
+                        key: value.clone(),
+                    },
+                }),
+                content_type: content_type.clone(),
+            },
+            OutputDestinationConfigTemplate::Database {
+                connection_string,
+                table_name,
+                column_mappings,
+                ..
+            } => OutputDestinationConfig::Database {
+                connection: connection_string.clone(),
+                table: table_name.clone(),
+                columns: column_mappings.clone(),
+                upsert: false,
+            },
+            OutputDestinationConfigTemplate::S3 {
+                bucket,
+                key_template,
+                region,
+                storage_class,
+                ..
+            } => OutputDestinationConfig::S3 {
+                bucket: bucket.clone(),
+                key: key_template.clone(),
+                region: region.clone(),
+                storage_class: Some(storage_class.clone()),
+                metadata: HashMap::new(),
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub enum OutputFormat {
     #[serde(rename = "json")]
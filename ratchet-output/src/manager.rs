@@ -4,7 +4,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use tokio::sync::RwLock;
+use ratchet_config::domains::output::OutputConfig;
+use tokio::sync::{RwLock, Semaphore};
 use tracing::{debug, error, info, warn};
 
 use crate::{
@@ -16,6 +17,9 @@ use crate::{
     OutputDestinationConfig,
 };
 
+/// Default cap on deliveries in flight across all destinations
+pub const DEFAULT_MAX_CONCURRENT_DELIVERIES: usize = 10;
+
 /// Result of testing a destination configuration
 #[derive(Debug, Clone)]
 pub struct TestResult {
@@ -26,23 +30,115 @@ pub struct TestResult {
     pub estimated_time: Duration,
 }
 
+/// Destination applied to executions by default
+#[derive(Debug, Clone)]
+struct DefaultDestination {
+    name: String,
+    always: bool,
+}
+
 /// Manager for handling output delivery to multiple destinations
 pub struct OutputDeliveryManager {
     destinations: Arc<RwLock<HashMap<String, Arc<dyn OutputDestination>>>>,
+    default_destination: RwLock<Option<DefaultDestination>>,
     template_engine: TemplateEngine,
     metrics: DeliveryMetrics,
+    /// Permits bounding deliveries in flight across all destinations
+    delivery_permits: Arc<Semaphore>,
+    max_concurrent_deliveries: usize,
 }
 
 impl OutputDeliveryManager {
     /// Create a new output delivery manager
     pub fn new() -> Self {
+        Self::with_max_concurrent_deliveries(DEFAULT_MAX_CONCURRENT_DELIVERIES)
+    }
+
+    /// Create a manager allowing at most `max_concurrent` deliveries in flight across
+    /// all destinations; further deliveries wait for a free slot
+    pub fn with_max_concurrent_deliveries(max_concurrent: usize) -> Self {
+        let max_concurrent = max_concurrent.max(1);
         Self {
             destinations: Arc::new(RwLock::new(HashMap::new())),
+            default_destination: RwLock::new(None),
             template_engine: TemplateEngine::new(),
             metrics: DeliveryMetrics::new(),
+            delivery_permits: Arc::new(Semaphore::new(max_concurrent)),
+            max_concurrent_deliveries: max_concurrent,
         }
     }
 
+    /// Maximum number of deliveries in flight across all destinations
+    pub fn max_concurrent_deliveries(&self) -> usize {
+        self.max_concurrent_deliveries
+    }
+
+    /// Number of deliveries currently in flight
+    pub fn active_deliveries(&self) -> usize {
+        self.max_concurrent_deliveries - self.delivery_permits.available_permits()
+    }
+
+    /// Register the default destination selected in the output configuration, if any
+    pub async fn configure_default_destination(&self, config: &OutputConfig) -> Result<(), ConfigError> {
+        let Some(name) = config.default_destination.clone() else {
+            return Ok(());
+        };
+        self.add_global_destination(config, &name, "default_destination")
+            .await?;
+        *self.default_destination.write().await = Some(DefaultDestination {
+            name,
+            always: config.always_use_default_destination,
+        });
+
+        Ok(())
+    }
+
+    /// Register the global destination template `name` from the output configuration
+    ///
+    /// `field` names the setting that referenced the template, for error reporting.
+    pub async fn add_global_destination(
+        &self,
+        config: &OutputConfig,
+        name: &str,
+        field: &str,
+    ) -> Result<(), ConfigError> {
+        let template = config
+            .destination_template(name)
+            .ok_or_else(|| ConfigError::InvalidValue {
+                field: field.to_string(),
+                value: name.to_string(),
+            })?;
+
+        self.add_destination(
+            name.to_string(),
+            OutputDestinationConfig::try_from(&template.destination)?,
+        )
+        .await
+    }
+
+    /// Deliver output to the default destination when it applies to the execution
+    ///
+    /// The default applies to executions without destinations of their own, or to every
+    /// execution when configured with `always_use_default_destination`. Returns `None`
+    /// when no delivery was attempted.
+    pub async fn deliver_default(
+        &self,
+        output: &TaskOutput,
+        context: &DeliveryContext,
+        has_own_destinations: bool,
+    ) -> Option<(String, Result<DeliveryResult, DeliveryError>)> {
+        let name = self
+            .default_destination
+            .read()
+            .await
+            .as_ref()
+            .filter(|default| default.always || !has_own_destinations)
+            .map(|default| default.name.clone())?;
+
+        let result = self.deliver_output(&name, output, context).await;
+        Some((name, result))
+    }
+
     /// Add a destination to the manager
     pub async fn add_destination(&self, name: String, config: OutputDestinationConfig) -> Result<(), ConfigError> {
         let destination = Self::create_destination_static(config, &self.template_engine)?;
@@ -87,15 +183,40 @@ impl OutputDeliveryManager {
 
         debug!("Delivering output to destination: {}", destination_name);
 
-        let destinations = self.destinations.read().await;
-        let destination = destinations
+        let destination = self
+            .destinations
+            .read()
+            .await
             .get(destination_name)
+            .cloned()
             .ok_or_else(|| DeliveryError::Network {
                 url: destination_name.to_string(),
                 error: "Destination not found".to_string(),
             })?;
 
-        let result = destination.deliver(output, context).await;
+        let delivery = async {
+            // Queue behind the global cap; the semaphore is never closed
+            let _permit = self
+                .delivery_permits
+                .acquire()
+                .await
+                .expect("delivery semaphore is never closed");
+
+            destination.deliver(output, context).await
+        };
+
+        // Delivery only gets what is left of the execution's overall budget
+        let result = match context.remaining_time() {
+            None => delivery.await,
+            Some(remaining) if remaining.is_zero() => Err(DeliveryError::DeadlineExceeded {
+                destination: destination_name.to_string(),
+            }),
+            Some(remaining) => tokio::time::timeout(remaining, delivery).await.unwrap_or_else(|_| {
+                Err(DeliveryError::DeadlineExceeded {
+                    destination: destination_name.to_string(),
+                })
+            }),
+        };
 
         // Record metrics
         match &result {
@@ -174,8 +295,8 @@ impl OutputDeliveryManager {
     }
 
     /// Create a delivery manager from destination configurations
-    pub fn from_configs(configs: &[OutputDestinationConfig], _max_concurrent: usize) -> Result<Self, ConfigError> {
-        let manager = Self::new();
+    pub fn from_configs(configs: &[OutputDestinationConfig], max_concurrent: usize) -> Result<Self, ConfigError> {
+        let manager = Self::with_max_concurrent_deliveries(max_concurrent);
 
         for (index, config) in configs.iter().enumerate() {
             let destination = Self::create_destination_static(config.clone(), &manager.template_engine)?;
@@ -333,3 +454,224 @@ impl Default for OutputDeliveryManager {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratchet_config::domains::output::{OutputDestinationConfigTemplate, OutputDestinationTemplate};
+
+    fn audit_config(dir: &std::path::Path, always: bool) -> OutputConfig {
+        OutputConfig {
+            global_destinations: vec![OutputDestinationTemplate {
+                name: "audit".to_string(),
+                description: None,
+                destination: OutputDestinationConfigTemplate::Filesystem {
+                    path: dir.join("{{execution_id}}.json").to_string_lossy().into_owned(),
+                    format: "json".to_string(),
+                    permissions: "644".to_string(),
+                    create_dirs: true,
+                    overwrite: true,
+                    backup_existing: false,
+                },
+            }],
+            default_destination: Some("audit".to_string()),
+            always_use_default_destination: always,
+            ..Default::default()
+        }
+    }
+
+    fn task_output(execution_id: i32) -> TaskOutput {
+        TaskOutput {
+            job_id: 1,
+            task_id: 1,
+            execution_id,
+            output_data: serde_json::json!({"status": "ok"}),
+            metadata: HashMap::new(),
+            completed_at: chrono::Utc::now(),
+            execution_duration: Duration::from_millis(10),
+        }
+    }
+
+    fn context(execution_id: i32) -> DeliveryContext {
+        let mut context = DeliveryContext::default();
+        context
+            .template_variables
+            .insert("execution_id".to_string(), execution_id.to_string());
+        context
+    }
+
+    #[tokio::test]
+    async fn test_execution_without_destinations_uses_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = OutputDeliveryManager::new();
+        manager
+            .configure_default_destination(&audit_config(dir.path(), false))
+            .await
+            .unwrap();
+
+        let (name, result) = manager
+            .deliver_default(&task_output(7), &context(7), false)
+            .await
+            .expect("default destination should apply");
+        assert_eq!(name, "audit");
+        assert!(result.unwrap().success);
+        assert!(dir.path().join("7.json").exists());
+
+        // Executions with their own destinations skip the default
+        assert!(manager
+            .deliver_default(&task_output(8), &context(8), true)
+            .await
+            .is_none());
+        assert!(!dir.path().join("8.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_always_default_combines_with_own_destinations() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = OutputDeliveryManager::new();
+        manager
+            .configure_default_destination(&audit_config(dir.path(), true))
+            .await
+            .unwrap();
+
+        let delivered = manager.deliver_default(&task_output(9), &context(9), true).await;
+        assert!(delivered.is_some_and(|(_, result)| result.is_ok()));
+        assert!(dir.path().join("9.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_no_default_destination_configured() {
+        let manager = OutputDeliveryManager::new();
+        manager
+            .configure_default_destination(&OutputConfig::default())
+            .await
+            .unwrap();
+
+        assert!(manager
+            .deliver_default(&task_output(1), &context(1), false)
+            .await
+            .is_none());
+    }
+
+    /// Destination recording the peak number of deliveries across all instances
+    struct SlowDestination {
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+        peak: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl OutputDestination for SlowDestination {
+        async fn deliver(
+            &self,
+            _output: &TaskOutput,
+            _context: &DeliveryContext,
+        ) -> Result<DeliveryResult, DeliveryError> {
+            use std::sync::atomic::Ordering;
+
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(DeliveryResult::success(
+                "slow".to_string(),
+                Duration::from_millis(20),
+                0,
+                None,
+            ))
+        }
+
+        fn validate_config(&self) -> Result<(), crate::errors::ValidationError> {
+            Ok(())
+        }
+
+        fn destination_type(&self) -> &'static str {
+            "slow"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_global_cap_bounds_deliveries_across_destinations() {
+        let manager = Arc::new(OutputDeliveryManager::with_max_concurrent_deliveries(2));
+        let in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let peak = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let names: Vec<String> = (0..3).map(|i| format!("slow_{}", i)).collect();
+        for name in &names {
+            manager.destinations.write().await.insert(
+                name.clone(),
+                Arc::new(SlowDestination {
+                    in_flight: in_flight.clone(),
+                    peak: peak.clone(),
+                }),
+            );
+        }
+
+        let deliveries: Vec<_> = (0..4)
+            .map(|execution_id| {
+                let manager = manager.clone();
+                let names = names.clone();
+                tokio::spawn(async move {
+                    manager
+                        .deliver_concurrent(&names, &task_output(execution_id), &context(execution_id))
+                        .await
+                })
+            })
+            .collect();
+
+        for delivery in deliveries {
+            let results = delivery.await.unwrap();
+            assert!(results.iter().all(|(_, result)| result.is_ok()));
+        }
+
+        assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(manager.active_deliveries(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_delivery_curtailed_near_execution_deadline() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let manager = OutputDeliveryManager::new();
+        manager
+            .add_destination(
+                "hook".to_string(),
+                OutputDestinationConfig::Webhook {
+                    url: server.uri(),
+                    method: crate::HttpMethod::Post,
+                    headers: HashMap::new(),
+                    timeout: Duration::from_secs(30),
+                    retry_policy: crate::RetryPolicy {
+                        max_attempts: 5,
+                        initial_delay: Duration::from_secs(1),
+                        ..Default::default()
+                    },
+                    auth: None,
+                    content_type: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        // The retry backoff would outlive the deadline, so delivery stops after one attempt
+        let started = Instant::now();
+        let mut near_deadline = context(1);
+        near_deadline.deadline = Some(Instant::now() + Duration::from_millis(500));
+        let result = manager.deliver_output("hook", &task_output(1), &near_deadline).await;
+        assert!(matches!(result, Err(DeliveryError::DeadlineExceeded { .. })));
+        assert!(started.elapsed() < Duration::from_secs(1));
+
+        // Nothing is attempted once the deadline has passed
+        let mut expired = context(2);
+        expired.deadline = Some(Instant::now());
+        let result = manager.deliver_output("hook", &task_output(2), &expired).await;
+        assert!(matches!(result, Err(DeliveryError::DeadlineExceeded { .. })));
+    }
+}
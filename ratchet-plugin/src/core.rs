@@ -87,6 +87,25 @@ impl PluginMetadata {
         self.metadata.insert(key.into(), value);
         self
     }
+
+    /// Check that the plugin was built against a supported plugin API version
+    pub fn check_api_version(&self) -> PluginResult<()> {
+        let api_version = semver::Version::parse(&self.api_version)
+            .map_err(|e| PluginError::generic(format!("Invalid API version: {}", e)))?;
+
+        let min_version = semver::Version::parse(crate::MIN_PLUGIN_API_VERSION)
+            .map_err(|e| PluginError::generic(format!("Invalid minimum API version: {}", e)))?;
+
+        if api_version < min_version {
+            return Err(PluginError::ApiVersionIncompatible {
+                name: self.name.clone(),
+                api_version: self.api_version.clone(),
+                system_version: crate::PLUGIN_SYSTEM_VERSION.to_string(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// Plugin execution context providing access to system resources
@@ -120,6 +139,9 @@ impl PluginContext {
     }
 
     /// Get plugin configuration as a specific type
+    ///
+    /// For plugins loaded by the [`PluginManager`](crate::PluginManager) this is
+    /// the plugin's `plugins.<id>` section of the main configuration.
     pub fn config_as<T>(&self) -> PluginResult<T>
     where
         T: for<'de> Deserialize<'de>,
@@ -182,6 +204,9 @@ pub trait Plugin: Send + Sync {
     }
 
     /// Validate plugin configuration
+    ///
+    /// Called with the configuration the plugin will be initialized with; an
+    /// error prevents the plugin from loading.
     fn validate_config(&self, config: &serde_json::Value) -> PluginResult<()> {
         // Default implementation does no validation
         let _ = config;
@@ -297,21 +322,7 @@ impl PluginManifest {
         }
 
         // Validate API version compatibility
-        let api_version = semver::Version::parse(&self.plugin.api_version)
-            .map_err(|e| PluginError::generic(format!("Invalid API version: {}", e)))?;
-
-        let min_version = semver::Version::parse(crate::MIN_PLUGIN_API_VERSION)
-            .map_err(|e| PluginError::generic(format!("Invalid minimum API version: {}", e)))?;
-
-        if api_version < min_version {
-            return Err(PluginError::ApiVersionIncompatible {
-                name: self.plugin.name.clone(),
-                api_version: self.plugin.api_version.clone(),
-                system_version: crate::PLUGIN_SYSTEM_VERSION.to_string(),
-            });
-        }
-
-        Ok(())
+        self.plugin.check_api_version()
     }
 }
 
@@ -446,4 +457,26 @@ mod tests {
         let invalid_manifest = PluginManifest::new(invalid_metadata);
         assert!(invalid_manifest.validate().is_err());
     }
+
+    #[test]
+    fn test_plugin_api_version_check() {
+        let mut metadata = PluginMetadata::new(
+            "test-plugin",
+            "Test Plugin",
+            PluginVersion::new(1, 0, 0),
+            "Test description",
+            "Test Author",
+            PluginType::Task,
+        );
+        assert!(metadata.check_api_version().is_ok());
+
+        metadata.api_version = "0.0.0".to_string();
+        assert!(matches!(
+            metadata.check_api_version(),
+            Err(PluginError::ApiVersionIncompatible { .. })
+        ));
+
+        metadata.api_version = "not-a-version".to_string();
+        assert!(metadata.check_api_version().is_err());
+    }
 }
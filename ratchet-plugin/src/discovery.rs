@@ -379,6 +379,35 @@ pub enum PluginDiscoveryEvent {
     DiscoveryError { path: PathBuf, error: String },
 }
 
+/// File extensions of plugin libraries
+const PLUGIN_LIBRARY_EXTENSIONS: [&str; 3] = ["so", "dll", "dylib"];
+
+/// Plugin libraries directly inside `directory`, in a stable load order
+pub async fn plugin_libraries(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut entries = tokio::fs::read_dir(directory).await?;
+    let mut libraries = Vec::new();
+
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let is_file = tokio::fs::metadata(&path)
+            .await
+            .is_ok_and(|metadata| metadata.is_file());
+        if is_file && is_plugin_library(&path) {
+            libraries.push(path);
+        }
+    }
+
+    libraries.sort();
+    Ok(libraries)
+}
+
+/// Whether `path` has the extension of a dynamic plugin library
+pub fn is_plugin_library(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| PLUGIN_LIBRARY_EXTENSIONS.contains(&extension))
+}
+
 /// Plugin catalog for managing discovered plugins
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PluginCatalog {
@@ -650,4 +679,25 @@ mod tests {
         // Should only have one plugin after deduplication
         assert_eq!(deduplicated.len(), 1);
     }
+
+    #[test]
+    fn test_is_plugin_library() {
+        assert!(is_plugin_library(Path::new("plugins/libmetrics.so")));
+        assert!(is_plugin_library(Path::new("plugins/metrics.dll")));
+        assert!(is_plugin_library(Path::new("plugins/libmetrics.dylib")));
+        assert!(!is_plugin_library(Path::new("plugins/manifest.json")));
+        assert!(!is_plugin_library(Path::new("plugins/README")));
+    }
+
+    #[tokio::test]
+    async fn test_plugin_libraries_skips_other_files() {
+        let dir = tempdir().unwrap();
+        std::fs::write(dir.path().join("b.so"), b"").unwrap();
+        std::fs::write(dir.path().join("a.dylib"), b"").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), b"").unwrap();
+        std::fs::create_dir(dir.path().join("nested.so")).unwrap();
+
+        let libraries = plugin_libraries(dir.path()).await.unwrap();
+        assert_eq!(libraries, vec![dir.path().join("a.dylib"), dir.path().join("b.so")]);
+    }
 }
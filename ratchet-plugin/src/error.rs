@@ -20,6 +20,10 @@ pub enum PluginError {
     #[error("Plugin '{name}' initialization failed: {reason}")]
     InitializationFailed { name: String, reason: String },
 
+    /// Plugin configuration rejected
+    #[error("Plugin '{name}' configuration is invalid: {reason}")]
+    InvalidConfig { name: String, reason: String },
+
     /// Plugin dependency error
     #[error("Plugin '{name}' dependency error: {reason}")]
     DependencyError { name: String, reason: String },
@@ -91,6 +95,14 @@ impl PluginError {
         }
     }
 
+    /// Create a new invalid configuration error
+    pub fn invalid_config(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidConfig {
+            name: name.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Create a new execution error
     pub fn execution_error(name: impl Into<String>, reason: impl Into<String>) -> Self {
         Self::ExecutionError {
@@ -1,6 +1,7 @@
 //! Plugin hook system for extending execution at specific points
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
@@ -120,6 +121,90 @@ impl TaskExecutionData {
     }
 }
 
+/// Resources consumed by an execution
+///
+/// Each figure is `None` when the executor cannot measure it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    /// Peak resident memory of the execution in bytes
+    pub peak_memory_bytes: Option<u64>,
+    /// CPU time spent by the execution in milliseconds
+    pub cpu_time_ms: Option<u64>,
+    /// Size of the serialized task output in bytes
+    pub output_bytes: Option<u64>,
+    /// Number of log entries the task produced
+    pub log_entries: Option<u64>,
+}
+
+/// Outcome of a finished execution, passed to metrics hooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionMetrics {
+    /// Task ID
+    pub task_id: String,
+    /// Execution ID, when the execution is tracked in storage
+    pub execution_id: Option<String>,
+    /// Whether the execution was successful
+    pub success: bool,
+    /// Error information (if execution failed)
+    pub error: Option<String>,
+    /// Time the task ran in milliseconds, as reported by the worker
+    pub duration_ms: u64,
+    /// Time from dispatch to result in milliseconds, including queueing on a worker
+    pub wall_time_ms: u64,
+    /// When the execution finished
+    pub completed_at: DateTime<Utc>,
+    /// Resources consumed by the execution
+    pub resource_usage: ResourceUsage,
+    /// Execution metadata
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+impl ExecutionMetrics {
+    /// Create metrics for a finished execution
+    pub fn new(task_id: impl Into<String>, success: bool, duration_ms: u64) -> Self {
+        Self {
+            task_id: task_id.into(),
+            execution_id: None,
+            success,
+            error: None,
+            duration_ms,
+            wall_time_ms: duration_ms,
+            completed_at: Utc::now(),
+            resource_usage: ResourceUsage::default(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Create metrics from the data post-execution hooks saw
+    pub fn from_execution_data(data: &TaskExecutionData) -> Self {
+        let mut metrics = Self::new(
+            data.task_id.clone(),
+            data.success.unwrap_or(false),
+            data.duration_ms.unwrap_or(0),
+        );
+        metrics.execution_id = data
+            .metadata
+            .get("execution_id")
+            .and_then(|value| value.as_str())
+            .map(str::to_string);
+        metrics.error = data.error.clone();
+        metrics.metadata = data.metadata.clone();
+        metrics
+    }
+
+    /// Set the wall-clock time observed by the executor
+    pub fn with_wall_time(mut self, wall_time_ms: u64) -> Self {
+        self.wall_time_ms = wall_time_ms;
+        self
+    }
+
+    /// Set resource usage
+    pub fn with_resource_usage(mut self, resource_usage: ResourceUsage) -> Self {
+        self.resource_usage = resource_usage;
+        self
+    }
+}
+
 /// Base trait for all hooks
 #[async_trait]
 pub trait Hook: Send + Sync {
@@ -221,6 +306,18 @@ pub trait ExecutionHook: Hook {
     }
 }
 
+/// Metrics hooks for observing the outcome of every execution
+///
+/// Metrics hooks are observers: they run after the task hooks of an execution,
+/// cannot change its result, and a failing metrics hook does not stop the
+/// others from running. Executions vetoed by a task hook are reported as
+/// failures.
+#[async_trait]
+pub trait MetricsHook: Hook {
+    /// Called once for every finished execution, successful or not
+    async fn on_execution_metrics(&self, context: &PluginContext, metrics: &ExecutionMetrics) -> PluginResult<()>;
+}
+
 /// Hook registration information
 #[derive(Debug, Clone)]
 pub struct HookRegistration {
@@ -242,6 +339,8 @@ pub struct HookRegistry {
     task_hooks: Arc<RwLock<BTreeMap<u16, Vec<(HookRegistration, Arc<dyn TaskHook>)>>>>,
     /// Registered execution hooks
     execution_hooks: Arc<RwLock<BTreeMap<u16, Vec<(HookRegistration, Arc<dyn ExecutionHook>)>>>>,
+    /// Registered metrics hooks
+    metrics_hooks: Arc<RwLock<BTreeMap<u16, Vec<(HookRegistration, Arc<dyn MetricsHook>)>>>>,
     /// Hook statistics
     stats: Arc<RwLock<HashMap<String, HookStats>>>,
 }
@@ -300,6 +399,7 @@ impl HookRegistry {
         Self {
             task_hooks: Arc::new(RwLock::new(BTreeMap::new())),
             execution_hooks: Arc::new(RwLock::new(BTreeMap::new())),
+            metrics_hooks: Arc::new(RwLock::new(BTreeMap::new())),
             stats: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -368,6 +468,38 @@ impl HookRegistry {
         Ok(registration.id)
     }
 
+    /// Register a metrics hook
+    pub async fn register_metrics_hook(
+        &self,
+        hook: Arc<dyn MetricsHook>,
+        plugin_id: impl Into<String>,
+    ) -> PluginResult<Uuid> {
+        let registration = HookRegistration {
+            id: Uuid::new_v4(),
+            name: hook.name().to_string(),
+            priority: hook.priority(),
+            plugin_id: plugin_id.into(),
+            enabled: true,
+        };
+
+        let priority_value: u16 = registration.priority.into();
+        let mut hooks = self.metrics_hooks.write().await;
+        hooks
+            .entry(priority_value)
+            .or_insert_with(Vec::new)
+            .push((registration.clone(), hook));
+
+        tracing::info!(
+            target: "hook_registry",
+            hook_id = %registration.id,
+            hook_name = %registration.name,
+            priority = priority_value,
+            "Metrics hook registered"
+        );
+
+        Ok(registration.id)
+    }
+
     /// Unregister a hook by ID
     pub async fn unregister_hook(&self, hook_id: Uuid) -> PluginResult<bool> {
         // Try task hooks first
@@ -404,6 +536,23 @@ impl HookRegistry {
             }
         }
 
+        // Try metrics hooks
+        {
+            let mut metrics_hooks = self.metrics_hooks.write().await;
+            for (_, hooks) in metrics_hooks.iter_mut() {
+                if let Some(pos) = hooks.iter().position(|(reg, _)| reg.id == hook_id) {
+                    let (registration, _) = hooks.remove(pos);
+                    tracing::info!(
+                        target: "hook_registry",
+                        hook_id = %hook_id,
+                        hook_name = %registration.name,
+                        "Metrics hook unregistered"
+                    );
+                    return Ok(true);
+                }
+            }
+        }
+
         Ok(false)
     }
 
@@ -795,6 +944,62 @@ impl HookRegistry {
         Ok(())
     }
 
+    /// Execute metrics hooks
+    ///
+    /// Every enabled hook runs even if an earlier one fails; the first failure
+    /// is returned once all hooks have run.
+    pub async fn execute_metrics_hooks(&self, context: &PluginContext, metrics: &ExecutionMetrics) -> PluginResult<()> {
+        let hooks = self.metrics_hooks.read().await;
+        let mut first_error = None;
+
+        for (_, priority_hooks) in hooks.iter() {
+            for (registration, hook) in priority_hooks {
+                if !registration.enabled {
+                    continue;
+                }
+
+                if !hook.should_run(context).await {
+                    continue;
+                }
+
+                let start_time = std::time::Instant::now();
+                let result = hook.on_execution_metrics(context, metrics).await;
+                let duration_us = start_time.elapsed().as_micros() as u64;
+
+                // Update statistics
+                let mut stats = self.stats.write().await;
+                let hook_stats = stats.entry(registration.name.clone()).or_default();
+
+                match result {
+                    Ok(()) => hook_stats.record_success(duration_us),
+                    Err(ref e) => {
+                        hook_stats.record_failure(duration_us);
+                        drop(stats);
+                        let _ = hook.handle_error(e, context).await;
+                        first_error.get_or_insert_with(|| {
+                            PluginError::hook_execution_failed(&registration.name, e.to_string())
+                        });
+                    }
+                }
+            }
+        }
+
+        match first_error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether any enabled metrics hook is registered
+    pub async fn has_metrics_hooks(&self) -> bool {
+        self.metrics_hooks
+            .read()
+            .await
+            .values()
+            .flatten()
+            .any(|(registration, _)| registration.enabled)
+    }
+
     /// Get hook statistics
     pub async fn get_stats(&self) -> HashMap<String, HookStats> {
         self.stats.read().await.clone()
@@ -818,6 +1023,13 @@ impl HookRegistry {
             }
         }
 
+        let metrics_hooks = self.metrics_hooks.read().await;
+        for (_, hooks) in metrics_hooks.iter() {
+            for (registration, _) in hooks {
+                registrations.push(registration.clone());
+            }
+        }
+
         registrations
     }
 
@@ -845,6 +1057,17 @@ impl HookRegistry {
             }
         }
 
+        // Try metrics hooks
+        {
+            let mut metrics_hooks = self.metrics_hooks.write().await;
+            for (_, hooks) in metrics_hooks.iter_mut() {
+                if let Some((registration, _)) = hooks.iter_mut().find(|(reg, _)| reg.id == hook_id) {
+                    registration.enabled = enabled;
+                    return Ok(true);
+                }
+            }
+        }
+
         Ok(false)
     }
 }
@@ -899,6 +1122,52 @@ mod tests {
         }
     }
 
+    struct TestMetricsHook {
+        name: String,
+        fail: bool,
+        observed: std::sync::Mutex<Vec<ExecutionMetrics>>,
+    }
+
+    impl TestMetricsHook {
+        fn new(name: impl Into<String>, fail: bool) -> Self {
+            Self {
+                name: name.into(),
+                fail,
+                observed: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Hook for TestMetricsHook {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn priority(&self) -> HookPriority {
+            if self.fail {
+                HookPriority::High
+            } else {
+                HookPriority::Low
+            }
+        }
+
+        async fn execute(&self, _context: &mut PluginContext, _data: &mut serde_json::Value) -> PluginResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl MetricsHook for TestMetricsHook {
+        async fn on_execution_metrics(&self, _context: &PluginContext, metrics: &ExecutionMetrics) -> PluginResult<()> {
+            self.observed.lock().unwrap().push(metrics.clone());
+            if self.fail {
+                return Err(PluginError::generic("exporter unavailable"));
+            }
+            Ok(())
+        }
+    }
+
     #[tokio::test]
     async fn test_hook_priority_ordering() {
         let registry = HookRegistry::new();
@@ -1023,6 +1292,69 @@ mod tests {
         assert_eq!(data.metadata["key"], "value");
     }
 
+    #[test]
+    fn test_execution_metrics_from_data() {
+        let data = TaskExecutionData::new("test-task", serde_json::json!({}))
+            .with_metadata("execution_id", serde_json::json!("exec-1"))
+            .with_result(false, 250)
+            .with_error("boom");
+
+        let metrics = ExecutionMetrics::from_execution_data(&data)
+            .with_wall_time(300)
+            .with_resource_usage(ResourceUsage {
+                output_bytes: Some(12),
+                ..Default::default()
+            });
+
+        assert_eq!(metrics.task_id, "test-task");
+        assert_eq!(metrics.execution_id.as_deref(), Some("exec-1"));
+        assert!(!metrics.success);
+        assert_eq!(metrics.error.as_deref(), Some("boom"));
+        assert_eq!(metrics.duration_ms, 250);
+        assert_eq!(metrics.wall_time_ms, 300);
+        assert_eq!(metrics.resource_usage.output_bytes, Some(12));
+        assert!(metrics.resource_usage.peak_memory_bytes.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failing_metrics_hook_does_not_stop_others() {
+        let registry = HookRegistry::new();
+        let failing = Arc::new(TestMetricsHook::new("failing-exporter", true));
+        let recording = Arc::new(TestMetricsHook::new("recording-exporter", false));
+        assert!(!registry.has_metrics_hooks().await);
+
+        registry
+            .register_metrics_hook(failing.clone(), "plugin1")
+            .await
+            .unwrap();
+        registry
+            .register_metrics_hook(recording.clone(), "plugin2")
+            .await
+            .unwrap();
+        assert_eq!(registry.list_hooks().await.len(), 2);
+        assert!(registry.has_metrics_hooks().await);
+
+        let context = PluginContext::new(
+            Uuid::new_v4(),
+            serde_json::json!({}),
+            ratchet_config::RatchetConfig::default(),
+        );
+        let metrics = ExecutionMetrics::new("test-task", true, 42);
+
+        let result = registry.execute_metrics_hooks(&context, &metrics).await;
+        assert!(result.is_err());
+
+        assert_eq!(failing.observed.lock().unwrap().len(), 1);
+        let observed = recording.observed.lock().unwrap().clone();
+        assert_eq!(observed.len(), 1);
+        assert_eq!(observed[0].task_id, "test-task");
+        assert_eq!(observed[0].duration_ms, 42);
+
+        let stats = registry.get_stats().await;
+        assert_eq!(stats["failing-exporter"].failures, 1);
+        assert_eq!(stats["recording-exporter"].successes, 1);
+    }
+
     #[tokio::test]
     async fn test_hook_stats() {
         let registry = HookRegistry::new();
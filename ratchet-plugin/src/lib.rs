@@ -15,7 +15,9 @@ pub mod types;
 // Re-export main types
 pub use core::{Plugin, PluginContext, PluginMetadata};
 pub use error::{PluginError, PluginResult};
-pub use hooks::{ExecutionHook, Hook, HookPriority, HookRegistry, TaskHook};
+pub use hooks::{
+    ExecutionHook, ExecutionMetrics, Hook, HookPriority, HookRegistry, MetricsHook, ResourceUsage, TaskHook,
+};
 pub use loader::{DynamicPluginLoader, PluginLoader, StaticPluginLoader};
 pub use manager::{PluginManager, PluginManagerBuilder};
 pub use registry::{PluginInfo, PluginRegistry};
@@ -4,10 +4,10 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::core::PluginContext;
+use crate::core::{Plugin, PluginContext};
 use crate::discovery::{DiscoveryConfig, PluginCatalog, PluginDiscovery};
 use crate::error::{PluginError, PluginResult};
-use crate::hooks::{ExecutionHook, HookRegistry, TaskExecutionData, TaskHook};
+use crate::hooks::{ExecutionHook, HookRegistry, MetricsHook, TaskExecutionData, TaskHook};
 use crate::loader::{ConfiguredPluginLoader, LoaderConfig, PluginLoader};
 use crate::registry::{PluginInfo, PluginRegistry};
 use crate::types::{PluginStatus, PluginType};
@@ -226,6 +226,9 @@ impl PluginManager {
     }
 
     /// Load a plugin from source
+    ///
+    /// A null or empty `config` is replaced by the plugin's `plugins.<id>`
+    /// section of the system configuration.
     pub async fn load_plugin_from_source(&self, source: &str, config: serde_json::Value) -> PluginResult<String> {
         let mut state = self.state.write().await;
         state.stats.total_loads += 1;
@@ -239,6 +242,24 @@ impl PluginManager {
 
         // Load plugin using loader
         let plugin = self.loader.load_plugin(source).await?;
+        self.register_loaded_plugin(plugin, config).await
+    }
+
+    /// Register and initialize a plugin that was loaded outside the manager
+    ///
+    /// The caller must keep whatever backs the plugin (such as its dynamic
+    /// library) alive for as long as the plugin stays registered. `config` is
+    /// resolved as in [`PluginManager::load_plugin_from_source`].
+    pub async fn register_plugin(&self, plugin: Box<dyn Plugin>, config: serde_json::Value) -> PluginResult<String> {
+        let mut state = self.state.write().await;
+        state.stats.total_loads += 1;
+        drop(state);
+
+        self.register_loaded_plugin(plugin, config).await
+    }
+
+    async fn register_loaded_plugin(&self, plugin: Box<dyn Plugin>, config: serde_json::Value) -> PluginResult<String> {
+        plugin.metadata().check_api_version()?;
         let plugin_id = plugin.metadata().id.clone();
 
         // Check if already loaded
@@ -246,8 +267,12 @@ impl PluginManager {
             return Err(PluginError::PluginAlreadyExists { name: plugin_id });
         }
 
-        // Validate plugin configuration
-        plugin.validate_config(&config)?;
+        // Validate plugin configuration; a rejected configuration keeps the plugin from loading
+        let config = self.resolve_plugin_config(&plugin_id, config).await;
+        plugin.validate_config(&config).map_err(|e| match e {
+            PluginError::InvalidConfig { .. } => e,
+            other => PluginError::invalid_config(&plugin_id, other.to_string()),
+        })?;
 
         // Register plugin
         self.registry.register_plugin(plugin, config.clone()).await?;
@@ -302,6 +327,27 @@ impl PluginManager {
         Ok(plugin_id)
     }
 
+    /// Configuration a plugin is loaded with: `config` unless it is null or
+    /// empty, otherwise the plugin's section of the system configuration
+    async fn resolve_plugin_config(&self, plugin_id: &str, config: serde_json::Value) -> serde_json::Value {
+        let is_empty = match &config {
+            serde_json::Value::Null => true,
+            serde_json::Value::Object(fields) => fields.is_empty(),
+            _ => false,
+        };
+        if !is_empty {
+            return config;
+        }
+
+        self.system_config
+            .read()
+            .await
+            .plugins
+            .section(plugin_id)
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}))
+    }
+
     /// Unload a plugin
     pub async fn unload_plugin(&self, plugin_id: &str) -> PluginResult<()> {
         tracing::info!(
@@ -463,6 +509,11 @@ impl PluginManager {
         self.registry.list_plugins_by_status(status).await
     }
 
+    /// Hook registry plugins register into, shared with the executors that run the hooks
+    pub fn hooks(&self) -> &Arc<HookRegistry> {
+        &self.hooks
+    }
+
     /// Register a task hook
     pub async fn register_task_hook(&self, hook: Arc<dyn TaskHook>, plugin_id: &str) -> PluginResult<Uuid> {
         self.hooks.register_task_hook(hook, plugin_id).await
@@ -473,6 +524,11 @@ impl PluginManager {
         self.hooks.register_execution_hook(hook, plugin_id).await
     }
 
+    /// Register a metrics hook
+    pub async fn register_metrics_hook(&self, hook: Arc<dyn MetricsHook>, plugin_id: &str) -> PluginResult<Uuid> {
+        self.hooks.register_metrics_hook(hook, plugin_id).await
+    }
+
     /// Get manager statistics
     pub async fn get_stats(&self) -> ManagerStats {
         let state = self.state.read().await;
@@ -618,15 +674,14 @@ mod tests {
     use crate::core::{Plugin, PluginContext, PluginMetadata};
     use crate::types::{PluginType, PluginVersion};
     use async_trait::async_trait;
+    use serde::Deserialize;
     use std::any::Any;
 
-    #[allow(dead_code)]
     struct TestPlugin {
         metadata: PluginMetadata,
     }
 
     impl TestPlugin {
-        #[allow(dead_code)]
         fn new() -> Self {
             let metadata = PluginMetadata::new(
                 "test-plugin",
@@ -660,6 +715,123 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Deserialize)]
+    struct ExporterConfig {
+        endpoint: String,
+        #[serde(default)]
+        interval_seconds: u64,
+    }
+
+    /// Plugin reading a typed configuration section
+    struct ExporterPlugin {
+        metadata: PluginMetadata,
+        config: Option<ExporterConfig>,
+    }
+
+    impl ExporterPlugin {
+        fn new() -> Self {
+            let metadata = PluginMetadata::new(
+                "metrics-exporter",
+                "Metrics Exporter",
+                PluginVersion::new(1, 0, 0),
+                "Exports metrics",
+                "Test Author",
+                PluginType::Monitoring,
+            );
+
+            Self { metadata, config: None }
+        }
+    }
+
+    #[async_trait]
+    impl Plugin for ExporterPlugin {
+        fn metadata(&self) -> &PluginMetadata {
+            &self.metadata
+        }
+
+        async fn initialize(&mut self, context: &mut PluginContext) -> PluginResult<()> {
+            self.config = Some(context.config_as()?);
+            context.set_status(PluginStatus::Active);
+            Ok(())
+        }
+
+        async fn execute(&mut self, _context: &mut PluginContext) -> PluginResult<serde_json::Value> {
+            let config = self.config.as_ref().expect("initialized");
+            Ok(serde_json::json!({"endpoint": config.endpoint, "interval_seconds": config.interval_seconds}))
+        }
+
+        fn validate_config(&self, config: &serde_json::Value) -> PluginResult<()> {
+            let config: ExporterConfig = serde_json::from_value(config.clone())?;
+            if !config.endpoint.starts_with("http") {
+                return Err(PluginError::invalid_config(
+                    &self.metadata.id,
+                    "endpoint must be an HTTP URL",
+                ));
+            }
+            Ok(())
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    fn manager_with_exporter_section(section: serde_json::Value) -> PluginManager {
+        let mut system_config = ratchet_config::RatchetConfig::default();
+        system_config
+            .plugins
+            .config
+            .insert("metrics-exporter".to_string(), section);
+
+        PluginManagerBuilder::new()
+            .with_system_config(system_config)
+            .with_auto_discover(false)
+            .with_auto_load(false)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_plugin_receives_config_section() {
+        let section = serde_json::json!({"endpoint": "http://localhost:9091", "interval_seconds": 30});
+        let manager = manager_with_exporter_section(section.clone());
+
+        let plugin_id = manager
+            .register_plugin(Box::new(ExporterPlugin::new()), serde_json::Value::Null)
+            .await
+            .unwrap();
+
+        let info = manager.get_plugin_info(&plugin_id).await.unwrap();
+        assert_eq!(info.status, PluginStatus::Active);
+        assert_eq!(info.config, section);
+
+        let output = manager.execute_plugin(&plugin_id, serde_json::json!({})).await.unwrap();
+        assert_eq!(output["endpoint"], "http://localhost:9091");
+        assert_eq!(output["interval_seconds"], 30);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_plugin_config_prevents_loading() {
+        // Rejected by the plugin's own checks
+        let manager = manager_with_exporter_section(serde_json::json!({"endpoint": "ftp://localhost"}));
+        let result = manager
+            .register_plugin(Box::new(ExporterPlugin::new()), serde_json::Value::Null)
+            .await;
+        assert!(matches!(result, Err(PluginError::InvalidConfig { .. })));
+        assert!(manager.get_plugin_info("metrics-exporter").await.is_none());
+
+        // Does not deserialize into the plugin's config struct
+        let manager = manager_with_exporter_section(serde_json::json!({"interval_seconds": "often"}));
+        let result = manager
+            .register_plugin(Box::new(ExporterPlugin::new()), serde_json::Value::Null)
+            .await;
+        assert!(matches!(result, Err(PluginError::InvalidConfig { .. })));
+        assert!(manager.get_plugin_info("metrics-exporter").await.is_none());
+    }
+
     #[tokio::test]
     async fn test_plugin_manager_builder() {
         let config = LoaderConfig::default();
@@ -736,6 +908,32 @@ mod tests {
         assert!(active_plugins.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_register_plugin() {
+        let manager = PluginManagerBuilder::new()
+            .with_auto_discover(false)
+            .with_auto_load(false)
+            .build();
+
+        let plugin_id = manager
+            .register_plugin(Box::new(TestPlugin::new()), serde_json::json!({}))
+            .await
+            .unwrap();
+        assert_eq!(plugin_id, "test-plugin");
+
+        let info = manager.get_plugin_info(&plugin_id).await.unwrap();
+        assert_eq!(info.status, PluginStatus::Active);
+        assert_eq!(manager.get_stats().await.total_loads, 1);
+
+        // Plugins built for an unsupported API version are rejected
+        let mut outdated = TestPlugin::new();
+        outdated.metadata.id = "outdated-plugin".to_string();
+        outdated.metadata.api_version = "0.0.0".to_string();
+        let result = manager.register_plugin(Box::new(outdated), serde_json::json!({})).await;
+        assert!(matches!(result, Err(PluginError::ApiVersionIncompatible { .. })));
+        assert!(manager.get_plugin_info("outdated-plugin").await.is_none());
+    }
+
     #[tokio::test]
     async fn test_plugin_manager_config_update() {
         let manager = PluginManagerBuilder::new()
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use url::Url;
 
 use crate::error::{RegistryError, Result};
+use crate::types::TaskReference;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegistryConfig {
@@ -12,6 +14,11 @@ pub struct RegistryConfig {
     pub enable_auto_sync: bool,
     pub enable_validation: bool,
     pub cache_config: CacheConfig,
+    /// How conflicting database copies are resolved, by source location (path or URL)
+    ///
+    /// Sources not listed use [`ConflictResolutionStrategy::RegistryWins`].
+    #[serde(default)]
+    pub conflict_resolution: HashMap<String, ConflictResolutionStrategy>,
 }
 
 impl Default for RegistryConfig {
@@ -22,10 +29,47 @@ impl Default for RegistryConfig {
             enable_auto_sync: true,
             enable_validation: true,
             cache_config: CacheConfig::default(),
+            conflict_resolution: HashMap::new(),
         }
     }
 }
 
+impl RegistryConfig {
+    /// Conflict resolution strategy for tasks synced from `source`
+    pub fn conflict_strategy(&self, source: &TaskSource) -> ConflictResolutionStrategy {
+        self.conflict_resolution
+            .get(source.location())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Filesystem source whose directory contains the task at `task_ref`
+    ///
+    /// Tasks from Git and HTTP sources are located in a local cache, not
+    /// under the source's URL, so they are never matched.
+    pub fn filesystem_source_of(&self, task_ref: &TaskReference) -> Option<&TaskSource> {
+        let task_path = Path::new(task_ref.source.strip_prefix("file://").unwrap_or(&task_ref.source));
+        self.sources
+            .iter()
+            .find(|source| source.filesystem_path().is_some_and(|path| task_path.starts_with(path)))
+    }
+}
+
+/// How a sync treats a task whose registry and database copies have both changed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolutionStrategy {
+    /// Overwrite the database copy with the registry version
+    #[default]
+    RegistryWins,
+    /// Keep the database copy and ignore the registry version
+    KeepLocal,
+    /// Keep whichever copy was modified most recently
+    PreferNewer,
+    /// Leave both copies untouched and report the conflict
+    Manual,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum TaskSource {
@@ -36,6 +80,8 @@ pub enum TaskSource {
         url: String,
         auth: Option<HttpAuth>,
         polling_interval: Duration,
+        #[serde(default)]
+        discovery: HttpDiscoveryConfig,
     },
     #[serde(rename = "git")]
     Git {
@@ -62,6 +108,34 @@ pub enum HttpAuthType {
     ApiKey { header_name: String, api_key: String },
 }
 
+/// How tasks are listed from an HTTP registry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpDiscoveryConfig {
+    /// Tasks requested per page from registries that support pagination
+    #[serde(default = "default_page_size")]
+    pub page_size: u32,
+    /// Only discover tasks carrying all of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Only discover tasks in this category
+    #[serde(default)]
+    pub category: Option<String>,
+}
+
+impl Default for HttpDiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            page_size: default_page_size(),
+            tags: Vec::new(),
+            category: None,
+        }
+    }
+}
+
+fn default_page_size() -> u32 {
+    100
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitAuth {
     #[serde(flatten)]
@@ -91,9 +165,9 @@ pub enum GitAuthType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GitConfig {
     /// Git branch name (e.g., "main", "master", "develop")
-    /// Also accepts tags or commit hashes
-    #[serde(alias = "git_ref", default = "default_git_branch")]
-    pub branch: String,
+    /// Also accepts tags or commit hashes; the remote's default branch when unset
+    #[serde(alias = "git_ref", default)]
+    pub branch: Option<String>,
 
     /// Subdirectory within repository
     pub subdirectory: Option<String>,
@@ -106,10 +180,6 @@ pub struct GitConfig {
     #[serde(default = "default_depth")]
     pub depth: Option<u32>,
 
-    /// Sync strategy
-    #[serde(default)]
-    pub sync_strategy: GitSyncStrategy,
-
     /// Cleanup on error
     #[serde(default = "default_cleanup_on_error")]
     pub cleanup_on_error: bool,
@@ -140,28 +210,13 @@ pub struct GitConfig {
     pub keep_history: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum GitSyncStrategy {
-    Clone,
-    Fetch,
-    Pull,
-}
-
-impl Default for GitSyncStrategy {
-    fn default() -> Self {
-        Self::Fetch
-    }
-}
-
 impl Default for GitConfig {
     fn default() -> Self {
         Self {
-            branch: default_git_branch(),
+            branch: None,
             subdirectory: None,
             shallow: default_shallow(),
             depth: default_depth(),
-            sync_strategy: GitSyncStrategy::default(),
             cleanup_on_error: default_cleanup_on_error(),
             verify_signatures: false,
             allowed_refs: None,
@@ -175,10 +230,6 @@ impl Default for GitConfig {
 }
 
 // Default value functions for Git config
-fn default_git_branch() -> String {
-    "main".to_string()
-}
-
 fn default_shallow() -> bool {
     true
 }
@@ -270,6 +321,7 @@ impl TaskSource {
                     url: uri.to_string(),
                     auth: None,
                     polling_interval: Duration::from_secs(300),
+                    discovery: HttpDiscoveryConfig::default(),
                 })
             }
         } else if uri.starts_with("git://") || uri.starts_with("ssh://") {
@@ -286,6 +338,14 @@ impl TaskSource {
         }
     }
 
+    /// Path or URL the source reads tasks from
+    pub fn location(&self) -> &str {
+        match self {
+            TaskSource::Filesystem { path, .. } => path,
+            TaskSource::Http { url, .. } | TaskSource::Git { url, .. } => url,
+        }
+    }
+
     pub fn filesystem_path(&self) -> Option<PathBuf> {
         match self {
             TaskSource::Filesystem { path, .. } => Some(PathBuf::from(path)),
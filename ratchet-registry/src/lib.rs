@@ -9,14 +9,15 @@ pub mod types;
 pub mod watcher;
 
 // Re-export main types and traits
-pub use config::{RegistryConfig, TaskSource, WatcherConfig};
+pub use config::{ConflictResolutionStrategy, RegistryConfig, TaskSource, WatcherConfig};
 pub use error::{RegistryError, Result};
-pub use loaders::{filesystem::FilesystemLoader, http::HttpLoader, TaskLoader};
+pub use loaders::{filesystem::FilesystemLoader, git::GitLoader, http::HttpLoader, TaskLoader};
 pub use registry::{DefaultTaskRegistry, TaskRegistry};
 pub use service::{DefaultRegistryService, RegistryService};
 pub use sync::{ConflictResolver, DatabaseSync};
 pub use types::{
-    DiscoveredTask, RegistryEvent, SyncResult, TaskDefinition, TaskMetadata, TaskReference, ValidationResult,
+    DiscoveredTask, RegistryEvent, SyncConflict, SyncResult, TaskDefinition, TaskMetadata, TaskReference,
+    ValidationResult,
 };
 pub use watcher::RegistryWatcher;
 
@@ -106,6 +106,7 @@ impl EmbeddedLoader {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             checksum: None,
+            commit: None,
         };
 
         // Create TaskDefinition
@@ -184,6 +185,7 @@ impl crate::loaders::TaskLoader for EmbeddedLoader {
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 checksum: None,
+                commit: None,
             };
 
             tasks.push(DiscoveredTask {
@@ -7,7 +7,7 @@ use uuid::Uuid;
 
 use crate::config::TaskSource;
 use crate::error::{RegistryError, Result};
-use crate::loaders::TaskLoader;
+use crate::loaders::{task_directory_checksum, task_directory_modified_at, TaskLoader};
 use crate::types::{DiscoveredTask, TaskDefinition, TaskMetadata, TaskReference};
 
 pub struct FilesystemLoader {
@@ -77,7 +77,8 @@ impl FilesystemLoader {
             .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
             .unwrap_or_default();
 
-        let now = Utc::now();
+        // Registry changes are detected by checksum, and ordered by the files' modification times
+        let modified_at = task_directory_modified_at(path).await?;
 
         Ok(TaskMetadata {
             uuid,
@@ -85,13 +86,15 @@ impl FilesystemLoader {
             version,
             description,
             tags,
-            created_at: now,
-            updated_at: now,
-            checksum: None, // TODO: Calculate checksum
+            created_at: modified_at,
+            updated_at: modified_at,
+            checksum: task_directory_checksum(path).await,
+            commit: None,
         })
     }
 
-    async fn load_task_definition_from_path(&self, path: &Path) -> Result<TaskDefinition> {
+    /// Load the task in the task directory at `path`
+    pub async fn load_task_definition_from_path(&self, path: &Path) -> Result<TaskDefinition> {
         let metadata = Self::load_task_metadata(path).await?;
 
         // Load main script
@@ -13,7 +13,7 @@ use std::collections::HashMap;
 #[cfg(feature = "git")]
 use std::path::{Path, PathBuf};
 #[cfg(feature = "git")]
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, Mutex};
 #[cfg(feature = "git")]
 use tokio::fs;
 #[cfg(feature = "git")]
@@ -26,7 +26,7 @@ use crate::config::{GitAuth, GitAuthType, GitConfig, TaskSource};
 #[cfg(feature = "git")]
 use crate::error::{RegistryError, Result};
 #[cfg(feature = "git")]
-use crate::loaders::TaskLoader;
+use crate::loaders::{task_directory_checksum, task_directory_modified_at, TaskLoader};
 #[cfg(feature = "git")]
 use crate::types::{DiscoveredTask, TaskDefinition, TaskMetadata, TaskReference};
 
@@ -90,7 +90,7 @@ impl GitLoader {
             // Check if we need to sync
             if self.cache.should_sync(&repo_path, &config.cache_ttl).await? {
                 info!("Syncing Git repository: {}", url);
-                self.git_client.sync_repository(&repo_path, config, auth).await?;
+                self.git_client.sync_repository(url, &repo_path, config, auth).await?;
             }
         }
 
@@ -193,7 +193,8 @@ impl GitLoader {
             .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
             .unwrap_or_default();
 
-        let now = Utc::now();
+        // Registry changes are detected by checksum, and ordered by the files' modification times
+        let modified_at = task_directory_modified_at(task_path).await?;
 
         Ok(TaskMetadata {
             uuid,
@@ -201,14 +202,16 @@ impl GitLoader {
             version,
             description,
             tags,
-            created_at: now,
-            updated_at: now,
-            checksum: None, // TODO: Calculate checksum from Git commit
+            created_at: modified_at,
+            updated_at: modified_at,
+            checksum: task_directory_checksum(task_path).await,
+            commit: None,
         })
     }
 
     async fn load_task_definition_from_path(&self, task_path: &Path) -> Result<TaskDefinition> {
-        let metadata = self.load_task_metadata(task_path).await?;
+        let mut metadata = self.load_task_metadata(task_path).await?;
+        metadata.commit = Some(self.git_client.head_commit(task_path).await?);
 
         // Load main script
         let main_js_path = task_path.join("main.js");
@@ -264,6 +267,7 @@ impl TaskLoader for GitLoader {
     async fn discover_tasks(&self, source: &TaskSource) -> Result<Vec<DiscoveredTask>> {
         let repo_path = self.ensure_repository_synced(source).await?;
         let config = source.git_config().unwrap();
+        let commit = self.git_client.head_commit(&repo_path).await?;
 
         // Try to use registry index for fast discovery
         if let Ok(Some(index)) = self.load_registry_index(&repo_path).await {
@@ -289,6 +293,7 @@ impl TaskLoader for GitLoader {
                         created_at: Utc::now(), // TODO: Use actual timestamps
                         updated_at: task_info.last_modified,
                         checksum: task_info.checksum,
+                        commit: Some(commit.clone()),
                     };
 
                     discovered.push(DiscoveredTask {
@@ -304,8 +309,14 @@ impl TaskLoader for GitLoader {
 
         // Fall back to directory scanning
         info!("Scanning repository directory for tasks");
-        self.scan_tasks_directory(&repo_path, config.subdirectory.as_deref())
-            .await
+        let mut discovered = self
+            .scan_tasks_directory(&repo_path, config.subdirectory.as_deref())
+            .await?;
+        for task in &mut discovered {
+            task.metadata.commit = Some(commit.clone());
+        }
+
+        Ok(discovered)
     }
 
     async fn load_task(&self, task_ref: &TaskReference) -> Result<TaskDefinition> {
@@ -433,8 +444,12 @@ impl GitRepositoryCache {
             return Ok(true);
         }
 
-        // Check if cache has expired based on last modified time
-        let metadata = fs::metadata(repo_path).await?;
+        // Check if cache has expired based on when it was last synced, or cloned if it never was
+        let stamp = sync_stamp_path(repo_path);
+        let metadata = match fs::metadata(&stamp).await {
+            Ok(metadata) => metadata,
+            Err(_) => fs::metadata(repo_path).await?,
+        };
         if let Ok(modified) = metadata.modified() {
             let elapsed = modified.elapsed().unwrap_or(std::time::Duration::MAX);
             return Ok(elapsed > *cache_ttl);
@@ -533,6 +548,14 @@ impl GitoxideClient {
         }
     }
 
+    /// Clone `url` into `local_path`, checking out the configured branch
+    ///
+    /// Without a configured branch the remote's default branch is checked out.
+    /// Shallow clones fetch only `config.depth` commits (one by default) unless
+    /// `keep_history` is set. Token and basic credentials answer the remote's
+    /// HTTP authentication requests; an SSH key is passed to `ssh` for SSH URLs,
+    /// which trusts a host's key on first use and records it in a `known_hosts`
+    /// file next to the clone.
     pub async fn clone_repository(
         &self,
         url: &str,
@@ -540,8 +563,6 @@ impl GitoxideClient {
         config: &GitConfig,
         auth: Option<&GitAuth>,
     ) -> Result<()> {
-        use gix::clone;
-
         // Create parent directory if it doesn't exist
         if let Some(parent) = local_path.parent() {
             fs::create_dir_all(parent).await?;
@@ -549,9 +570,10 @@ impl GitoxideClient {
 
         let url = url.to_string();
         let local_path_buf = local_path.to_path_buf();
+        let known_hosts = local_path.with_file_name(KNOWN_HOSTS_FILE);
         let git_ref = config.branch.clone();
-        let shallow = config.shallow;
-        let depth = config.depth;
+        let pinned = pinned_commit(config.branch.as_deref());
+        let depth = clone_depth(config);
         let auth_info = auth.map(|a| a.auth_type.clone());
 
         // Run Git operations in blocking task
@@ -563,312 +585,500 @@ impl GitoxideClient {
                 local_path_buf,
                 gix::create::Kind::WithWorktree,
                 gix::create::Options::default(),
-                gix::open::Options::isolated(),
+                Self::open_options(auth_info.as_ref(), &known_hosts)?,
             )
             .map_err(|e| format!("Failed to prepare fetch: {}", e))?;
 
-            // Configure authentication if provided
-            if let Some(auth_type) = auth_info {
-                match Self::setup_gix_auth(&mut clone_options, &auth_type) {
-                    Ok(_) => {}
-                    Err(e) => return Err(format!("Auth setup failed: {}", e)),
-                }
+            if let Some(depth) = depth {
+                clone_options = clone_options.with_shallow(gix::remote::fetch::Shallow::DepthAtRemote(depth));
             }
 
-            // Configure shallow clone if requested
-            if shallow {
-                if let Some(depth) = depth {
-                    info!("Shallow clone requested with depth: {}", depth);
-                    // Note: In gix 0.66, shallow clone configuration might be handled differently
-                    // For now, we'll rely on the default clone behavior and add depth later if needed
-                }
+            // A commit hash is no ref name: clone the default branch and check the commit out afterwards
+            if let (Some(git_ref), None) = (&git_ref, pinned) {
+                clone_options = clone_options
+                    .with_ref_name(Some(git_ref.as_str()))
+                    .map_err(|e| format!("Invalid ref '{}': {}", git_ref, e))?;
             }
 
-            // Configure ref if not default
-            if git_ref != "main" && git_ref != "master" {
-                info!("Custom ref '{}' will be checked out after clone", git_ref);
-                // We'll handle custom refs in the checkout phase
-            }
+            clone_options = Self::with_credentials(clone_options, auth_info.as_ref());
 
             // Perform the clone
             let (mut clone_prep, _outcome) = clone_options
                 .fetch_then_checkout(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
                 .map_err(|e| format!("Clone failed: {}", e))?;
 
-            let (_repo, _outcome) = clone_prep
+            let (repo, _outcome) = clone_prep
                 .main_worktree(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
                 .map_err(|e| format!("Checkout failed: {}", e))?;
+            if let Some(commit) = pinned {
+                Self::checkout_commit(&repo, commit, false)?;
+            }
 
+            let commit = repo.head_id().map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
             info!(
-                "Successfully cloned repository {} to {}",
+                "Successfully cloned repository {} to {} at commit {}",
                 url_clone,
-                path_clone.display()
+                path_clone.display(),
+                commit
             );
             Ok::<(), String>(())
         })
         .await?;
 
+        if result.is_err() && config.cleanup_on_error && local_path.exists() {
+            if let Err(e) = fs::remove_dir_all(local_path).await {
+                warn!("Failed to remove incomplete clone at {:?}: {}", local_path, e);
+            }
+        }
+
         result.map_err(RegistryError::GitError)
     }
 
-    pub async fn sync_repository(&self, repo_path: &Path, config: &GitConfig, auth: Option<&GitAuth>) -> Result<()> {
-        let repo_path_buf = repo_path.to_path_buf();
+    /// Bring the clone at `repo_path` up to date with the configured branch
+    ///
+    /// New commits are fetched into the clone and its checkout fast-forwarded.
+    /// A shallow clone cannot be fast-forwarded in place, nor can a branch whose
+    /// history was rewritten, so those are cloned afresh next to the existing
+    /// clone and swapped in once the clone succeeds. The existing clone is kept
+    /// if the new one fails. Syncs of the same clone run one at a time.
+    pub async fn sync_repository(
+        &self,
+        url: &str,
+        repo_path: &Path,
+        config: &GitConfig,
+        auth: Option<&GitAuth>,
+    ) -> Result<()> {
+        let lock = repository_lock(repo_path);
+        let _guard = lock.lock().await;
+
+        let path = repo_path.to_path_buf();
+        let known_hosts = repo_path.with_file_name(KNOWN_HOSTS_FILE);
         let git_ref = config.branch.clone();
         let auth_info = auth.map(|a| a.auth_type.clone());
-
-        let result = tokio::task::spawn_blocking(move || {
-            let repo_path_str = repo_path_buf.display().to_string();
-            // Open the repository
-            let repo = gix::discover(&repo_path_buf).map_err(|e| format!("Failed to open repository: {}", e))?;
-
-            // Get the remote
-            let remote = repo
-                .find_default_remote(gix::remote::Direction::Fetch)
-                .ok_or_else(|| "No default remote found".to_string())?
-                .map_err(|e| format!("Failed to get remote: {}", e))?;
-
-            // Configure authentication if provided
-            if let Some(auth_type) = auth_info {
-                match Self::setup_gix_sync_auth(&auth_type) {
-                    Ok(_) => {}
-                    Err(e) => return Err(format!("Auth setup failed for sync: {}", e)),
-                }
-            }
-
-            // Fetch from remote
-            let connection = remote
-                .connect(gix::remote::Direction::Fetch)
-                .map_err(|e| format!("Failed to connect to remote: {}", e))?;
-
-            let _fetch_outcome = connection
-                .prepare_fetch(gix::progress::Discard, gix::remote::ref_map::Options::default())
-                .map_err(|e| format!("Failed to prepare fetch: {}", e))?
-                .receive(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
-                .map_err(|e| format!("Failed to fetch: {}", e))?;
-
-            // Update refs - simplified for gix 0.66 compatibility
-            // The fetch_outcome should have already updated refs
-            info!("Fetch completed successfully");
-
-            // Checkout the requested ref
-            Self::checkout_gix_ref(&repo, &git_ref)
-                .map_err(|e| format!("Failed to checkout ref '{}': {}", git_ref, e))?;
-
-            info!(
-                "Successfully synced repository at {} (branch: {})",
-                repo_path_str, git_ref
-            );
-            Ok::<(), String>(())
+        let fetched = tokio::task::spawn_blocking(move || {
+            Self::fetch_and_fast_forward(&path, git_ref.as_deref(), auth_info.as_ref(), &known_hosts)
         })
-        .await?;
+        .await?
+        .map_err(RegistryError::GitError)?;
 
-        result.map_err(RegistryError::GitError)
-    }
-
-    fn setup_gix_auth(
-        clone_options: &mut clone::PrepareFetch,
-        auth_type: &GitAuthType,
-    ) -> std::result::Result<(), String> {
-        match auth_type {
-            GitAuthType::Token { token } => {
-                info!("Setting up token authentication for gitoxide");
-                // For token auth, we can set up credentials callback
-                Self::setup_token_auth(clone_options, token)
+        match fetched {
+            FetchOutcome::UpToDate => {
+                info!("Repository at {} is up to date", repo_path.display());
             }
-            GitAuthType::Basic { username, password } => {
-                info!("Setting up basic authentication for gitoxide");
-                Self::setup_basic_auth(clone_options, username, password)
+            FetchOutcome::FastForwarded { from, to } => {
+                info!(
+                    "Fast-forwarded repository at {} from {} to {}",
+                    repo_path.display(),
+                    from,
+                    to
+                );
             }
-            GitAuthType::SshKey {
-                private_key_path,
-                passphrase,
-            } => {
-                info!("Setting up SSH key authentication for gitoxide");
-                Self::setup_ssh_auth(clone_options, private_key_path, passphrase.as_deref())
-            }
-            GitAuthType::GitHubApp { .. } => {
-                // GitHub App auth is complex and would need JWT token generation
-                Err("GitHub App authentication not yet implemented - use token instead".to_string())
+            FetchOutcome::NeedsClone(reason) => {
+                info!("Re-cloning repository at {}: {}", repo_path.display(), reason);
+                self.replace_clone(url, repo_path, config, auth).await?;
             }
         }
-    }
-
-    fn setup_token_auth(_clone_options: &mut clone::PrepareFetch, token: &str) -> std::result::Result<(), String> {
-        // For HTTP clone operations with tokens, gix typically handles this through URL credentials
-        // or environment variables. For now, we'll configure the credential helper approach.
-        std::env::set_var("GIT_ASKPASS", "echo");
-        std::env::set_var("GIT_USERNAME", token);
-        std::env::set_var("GIT_PASSWORD", "");
 
-        info!("Configured token-based authentication via environment");
+        fs::write(sync_stamp_path(repo_path), Utc::now().to_rfc3339()).await?;
+        info!(
+            "Successfully synced repository at {} (branch: {})",
+            repo_path.display(),
+            config.branch.as_deref().unwrap_or("remote default")
+        );
         Ok(())
     }
 
-    fn setup_basic_auth(
-        _clone_options: &mut clone::PrepareFetch,
-        username: &str,
-        password: &str,
-    ) -> std::result::Result<(), String> {
-        // Set up basic auth via environment variables
-        std::env::set_var("GIT_ASKPASS", "echo");
-        std::env::set_var("GIT_USERNAME", username);
-        std::env::set_var("GIT_PASSWORD", password);
+    /// Clone `url` next to `repo_path` and swap the new clone in
+    async fn replace_clone(
+        &self,
+        url: &str,
+        repo_path: &Path,
+        config: &GitConfig,
+        auth: Option<&GitAuth>,
+    ) -> Result<()> {
+        let staging_path = sibling_path(repo_path, "sync");
+        let previous_path = sibling_path(repo_path, "previous");
+
+        for stale in [&staging_path, &previous_path] {
+            if stale.exists() {
+                fs::remove_dir_all(stale).await?;
+            }
+        }
+
+        self.clone_repository(url, &staging_path, config, auth).await?;
 
-        info!("Configured basic authentication for user: {}", username);
+        fs::rename(repo_path, &previous_path).await?;
+        if let Err(e) = fs::rename(&staging_path, repo_path).await {
+            // Put the previous clone back so the loader keeps serving it
+            fs::rename(&previous_path, repo_path).await?;
+            return Err(e.into());
+        }
+        fs::remove_dir_all(&previous_path).await?;
         Ok(())
     }
 
-    fn setup_ssh_auth(
-        _clone_options: &mut clone::PrepareFetch,
-        private_key_path: &str,
-        passphrase: Option<&str>,
-    ) -> std::result::Result<(), String> {
-        // Verify SSH key exists
-        if !std::path::Path::new(private_key_path).exists() {
-            return Err(format!("SSH private key not found: {}", private_key_path));
+    /// Fetch from the clone's remote and move its checkout to the configured ref
+    ///
+    /// Branches only move forward; a pinned commit or tag is checked out as is.
+    fn fetch_and_fast_forward(
+        repo_path: &Path,
+        git_ref: Option<&str>,
+        auth_type: Option<&GitAuthType>,
+        known_hosts: &Path,
+    ) -> std::result::Result<FetchOutcome, String> {
+        let repo = gix::open_opts(repo_path, Self::open_options(auth_type, known_hosts)?)
+            .map_err(|e| format!("Failed to open repository: {}", e))?;
+        if repo.is_shallow() {
+            return Ok(FetchOutcome::NeedsClone("shallow clones cannot be fast-forwarded"));
         }
 
-        // Set up SSH authentication via environment variables
-        std::env::set_var(
-            "GIT_SSH_COMMAND",
-            format!(
-                "ssh -i {} -o StrictHostKeyChecking=no{}",
-                private_key_path,
-                if passphrase.is_some() {
-                    " -o PasswordAuthentication=yes"
-                } else {
-                    " -o PasswordAuthentication=no"
+        let head = repo
+            .head_id()
+            .map_err(|e| format!("Failed to resolve HEAD: {}", e))?
+            .detach();
+        let pinned = pinned_commit(git_ref);
+        if pinned == Some(head) {
+            return Ok(FetchOutcome::UpToDate);
+        }
+
+        let remote = repo
+            .find_default_remote(gix::remote::Direction::Fetch)
+            .ok_or_else(|| "Repository has no remote to fetch from".to_string())?
+            .map_err(|e| format!("Failed to find remote: {}", e))?;
+        let remote_name = remote
+            .name()
+            .map(|name| name.as_bstr().to_string())
+            .unwrap_or_else(|| "origin".to_string());
+        let mut connection = remote
+            .connect(gix::remote::Direction::Fetch)
+            .map_err(|e| format!("Failed to connect to remote: {}", e))?;
+        if let Some((username, password)) = http_credentials(auth_type) {
+            connection.set_credentials(credential_helper(username, password));
+        }
+        connection
+            .prepare_fetch(gix::progress::Discard, Default::default())
+            .map_err(|e| format!("Failed to prepare fetch: {}", e))?
+            .receive(gix::progress::Discard, &gix::interrupt::IS_INTERRUPTED)
+            .map_err(|e| format!("Fetch failed: {}", e))?;
+
+        let (target, follow_branch) = match (pinned, git_ref) {
+            (Some(commit), _) => (commit, false),
+            (None, git_ref) => {
+                let branch = match git_ref {
+                    Some(git_ref) => git_ref.to_string(),
+                    None => repo
+                        .head_name()
+                        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?
+                        .map(|name| name.shorten().to_string())
+                        .ok_or_else(|| "HEAD is detached and no branch is configured".to_string())?,
+                };
+                let resolve = |name: String| {
+                    repo.find_reference(name.as_str())
+                        .ok()
+                        .and_then(|mut reference| reference.peel_to_id_in_place().ok())
+                        .map(|id| id.detach())
+                };
+                match resolve(format!("refs/remotes/{}/{}", remote_name, branch)) {
+                    Some(commit) => (commit, true),
+                    None => (
+                        resolve(format!("refs/tags/{}", branch))
+                            .ok_or_else(|| format!("Ref '{}' not found on the remote", branch))?,
+                        false,
+                    ),
                 }
-            ),
-        );
+            }
+        };
 
-        if let Some(_passphrase) = passphrase {
-            // For SSH keys with passphrase, we'd need a more sophisticated approach
-            warn!("SSH key passphrase provided but automated handling not fully implemented");
-            std::env::set_var("SSH_ASKPASS", "echo");
-            std::env::set_var("DISPLAY", ":0"); // Required for SSH_ASKPASS to work
+        if target == head {
+            return Ok(FetchOutcome::UpToDate);
+        }
+        if follow_branch && !Self::is_ancestor(&repo, head, target)? {
+            return Ok(FetchOutcome::NeedsClone("the branch history was rewritten"));
         }
 
-        info!("Configured SSH authentication with key: {}", private_key_path);
-        Ok(())
+        Self::checkout_commit(&repo, target, follow_branch)?;
+        Ok(FetchOutcome::FastForwarded { from: head, to: target })
     }
 
-    fn setup_gix_sync_auth(auth_type: &GitAuthType) -> std::result::Result<(), String> {
-        // For sync operations, we use the same auth setup as clone
-        match auth_type {
-            GitAuthType::Token { token } => Self::setup_sync_token_auth(token),
-            GitAuthType::Basic { username, password } => Self::setup_sync_basic_auth(username, password),
-            GitAuthType::SshKey {
-                private_key_path,
-                passphrase,
-            } => Self::setup_sync_ssh_auth(private_key_path, passphrase.as_deref()),
-            GitAuthType::GitHubApp { .. } => {
-                Err("GitHub App authentication not yet implemented for sync - use token instead".to_string())
+    /// Whether `ancestor` is in the history of `commit`
+    fn is_ancestor(
+        repo: &gix::Repository,
+        ancestor: gix::ObjectId,
+        commit: gix::ObjectId,
+    ) -> std::result::Result<bool, String> {
+        let walk = repo
+            .rev_walk([commit])
+            .all()
+            .map_err(|e| format!("Failed to walk history: {}", e))?;
+        for info in walk {
+            let info = info.map_err(|e| format!("Failed to walk history: {}", e))?;
+            if info.id == ancestor {
+                return Ok(true);
             }
         }
+        Ok(false)
     }
 
-    fn setup_sync_token_auth(token: &str) -> std::result::Result<(), String> {
-        std::env::set_var("GIT_ASKPASS", "echo");
-        std::env::set_var("GIT_USERNAME", token);
-        std::env::set_var("GIT_PASSWORD", "");
-        info!("Configured token-based authentication for sync");
+    /// Check `commit` out into the worktree and point HEAD at it
+    ///
+    /// With `follow_branch` the branch HEAD refers to is moved, otherwise HEAD
+    /// is detached at the commit. Files the new commit no longer tracks are
+    /// removed, along with directories that are left empty.
+    fn checkout_commit(
+        repo: &gix::Repository,
+        commit: gix::ObjectId,
+        follow_branch: bool,
+    ) -> std::result::Result<(), String> {
+        let workdir = repo
+            .work_dir()
+            .ok_or_else(|| "Repository has no worktree".to_string())?
+            .to_path_buf();
+        let tree = repo
+            .find_object(commit)
+            .map_err(|e| format!("Commit {} not found: {}", commit, e))?
+            .peel_to_tree()
+            .map_err(|e| format!("Commit {} has no tree: {}", commit, e))?
+            .id;
+
+        let previous = repo
+            .index_or_empty()
+            .map_err(|e| format!("Failed to read index: {}", e))?;
+        let mut index = repo
+            .index_from_tree(&tree)
+            .map_err(|e| format!("Failed to build index: {}", e))?;
+        let mut options = repo
+            .checkout_options(gix::worktree::stack::state::attributes::Source::IdMapping)
+            .map_err(|e| format!("Failed to configure checkout: {}", e))?;
+        options.overwrite_existing = true;
+        options.destination_is_initially_empty = false;
+
+        let objects = repo
+            .objects
+            .clone()
+            .into_arc()
+            .map_err(|e| format!("Failed to open object database: {}", e))?;
+        gix::worktree::state::checkout(
+            &mut index,
+            workdir.clone(),
+            objects,
+            &gix::progress::Discard,
+            &gix::progress::Discard,
+            &gix::interrupt::IS_INTERRUPTED,
+            options,
+        )
+        .map_err(|e| format!("Checkout failed: {}", e))?;
+        index
+            .write(Default::default())
+            .map_err(|e| format!("Failed to write index: {}", e))?;
+
+        let tracked: std::collections::HashSet<_> = index.entries().iter().map(|entry| entry.path(&index)).collect();
+        for entry in previous.entries() {
+            let path = entry.path(&previous);
+            if !tracked.contains(path) {
+                let file = workdir.join(gix::path::from_bstr(path));
+                if let Err(e) = std::fs::remove_file(&file) {
+                    warn!("Failed to remove untracked file {:?}: {}", file, e);
+                    continue;
+                }
+                let mut dir = file.parent();
+                while let Some(parent) = dir.filter(|parent| *parent != workdir) {
+                    if std::fs::remove_dir(parent).is_err() {
+                        break;
+                    }
+                    dir = parent.parent();
+                }
+            }
+        }
+
+        let head = gix::refs::FullName::try_from("HEAD").map_err(|e| e.to_string())?;
+        repo.edit_reference(gix::refs::transaction::RefEdit {
+            change: gix::refs::transaction::Change::Update {
+                log: gix::refs::transaction::LogChange {
+                    message: format!("ratchet: sync to {}", commit).into(),
+                    ..Default::default()
+                },
+                expected: gix::refs::transaction::PreviousValue::Any,
+                new: gix::refs::Target::Object(commit),
+            },
+            name: head,
+            deref: follow_branch,
+        })
+        .map_err(|e| format!("Failed to update HEAD: {}", e))?;
         Ok(())
     }
 
-    fn setup_sync_basic_auth(username: &str, password: &str) -> std::result::Result<(), String> {
-        std::env::set_var("GIT_ASKPASS", "echo");
-        std::env::set_var("GIT_USERNAME", username);
-        std::env::set_var("GIT_PASSWORD", password);
-        info!("Configured basic authentication for sync: {}", username);
-        Ok(())
+    /// Commit checked out in the repository containing `path`
+    pub async fn head_commit(&self, path: &Path) -> Result<String> {
+        let path = path.to_path_buf();
+
+        let result = tokio::task::spawn_blocking(move || {
+            let repo = gix::discover(&path).map_err(|e| format!("Failed to open repository: {}", e))?;
+            let commit = repo.head_id().map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+            Ok::<String, String>(commit.detach().to_string())
+        })
+        .await?;
+
+        result.map_err(RegistryError::GitError)
     }
 
-    fn setup_sync_ssh_auth(private_key_path: &str, passphrase: Option<&str>) -> std::result::Result<(), String> {
-        if !std::path::Path::new(private_key_path).exists() {
-            return Err(format!("SSH private key not found: {}", private_key_path));
-        }
+    /// Options for opening a new clone, pointing `ssh` at the configured key
+    ///
+    /// Unknown hosts are accepted and added to `known_hosts`; a host whose key
+    /// changed afterwards is refused.
+    fn open_options(
+        auth_type: Option<&GitAuthType>,
+        known_hosts: &Path,
+    ) -> std::result::Result<gix::open::Options, String> {
+        let options = gix::open::Options::isolated();
 
-        std::env::set_var(
-            "GIT_SSH_COMMAND",
-            format!(
-                "ssh -i {} -o StrictHostKeyChecking=no{}",
+        match auth_type {
+            Some(GitAuthType::SshKey {
                 private_key_path,
+                passphrase,
+            }) => {
+                if !Path::new(private_key_path).exists() {
+                    return Err(format!("SSH private key not found: {}", private_key_path));
+                }
                 if passphrase.is_some() {
-                    " -o PasswordAuthentication=yes"
-                } else {
-                    " -o PasswordAuthentication=no"
+                    warn!(
+                        "SSH key passphrases cannot be entered non-interactively; load the key into ssh-agent instead"
+                    );
                 }
-            ),
-        );
 
-        if let Some(_passphrase) = passphrase {
-            warn!("SSH key passphrase provided for sync but automated handling not fully implemented");
-            std::env::set_var("SSH_ASKPASS", "echo");
-            std::env::set_var("DISPLAY", ":0");
+                info!("Configured SSH authentication with key: {}", private_key_path);
+                Ok(options.config_overrides([format!(
+                    "core.sshCommand=ssh -i {} -o IdentitiesOnly=yes -o StrictHostKeyChecking=accept-new -o UserKnownHostsFile={}",
+                    shell_quote(private_key_path),
+                    shell_quote(&known_hosts.to_string_lossy())
+                )]))
+            }
+            Some(GitAuthType::GitHubApp { .. }) => {
+                // GitHub App auth is complex and would need JWT token generation
+                Err("GitHub App authentication not yet implemented - use token instead".to_string())
+            }
+            _ => Ok(options),
         }
+    }
 
-        info!("Configured SSH authentication for sync with key: {}", private_key_path);
-        Ok(())
+    /// Answer HTTP authentication requests with the configured token or username and password
+    fn with_credentials(clone_options: clone::PrepareFetch, auth_type: Option<&GitAuthType>) -> clone::PrepareFetch {
+        let Some((username, password)) = http_credentials(auth_type) else {
+            return clone_options;
+        };
+
+        clone_options.configure_connection(move |connection| {
+            connection.set_credentials(credential_helper(username.clone(), password.clone()));
+            Ok(())
+        })
     }
+}
 
-    fn checkout_gix_ref(repo: &gix::Repository, git_ref: &str) -> std::result::Result<(), String> {
-        info!("Attempting to checkout ref: {}", git_ref);
-
-        // Try multiple reference patterns to find the desired ref
-        let ref_patterns = vec![
-            git_ref.to_string(),                        // Direct ref (e.g., "main")
-            format!("refs/heads/{}", git_ref),          // Local branch
-            format!("refs/remotes/origin/{}", git_ref), // Remote tracking branch
-            format!("refs/tags/{}", git_ref),           // Tag
-            format!("origin/{}", git_ref),              // Short remote ref
-        ];
-
-        let mut reference = None;
-        for pattern in &ref_patterns {
-            match repo.find_reference(pattern) {
-                Ok(ref_obj) => {
-                    info!("Found reference: {}", pattern);
-                    reference = Some(ref_obj);
-                    break;
-                }
-                Err(_) => continue,
-            }
-        }
+/// Result of fetching into an existing clone
+#[cfg(feature = "git")]
+enum FetchOutcome {
+    /// The clone already has the configured commit checked out
+    UpToDate,
+    /// The checkout moved to a newer commit
+    FastForwarded { from: gix::ObjectId, to: gix::ObjectId },
+    /// The clone can't be updated in place, for the given reason
+    NeedsClone(&'static str),
+}
 
-        let reference = reference
-            .ok_or_else(|| format!("Reference '{}' not found. Tried patterns: {:?}", git_ref, ref_patterns))?;
+/// Username and password answering HTTP authentication requests, if the auth uses them
+#[cfg(feature = "git")]
+fn http_credentials(auth_type: Option<&GitAuthType>) -> Option<(String, String)> {
+    match auth_type {
+        Some(GitAuthType::Token { token }) => Some((TOKEN_USERNAME.to_string(), token.clone())),
+        Some(GitAuthType::Basic { username, password }) => Some((username.clone(), password.clone())),
+        _ => None,
+    }
+}
 
-        // Get the target commit
-        let target = reference.target();
-        let commit_id = target
-            .try_id()
-            .ok_or_else(|| format!("Reference '{}' does not point to a commit", git_ref))?;
+/// Credential helper answering every request with `username` and `password`
+#[cfg(feature = "git")]
+fn credential_helper(
+    username: String,
+    password: String,
+) -> impl FnMut(gix::credentials::helper::Action) -> gix::credentials::protocol::Result {
+    move |action| match action {
+        gix::credentials::helper::Action::Get(context) => Ok(Some(gix::credentials::protocol::Outcome {
+            identity: gix::sec::identity::Account {
+                username: username.clone(),
+                password: password.clone(),
+            },
+            next: context.into(),
+        })),
+        _ => Ok(None),
+    }
+}
 
-        info!("Found commit ID: {}", commit_id);
+/// Locks serialising syncs of each clone, keyed by its path
+#[cfg(feature = "git")]
+static SYNC_LOCKS: LazyLock<Mutex<HashMap<PathBuf, Arc<tokio::sync::Mutex<()>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
 
-        // Update HEAD to point to the commit
-        let head_ref = repo.head_ref().map_err(|e| format!("Failed to get HEAD: {}", e))?;
-        if let Some(mut head_ref) = head_ref {
-            head_ref
-                .set_target_id(commit_id, format!("checkout ref {}", git_ref))
-                .map_err(|e| format!("Failed to set HEAD to commit: {}", e))?;
-            info!("Updated HEAD to commit: {}", commit_id);
-        } else {
-            warn!("No HEAD reference found, but continuing");
-        }
+/// Lock held while the clone at `repo_path` is fetched into or replaced
+#[cfg(feature = "git")]
+fn repository_lock(repo_path: &Path) -> Arc<tokio::sync::Mutex<()>> {
+    SYNC_LOCKS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(repo_path.to_path_buf())
+        .or_default()
+        .clone()
+}
 
-        // For gix 0.66, we'll use a simplified approach for working tree checkout
-        if let Some(_worktree) = repo.worktree() {
-            info!("Working tree found - checkout completed for ref '{}'", git_ref);
-            // In a future implementation, we could use gix's checkout functionality here
-        } else {
-            return Err("Repository has no working tree".to_string());
-        }
+/// The commit `git_ref` names, if it is a full commit hash rather than a branch or tag
+#[cfg(feature = "git")]
+fn pinned_commit(git_ref: Option<&str>) -> Option<gix::ObjectId> {
+    let git_ref = git_ref?;
+    if !matches!(git_ref.len(), 40 | 64) || !git_ref.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    gix::ObjectId::from_hex(git_ref.as_bytes()).ok()
+}
 
-        info!("Successfully checked out ref: {}", git_ref);
-        Ok(())
+/// File in a clone's `.git` directory touched whenever the clone is synced
+#[cfg(feature = "git")]
+const SYNC_STAMP_FILE: &str = "ratchet-last-sync";
+
+/// Path of the file recording when the clone at `repo_path` was last synced
+#[cfg(feature = "git")]
+fn sync_stamp_path(repo_path: &Path) -> PathBuf {
+    repo_path.join(".git").join(SYNC_STAMP_FILE)
+}
+
+/// Username sent with token credentials; hosts authenticate the token itself
+#[cfg(feature = "git")]
+const TOKEN_USERNAME: &str = "x-access-token";
+
+/// File next to the cached clones recording the SSH host keys seen so far
+#[cfg(feature = "git")]
+const KNOWN_HOSTS_FILE: &str = "known_hosts";
+
+/// Quote `value` as a single argument for the shell `core.sshCommand` runs in
+#[cfg(feature = "git")]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Number of commits to fetch, or `None` for the full history
+///
+/// A pinned commit may be anywhere in the history, so it always gets all of it.
+#[cfg(feature = "git")]
+fn clone_depth(config: &GitConfig) -> Option<std::num::NonZeroU32> {
+    if !config.shallow || config.keep_history || pinned_commit(config.branch.as_deref()).is_some() {
+        return None;
     }
+    std::num::NonZeroU32::new(config.depth.unwrap_or(1))
+}
+
+/// Path next to `repo_path` used while replacing the clone
+#[cfg(feature = "git")]
+fn sibling_path(repo_path: &Path, suffix: &str) -> PathBuf {
+    let mut name = repo_path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", suffix));
+    repo_path.with_file_name(name)
 }
 
 #[cfg(feature = "git")]
@@ -921,7 +1131,7 @@ impl GitoxideLoader {
             // Check if we need to sync
             if self.cache.should_sync(&repo_path, &config.cache_ttl).await? {
                 info!("Syncing Git repository with gitoxide: {}", url);
-                self.git_client.sync_repository(&repo_path, config, auth).await?;
+                self.git_client.sync_repository(url, &repo_path, config, auth).await?;
             }
         }
 
@@ -1052,3 +1262,207 @@ impl TaskLoader for GitoxideLoader {
         matches!(source, TaskSource::Git { .. })
     }
 }
+
+#[cfg(all(test, feature = "git"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_depth() {
+        let config = GitConfig::default();
+        assert_eq!(clone_depth(&config), std::num::NonZeroU32::new(1));
+
+        let deeper = GitConfig {
+            depth: Some(10),
+            ..GitConfig::default()
+        };
+        assert_eq!(clone_depth(&deeper), std::num::NonZeroU32::new(10));
+
+        let full = GitConfig {
+            shallow: false,
+            ..GitConfig::default()
+        };
+        assert_eq!(clone_depth(&full), None);
+
+        let history = GitConfig {
+            keep_history: true,
+            ..GitConfig::default()
+        };
+        assert_eq!(clone_depth(&history), None);
+    }
+
+    #[test]
+    fn test_pinned_commit() {
+        let hash = "3f786850e387550fdab836ed7e6dc881de23001b";
+        assert_eq!(pinned_commit(Some(hash)), gix::ObjectId::from_hex(hash.as_bytes()).ok());
+        assert_eq!(pinned_commit(Some("main")), None);
+        assert_eq!(pinned_commit(Some("3f78685")), None);
+        assert_eq!(pinned_commit(None), None);
+
+        // A pinned commit may be older than any shallow clone would reach
+        let pinned = GitConfig {
+            branch: Some(hash.to_string()),
+            ..GitConfig::default()
+        };
+        assert_eq!(clone_depth(&pinned), None);
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("/keys/id_ed25519"), "'/keys/id_ed25519'");
+        assert_eq!(shell_quote("/my keys/it's"), r"'/my keys/it'\''s'");
+    }
+
+    #[test]
+    fn test_sibling_path() {
+        let repo_path = Path::new("/cache/git-repos/tasks_abc123");
+        assert_eq!(
+            sibling_path(repo_path, "sync"),
+            PathBuf::from("/cache/git-repos/tasks_abc123.sync")
+        );
+    }
+
+    /// Run `git` in `dir`, returning its trimmed output
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .args(["-c", "user.name=Ratchet", "-c", "user.email=ratchet@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("git should be installed");
+        assert!(output.status.success(), "git {:?} failed: {:?}", args, output);
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    /// Commit a task called `name` whose `main.js` is `script`, returning the commit hash
+    fn commit_task(origin: &Path, name: &str, script: &str) -> String {
+        let task_dir = origin.join("tasks").join(name);
+        std::fs::create_dir_all(&task_dir).unwrap();
+        std::fs::write(
+            task_dir.join("metadata.json"),
+            format!(r#"{{"name": "{}", "version": "1.0.0"}}"#, name),
+        )
+        .unwrap();
+        std::fs::write(task_dir.join("main.js"), script).unwrap();
+        git(origin, &["add", "-A"]);
+        git(origin, &["commit", "-m", name]);
+        git(origin, &["rev-parse", "HEAD"])
+    }
+
+    /// Repository with one task, and a Git source cloning it over file://
+    fn fixture(config: GitConfig) -> (tempfile::TempDir, String, TaskSource) {
+        let dir = tempfile::tempdir().unwrap();
+        let origin = dir.path().join("origin");
+        std::fs::create_dir(&origin).unwrap();
+        git(&origin, &["init", "-b", "main"]);
+        let first = commit_task(&origin, "weather", "function main() { return 1; }");
+
+        let source = TaskSource::Git {
+            url: format!("file://{}", origin.display()),
+            auth: None,
+            config: GitConfig {
+                cache_ttl: std::time::Duration::ZERO,
+                ..config
+            },
+        };
+        (dir, first, source)
+    }
+
+    async fn discovered_commits(loader: &GitLoader, source: &TaskSource) -> Vec<(String, Option<String>)> {
+        let mut tasks: Vec<_> = loader
+            .discover_tasks(source)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| (task.metadata.name, task.metadata.commit))
+            .collect();
+        tasks.sort();
+        tasks
+    }
+
+    #[tokio::test]
+    async fn test_sync_fast_forwards_full_clone() {
+        let (dir, first, source) = fixture(GitConfig {
+            shallow: false,
+            ..GitConfig::default()
+        });
+        let origin = dir.path().join("origin");
+        let loader = GitLoader::with_cache_path(dir.path().join("cache"));
+
+        assert_eq!(
+            discovered_commits(&loader, &source).await,
+            vec![("weather".to_string(), Some(first))]
+        );
+        let repo_path = loader.get_repository_path(&source).await.unwrap();
+        std::fs::write(repo_path.join(".git").join("marker"), "").unwrap();
+
+        // The next sync fetches the new commit instead of cloning again
+        let second = commit_task(&origin, "traffic", "function main() { return 2; }");
+        std::fs::remove_dir_all(origin.join("tasks").join("weather")).unwrap();
+        git(&origin, &["commit", "-am", "remove weather"]);
+        let third = git(&origin, &["rev-parse", "HEAD"]);
+        assert_ne!(second, third);
+
+        assert_eq!(
+            discovered_commits(&loader, &source).await,
+            vec![("traffic".to_string(), Some(third))]
+        );
+        assert!(repo_path.join(".git").join("marker").exists());
+        assert!(!repo_path.join("tasks").join("weather").exists());
+        assert!(!sibling_path(&repo_path, "sync").exists());
+        assert!(!sibling_path(&repo_path, "previous").exists());
+    }
+
+    #[tokio::test]
+    async fn test_sync_replaces_shallow_clone() {
+        let (dir, first, source) = fixture(GitConfig::default());
+        let origin = dir.path().join("origin");
+        let loader = GitLoader::with_cache_path(dir.path().join("cache"));
+
+        assert_eq!(
+            discovered_commits(&loader, &source).await,
+            vec![("weather".to_string(), Some(first))]
+        );
+        let repo_path = loader.get_repository_path(&source).await.unwrap();
+        std::fs::write(repo_path.join(".git").join("marker"), "").unwrap();
+
+        let second = commit_task(&origin, "traffic", "function main() { return 2; }");
+        assert_eq!(
+            discovered_commits(&loader, &source).await,
+            vec![
+                ("traffic".to_string(), Some(second.clone())),
+                ("weather".to_string(), Some(second))
+            ]
+        );
+
+        // The clone was swapped for a fresh one and the staging copies cleaned up
+        assert!(!repo_path.join(".git").join("marker").exists());
+        assert!(!sibling_path(&repo_path, "sync").exists());
+        assert!(!sibling_path(&repo_path, "previous").exists());
+    }
+
+    #[tokio::test]
+    async fn test_pinned_commit_is_checked_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let origin = dir.path().join("origin");
+        std::fs::create_dir(&origin).unwrap();
+        git(&origin, &["init", "-b", "main"]);
+        let first = commit_task(&origin, "weather", "function main() { return 1; }");
+        commit_task(&origin, "traffic", "function main() { return 2; }");
+
+        let source = TaskSource::Git {
+            url: format!("file://{}", origin.display()),
+            auth: None,
+            config: GitConfig {
+                branch: Some(first.clone()),
+                ..GitConfig::default()
+            },
+        };
+        let loader = GitLoader::with_cache_path(dir.path().join("cache"));
+
+        assert_eq!(
+            discovered_commits(&loader, &source).await,
+            vec![("weather".to_string(), Some(first))]
+        );
+    }
+}
@@ -1,14 +1,62 @@
+//! HTTP registry loader
+//!
+//! Tasks are listed from the registry's `GET {url}/tasks` endpoint, one page at
+//! a time: `?page=1&limit=100`, plus a `tag` parameter per configured tag and a
+//! `category` parameter when one is configured. A registry that supports
+//! pagination answers with an envelope describing the page:
+//!
+//! ```json
+//! {
+//!   "tasks": [{ "name": "weather", "version": "1.0.0", "tags": ["api"] }],
+//!   "pagination": { "page": 1, "limit": 100, "total": 250, "has_more": true }
+//! }
+//! ```
+//!
+//! A registry that ignores the query and answers with a plain array of tasks, or
+//! with an envelope without `pagination`, is taken to have returned its full
+//! list. Filters are also applied to every response, so registries that ignore
+//! them still only yield matching tasks.
+//!
+//! A listed task is loaded from `GET {url}/tasks/{name}/{version}`, which
+//! answers with the task entry plus its code and schemas:
+//!
+//! ```json
+//! {
+//!   "name": "weather",
+//!   "version": "1.0.0",
+//!   "script": "(input) => fetch(input.city)",
+//!   "input_schema": { "type": "object" },
+//!   "output_schema": { "type": "object" }
+//! }
+//! ```
+//!
+//! Tasks the registry gives no `uuid` get one derived from the registry URL
+//! and the task name, so they keep it across discoveries and versions.
+
 use async_trait::async_trait;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::warn;
+use tracing::{debug, info, warn};
+use url::Url;
+use uuid::Uuid;
+
+use ratchet_http::HttpClient;
 
-use crate::config::TaskSource;
+use crate::config::{HttpAuth, HttpAuthType, HttpDiscoveryConfig, TaskSource};
 use crate::error::{RegistryError, Result};
 use crate::loaders::TaskLoader;
-use crate::types::{DiscoveredTask, TaskDefinition, TaskReference};
+use crate::types::{DiscoveredTask, TaskDefinition, TaskMetadata, TaskReference};
+
+/// Upper bound on pages requested from one registry, guarding against a registry that never ends
+const MAX_PAGES: u32 = 10_000;
 
 pub struct HttpLoader {
     client: Arc<ratchet_http::HttpManager>,
+    /// Credentials of the configured registries, by registry URL
+    credentials: Vec<(String, HttpAuth)>,
 }
 
 impl Default for HttpLoader {
@@ -19,13 +67,93 @@ impl Default for HttpLoader {
 
 impl HttpLoader {
     pub fn new() -> Self {
+        Self::with_client(Arc::new(ratchet_http::HttpManager::new()))
+    }
+
+    pub fn with_client(client: Arc<ratchet_http::HttpManager>) -> Self {
         Self {
-            client: Arc::new(ratchet_http::HttpManager::new()),
+            client,
+            credentials: Vec::new(),
         }
     }
 
-    pub fn with_client(client: Arc<ratchet_http::HttpManager>) -> Self {
-        Self { client }
+    /// Authenticate task loads with the credentials of the HTTP sources they come from
+    pub fn with_source_credentials(mut self, sources: &[TaskSource]) -> Self {
+        self.credentials = sources
+            .iter()
+            .filter_map(|source| match source {
+                TaskSource::Http {
+                    url, auth: Some(auth), ..
+                } => Some((url.trim_end_matches('/').to_string(), auth.clone())),
+                _ => None,
+            })
+            .collect();
+        self
+    }
+
+    /// Credentials of the registry serving `task_url`, preferring the most specific registry URL
+    fn credentials_for(&self, task_url: &str) -> Option<&HttpAuth> {
+        self.credentials
+            .iter()
+            .filter(|(url, _)| task_url.starts_with(&format!("{}/", url)))
+            .max_by_key(|(url, _)| url.len())
+            .map(|(_, auth)| auth)
+    }
+
+    async fn list_tasks(
+        &self,
+        base_url: &str,
+        auth: Option<&HttpAuth>,
+        discovery: &HttpDiscoveryConfig,
+    ) -> Result<Vec<DiscoveredTask>> {
+        let params = request_params(auth);
+        let mut discovered = Vec::new();
+
+        for page in 1..=MAX_PAGES {
+            let url = task_list_url(base_url, page, discovery)?;
+            debug!("Fetching task list page {} from {}", page, url);
+
+            let body = self.get_json(&url, &params).await?;
+            let (tasks, pagination) = match serde_json::from_value(body)? {
+                TaskListResponse::Paged { tasks, pagination } => (tasks, pagination),
+                TaskListResponse::Full(tasks) => (tasks, None),
+            };
+
+            let received = tasks.len();
+            discovered.extend(
+                tasks
+                    .into_iter()
+                    .filter(|task| task.matches(discovery))
+                    .map(|task| task.into_discovered(base_url)),
+            );
+
+            let Some(pagination) = pagination else {
+                if page == 1 {
+                    info!("Registry {} does not paginate; using its full task list", base_url);
+                }
+                return Ok(discovered);
+            };
+
+            if received == 0 || !pagination.has_more(page, discovery.page_size, received) {
+                return Ok(discovered);
+            }
+        }
+
+        warn!("Stopped listing tasks from {} after {} pages", base_url, MAX_PAGES);
+        Ok(discovered)
+    }
+
+    async fn get_json(&self, url: &str, params: &serde_json::Value) -> Result<serde_json::Value> {
+        let response = self.client.call_http(url, Some(params), None).await?;
+
+        if !response["ok"].as_bool().unwrap_or(false) {
+            return Err(RegistryError::LoadError(format!(
+                "Registry request to {} failed with status {}",
+                url, response["status"]
+            )));
+        }
+
+        Ok(response["body"].clone())
     }
 }
 
@@ -33,17 +161,9 @@ impl HttpLoader {
 impl TaskLoader for HttpLoader {
     async fn discover_tasks(&self, source: &TaskSource) -> Result<Vec<DiscoveredTask>> {
         match source {
-            TaskSource::Http { url, .. } => {
-                warn!("HTTP task discovery not yet implemented for URL: {}", url);
-                // TODO: Implement HTTP discovery
-                // This would typically:
-                // 1. GET /tasks endpoint to list available tasks
-                // 2. Parse response to extract task metadata
-                // 3. Convert to DiscoveredTask structs
-                Err(RegistryError::NotImplemented(
-                    "HTTP task discovery is not yet implemented".to_string(),
-                ))
-            }
+            TaskSource::Http {
+                url, auth, discovery, ..
+            } => self.list_tasks(url, auth.as_ref(), discovery).await,
             _ => Err(RegistryError::Configuration(
                 "HttpLoader only supports HTTP sources".to_string(),
             )),
@@ -57,21 +177,402 @@ impl TaskLoader for HttpLoader {
             ));
         }
 
-        warn!("HTTP task loading not yet implemented for: {}", task_ref.source);
+        let suffix = format!("/tasks/{}/{}", task_ref.name, task_ref.version);
+        let base_url = task_ref.source.strip_suffix(&suffix).ok_or_else(|| {
+            RegistryError::Configuration(format!(
+                "{} is not the task endpoint of {} v{}",
+                task_ref.source, task_ref.name, task_ref.version
+            ))
+        })?;
 
-        // TODO: Implement HTTP loading
-        // This would typically:
-        // 1. GET the task endpoint (e.g., /tasks/{name}/{version})
-        // 2. Download and parse the task definition
-        // 3. Handle different content types (JSON, ZIP, etc.)
-        // 4. Apply authentication if configured
+        debug!(
+            "Loading task {} v{} from {}",
+            task_ref.name, task_ref.version, task_ref.source
+        );
+        let params = request_params(self.credentials_for(&task_ref.source));
+        let body = self.get_json(&task_ref.source, &params).await?;
+        let remote: RemoteTaskDefinition = serde_json::from_value(body)?;
 
-        Err(RegistryError::NotImplemented(
-            "HTTP task loading is not yet implemented".to_string(),
-        ))
+        if remote.task.name != task_ref.name || remote.task.version != task_ref.version {
+            return Err(RegistryError::LoadError(format!(
+                "Registry returned task {} v{} for {}",
+                remote.task.name, remote.task.version, task_ref.source
+            )));
+        }
+
+        Ok(remote.into_definition(base_url))
     }
 
     async fn supports_source(&self, source: &TaskSource) -> bool {
         matches!(source, TaskSource::Http { .. })
     }
 }
+
+/// Task list endpoint for one page, carrying the configured filters
+fn task_list_url(base_url: &str, page: u32, discovery: &HttpDiscoveryConfig) -> Result<String> {
+    let mut url = Url::parse(&format!("{}/tasks", base_url.trim_end_matches('/')))
+        .map_err(|e| RegistryError::Configuration(format!("Invalid registry URL {}: {}", base_url, e)))?;
+
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("page", &page.to_string());
+        query.append_pair("limit", &discovery.page_size.to_string());
+        for tag in &discovery.tags {
+            query.append_pair("tag", tag);
+        }
+        if let Some(category) = &discovery.category {
+            query.append_pair("category", category);
+        }
+    }
+
+    Ok(url.to_string())
+}
+
+/// Fetch parameters for a GET request with the source's credentials
+fn request_params(auth: Option<&HttpAuth>) -> serde_json::Value {
+    let mut headers = serde_json::Map::new();
+    headers.insert("Accept".to_string(), "application/json".into());
+
+    match auth.map(|auth| &auth.auth_type) {
+        Some(HttpAuthType::Bearer { token }) => {
+            headers.insert("Authorization".to_string(), format!("Bearer {}", token).into());
+        }
+        Some(HttpAuthType::Basic { username, password }) => {
+            let credentials = base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", username, password));
+            headers.insert("Authorization".to_string(), format!("Basic {}", credentials).into());
+        }
+        Some(HttpAuthType::ApiKey { header_name, api_key }) => {
+            headers.insert(header_name.clone(), api_key.clone().into());
+        }
+        None => {}
+    }
+
+    serde_json::json!({ "method": "GET", "headers": headers })
+}
+
+/// Body of the task list endpoint
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TaskListResponse {
+    Paged {
+        tasks: Vec<RemoteTask>,
+        #[serde(default)]
+        pagination: Option<Pagination>,
+    },
+    Full(Vec<RemoteTask>),
+}
+
+/// Page description returned by registries that support pagination
+#[derive(Debug, Deserialize)]
+struct Pagination {
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    total: Option<u64>,
+    #[serde(default)]
+    has_more: Option<bool>,
+}
+
+impl Pagination {
+    /// Whether pages follow `page`, judged by the registry's own answer when it gives one
+    fn has_more(&self, page: u32, requested_limit: u32, received: usize) -> bool {
+        let limit = self.limit.unwrap_or(requested_limit);
+        match (self.has_more, self.total) {
+            (Some(has_more), _) => has_more,
+            (None, Some(total)) => u64::from(page) * u64::from(limit) < total,
+            (None, None) => received >= limit as usize,
+        }
+    }
+}
+
+/// Identity of tasks the registry gives no UUID, stable for the registry and task name
+fn stable_task_uuid(base_url: &str, name: &str) -> Uuid {
+    let task_url = format!("{}/tasks/{}", base_url.trim_end_matches('/'), name);
+    Uuid::new_v5(&Uuid::NAMESPACE_URL, task_url.as_bytes())
+}
+
+/// Task entry in the task list
+#[derive(Debug, Deserialize)]
+struct RemoteTask {
+    name: String,
+    version: String,
+    #[serde(default)]
+    uuid: Option<Uuid>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    checksum: Option<String>,
+}
+
+impl RemoteTask {
+    fn matches(&self, discovery: &HttpDiscoveryConfig) -> bool {
+        discovery.tags.iter().all(|tag| self.tags.contains(tag))
+            && discovery
+                .category
+                .as_ref()
+                .is_none_or(|category| self.category.as_ref() == Some(category))
+    }
+
+    fn into_discovered(self, base_url: &str) -> DiscoveredTask {
+        let (task_ref, metadata) = self.into_parts(base_url);
+        DiscoveredTask {
+            task_ref,
+            metadata,
+            discovered_at: Utc::now(),
+        }
+    }
+
+    fn into_parts(self, base_url: &str) -> (TaskReference, TaskMetadata) {
+        let now = Utc::now();
+        let task_ref = TaskReference {
+            name: self.name.clone(),
+            version: self.version.clone(),
+            source: format!(
+                "{}/tasks/{}/{}",
+                base_url.trim_end_matches('/'),
+                self.name,
+                self.version
+            ),
+        };
+
+        let metadata = TaskMetadata {
+            uuid: self.uuid.unwrap_or_else(|| stable_task_uuid(base_url, &self.name)),
+            name: self.name,
+            version: self.version,
+            description: self.description,
+            tags: self.tags,
+            created_at: self.created_at.unwrap_or(now),
+            updated_at: self.updated_at.unwrap_or(now),
+            checksum: self.checksum,
+            commit: None,
+        };
+
+        (task_ref, metadata)
+    }
+}
+
+/// Body of the task endpoint
+#[derive(Debug, Deserialize)]
+struct RemoteTaskDefinition {
+    #[serde(flatten)]
+    task: RemoteTask,
+    #[serde(alias = "source_code")]
+    script: String,
+    #[serde(default)]
+    input_schema: Option<serde_json::Value>,
+    #[serde(default)]
+    output_schema: Option<serde_json::Value>,
+    #[serde(default)]
+    dependencies: Vec<String>,
+    #[serde(default)]
+    environment: HashMap<String, String>,
+}
+
+impl RemoteTaskDefinition {
+    fn into_definition(self, base_url: &str) -> TaskDefinition {
+        let (reference, metadata) = self.task.into_parts(base_url);
+        TaskDefinition {
+            reference,
+            metadata,
+            script: self.script,
+            input_schema: self.input_schema,
+            output_schema: self.output_schema,
+            dependencies: self.dependencies,
+            environment: self.environment,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratchet_http::{HttpManager, HttpMethod, MockResponse, RequestMatcher};
+    use serde_json::json;
+    use std::time::Duration;
+
+    const REGISTRY: &str = "https://registry.example.com";
+
+    fn source(discovery: HttpDiscoveryConfig) -> TaskSource {
+        TaskSource::Http {
+            url: REGISTRY.to_string(),
+            auth: None,
+            polling_interval: Duration::from_secs(300),
+            discovery,
+        }
+    }
+
+    fn loader(responses: Vec<(&str, serde_json::Value)>) -> HttpLoader {
+        let mut client = HttpManager::new();
+        client.set_offline();
+        for (url, body) in responses {
+            client.register_mock(RequestMatcher::exact(HttpMethod::Get, url), MockResponse::json(body));
+        }
+        HttpLoader::with_client(Arc::new(client))
+    }
+
+    fn names(discovered: &[DiscoveredTask]) -> Vec<&str> {
+        discovered.iter().map(|task| task.metadata.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_discovers_every_page() {
+        let loader = loader(vec![
+            (
+                "https://registry.example.com/tasks?page=1&limit=2",
+                json!({
+                    "tasks": [{"name": "a", "version": "1.0.0"}, {"name": "b", "version": "1.0.0"}],
+                    "pagination": {"page": 1, "limit": 2, "total": 3}
+                }),
+            ),
+            (
+                "https://registry.example.com/tasks?page=2&limit=2",
+                json!({
+                    "tasks": [{"name": "c", "version": "2.0.0"}],
+                    "pagination": {"page": 2, "limit": 2, "total": 3}
+                }),
+            ),
+        ]);
+        let discovery = HttpDiscoveryConfig {
+            page_size: 2,
+            ..HttpDiscoveryConfig::default()
+        };
+
+        let discovered = loader.discover_tasks(&source(discovery)).await.unwrap();
+        assert_eq!(names(&discovered), vec!["a", "b", "c"]);
+        assert_eq!(
+            discovered[2].task_ref.source,
+            "https://registry.example.com/tasks/c/2.0.0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_full_list() {
+        let loader = loader(vec![(
+            "https://registry.example.com/tasks?page=1&limit=100&tag=api&category=weather",
+            json!([
+                {"name": "forecast", "version": "1.0.0", "tags": ["api"], "category": "weather"},
+                {"name": "radar", "version": "1.0.0", "tags": ["images"], "category": "weather"},
+                {"name": "stocks", "version": "1.0.0", "tags": ["api"], "category": "finance"}
+            ]),
+        )]);
+        let discovery = HttpDiscoveryConfig {
+            tags: vec!["api".to_string()],
+            category: Some("weather".to_string()),
+            ..HttpDiscoveryConfig::default()
+        };
+
+        let discovered = loader.discover_tasks(&source(discovery)).await.unwrap();
+        assert_eq!(names(&discovered), vec!["forecast"]);
+    }
+
+    #[tokio::test]
+    async fn test_tasks_without_uuid_keep_their_identity() {
+        let list = json!([{"name": "forecast", "version": "1.0.0"}]);
+        let url = "https://registry.example.com/tasks?page=1&limit=100";
+        let source = source(HttpDiscoveryConfig::default());
+
+        let first = loader(vec![(url, list.clone())]).discover_tasks(&source).await.unwrap();
+        let second = loader(vec![(url, list)]).discover_tasks(&source).await.unwrap();
+        assert_eq!(first[0].metadata.uuid, second[0].metadata.uuid);
+        assert_ne!(
+            first[0].metadata.uuid,
+            stable_task_uuid("https://other.example.com", "forecast")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_loads_task_definition() {
+        let loader = loader(vec![
+            (
+                "https://registry.example.com/tasks?page=1&limit=100",
+                json!([{"name": "forecast", "version": "1.0.0"}]),
+            ),
+            (
+                "https://registry.example.com/tasks/forecast/1.0.0",
+                json!({
+                    "name": "forecast",
+                    "version": "1.0.0",
+                    "script": "(input) => input.city",
+                    "input_schema": {"type": "object"}
+                }),
+            ),
+        ]);
+
+        let discovered = loader
+            .discover_tasks(&source(HttpDiscoveryConfig::default()))
+            .await
+            .unwrap();
+        let task = loader.load_task(&discovered[0].task_ref).await.unwrap();
+
+        assert_eq!(task.script, "(input) => input.city");
+        assert_eq!(task.input_schema, Some(json!({"type": "object"})));
+        assert_eq!(task.reference, discovered[0].task_ref);
+        assert_eq!(task.metadata.uuid, discovered[0].metadata.uuid);
+    }
+
+    #[test]
+    fn test_credentials_follow_the_task_registry() {
+        let bearer = |token: &str| HttpAuth {
+            auth_type: HttpAuthType::Bearer {
+                token: token.to_string(),
+            },
+        };
+        let sources = vec![
+            TaskSource::Http {
+                url: REGISTRY.to_string(),
+                auth: Some(bearer("outer")),
+                polling_interval: Duration::from_secs(300),
+                discovery: HttpDiscoveryConfig::default(),
+            },
+            TaskSource::Http {
+                url: "https://registry.example.com/team/".to_string(),
+                auth: Some(bearer("team")),
+                polling_interval: Duration::from_secs(300),
+                discovery: HttpDiscoveryConfig::default(),
+            },
+        ];
+        let loader = HttpLoader::new().with_source_credentials(&sources);
+        let token = |url: &str| match loader.credentials_for(url).map(|auth| &auth.auth_type) {
+            Some(HttpAuthType::Bearer { token }) => Some(token.as_str()),
+            _ => None,
+        };
+
+        assert_eq!(token("https://registry.example.com/tasks/a/1.0.0"), Some("outer"));
+        assert_eq!(token("https://registry.example.com/team/tasks/a/1.0.0"), Some("team"));
+        assert_eq!(token("https://registry.example.com.evil/tasks/a/1.0.0"), None);
+    }
+
+    #[test]
+    fn test_pagination_has_more() {
+        let explicit = Pagination {
+            limit: None,
+            total: Some(1_000),
+            has_more: Some(false),
+        };
+        assert!(!explicit.has_more(1, 100, 100));
+
+        let counted = Pagination {
+            limit: Some(50),
+            total: Some(120),
+            has_more: None,
+        };
+        assert!(counted.has_more(2, 100, 50));
+        assert!(!counted.has_more(3, 100, 20));
+
+        let unknown = Pagination {
+            limit: None,
+            total: None,
+            has_more: None,
+        };
+        assert!(unknown.has_more(1, 100, 100));
+        assert!(!unknown.has_more(2, 100, 40));
+    }
+}
@@ -5,14 +5,48 @@ pub mod http;
 pub mod validation;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use std::path::Path;
 
 use crate::config::TaskSource;
 use crate::error::Result;
 use crate::types::{DiscoveredTask, TaskDefinition, TaskReference};
 
+/// Files of a task directory whose changes make a new revision of the task
+const TASK_FILES: [&str; 4] = ["metadata.json", "main.js", "input.schema.json", "output.schema.json"];
+
 #[async_trait]
 pub trait TaskLoader: Send + Sync {
     async fn discover_tasks(&self, source: &TaskSource) -> Result<Vec<DiscoveredTask>>;
     async fn load_task(&self, task_ref: &TaskReference) -> Result<TaskDefinition>;
     async fn supports_source(&self, source: &TaskSource) -> bool;
 }
+
+/// Checksum of a task's code, as stored with tasks synced to the database
+pub fn script_checksum(script: &str) -> String {
+    format!("{:x}", Sha256::digest(script.as_bytes()))
+}
+
+/// Checksum of the code of the task directory at `path`, if it has a `main.js`
+pub(crate) async fn task_directory_checksum(path: &Path) -> Option<String> {
+    tokio::fs::read_to_string(path.join("main.js"))
+        .await
+        .ok()
+        .map(|script| script_checksum(&script))
+}
+
+/// When the files of the task directory at `path` were last modified
+///
+/// Unlike the time a task is loaded, this only moves when the task changes.
+pub(crate) async fn task_directory_modified_at(path: &Path) -> Result<DateTime<Utc>> {
+    let mut modified_at = None;
+    for file in TASK_FILES {
+        let Ok(metadata) = tokio::fs::metadata(path.join(file)).await else {
+            continue;
+        };
+        let file_modified_at = DateTime::<Utc>::from(metadata.modified()?);
+        modified_at = modified_at.max(Some(file_modified_at));
+    }
+    Ok(modified_at.unwrap_or_else(Utc::now))
+}
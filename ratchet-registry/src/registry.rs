@@ -118,6 +118,84 @@ impl DefaultTaskRegistry {
         }
     }
 
+    /// Add `task`, replacing every task previously loaded from the same source
+    ///
+    /// Returns the tasks that were replaced. Used to apply a reloaded task so
+    /// stale versions of it are no longer served.
+    pub async fn replace_task(&self, task: TaskDefinition) -> Result<Vec<Arc<TaskDefinition>>> {
+        let mut tasks = self.tasks.write().await;
+        let replaced = Self::remove_matching(&mut tasks, |existing| {
+            existing.reference.source == task.reference.source
+        });
+
+        info!(
+            "Replacing task {} version {} in registry ({} previous entries)",
+            task.metadata.uuid,
+            task.metadata.version,
+            replaced.len()
+        );
+        tasks
+            .entry(task.metadata.uuid)
+            .or_insert_with(HashMap::new)
+            .insert(task.metadata.version.clone(), Arc::new(task));
+
+        Ok(replaced)
+    }
+
+    /// Remove the task version identified by `task_ref`, returning it if it was present
+    pub async fn remove_task_reference(&self, task_ref: &TaskReference) -> Result<Option<Arc<TaskDefinition>>> {
+        let mut tasks = self.tasks.write().await;
+        let removed = Self::remove_matching(&mut tasks, |existing| existing.reference == *task_ref);
+
+        if removed.is_empty() {
+            warn!(
+                "Task {} version {} not found in registry",
+                task_ref.name, task_ref.version
+            );
+        } else {
+            info!(
+                "Removed task {} version {} from registry",
+                task_ref.name, task_ref.version
+            );
+        }
+
+        Ok(removed.into_iter().next())
+    }
+
+    /// Tasks loaded from `source` or from a location below it, in every version
+    pub async fn tasks_from_source(&self, source: &str) -> Vec<Arc<TaskDefinition>> {
+        let nested_prefix = format!("{}/", source.trim_end_matches('/'));
+        let tasks = self.tasks.read().await;
+
+        tasks
+            .values()
+            .flat_map(|version_map| version_map.values())
+            .filter(|task| task.reference.source == source || task.reference.source.starts_with(&nested_prefix))
+            .cloned()
+            .collect()
+    }
+
+    fn remove_matching(
+        tasks: &mut HashMap<Uuid, HashMap<String, Arc<TaskDefinition>>>,
+        matches: impl Fn(&TaskDefinition) -> bool,
+    ) -> Vec<Arc<TaskDefinition>> {
+        let mut removed = Vec::new();
+
+        for version_map in tasks.values_mut() {
+            version_map.retain(|_, task| {
+                if matches(task) {
+                    removed.push(task.clone());
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+        tasks.retain(|_, version_map| !version_map.is_empty());
+
+        removed
+    }
+
     pub fn sources(&self) -> &[TaskSource] {
         &self.sources
     }
@@ -175,3 +253,105 @@ impl TaskRegistry for DefaultTaskRegistry {
         Ok(rx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TaskMetadata;
+    use chrono::Utc;
+
+    fn task(uuid: Uuid, version: &str, source: &str) -> TaskDefinition {
+        let now = Utc::now();
+        TaskDefinition {
+            reference: TaskReference {
+                name: "greeter".to_string(),
+                version: version.to_string(),
+                source: source.to_string(),
+            },
+            metadata: TaskMetadata {
+                uuid,
+                name: "greeter".to_string(),
+                version: version.to_string(),
+                description: None,
+                tags: Vec::new(),
+                created_at: now,
+                updated_at: now,
+                checksum: None,
+                commit: None,
+            },
+            script: "module.exports = () => ({})".to_string(),
+            input_schema: None,
+            output_schema: None,
+            dependencies: Vec::new(),
+            environment: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replace_task_drops_stale_versions_from_same_source() {
+        let registry = DefaultTaskRegistry::new();
+        let uuid = Uuid::new_v4();
+        registry
+            .add_task(task(uuid, "1.0.0", "file://tasks/greeter"))
+            .await
+            .unwrap();
+        registry
+            .add_task(task(Uuid::new_v4(), "1.0.0", "file://tasks/other"))
+            .await
+            .unwrap();
+
+        let mut updated = task(uuid, "1.1.0", "file://tasks/greeter");
+        updated.script = "module.exports = () => ({ updated: true })".to_string();
+        let replaced = registry.replace_task(updated).await.unwrap();
+
+        assert_eq!(replaced.len(), 1);
+        assert_eq!(replaced[0].metadata.version, "1.0.0");
+        assert_eq!(registry.list_versions(uuid).await.unwrap(), vec!["1.1.0".to_string()]);
+        assert_eq!(registry.list_tasks().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_replace_task_overwrites_same_version() {
+        let registry = DefaultTaskRegistry::new();
+        let uuid = Uuid::new_v4();
+        registry
+            .add_task(task(uuid, "1.0.0", "file://tasks/greeter"))
+            .await
+            .unwrap();
+
+        let mut edited = task(uuid, "1.0.0", "file://tasks/greeter");
+        edited.script = "module.exports = () => ({ edited: true })".to_string();
+        registry.replace_task(edited).await.unwrap();
+
+        let current = registry.get_task(uuid, Some("1.0.0")).await.unwrap().unwrap();
+        assert!(current.script.contains("edited"));
+    }
+
+    #[tokio::test]
+    async fn test_tasks_from_source_and_removal() {
+        let registry = DefaultTaskRegistry::new();
+        registry
+            .add_task(task(Uuid::new_v4(), "1.0.0", "file://tasks/greeter"))
+            .await
+            .unwrap();
+        registry
+            .add_task(task(Uuid::new_v4(), "1.0.0", "file://tasks/greeter-v2"))
+            .await
+            .unwrap();
+        registry
+            .add_task(task(Uuid::new_v4(), "1.0.0", "file://tasks/nested/inner"))
+            .await
+            .unwrap();
+
+        assert_eq!(registry.tasks_from_source("file://tasks/greeter").await.len(), 1);
+        assert_eq!(registry.tasks_from_source("file://tasks/nested").await.len(), 1);
+        assert_eq!(registry.tasks_from_source("file://tasks").await.len(), 3);
+
+        let task_ref = registry.tasks_from_source("file://tasks/greeter").await[0]
+            .reference
+            .clone();
+        assert!(registry.remove_task_reference(&task_ref).await.unwrap().is_some());
+        assert!(registry.tasks_from_source("file://tasks/greeter").await.is_empty());
+        assert!(registry.remove_task_reference(&task_ref).await.unwrap().is_none());
+    }
+}
@@ -1,14 +1,14 @@
 use async_trait::async_trait;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{error, info};
 
-use crate::config::{RegistryConfig, TaskSource};
+use crate::config::{RegistryConfig, TaskSource, WatcherConfig};
 use crate::error::{RegistryError, Result};
 use crate::loaders::{filesystem::FilesystemLoader, git::GitLoader, http::HttpLoader, TaskLoader};
 use crate::registry::DefaultTaskRegistry;
 use crate::sync::DatabaseSync;
-use crate::types::{DiscoveredTask, SyncResult, TaskDefinition, TaskReference};
+use crate::types::{DiscoveredTask, RegistryEvent, SyncResult, TaskDefinition, TaskReference};
 use crate::watcher::RegistryWatcher;
 
 #[async_trait]
@@ -27,29 +27,59 @@ pub struct DefaultRegistryService {
     http_loader: HttpLoader,
     git_loader: GitLoader,
     sync_service: Option<Arc<DatabaseSync>>,
-    watcher: Option<Arc<RwLock<RegistryWatcher>>>,
+    watcher: RwLock<Option<RegistryWatcher>>,
+    watcher_config: WatcherConfig,
+    events: broadcast::Sender<RegistryEvent>,
     config: RegistryConfig,
 }
 
+/// Registry events buffered per subscriber before the oldest are dropped
+const REGISTRY_EVENT_CAPACITY: usize = 256;
+
 impl DefaultRegistryService {
     pub fn new(config: RegistryConfig) -> Self {
         let sources = config.sources.clone();
+        let (events, _) = broadcast::channel(REGISTRY_EVENT_CAPACITY);
         Self {
             registry: Arc::new(DefaultTaskRegistry::with_sources(sources)),
             filesystem_loader: FilesystemLoader::new(),
-            http_loader: HttpLoader::new(),
+            http_loader: HttpLoader::new().with_source_credentials(&config.sources),
             git_loader: GitLoader::new(),
             sync_service: None,
-            watcher: None,
+            watcher: RwLock::new(None),
+            watcher_config: WatcherConfig {
+                enabled: true,
+                ..WatcherConfig::default()
+            },
+            events,
             config,
         }
     }
 
+    /// Set how watched filesystem sources are debounced and reloaded
+    pub fn with_watcher_config(mut self, watcher_config: WatcherConfig) -> Self {
+        self.watcher_config = watcher_config;
+        self
+    }
+
+    /// Subscribe to changes of watched tasks
+    ///
+    /// Events are only produced after [`RegistryService::start_watching`]; they
+    /// report changes on disk and are not yet applied to the registry.
+    pub fn subscribe(&self) -> broadcast::Receiver<RegistryEvent> {
+        self.events.subscribe()
+    }
+
     pub fn with_sync_service(mut self, sync_service: Arc<DatabaseSync>) -> Self {
         self.sync_service = Some(sync_service);
         self
     }
 
+    /// Whether tasks are synced to a database
+    pub fn has_sync_service(&self) -> bool {
+        self.sync_service.is_some()
+    }
+
     async fn discover_from_source(&self, source: &TaskSource) -> Result<Vec<DiscoveredTask>> {
         info!("Discovering tasks from source: {:?}", source);
 
@@ -63,26 +93,50 @@ impl DefaultRegistryService {
         Ok(discovered)
     }
 
-    async fn load_discovered_tasks(&self, discovered: Vec<DiscoveredTask>) -> Result<()> {
+    /// Load the definitions of discovered tasks into the registry, returning the ones that loaded
+    ///
+    /// A task that fails to load is logged and left out.
+    async fn load_discovered_tasks(&self, discovered: &[DiscoveredTask]) -> Vec<TaskDefinition> {
+        let mut loaded = Vec::with_capacity(discovered.len());
+
         for task in discovered {
             // Load the full task definition
-            let task_def = self.load_task(&task.task_ref).await?;
+            let task_def = match self.load_task(&task.task_ref).await {
+                Ok(task_def) => task_def,
+                Err(e) => {
+                    error!(
+                        "Failed to load task {} from {}: {}",
+                        task.task_ref.name, task.task_ref.source, e
+                    );
+                    continue;
+                }
+            };
 
-            // Add to registry
-            if let Err(e) = self.registry.add_task(task_def.clone()).await {
+            // Add to registry, in place of a copy loaded before
+            if let Err(e) = self.registry.replace_task(task_def.clone()).await {
                 error!("Failed to add task to registry: {}", e);
-            } else {
-                // Auto-sync to database if sync service is available
-                if let Some(sync_service) = &self.sync_service {
-                    let discovered_tasks = vec![task];
-                    if let Err(e) = sync_service.sync_discovered_tasks(discovered_tasks).await {
-                        error!("Failed to sync task to database: {}", e);
-                    }
-                }
             }
+            loaded.push(task_def);
         }
 
-        Ok(())
+        loaded
+    }
+
+    /// Store a task reloaded from a watched filesystem source in the database
+    ///
+    /// Conflicts are resolved with the strategy of the source the task lives in.
+    pub async fn sync_reloaded_task(&self, task: TaskDefinition) -> Result<SyncResult> {
+        let sync_service = self
+            .sync_service
+            .as_ref()
+            .ok_or_else(|| RegistryError::Configuration("No sync service configured".to_string()))?;
+
+        let strategy = self
+            .config
+            .filesystem_source_of(&task.reference)
+            .map(|source| self.config.conflict_strategy(source))
+            .unwrap_or_default();
+        sync_service.sync_source_definitions(vec![task], strategy).await
     }
 }
 
@@ -125,14 +179,27 @@ impl RegistryService for DefaultRegistryService {
 
     async fn sync_to_database(&self) -> Result<SyncResult> {
         if let Some(sync_service) = &self.sync_service {
-            // Discover all tasks first
-            let discovered = self.discover_all_tasks().await?;
+            let mut sync_result = SyncResult::new();
+
+            // Each source's tasks are synced with the conflict strategy configured for it
+            for source in &self.config.sources {
+                let discovered = match self.discover_from_source(source).await {
+                    Ok(discovered) => discovered,
+                    Err(e) => {
+                        error!("Failed to discover tasks from source {:?}: {}", source, e);
+                        continue;
+                    }
+                };
 
-            // Load and add to registry
-            self.load_discovered_tasks(discovered.clone()).await?;
+                // Load and add to registry
+                let loaded = self.load_discovered_tasks(&discovered).await;
 
-            // Sync to database
-            sync_service.sync_discovered_tasks(discovered).await
+                // Sync to database
+                let strategy = self.config.conflict_strategy(source);
+                sync_result.merge(sync_service.sync_source_definitions(loaded, strategy).await?);
+            }
+
+            Ok(sync_result)
         } else {
             Err(RegistryError::Configuration("No sync service configured".to_string()))
         }
@@ -155,29 +222,28 @@ impl RegistryService for DefaultRegistryService {
             return Ok(());
         }
 
+        let mut current = self.watcher.write().await;
+        if current.is_some() {
+            info!("File system watcher already running");
+            return Ok(());
+        }
+
         // Create and start watcher
-        let mut watcher = RegistryWatcher::new(
-            self.registry.clone(),
-            self.sync_service.clone(),
-            crate::config::WatcherConfig::default(),
-        );
+        let mut watcher = RegistryWatcher::new(self.registry.clone(), self.events.clone(), self.watcher_config.clone());
 
         for (path, recursive) in watch_paths {
             watcher.add_watch_path(path, recursive);
         }
 
         watcher.start().await?;
-
-        // Store watcher reference (this is simplified - in practice you'd want better lifecycle management)
-        // self.watcher = Some(Arc::new(RwLock::new(watcher)));
+        *current = Some(watcher);
 
         info!("File system watcher started");
         Ok(())
     }
 
     async fn stop_watching(&self) -> Result<()> {
-        if let Some(watcher_arc) = &self.watcher {
-            let mut watcher = watcher_arc.write().await;
+        if let Some(mut watcher) = self.watcher.write().await.take() {
             watcher.stop().await?;
         }
         Ok(())
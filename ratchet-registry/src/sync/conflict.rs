@@ -1,28 +1,13 @@
 use tracing::info;
 
+use ratchet_storage::seaorm::entities::tasks;
+
+use crate::config::ConflictResolutionStrategy;
 use crate::sync::database::ConflictResolution;
 use crate::types::DiscoveredTask;
 
-#[derive(Debug, Clone)]
-pub enum ConflictStrategy {
-    /// Always use the registry version
-    PreferRegistry,
-    /// Always use the database version
-    PreferDatabase,
-    /// Use the newer version based on timestamps
-    PreferNewer,
-    /// Attempt to merge changes (advanced)
-    Merge,
-}
-
-impl Default for ConflictStrategy {
-    fn default() -> Self {
-        Self::PreferRegistry
-    }
-}
-
 pub struct ConflictResolver {
-    strategy: ConflictStrategy,
+    strategy: ConflictResolutionStrategy,
 }
 
 impl Default for ConflictResolver {
@@ -34,35 +19,60 @@ impl Default for ConflictResolver {
 impl ConflictResolver {
     pub fn new() -> Self {
         Self {
-            strategy: ConflictStrategy::default(),
+            strategy: ConflictResolutionStrategy::default(),
         }
     }
 
-    pub fn with_strategy(mut self, strategy: ConflictStrategy) -> Self {
+    pub fn with_strategy(mut self, strategy: ConflictResolutionStrategy) -> Self {
         self.strategy = strategy;
         self
     }
 
-    pub fn resolve_conflict(&self, _existing: &(), discovered: &DiscoveredTask) -> ConflictResolution {
-        // TEMPORARILY DISABLED: Legacy entity parameter removed during SeaORM migration
+    pub fn strategy(&self) -> ConflictResolutionStrategy {
+        self.strategy
+    }
+
+    /// Whether the registry copy's code differs from the code last synced to the database
+    ///
+    /// Changes are detected by content: loaders report the time a task is
+    /// loaded, or its files' modification times, which move without the task
+    /// changing. A task discovered without a checksum is taken as unchanged.
+    pub fn registry_changed(existing: &tasks::Model, discovered: &DiscoveredTask) -> bool {
+        discovered
+            .metadata
+            .checksum
+            .as_ref()
+            .is_some_and(|checksum| *checksum != existing.checksum)
+    }
+
+    /// Whether the database copy was modified after it was last synced from the registry
+    pub fn database_changed(existing: &tasks::Model) -> bool {
+        existing.needs_push
+            || existing
+                .last_synced_at
+                .is_none_or(|last_synced_at| existing.updated_at > last_synced_at)
+    }
+
+    /// Decide which copy of a task whose registry and database copies have both changed is kept
+    pub fn resolve_conflict(&self, existing: &tasks::Model, discovered: &DiscoveredTask) -> ConflictResolution {
         match self.strategy {
-            ConflictStrategy::PreferRegistry => {
+            ConflictResolutionStrategy::RegistryWins => {
                 info!(
                     "Conflict resolution: preferring registry version for task {} {}",
                     discovered.metadata.name, discovered.metadata.version
                 );
                 ConflictResolution::UseRegistry
             }
-            ConflictStrategy::PreferDatabase => {
+            ConflictResolutionStrategy::KeepLocal => {
                 info!(
                     "Conflict resolution: preferring database version for task {} {}",
                     discovered.metadata.name, discovered.metadata.version
                 );
                 ConflictResolution::UseDatabase
             }
-            ConflictStrategy::PreferNewer => {
+            ConflictResolutionStrategy::PreferNewer => {
                 // Compare timestamps to determine which is newer
-                if self.is_registry_newer(&(), discovered) {
+                if self.is_registry_newer(existing, discovered) {
                     info!(
                         "Conflict resolution: registry version is newer for task {} {}",
                         discovered.metadata.name, discovered.metadata.version
@@ -76,20 +86,151 @@ impl ConflictResolver {
                     ConflictResolution::UseDatabase
                 }
             }
-            ConflictStrategy::Merge => {
+            ConflictResolutionStrategy::Manual => {
                 info!(
-                    "Conflict resolution: attempting merge for task {} {}",
+                    "Conflict resolution: leaving task {} {} for manual resolution",
                     discovered.metadata.name, discovered.metadata.version
                 );
-                // For now, merging is not implemented, so fall back to registry
-                ConflictResolution::UseRegistry
+                ConflictResolution::Manual
             }
         }
     }
 
-    fn is_registry_newer(&self, _existing: &(), _discovered: &DiscoveredTask) -> bool {
-        // TEMPORARILY DISABLED: Legacy entity parameter removed during SeaORM migration
-        // Always assume registry is newer for now
-        true
+    /// Whether the registry copy was modified after the database copy
+    ///
+    /// Filesystem and Git tasks are dated by their files' modification times.
+    fn is_registry_newer(&self, existing: &tasks::Model, discovered: &DiscoveredTask) -> bool {
+        discovered.metadata.updated_at > existing.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loaders::script_checksum;
+    use crate::types::{TaskMetadata, TaskReference};
+    use chrono::{DateTime, Duration, Utc};
+
+    fn discovered(updated_at: DateTime<Utc>) -> DiscoveredTask {
+        DiscoveredTask {
+            task_ref: TaskReference {
+                name: "greet".to_string(),
+                version: "1.0.0".to_string(),
+                source: "file:///tasks/greet".to_string(),
+            },
+            metadata: TaskMetadata {
+                uuid: uuid::Uuid::new_v4(),
+                name: "greet".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                tags: Vec::new(),
+                created_at: updated_at,
+                updated_at,
+                checksum: None,
+                commit: None,
+            },
+            discovered_at: Utc::now(),
+        }
+    }
+
+    fn synced_task(source_modified_at: DateTime<Utc>, last_synced_at: DateTime<Utc>) -> tasks::Model {
+        tasks::Model {
+            id: 7,
+            uuid: uuid::Uuid::new_v4(),
+            name: "greet".to_string(),
+            description: None,
+            version: "1.0.0".to_string(),
+            path: None,
+            metadata: serde_json::json!({}),
+            input_schema: serde_json::json!({}),
+            output_schema: serde_json::json!({}),
+            enabled: true,
+            source_code: String::new(),
+            source_type: "javascript".to_string(),
+            storage_type: "registry".to_string(),
+            file_path: None,
+            checksum: String::new(),
+            repository_id: 1,
+            repository_path: String::new(),
+            last_synced_at: Some(last_synced_at),
+            sync_status: "synced".to_string(),
+            is_editable: false,
+            created_from: "registry".to_string(),
+            needs_push: false,
+            created_at: source_modified_at,
+            updated_at: source_modified_at,
+            validated_at: None,
+            source_modified_at: Some(source_modified_at),
+        }
+    }
+
+    #[test]
+    fn test_change_detection() {
+        let synced_at = Utc::now() - Duration::hours(1);
+        let modified_at = synced_at - Duration::hours(1);
+        let mut existing = synced_task(modified_at, synced_at);
+        existing.checksum = script_checksum("(input) => input");
+
+        let with_code = |script: &str, updated_at| {
+            let mut task = discovered(updated_at);
+            task.metadata.checksum = Some(script_checksum(script));
+            task
+        };
+
+        // Loading the same code again later is not a change
+        assert!(!ConflictResolver::registry_changed(
+            &existing,
+            &with_code("(input) => input", Utc::now())
+        ));
+        assert!(ConflictResolver::registry_changed(
+            &existing,
+            &with_code("(input) => null", modified_at)
+        ));
+        assert!(!ConflictResolver::registry_changed(&existing, &discovered(Utc::now())));
+        assert!(!ConflictResolver::database_changed(&existing));
+
+        existing.updated_at = Utc::now();
+        assert!(ConflictResolver::database_changed(&existing));
+
+        existing.updated_at = modified_at;
+        existing.needs_push = true;
+        assert!(ConflictResolver::database_changed(&existing));
+    }
+
+    #[test]
+    fn test_resolution_strategies() {
+        let synced_at = Utc::now() - Duration::hours(2);
+        let mut existing = synced_task(synced_at - Duration::hours(1), synced_at);
+        existing.updated_at = Utc::now() - Duration::hours(1);
+
+        let older = discovered(existing.updated_at - Duration::minutes(5));
+        let newer = discovered(Utc::now());
+
+        let resolve = |strategy, discovered: &DiscoveredTask| {
+            ConflictResolver::new()
+                .with_strategy(strategy)
+                .resolve_conflict(&existing, discovered)
+        };
+
+        assert!(matches!(
+            resolve(ConflictResolutionStrategy::RegistryWins, &older),
+            ConflictResolution::UseRegistry
+        ));
+        assert!(matches!(
+            resolve(ConflictResolutionStrategy::KeepLocal, &newer),
+            ConflictResolution::UseDatabase
+        ));
+        assert!(matches!(
+            resolve(ConflictResolutionStrategy::PreferNewer, &newer),
+            ConflictResolution::UseRegistry
+        ));
+        assert!(matches!(
+            resolve(ConflictResolutionStrategy::PreferNewer, &older),
+            ConflictResolution::UseDatabase
+        ));
+        assert!(matches!(
+            resolve(ConflictResolutionStrategy::Manual, &newer),
+            ConflictResolution::Manual
+        ));
     }
 }
@@ -1,11 +1,12 @@
 use std::collections::HashSet;
 use std::sync::Arc;
-use sha2::{Digest, Sha256};
 use tracing::{error, info};
 
+use crate::config::ConflictResolutionStrategy;
 use crate::error::{RegistryError, Result};
+use crate::loaders::script_checksum;
 use crate::sync::ConflictResolver;
-use crate::types::{DiscoveredTask, SyncError, SyncResult, TaskReference};
+use crate::types::{DiscoveredTask, SyncConflict, SyncError, SyncResult, TaskDefinition, TaskMetadata, TaskReference};
 
 // SeaORM repository imports
 use ratchet_storage::seaorm::entities::tasks;
@@ -30,16 +31,46 @@ impl DatabaseSync {
     }
 
     pub async fn sync_discovered_tasks(&self, tasks: Vec<DiscoveredTask>) -> Result<SyncResult> {
-        info!("Starting database sync of {} discovered tasks", tasks.len());
-        let mut sync_result = SyncResult {
-            tasks_added: 0,
-            tasks_updated: 0,
-            tasks_removed: 0,
-            errors: Vec::new(),
-        };
+        let candidates = tasks.into_iter().map(SyncCandidate::discovered).collect();
+        self.sync_with_resolver(candidates, &self.conflict_resolver).await
+    }
+
+    /// Sync tasks discovered from one source, resolving conflicts with that source's strategy
+    pub async fn sync_source_tasks(
+        &self,
+        tasks: Vec<DiscoveredTask>,
+        strategy: ConflictResolutionStrategy,
+    ) -> Result<SyncResult> {
+        let resolver = ConflictResolver::new().with_strategy(strategy);
+        let candidates = tasks.into_iter().map(SyncCandidate::discovered).collect();
+        self.sync_with_resolver(candidates, &resolver).await
+    }
 
-        for discovered_task in tasks {
-            match self.sync_single_task(&discovered_task).await {
+    /// Sync tasks loaded from one source, storing their code and schemas
+    ///
+    /// Conflicts are resolved with that source's strategy, as in
+    /// [`sync_source_tasks`](Self::sync_source_tasks).
+    pub async fn sync_source_definitions(
+        &self,
+        tasks: Vec<TaskDefinition>,
+        strategy: ConflictResolutionStrategy,
+    ) -> Result<SyncResult> {
+        let resolver = ConflictResolver::new().with_strategy(strategy);
+        let candidates = tasks.into_iter().map(SyncCandidate::loaded).collect();
+        self.sync_with_resolver(candidates, &resolver).await
+    }
+
+    async fn sync_with_resolver(&self, tasks: Vec<SyncCandidate>, resolver: &ConflictResolver) -> Result<SyncResult> {
+        info!(
+            "Starting database sync of {} discovered tasks ({:?} conflict resolution)",
+            tasks.len(),
+            resolver.strategy()
+        );
+        let mut sync_result = SyncResult::new();
+
+        for candidate in tasks {
+            let discovered_task = &candidate.discovered;
+            match self.sync_single_task(&candidate, resolver).await {
                 Ok(sync_type) => {
                     match sync_type {
                         SyncType::Added => sync_result.tasks_added += 1,
@@ -47,6 +78,7 @@ impl DatabaseSync {
                         SyncType::Skipped => {
                             // No change needed, don't increment counters
                         }
+                        SyncType::Conflict(conflict) => sync_result.conflicts.push(conflict),
                     }
                 }
                 Err(e) => {
@@ -63,16 +95,18 @@ impl DatabaseSync {
         }
 
         info!(
-            "Database sync completed: {} added, {} updated, {} errors",
+            "Database sync completed: {} added, {} updated, {} conflicts, {} errors",
             sync_result.tasks_added,
             sync_result.tasks_updated,
+            sync_result.conflicts.len(),
             sync_result.errors.len()
         );
 
         Ok(sync_result)
     }
 
-    async fn sync_single_task(&self, discovered_task: &DiscoveredTask) -> Result<SyncType> {
+    async fn sync_single_task(&self, candidate: &SyncCandidate, resolver: &ConflictResolver) -> Result<SyncType> {
+        let discovered_task = &candidate.discovered;
         let task_repo = self.repository_factory.task_repository();
 
         // Check if task already exists by finding all tasks with matching name and version
@@ -82,23 +116,38 @@ impl DatabaseSync {
             .await
             .map_err(|e| RegistryError::Other(e.to_string()))?;
 
-        let existing_task = all_tasks.into_iter().find(|task| {
-            task.name == discovered_task.metadata.name && task.version == discovered_task.metadata.version
-        });
+        // A task whose version changed keeps its record, found by its UUID
+        let existing_task = all_tasks
+            .iter()
+            .find(|task| task.name == discovered_task.metadata.name && task.version == discovered_task.metadata.version)
+            .or_else(|| all_tasks.iter().find(|task| task.uuid == discovered_task.metadata.uuid))
+            .cloned();
 
         match existing_task {
+            Some(existing) if !ConflictResolver::registry_changed(&existing, discovered_task) => {
+                info!(
+                    "Skipped task {} v{} (unchanged in registry)",
+                    discovered_task.metadata.name, discovered_task.metadata.version
+                );
+                Ok(SyncType::Skipped)
+            }
             Some(existing) => {
-                // Task exists, check for conflicts
-                let resolution = self.conflict_resolver.resolve_conflict(&(), discovered_task);
+                // Only a task also modified in the database is in conflict
+                let resolution = if ConflictResolver::database_changed(&existing) {
+                    resolver.resolve_conflict(&existing, discovered_task)
+                } else {
+                    ConflictResolution::UseRegistry
+                };
 
                 match resolution {
                     ConflictResolution::UseRegistry => {
                         // Update the existing task with registry data
-                        let updated_task = self.convert_discovered_to_task_model(discovered_task, Some(existing.id))?;
+                        let updated_task = self.convert_to_task_model(candidate, Some(&existing))?;
                         task_repo
                             .update(updated_task)
                             .await
                             .map_err(|e| RegistryError::Other(e.to_string()))?;
+                        self.mark_synced(existing.id).await?;
 
                         info!(
                             "Updated task {} v{}",
@@ -113,29 +162,23 @@ impl DatabaseSync {
                         );
                         Ok(SyncType::Skipped)
                     }
-                    ConflictResolution::Merge => {
-                        // For now, merge is the same as UseRegistry
-                        let updated_task = self.convert_discovered_to_task_model(discovered_task, Some(existing.id))?;
-                        task_repo
-                            .update(updated_task)
-                            .await
-                            .map_err(|e| RegistryError::Other(e.to_string()))?;
-
-                        info!(
-                            "Merged task {} v{}",
-                            discovered_task.metadata.name, discovered_task.metadata.version
-                        );
-                        Ok(SyncType::Updated)
-                    }
+                    ConflictResolution::Manual => Ok(SyncType::Conflict(SyncConflict {
+                        task_ref: discovered_task.task_ref.clone(),
+                        task_id: existing.id,
+                        registry_updated_at: discovered_task.metadata.updated_at,
+                        database_updated_at: existing.updated_at,
+                        last_synced_at: existing.last_synced_at,
+                    })),
                 }
             }
             None => {
                 // Task doesn't exist, add it
-                let new_task = self.convert_discovered_to_task_model(discovered_task, None)?;
-                task_repo
+                let new_task = self.convert_to_task_model(candidate, None)?;
+                let created = task_repo
                     .create(new_task)
                     .await
                     .map_err(|e| RegistryError::Other(e.to_string()))?;
+                self.mark_synced(created.id).await?;
 
                 info!(
                     "Added task {} v{}",
@@ -146,11 +189,21 @@ impl DatabaseSync {
         }
     }
 
-    fn convert_discovered_to_task_model(
+    /// Stamp a stored task as synced, after the write that stored it
+    async fn mark_synced(&self, id: i32) -> Result<()> {
+        self.repository_factory
+            .task_repository()
+            .mark_synced(id)
+            .await
+            .map_err(|e| RegistryError::Other(e.to_string()))
+    }
+
+    fn convert_to_task_model(
         &self,
-        discovered: &DiscoveredTask,
-        existing_id: Option<i32>,
+        candidate: &SyncCandidate,
+        existing: Option<&tasks::Model>,
     ) -> Result<tasks::Model> {
+        let discovered = &candidate.discovered;
         let now = chrono::Utc::now();
 
         // Generate a path from the task reference and source
@@ -159,34 +212,50 @@ impl DatabaseSync {
             discovered.task_ref.source, discovered.task_ref.name, discovered.task_ref.version
         );
 
+        // Only loaded tasks carry their code; a discovered task keeps what is stored
+        let (source_code, input_schema, output_schema) = match (&candidate.definition, existing) {
+            (Some(definition), _) => (
+                definition.script.clone(),
+                definition.input_schema.clone().unwrap_or_else(|| serde_json::json!({})),
+                definition
+                    .output_schema
+                    .clone()
+                    .unwrap_or_else(|| serde_json::json!({})),
+            ),
+            (None, Some(existing)) => (
+                existing.source_code.clone(),
+                existing.input_schema.clone(),
+                existing.output_schema.clone(),
+            ),
+            (None, None) => (String::new(), serde_json::json!({}), serde_json::json!({})),
+        };
+
         Ok(tasks::Model {
-            id: existing_id.unwrap_or(0), // Will be auto-generated for new tasks
-            uuid: existing_id
-                .map(|_| discovered.metadata.uuid)
-                .unwrap_or(discovered.metadata.uuid),
+            id: existing.map(|task| task.id).unwrap_or(0), // Will be auto-generated for new tasks
+            uuid: existing.map(|task| task.uuid).unwrap_or(discovered.metadata.uuid),
             name: discovered.metadata.name.clone(),
             description: discovered.metadata.description.clone(),
             version: discovered.metadata.version.clone(),
             path: Some(path.clone()),
-            metadata: serde_json::to_value(&discovered.metadata).map_err(|e| RegistryError::Json(e))?,
-            input_schema: serde_json::json!({}), // Default empty schema - TODO: Load from task definition
-            output_schema: serde_json::json!({}), // Default empty schema - TODO: Load from task definition
-            enabled: true,                       // New tasks are enabled by default
-            // New required fields for full task storage
-            source_code: "// TODO: Load source code from registry".to_string(), // Will be loaded in Phase 2
+            metadata: serde_json::to_value(&discovered.metadata).map_err(RegistryError::Json)?,
+            input_schema,
+            output_schema,
+            enabled: true, // New tasks are enabled by default
+            checksum: script_checksum(&source_code),
+            source_code,
             source_type: "javascript".to_string(),
             storage_type: "registry".to_string(),
             file_path: Some(path.clone()),
-            checksum: format!("{:x}", Sha256::digest("// TODO: Load source code from registry".as_bytes())), // Placeholder checksum
             repository_id: 1, // Default repository - TODO: make configurable
             repository_path: path.clone(),
-            last_synced_at: Some(now),
+            // Stamped by mark_synced once the task is stored
+            last_synced_at: existing.and_then(|task| task.last_synced_at),
             sync_status: "synced".to_string(),
             is_editable: false, // Registry tasks are read-only
             created_from: "registry".to_string(),
             needs_push: false,
-            created_at: existing_id
-                .map(|_| discovered.metadata.created_at)
+            created_at: existing
+                .map(|task| task.created_at)
                 .unwrap_or(discovered.metadata.created_at),
             updated_at: discovered.metadata.updated_at,
             validated_at: Some(now), // Mark as validated since it came from registry
@@ -239,16 +308,227 @@ impl DatabaseSync {
     }
 }
 
+/// A task to sync, with its full definition when it was loaded
+struct SyncCandidate {
+    discovered: DiscoveredTask,
+    definition: Option<TaskDefinition>,
+}
+
+impl SyncCandidate {
+    fn discovered(discovered: DiscoveredTask) -> Self {
+        Self {
+            discovered,
+            definition: None,
+        }
+    }
+
+    fn loaded(definition: TaskDefinition) -> Self {
+        // The checksum compared against the stored task is always that of the code being synced
+        let metadata = TaskMetadata {
+            checksum: Some(script_checksum(&definition.script)),
+            ..definition.metadata.clone()
+        };
+        Self {
+            discovered: DiscoveredTask {
+                task_ref: definition.reference.clone(),
+                metadata,
+                discovered_at: chrono::Utc::now(),
+            },
+            definition: Some(definition),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum SyncType {
     Added,
     Updated,
     Skipped,
+    Conflict(SyncConflict),
 }
 
 #[derive(Debug)]
 pub enum ConflictResolution {
     UseRegistry,
     UseDatabase,
-    Merge,
+    /// Leave both copies untouched and report the conflict
+    Manual,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loaders::filesystem::FilesystemLoader;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use ratchet_storage::seaorm::{config::DatabaseConfig, connection::DatabaseConnection};
+
+    async fn create_test_sync() -> (DatabaseSync, Arc<RepositoryFactory>) {
+        let db = DatabaseConnection::new(DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: std::time::Duration::from_secs(10),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        db.migrate().await.unwrap();
+
+        let repositories = Arc::new(RepositoryFactory::new(db));
+        (DatabaseSync::new(repositories.clone()), repositories)
+    }
+
+    fn definition(script: &str, updated_at: DateTime<Utc>) -> TaskDefinition {
+        TaskDefinition {
+            reference: TaskReference {
+                name: "greet".to_string(),
+                version: "1.0.0".to_string(),
+                source: "file:///tasks/greet".to_string(),
+            },
+            metadata: TaskMetadata {
+                uuid: uuid::Uuid::parse_str("6f6b2a9e-54a4-4b8e-9d0e-7f3c1b2a4d5e").unwrap(),
+                name: "greet".to_string(),
+                version: "1.0.0".to_string(),
+                description: None,
+                tags: Vec::new(),
+                created_at: updated_at,
+                updated_at,
+                checksum: None,
+                commit: None,
+            },
+            script: script.to_string(),
+            input_schema: Some(serde_json::json!({"type": "object"})),
+            output_schema: None,
+            dependencies: Vec::new(),
+            environment: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resync_of_unchanged_task_is_not_a_conflict() {
+        let (sync, repositories) = create_test_sync().await;
+        let updated_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let task = definition("(input) => input", updated_at);
+
+        let first = sync
+            .sync_source_definitions(vec![task.clone()], ConflictResolutionStrategy::Manual)
+            .await
+            .unwrap();
+        assert_eq!(first.tasks_added, 1);
+
+        let stored = repositories
+            .task_repository()
+            .find_by_name("greet")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.source_code, "(input) => input");
+        assert_eq!(stored.input_schema, serde_json::json!({"type": "object"}));
+        assert!(stored.last_synced_at.is_some_and(|synced| synced >= stored.updated_at));
+
+        let second = sync
+            .sync_source_definitions(vec![task.clone()], ConflictResolutionStrategy::Manual)
+            .await
+            .unwrap();
+        assert_eq!((second.tasks_added, second.tasks_updated), (0, 0));
+        assert!(second.conflicts.is_empty());
+
+        // A change made only in the registry is applied without a conflict
+        let edited = definition("(input) => ({ ...input })", updated_at + Duration::hours(1));
+        let third = sync
+            .sync_source_definitions(vec![edited], ConflictResolutionStrategy::Manual)
+            .await
+            .unwrap();
+        assert_eq!(third.tasks_updated, 1);
+        assert!(third.conflicts.is_empty());
+
+        let stored = repositories
+            .task_repository()
+            .find_by_name("greet")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.source_code, "(input) => ({ ...input })");
+    }
+
+    #[tokio::test]
+    async fn test_task_changed_in_both_places_is_a_conflict() {
+        let (sync, repositories) = create_test_sync().await;
+        let updated_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        sync.sync_source_definitions(
+            vec![definition("(input) => input", updated_at)],
+            ConflictResolutionStrategy::Manual,
+        )
+        .await
+        .unwrap();
+
+        let task_repo = repositories.task_repository();
+        let mut stored = task_repo.find_by_name("greet").await.unwrap().unwrap();
+        stored.description = Some("Edited in the database".to_string());
+        task_repo.update(stored).await.unwrap();
+
+        let result = sync
+            .sync_source_definitions(
+                vec![definition("(input) => null", updated_at + Duration::hours(1))],
+                ConflictResolutionStrategy::Manual,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.tasks_updated, 0);
+
+        let stored = task_repo.find_by_name("greet").await.unwrap().unwrap();
+        assert_eq!(stored.source_code, "(input) => input");
+    }
+
+    #[tokio::test]
+    async fn test_reloaded_filesystem_task_is_compared_by_content() {
+        let (sync, repositories) = create_test_sync().await;
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("metadata.json"),
+            r#"{"name": "greet", "version": "1.0.0"}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("main.js"), "(input) => input").unwrap();
+
+        let loader = FilesystemLoader::new();
+        let load = || loader.load_task_definition_from_path(dir.path());
+        let sync_loaded = |task, strategy| sync.sync_source_definitions(vec![task], strategy);
+
+        let first = sync_loaded(load().await.unwrap(), ConflictResolutionStrategy::Manual)
+            .await
+            .unwrap();
+        assert_eq!(first.tasks_added, 1);
+
+        // Edit the database copy, so any change in the registry would be a conflict
+        let task_repo = repositories.task_repository();
+        let mut stored = task_repo.find_by_name("greet").await.unwrap().unwrap();
+        stored.description = Some("Edited in the database".to_string());
+        task_repo.update(stored).await.unwrap();
+
+        // Loading the unchanged files again is no change, however often it happens
+        for _ in 0..2 {
+            let result = sync_loaded(load().await.unwrap(), ConflictResolutionStrategy::Manual)
+                .await
+                .unwrap();
+            assert_eq!((result.tasks_added, result.tasks_updated), (0, 0));
+            assert!(result.conflicts.is_empty());
+        }
+
+        std::fs::write(dir.path().join("main.js"), "(input) => ({ ...input })").unwrap();
+        let edited = load().await.unwrap();
+
+        let result = sync_loaded(edited.clone(), ConflictResolutionStrategy::Manual)
+            .await
+            .unwrap();
+        assert_eq!(result.conflicts.len(), 1);
+
+        // The file was written after the database edit, so it is the newer copy
+        let result = sync_loaded(edited, ConflictResolutionStrategy::PreferNewer)
+            .await
+            .unwrap();
+        assert_eq!(result.tasks_updated, 1);
+        let stored = task_repo.find_by_name("greet").await.unwrap().unwrap();
+        assert_eq!(stored.source_code, "(input) => ({ ...input })");
+    }
 }
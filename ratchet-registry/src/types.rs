@@ -27,6 +27,9 @@ pub struct TaskMetadata {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub checksum: Option<String>,
+    /// Commit the task was loaded from, for tasks from Git repositories
+    #[serde(default)]
+    pub commit: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +70,11 @@ pub struct SyncResult {
     pub tasks_updated: usize,
     pub tasks_removed: usize,
     pub errors: Vec<SyncError>,
+    /// Conflicts left unresolved under [`ConflictResolutionStrategy::Manual`]
+    ///
+    /// [`ConflictResolutionStrategy::Manual`]: crate::config::ConflictResolutionStrategy::Manual
+    #[serde(default)]
+    pub conflicts: Vec<SyncConflict>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -75,6 +83,20 @@ pub struct SyncError {
     pub error: String,
 }
 
+/// A task whose registry and database copies both changed since the last sync
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConflict {
+    pub task_ref: TaskReference,
+    /// Database ID of the conflicting task
+    pub task_id: i32,
+    /// When the registry copy was last modified
+    pub registry_updated_at: DateTime<Utc>,
+    /// When the database copy was last modified
+    pub database_updated_at: DateTime<Utc>,
+    /// When the database copy was last synced from the registry
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RegistryEvent {
     TaskAdded(DiscoveredTask),
@@ -84,6 +106,17 @@ pub enum RegistryEvent {
     Error(String),
 }
 
+impl TaskDefinition {
+    /// Discovery record for this task, as reported in registry events
+    pub fn to_discovered(&self) -> DiscoveredTask {
+        DiscoveredTask {
+            task_ref: self.reference.clone(),
+            metadata: self.metadata.clone(),
+            discovered_at: Utc::now(),
+        }
+    }
+}
+
 impl ValidationResult {
     pub fn new() -> Self {
         Self {
@@ -116,12 +149,22 @@ impl SyncResult {
             tasks_updated: 0,
             tasks_removed: 0,
             errors: Vec::new(),
+            conflicts: Vec::new(),
         }
     }
 
     pub fn add_error(&mut self, task_ref: TaskReference, error: String) {
         self.errors.push(SyncError { task_ref, error });
     }
+
+    /// Add the counts, errors and conflicts of another sync to this one
+    pub fn merge(&mut self, other: SyncResult) {
+        self.tasks_added += other.tasks_added;
+        self.tasks_updated += other.tasks_updated;
+        self.tasks_removed += other.tasks_removed;
+        self.errors.extend(other.errors);
+        self.conflicts.extend(other.conflicts);
+    }
 }
 
 impl Default for SyncResult {
@@ -196,7 +239,11 @@ impl RepositoryStatus {
 
         if result.errors.is_empty() {
             self.sync_state = SyncState::Synced;
-            self.health_status = HealthStatus::Healthy;
+            self.health_status = if result.conflicts.is_empty() {
+                HealthStatus::Healthy
+            } else {
+                HealthStatus::Warning(format!("{} unresolved sync conflicts", result.conflicts.len()))
+            };
         } else {
             let error_msg = format!("{} sync errors", result.errors.len());
             self.sync_state = SyncState::Error(error_msg.clone());
@@ -1,17 +1,26 @@
+//! Filesystem watcher for task sources
+//!
+//! Changes below a watched path are debounced per task directory: a task is
+//! only looked at again once its files have been quiet for
+//! [`WatcherConfig::debounce_ms`]. Each settled change is reported as a
+//! [`RegistryEvent`] to the subscribers of the watcher's event channel; the
+//! watcher itself never modifies the registry, so a subscriber can validate a
+//! reloaded task before applying it.
+
 use notify::{Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::{mpsc, oneshot};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio::time::{interval, timeout};
 use tracing::{debug, error, info, warn};
 
 use crate::config::WatcherConfig;
 use crate::error::{RegistryError, Result};
+use crate::loaders::filesystem::FilesystemLoader;
 use crate::registry::DefaultTaskRegistry;
-use crate::sync::DatabaseSync;
-use crate::types::TaskDefinition;
+use crate::types::{RegistryEvent, TaskDefinition};
 
 #[derive(Debug, Clone)]
 pub enum WatchEvent {
@@ -24,7 +33,7 @@ pub enum WatchEvent {
 pub struct RegistryWatcher {
     watcher: Option<RecommendedWatcher>,
     registry: Arc<DefaultTaskRegistry>,
-    sync_service: Option<Arc<DatabaseSync>>,
+    registry_events: broadcast::Sender<RegistryEvent>,
     watch_paths: Vec<(PathBuf, bool)>,
     event_tx: mpsc::UnboundedSender<WatchEvent>,
     event_rx: Option<mpsc::UnboundedReceiver<WatchEvent>>,
@@ -34,9 +43,10 @@ pub struct RegistryWatcher {
 }
 
 impl RegistryWatcher {
+    /// Create a watcher that reports changes to tasks in `registry` on `registry_events`
     pub fn new(
         registry: Arc<DefaultTaskRegistry>,
-        sync_service: Option<Arc<DatabaseSync>>,
+        registry_events: broadcast::Sender<RegistryEvent>,
         config: WatcherConfig,
     ) -> Self {
         let (event_tx, event_rx) = mpsc::unbounded_channel();
@@ -44,7 +54,7 @@ impl RegistryWatcher {
         Self {
             watcher: None,
             registry,
-            sync_service,
+            registry_events,
             watch_paths: Vec::new(),
             event_tx,
             event_rx: Some(event_rx),
@@ -58,6 +68,11 @@ impl RegistryWatcher {
         self.watch_paths.push((path, recursive));
     }
 
+    /// Subscribe to the registry events produced by this watcher
+    pub fn subscribe(&self) -> broadcast::Receiver<RegistryEvent> {
+        self.registry_events.subscribe()
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         if !self.config.enabled {
             info!("Registry watching is disabled");
@@ -82,6 +97,7 @@ impl RegistryWatcher {
         .map_err(|e| RegistryError::WatcherError(format!("Failed to create watcher: {}", e)))?;
 
         // Add all watch paths
+        let mut roots = Vec::new();
         for (path, recursive) in &self.watch_paths {
             let mode = if *recursive {
                 RecursiveMode::Recursive
@@ -93,6 +109,14 @@ impl RegistryWatcher {
                 .watch(path, mode)
                 .map_err(|e| RegistryError::WatcherError(format!("Failed to watch path {:?}: {}", path, e)))?;
 
+            // Notify may report absolute paths; map them back to the path the source was configured with
+            if let Ok(canonical) = path.canonicalize() {
+                roots.push(WatchRoot {
+                    canonical,
+                    configured: path.clone(),
+                });
+            }
+
             info!("Watching path: {:?} (recursive: {})", path, recursive);
         }
 
@@ -105,7 +129,8 @@ impl RegistryWatcher {
         if let Some(event_rx) = self.event_rx.take() {
             let processor = EventProcessor {
                 registry: self.registry.clone(),
-                sync_service: self.sync_service.clone(),
+                registry_events: self.registry_events.clone(),
+                roots: Arc::new(roots),
                 config: self.config.clone(),
             };
 
@@ -141,96 +166,111 @@ impl RegistryWatcher {
     }
 
     fn handle_notify_event(event: Event, event_tx: &mpsc::UnboundedSender<WatchEvent>) -> Result<()> {
-        match event.kind {
-            EventKind::Create(_) => {
-                for path in event.paths {
-                    if Self::is_task_directory(&path) {
-                        debug!("Task added: {:?}", path);
-                        event_tx
-                            .send(WatchEvent::TaskAdded(path))
-                            .map_err(|e| RegistryError::WatcherError(e.to_string()))?;
-                    }
-                }
-            }
-            EventKind::Modify(_) => {
-                for path in event.paths {
-                    if let Some(task_dir) = Self::find_task_directory(&path) {
-                        debug!("Task modified: {:?}", task_dir);
-                        event_tx
-                            .send(WatchEvent::TaskModified(task_dir))
-                            .map_err(|e| RegistryError::WatcherError(e.to_string()))?;
-                    }
-                }
-            }
-            EventKind::Remove(_) => {
-                for path in event.paths {
-                    if Self::is_task_directory(&path) || path.ends_with("metadata.json") {
-                        let task_dir = if path.ends_with("metadata.json") {
-                            path.parent().map(|p| p.to_path_buf()).unwrap_or(path)
-                        } else {
-                            path
-                        };
-                        debug!("Task removed: {:?}", task_dir);
-                        event_tx
-                            .send(WatchEvent::TaskRemoved(task_dir))
-                            .map_err(|e| RegistryError::WatcherError(e.to_string()))?;
-                    }
-                }
-            }
-            _ => {
-                // Ignore other events like Access
-            }
+        let created = matches!(event.kind, EventKind::Create(_));
+        if !matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        ) {
+            // Ignore other events like Access
+            return Ok(());
+        }
+
+        for path in event.paths {
+            let Some(watch_event) = Self::classify_change(path, created) else {
+                continue;
+            };
+
+            debug!("Task change: {:?}", watch_event);
+            event_tx
+                .send(watch_event)
+                .map_err(|e| RegistryError::WatcherError(e.to_string()))?;
         }
 
         Ok(())
     }
 
+    /// Map a changed path to the task directory it affects
+    ///
+    /// A path that no longer exists was removed or renamed away: losing
+    /// `metadata.json`, or a path with no task directory above it, removes the
+    /// tasks loaded from there, while any other missing file is a modification
+    /// of the task that contained it.
+    fn classify_change(path: PathBuf, created: bool) -> Option<WatchEvent> {
+        if !path.exists() {
+            if path.ends_with("metadata.json") {
+                let task_dir = path.parent().map(Path::to_path_buf).unwrap_or(path);
+                return Some(WatchEvent::TaskRemoved(task_dir));
+            }
+            return match Self::find_task_directory(&path) {
+                Some(task_dir) => Some(WatchEvent::TaskModified(task_dir)),
+                None => Some(WatchEvent::TaskRemoved(path)),
+            };
+        }
+
+        if created && Self::is_task_directory(&path) {
+            return Some(WatchEvent::TaskAdded(path));
+        }
+
+        Self::find_task_directory(&path).map(WatchEvent::TaskModified)
+    }
+
     fn is_task_directory(path: &Path) -> bool {
         path.join("metadata.json").exists()
     }
 
     fn find_task_directory(path: &Path) -> Option<PathBuf> {
-        let mut current = path;
-
-        // Walk up the directory tree looking for metadata.json
-        while let Some(parent) = current.parent() {
-            if parent.join("metadata.json").exists() {
-                return Some(parent.to_path_buf());
-            }
-            current = parent;
-        }
-
         // Check if the path itself is a task directory
-        if path.join("metadata.json").exists() {
-            Some(path.to_path_buf())
-        } else {
-            None
+        if Self::is_task_directory(path) {
+            return Some(path.to_path_buf());
         }
+
+        // Walk up the directory tree looking for metadata.json
+        path.ancestors()
+            .skip(1)
+            .find(|ancestor| Self::is_task_directory(ancestor))
+            .map(Path::to_path_buf)
     }
 }
 
+/// A watched path, as configured and as reported by the operating system
+#[derive(Debug, Clone)]
+struct WatchRoot {
+    canonical: PathBuf,
+    configured: PathBuf,
+}
+
+/// A change waiting for its task directory to settle
+#[derive(Debug)]
+struct PendingChange {
+    event: WatchEvent,
+    last_seen: Instant,
+}
+
 struct EventProcessor {
     registry: Arc<DefaultTaskRegistry>,
-    sync_service: Option<Arc<DatabaseSync>>,
+    registry_events: broadcast::Sender<RegistryEvent>,
+    roots: Arc<Vec<WatchRoot>>,
     config: WatcherConfig,
 }
 
 impl EventProcessor {
     async fn run(self, mut event_rx: mpsc::UnboundedReceiver<WatchEvent>, mut shutdown_rx: oneshot::Receiver<()>) {
-        let mut pending_events: HashMap<PathBuf, WatchEvent> = HashMap::new();
-        let mut debounce_interval = interval(Duration::from_millis(self.config.debounce_ms));
+        let mut pending_events: HashMap<PathBuf, PendingChange> = HashMap::new();
+        let debounce = Duration::from_millis(self.config.debounce_ms);
+        let mut debounce_interval = interval((debounce / 2).max(Duration::from_millis(10)));
 
         loop {
             tokio::select! {
                 // Process incoming events
                 Some(event) = event_rx.recv() => {
-                    self.buffer_event(&mut pending_events, event);
+                    buffer_event(&mut pending_events, event, Instant::now());
                 }
 
-                // Process buffered events after debounce period
+                // Process changes whose task directory has been quiet for the debounce period
                 _ = debounce_interval.tick() => {
-                    if !pending_events.is_empty() {
-                        self.process_pending_events(&mut pending_events).await;
+                    let settled = take_settled(&mut pending_events, debounce, Instant::now());
+                    if !settled.is_empty() {
+                        self.process_events(settled).await;
                     }
                 }
 
@@ -244,36 +284,12 @@ impl EventProcessor {
 
         // Process any remaining events
         if !pending_events.is_empty() {
-            self.process_pending_events(&mut pending_events).await;
-        }
-    }
-
-    fn buffer_event(&self, pending: &mut HashMap<PathBuf, WatchEvent>, event: WatchEvent) {
-        match event {
-            WatchEvent::TaskAdded(path) => {
-                pending.insert(path.clone(), WatchEvent::TaskAdded(path));
-            }
-            WatchEvent::TaskModified(path) => {
-                // If we already have an add event, keep it
-                if !matches!(pending.get(&path), Some(WatchEvent::TaskAdded(_))) {
-                    pending.insert(path.clone(), WatchEvent::TaskModified(path));
-                }
-            }
-            WatchEvent::TaskRemoved(path) => {
-                // Remove always takes precedence
-                pending.insert(path.clone(), WatchEvent::TaskRemoved(path));
-            }
-            WatchEvent::BulkChange(paths) => {
-                for path in paths {
-                    self.buffer_event(pending, WatchEvent::TaskModified(path));
-                }
-            }
+            let remaining = pending_events.drain().map(|(_, pending)| pending.event).collect();
+            self.process_events(remaining).await;
         }
     }
 
-    async fn process_pending_events(&self, pending: &mut HashMap<PathBuf, WatchEvent>) {
-        let events: Vec<_> = pending.drain().map(|(_, event)| event).collect();
-
+    async fn process_events(&self, events: Vec<WatchEvent>) {
         info!("Processing {} file system events", events.len());
 
         // Limit concurrent reloads
@@ -283,7 +299,8 @@ impl EventProcessor {
         for event in events {
             let semaphore = semaphore.clone();
             let registry = self.registry.clone();
-            let sync_service = self.sync_service.clone();
+            let registry_events = self.registry_events.clone();
+            let roots = self.roots.clone();
             let retry_on_error = self.config.retry_on_error;
             let retry_delay_ms = self.config.retry_delay_ms;
 
@@ -296,23 +313,21 @@ impl EventProcessor {
                     }
                 };
 
-                match event {
+                let changes = match event {
                     WatchEvent::TaskAdded(path) | WatchEvent::TaskModified(path) => {
-                        if let Err(e) =
-                            Self::reload_task(&path, registry, sync_service, retry_on_error, retry_delay_ms).await
-                        {
-                            error!("Failed to reload task at {:?}: {}", path, e);
-                        }
-                    }
-                    WatchEvent::TaskRemoved(path) => {
-                        if let Err(e) = Self::remove_task(&path, registry, sync_service).await {
-                            error!("Failed to remove task at {:?}: {}", path, e);
-                        }
+                        let path = source_path(&roots, &path);
+                        vec![Self::reload_task(&path, &registry, retry_on_error, retry_delay_ms).await]
                     }
+                    WatchEvent::TaskRemoved(path) => Self::remove_task(&source_path(&roots, &path), &registry).await,
                     WatchEvent::BulkChange(_) => {
                         // Should have been expanded in buffer_event
                         unreachable!("BulkChange should have been expanded");
                     }
+                };
+
+                for registry_event in changes {
+                    // Sending only fails when nobody is subscribed
+                    let _ = registry_events.send(registry_event);
                 }
             });
 
@@ -325,111 +340,266 @@ impl EventProcessor {
         }
     }
 
+    /// Load the task at `path`, reporting it as added or updated
+    ///
+    /// Loading is retried because editors often write a file in several steps.
     async fn reload_task(
         path: &Path,
-        registry: Arc<DefaultTaskRegistry>,
-        sync_service: Option<Arc<DatabaseSync>>,
+        registry: &DefaultTaskRegistry,
         retry_on_error: bool,
         retry_delay_ms: u64,
-    ) -> Result<()> {
-        let mut attempts = 0;
+    ) -> RegistryEvent {
         let max_attempts = if retry_on_error { 3 } else { 1 };
+        let mut attempts = 0;
 
-        while attempts < max_attempts {
+        loop {
             attempts += 1;
 
-            // TODO: Implement task loading from filesystem
-            // This would use the filesystem loader to load the task
-            // For now, this is a placeholder
             match Self::load_task_from_path(path).await {
                 Ok(task) => {
-                    info!("Reloading task: {} ({})", task.metadata.name, task.metadata.uuid);
-
-                    // Update registry
-                    if let Err(e) = registry.add_task(task.clone()).await {
-                        error!("Failed to add task to registry: {}", e);
-                        if attempts < max_attempts {
-                            tokio::time::sleep(Duration::from_millis(retry_delay_ms)).await;
-                            continue;
-                        }
-                        return Err(RegistryError::LoadError(format!(
-                            "Failed to add task to registry: {}",
-                            e
-                        )));
-                    }
-
-                    // Sync with database if available
-                    if let Some(_sync) = &sync_service {
-                        // TODO: Implement database sync
-                        // sync.sync_task_to_db(&task).await?;
-                    }
-
-                    return Ok(());
+                    let known = !registry.tasks_from_source(&task.reference.source).await.is_empty();
+                    info!("Task {} ({}) changed on disk", task.metadata.name, task.metadata.uuid);
+                    return if known {
+                        RegistryEvent::TaskUpdated(task.to_discovered())
+                    } else {
+                        RegistryEvent::TaskAdded(task.to_discovered())
+                    };
+                }
+                Err(e) if attempts < max_attempts => {
+                    warn!(
+                        "Failed to reload task from {:?} (attempt {}/{}): {}",
+                        path, attempts, max_attempts, e
+                    );
+                    tokio::time::sleep(Duration::from_millis(retry_delay_ms)).await;
                 }
                 Err(e) => {
-                    if attempts < max_attempts {
-                        warn!(
-                            "Failed to reload task from {:?} (attempt {}/{}): {}",
-                            path, attempts, max_attempts, e
-                        );
-                        tokio::time::sleep(Duration::from_millis(retry_delay_ms)).await;
-                    } else {
-                        return Err(RegistryError::LoadError(format!(
-                            "Failed to reload task after {} attempts: {}",
-                            max_attempts, e
-                        )));
-                    }
+                    error!("Failed to reload task at {:?}: {}", path, e);
+                    return RegistryEvent::Error(format!(
+                        "Failed to reload task at {} after {} attempts: {}",
+                        path.display(),
+                        attempts,
+                        e
+                    ));
                 }
             }
         }
+    }
+
+    /// Report every task loaded from `path` or below it as removed
+    async fn remove_task(path: &Path, registry: &DefaultTaskRegistry) -> Vec<RegistryEvent> {
+        let removed = registry.tasks_from_source(&file_source(path)).await;
+
+        if removed.is_empty() {
+            debug!("No registered tasks were loaded from removed path {:?}", path);
+        }
 
-        unreachable!("Should have returned from loop");
+        removed
+            .into_iter()
+            .map(|task| {
+                info!("Task {} ({}) removed from disk", task.metadata.name, task.metadata.uuid);
+                RegistryEvent::TaskRemoved(task.reference.clone())
+            })
+            .collect()
     }
 
-    async fn remove_task(
-        path: &Path,
-        registry: Arc<DefaultTaskRegistry>,
-        _sync_service: Option<Arc<DatabaseSync>>,
-    ) -> Result<()> {
-        // Extract task UUID from path
-        let dir_name = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .ok_or_else(|| RegistryError::LoadError("Invalid path".to_string()))?;
-
-        info!("Removing task from directory: {}", dir_name);
-
-        // Try to find the task by iterating through all tasks
-        let tasks = registry.list_tasks().await?;
-
-        for task in tasks {
-            // Check if this task's path matches
-            if let Some(task_dir) = path.file_name().and_then(|n| n.to_str()) {
-                if task.metadata.uuid.to_string() == task_dir {
-                    info!("Found task to remove: {} ({})", task.metadata.name, task.metadata.uuid);
-
-                    // Remove from registry
-                    if let Err(e) = registry.remove_task(&task.metadata.uuid).await {
-                        return Err(RegistryError::LoadError(format!(
-                            "Failed to remove task from registry: {}",
-                            e
-                        )));
-                    }
+    async fn load_task_from_path(path: &Path) -> Result<TaskDefinition> {
+        FilesystemLoader::new().load_task_definition_from_path(path).await
+    }
+}
 
-                    return Ok(());
-                }
+/// Record `event` as the pending change for its task directory
+fn buffer_event(pending: &mut HashMap<PathBuf, PendingChange>, event: WatchEvent, now: Instant) {
+    let (path, event) = match event {
+        WatchEvent::TaskAdded(path) => (path.clone(), WatchEvent::TaskAdded(path)),
+        WatchEvent::TaskModified(path) => {
+            // If we already have an add event, keep it
+            let event = match pending.get(&path) {
+                Some(PendingChange {
+                    event: WatchEvent::TaskAdded(_),
+                    ..
+                }) => WatchEvent::TaskAdded(path.clone()),
+                _ => WatchEvent::TaskModified(path.clone()),
+            };
+            (path, event)
+        }
+        // Remove always takes precedence
+        WatchEvent::TaskRemoved(path) => (path.clone(), WatchEvent::TaskRemoved(path)),
+        WatchEvent::BulkChange(paths) => {
+            for path in paths {
+                buffer_event(pending, WatchEvent::TaskModified(path), now);
             }
+            return;
         }
+    };
 
-        warn!("Could not find task to remove for path: {:?}", path);
-        Ok(())
+    pending.insert(path, PendingChange { event, last_seen: now });
+}
+
+/// Take the changes that have seen no further events for `debounce`
+fn take_settled(pending: &mut HashMap<PathBuf, PendingChange>, debounce: Duration, now: Instant) -> Vec<WatchEvent> {
+    let settled: Vec<PathBuf> = pending
+        .iter()
+        .filter(|(_, change)| now.duration_since(change.last_seen) >= debounce)
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    settled
+        .into_iter()
+        .filter_map(|path| pending.remove(&path))
+        .map(|change| change.event)
+        .collect()
+}
+
+/// `path` as it appears in task sources, under the watch path it was configured with
+fn source_path(roots: &[WatchRoot], path: &Path) -> PathBuf {
+    roots
+        .iter()
+        .find_map(|root| {
+            path.strip_prefix(&root.canonical)
+                .ok()
+                .map(|relative| root.configured.join(relative))
+        })
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Task source string of a task directory, as produced by [`FilesystemLoader`]
+fn file_source(path: &Path) -> String {
+    format!("file://{}", path.display())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_task(dir: &Path, version: &str, script: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(
+            dir.join("metadata.json"),
+            format!(
+                r#"{{"uuid": "3f0b6a4e-9d6c-4d8e-8a57-2a4b5c6d7e8f", "name": "greeter", "version": "{}"}}"#,
+                version
+            ),
+        )
+        .unwrap();
+        std::fs::write(dir.join("main.js"), script).unwrap();
     }
 
-    async fn load_task_from_path(_path: &Path) -> Result<TaskDefinition> {
-        // TODO: Implement actual task loading using filesystem loader
-        // This is a placeholder
-        Err(RegistryError::NotImplemented(
-            "Task loading from path not yet implemented".to_string(),
-        ))
+    #[test]
+    fn test_rapid_changes_are_debounced_per_task() {
+        let mut pending = HashMap::new();
+        let start = Instant::now();
+        let debounce = Duration::from_millis(500);
+        let task_dir = PathBuf::from("tasks/greeter");
+
+        buffer_event(&mut pending, WatchEvent::TaskAdded(task_dir.clone()), start);
+        buffer_event(
+            &mut pending,
+            WatchEvent::TaskModified(task_dir.clone()),
+            start + Duration::from_millis(400),
+        );
+
+        // The second change restarts the quiet period
+        assert!(take_settled(&mut pending, debounce, start + Duration::from_millis(600)).is_empty());
+
+        let settled = take_settled(&mut pending, debounce, start + Duration::from_millis(900));
+        assert_eq!(settled.len(), 1);
+        assert!(matches!(&settled[0], WatchEvent::TaskAdded(path) if *path == task_dir));
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn test_removal_takes_precedence() {
+        let mut pending = HashMap::new();
+        let now = Instant::now();
+        let task_dir = PathBuf::from("tasks/greeter");
+
+        buffer_event(&mut pending, WatchEvent::TaskModified(task_dir.clone()), now);
+        buffer_event(&mut pending, WatchEvent::TaskRemoved(task_dir.clone()), now);
+
+        let settled = take_settled(&mut pending, Duration::ZERO, now);
+        assert!(matches!(&settled[0], WatchEvent::TaskRemoved(path) if *path == task_dir));
+    }
+
+    #[test]
+    fn test_classify_change() {
+        let root = tempfile::tempdir().unwrap();
+        let task_dir = root.path().join("greeter");
+        write_task(&task_dir, "1.0.0", "module.exports = () => ({})");
+
+        assert!(matches!(
+            RegistryWatcher::classify_change(task_dir.clone(), true),
+            Some(WatchEvent::TaskAdded(path)) if path == task_dir
+        ));
+        assert!(matches!(
+            RegistryWatcher::classify_change(task_dir.join("main.js"), false),
+            Some(WatchEvent::TaskModified(path)) if path == task_dir
+        ));
+        assert!(matches!(
+            RegistryWatcher::classify_change(task_dir.join("deleted-helper.js"), false),
+            Some(WatchEvent::TaskModified(path)) if path == task_dir
+        ));
+        assert!(RegistryWatcher::classify_change(root.path().join("README.md"), false).is_none());
+
+        std::fs::remove_file(task_dir.join("metadata.json")).unwrap();
+        assert!(matches!(
+            RegistryWatcher::classify_change(task_dir.join("metadata.json"), false),
+            Some(WatchEvent::TaskRemoved(path)) if path == task_dir
+        ));
+    }
+
+    #[test]
+    fn test_source_path_maps_canonical_paths_back() {
+        let roots = vec![WatchRoot {
+            canonical: PathBuf::from("/srv/ratchet/tasks"),
+            configured: PathBuf::from("./tasks"),
+        }];
+
+        assert_eq!(
+            source_path(&roots, Path::new("/srv/ratchet/tasks/greeter")),
+            PathBuf::from("./tasks/greeter")
+        );
+        assert_eq!(
+            source_path(&roots, Path::new("/elsewhere/greeter")),
+            PathBuf::from("/elsewhere/greeter")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reload_reports_added_then_updated() {
+        let root = tempfile::tempdir().unwrap();
+        let task_dir = root.path().join("greeter");
+        write_task(&task_dir, "1.0.0", "module.exports = () => ({})");
+        let registry = DefaultTaskRegistry::new();
+
+        let event = EventProcessor::reload_task(&task_dir, &registry, false, 0).await;
+        let RegistryEvent::TaskAdded(discovered) = event else {
+            panic!("expected TaskAdded, got {:?}", event);
+        };
+        assert_eq!(discovered.task_ref.source, file_source(&task_dir));
+
+        let task = FilesystemLoader::new()
+            .load_task_definition_from_path(&task_dir)
+            .await
+            .unwrap();
+        registry.add_task(task).await.unwrap();
+
+        write_task(&task_dir, "1.1.0", "module.exports = () => ({ updated: true })");
+        let event = EventProcessor::reload_task(&task_dir, &registry, false, 0).await;
+        assert!(matches!(event, RegistryEvent::TaskUpdated(ref discovered) if discovered.metadata.version == "1.1.0"));
+
+        let removed = EventProcessor::remove_task(&task_dir, &registry).await;
+        assert_eq!(removed.len(), 1);
+        assert!(matches!(&removed[0], RegistryEvent::TaskRemoved(task_ref) if task_ref.version == "1.0.0"));
+    }
+
+    #[tokio::test]
+    async fn test_broken_task_reports_error() {
+        let root = tempfile::tempdir().unwrap();
+        let task_dir = root.path().join("broken");
+        std::fs::create_dir_all(&task_dir).unwrap();
+        std::fs::write(task_dir.join("metadata.json"), "{ not json").unwrap();
+
+        let event = EventProcessor::reload_task(&task_dir, &DefaultTaskRegistry::new(), false, 0).await;
+        assert!(matches!(event, RegistryEvent::Error(_)));
     }
 }
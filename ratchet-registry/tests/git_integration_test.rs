@@ -1,6 +1,6 @@
 #[cfg(feature = "git")]
 mod git_tests {
-    use ratchet_registry::config::{GitConfig, GitSyncStrategy, TaskSource};
+    use ratchet_registry::config::{GitConfig, TaskSource};
     use ratchet_registry::loaders::git::GitLoader;
     use ratchet_registry::loaders::TaskLoader;
 
@@ -29,11 +29,10 @@ mod git_tests {
         let loader = GitLoader::new();
 
         let git_config = GitConfig {
-            branch: "main".to_string(),
+            branch: Some("main".to_string()),
             subdirectory: None,
             shallow: true,
             depth: Some(1),
-            sync_strategy: GitSyncStrategy::Fetch,
             cleanup_on_error: true,
             verify_signatures: false,
             allowed_refs: None,
@@ -15,7 +15,7 @@ mod gitoxide_tests {
             url: "https://github.com/octocat/Hello-World.git".to_string(),
             auth: None,
             config: GitConfig {
-                branch: "master".to_string(),
+                branch: Some("master".to_string()),
                 shallow: true,
                 depth: Some(1),
                 ..GitConfig::default()
@@ -146,6 +146,7 @@ mod gitoxide_tests {
             url: "https://example.com/tasks".to_string(),
             auth: None,
             polling_interval: std::time::Duration::from_secs(300),
+            discovery: Default::default(),
         };
 
         assert!(
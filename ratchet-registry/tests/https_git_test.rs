@@ -18,11 +18,10 @@ mod https_git_tests {
         let test_repo_url = "https://github.com/ratchet-runner/ratchet-repo-samples";
 
         let git_config = GitConfig {
-            branch: "main".to_string(),
+            branch: Some("main".to_string()),
             subdirectory: None,
             shallow: true,
             depth: Some(1),
-            sync_strategy: ratchet_registry::config::GitSyncStrategy::Fetch,
             cleanup_on_error: true,
             verify_signatures: false,
             allowed_refs: None,
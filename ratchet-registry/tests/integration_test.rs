@@ -45,6 +45,7 @@ async fn test_registry_service() {
         enable_auto_sync: false,
         enable_validation: true,
         cache_config: Default::default(),
+        conflict_resolution: Default::default(),
     };
 
     let service = DefaultRegistryService::new(config);
@@ -74,6 +75,7 @@ async fn test_task_validation() {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             checksum: None,
+            commit: None,
         },
         script: "console.log('Hello');".to_string(),
         input_schema: None,
@@ -0,0 +1,388 @@
+//! Bulkhead (semaphore isolation) pattern implementation
+//!
+//! A bulkhead caps the number of concurrent calls to a downstream dependency.
+//! Calls beyond the limit wait in a bounded queue, optionally for a limited time,
+//! and are rejected once both the concurrency limit and the queue are exhausted.
+
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Semaphore, TryAcquireError};
+
+use crate::retry::Retryable;
+
+/// Bulkhead configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkheadConfig {
+    /// Maximum number of calls executing concurrently
+    pub max_concurrent: usize,
+
+    /// Maximum number of calls waiting for a free slot
+    #[serde(default)]
+    pub max_queued: usize,
+
+    /// How long a queued call waits for a free slot before giving up
+    #[serde(with = "humantime_serde", default)]
+    pub queue_timeout: Option<Duration>,
+}
+
+impl Default for BulkheadConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 10,
+            max_queued: 0,
+            queue_timeout: None,
+        }
+    }
+}
+
+/// Point-in-time bulkhead metrics
+#[derive(Debug, Clone, Default)]
+pub struct BulkheadMetrics {
+    /// Configured concurrency limit
+    pub max_concurrent: usize,
+    /// Configured queue capacity
+    pub max_queued: usize,
+    /// Calls currently executing
+    pub active: usize,
+    /// Calls currently waiting for a slot
+    pub queued: usize,
+    /// Fraction of the concurrency limit in use, between 0.0 and 1.0
+    pub utilization: f64,
+    /// Total calls rejected because the bulkhead was full
+    pub total_rejected: u64,
+    /// Total queued calls that gave up waiting
+    pub total_timed_out: u64,
+}
+
+/// Bulkhead error types
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BulkheadError<E> {
+    /// Concurrency limit and queue are both exhausted
+    #[error("Bulkhead is full ({max_concurrent} active, {max_queued} queued)")]
+    Full { max_concurrent: usize, max_queued: usize },
+
+    /// Call waited in the queue longer than the queue timeout
+    #[error("Timed out after {0:?} waiting for bulkhead capacity")]
+    QueueTimeout(Duration),
+
+    /// The operation itself failed
+    #[error("{0}")]
+    Inner(E),
+}
+
+impl<E> BulkheadError<E> {
+    /// Get the operation error if the call was admitted
+    pub fn into_inner(self) -> Option<E> {
+        match self {
+            BulkheadError::Inner(error) => Some(error),
+            BulkheadError::Full { .. } | BulkheadError::QueueTimeout(_) => None,
+        }
+    }
+
+    /// Check if the call was rejected without running the operation
+    pub fn is_rejected(&self) -> bool {
+        !matches!(self, BulkheadError::Inner(_))
+    }
+}
+
+impl<E: Retryable> Retryable for BulkheadError<E> {
+    fn is_retryable(&self) -> bool {
+        match self {
+            // Capacity frees up as in-flight calls complete
+            BulkheadError::Full { .. } | BulkheadError::QueueTimeout(_) => true,
+            BulkheadError::Inner(error) => error.is_retryable(),
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        match self {
+            BulkheadError::Inner(error) => error.is_transient(),
+            _ => false,
+        }
+    }
+
+    fn retry_delay(&self) -> Option<Duration> {
+        match self {
+            BulkheadError::Inner(error) => error.retry_delay(),
+            _ => None,
+        }
+    }
+}
+
+/// Thread-safe bulkhead limiting concurrent calls
+#[derive(Clone)]
+pub struct Bulkhead {
+    config: Arc<BulkheadConfig>,
+    semaphore: Arc<Semaphore>,
+    queued: Arc<AtomicUsize>,
+    total_rejected: Arc<AtomicU64>,
+    total_timed_out: Arc<AtomicU64>,
+}
+
+impl Bulkhead {
+    /// Create a new bulkhead with the given configuration
+    ///
+    /// A `max_concurrent` of zero is treated as one, since a bulkhead that admits
+    /// nothing would queue every call forever.
+    pub fn new(mut config: BulkheadConfig) -> Self {
+        config.max_concurrent = config.max_concurrent.max(1);
+
+        Self {
+            semaphore: Arc::new(Semaphore::new(config.max_concurrent)),
+            config: Arc::new(config),
+            queued: Arc::new(AtomicUsize::new(0)),
+            total_rejected: Arc::new(AtomicU64::new(0)),
+            total_timed_out: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Create a bulkhead admitting `max_concurrent` calls without a queue
+    pub fn with_capacity(max_concurrent: usize) -> Self {
+        Self::new(BulkheadConfig {
+            max_concurrent,
+            ..BulkheadConfig::default()
+        })
+    }
+
+    /// Execute an operation inside the bulkhead
+    ///
+    /// The operation only starts once a slot is free. If no slot is free the call
+    /// joins the queue, and fails with [`BulkheadError::Full`] when the queue is
+    /// full as well.
+    pub async fn execute<F, Fut, T, E>(&self, f: F) -> Result<T, BulkheadError<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let _permit = match self.semaphore.try_acquire() {
+            Ok(permit) => permit,
+            Err(TryAcquireError::NoPermits) => self.wait_for_permit().await?,
+            Err(TryAcquireError::Closed) => unreachable!("bulkhead semaphore is never closed"),
+        };
+
+        f().await.map_err(BulkheadError::Inner)
+    }
+
+    /// Number of calls currently executing
+    pub fn active(&self) -> usize {
+        self.config.max_concurrent - self.semaphore.available_permits()
+    }
+
+    /// Number of calls currently waiting for a slot
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Fraction of the concurrency limit in use, between 0.0 and 1.0
+    pub fn utilization(&self) -> f64 {
+        self.active() as f64 / self.config.max_concurrent as f64
+    }
+
+    /// Get current metrics
+    pub fn metrics(&self) -> BulkheadMetrics {
+        BulkheadMetrics {
+            max_concurrent: self.config.max_concurrent,
+            max_queued: self.config.max_queued,
+            active: self.active(),
+            queued: self.queued(),
+            utilization: self.utilization(),
+            total_rejected: self.total_rejected.load(Ordering::Relaxed),
+            total_timed_out: self.total_timed_out.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Get the bulkhead configuration
+    pub fn config(&self) -> &BulkheadConfig {
+        &self.config
+    }
+
+    async fn wait_for_permit<E>(&self) -> Result<tokio::sync::SemaphorePermit<'_>, BulkheadError<E>> {
+        let max_queued = self.config.max_queued;
+        if self
+            .queued
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                (queued < max_queued).then_some(queued + 1)
+            })
+            .is_err()
+        {
+            self.total_rejected.fetch_add(1, Ordering::Relaxed);
+            log::debug!("Bulkhead full, rejecting call");
+            return Err(BulkheadError::Full {
+                max_concurrent: self.config.max_concurrent,
+                max_queued,
+            });
+        }
+
+        // Leave the queue however waiting ends, including when the caller is cancelled
+        let _queued = QueueSlot(&self.queued);
+
+        let acquire = self.semaphore.acquire();
+        let permit = match self.config.queue_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, acquire).await {
+                Ok(permit) => permit,
+                Err(_) => {
+                    self.total_timed_out.fetch_add(1, Ordering::Relaxed);
+                    log::debug!("Bulkhead queue timeout after {:?}", timeout);
+                    return Err(BulkheadError::QueueTimeout(timeout));
+                }
+            },
+            None => acquire.await,
+        };
+
+        Ok(permit.expect("bulkhead semaphore is never closed"))
+    }
+}
+
+/// Releases a queue slot when dropped
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit_breaker::CircuitBreakerBuilder;
+    use crate::retry::{RetryExecutor, RetryPolicy};
+    use tokio::sync::oneshot;
+
+    #[derive(Debug, Clone)]
+    struct TestError;
+
+    impl std::fmt::Display for TestError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "test error")
+        }
+    }
+
+    impl Retryable for TestError {
+        fn is_retryable(&self) -> bool {
+            true
+        }
+    }
+
+    /// Occupy a bulkhead slot until the returned sender is dropped or fired
+    async fn occupy(bulkhead: &Bulkhead) -> (oneshot::Sender<()>, tokio::task::JoinHandle<()>) {
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let (started_tx, started_rx) = oneshot::channel::<()>();
+        let bulkhead = bulkhead.clone();
+        let handle = tokio::spawn(async move {
+            let _ = bulkhead
+                .execute(|| async move {
+                    let _ = started_tx.send(());
+                    let _ = release_rx.await;
+                    Ok::<_, TestError>(())
+                })
+                .await;
+        });
+        started_rx.await.unwrap();
+        (release_tx, handle)
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_rejects_when_full() {
+        let bulkhead = Bulkhead::with_capacity(2);
+
+        let (release_a, task_a) = occupy(&bulkhead).await;
+        let (release_b, task_b) = occupy(&bulkhead).await;
+        assert_eq!(bulkhead.active(), 2);
+        assert_eq!(bulkhead.utilization(), 1.0);
+
+        let result = bulkhead.execute(|| async { Ok::<_, TestError>(()) }).await;
+        assert!(matches!(
+            result,
+            Err(BulkheadError::Full {
+                max_concurrent: 2,
+                max_queued: 0
+            })
+        ));
+        assert_eq!(bulkhead.metrics().total_rejected, 1);
+
+        release_a.send(()).unwrap();
+        release_b.send(()).unwrap();
+        task_a.await.unwrap();
+        task_b.await.unwrap();
+
+        assert_eq!(bulkhead.active(), 0);
+        assert_eq!(bulkhead.execute(|| async { Ok::<_, TestError>(7) }).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_queued_call_runs_when_slot_frees() {
+        let bulkhead = Bulkhead::new(BulkheadConfig {
+            max_concurrent: 1,
+            max_queued: 1,
+            queue_timeout: None,
+        });
+
+        let (release, task) = occupy(&bulkhead).await;
+        let waiter = {
+            let bulkhead = bulkhead.clone();
+            tokio::spawn(async move { bulkhead.execute(|| async { Ok::<_, TestError>("ran") }).await })
+        };
+        while bulkhead.queued() == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        // Capacity plus queue is exhausted
+        let rejected = bulkhead.execute(|| async { Ok::<_, TestError>("rejected") }).await;
+        assert!(matches!(rejected, Err(BulkheadError::Full { .. })));
+
+        release.send(()).unwrap();
+        task.await.unwrap();
+        assert_eq!(waiter.await.unwrap().unwrap(), "ran");
+        assert_eq!(bulkhead.queued(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_queue_timeout() {
+        let bulkhead = Bulkhead::new(BulkheadConfig {
+            max_concurrent: 1,
+            max_queued: 1,
+            queue_timeout: Some(Duration::from_millis(20)),
+        });
+
+        let (release, task) = occupy(&bulkhead).await;
+
+        let result = bulkhead.execute(|| async { Ok::<_, TestError>(()) }).await;
+        assert!(matches!(result, Err(BulkheadError::QueueTimeout(_))));
+        assert_eq!(bulkhead.queued(), 0);
+        assert_eq!(bulkhead.metrics().total_timed_out, 1);
+
+        release.send(()).unwrap();
+        task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bulkhead_composes_with_retry_and_circuit_breaker() {
+        let bulkhead = Bulkhead::with_capacity(1);
+        let mut breaker = CircuitBreakerBuilder::new().build();
+        let executor = RetryExecutor::new(RetryPolicy::linear(5, Duration::from_millis(10)));
+
+        let (release, task) = occupy(&bulkhead).await;
+        let releaser = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(15)).await;
+            release.send(()).unwrap();
+        });
+
+        // Rejections are retried until the occupied slot frees up
+        let result = executor
+            .execute_with_circuit_breaker(
+                || bulkhead.execute(|| async { Ok::<_, TestError>("done") }),
+                &mut breaker,
+            )
+            .await;
+        assert_eq!(result.unwrap(), "done");
+        assert!(bulkhead.metrics().total_rejected >= 1);
+        assert_eq!(breaker.metrics().total_successes, 1);
+
+        releaser.await.unwrap();
+        task.await.unwrap();
+    }
+}
@@ -92,11 +92,26 @@ pub struct CircuitMetrics {
     pub last_state_change: Option<Instant>,
 }
 
+/// A transition between two circuit states
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitStateChange {
+    /// State before the transition
+    pub from: CircuitState,
+    /// State after the transition
+    pub to: CircuitState,
+    /// When the transition happened
+    pub at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Callback invoked on every circuit state transition
+pub type StateChangeListener = Arc<dyn Fn(&CircuitStateChange) + Send + Sync>;
+
 /// Thread-safe circuit breaker implementation
 #[derive(Clone)]
 pub struct CircuitBreaker {
     config: Arc<CircuitBreakerConfig>,
     state: Arc<Mutex<CircuitBreakerState>>,
+    listeners: Arc<Vec<StateChangeListener>>,
 }
 
 struct CircuitBreakerState {
@@ -115,6 +130,7 @@ impl CircuitBreaker {
                 metrics: CircuitMetrics::default(),
                 window_requests: Vec::new(),
             })),
+            listeners: Arc::new(Vec::new()),
         }
     }
 
@@ -125,81 +141,90 @@ impl CircuitBreaker {
 
     /// Check if the circuit breaker is open (requests should be blocked)
     pub fn is_open(&self) -> bool {
-        let mut state = self.state.lock();
-        self.update_state(&mut state);
-
-        match state.state {
-            CircuitState::Open => true,
-            CircuitState::HalfOpen => false,
-            CircuitState::Closed => false,
-        }
+        matches!(self.state(), CircuitState::Open)
     }
 
     /// Get the current state
     pub fn state(&self) -> CircuitState {
-        let mut state = self.state.lock();
-        self.update_state(&mut state);
-        state.state
+        let mut changes = Vec::new();
+        let current = {
+            let mut state = self.state.lock();
+            self.update_state(&mut state, &mut changes);
+            state.state
+        };
+
+        self.notify(changes);
+        current
     }
 
     /// Record a successful operation
     pub fn record_success(&self) {
-        let mut state = self.state.lock();
-        self.update_state(&mut state);
-
-        let now = Instant::now();
-        state.metrics.total_requests += 1;
-        state.metrics.total_successes += 1;
-        state.metrics.last_success_time = Some(now);
-        state.window_requests.push((now, true));
-
-        match state.state {
-            CircuitState::HalfOpen => {
-                state.metrics.consecutive_successes += 1;
-                state.metrics.consecutive_failures = 0;
-
-                if state.metrics.consecutive_successes >= self.config.success_threshold {
-                    self.transition_to_closed(&mut state);
+        let mut changes = Vec::new();
+        {
+            let mut state = self.state.lock();
+            self.update_state(&mut state, &mut changes);
+
+            let now = Instant::now();
+            state.metrics.total_requests += 1;
+            state.metrics.total_successes += 1;
+            state.metrics.last_success_time = Some(now);
+            state.window_requests.push((now, true));
+
+            match state.state {
+                CircuitState::HalfOpen => {
+                    state.metrics.consecutive_successes += 1;
+                    state.metrics.consecutive_failures = 0;
+
+                    if state.metrics.consecutive_successes >= self.config.success_threshold {
+                        self.transition_to_closed(&mut state, &mut changes);
+                    }
+                }
+                CircuitState::Closed => {
+                    state.metrics.consecutive_failures = 0;
+                }
+                CircuitState::Open => {
+                    // Shouldn't happen, but handle gracefully
                 }
             }
-            CircuitState::Closed => {
-                state.metrics.consecutive_failures = 0;
-            }
-            CircuitState::Open => {
-                // Shouldn't happen, but handle gracefully
-            }
+
+            self.clean_window(&mut state);
         }
 
-        self.clean_window(&mut state);
+        self.notify(changes);
     }
 
     /// Record a failed operation
     pub fn record_failure(&self) {
-        let mut state = self.state.lock();
-        self.update_state(&mut state);
-
-        let now = Instant::now();
-        state.metrics.total_requests += 1;
-        state.metrics.total_failures += 1;
-        state.metrics.last_failure_time = Some(now);
-        state.metrics.consecutive_failures += 1;
-        state.window_requests.push((now, false));
-
-        match state.state {
-            CircuitState::Closed => {
-                self.clean_window(&mut state);
-                if self.should_open(&state) {
-                    self.transition_to_open(&mut state);
+        let mut changes = Vec::new();
+        {
+            let mut state = self.state.lock();
+            self.update_state(&mut state, &mut changes);
+
+            let now = Instant::now();
+            state.metrics.total_requests += 1;
+            state.metrics.total_failures += 1;
+            state.metrics.last_failure_time = Some(now);
+            state.metrics.consecutive_failures += 1;
+            state.window_requests.push((now, false));
+
+            match state.state {
+                CircuitState::Closed => {
+                    self.clean_window(&mut state);
+                    if self.should_open(&state) {
+                        self.transition_to_open(&mut state, &mut changes);
+                    }
+                }
+                CircuitState::HalfOpen => {
+                    state.metrics.consecutive_successes = 0;
+                    self.transition_to_open(&mut state, &mut changes);
+                }
+                CircuitState::Open => {
+                    // Already open, no action needed
                 }
-            }
-            CircuitState::HalfOpen => {
-                state.metrics.consecutive_successes = 0;
-                self.transition_to_open(&mut state);
-            }
-            CircuitState::Open => {
-                // Already open, no action needed
             }
         }
+
+        self.notify(changes);
     }
 
     /// Record a rejected request (due to open circuit)
@@ -216,25 +241,56 @@ impl CircuitBreaker {
 
     /// Reset the circuit breaker to closed state
     pub fn reset(&self) {
-        let mut state = self.state.lock();
-        state.state = CircuitState::Closed;
-        state.metrics = CircuitMetrics::default();
-        state.window_requests.clear();
-        state.metrics.last_state_change = Some(Instant::now());
+        let mut changes = Vec::new();
+        {
+            let mut state = self.state.lock();
+            if state.state != CircuitState::Closed {
+                changes.push(CircuitStateChange {
+                    from: state.state,
+                    to: CircuitState::Closed,
+                    at: chrono::Utc::now(),
+                });
+            }
+            state.state = CircuitState::Closed;
+            state.metrics = CircuitMetrics::default();
+            state.window_requests.clear();
+            state.metrics.last_state_change = Some(Instant::now());
+        }
+
+        self.notify(changes);
     }
 
     // Internal methods
 
-    fn update_state(&self, state: &mut CircuitBreakerState) {
+    fn update_state(&self, state: &mut CircuitBreakerState, changes: &mut Vec<CircuitStateChange>) {
         if state.state == CircuitState::Open {
             if let Some(last_failure) = state.metrics.last_failure_time {
                 if last_failure.elapsed() >= self.config.timeout {
-                    self.transition_to_half_open(state);
+                    self.transition_to_half_open(state, changes);
                 }
             }
         }
     }
 
+    /// Deliver state changes to listeners; must be called after the state lock is released
+    fn notify(&self, changes: Vec<CircuitStateChange>) {
+        for change in &changes {
+            for listener in self.listeners.iter() {
+                listener(change);
+            }
+        }
+    }
+
+    fn record_transition(state: &mut CircuitBreakerState, to: CircuitState, changes: &mut Vec<CircuitStateChange>) {
+        changes.push(CircuitStateChange {
+            from: state.state,
+            to,
+            at: chrono::Utc::now(),
+        });
+        state.state = to;
+        state.metrics.last_state_change = Some(Instant::now());
+    }
+
     fn should_open(&self, state: &CircuitBreakerState) -> bool {
         let window_failures = state.window_requests.iter().filter(|(_, success)| !success).count() as u32;
 
@@ -248,27 +304,24 @@ impl CircuitBreaker {
         state.window_requests.retain(|(timestamp, _)| *timestamp > cutoff);
     }
 
-    fn transition_to_open(&self, state: &mut CircuitBreakerState) {
-        state.state = CircuitState::Open;
-        state.metrics.last_state_change = Some(Instant::now());
+    fn transition_to_open(&self, state: &mut CircuitBreakerState, changes: &mut Vec<CircuitStateChange>) {
+        Self::record_transition(state, CircuitState::Open, changes);
         log::warn!(
             "Circuit breaker opened after {} consecutive failures",
             state.metrics.consecutive_failures
         );
     }
 
-    fn transition_to_closed(&self, state: &mut CircuitBreakerState) {
-        state.state = CircuitState::Closed;
+    fn transition_to_closed(&self, state: &mut CircuitBreakerState, changes: &mut Vec<CircuitStateChange>) {
+        Self::record_transition(state, CircuitState::Closed, changes);
         state.metrics.consecutive_failures = 0;
         state.metrics.consecutive_successes = 0;
-        state.metrics.last_state_change = Some(Instant::now());
         log::info!("Circuit breaker closed after successful recovery");
     }
 
-    fn transition_to_half_open(&self, state: &mut CircuitBreakerState) {
-        state.state = CircuitState::HalfOpen;
+    fn transition_to_half_open(&self, state: &mut CircuitBreakerState, changes: &mut Vec<CircuitStateChange>) {
+        Self::record_transition(state, CircuitState::HalfOpen, changes);
         state.metrics.consecutive_successes = 0;
-        state.metrics.last_state_change = Some(Instant::now());
         log::info!("Circuit breaker transitioned to half-open state");
     }
 }
@@ -276,6 +329,7 @@ impl CircuitBreaker {
 /// Builder for circuit breaker configuration
 pub struct CircuitBreakerBuilder {
     config: CircuitBreakerConfig,
+    listeners: Vec<StateChangeListener>,
 }
 
 impl CircuitBreakerBuilder {
@@ -283,6 +337,7 @@ impl CircuitBreakerBuilder {
     pub fn new() -> Self {
         Self {
             config: CircuitBreakerConfig::default(),
+            listeners: Vec::new(),
         }
     }
 
@@ -316,9 +371,23 @@ impl CircuitBreakerBuilder {
         self
     }
 
+    /// Register a callback fired on every state transition
+    ///
+    /// Callbacks run on the thread that caused the transition, after the breaker's
+    /// internal lock has been released, so they may call back into the breaker.
+    pub fn on_state_change<F>(mut self, listener: F) -> Self
+    where
+        F: Fn(&CircuitStateChange) + Send + Sync + 'static,
+    {
+        self.listeners.push(Arc::new(listener));
+        self
+    }
+
     /// Build the circuit breaker
     pub fn build(self) -> CircuitBreaker {
-        CircuitBreaker::new(self.config)
+        let mut breaker = CircuitBreaker::new(self.config);
+        breaker.listeners = Arc::new(self.listeners);
+        breaker
     }
 }
 
@@ -406,6 +475,55 @@ mod tests {
         assert_eq!(metrics.total_rejected, 1);
     }
 
+    #[test]
+    fn test_state_change_listener() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let breaker = {
+            let changes = changes.clone();
+            CircuitBreakerBuilder::new()
+                .failure_threshold(1)
+                .success_threshold(1)
+                .timeout(Duration::from_millis(50))
+                .min_requests(1)
+                .on_state_change(move |change| changes.lock().push((change.from, change.to)))
+                .build()
+        };
+
+        breaker.record_failure();
+        thread::sleep(Duration::from_millis(100));
+        breaker.record_success();
+        breaker.record_success();
+
+        assert_eq!(
+            *changes.lock(),
+            vec![
+                (CircuitState::Closed, CircuitState::Open),
+                (CircuitState::Open, CircuitState::HalfOpen),
+                (CircuitState::HalfOpen, CircuitState::Closed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_state_change_listener_can_reenter_breaker() {
+        let handle = Arc::new(std::sync::OnceLock::<CircuitBreaker>::new());
+        let observed = Arc::new(Mutex::new(None));
+        let breaker = {
+            let handle = handle.clone();
+            let observed = observed.clone();
+            CircuitBreakerBuilder::new()
+                .failure_threshold(1)
+                .min_requests(1)
+                // Querying the breaker here would deadlock if listeners ran under its lock
+                .on_state_change(move |_| *observed.lock() = handle.get().map(|breaker| breaker.state()))
+                .build()
+        };
+        handle.set(breaker.clone()).ok();
+
+        breaker.record_failure();
+        assert_eq!(*observed.lock(), Some(CircuitState::Open));
+    }
+
     #[test]
     fn test_circuit_breaker_window() {
         let breaker = CircuitBreakerBuilder::new()
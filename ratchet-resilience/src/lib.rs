@@ -1,17 +1,25 @@
 //! Resilience patterns for Ratchet
 //!
 //! This crate provides resilience patterns including retry policies,
-//! circuit breakers, and graceful shutdown coordination.
+//! circuit breakers, bulkheads, composable resilience policies, and graceful
+//! shutdown coordination.
 
 pub mod backoff;
+pub mod bulkhead;
 pub mod circuit_breaker;
+pub mod policy;
 pub mod retry;
 pub mod shutdown;
 
 // Re-export commonly used types
 pub use backoff::{BackoffCalculator, BackoffStrategy, DecorrelatedJitterCalculator};
-pub use circuit_breaker::{CircuitBreaker, CircuitBreakerBuilder, CircuitBreakerConfig, CircuitState};
+pub use bulkhead::{Bulkhead, BulkheadConfig, BulkheadError, BulkheadMetrics};
+pub use circuit_breaker::{
+    CircuitBreaker, CircuitBreakerBuilder, CircuitBreakerConfig, CircuitState, CircuitStateChange, StateChangeListener,
+};
+pub use policy::{ResilienceError, ResiliencePolicy, ResiliencePolicyBuilder};
 pub use retry::{RetryError, RetryExecutor, RetryPolicy, Retryable};
 pub use shutdown::{
-    GracefulTask, ProcessShutdownManager, ShutdownAwareTask, ShutdownCoordinator, ShutdownError, ShutdownSignal,
+    GracefulTask, ProcessShutdownManager, ShutdownAwareTask, ShutdownCoordinator, ShutdownDrainSummary, ShutdownError,
+    ShutdownSignal,
 };
@@ -0,0 +1,555 @@
+//! Composable resilience policies
+//!
+//! A [`ResiliencePolicy`] stacks retry, circuit breaker, timeout and bulkhead layers
+//! around an operation and exposes a single [`ResiliencePolicy::execute`] entry point.
+//!
+//! Layers wrap each other in the order they are added to the builder: the first
+//! layer added is the outermost. A typical stack is
+//!
+//! ```text
+//! retry → circuit breaker → timeout → bulkhead → operation
+//! ```
+//!
+//! ## Failure propagation
+//!
+//! Every layer reports failures as a [`ResilienceError`], and errors from inner
+//! layers pass through outer layers unchanged unless stated otherwise:
+//!
+//! - **Operation**: an error returned by the operation becomes
+//!   [`ResilienceError::Operation`].
+//! - **Timeout**: when the layers below take longer than the timeout they are
+//!   cancelled and [`ResilienceError::Timeout`] is returned.
+//! - **Bulkhead**: when capacity and queue are exhausted the layers below are not
+//!   run and [`ResilienceError::BulkheadFull`] (or
+//!   [`ResilienceError::BulkheadQueueTimeout`]) is returned.
+//! - **Circuit breaker**: while open, the layers below are not run and
+//!   [`ResilienceError::CircuitOpen`] is returned. Operation errors and timeouts
+//!   from below count as failures; rejections (an inner open circuit or a full
+//!   bulkhead) are passed through without being counted, since they say nothing
+//!   about the health of the downstream.
+//! - **Retry**: errors that are [`Retryable`] are retried according to the
+//!   [`RetryPolicy`]. Once attempts run out, or on a non-retryable error, the last
+//!   error from below is returned as is. Timeouts and bulkhead rejections are
+//!   retryable; an open circuit is not.
+//!
+//! Placing retry outside the circuit breaker means every attempt is counted by the
+//! breaker and retrying stops as soon as it opens. Placing it inside means a whole
+//! retry sequence counts as a single call. Likewise a timeout outside the bulkhead
+//! includes time spent queueing, while a timeout inside it only bounds the
+//! operation itself.
+
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::bulkhead::{Bulkhead, BulkheadError};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::retry::{RetryError, RetryExecutor, RetryPolicy, Retryable};
+
+/// Error returned by a [`ResiliencePolicy`]
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ResilienceError<E> {
+    /// The operation itself failed
+    #[error("{0}")]
+    Operation(E),
+
+    /// The timeout layer expired before the operation completed
+    #[error("Operation timed out after {0:?}")]
+    Timeout(Duration),
+
+    /// The circuit breaker rejected the call
+    #[error("Circuit breaker is open")]
+    CircuitOpen,
+
+    /// The bulkhead concurrency limit and queue are both exhausted
+    #[error("Bulkhead is full ({max_concurrent} active, {max_queued} queued)")]
+    BulkheadFull { max_concurrent: usize, max_queued: usize },
+
+    /// The call waited in the bulkhead queue longer than the queue timeout
+    #[error("Timed out after {0:?} waiting for bulkhead capacity")]
+    BulkheadQueueTimeout(Duration),
+}
+
+impl<E> ResilienceError<E> {
+    /// Get the operation error if the operation ran and failed
+    pub fn into_inner(self) -> Option<E> {
+        match self {
+            ResilienceError::Operation(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Check if the call was rejected without running the operation
+    pub fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            ResilienceError::CircuitOpen
+                | ResilienceError::BulkheadFull { .. }
+                | ResilienceError::BulkheadQueueTimeout(_)
+        )
+    }
+}
+
+impl<E: Retryable> Retryable for ResilienceError<E> {
+    fn is_retryable(&self) -> bool {
+        match self {
+            ResilienceError::Operation(error) => error.is_retryable(),
+            ResilienceError::Timeout(_)
+            | ResilienceError::BulkheadFull { .. }
+            | ResilienceError::BulkheadQueueTimeout(_) => true,
+            ResilienceError::CircuitOpen => false,
+        }
+    }
+
+    fn is_transient(&self) -> bool {
+        match self {
+            ResilienceError::Operation(error) => error.is_transient(),
+            _ => false,
+        }
+    }
+
+    fn retry_delay(&self) -> Option<Duration> {
+        match self {
+            ResilienceError::Operation(error) => error.retry_delay(),
+            _ => None,
+        }
+    }
+}
+
+impl<E> From<BulkheadError<ResilienceError<E>>> for ResilienceError<E> {
+    fn from(error: BulkheadError<ResilienceError<E>>) -> Self {
+        match error {
+            BulkheadError::Full {
+                max_concurrent,
+                max_queued,
+            } => ResilienceError::BulkheadFull {
+                max_concurrent,
+                max_queued,
+            },
+            BulkheadError::QueueTimeout(timeout) => ResilienceError::BulkheadQueueTimeout(timeout),
+            BulkheadError::Inner(error) => error,
+        }
+    }
+}
+
+impl<E> From<RetryError<ResilienceError<E>>> for ResilienceError<E> {
+    fn from(error: RetryError<ResilienceError<E>>) -> Self {
+        match error {
+            RetryError::MaxAttemptsExceeded { last_error, .. } | RetryError::DeadlineExceeded { last_error, .. } => {
+                last_error
+            }
+            RetryError::NonRetryableError(error) => error,
+            RetryError::CircuitBreakerOpen => ResilienceError::CircuitOpen,
+        }
+    }
+}
+
+/// A single layer of a resilience policy
+#[derive(Clone)]
+enum Layer {
+    Retry(RetryPolicy),
+    CircuitBreaker(CircuitBreaker),
+    Timeout(Duration),
+    Bulkhead(Bulkhead),
+}
+
+/// Stack of resilience layers applied around an operation
+///
+/// Cloning a policy shares circuit breaker and bulkhead state between the clones.
+#[derive(Clone, Default)]
+pub struct ResiliencePolicy {
+    layers: Vec<Layer>,
+}
+
+impl ResiliencePolicy {
+    /// Start building a policy
+    pub fn builder() -> ResiliencePolicyBuilder {
+        ResiliencePolicyBuilder::new()
+    }
+
+    /// Execute an operation through every layer of the policy
+    ///
+    /// The operation may be invoked several times when a retry layer is present.
+    pub async fn execute<F, Fut, T, E>(&self, op: F) -> Result<T, ResilienceError<E>>
+    where
+        F: Fn() -> Fut + Sync,
+        Fut: Future<Output = Result<T, E>> + Send,
+        T: Send,
+        E: Retryable + std::fmt::Display + Clone + Send,
+    {
+        run_layers(&self.layers, &op).await
+    }
+
+    /// Number of layers in the policy
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Whether the policy has no layers and runs operations directly
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
+
+fn run_layers<'a, F, Fut, T, E>(layers: &'a [Layer], op: &'a F) -> BoxFuture<'a, Result<T, ResilienceError<E>>>
+where
+    F: Fn() -> Fut + Sync,
+    Fut: Future<Output = Result<T, E>> + Send,
+    T: Send,
+    E: Retryable + std::fmt::Display + Clone + Send,
+{
+    let Some((layer, inner)) = layers.split_first() else {
+        return Box::pin(async move { op().await.map_err(ResilienceError::Operation) });
+    };
+
+    match layer {
+        Layer::Retry(policy) => Box::pin(async move {
+            RetryExecutor::new(policy.clone())
+                .execute(|| run_layers(inner, op))
+                .await
+                .map_err(ResilienceError::from)
+        }),
+        Layer::CircuitBreaker(breaker) => Box::pin(async move {
+            if breaker.is_open() {
+                breaker.record_rejection();
+                return Err(ResilienceError::CircuitOpen);
+            }
+
+            let result = run_layers(inner, op).await;
+            match &result {
+                Ok(_) => breaker.record_success(),
+                Err(error) if error.is_rejection() => {}
+                Err(_) => breaker.record_failure(),
+            }
+            result
+        }),
+        Layer::Timeout(timeout) => Box::pin(async move {
+            match tokio::time::timeout(*timeout, run_layers(inner, op)).await {
+                Ok(result) => result,
+                Err(_) => Err(ResilienceError::Timeout(*timeout)),
+            }
+        }),
+        Layer::Bulkhead(bulkhead) => Box::pin(async move {
+            bulkhead
+                .execute(|| run_layers(inner, op))
+                .await
+                .map_err(ResilienceError::from)
+        }),
+    }
+}
+
+/// Builder for [`ResiliencePolicy`]
+///
+/// Each call adds a layer inside the layers added before it.
+#[derive(Default)]
+pub struct ResiliencePolicyBuilder {
+    layers: Vec<Layer>,
+}
+
+impl ResiliencePolicyBuilder {
+    /// Create an empty builder
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Add a retry layer
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.layers.push(Layer::Retry(policy));
+        self
+    }
+
+    /// Add a circuit breaker layer
+    pub fn circuit_breaker(mut self, breaker: CircuitBreaker) -> Self {
+        self.layers.push(Layer::CircuitBreaker(breaker));
+        self
+    }
+
+    /// Add a timeout layer
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.layers.push(Layer::Timeout(timeout));
+        self
+    }
+
+    /// Add a bulkhead layer
+    pub fn bulkhead(mut self, bulkhead: Bulkhead) -> Self {
+        self.layers.push(Layer::Bulkhead(bulkhead));
+        self
+    }
+
+    /// Build the policy
+    pub fn build(self) -> ResiliencePolicy {
+        ResiliencePolicy { layers: self.layers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backoff::BackoffStrategy;
+    use crate::circuit_breaker::{CircuitBreakerBuilder, CircuitState};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use tokio::sync::oneshot;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestError {
+        retryable: bool,
+    }
+
+    impl std::fmt::Display for TestError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "test error (retryable: {})", self.retryable)
+        }
+    }
+
+    impl Retryable for TestError {
+        fn is_retryable(&self) -> bool {
+            self.retryable
+        }
+    }
+
+    fn fast_retry(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            backoff_strategy: BackoffStrategy::Fixed,
+            jitter: false,
+        }
+    }
+
+    fn breaker(failure_threshold: u32) -> CircuitBreaker {
+        CircuitBreakerBuilder::new()
+            .failure_threshold(failure_threshold)
+            .min_requests(1)
+            .timeout(Duration::from_secs(60))
+            .build()
+    }
+
+    /// Operation failing `failures` times before succeeding
+    fn flaky(calls: &Arc<AtomicU32>, failures: u32) -> impl Fn() -> BoxFuture<'static, Result<u32, TestError>> + Sync {
+        let calls = calls.clone();
+        move || {
+            let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Box::pin(async move {
+                if call <= failures {
+                    Err(TestError { retryable: true })
+                } else {
+                    Ok(call)
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_policy_runs_operation() {
+        let policy = ResiliencePolicy::builder().build();
+        assert!(policy.is_empty());
+
+        let ok: Result<u32, ResilienceError<TestError>> = policy.execute(|| async { Ok(1) }).await;
+        assert_eq!(ok.unwrap(), 1);
+
+        let err: Result<u32, _> = policy.execute(|| async { Err(TestError { retryable: true }) }).await;
+        assert!(matches!(err, Err(ResilienceError::Operation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retry_outside_circuit_breaker() {
+        // Every attempt is recorded; retrying stops once the breaker opens
+        let breaker = breaker(2);
+        let policy = ResiliencePolicy::builder()
+            .retry(fast_retry(5))
+            .circuit_breaker(breaker.clone())
+            .build();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let result = policy.execute(flaky(&calls, u32::MAX)).await;
+
+        assert!(matches!(result, Err(ResilienceError::CircuitOpen)));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert_eq!(breaker.metrics().total_failures, 2);
+        assert_eq!(breaker.metrics().total_rejected, 1);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_outside_retry() {
+        // A whole retry sequence counts as a single call
+        let breaker = breaker(2);
+        let policy = ResiliencePolicy::builder()
+            .circuit_breaker(breaker.clone())
+            .retry(fast_retry(3))
+            .build();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let result = policy.execute(flaky(&calls, 2)).await;
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(breaker.metrics().total_successes, 1);
+        assert_eq!(breaker.metrics().total_failures, 0);
+
+        let result = policy.execute(flaky(&calls, u32::MAX)).await;
+        assert!(matches!(
+            result,
+            Err(ResilienceError::Operation(TestError { retryable: true }))
+        ));
+        assert_eq!(breaker.metrics().total_failures, 1);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_error_propagates_unchanged() {
+        let policy = ResiliencePolicy::builder()
+            .retry(fast_retry(5))
+            .timeout(Duration::from_secs(1))
+            .build();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let counter = calls.clone();
+        let result: Result<(), _> = policy
+            .execute(|| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Err(TestError { retryable: false }) }
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ResilienceError::Operation(TestError { retryable: false }))
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_outside_timeout() {
+        // Each attempt gets its own timeout and timeouts are retried
+        let policy = ResiliencePolicy::builder()
+            .retry(fast_retry(3))
+            .timeout(Duration::from_millis(20))
+            .build();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let counter = calls.clone();
+        let result: Result<&str, ResilienceError<TestError>> = policy
+            .execute(|| {
+                let call = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    if call < 3 {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                    }
+                    Ok("done")
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_outside_retry() {
+        // The timeout bounds the whole retry sequence
+        let policy = ResiliencePolicy::builder()
+            .timeout(Duration::from_millis(30))
+            .retry(RetryPolicy::linear(100, Duration::from_millis(10)))
+            .build();
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let result = policy.execute(flaky(&calls, u32::MAX)).await;
+
+        assert!(matches!(result, Err(ResilienceError::Timeout(_))));
+        assert!(calls.load(Ordering::SeqCst) < 100);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_ignores_bulkhead_rejections() {
+        let breaker = breaker(1);
+        let bulkhead = Bulkhead::with_capacity(1);
+        let policy = ResiliencePolicy::builder()
+            .circuit_breaker(breaker.clone())
+            .bulkhead(bulkhead.clone())
+            .build();
+
+        // Hold the only bulkhead slot
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let (started_tx, started_rx) = oneshot::channel::<()>();
+        let holder = {
+            let bulkhead = bulkhead.clone();
+            tokio::spawn(async move {
+                bulkhead
+                    .execute(|| async move {
+                        let _ = started_tx.send(());
+                        let _ = release_rx.await;
+                        Ok::<_, TestError>(())
+                    })
+                    .await
+            })
+        };
+        started_rx.await.unwrap();
+
+        let result: Result<(), ResilienceError<TestError>> = policy.execute(|| async { Ok(()) }).await;
+        assert!(matches!(result, Err(ResilienceError::BulkheadFull { .. })));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.metrics().total_failures, 0);
+
+        release_tx.send(()).unwrap();
+        holder.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retry_outside_bulkhead() {
+        // Bulkhead rejections are retried until a slot frees up
+        let bulkhead = Bulkhead::with_capacity(1);
+        let policy = ResiliencePolicy::builder()
+            .retry(RetryPolicy::linear(10, Duration::from_millis(10)))
+            .bulkhead(bulkhead.clone())
+            .build();
+
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let (started_tx, started_rx) = oneshot::channel::<()>();
+        let holder = {
+            let bulkhead = bulkhead.clone();
+            tokio::spawn(async move {
+                bulkhead
+                    .execute(|| async move {
+                        let _ = started_tx.send(());
+                        let _ = release_rx.await;
+                        Ok::<_, TestError>(())
+                    })
+                    .await
+            })
+        };
+        started_rx.await.unwrap();
+        let releaser = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(25)).await;
+            release_tx.send(()).unwrap();
+        });
+
+        let result: Result<&str, ResilienceError<TestError>> = policy.execute(|| async { Ok("done") }).await;
+        assert_eq!(result.unwrap(), "done");
+        assert!(bulkhead.metrics().total_rejected >= 1);
+
+        releaser.await.unwrap();
+        holder.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_full_stack() {
+        let breaker = breaker(5);
+        let bulkhead = Bulkhead::with_capacity(4);
+        let policy = ResiliencePolicy::builder()
+            .retry(fast_retry(3))
+            .circuit_breaker(breaker.clone())
+            .timeout(Duration::from_secs(1))
+            .bulkhead(bulkhead.clone())
+            .build();
+        assert_eq!(policy.len(), 4);
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let result = policy.execute(flaky(&calls, 1)).await;
+
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(breaker.metrics().total_failures, 1);
+        assert_eq!(breaker.metrics().total_successes, 1);
+        assert_eq!(bulkhead.active(), 0);
+    }
+}
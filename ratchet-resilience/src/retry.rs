@@ -3,7 +3,7 @@
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use std::future::Future;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
 use crate::backoff::BackoffCalculator;
@@ -182,6 +182,63 @@ impl RetryExecutor {
         }
     }
 
+    /// Execute a function, retrying until `deadline` instead of up to `max_attempts`
+    ///
+    /// Backoff delays follow the policy as usual. Retrying stops as soon as the next
+    /// delay would end past the deadline, returning [`RetryError::DeadlineExceeded`]
+    /// with the last error.
+    pub async fn execute_until<F, Fut, T, E>(&self, deadline: Instant, mut f: F) -> Result<T, RetryError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Retryable + std::fmt::Display + Clone,
+    {
+        let mut attempt = 1;
+
+        loop {
+            debug!("Executing attempt {} before deadline", attempt);
+
+            match f().await {
+                Ok(result) => {
+                    if attempt > 1 {
+                        info!("Operation succeeded after {} attempts", attempt);
+                    }
+                    return Ok(result);
+                }
+                Err(error) => {
+                    if !error.is_retryable() {
+                        warn!("Operation failed with non-retryable error: {}", error);
+                        return Err(RetryError::NonRetryableError(error));
+                    }
+
+                    let delay = error
+                        .retry_delay()
+                        .unwrap_or_else(|| self.policy.delay_for_attempt(attempt));
+
+                    if Instant::now() + delay >= deadline {
+                        warn!(
+                            "Operation failed after {} attempts, deadline reached: {}",
+                            attempt, error
+                        );
+                        return Err(RetryError::DeadlineExceeded {
+                            attempts: attempt,
+                            last_error: error,
+                        });
+                    }
+
+                    if error.is_transient() && delay < Duration::from_millis(10) {
+                        debug!("Transient error, retrying immediately");
+                    } else {
+                        warn!("Attempt {} failed: {}. Retrying in {:?}", attempt, error, delay);
+                        sleep(delay).await;
+                    }
+
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
     /// Execute with a circuit breaker pattern
     pub async fn execute_with_circuit_breaker<F, Fut, T, E>(
         &self,
@@ -217,6 +274,10 @@ pub enum RetryError<E> {
     #[error("Maximum retry attempts ({attempts}) exceeded. Last error: {last_error}")]
     MaxAttemptsExceeded { attempts: u32, last_error: E },
 
+    /// Deadline reached before the operation succeeded
+    #[error("Retry deadline exceeded after {attempts} attempts. Last error: {last_error}")]
+    DeadlineExceeded { attempts: u32, last_error: E },
+
     /// Non-retryable error encountered
     #[error("Non-retryable error: {0}")]
     NonRetryableError(E),
@@ -231,6 +292,7 @@ impl<E> RetryError<E> {
     pub fn into_inner(self) -> Option<E> {
         match self {
             RetryError::MaxAttemptsExceeded { last_error, .. } => Some(last_error),
+            RetryError::DeadlineExceeded { last_error, .. } => Some(last_error),
             RetryError::NonRetryableError(error) => Some(error),
             RetryError::CircuitBreakerOpen => None,
         }
@@ -377,4 +439,87 @@ mod tests {
         assert_eq!(result.unwrap(), 3);
         assert_eq!(attempts.load(Ordering::Relaxed), 3);
     }
+
+    #[tokio::test]
+    async fn test_execute_until_stops_before_deadline() {
+        let attempts = Arc::new(AtomicU32::new(0));
+        let attempts_clone = attempts.clone();
+
+        // Far more attempts than fit before the deadline
+        let executor = RetryExecutor::new(RetryPolicy {
+            max_attempts: 1000,
+            initial_delay: Duration::from_millis(40),
+            max_delay: Duration::from_millis(40),
+            backoff_strategy: crate::backoff::BackoffStrategy::Fixed,
+            jitter: false,
+        });
+
+        let started = Instant::now();
+        let deadline = started + Duration::from_millis(150);
+        let result: Result<(), RetryError<TestError>> = executor
+            .execute_until(deadline, || {
+                attempts_clone.fetch_add(1, Ordering::Relaxed);
+                async {
+                    Err(TestError {
+                        retryable: true,
+                        message: "Still failing".to_string(),
+                    })
+                }
+            })
+            .await;
+
+        match result {
+            Err(RetryError::DeadlineExceeded {
+                attempts: count,
+                last_error,
+            }) => {
+                assert_eq!(count, attempts.load(Ordering::Relaxed));
+                assert_eq!(last_error.message, "Still failing");
+            }
+            other => panic!("expected deadline exceeded, got {:?}", other.err()),
+        }
+        assert!(attempts.load(Ordering::Relaxed) <= 4);
+        assert!(Instant::now() <= deadline);
+    }
+
+    #[tokio::test]
+    async fn test_execute_until_succeeds_and_respects_non_retryable() {
+        let executor = RetryExecutor::new(RetryPolicy {
+            max_attempts: 1,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            backoff_strategy: crate::backoff::BackoffStrategy::Fixed,
+            jitter: false,
+        });
+        let deadline = Instant::now() + Duration::from_secs(5);
+
+        // max_attempts doesn't limit deadline-based retries
+        let counter = AtomicU32::new(0);
+        let result = executor
+            .execute_until(deadline, || {
+                let count = counter.fetch_add(1, Ordering::Relaxed);
+                async move {
+                    if count < 3 {
+                        Err(TestError {
+                            retryable: true,
+                            message: "Temporary failure".to_string(),
+                        })
+                    } else {
+                        Ok(count)
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 3);
+
+        let result: Result<(), RetryError<TestError>> = executor
+            .execute_until(deadline, || async {
+                Err(TestError {
+                    retryable: false,
+                    message: "Non-retryable".to_string(),
+                })
+            })
+            .await;
+        assert!(matches!(result.unwrap_err(), RetryError::NonRetryableError(_)));
+    }
 }
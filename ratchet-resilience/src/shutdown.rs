@@ -4,13 +4,15 @@
 //! task tracking, and process management.
 
 use log::{error, info, warn};
+use serde::Serialize;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{broadcast, RwLock};
 use tokio::time::timeout;
 
 /// Shutdown signal types with escalating urgency
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ShutdownSignal {
     /// Graceful shutdown - allow current tasks to complete
     Graceful,
@@ -30,13 +32,39 @@ impl std::fmt::Display for ShutdownSignal {
     }
 }
 
+/// Outcome of draining active tasks during a shutdown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShutdownDrainSummary {
+    /// Tasks still active when shutdown started
+    pub in_flight_at_start: u32,
+    /// Tasks that finished while draining
+    pub completed: u32,
+    /// Tasks still active when shutdown finished
+    pub remaining: u32,
+    /// Most urgent signal that had to be sent
+    pub final_phase: ShutdownSignal,
+    /// Time spent draining
+    #[serde(with = "humantime_serde")]
+    pub duration: Duration,
+}
+
+impl ShutdownDrainSummary {
+    /// Whether any task had to be force-terminated
+    pub fn force_terminated(&self) -> bool {
+        self.final_phase == ShutdownSignal::Forced && self.remaining > 0
+    }
+}
+
 /// Graceful shutdown coordinator
 pub struct ShutdownCoordinator {
     sender: broadcast::Sender<ShutdownSignal>,
     is_shutting_down: Arc<RwLock<bool>>,
     active_tasks: Arc<RwLock<u32>>,
+    /// Tasks that finished since shutdown started
+    drained_tasks: Arc<RwLock<u32>>,
     graceful_timeout: Duration,
     urgent_timeout: Duration,
+    drain_summary: Arc<RwLock<Option<ShutdownDrainSummary>>>,
 }
 
 impl ShutdownCoordinator {
@@ -53,8 +81,10 @@ impl ShutdownCoordinator {
             sender,
             is_shutting_down: Arc::new(RwLock::new(false)),
             active_tasks: Arc::new(RwLock::new(0)),
+            drained_tasks: Arc::new(RwLock::new(0)),
             graceful_timeout,
             urgent_timeout,
+            drain_summary: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -79,6 +109,9 @@ impl ShutdownCoordinator {
         let mut count = self.active_tasks.write().await;
         if *count > 0 {
             *count -= 1;
+            if *self.is_shutting_down.read().await {
+                *self.drained_tasks.write().await += 1;
+            }
         }
     }
 
@@ -87,6 +120,11 @@ impl ShutdownCoordinator {
         *self.active_tasks.read().await
     }
 
+    /// Drain summary of the last completed shutdown
+    pub async fn drain_summary(&self) -> Option<ShutdownDrainSummary> {
+        self.drain_summary.read().await.clone()
+    }
+
     /// Initiate graceful shutdown with escalating urgency
     pub async fn shutdown(&self) -> Result<(), ShutdownError> {
         // Prevent multiple simultaneous shutdowns
@@ -98,7 +136,9 @@ impl ShutdownCoordinator {
             *shutting_down = true;
         }
 
-        info!("Starting graceful shutdown");
+        let started = tokio::time::Instant::now();
+        let in_flight_at_start = self.active_task_count().await;
+        info!("Starting graceful shutdown with {} active tasks", in_flight_at_start);
 
         // Phase 1: Graceful shutdown
         self.sender
@@ -107,6 +147,8 @@ impl ShutdownCoordinator {
 
         if self.wait_for_tasks(self.graceful_timeout).await {
             info!("Graceful shutdown completed successfully");
+            self.record_drain(in_flight_at_start, ShutdownSignal::Graceful, started)
+                .await;
             return Ok(());
         }
 
@@ -118,6 +160,8 @@ impl ShutdownCoordinator {
 
         if self.wait_for_tasks(self.urgent_timeout).await {
             info!("Urgent shutdown completed");
+            self.record_drain(in_flight_at_start, ShutdownSignal::Urgent, started)
+                .await;
             return Ok(());
         }
 
@@ -130,16 +174,51 @@ impl ShutdownCoordinator {
         // Give a brief moment for forced shutdown to take effect
         tokio::time::sleep(Duration::from_millis(500)).await;
 
-        let remaining_tasks = self.active_task_count().await;
-        if remaining_tasks > 0 {
-            warn!("Forced shutdown completed with {} tasks still active", remaining_tasks);
-            Err(ShutdownError::TasksRemaining(remaining_tasks))
+        let summary = self
+            .record_drain(in_flight_at_start, ShutdownSignal::Forced, started)
+            .await;
+        if summary.remaining > 0 {
+            warn!(
+                "Forced shutdown completed with {} tasks still active",
+                summary.remaining
+            );
+            Err(ShutdownError::TasksRemaining(summary.remaining))
         } else {
             info!("Forced shutdown completed successfully");
             Ok(())
         }
     }
 
+    /// Log and store the drain summary for a finished shutdown
+    async fn record_drain(
+        &self,
+        in_flight_at_start: u32,
+        final_signal: ShutdownSignal,
+        started: tokio::time::Instant,
+    ) -> ShutdownDrainSummary {
+        let remaining = self.active_task_count().await;
+        let summary = ShutdownDrainSummary {
+            in_flight_at_start,
+            completed: *self.drained_tasks.read().await,
+            remaining,
+            final_phase: final_signal,
+            duration: started.elapsed(),
+        };
+
+        info!(
+            "Shutdown drain summary: in_flight_at_start={} completed={} remaining={} final_phase={} duration_ms={} force_terminated={}",
+            summary.in_flight_at_start,
+            summary.completed,
+            summary.remaining,
+            final_signal,
+            summary.duration.as_millis(),
+            summary.force_terminated()
+        );
+
+        *self.drain_summary.write().await = Some(summary.clone());
+        summary
+    }
+
     /// Wait for all tasks to complete within the given timeout
     async fn wait_for_tasks(&self, timeout_duration: Duration) -> bool {
         let start = tokio::time::Instant::now();
@@ -579,4 +658,60 @@ mod tests {
         assert!(result.is_ok());
         assert!(counter.load(Ordering::Relaxed) >= 5);
     }
+
+    #[tokio::test]
+    async fn test_drain_summary_counts_task_finished_during_shutdown() {
+        let coordinator = Arc::new(ShutdownCoordinator::with_timeouts(
+            Duration::from_secs(5),
+            Duration::from_millis(50),
+        ));
+        let _receiver = coordinator.subscribe();
+        assert!(coordinator.drain_summary().await.is_none());
+
+        coordinator.task_started().await;
+        let worker = coordinator.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            worker.task_completed().await;
+        });
+
+        coordinator.shutdown().await.unwrap();
+
+        let summary = coordinator.drain_summary().await.unwrap();
+        assert_eq!(summary.in_flight_at_start, 1);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.remaining, 0);
+        assert_eq!(summary.final_phase, ShutdownSignal::Graceful);
+        assert!(summary.duration >= Duration::from_millis(100));
+        assert!(!summary.force_terminated());
+    }
+
+    #[tokio::test]
+    async fn test_drain_summary_counts_completions_despite_late_starters() {
+        let coordinator = Arc::new(ShutdownCoordinator::with_timeouts(
+            Duration::from_millis(200),
+            Duration::from_millis(50),
+        ));
+        let _receiver = coordinator.subscribe();
+
+        // One task finishes while draining, but another starts and never finishes
+        coordinator.task_started().await;
+        let worker = coordinator.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            worker.task_started().await;
+            worker.task_completed().await;
+        });
+
+        assert!(matches!(
+            coordinator.shutdown().await,
+            Err(ShutdownError::TasksRemaining(1))
+        ));
+
+        let summary = coordinator.drain_summary().await.unwrap();
+        assert_eq!(summary.in_flight_at_start, 1);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.remaining, 1);
+        assert!(summary.force_terminated());
+    }
 }
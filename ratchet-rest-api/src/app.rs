@@ -2,14 +2,16 @@
 
 use axum::{
     response::{Html, IntoResponse, Json},
-    routing::{get, post},
+    routing::{delete, get, post},
     Router,
 };
 use ratchet_interfaces::{RegistryManager, RepositoryFactory, TaskRegistry, TaskValidator};
 use ratchet_web::middleware::{
-    audit_middleware, cors_layer, create_rate_limit_middleware, create_session_manager, error_handler_layer,
-    rate_limit_middleware, request_id_layer, security_headers_middleware, session_middleware, AuditConfig,
-    RateLimitConfig, SecurityConfig, SessionConfig,
+    audit_middleware, body_limit_layer, compression_layer, cors_layer, create_idempotency_store,
+    create_rate_limit_middleware, create_session_manager, error_handler_layer, etag_middleware, idempotency_middleware,
+    optional_auth_middleware, rate_limit_middleware, request_id_layer, security_headers_middleware, session_middleware,
+    AuditConfig, AuthConfig, CompressionConfig, IdempotencyConfig, JwtManager, RateLimitConfig, SecurityConfig,
+    SessionConfig, DEFAULT_MAX_BODY_BYTES,
 };
 use std::sync::Arc;
 use tower_http::trace::TraceLayer;
@@ -37,6 +39,18 @@ pub struct AppConfig {
     pub enable_rate_limiting: bool,
     /// Enable session management
     pub enable_session_management: bool,
+    /// Enable ETags and `If-None-Match` handling on GET responses
+    pub enable_etags: bool,
+    /// Largest request body accepted, in bytes
+    pub max_body_bytes: usize,
+    /// Enable response compression
+    pub enable_compression: bool,
+    /// Response compression configuration
+    pub compression_config: CompressionConfig,
+    /// Replay responses to POST requests retried with the same `Idempotency-Key`
+    pub enable_idempotency: bool,
+    /// Idempotency key configuration
+    pub idempotency_config: IdempotencyConfig,
     /// Security configuration
     pub security_config: SecurityConfig,
     /// Audit configuration
@@ -45,6 +59,8 @@ pub struct AppConfig {
     pub rate_limit_config: RateLimitConfig,
     /// Session management configuration
     pub session_config: SessionConfig,
+    /// Identify callers by JWT or API key (no credential checks when `None`)
+    pub auth_config: Option<AuthConfig>,
     /// API path prefix
     pub api_prefix: String,
 }
@@ -59,10 +75,17 @@ impl Default for AppConfig {
             enable_audit_logging: true,
             enable_rate_limiting: true,
             enable_session_management: true,
+            enable_etags: true,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            enable_compression: true,
+            compression_config: CompressionConfig::default(),
+            enable_idempotency: true,
+            idempotency_config: IdempotencyConfig::default(),
             security_config: SecurityConfig::development(),
             audit_config: AuditConfig::development(),
             rate_limit_config: RateLimitConfig::permissive(),
             session_config: SessionConfig::development(),
+            auth_config: None,
             api_prefix: "/api/v1".to_string(),
         }
     }
@@ -79,10 +102,17 @@ impl AppConfig {
             enable_audit_logging: true,
             enable_rate_limiting: true,
             enable_session_management: true,
+            enable_etags: true,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            enable_compression: true,
+            compression_config: CompressionConfig::default(),
+            enable_idempotency: true,
+            idempotency_config: IdempotencyConfig::default(),
             security_config: SecurityConfig::production(),
             audit_config: AuditConfig::production(),
             rate_limit_config: RateLimitConfig::strict(),
             session_config: SessionConfig::production(),
+            auth_config: None,
             api_prefix: "/api/v1".to_string(),
         }
     }
@@ -97,10 +127,17 @@ impl AppConfig {
             enable_audit_logging: true,
             enable_rate_limiting: true,
             enable_session_management: true,
+            enable_etags: true,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            enable_compression: true,
+            compression_config: CompressionConfig::default(),
+            enable_idempotency: true,
+            idempotency_config: IdempotencyConfig::default(),
             security_config: SecurityConfig::development(),
             audit_config: AuditConfig::development(),
             rate_limit_config: RateLimitConfig::permissive(),
             session_config: SessionConfig::development(),
+            auth_config: None,
             api_prefix: "/api/v1".to_string(),
         }
     }
@@ -135,6 +172,8 @@ impl AppContext {
 
 /// Create the complete REST API application
 pub fn create_rest_app(context: AppContext, config: AppConfig) -> Router<()> {
+    let caches = context.tasks.caches.clone();
+    let repositories = context.tasks.repositories.clone();
     let app = Router::new()
         // Health endpoints (no prefix) - need context for detailed checks
         .route("/health", get(handlers::health::health_check))
@@ -155,6 +194,28 @@ pub fn create_rest_app(context: AppContext, config: AppConfig) -> Router<()> {
     // Add middleware layers (applied in reverse order)
     let mut app = app;
 
+    // Conditional GET (innermost, so it sees handler-set ETags and final bodies)
+    if config.enable_etags {
+        app = app.layer(axum::middleware::from_fn(etag_middleware));
+    }
+
+    // Idempotency keys (inside the body limit, so only bounded bodies are buffered)
+    if config.enable_idempotency {
+        let idempotency_store = create_idempotency_store(config.idempotency_config.clone());
+        if let Some(caches) = &caches {
+            idempotency_store.register_cache(caches, "idempotency_keys");
+        }
+        app = app.layer(axum::middleware::from_fn(
+            move |mut req: axum::http::Request<axum::body::Body>, next: axum::middleware::Next| {
+                let idempotency_store = idempotency_store.clone();
+                async move {
+                    req.extensions_mut().insert(idempotency_store);
+                    idempotency_middleware(req, next).await
+                }
+            },
+        ));
+    }
+
     // Security headers (applied first, affects all responses)
     if config.enable_security_headers {
         let security_config = config.security_config.clone();
@@ -169,9 +230,15 @@ pub fn create_rest_app(context: AppContext, config: AppConfig) -> Router<()> {
         ));
     }
 
+    // Body size limit (after rate limiting, so rejected uploads still count against the client)
+    app = app.layer(body_limit_layer(config.max_body_bytes));
+
     // Rate limiting (applied early to prevent abuse)
     if config.enable_rate_limiting {
         let rate_limiter = create_rate_limit_middleware(config.rate_limit_config.clone());
+        if let Some(caches) = &caches {
+            rate_limiter.register_cache(caches, "rate_limit_clients");
+        }
         app = app.layer(axum::middleware::from_fn(
             move |req: axum::http::Request<axum::body::Body>, next: axum::middleware::Next| {
                 let rate_limiter = rate_limiter.clone();
@@ -192,6 +259,21 @@ pub fn create_rest_app(context: AppContext, config: AppConfig) -> Router<()> {
         ));
     }
 
+    // Authentication (before rate limiting, so API keys and users get their own quotas)
+    if let Some(auth_config) = &config.auth_config {
+        let jwt_manager = Arc::new(JwtManager::new_with_repositories(auth_config.clone(), repositories));
+        app = app.layer(axum::middleware::from_fn(
+            move |mut req: axum::http::Request<axum::body::Body>, next: axum::middleware::Next| {
+                let jwt_manager = jwt_manager.clone();
+                async move {
+                    let headers = req.headers().clone();
+                    req.extensions_mut().insert(jwt_manager);
+                    optional_auth_middleware(headers, req, next).await
+                }
+            },
+        ));
+    }
+
     // Session management (applied after rate limiting but before other middleware)
     if config.enable_session_management {
         let session_manager = create_session_manager(config.session_config.clone());
@@ -231,6 +313,11 @@ pub fn create_rest_app(context: AppContext, config: AppConfig) -> Router<()> {
         app = app.layer(cors_layer());
     }
 
+    // Response compression (outside the ETag layer, which tags uncompressed bodies)
+    if config.enable_compression {
+        app = app.layer(compression_layer(&config.compression_config));
+    }
+
     // Request ID tracking
     if config.enable_request_id {
         app = app.layer(request_id_layer());
@@ -343,6 +430,15 @@ fn create_api_router() -> Router<TasksContext> {
         .route("/auth/me", get(handlers::auth::get_current_user))
         .route("/auth/logout", post(handlers::auth::logout))
         .route("/auth/change-password", post(handlers::auth::change_password))
+        // Admin endpoints (admin privileges required)
+        .route("/admin/caches", get(handlers::admin::list_caches))
+        .route("/admin/caches/{name}/flush", post(handlers::admin::flush_cache))
+        .route(
+            "/admin/caches/{name}/keys/{key}",
+            delete(handlers::admin::flush_cache_key),
+        )
+        .route("/admin/plugins", get(handlers::admin::list_plugins))
+        .route("/audit", get(handlers::admin::export_audit_log))
         // Task endpoints
         .route(
             "/tasks",
@@ -350,6 +446,8 @@ fn create_api_router() -> Router<TasksContext> {
         )
         .route("/tasks/stats", get(handlers::tasks::get_task_stats))
         .route("/tasks/sync", post(handlers::tasks::sync_tasks))
+        .route("/tasks/revalidate", post(handlers::tasks::revalidate_all_tasks))
+        .route("/tasks/batch", post(handlers::tasks::batch_tasks))
         .route(
             "/tasks/{id}",
             get(handlers::tasks::get_task)
@@ -358,6 +456,8 @@ fn create_api_router() -> Router<TasksContext> {
         )
         .route("/tasks/{id}/enable", post(handlers::tasks::enable_task))
         .route("/tasks/{id}/disable", post(handlers::tasks::disable_task))
+        .route("/tasks/{id}/preflight", post(handlers::tasks::preflight_task))
+        .route("/tasks/{id}/validate", post(handlers::tasks::validate_task))
         // Execution endpoints
         .route(
             "/executions",
@@ -373,9 +473,15 @@ fn create_api_router() -> Router<TasksContext> {
         .route("/executions/{id}/cancel", post(handlers::executions::cancel_execution))
         .route("/executions/{id}/retry", post(handlers::executions::retry_execution))
         .route("/executions/{id}/logs", get(handlers::executions::get_execution_logs))
+        .route(
+            "/executions/{id}/attachments/{name}",
+            get(handlers::executions::get_execution_attachment),
+        )
         // Job endpoints
         .route("/jobs", get(handlers::jobs::list_jobs).post(handlers::jobs::create_job))
         .route("/jobs/stats", get(handlers::jobs::get_job_stats))
+        .route("/jobs/dead-letter", get(handlers::jobs::list_dead_letter_jobs))
+        .route("/jobs/batch", post(handlers::jobs::batch_jobs))
         .route(
             "/jobs/{id}",
             get(handlers::jobs::get_job)
@@ -384,12 +490,18 @@ fn create_api_router() -> Router<TasksContext> {
         )
         .route("/jobs/{id}/cancel", post(handlers::jobs::cancel_job))
         .route("/jobs/{id}/retry", post(handlers::jobs::retry_job))
+        .route("/jobs/{id}/requeue", post(handlers::jobs::requeue_job))
         // Schedule endpoints
         .route(
             "/schedules",
             get(handlers::schedules::list_schedules).post(handlers::schedules::create_schedule),
         )
         .route("/schedules/stats", get(handlers::schedules::get_schedule_stats))
+        .route("/schedules/validate", post(handlers::schedules::validate_schedule))
+        .route("/schedules/pause-all", post(handlers::schedules::pause_all_schedules))
+        .route("/schedules/resume-all", post(handlers::schedules::resume_all_schedules))
+        .route("/schedules/enabled", post(handlers::schedules::set_schedules_enabled))
+        .route("/schedules/batch", post(handlers::schedules::batch_schedules))
         .route(
             "/schedules/{id}",
             get(handlers::schedules::get_schedule)
@@ -4,8 +4,14 @@
 //! for each REST API endpoint group. This enables clean dependency injection
 //! and makes testing easier with mock implementations.
 
-use ratchet_interfaces::{RegistryManager, RepositoryFactory, SchedulerService, TaskRegistry, TaskValidator};
+use ratchet_caching::CacheRegistry;
+pub use ratchet_config::domains::output::DEFAULT_MAX_OUTPUT_DESTINATIONS;
+use ratchet_interfaces::{
+    AuditLogExport, PluginInventory, RegistryManager, RepositoryFactory, SchedulerService, TaskRegistry, TaskValidator,
+};
 use ratchet_mcp::server::task_dev_tools::TaskDevelopmentService;
+use ratchet_storage::AttachmentStore;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 /// Context for task-related endpoints
@@ -25,6 +31,18 @@ pub struct TasksContext {
     pub mcp_task_service: Option<Arc<TaskDevelopmentService>>,
     /// Optional scheduler service for schedule management integration
     pub scheduler_service: Option<Arc<dyn SchedulerService>>,
+    /// Feature flag states reported read-only by the system info endpoint
+    pub features: BTreeMap<String, bool>,
+    /// Optional store for files attached to executions
+    pub attachments: Option<Arc<AttachmentStore>>,
+    /// Optional registry of caches exposed through the admin endpoints
+    pub caches: Option<Arc<CacheRegistry>>,
+    /// Optional audit log exported through the admin endpoints
+    pub audit: Option<Arc<dyn AuditLogExport>>,
+    /// Optional inventory of loaded plugins listed through the admin endpoints
+    pub plugins: Option<Arc<dyn PluginInventory>>,
+    /// Maximum number of output destinations a job or schedule may configure
+    pub max_output_destinations: usize,
 }
 
 impl TasksContext {
@@ -41,6 +59,12 @@ impl TasksContext {
             validator,
             mcp_task_service: None,
             scheduler_service: None,
+            features: BTreeMap::new(),
+            attachments: None,
+            caches: None,
+            audit: None,
+            plugins: None,
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
 
@@ -59,6 +83,12 @@ impl TasksContext {
             validator,
             mcp_task_service: Some(mcp_task_service),
             scheduler_service: None,
+            features: BTreeMap::new(),
+            attachments: None,
+            caches: None,
+            audit: None,
+            plugins: None,
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
 
@@ -77,6 +107,12 @@ impl TasksContext {
             validator,
             mcp_task_service: None,
             scheduler_service: Some(scheduler_service),
+            features: BTreeMap::new(),
+            attachments: None,
+            caches: None,
+            audit: None,
+            plugins: None,
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
 
@@ -96,8 +132,50 @@ impl TasksContext {
             validator,
             mcp_task_service: Some(mcp_task_service),
             scheduler_service: Some(scheduler_service),
+            features: BTreeMap::new(),
+            attachments: None,
+            caches: None,
+            audit: None,
+            plugins: None,
+            max_output_destinations: DEFAULT_MAX_OUTPUT_DESTINATIONS,
         }
     }
+
+    /// Attach the configured feature flag states
+    pub fn with_feature_flags(mut self, features: BTreeMap<String, bool>) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Attach the store used to serve execution attachments
+    pub fn with_attachment_store(mut self, attachments: Arc<AttachmentStore>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    /// Attach the cache registry inspected and flushed by the admin endpoints
+    pub fn with_cache_registry(mut self, caches: Arc<CacheRegistry>) -> Self {
+        self.caches = Some(caches);
+        self
+    }
+
+    /// Attach the audit log exported by the admin endpoints
+    pub fn with_audit_log(mut self, audit: Arc<dyn AuditLogExport>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Attach the plugin inventory listed by the admin endpoints
+    pub fn with_plugin_inventory(mut self, plugins: Arc<dyn PluginInventory>) -> Self {
+        self.plugins = Some(plugins);
+        self
+    }
+
+    /// Limit the number of output destinations accepted per job or schedule
+    pub fn with_max_output_destinations(mut self, max_output_destinations: usize) -> Self {
+        self.max_output_destinations = max_output_destinations;
+        self
+    }
 }
 
 /// Context for execution-related endpoints
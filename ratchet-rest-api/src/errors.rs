@@ -6,11 +6,14 @@ use axum::{
     Json,
 };
 use ratchet_api_types::errors::ApiError;
-use ratchet_core::validation::{error_sanitization::ErrorSanitizer, InputValidationError};
+use ratchet_core::validation::{error_sanitization::ErrorSanitizer, InputValidationError, InputValidator};
 use ratchet_interfaces::DatabaseError;
 use ratchet_web::WebError;
-use serde_json::json;
+
+use crate::models::tasks::ValidationErrorDetail;
+use serde_json::{json, Value as JsonValue};
 use thiserror::Error;
+use tracing::warn;
 
 /// REST API specific error type
 #[derive(Error, Debug)]
@@ -49,7 +52,11 @@ pub enum RestError {
     Web(#[from] WebError),
 
     #[error("Validation error: {message}")]
-    Validation { message: String },
+    Validation {
+        message: String,
+        /// Per-field failures, sent to clients under `errors`
+        errors: Vec<ValidationErrorDetail>,
+    },
 
     #[error("Input validation error")]
     InputValidation(#[from] InputValidationError),
@@ -65,14 +72,17 @@ impl IntoResponse for RestError {
         let status =
             StatusCode::from_u16(unified_error.http_status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
-        let error_response = json!({
-            "error": {
-                "code": unified_error.code,
-                "message": unified_error.message,
-                "status": status.as_u16()
-            }
+        let mut error = json!({
+            "code": unified_error.code,
+            "message": unified_error.message,
+            "status": status.as_u16()
         });
-        (status, Json(error_response)).into_response()
+        let field_errors = self.field_errors();
+        if !field_errors.is_empty() {
+            error["errors"] = json!(field_errors);
+        }
+
+        (status, Json(json!({ "error": error }))).into_response()
     }
 }
 
@@ -90,7 +100,7 @@ impl RestError {
             RestError::Conflict(msg) => ("CONFLICT".to_string(), msg.clone()),
             RestError::Timeout(msg) => ("TIMEOUT".to_string(), msg.clone()),
             RestError::ServiceUnavailable(msg) => ("SERVICE_UNAVAILABLE".to_string(), msg.clone()),
-            RestError::Validation { message } => ("VALIDATION_ERROR".to_string(), message.clone()),
+            RestError::Validation { message, .. } => ("VALIDATION_ERROR".to_string(), message.clone()),
 
             // These error types may contain sensitive data and need sanitization
             RestError::InternalError(_)
@@ -115,6 +125,22 @@ impl RestError {
         ApiError::new(error_code, message)
     }
 
+    /// Per-field validation failures carried by this error
+    pub fn field_errors(&self) -> Vec<ValidationErrorDetail> {
+        match self {
+            RestError::Validation { errors, .. } => errors.clone(),
+            RestError::Web(WebError::Validation { errors }) => errors
+                .iter()
+                .map(|error| ValidationErrorDetail {
+                    field: error.field.clone(),
+                    message: error.message.clone(),
+                    code: error.code.clone(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     // Common error constructors
     pub fn not_found(resource: &str, id: &str) -> Self {
         RestError::NotFound(format!("{} with ID '{}' not found", resource, id))
@@ -143,6 +169,70 @@ impl RestError {
     pub fn validation_error(message: impl Into<String>) -> Self {
         RestError::Validation {
             message: message.into(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Validation failure listing each offending field
+    pub fn invalid_fields(errors: Vec<ValidationErrorDetail>) -> Self {
+        let fields: Vec<_> = errors.iter().filter_map(|error| error.field.as_deref()).collect();
+        let message = if fields.is_empty() {
+            "Request failed validation".to_string()
+        } else {
+            format!("Invalid fields: {}", fields.join(", "))
+        };
+        RestError::Validation { message, errors }
+    }
+}
+
+/// Collects the fields of a request that fail validation, so they are all reported at once
+#[derive(Debug, Default)]
+pub struct FieldErrors {
+    errors: Vec<ValidationErrorDetail>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `field` as invalid if its input validation failed
+    pub fn check<T>(&mut self, field: &str, result: Result<T, InputValidationError>) -> Option<T> {
+        result
+            .map_err(|error| {
+                warn!("Invalid {} provided: {}", field, error);
+                self.errors.push(ValidationErrorDetail::for_field(field, &error));
+            })
+            .ok()
+    }
+
+    /// Record `field` as invalid under `code` if some other check of it failed
+    pub fn check_with<T, E: std::fmt::Display>(&mut self, field: &str, code: &str, result: Result<T, E>) -> Option<T> {
+        result
+            .map_err(|error| {
+                warn!("Invalid {} provided: {}", field, error);
+                self.errors.push(ValidationErrorDetail {
+                    field: Some(field.to_string()),
+                    message: error.to_string(),
+                    code: code.to_string(),
+                });
+            })
+            .ok()
+    }
+
+    /// Record the JSON document in `field`, if any, as invalid if it fails validation
+    pub fn check_json(&mut self, validator: &InputValidator, field: &str, value: Option<&JsonValue>) {
+        if let Some(value) = value {
+            self.check(field, validator.validate_json(&value.to_string()));
+        }
+    }
+
+    /// Fail with every recorded field, if there are any
+    pub fn into_result(self) -> RestResult<()> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(RestError::invalid_fields(self.errors))
         }
     }
 }
@@ -161,3 +251,65 @@ pub fn db_error(err: DatabaseError) -> RestError {
 pub fn web_error(err: WebError) -> RestError {
     RestError::Web(err)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratchet_core::validation::InputValidator;
+
+    async fn error_body(error: RestError) -> (StatusCode, serde_json::Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_field_errors_are_listed_under_errors() {
+        let validation_err = InputValidator::new().validate_semver("not-a-version").unwrap_err();
+        let error = RestError::invalid_fields(vec![ValidationErrorDetail::for_field("version", &validation_err)]);
+
+        let (status, body) = error_body(error).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], "VALIDATION_ERROR");
+        assert_eq!(body["error"]["message"], "Invalid fields: version");
+        assert_eq!(body["error"]["errors"][0]["field"], "version");
+        assert_eq!(body["error"]["errors"][0]["code"], validation_err.code());
+    }
+
+    #[test]
+    fn test_field_errors_collect_every_failure() {
+        let validator = InputValidator::new();
+        let mut errors = FieldErrors::new();
+
+        assert_eq!(
+            errors.check("name", validator.validate_task_name("valid-name")),
+            Some(())
+        );
+        assert!(errors
+            .check("version", validator.validate_semver("not-a-version"))
+            .is_none());
+        assert!(errors
+            .check_with("cronExpression", "INVALID_CRON", Err::<(), _>("bad cron"))
+            .is_none());
+
+        let error = errors.into_result().unwrap_err();
+        let fields = error.field_errors();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].field.as_deref(), Some("version"));
+        assert_eq!(fields[1].field.as_deref(), Some("cronExpression"));
+        assert_eq!(fields[1].code, "INVALID_CRON");
+        assert_eq!(fields[1].message, "bad cron");
+
+        assert!(FieldErrors::new().into_result().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_errors_without_fields_omit_errors() {
+        let (status, body) = error_body(RestError::validation_error("bad input")).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].get("errors").is_none());
+    }
+}
@@ -0,0 +1,239 @@
+//! Administrative endpoints
+//!
+//! Operator tooling for incidents and compliance, such as inspecting and
+//! flushing caches, listing plugins or exporting the audit log. Every
+//! endpoint requires admin privileges.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use ratchet_caching::{CacheError, CacheRegistry};
+use ratchet_interfaces::AuditExportFormat;
+use ratchet_web::{middleware::AuthContext, ApiResponse};
+use serde::Deserialize;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::{
+    context::TasksContext,
+    errors::{RestError, RestResult},
+};
+
+fn require_admin(auth_context: &AuthContext) -> RestResult<()> {
+    if auth_context.can_admin() {
+        Ok(())
+    } else {
+        warn!("Rejected admin request from user {}", auth_context.user_id);
+        Err(RestError::forbidden("Admin privileges required"))
+    }
+}
+
+fn cache_registry(ctx: &TasksContext) -> RestResult<&Arc<CacheRegistry>> {
+    ctx.caches
+        .as_ref()
+        .ok_or_else(|| RestError::InternalError("Cache registry not configured".to_string()))
+}
+
+fn cache_error(name: &str, error: CacheError) -> RestError {
+    match error {
+        CacheError::CacheNotFound(_) => RestError::not_found("Cache", name),
+        CacheError::InvalidKey(key) => RestError::bad_request(format!("Invalid cache key: {}", key)),
+        other => RestError::InternalError(format!("Cache operation failed: {}", other)),
+    }
+}
+
+/// List registered caches with their statistics
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/caches",
+    tag = "admin",
+    summary = "List caches",
+    description = "List every registered cache with its hit, miss and entry statistics",
+    responses(
+        (status = 200, description = "Registered caches and their statistics"),
+        (status = 403, description = "Admin privileges required")
+    )
+)]
+pub async fn list_caches(
+    State(ctx): State<TasksContext>,
+    Extension(auth_context): Extension<AuthContext>,
+) -> RestResult<impl IntoResponse> {
+    require_admin(&auth_context)?;
+
+    let stats = cache_registry(&ctx)?
+        .stats()
+        .await
+        .map_err(|e| RestError::InternalError(format!("Failed to read cache statistics: {}", e)))?;
+
+    Ok(Json(ApiResponse::new(stats)))
+}
+
+/// Remove every entry from a cache
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/caches/{name}/flush",
+    tag = "admin",
+    summary = "Flush a cache",
+    description = "Remove every entry from the named cache",
+    params(("name" = String, Path, description = "Cache name")),
+    responses(
+        (status = 200, description = "Cache flushed"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Cache not found")
+    )
+)]
+pub async fn flush_cache(
+    State(ctx): State<TasksContext>,
+    Extension(auth_context): Extension<AuthContext>,
+    Path(name): Path<String>,
+) -> RestResult<impl IntoResponse> {
+    require_admin(&auth_context)?;
+
+    cache_registry(&ctx)?
+        .flush(&name)
+        .await
+        .map_err(|e| cache_error(&name, e))?;
+    info!("Cache '{}' flushed by {}", name, auth_context.user_id);
+
+    Ok(Json(ApiResponse::new(serde_json::json!({
+        "cache": name,
+        "flushed": true
+    }))))
+}
+
+/// Remove a single entry from a cache
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/caches/{name}/keys/{key}",
+    tag = "admin",
+    summary = "Flush a cache key",
+    description = "Remove a single entry from the named cache",
+    params(
+        ("name" = String, Path, description = "Cache name"),
+        ("key" = String, Path, description = "Cache key")
+    ),
+    responses(
+        (status = 200, description = "Key removed, or was not cached"),
+        (status = 400, description = "Key is not valid for this cache"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 404, description = "Cache not found")
+    )
+)]
+pub async fn flush_cache_key(
+    State(ctx): State<TasksContext>,
+    Extension(auth_context): Extension<AuthContext>,
+    Path((name, key)): Path<(String, String)>,
+) -> RestResult<impl IntoResponse> {
+    require_admin(&auth_context)?;
+
+    let removed = cache_registry(&ctx)?
+        .flush_key(&name, &key)
+        .await
+        .map_err(|e| cache_error(&name, e))?;
+    info!("Cache '{}' key '{}' flushed by {}", name, key, auth_context.user_id);
+
+    Ok(Json(ApiResponse::new(serde_json::json!({
+        "cache": name,
+        "key": key,
+        "removed": removed
+    }))))
+}
+
+/// List loaded plugins and plugin libraries that failed to load
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/plugins",
+    tag = "admin",
+    summary = "List plugins",
+    description = "List registered plugins with their status, and plugin libraries that failed to load at startup",
+    responses(
+        (status = 200, description = "Loaded plugins and load failures"),
+        (status = 403, description = "Admin privileges required"),
+        (status = 503, description = "Plugin system is not configured")
+    )
+)]
+pub async fn list_plugins(
+    State(ctx): State<TasksContext>,
+    Extension(auth_context): Extension<AuthContext>,
+) -> RestResult<impl IntoResponse> {
+    require_admin(&auth_context)?;
+
+    let plugins = ctx
+        .plugins
+        .as_ref()
+        .ok_or_else(|| RestError::ServiceUnavailable("Plugin system is not configured".to_string()))?;
+
+    Ok(Json(ApiResponse::new(serde_json::json!({
+        "plugins": plugins.loaded_plugins().await,
+        "failures": plugins.load_failures().await
+    }))))
+}
+
+/// Query parameters of the audit log export
+#[derive(Debug, Deserialize)]
+pub struct AuditExportParams {
+    /// Earliest entry timestamp (inclusive)
+    pub from: Option<DateTime<Utc>>,
+    /// Latest entry timestamp (inclusive)
+    pub to: Option<DateTime<Utc>>,
+    /// Export format, `json` unless specified
+    #[serde(default)]
+    pub format: AuditExportFormat,
+}
+
+/// Export the audit log for a date range
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit",
+    tag = "admin",
+    summary = "Export audit log",
+    description = "Export audit log entries recorded within an optional date range as JSON or CSV",
+    params(
+        ("from" = Option<String>, Query, description = "Earliest entry timestamp (RFC 3339, inclusive)"),
+        ("to" = Option<String>, Query, description = "Latest entry timestamp (RFC 3339, inclusive)"),
+        ("format" = Option<String>, Query, description = "Export format: json (default) or csv")
+    ),
+    responses(
+        (status = 200, description = "Audit log entries in the requested format"),
+        (status = 400, description = "Invalid date range"),
+        (status = 403, description = "Admin privileges required")
+    )
+)]
+pub async fn export_audit_log(
+    State(ctx): State<TasksContext>,
+    Extension(auth_context): Extension<AuthContext>,
+    Query(params): Query<AuditExportParams>,
+) -> RestResult<impl IntoResponse> {
+    require_admin(&auth_context)?;
+
+    if let (Some(from), Some(to)) = (params.from, params.to) {
+        if from > to {
+            return Err(RestError::bad_request("'from' must not be after 'to'"));
+        }
+    }
+
+    let audit = ctx
+        .audit
+        .as_ref()
+        .ok_or_else(|| RestError::ServiceUnavailable("Audit log is not configured".to_string()))?;
+    let data = audit
+        .export_range(params.from, params.to, params.format)
+        .await
+        .map_err(|e| RestError::InternalError(e.to_string()))?;
+    info!("Audit log exported as {:?} by {}", params.format, auth_context.user_id);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, params.format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"audit.{}\"", params.format.extension()),
+            ),
+        ],
+        data,
+    ))
+}
@@ -1,14 +1,22 @@
 //! Execution management endpoints
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
-use ratchet_api_types::ApiId;
+use chrono::{DateTime, Utc};
+use ratchet_api_types::{ApiId, UnifiedExecution};
 use ratchet_core::validation::{ErrorSanitizer, InputValidator};
-use ratchet_web::{extract_execution_filters, ApiResponse, QueryParams};
+use ratchet_interfaces::DatabaseError;
+use ratchet_storage::StorageError;
+use ratchet_web::{
+    extract_execution_filters, long_poll, middleware::content_etag, utils::DEFAULT_POLL_INTERVAL, ApiResponse,
+    QueryParams, WaitParams,
+};
+use serde::Deserialize;
+use std::time::Duration;
 use tracing::{info, warn};
 
 use crate::{
@@ -16,7 +24,9 @@ use crate::{
     errors::{RestError, RestResult},
     models::{
         common::StatsResponse,
-        executions::{CreateExecutionRequest, ExecutionStats, RetryExecutionRequest, UpdateExecutionRequest},
+        executions::{
+            CreateExecutionRequest, ExecutionStats, ExecutionStatsBucket, RetryExecutionRequest, UpdateExecutionRequest,
+        },
     },
 };
 
@@ -26,6 +36,8 @@ use crate::{
     path = "/api/v1/executions",
     responses(
         (status = 200, description = "List of executions retrieved successfully"),
+        (status = 304, description = "Not modified since the ETag in If-None-Match"),
+        (status = 400, description = "Date range filter ends before it starts"),
         (status = 500, description = "Internal server error")
     ),
     tag = "executions"
@@ -36,24 +48,41 @@ pub async fn list_executions(State(ctx): State<TasksContext>, query: QueryParams
     let list_input = query.0.to_list_input();
 
     // Extract filters from query parameters
-    let filters = extract_execution_filters(&query.0.filters);
+    let filters = extract_execution_filters(&query.0.filters)?;
 
     let execution_repo = ctx.repositories.execution_repository();
+
+    // Opt-in keyset pagination, stable under concurrent inserts
+    if let Some(page) = query.0.cursor_page() {
+        let executions = execution_repo
+            .find_after(filters, page.after.clone(), page.fetch_limit())
+            .await
+            .map_err(RestError::Database)?;
+        let response = page.into_response(executions, |execution| &execution.id);
+        let etag = content_etag(&response);
+        return Ok(([(header::ETAG, etag)], Json(response)));
+    }
+
     let list_response = execution_repo
         .find_with_list_input(filters, list_input)
         .await
         .map_err(RestError::Database)?;
 
-    Ok(Json(ApiResponse::from(list_response)))
+    let etag = content_etag(&list_response);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::from(list_response))))
 }
 
 /// Get a specific execution by ID
+///
+/// With `?wait=30s` the request is held open until the execution reaches a terminal
+/// state or the wait elapses, and the current state is returned either way.
 
 pub async fn get_execution(
     State(ctx): State<TasksContext>,
     Path(execution_id): Path<String>,
+    WaitParams(wait): WaitParams,
 ) -> RestResult<impl IntoResponse> {
-    info!("Getting execution with ID: {}", execution_id);
+    info!("Getting execution with ID: {} (wait: {:?})", execution_id, wait);
 
     // Validate execution ID input
     let validator = InputValidator::new();
@@ -67,17 +96,90 @@ pub async fn get_execution(
     let api_id = ApiId::from_string(execution_id.clone());
     let execution_repo = ctx.repositories.execution_repository();
 
+    let fetch = || async {
+        execution_repo
+            .find_by_id(api_id.as_i32().unwrap_or(0))
+            .await
+            .map_err(|db_err| {
+                let sanitizer = ErrorSanitizer::default();
+                let sanitized_error = sanitizer.sanitize_error(&db_err);
+                RestError::InternalError(sanitized_error.message)
+            })?
+            .ok_or_else(|| RestError::not_found("Execution", &execution_id))
+    };
+
+    // The repository lists the execution's attachments along with it
+    let execution = match wait {
+        Some(wait) => {
+            long_poll(wait, DEFAULT_POLL_INTERVAL, fetch, |execution: &UnifiedExecution| {
+                execution.status.is_terminal()
+            })
+            .await?
+        }
+        None => fetch().await?,
+    };
+
+    // Executions carry no modification time, and progress changes while they run
+    let etag = content_etag(&execution);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::new(execution))))
+}
+
+/// Download a file attached to an execution
+
+pub async fn get_execution_attachment(
+    State(ctx): State<TasksContext>,
+    Path((execution_id, name)): Path<(String, String)>,
+) -> RestResult<impl IntoResponse> {
+    info!("Getting attachment '{}' of execution: {}", name, execution_id);
+
+    let store = ctx
+        .attachments
+        .as_ref()
+        .ok_or_else(|| RestError::ServiceUnavailable("Execution attachments are not configured".to_string()))?;
+
+    // Validate execution ID input
+    let validator = InputValidator::new();
+    let sanitizer = ErrorSanitizer::default();
+    if let Err(validation_err) = validator.validate_string(&execution_id, "execution_id") {
+        warn!("Invalid execution ID provided: {}", validation_err);
+        let sanitized_error = sanitizer.sanitize_error(&validation_err);
+        return Err(RestError::BadRequest(sanitized_error.message));
+    }
+
+    let api_id = ApiId::from_string(execution_id.clone());
+    let execution_repo = ctx.repositories.execution_repository();
+
     let execution = execution_repo
         .find_by_id(api_id.as_i32().unwrap_or(0))
         .await
         .map_err(|db_err| {
-            let sanitizer = ErrorSanitizer::default();
             let sanitized_error = sanitizer.sanitize_error(&db_err);
             RestError::InternalError(sanitized_error.message)
         })?
         .ok_or_else(|| RestError::not_found("Execution", &execution_id))?;
 
-    Ok(Json(ApiResponse::new(execution)))
+    let (attachment, data) = store
+        .get(&execution.uuid.to_string(), &name)
+        .await
+        .map_err(|e| match e {
+            StorageError::NotFound => RestError::not_found("Attachment", &name),
+            StorageError::ValidationFailed(message) => RestError::BadRequest(message),
+            other => {
+                let sanitized_error = sanitizer.sanitize_error(&other);
+                RestError::InternalError(sanitized_error.message)
+            }
+        })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, attachment.mime_type),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", attachment.name),
+            ),
+        ],
+        data,
+    ))
 }
 
 /// Create a new execution
@@ -101,6 +203,22 @@ pub async fn create_execution(
         return Err(RestError::BadRequest(sanitized_error.message));
     }
 
+    // Labels must stay expressible as `?label=key:value` filters
+    for (key, value) in &request.labels {
+        if key.is_empty() || key.len() > 255 || value.len() > 255 {
+            return Err(RestError::BadRequest(format!(
+                "Label '{}' must have a 1-255 character key and a value of at most 255 characters",
+                key
+            )));
+        }
+        if key.contains([':', ',']) || value.contains(',') {
+            return Err(RestError::BadRequest(format!(
+                "Label '{}' cannot contain ',' and its key cannot contain ':'",
+                key
+            )));
+        }
+    }
+
     // Validate that task exists
     let task_repo = ctx.repositories.task_repository();
     let _task = task_repo
@@ -128,6 +246,8 @@ pub async fn create_execution(
         duration_ms: None,
         http_requests: None,
         recording_path: None,
+        attachments: Vec::new(),
+        labels: request.labels,
         can_retry: false,
         can_cancel: true,
         progress: None,
@@ -370,6 +490,8 @@ pub async fn retry_execution(
         duration_ms: None,
         http_requests: None,
         recording_path: None,
+        attachments: Vec::new(),
+        labels: original_execution.labels,
         can_retry: false,
         can_cancel: true,
         progress: None,
@@ -387,49 +509,108 @@ pub async fn retry_execution(
 }
 
 /// Get execution logs
+///
+/// Returns the log events stored when the execution ran, such as the task's
+/// console output, oldest first.
 
 pub async fn get_execution_logs(
-    State(_ctx): State<TasksContext>,
+    State(ctx): State<TasksContext>,
     Path(execution_id): Path<String>,
 ) -> RestResult<impl IntoResponse> {
     info!("Getting logs for execution: {}", execution_id);
 
-    // For now, return placeholder logs
-    // In a full implementation, this would:
-    // 1. Validate execution exists
-    // 2. Retrieve logs from logging system
-    // 3. Support real-time streaming if requested
-    // 4. Return formatted log entries
+    // Validate execution ID input
+    let validator = InputValidator::new();
+    if let Err(validation_err) = validator.validate_string(&execution_id, "execution_id") {
+        warn!("Invalid execution ID provided: {}", validation_err);
+        let sanitizer = ErrorSanitizer::default();
+        let sanitized_error = sanitizer.sanitize_error(&validation_err);
+        return Err(RestError::BadRequest(sanitized_error.message));
+    }
+
+    let logs = ctx
+        .repositories
+        .execution_repository()
+        .find_logs(ApiId::from_string(execution_id.clone()))
+        .await
+        .map_err(|db_err| match db_err {
+            DatabaseError::NotFound { .. } => RestError::not_found("Execution", &execution_id),
+            db_err => RestError::Database(db_err),
+        })?;
 
     Ok(Json(serde_json::json!({
         "execution_id": execution_id,
-        "logs": [
-            {
-                "timestamp": "2023-12-07T14:30:15.123Z",
-                "level": "info",
-                "message": "Starting task execution",
-                "source": "task_executor"
-            },
-            {
-                "timestamp": "2023-12-07T14:30:15.145Z",
-                "level": "info",
-                "message": "Processing input data",
-                "source": "task_executor"
-            }
-        ],
+        "logs": logs,
         "has_more": false
     })))
 }
 
-/// Get execution statistics
+/// Most history buckets a single stats request may cover
+const MAX_HISTORY_BUCKETS: i64 = 1000;
 
-pub async fn get_execution_stats(State(ctx): State<TasksContext>) -> RestResult<impl IntoResponse> {
+fn default_history_interval() -> Duration {
+    Duration::from_secs(3600)
+}
+
+/// Query parameters of the execution statistics
+#[derive(Debug, Deserialize)]
+pub struct ExecutionStatsParams {
+    /// Width of each history bucket, e.g. `15m` or `1h`
+    #[serde(with = "humantime_serde", default = "default_history_interval")]
+    pub interval: Duration,
+    /// Start of the history range (inclusive), 24 hours before `to` by default
+    pub from: Option<DateTime<Utc>>,
+    /// End of the history range (exclusive), now by default
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Get execution statistics
+#[utoipa::path(
+    get,
+    path = "/api/v1/executions/stats",
+    tag = "executions",
+    summary = "Execution statistics",
+    description = "Execution counts by status and duration percentiles per time interval, for dashboards",
+    params(
+        ("interval" = Option<String>, Query, description = "History bucket width such as 15m or 1h (default 1h)"),
+        ("from" = Option<String>, Query, description = "Start of the history range (RFC 3339, inclusive)"),
+        ("to" = Option<String>, Query, description = "End of the history range (RFC 3339, exclusive)")
+    ),
+    responses(
+        (status = 200, description = "Execution statistics", body = ExecutionStats),
+        (status = 400, description = "Invalid interval or date range")
+    )
+)]
+pub async fn get_execution_stats(
+    State(ctx): State<TasksContext>,
+    Query(params): Query<ExecutionStatsParams>,
+) -> RestResult<impl IntoResponse> {
     info!("Getting execution statistics");
 
+    let interval_seconds = params.interval.as_secs() as i64;
+    if interval_seconds == 0 {
+        return Err(RestError::bad_request("'interval' must be at least one second"));
+    }
+    let to = params.to.unwrap_or_else(Utc::now);
+    let from = params.from.unwrap_or(to - chrono::Duration::hours(24));
+    if from >= to {
+        return Err(RestError::bad_request("'from' must be before 'to'"));
+    }
+    if (to - from).num_seconds() / interval_seconds >= MAX_HISTORY_BUCKETS {
+        return Err(RestError::bad_request(format!(
+            "Range covers more than {} intervals",
+            MAX_HISTORY_BUCKETS
+        )));
+    }
+
     let execution_repo = ctx.repositories.execution_repository();
 
     // Get basic counts
     let total_executions = execution_repo.count().await.map_err(RestError::Database)?;
+    let history = execution_repo
+        .history(from, to, interval_seconds)
+        .await
+        .map_err(RestError::Database)?;
 
     // For now, return basic stats
     // In a full implementation, this would query for more detailed metrics
@@ -443,7 +624,13 @@ pub async fn get_execution_stats(State(ctx): State<TasksContext>) -> RestResult<
         average_duration_ms: None, // TODO: Implement
         success_rate: 0.0,         // TODO: Implement
         executions_last_24h: 0,    // TODO: Implement
+        interval_seconds: interval_seconds as u64,
+        history: history.into_iter().map(ExecutionStatsBucket::from).collect(),
     };
 
-    Ok(Json(StatsResponse::new(stats)))
+    Ok(Json(StatsResponse::new(stats).with_period(format!(
+        "{}/{}",
+        from.to_rfc3339(),
+        to.to_rfc3339()
+    ))))
 }
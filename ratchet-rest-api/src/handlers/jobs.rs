@@ -2,30 +2,45 @@
 
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
-use ratchet_api_types::ApiId;
+use ratchet_api_types::{ApiId, JobStatus};
+use ratchet_core::schedule::{resolve_job_run_time, validate_jitter};
 use ratchet_core::validation::{ErrorSanitizer, InputValidator};
-use ratchet_web::{extract_job_filters, ApiResponse, QueryParams};
+use ratchet_web::{extract_job_filters, middleware::content_etag, ApiResponse, QueryParams};
 use tracing::{info, warn};
 
 use crate::{
     context::TasksContext,
-    errors::{RestError, RestResult},
+    errors::{FieldErrors, RestError, RestResult},
+    handlers::run_batch,
     models::{
-        common::StatsResponse,
+        common::{BatchAction, BatchRequest, BatchResponse, StatsResponse},
         jobs::{CreateJobRequest, JobStats, UpdateJobRequest},
     },
 };
 
+/// Reject output destination lists longer than the configured maximum
+pub(crate) fn validate_output_destination_count(count: usize, max: usize, owner: &str) -> Result<(), RestError> {
+    if count > max {
+        return Err(RestError::BadRequest(format!(
+            "Maximum of {} output destinations allowed per {}, got {}",
+            max, owner, count
+        )));
+    }
+    Ok(())
+}
+
 /// List all jobs with optional filtering and pagination
 #[utoipa::path(
     get,
     path = "/api/v1/jobs",
     responses(
         (status = 200, description = "List of jobs retrieved successfully"),
+        (status = 304, description = "Not modified since the ETag in If-None-Match"),
+        (status = 400, description = "Date range filter ends before it starts"),
         (status = 500, description = "Internal server error")
     ),
     tag = "jobs"
@@ -36,15 +51,28 @@ pub async fn list_jobs(State(ctx): State<TasksContext>, query: QueryParams) -> R
     let list_input = query.0.to_list_input();
 
     // Extract filters from query parameters
-    let filters = extract_job_filters(&query.0.filters);
+    let filters = extract_job_filters(&query.0.filters)?;
 
     let job_repo = ctx.repositories.job_repository();
+
+    // Opt-in keyset pagination, stable under concurrent inserts
+    if let Some(page) = query.0.cursor_page() {
+        let jobs = job_repo
+            .find_after(filters, page.after.clone(), page.fetch_limit())
+            .await
+            .map_err(RestError::Database)?;
+        let response = page.into_response(jobs, |job| &job.id);
+        let etag = content_etag(&response);
+        return Ok(([(header::ETAG, etag)], Json(response)));
+    }
+
     let list_response = job_repo
         .find_with_list_input(filters, list_input)
         .await
         .map_err(RestError::Database)?;
 
-    Ok(Json(ApiResponse::from(list_response)))
+    let etag = content_etag(&list_response);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::from(list_response))))
 }
 
 /// Get a specific job by ID
@@ -74,7 +102,9 @@ pub async fn get_job(State(ctx): State<TasksContext>, Path(job_id): Path<String>
         })?
         .ok_or_else(|| RestError::not_found("Job", &job_id))?;
 
-    Ok(Json(ApiResponse::new(job)))
+    // Jobs carry no modification time, so tag what they return
+    let etag = content_etag(&job);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::new(job))))
 }
 
 /// Create a new job
@@ -85,9 +115,46 @@ pub async fn create_job(
 ) -> RestResult<impl IntoResponse> {
     info!("Creating job for task: {:?}", request.task_id);
 
-    // Validate the request input
-    let _validator = InputValidator::new();
+    // Validate the request input, collecting every failing field
     let sanitizer = ErrorSanitizer::default();
+    let mut errors = FieldErrors::new();
+
+    if let Some(ref destinations) = request.output_destinations {
+        errors.check_with(
+            "outputDestinations",
+            "TOO_MANY_DESTINATIONS",
+            validate_output_destination_count(destinations.len(), ctx.max_output_destinations, "job")
+                .map_err(|e| e.to_unified_error().message),
+        );
+    }
+
+    let log_level = errors.check_with(
+        "logLevel",
+        "INVALID_VALUE",
+        request
+            .log_level
+            .as_deref()
+            .map(|level| ratchet_interfaces::LogLevel::from_str(level).map(|level| level.as_str().to_string()))
+            .transpose(),
+    );
+
+    if let Some(jitter_seconds) = request.jitter_seconds {
+        errors.check_with("jitterSeconds", "INVALID_VALUE", validate_jitter(jitter_seconds));
+    }
+
+    let now = chrono::Utc::now();
+    let scheduled_for = errors.check_with(
+        "scheduledFor",
+        "INVALID_VALUE",
+        resolve_job_run_time(
+            now,
+            request.scheduled_for,
+            request.delay_seconds,
+            request.jitter_seconds,
+        ),
+    );
+    errors.into_result()?;
+    let (log_level, scheduled_for) = (log_level.flatten(), scheduled_for.flatten());
 
     // Validate that task exists
     let task_repo = ctx.repositories.task_repository();
@@ -108,10 +175,13 @@ pub async fn create_job(
         status: ratchet_api_types::JobStatus::Queued,
         retry_count: 0,
         max_retries: request.max_retries.unwrap_or(3),
-        queued_at: chrono::Utc::now(),
-        scheduled_for: request.scheduled_for,
+        queued_at: now,
+        scheduled_for,
         error_message: None,
         output_destinations: request.output_destinations,
+        schedule_id: None,
+        execution_id: None,
+        log_level,
     };
 
     // Create the job using the repository
@@ -196,16 +266,26 @@ pub async fn update_job(
 pub async fn delete_job(State(ctx): State<TasksContext>, Path(job_id): Path<String>) -> RestResult<impl IntoResponse> {
     info!("Deleting job with ID: {}", job_id);
 
+    delete_job_by_id(&ctx, &job_id).await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Job {} deleted successfully", job_id)
+    })))
+}
+
+/// Delete a job after checking that it exists
+async fn delete_job_by_id(ctx: &TasksContext, job_id: &str) -> RestResult<()> {
     // Validate job ID input
     let validator = InputValidator::new();
-    if let Err(validation_err) = validator.validate_string(&job_id, "job_id") {
+    if let Err(validation_err) = validator.validate_string(job_id, "job_id") {
         warn!("Invalid job ID provided: {}", validation_err);
         let sanitizer = ErrorSanitizer::default();
         let sanitized_error = sanitizer.sanitize_error(&validation_err);
         return Err(RestError::BadRequest(sanitized_error.message));
     }
 
-    let api_id = ApiId::from_string(job_id.clone());
+    let api_id = ApiId::from_string(job_id.to_string());
     let job_repo = ctx.repositories.job_repository();
 
     // Check if job exists
@@ -217,7 +297,7 @@ pub async fn delete_job(State(ctx): State<TasksContext>, Path(job_id): Path<Stri
             let sanitized_error = sanitizer.sanitize_error(&db_err);
             RestError::InternalError(sanitized_error.message)
         })?
-        .ok_or_else(|| RestError::not_found("Job", &job_id))?;
+        .ok_or_else(|| RestError::not_found("Job", job_id))?;
 
     // Delete the job
     job_repo.delete(api_id.as_i32().unwrap_or(0)).await.map_err(|db_err| {
@@ -226,10 +306,7 @@ pub async fn delete_job(State(ctx): State<TasksContext>, Path(job_id): Path<Stri
         RestError::InternalError(sanitized_error.message)
     })?;
 
-    Ok(Json(serde_json::json!({
-        "success": true,
-        "message": format!("Job {} deleted successfully", job_id)
-    })))
+    Ok(())
 }
 
 /// Cancel a queued job
@@ -248,6 +325,48 @@ pub async fn cancel_job(State(ctx): State<TasksContext>, Path(job_id): Path<Stri
     })))
 }
 
+/// Cancel or delete several jobs in one request
+#[utoipa::path(
+    post,
+    path = "/api/v1/jobs/batch",
+    tag = "jobs",
+    summary = "Apply a batch of job operations",
+    description = "Cancel or delete jobs by ID. Operations run in order and independently; each reports its own result, so a failed operation does not undo the others.",
+    request_body = BatchRequest,
+    responses(
+        (status = 200, description = "Batch applied, see per-operation results", body = BatchResponse),
+        (status = 400, description = "No operations, or too many operations"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn batch_jobs(
+    State(ctx): State<TasksContext>,
+    Json(request): Json<BatchRequest>,
+) -> RestResult<impl IntoResponse> {
+    info!("Applying {} job batch operations", request.operations.len());
+
+    let response = run_batch(request, |operation| {
+        let ctx = &ctx;
+        async move {
+            match operation.action {
+                BatchAction::Cancel => ctx
+                    .repositories
+                    .job_repository()
+                    .cancel(operation.id)
+                    .await
+                    .map_err(RestError::Database),
+                BatchAction::Delete => delete_job_by_id(ctx, operation.id.as_str()).await,
+                BatchAction::Enable | BatchAction::Disable => Err(RestError::BadRequest(
+                    "Jobs can only be cancelled or deleted".to_string(),
+                )),
+            }
+        }
+    })
+    .await?;
+
+    Ok(Json(ApiResponse::new(response)))
+}
+
 /// Retry a failed job
 
 pub async fn retry_job(State(ctx): State<TasksContext>, Path(job_id): Path<String>) -> RestResult<impl IntoResponse> {
@@ -270,6 +389,76 @@ pub async fn retry_job(State(ctx): State<TasksContext>, Path(job_id): Path<Strin
     })))
 }
 
+/// List jobs that failed after exhausting their retries
+#[utoipa::path(
+    get,
+    path = "/api/v1/jobs/dead-letter",
+    responses(
+        (status = 200, description = "Dead-lettered jobs retrieved successfully"),
+        (status = 304, description = "Not modified since the ETag in If-None-Match"),
+        (status = 400, description = "Date range filter ends before it starts"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "jobs"
+)]
+pub async fn list_dead_letter_jobs(
+    State(ctx): State<TasksContext>,
+    query: QueryParams,
+) -> RestResult<impl IntoResponse> {
+    info!("Listing dead-lettered jobs with query: {:?}", query.0);
+
+    let list_input = query.0.to_list_input();
+
+    let mut filters = extract_job_filters(&query.0.filters)?;
+    filters.status = Some(JobStatus::DeadLetter);
+
+    let job_repo = ctx.repositories.job_repository();
+    let list_response = job_repo
+        .find_with_list_input(filters, list_input)
+        .await
+        .map_err(RestError::Database)?;
+
+    let etag = content_etag(&list_response);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::from(list_response))))
+}
+
+/// Put a dead-lettered job back in the queue
+#[utoipa::path(
+    post,
+    path = "/api/v1/jobs/{id}/requeue",
+    params(("id" = String, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Job requeued with a fresh retry budget"),
+        (status = 404, description = "Job not found"),
+        (status = 409, description = "Job is not dead-lettered"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "jobs"
+)]
+pub async fn requeue_job(State(ctx): State<TasksContext>, Path(job_id): Path<String>) -> RestResult<impl IntoResponse> {
+    info!("Requeueing dead-lettered job with ID: {}", job_id);
+
+    let api_id = ApiId::from_string(job_id.clone());
+    let job_repo = ctx.repositories.job_repository();
+
+    let job = job_repo
+        .find_by_id(api_id.as_i32().unwrap_or(0))
+        .await
+        .map_err(RestError::Database)?
+        .ok_or_else(|| RestError::not_found("Job", &job_id))?;
+
+    if job.status != JobStatus::DeadLetter
+        || !job_repo.requeue_dead_letter(api_id).await.map_err(RestError::Database)?
+    {
+        return Err(RestError::Conflict(format!("Job {} is not dead-lettered", job_id)));
+    }
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Job {} requeued", job_id)
+    })))
+}
+
 /// Get job statistics
 
 pub async fn get_job_stats(State(ctx): State<TasksContext>) -> RestResult<impl IntoResponse> {
@@ -296,3 +485,21 @@ pub async fn get_job_stats(State(ctx): State<TasksContext>) -> RestResult<impl I
 
     Ok(Json(StatsResponse::new(stats)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_job_with_too_many_output_destinations() {
+        assert!(validate_output_destination_count(10, 10, "job").is_ok());
+
+        let err = validate_output_destination_count(11, 10, "job").unwrap_err();
+        match err {
+            RestError::BadRequest(message) => {
+                assert_eq!(message, "Maximum of 10 output destinations allowed per job, got 11")
+            }
+            other => panic!("expected a bad request, got {:?}", other),
+        }
+    }
+}
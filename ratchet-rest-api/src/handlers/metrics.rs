@@ -2,6 +2,7 @@
 
 use axum::{extract::State, response::IntoResponse, Json};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use tracing::info;
 use utoipa::ToSchema;
 
@@ -31,6 +32,7 @@ pub struct SystemInfo {
     pub target_triple: String,
     pub uptime_seconds: u64,
     pub git_commit: Option<String>,
+    pub features: BTreeMap<String, bool>,
 }
 
 /// Performance metrics
@@ -151,7 +153,7 @@ pub async fn get_metrics(State(ctx): State<TasksContext>) -> RestResult<impl Int
     let start_time = std::time::Instant::now();
 
     // Collect system information
-    let system_info = collect_system_info();
+    let system_info = collect_system_info(&ctx);
 
     // Collect performance metrics (placeholder for now)
     let performance = collect_performance_metrics();
@@ -201,7 +203,7 @@ pub async fn get_prometheus_metrics(State(ctx): State<TasksContext>) -> RestResu
 
 // Helper functions for metrics collection
 
-fn collect_system_info() -> SystemInfo {
+fn collect_system_info(ctx: &TasksContext) -> SystemInfo {
     let uptime = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
@@ -214,6 +216,7 @@ fn collect_system_info() -> SystemInfo {
         target_triple: std::env::consts::ARCH.to_string(),
         uptime_seconds: uptime,
         git_commit: None, // TODO: Add git information through build script
+        features: ctx.features.clone(),
     }
 }
 
@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod auth;
 pub mod executions;
 pub mod health;
@@ -8,6 +9,7 @@ pub mod tasks;
 pub mod workers;
 
 // Re-export handler functions
+pub use admin::*;
 pub use auth::*;
 pub use executions::*;
 pub use health::*;
@@ -16,3 +18,111 @@ pub use metrics::*;
 pub use schedules::*;
 pub use tasks::*;
 pub use workers::*;
+
+use std::future::Future;
+
+use crate::{
+    errors::{RestError, RestResult},
+    models::common::{BatchItemResult, BatchOperation, BatchRequest, BatchResponse, MAX_BATCH_OPERATIONS},
+};
+
+/// Apply batch operations in order, collecting a result per operation
+///
+/// Operations are independent: a failure is reported in its result and does not
+/// stop or roll back the others.
+pub(crate) async fn run_batch<F, Fut>(request: BatchRequest, mut apply: F) -> RestResult<BatchResponse>
+where
+    F: FnMut(BatchOperation) -> Fut,
+    Fut: Future<Output = RestResult<()>>,
+{
+    if request.operations.is_empty() {
+        return Err(RestError::BadRequest("operations cannot be empty".to_string()));
+    }
+    if request.operations.len() > MAX_BATCH_OPERATIONS {
+        return Err(RestError::BadRequest(format!(
+            "At most {} operations are allowed per batch",
+            MAX_BATCH_OPERATIONS
+        )));
+    }
+
+    let mut results = Vec::with_capacity(request.operations.len());
+    for operation in request.operations {
+        let (id, action) = (operation.id.clone(), operation.action);
+        let outcome = apply(operation).await;
+        results.push(BatchItemResult {
+            id,
+            action,
+            success: outcome.is_ok(),
+            error: outcome.err().map(|err| err.to_unified_error().message),
+        });
+    }
+
+    Ok(BatchResponse::new(results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::common::BatchAction;
+    use ratchet_api_types::ApiId;
+
+    fn request(count: usize) -> BatchRequest {
+        BatchRequest {
+            operations: (1..=count as i32)
+                .map(|id| BatchOperation {
+                    id: ApiId::from_i32(id),
+                    action: BatchAction::Delete,
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_reports_each_operation() {
+        let mut applied = Vec::new();
+        let response = run_batch(request(3), |operation| {
+            applied.push(operation.id.clone());
+            async move {
+                match operation.id.as_i32() {
+                    Some(2) => Err(RestError::not_found("Task", "2")),
+                    _ => Ok(()),
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        // A failure in the middle doesn't stop the operations after it
+        assert_eq!(
+            applied,
+            vec![ApiId::from_i32(1), ApiId::from_i32(2), ApiId::from_i32(3)]
+        );
+        assert_eq!((response.succeeded, response.failed), (2, 1));
+        let outcomes: Vec<_> = response
+            .results
+            .iter()
+            .map(|result| (result.id.to_string(), result.success, result.error.is_some()))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                ("1".to_string(), true, false),
+                ("2".to_string(), false, true),
+                ("3".to_string(), true, false)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_rejects_empty_and_oversized_batches() {
+        for count in [0, MAX_BATCH_OPERATIONS + 1] {
+            let result = run_batch(request(count), |_| async { Ok(()) }).await;
+            assert!(matches!(result, Err(RestError::BadRequest(_))), "{} operations", count);
+        }
+
+        let response = run_batch(request(MAX_BATCH_OPERATIONS), |_| async { Ok(()) })
+            .await
+            .unwrap();
+        assert_eq!(response.succeeded, MAX_BATCH_OPERATIONS);
+    }
+}
@@ -2,38 +2,47 @@
 
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
 use ratchet_api_types::ApiId;
+use ratchet_core::schedule::{
+    parse_timezone, preview_schedule, validate_cron_expression, validate_jitter, DEFAULT_PREVIEW_COUNT,
+};
 use ratchet_core::validation::{ErrorSanitizer, InputValidator};
-use ratchet_web::{extract_schedule_filters, ApiResponse, QueryParams};
+use ratchet_interfaces::SchedulerError;
+use ratchet_web::{
+    extract_schedule_filters,
+    middleware::{content_etag, timestamp_etag},
+    ApiResponse, QueryParams,
+};
 use tracing::{info, warn};
 
 use crate::{
     context::TasksContext,
-    errors::{RestError, RestResult},
+    errors::{FieldErrors, RestError, RestResult},
+    handlers::{jobs::validate_output_destination_count, run_batch},
     models::{
-        common::StatsResponse,
-        schedules::{CreateScheduleRequest, ScheduleStats, UpdateScheduleRequest},
+        common::{BatchAction, BatchRequest, BatchResponse, StatsResponse},
+        schedules::{
+            BulkScheduleResponse, BulkSetEnabledRequest, CreateScheduleRequest, ScheduleStats, UpdateScheduleRequest,
+            ValidateScheduleRequest, ValidateScheduleResponse,
+        },
+        tasks::ValidationErrorDetail,
     },
 };
-use ratchet_api_types::UnifiedOutputDestination;
+use ratchet_api_types::{UnifiedOutputDestination, UnifiedSchedule};
 
 /// Validate output destinations configuration
-fn validate_output_destinations(destinations: &[UnifiedOutputDestination]) -> Result<(), RestError> {
+fn validate_output_destinations(destinations: &[UnifiedOutputDestination], max: usize) -> Result<(), RestError> {
     if destinations.is_empty() {
         return Err(RestError::BadRequest(
             "Output destinations array cannot be empty".to_string(),
         ));
     }
 
-    if destinations.len() > 10 {
-        return Err(RestError::BadRequest(
-            "Maximum of 10 output destinations allowed per schedule".to_string(),
-        ));
-    }
+    validate_output_destination_count(destinations.len(), max, "schedule")?;
 
     for (index, dest) in destinations.iter().enumerate() {
         let context = format!("destination[{}]", index);
@@ -285,6 +294,7 @@ fn validate_output_destinations(destinations: &[UnifiedOutputDestination]) -> Re
     description = "Retrieve all schedules with optional filtering and pagination",
     responses(
         (status = 200, description = "List of schedules retrieved successfully"),
+        (status = 304, description = "Not modified since the ETag in If-None-Match"),
         (status = 400, description = "Invalid query parameters"),
         (status = 500, description = "Internal server error")
     )
@@ -298,12 +308,25 @@ pub async fn list_schedules(State(ctx): State<TasksContext>, query: QueryParams)
     let filters = extract_schedule_filters(&query.0.filters);
 
     let schedule_repo = ctx.repositories.schedule_repository();
+
+    // Opt-in keyset pagination, stable under concurrent inserts
+    if let Some(page) = query.0.cursor_page() {
+        let schedules = schedule_repo
+            .find_after(filters, page.after.clone(), page.fetch_limit())
+            .await
+            .map_err(RestError::Database)?;
+        let response = page.into_response(schedules, |schedule| &schedule.id);
+        let etag = content_etag(&response);
+        return Ok(([(header::ETAG, etag)], Json(response)));
+    }
+
     let list_response = schedule_repo
         .find_with_list_input(filters, list_input)
         .await
         .map_err(RestError::Database)?;
 
-    Ok(Json(ApiResponse::from(list_response)))
+    let etag = content_etag(&list_response);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::from(list_response))))
 }
 
 /// Get a specific schedule by ID
@@ -336,7 +359,8 @@ pub async fn get_schedule(
         })?
         .ok_or_else(|| RestError::not_found("Schedule", &schedule_id))?;
 
-    Ok(Json(ApiResponse::new(schedule)))
+    let etag = timestamp_etag(schedule.updated_at);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::new(schedule))))
 }
 
 /// Create a new schedule
@@ -358,45 +382,36 @@ pub async fn create_schedule(
 ) -> RestResult<impl IntoResponse> {
     info!("Creating schedule: {:?}", request.name);
 
-    // Validate the request input
+    // Validate the request input, collecting every failing field
     let validator = InputValidator::new();
     let sanitizer = ErrorSanitizer::default();
-
-    // Validate schedule name
-    if let Err(validation_err) = validator.validate_string(&request.name, "name") {
-        warn!("Invalid schedule name provided: {}", validation_err);
-        let sanitized_error = sanitizer.sanitize_error(&validation_err);
-        return Err(RestError::BadRequest(sanitized_error.message));
+    let mut errors = FieldErrors::new();
+
+    errors.check("name", validator.validate_string(&request.name, "name"));
+    // Reject cron expressions the scheduler would not be able to run
+    errors.check_with(
+        "cronExpression",
+        "INVALID_CRON",
+        validate_cron_expression(&request.cron_expression),
+    );
+    if let Some(ref timezone) = request.timezone {
+        errors.check_with("timezone", "INVALID_TIMEZONE", parse_timezone(timezone));
     }
-
-    // Validate cron expression format
-    if let Err(validation_err) = validator.validate_string(&request.cron_expression, "cron_expression") {
-        warn!("Invalid cron expression provided: {}", validation_err);
-        let sanitized_error = sanitizer.sanitize_error(&validation_err);
-        return Err(RestError::BadRequest(sanitized_error.message));
+    if let Some(jitter_seconds) = request.jitter_seconds {
+        errors.check_with("jitterSeconds", "INVALID_VALUE", validate_jitter(jitter_seconds));
     }
-
-    // Basic cron expression validation
-    if request.cron_expression.trim().is_empty() {
-        return Err(RestError::BadRequest("Cron expression cannot be empty".to_string()));
-    }
-
-    // Validate description if provided
     if let Some(ref description) = request.description {
-        if let Err(validation_err) = validator.validate_string(description, "description") {
-            warn!("Invalid description provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
+        errors.check("description", validator.validate_string(description, "description"));
     }
-
-    // Validate output destinations if provided
     if let Some(ref destinations) = request.output_destinations {
-        if let Err(validation_err) = validate_output_destinations(destinations) {
-            warn!("Invalid output destinations provided: {}", validation_err);
-            return Err(validation_err);
-        }
+        errors.check_with(
+            "outputDestinations",
+            "INVALID_DESTINATION",
+            validate_output_destinations(destinations, ctx.max_output_destinations)
+                .map_err(|e| e.to_unified_error().message),
+        );
     }
+    errors.into_result()?;
 
     // Validate that task exists
     let task_repo = ctx.repositories.task_repository();
@@ -422,6 +437,12 @@ pub async fn create_schedule(
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
         output_destinations: request.output_destinations,
+        serial: request.serial.unwrap_or(false),
+        timezone: request.timezone,
+        misfire_policy: request.misfire_policy.unwrap_or_default(),
+        max_concurrent: request.max_concurrent.filter(|&max| max > 0),
+        jitter_seconds: request.jitter_seconds.filter(|&jitter| jitter > 0),
+        paused: false,
     };
 
     // Create the schedule using the repository
@@ -450,6 +471,161 @@ pub async fn create_schedule(
     Ok((StatusCode::CREATED, Json(ApiResponse::new(created_schedule))))
 }
 
+/// Validate a cron expression and preview its upcoming runs
+///
+/// Nothing is persisted. Invalid expressions or timezones are reported in the
+/// response body rather than as an error status.
+#[utoipa::path(
+    post,
+    path = "/api/v1/schedules/validate",
+    tag = "schedules",
+    summary = "Validate and preview a schedule",
+    description = "Validate a cron expression and return its next run times in the given timezone, without saving a schedule",
+    request_body = ValidateScheduleRequest,
+    responses(
+        (status = 200, description = "Validation result with upcoming run times"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn validate_schedule(Json(request): Json<ValidateScheduleRequest>) -> RestResult<impl IntoResponse> {
+    let timezone = request.timezone.unwrap_or_else(|| "UTC".to_string());
+    let count = request.count.unwrap_or(DEFAULT_PREVIEW_COUNT);
+    info!(
+        "Previewing schedule '{}' in timezone {}",
+        request.cron_expression, timezone
+    );
+
+    let (field, code) = if parse_timezone(&timezone).is_err() {
+        ("timezone", "INVALID_TIMEZONE")
+    } else {
+        ("cron_expression", "INVALID_CRON_EXPRESSION")
+    };
+
+    let response = match preview_schedule(&request.cron_expression, &timezone, chrono::Utc::now(), count) {
+        Ok(runs) => ValidateScheduleResponse {
+            valid: true,
+            errors: Vec::new(),
+            timezone,
+            next_runs: runs.iter().map(|run| run.to_rfc3339()).collect(),
+        },
+        Err(e) => ValidateScheduleResponse {
+            valid: false,
+            errors: vec![ValidationErrorDetail {
+                field: Some(field.to_string()),
+                message: e.to_string(),
+                code: code.to_string(),
+            }],
+            timezone,
+            next_runs: Vec::new(),
+        },
+    };
+
+    Ok(Json(ApiResponse::new(response)))
+}
+
+/// Map a scheduler failure to a REST error
+fn scheduler_error(err: SchedulerError) -> RestError {
+    match err {
+        SchedulerError::ScheduleNotFound(id) => RestError::not_found("Schedule", &id.to_string()),
+        other => RestError::InternalError(other.to_string()),
+    }
+}
+
+/// Pause every enabled schedule
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/schedules/pause-all",
+    tag = "schedules",
+    summary = "Pause all schedules",
+    description = "Disable every enabled schedule, remembering which ones to re-enable on resume. Schedules that are already disabled stay disabled after resuming.",
+    responses(
+        (status = 200, description = "Schedules paused", body = BulkScheduleResponse),
+        (status = 503, description = "Scheduler is not available"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pause_all_schedules(State(ctx): State<TasksContext>) -> RestResult<impl IntoResponse> {
+    info!("Pausing all schedules");
+
+    let scheduler = ctx
+        .scheduler_service
+        .as_ref()
+        .ok_or_else(|| RestError::ServiceUnavailable("Scheduler is not available".to_string()))?;
+    let paused = scheduler.pause_all().await.map_err(scheduler_error)?;
+
+    Ok(Json(ApiResponse::new(BulkScheduleResponse::new(paused))))
+}
+
+/// Resume the schedules disabled by pausing all schedules
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/schedules/resume-all",
+    tag = "schedules",
+    summary = "Resume paused schedules",
+    description = "Re-enable the schedules disabled by pause-all. Schedules explicitly enabled or disabled since the pause are not touched.",
+    responses(
+        (status = 200, description = "Schedules resumed", body = BulkScheduleResponse),
+        (status = 503, description = "Scheduler is not available"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn resume_all_schedules(State(ctx): State<TasksContext>) -> RestResult<impl IntoResponse> {
+    info!("Resuming paused schedules");
+
+    let scheduler = ctx
+        .scheduler_service
+        .as_ref()
+        .ok_or_else(|| RestError::ServiceUnavailable("Scheduler is not available".to_string()))?;
+    let resumed = scheduler.resume_all().await.map_err(scheduler_error)?;
+
+    Ok(Json(ApiResponse::new(BulkScheduleResponse::new(resumed))))
+}
+
+/// Enable or disable several schedules at once
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/schedules/enabled",
+    tag = "schedules",
+    summary = "Enable or disable schedules in bulk",
+    description = "Set the enabled state of several schedules. Nothing is changed if any schedule does not exist.",
+    request_body = BulkSetEnabledRequest,
+    responses(
+        (status = 200, description = "Schedules updated", body = BulkScheduleResponse),
+        (status = 400, description = "No schedule IDs given"),
+        (status = 404, description = "A schedule was not found"),
+        (status = 503, description = "Scheduler is not available"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn set_schedules_enabled(
+    State(ctx): State<TasksContext>,
+    Json(request): Json<BulkSetEnabledRequest>,
+) -> RestResult<impl IntoResponse> {
+    info!(
+        "Setting enabled={} on {} schedules",
+        request.enabled,
+        request.schedule_ids.len()
+    );
+
+    if request.schedule_ids.is_empty() {
+        return Err(RestError::BadRequest("scheduleIds cannot be empty".to_string()));
+    }
+
+    let scheduler = ctx
+        .scheduler_service
+        .as_ref()
+        .ok_or_else(|| RestError::ServiceUnavailable("Scheduler is not available".to_string()))?;
+    scheduler
+        .set_enabled(request.schedule_ids.clone(), request.enabled)
+        .await
+        .map_err(scheduler_error)?;
+
+    Ok(Json(ApiResponse::new(BulkScheduleResponse::new(request.schedule_ids))))
+}
+
 /// Update an existing schedule
 
 pub async fn update_schedule(
@@ -487,15 +663,16 @@ pub async fn update_schedule(
     }
 
     if let Some(ref cron_expression) = request.cron_expression {
-        if let Err(validation_err) = validator.validate_string(cron_expression, "cron_expression") {
-            warn!("Invalid cron expression provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
+        if let Err(cron_err) = validate_cron_expression(cron_expression) {
+            warn!("Invalid cron expression provided in update: {}", cron_err);
+            return Err(RestError::BadRequest(cron_err.to_string()));
         }
+    }
 
-        // Basic cron expression validation
-        if cron_expression.trim().is_empty() {
-            return Err(RestError::BadRequest("Cron expression cannot be empty".to_string()));
+    if let Some(jitter_seconds) = request.jitter_seconds {
+        if let Err(jitter_err) = validate_jitter(jitter_seconds) {
+            warn!("Invalid jitter provided in update: {}", jitter_err);
+            return Err(RestError::BadRequest(jitter_err.to_string()));
         }
     }
 
@@ -525,11 +702,34 @@ pub async fn update_schedule(
         existing_schedule.next_run = None;
     }
     if let Some(enabled) = request.enabled {
+        // An explicit choice overrides a pending resume of paused schedules
         existing_schedule.enabled = enabled;
+        existing_schedule.paused = false;
+    }
+    if let Some(serial) = request.serial {
+        existing_schedule.serial = serial;
+    }
+    if let Some(timezone) = request.timezone {
+        if let Some(Err(tz_err)) = timezone.as_deref().map(parse_timezone) {
+            warn!("Invalid timezone provided in update: {}", tz_err);
+            return Err(RestError::BadRequest(tz_err.to_string()));
+        }
+        existing_schedule.timezone = timezone;
+        // Run times shift with the timezone, so let the scheduler recalculate them
+        existing_schedule.next_run = None;
+    }
+    if let Some(misfire_policy) = request.misfire_policy {
+        existing_schedule.misfire_policy = misfire_policy;
+    }
+    if let Some(max_concurrent) = request.max_concurrent {
+        existing_schedule.max_concurrent = Some(max_concurrent).filter(|&max| max > 0);
+    }
+    if let Some(jitter_seconds) = request.jitter_seconds {
+        existing_schedule.jitter_seconds = Some(jitter_seconds).filter(|&jitter| jitter > 0);
     }
     if let Some(destinations) = request.output_destinations {
         // Validate the new output destinations
-        if let Err(validation_err) = validate_output_destinations(&destinations) {
+        if let Err(validation_err) = validate_output_destinations(&destinations, ctx.max_output_destinations) {
             warn!("Invalid output destinations provided in update: {}", validation_err);
             return Err(validation_err);
         }
@@ -570,7 +770,17 @@ pub async fn delete_schedule(
 ) -> RestResult<impl IntoResponse> {
     info!("Deleting schedule with ID: {}", schedule_id);
 
-    let api_id = ApiId::from_string(schedule_id.clone());
+    delete_schedule_by_id(&ctx, &schedule_id).await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Schedule {} deleted", schedule_id)
+    })))
+}
+
+/// Remove a schedule from the running scheduler and the database
+async fn delete_schedule_by_id(ctx: &TasksContext, schedule_id: &str) -> RestResult<()> {
+    let api_id = ApiId::from_string(schedule_id.to_string());
     let schedule_repo = ctx.repositories.schedule_repository();
 
     // Remove from running scheduler first if available
@@ -589,10 +799,25 @@ pub async fn delete_schedule(
         .await
         .map_err(RestError::Database)?;
 
-    Ok(Json(serde_json::json!({
-        "success": true,
-        "message": format!("Schedule {} deleted", schedule_id)
-    })))
+    Ok(())
+}
+
+/// Persist an explicit enable or disable, which also cancels a pending resume from pause-all
+async fn persist_enabled(ctx: &TasksContext, schedule_id: &str, enabled: bool) -> RestResult<UnifiedSchedule> {
+    let schedule_repo = ctx.repositories.schedule_repository();
+    let api_id = ApiId::from_string(schedule_id.to_string());
+
+    let mut schedule = schedule_repo
+        .find_by_id(api_id.as_i32().unwrap_or(0))
+        .await
+        .map_err(RestError::Database)?
+        .ok_or_else(|| RestError::not_found("Schedule", schedule_id))?;
+
+    schedule.enabled = enabled;
+    schedule.paused = false;
+    schedule.updated_at = chrono::Utc::now();
+
+    schedule_repo.update(schedule).await.map_err(RestError::Database)
 }
 
 /// Enable a schedule
@@ -603,35 +828,33 @@ pub async fn enable_schedule(
 ) -> RestResult<impl IntoResponse> {
     info!("Enabling schedule with ID: {}", schedule_id);
 
-    let api_id = ApiId::from_string(schedule_id.clone());
-    let schedule_repo = ctx.repositories.schedule_repository();
+    enable_schedule_by_id(&ctx, &schedule_id).await?;
 
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Schedule {} enabled", schedule_id)
+    })))
+}
+
+/// Enable a schedule in the database and add it to the running scheduler
+async fn enable_schedule_by_id(ctx: &TasksContext, schedule_id: &str) -> RestResult<()> {
     // Update the schedule in the database
-    schedule_repo
-        .set_enabled(api_id.clone(), true)
-        .await
-        .map_err(RestError::Database)?;
+    let updated_schedule = persist_enabled(ctx, schedule_id, true).await?;
 
     // Add to running scheduler if available
     if let Some(scheduler) = &ctx.scheduler_service {
-        // Get the updated schedule to add to scheduler
-        if let Ok(Some(updated_schedule)) = schedule_repo.find_by_id(api_id.as_i32().unwrap_or(0)).await {
-            if let Err(scheduler_err) = scheduler.add_schedule(updated_schedule).await {
-                warn!("Failed to add enabled schedule to running scheduler: {}", scheduler_err);
-                // Don't fail the request - schedule is enabled in database
-            } else {
-                info!(
-                    "Successfully added enabled schedule {} to running scheduler",
-                    schedule_id
-                );
-            }
+        if let Err(scheduler_err) = scheduler.add_schedule(updated_schedule).await {
+            warn!("Failed to add enabled schedule to running scheduler: {}", scheduler_err);
+            // Don't fail the request - schedule is enabled in database
+        } else {
+            info!(
+                "Successfully added enabled schedule {} to running scheduler",
+                schedule_id
+            );
         }
     }
 
-    Ok(Json(serde_json::json!({
-        "success": true,
-        "message": format!("Schedule {} enabled", schedule_id)
-    })))
+    Ok(())
 }
 
 /// Disable a schedule
@@ -642,12 +865,21 @@ pub async fn disable_schedule(
 ) -> RestResult<impl IntoResponse> {
     info!("Disabling schedule with ID: {}", schedule_id);
 
-    let api_id = ApiId::from_string(schedule_id.clone());
-    let schedule_repo = ctx.repositories.schedule_repository();
+    disable_schedule_by_id(&ctx, &schedule_id).await?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": format!("Schedule {} disabled", schedule_id)
+    })))
+}
+
+/// Remove a schedule from the running scheduler and disable it in the database
+async fn disable_schedule_by_id(ctx: &TasksContext, schedule_id: &str) -> RestResult<()> {
+    let api_id = ApiId::from_string(schedule_id.to_string());
 
     // Remove from running scheduler first if available
     if let Some(scheduler) = &ctx.scheduler_service {
-        if let Err(scheduler_err) = scheduler.remove_schedule(api_id.clone()).await {
+        if let Err(scheduler_err) = scheduler.remove_schedule(api_id).await {
             warn!("Failed to remove schedule from running scheduler: {}", scheduler_err);
             // Continue with database update even if scheduler removal fails
         } else {
@@ -655,16 +887,46 @@ pub async fn disable_schedule(
         }
     }
 
-    // Update the schedule in the database
-    schedule_repo
-        .set_enabled(api_id, false)
-        .await
-        .map_err(RestError::Database)?;
+    // Update the schedule in the database; an explicit disable is not undone by resume-all
+    persist_enabled(ctx, schedule_id, false).await?;
 
-    Ok(Json(serde_json::json!({
-        "success": true,
-        "message": format!("Schedule {} disabled", schedule_id)
-    })))
+    Ok(())
+}
+
+/// Enable, disable or delete several schedules in one request
+#[utoipa::path(
+    post,
+    path = "/api/v1/schedules/batch",
+    tag = "schedules",
+    summary = "Apply a batch of schedule operations",
+    description = "Enable, disable or delete schedules by ID. Operations run in order and independently; each reports its own result, so a failed operation does not undo the others.",
+    request_body = BatchRequest,
+    responses(
+        (status = 200, description = "Batch applied, see per-operation results", body = BatchResponse),
+        (status = 400, description = "No operations, or too many operations"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn batch_schedules(
+    State(ctx): State<TasksContext>,
+    Json(request): Json<BatchRequest>,
+) -> RestResult<impl IntoResponse> {
+    info!("Applying {} schedule batch operations", request.operations.len());
+
+    let response = run_batch(request, |operation| {
+        let ctx = &ctx;
+        async move {
+            match operation.action {
+                BatchAction::Enable => enable_schedule_by_id(ctx, operation.id.as_str()).await,
+                BatchAction::Disable => disable_schedule_by_id(ctx, operation.id.as_str()).await,
+                BatchAction::Delete => delete_schedule_by_id(ctx, operation.id.as_str()).await,
+                BatchAction::Cancel => Err(RestError::BadRequest("Schedules cannot be cancelled".to_string())),
+            }
+        }
+    })
+    .await?;
+
+    Ok(Json(ApiResponse::new(response)))
 }
 
 /// Trigger a schedule manually
@@ -730,6 +992,9 @@ pub async fn trigger_schedule(
         scheduled_for: None, // Immediate execution
         error_message: None,
         output_destinations: output_destinations_clone,
+        schedule_id: Some(schedule.id.clone()),
+        execution_id: None,
+        log_level: None,
     };
 
     // Create the job
@@ -2,24 +2,33 @@
 
 use axum::{
     extract::{Path, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
 use ratchet_api_types::ApiId;
 use ratchet_core::validation::{ErrorSanitizer, InputValidator};
-use ratchet_interfaces::DatabaseError;
+use ratchet_interfaces::{registry::ValidationError, DatabaseError, TaskMetadata};
 use ratchet_mcp::server::task_dev_tools::{
     CreateTaskRequest as McpCreateTaskRequest, DeleteTaskRequest as McpDeleteTaskRequest,
-    EditTaskRequest as McpEditTaskRequest, RunTaskTestsRequest as McpRunTaskTestsRequest,
+    EditTaskRequest as McpEditTaskRequest, PreflightTaskRequest, RunTaskTestsRequest as McpRunTaskTestsRequest,
+};
+use ratchet_storage::revalidation::{revalidate_tasks, DEFAULT_REVALIDATION_CONCURRENCY};
+use ratchet_web::{
+    extract_task_filters,
+    middleware::{content_etag, timestamp_etag},
+    ApiResponse, QueryParams,
 };
-use ratchet_web::{extract_task_filters, ApiResponse, QueryParams};
 use tracing::{info, warn};
 
 use crate::{
     context::TasksContext,
-    errors::{RestError, RestResult},
-    models::{common::StatsResponse, CreateTaskRequest, TaskStats, UpdateTaskRequest},
+    errors::{FieldErrors, RestError, RestResult},
+    handlers::run_batch,
+    models::{
+        common::{BatchAction, BatchRequest, BatchResponse, StatsResponse},
+        CreateTaskRequest, RevalidateTasksRequest, TaskStats, UpdateTaskRequest,
+    },
 };
 
 /// List all tasks with optional filtering and pagination
@@ -31,6 +40,7 @@ use crate::{
     description = "Retrieve all tasks with optional filtering and pagination",
     responses(
         (status = 200, description = "List of tasks retrieved successfully"),
+        (status = 304, description = "Not modified since the ETag in If-None-Match"),
         (status = 400, description = "Invalid query parameters"),
         (status = 500, description = "Internal server error")
     )
@@ -44,12 +54,25 @@ pub async fn list_tasks(State(ctx): State<TasksContext>, query: QueryParams) ->
     let filters = extract_task_filters(&query.0.filters);
 
     let task_repo = ctx.repositories.task_repository();
+
+    // Opt-in keyset pagination, stable under concurrent inserts
+    if let Some(page) = query.0.cursor_page() {
+        let tasks = task_repo
+            .find_after(filters, page.after.clone(), page.fetch_limit())
+            .await
+            .map_err(RestError::Database)?;
+        let response = page.into_response(tasks, |task| &task.id);
+        let etag = content_etag(&response);
+        return Ok(([(header::ETAG, etag)], Json(response)));
+    }
+
     let list_response = task_repo
         .find_with_list_input(filters, list_input)
         .await
         .map_err(RestError::Database)?;
 
-    Ok(Json(ApiResponse::from(list_response)))
+    let etag = content_etag(&list_response);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::from(list_response))))
 }
 
 /// Get a specific task by ID
@@ -64,6 +87,7 @@ pub async fn list_tasks(State(ctx): State<TasksContext>, query: QueryParams) ->
     ),
     responses(
         (status = 200, description = "Task retrieved successfully"),
+        (status = 304, description = "Not modified since the ETag in If-None-Match"),
         (status = 404, description = "Task not found"),
         (status = 500, description = "Internal server error")
     )
@@ -107,7 +131,8 @@ pub async fn get_task(State(ctx): State<TasksContext>, Path(task_id): Path<Strin
         })?
         .ok_or_else(|| RestError::not_found("Task", &task_id))?;
 
-    Ok(Json(ApiResponse::new(task)))
+    let etag = timestamp_etag(task.updated_at);
+    Ok(([(header::ETAG, etag)], Json(ApiResponse::new(task))))
 }
 
 /// Create a new task
@@ -119,7 +144,7 @@ pub async fn get_task(State(ctx): State<TasksContext>, Path(task_id): Path<Strin
     description = "Create a new task with the provided configuration",
     responses(
         (status = 201, description = "Task created successfully"),
-        (status = 400, description = "Invalid task data"),
+        (status = 400, description = "Invalid task data; failing fields are listed under `error.errors`"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -129,55 +154,21 @@ pub async fn create_task(
 ) -> RestResult<impl IntoResponse> {
     info!("Creating task: {}", request.name);
 
-    // Validate the request input
+    // Validate the request input, collecting every failing field
     let validator = InputValidator::new();
-    let sanitizer = ErrorSanitizer::default();
-
-    // Validate task name
-    if let Err(validation_err) = validator.validate_task_name(&request.name) {
-        warn!("Invalid task name provided: {}", validation_err);
-        let sanitized_error = sanitizer.sanitize_error(&validation_err);
-        return Err(RestError::BadRequest(sanitized_error.message));
-    }
+    let mut errors = FieldErrors::new();
 
-    // Validate description if provided
+    errors.check("name", validator.validate_task_name(&request.name));
     if let Some(ref description) = request.description {
-        if let Err(validation_err) = validator.validate_string(description, "description") {
-            warn!("Invalid task description provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
-    }
-
-    // Validate version
-    if let Err(validation_err) = validator.validate_semver(&request.version) {
-        warn!("Invalid task version provided: {}", validation_err);
-        let sanitized_error = sanitizer.sanitize_error(&validation_err);
-        return Err(RestError::BadRequest(sanitized_error.message));
+        errors.check("description", validator.validate_string(description, "description"));
     }
+    errors.check("version", validator.validate_semver(&request.version));
 
     // Note: Path validation would be done here if the request had a path field
 
-    // Validate JSON schemas if provided
-    if let Some(ref input_schema) = request.input_schema {
-        let input_str = serde_json::to_string(input_schema)
-            .map_err(|e| RestError::BadRequest(format!("Invalid input schema JSON: {}", e)))?;
-        if let Err(validation_err) = validator.validate_json(&input_str) {
-            warn!("Invalid input schema provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
-    }
-
-    if let Some(ref output_schema) = request.output_schema {
-        let output_str = serde_json::to_string(output_schema)
-            .map_err(|e| RestError::BadRequest(format!("Invalid output schema JSON: {}", e)))?;
-        if let Err(validation_err) = validator.validate_json(&output_str) {
-            warn!("Invalid output schema provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
-    }
+    errors.check_json(&validator, "inputSchema", request.input_schema.as_ref());
+    errors.check_json(&validator, "outputSchema", request.output_schema.as_ref());
+    errors.into_result()?;
 
     // Create UnifiedTask from request
     let unified_task = ratchet_api_types::UnifiedTask {
@@ -244,51 +235,20 @@ pub async fn update_task(
         return Err(RestError::BadRequest(sanitized_error.message));
     }
 
-    // Validate update request fields if provided
+    // Validate the fields being updated, collecting every failing one
+    let mut errors = FieldErrors::new();
     if let Some(ref name) = request.name {
-        if let Err(validation_err) = validator.validate_task_name(name) {
-            warn!("Invalid task name provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
+        errors.check("name", validator.validate_task_name(name));
     }
-
     if let Some(ref description) = request.description {
-        if let Err(validation_err) = validator.validate_string(description, "description") {
-            warn!("Invalid task description provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
+        errors.check("description", validator.validate_string(description, "description"));
     }
-
     if let Some(ref version) = request.version {
-        if let Err(validation_err) = validator.validate_semver(version) {
-            warn!("Invalid task version provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
-    }
-
-    // Validate JSON schemas if provided
-    if let Some(ref input_schema) = request.input_schema {
-        let input_str = serde_json::to_string(input_schema)
-            .map_err(|e| RestError::BadRequest(format!("Invalid input schema JSON: {}", e)))?;
-        if let Err(validation_err) = validator.validate_json(&input_str) {
-            warn!("Invalid input schema provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
-    }
-
-    if let Some(ref output_schema) = request.output_schema {
-        let output_str = serde_json::to_string(output_schema)
-            .map_err(|e| RestError::BadRequest(format!("Invalid output schema JSON: {}", e)))?;
-        if let Err(validation_err) = validator.validate_json(&output_str) {
-            warn!("Invalid output schema provided: {}", validation_err);
-            let sanitized_error = sanitizer.sanitize_error(&validation_err);
-            return Err(RestError::BadRequest(sanitized_error.message));
-        }
+        errors.check("version", validator.validate_semver(version));
     }
+    errors.check_json(&validator, "inputSchema", request.input_schema.as_ref());
+    errors.check_json(&validator, "outputSchema", request.output_schema.as_ref());
+    errors.into_result()?;
 
     let api_id = ApiId::from_string(task_id.clone());
     let task_repo = ctx.repositories.task_repository();
@@ -350,17 +310,24 @@ pub async fn delete_task(
 ) -> RestResult<impl IntoResponse> {
     info!("Deleting task with ID: {}", task_id);
 
+    delete_task_by_id(&ctx, &task_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Delete a task after checking that it exists
+async fn delete_task_by_id(ctx: &TasksContext, task_id: &str) -> RestResult<()> {
     // Validate task ID input
     let validator = InputValidator::new();
     let sanitizer = ErrorSanitizer::default();
 
-    if let Err(validation_err) = validator.validate_string(&task_id, "task_id") {
+    if let Err(validation_err) = validator.validate_string(task_id, "task_id") {
         warn!("Invalid task ID provided: {}", validation_err);
         let sanitized_error = sanitizer.sanitize_error(&validation_err);
         return Err(RestError::BadRequest(sanitized_error.message));
     }
 
-    let api_id = ApiId::from_string(task_id.clone());
+    let api_id = ApiId::from_string(task_id.to_string());
     let task_repo = ctx.repositories.task_repository();
 
     // Check if task exists before deletion
@@ -373,7 +340,7 @@ pub async fn delete_task(
         })?;
 
     if existing_task.is_none() {
-        return Err(RestError::not_found("Task", &task_id));
+        return Err(RestError::not_found("Task", task_id));
     }
 
     // Delete the task using the repository
@@ -382,7 +349,7 @@ pub async fn delete_task(
         .await
         .map_err(|e| RestError::InternalError(format!("Failed to delete task: {}", e)))?;
 
-    Ok(StatusCode::NO_CONTENT)
+    Ok(())
 }
 
 /// Enable a task
@@ -424,6 +391,238 @@ pub async fn disable_task(
     })))
 }
 
+/// Enable, disable or delete several tasks in one request
+#[utoipa::path(
+    post,
+    path = "/api/v1/tasks/batch",
+    tag = "tasks",
+    summary = "Apply a batch of task operations",
+    description = "Enable, disable or delete tasks by ID. Operations run in order and independently; each reports its own result, so a failed operation does not undo the others.",
+    request_body = BatchRequest,
+    responses(
+        (status = 200, description = "Batch applied, see per-operation results", body = BatchResponse),
+        (status = 400, description = "No operations, or too many operations"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn batch_tasks(
+    State(ctx): State<TasksContext>,
+    Json(request): Json<BatchRequest>,
+) -> RestResult<impl IntoResponse> {
+    info!("Applying {} task batch operations", request.operations.len());
+
+    let response = run_batch(request, |operation| {
+        let ctx = &ctx;
+        async move {
+            let task_repo = ctx.repositories.task_repository();
+            match operation.action {
+                BatchAction::Enable => task_repo
+                    .set_enabled(operation.id, true)
+                    .await
+                    .map_err(RestError::Database),
+                BatchAction::Disable => task_repo
+                    .set_enabled(operation.id, false)
+                    .await
+                    .map_err(RestError::Database),
+                BatchAction::Delete => delete_task_by_id(ctx, operation.id.as_str()).await,
+                BatchAction::Cancel => Err(RestError::BadRequest("Tasks cannot be cancelled".to_string())),
+            }
+        }
+    })
+    .await?;
+
+    Ok(Json(ApiResponse::new(response)))
+}
+
+/// Run a preflight check for a task
+///
+/// Validates the task schemas, compiles its source and dry-runs it with sample
+/// input, returning a consolidated report. Stage failures are part of the
+/// report rather than an error response.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tasks/{id}/preflight",
+    tag = "tasks",
+    summary = "Preflight a task",
+    description = "Validate, compile and dry-run a task with sample input before enabling it in production",
+    params(
+        ("id" = String, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Preflight report generated"),
+        (status = 400, description = "Invalid preflight request"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn preflight_task(
+    State(ctx): State<TasksContext>,
+    Path(task_id): Path<String>,
+    request: Option<Json<PreflightTaskRequest>>,
+) -> RestResult<impl IntoResponse> {
+    info!("Running preflight for task with ID: {}", task_id);
+
+    let validator = InputValidator::new();
+    if let Err(validation_err) = validator.validate_string(&task_id, "task_id") {
+        warn!("Invalid task ID provided: {}", validation_err);
+        let sanitizer = ErrorSanitizer::default();
+        let sanitized_error = sanitizer.sanitize_error(&validation_err);
+        return Err(RestError::BadRequest(sanitized_error.message));
+    }
+
+    let mcp_service = ctx
+        .mcp_task_service
+        .as_ref()
+        .ok_or_else(|| RestError::InternalError("MCP task development service is not available".to_string()))?;
+
+    let task = ctx
+        .repositories
+        .task_repository()
+        .find_by_id(ApiId::from_string(task_id.clone()).as_i32().unwrap_or(0))
+        .await
+        .map_err(RestError::Database)?
+        .ok_or_else(|| RestError::not_found("Task", &task_id))?;
+
+    let mut preflight_request = request
+        .map(|Json(request)| request)
+        .unwrap_or_else(|| PreflightTaskRequest::new(String::new()));
+    preflight_request.task_id = task.uuid.to_string();
+
+    match mcp_service.preflight_task(preflight_request).await {
+        Ok(report) => {
+            if !report.passed {
+                warn!("Preflight failed for task {}", task.name);
+            }
+            Ok(Json(ApiResponse::new(report)))
+        }
+        Err(mcp_error) => {
+            warn!("Failed to run preflight for task {}: {}", task.name, mcp_error);
+            Err(RestError::InternalError(format!(
+                "Task preflight failed: {}",
+                mcp_error
+            )))
+        }
+    }
+}
+
+/// Validate a task without executing it
+///
+/// Compiles the task source and checks its input and output schemas, returning
+/// every problem found, located by line and column where possible. An invalid
+/// task is reported in the result rather than as an error response.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tasks/{id}/validate",
+    tag = "tasks",
+    summary = "Validate a task",
+    description = "Compile a task and check its schemas without executing it, reporting syntax errors by line",
+    params(
+        ("id" = String, Path, description = "Task ID")
+    ),
+    responses(
+        (status = 200, description = "Validation result generated"),
+        (status = 400, description = "Invalid task ID"),
+        (status = 404, description = "Task not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn validate_task(
+    State(ctx): State<TasksContext>,
+    Path(task_id): Path<String>,
+) -> RestResult<impl IntoResponse> {
+    info!("Validating task with ID: {}", task_id);
+
+    let validator = InputValidator::new();
+    if let Err(validation_err) = validator.validate_string(&task_id, "task_id") {
+        warn!("Invalid task ID provided: {}", validation_err);
+        let sanitizer = ErrorSanitizer::default();
+        let sanitized_error = sanitizer.sanitize_error(&validation_err);
+        return Err(RestError::BadRequest(sanitized_error.message));
+    }
+
+    let task = ctx
+        .repositories
+        .task_repository()
+        .find_by_id(ApiId::from_string(task_id.clone()).as_i32().unwrap_or(0))
+        .await
+        .map_err(RestError::Database)?
+        .ok_or_else(|| RestError::not_found("Task", &task_id))?;
+
+    let metadata = TaskMetadata {
+        name: task.name.clone(),
+        version: task.version.clone(),
+        description: task.description.clone(),
+        input_schema: task.input_schema.clone(),
+        output_schema: task.output_schema.clone(),
+        metadata: task.metadata.clone(),
+    };
+    let mut result = ctx
+        .validator
+        .validate_content(&task.source_code, &metadata)
+        .await
+        .map_err(|e| RestError::InternalError(format!("Task validation failed: {}", e)))?;
+
+    for (field, schema) in [
+        ("input_schema", &task.input_schema),
+        ("output_schema", &task.output_schema),
+    ] {
+        if let Some(Err(e)) = schema.as_ref().map(ratchet_core::validation::validate_schema) {
+            result.add_error(ValidationError::new(Some(field), "INVALID_SCHEMA", e.to_string()));
+        }
+    }
+
+    if !result.valid {
+        warn!(
+            "Task {} failed validation with {} errors",
+            task.name,
+            result.errors.len()
+        );
+    }
+    Ok(Json(ApiResponse::new(result)))
+}
+
+/// Re-validate all stored tasks
+///
+/// Checks every stored task against the current validator, refreshing
+/// `validated_at` for tasks that pass. Tasks that fail are listed in the
+/// report rather than turned into an error response.
+#[utoipa::path(
+    post,
+    path = "/api/v1/tasks/revalidate",
+    tag = "tasks",
+    summary = "Re-validate all tasks",
+    description = "Re-run validation across all stored tasks, e.g. after an upgrade, and report the tasks that no longer pass",
+    request_body = RevalidateTasksRequest,
+    responses(
+        (status = 200, description = "Re-validation report generated"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn revalidate_all_tasks(
+    State(ctx): State<TasksContext>,
+    request: Option<Json<RevalidateTasksRequest>>,
+) -> RestResult<impl IntoResponse> {
+    let concurrency = request
+        .and_then(|Json(request)| request.concurrency)
+        .unwrap_or(DEFAULT_REVALIDATION_CONCURRENCY);
+    info!("Re-validating all tasks with concurrency {}", concurrency);
+
+    let task_repo = ctx.repositories.task_repository();
+    let report = revalidate_tasks(task_repo, ctx.validator.as_ref(), concurrency)
+        .await
+        .map_err(RestError::Database)?;
+
+    for failure in &report.failures {
+        warn!(
+            "Task {} failed re-validation: {}",
+            failure.name,
+            failure.errors.join("; ")
+        );
+    }
+
+    Ok(Json(ApiResponse::new(report)))
+}
+
 /// Sync tasks from registry
 pub async fn sync_tasks(State(ctx): State<TasksContext>) -> RestResult<impl IntoResponse> {
     info!("Syncing tasks from registry");
@@ -711,6 +910,7 @@ pub async fn mcp_get_results(
         // Advanced boolean filtering
         can_retry: None,
         can_cancel: None,
+        labels: None,
     };
 
     match execution_repo.find_with_filters(empty_filters, pagination).await {
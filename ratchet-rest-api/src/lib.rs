@@ -85,16 +85,36 @@ use utoipa::OpenApi;
         handlers::tasks::list_tasks,
         handlers::tasks::create_task,
         handlers::tasks::get_task,
+        handlers::tasks::preflight_task,
+        handlers::tasks::validate_task,
+        handlers::tasks::revalidate_all_tasks,
+        handlers::tasks::batch_tasks,
 
         // Execution endpoints
         handlers::executions::list_executions,
+        handlers::executions::get_execution_stats,
 
         // Job endpoints
         handlers::jobs::list_jobs,
+        handlers::jobs::list_dead_letter_jobs,
+        handlers::jobs::requeue_job,
+        handlers::jobs::batch_jobs,
 
         // Schedule endpoints (only annotated ones)
         handlers::schedules::list_schedules,
         handlers::schedules::create_schedule,
+        handlers::schedules::validate_schedule,
+        handlers::schedules::pause_all_schedules,
+        handlers::schedules::resume_all_schedules,
+        handlers::schedules::set_schedules_enabled,
+        handlers::schedules::batch_schedules,
+
+        // Admin endpoints
+        handlers::admin::list_caches,
+        handlers::admin::flush_cache,
+        handlers::admin::flush_cache_key,
+        handlers::admin::list_plugins,
+        handlers::admin::export_audit_log,
 
         // Monitoring and metrics  
         handlers::metrics::get_metrics,
@@ -105,6 +125,7 @@ use utoipa::OpenApi;
             // Task request/response models
             models::tasks::CreateTaskRequest,
             models::tasks::UpdateTaskRequest,
+            models::tasks::RevalidateTasksRequest,
             models::tasks::ValidateTaskRequest,
             models::tasks::ValidateTaskResponse,
             models::tasks::ValidationErrorDetail,
@@ -118,6 +139,7 @@ use utoipa::OpenApi;
             models::executions::UpdateExecutionRequest,
             models::executions::RetryExecutionRequest,
             models::executions::ExecutionStats,
+            models::executions::ExecutionStatsBucket,
 
             // Job request/response models
             models::jobs::CreateJobRequest,
@@ -127,8 +149,19 @@ use utoipa::OpenApi;
             // Schedule request/response models
             models::schedules::CreateScheduleRequest,
             models::schedules::UpdateScheduleRequest,
+            models::schedules::ValidateScheduleRequest,
+            models::schedules::ValidateScheduleResponse,
+            models::schedules::BulkSetEnabledRequest,
+            models::schedules::BulkScheduleResponse,
             models::schedules::ScheduleStats,
 
+            // Batch operation models
+            models::common::BatchAction,
+            models::common::BatchOperation,
+            models::common::BatchRequest,
+            models::common::BatchItemResult,
+            models::common::BatchResponse,
+
             // Domain types from ratchet-api-types
             ratchet_api_types::UnifiedOutputDestination,
             ratchet_api_types::UnifiedWebhookConfig,
@@ -165,6 +198,7 @@ use utoipa::OpenApi;
         (name = "workers", description = "Worker monitoring and management"),
         (name = "mcp", description = "MCP (Model Context Protocol) development tools"),
         (name = "health", description = "System health and monitoring"),
+        (name = "admin", description = "Administrative operations such as cache management and audit log export"),
         (name = "monitoring", description = "System metrics and observability")
     )
 )]
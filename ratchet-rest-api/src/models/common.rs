@@ -1,5 +1,6 @@
 //! Common types for REST API requests and responses
 
+use ratchet_api_types::ApiId;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -92,3 +93,81 @@ impl<T> StatsResponse<T> {
         self
     }
 }
+
+/// Maximum number of operations accepted in one batch request
+pub const MAX_BATCH_OPERATIONS: usize = 500;
+
+/// Action applied to a single resource in a batch request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchAction {
+    Enable,
+    Disable,
+    Cancel,
+    Delete,
+}
+
+/// One operation in a batch request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOperation {
+    /// Resource the action applies to
+    pub id: ApiId,
+
+    /// Action to apply
+    pub action: BatchAction,
+}
+
+/// Request applying several operations in one call
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchRequest {
+    /// Operations, applied in order
+    pub operations: Vec<BatchOperation>,
+}
+
+/// Outcome of one operation in a batch
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItemResult {
+    pub id: ApiId,
+    pub action: BatchAction,
+    pub success: bool,
+
+    /// Why the operation failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Per-operation results of a batch request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResponse {
+    /// Results in the order the operations were given
+    pub results: Vec<BatchItemResult>,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+impl BatchResponse {
+    pub fn new(results: Vec<BatchItemResult>) -> Self {
+        let succeeded = results.iter().filter(|result| result.success).count();
+        Self {
+            failed: results.len() - succeeded,
+            succeeded,
+            results,
+        }
+    }
+}
+
+/// Deserialize a field that tells "absent" apart from `null`
+///
+/// Use with `#[serde(default)]`: a missing field stays `None`, while an explicit
+/// `null` becomes `Some(None)`, e.g. to clear an optional setting in an update.
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -1,6 +1,7 @@
 //! Execution-related request and response models
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use utoipa::ToSchema;
 use ratchet_api_types::{ApiId, ExecutionStatus};
 
@@ -19,6 +20,10 @@ pub struct CreateExecutionRequest {
 
     /// Optional scheduled execution time (ISO 8601 format)
     pub scheduled_for: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Optional key/value labels, filterable with `?label=key:value`
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
 }
 
 /// Request to update execution status
@@ -34,7 +39,8 @@ pub struct UpdateExecutionRequest {
     /// Error message if execution failed
     pub error_message: Option<String>,
 
-    /// Additional error details
+    /// Additional error details; for JavaScript errors, the message and the
+    /// `stack` of frames leading to the throw, innermost first
     pub error_details: Option<serde_json::Value>,
 
     /// Execution progress percentage (0.0 to 100.0)
@@ -79,4 +85,50 @@ pub struct ExecutionStats {
 
     /// Number of executions in the last 24 hours
     pub executions_last_24h: u64,
+
+    /// Bucket width of `history` in seconds
+    pub interval_seconds: u64,
+
+    /// Executions queued in the requested range, one entry per interval
+    pub history: Vec<ExecutionStatsBucket>,
+}
+
+/// Executions queued within one interval of the execution history
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionStatsBucket {
+    /// Start of the interval
+    pub start: chrono::DateTime<chrono::Utc>,
+
+    /// Executions by status
+    pub pending: u64,
+    pub running: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub cancelled: u64,
+
+    /// Median duration of finished executions in milliseconds
+    pub duration_p50_ms: Option<i64>,
+
+    /// 90th percentile duration in milliseconds
+    pub duration_p90_ms: Option<i64>,
+
+    /// 99th percentile duration in milliseconds
+    pub duration_p99_ms: Option<i64>,
+}
+
+impl From<ratchet_interfaces::ExecutionHistoryBucket> for ExecutionStatsBucket {
+    fn from(bucket: ratchet_interfaces::ExecutionHistoryBucket) -> Self {
+        Self {
+            start: bucket.start,
+            pending: bucket.pending,
+            running: bucket.running,
+            completed: bucket.completed,
+            failed: bucket.failed,
+            cancelled: bucket.cancelled,
+            duration_p50_ms: bucket.duration_p50_ms,
+            duration_p90_ms: bucket.duration_p90_ms,
+            duration_p99_ms: bucket.duration_p99_ms,
+        }
+    }
 }
@@ -23,8 +23,18 @@ pub struct CreateJobRequest {
     /// Optional scheduled execution time (ISO 8601 format)
     pub scheduled_for: Option<chrono::DateTime<chrono::Utc>>,
 
+    /// Run the job this many seconds from now instead of at `scheduledFor`
+    pub delay_seconds: Option<u64>,
+
+    /// Spread the run time randomly over this many seconds (at most 3600)
+    pub jitter_seconds: Option<u32>,
+
     /// Optional output destinations for job results
     pub output_destinations: Option<Vec<ratchet_api_types::UnifiedOutputDestination>>,
+
+    /// Log level to run the task at (trace, debug, info, warn or error)
+    #[serde(default)]
+    pub log_level: Option<String>,
 }
 
 /// Request to update job status
@@ -2,7 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
-use ratchet_api_types::{ApiId, UnifiedOutputDestination};
+use ratchet_api_types::{ApiId, MisfirePolicy, UnifiedOutputDestination};
+
+use crate::models::tasks::ValidationErrorDetail;
 
 /// Request to create a new schedule
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -23,6 +25,26 @@ pub struct CreateScheduleRequest {
     /// Whether the schedule is enabled
     pub enabled: Option<bool>,
 
+    /// Run executions strictly one at a time in trigger order (defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<bool>,
+
+    /// IANA timezone to evaluate the cron expression in, e.g. `Europe/Amsterdam` (defaults to UTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// What to do about runs missed while the scheduler was down (defaults to SKIP)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub misfire_policy: Option<MisfirePolicy>,
+
+    /// Most executions allowed to be active at once; further triggers are skipped (0 or unset means unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<u32>,
+
+    /// Randomly delay each run by up to this many seconds, at most 3600 (0 or unset means none)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jitter_seconds: Option<u32>,
+
     /// Optional output destinations for execution results (webhooks, files, etc.)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_destinations: Option<Vec<UnifiedOutputDestination>>,
@@ -44,11 +66,100 @@ pub struct UpdateScheduleRequest {
     /// Updated enabled status
     pub enabled: Option<bool>,
 
+    /// Updated serial execution mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<bool>,
+
+    /// Updated IANA timezone for the cron expression, or `null` to go back to UTC
+    #[serde(
+        default,
+        deserialize_with = "crate::models::common::deserialize_nullable",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = Option<String>)]
+    pub timezone: Option<Option<String>>,
+
+    /// Updated misfire policy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub misfire_policy: Option<MisfirePolicy>,
+
+    /// Updated concurrency limit; 0 removes the limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent: Option<u32>,
+
+    /// Updated jitter window in seconds; 0 removes the jitter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jitter_seconds: Option<u32>,
+
     /// Updated output destinations for execution results (webhooks, files, etc.)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_destinations: Option<Vec<UnifiedOutputDestination>>,
 }
 
+/// Request to validate a cron expression and preview its next runs
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateScheduleRequest {
+    /// Cron expression to validate
+    pub cron_expression: String,
+
+    /// IANA timezone to compute run times in (default: UTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Number of upcoming runs to return (default: 5, at most 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+}
+
+/// Schedule validation result with a preview of upcoming runs
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateScheduleResponse {
+    /// Whether the cron expression and timezone are valid
+    pub valid: bool,
+
+    /// Validation errors, empty when valid
+    pub errors: Vec<ValidationErrorDetail>,
+
+    /// Timezone the run times are expressed in
+    pub timezone: String,
+
+    /// Upcoming run times as RFC 3339 timestamps with the timezone's offset
+    pub next_runs: Vec<String>,
+}
+
+/// Request to enable or disable several schedules at once
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkSetEnabledRequest {
+    /// Schedules to change; nothing is changed if any of them does not exist
+    pub schedule_ids: Vec<ApiId>,
+
+    /// Whether the schedules should be enabled
+    pub enabled: bool,
+}
+
+/// Schedules affected by a bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkScheduleResponse {
+    /// IDs of the schedules that were changed
+    pub schedule_ids: Vec<ApiId>,
+
+    /// Number of schedules that were changed
+    pub count: usize,
+}
+
+impl BulkScheduleResponse {
+    pub fn new(schedule_ids: Vec<ApiId>) -> Self {
+        Self {
+            count: schedule_ids.len(),
+            schedule_ids,
+        }
+    }
+}
+
 /// Schedule statistics
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -74,3 +185,20 @@ pub struct ScheduleStats {
     /// Next scheduled execution time
     pub next_execution: Option<chrono::DateTime<chrono::Utc>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_timezone_distinguishes_null_from_absent() {
+        let request: UpdateScheduleRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(request.timezone, None);
+
+        let request: UpdateScheduleRequest = serde_json::from_str(r#"{ "timezone": null }"#).unwrap();
+        assert_eq!(request.timezone, Some(None));
+
+        let request: UpdateScheduleRequest = serde_json::from_str(r#"{ "timezone": "Europe/Amsterdam" }"#).unwrap();
+        assert_eq!(request.timezone, Some(Some("Europe/Amsterdam".to_string())));
+    }
+}
@@ -1,5 +1,6 @@
 //! Task-related request and response models
 
+use ratchet_core::validation::{ErrorSanitizer, InputValidationError};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -91,6 +92,17 @@ pub struct ValidationErrorDetail {
     pub code: String,
 }
 
+impl ValidationErrorDetail {
+    /// Detail for a field that failed input validation, with a sanitized message
+    pub fn for_field(field: impl Into<String>, error: &InputValidationError) -> Self {
+        Self {
+            field: Some(field.into()),
+            message: ErrorSanitizer::default().sanitize_error(error).message,
+            code: error.code().to_string(),
+        }
+    }
+}
+
 /// Validation warning detail
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -105,6 +117,14 @@ pub struct ValidationWarningDetail {
     pub code: String,
 }
 
+/// Request to re-validate all stored tasks
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RevalidateTasksRequest {
+    /// Maximum number of tasks validated at the same time (default: 8)
+    pub concurrency: Option<usize>,
+}
+
 /// Task synchronization response
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
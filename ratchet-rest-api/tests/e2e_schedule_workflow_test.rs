@@ -683,3 +683,109 @@ async fn test_schedule_webhook_workflow() -> Result<()> {
 
     Ok(())
 }
+
+/// End-to-end test for pausing and resuming all schedules
+///
+/// A schedule that was already disabled before the pause must stay disabled
+/// after resuming, while a schedule that was enabled comes back.
+#[tokio::test]
+#[ignore] // Integration test - requires running server
+async fn test_pause_and_resume_all_schedules() -> Result<()> {
+    let client = Client::new();
+    let api_base = "http://localhost:8080/api/v1";
+
+    println!("🧪 Testing pause-all and resume-all...");
+
+    let tasks_response = client.get(format!("{}/tasks", api_base)).send().await?;
+    let tasks_data: Value = tasks_response.json().await?;
+    let tasks = tasks_data["data"].as_array().expect("Tasks data should be an array");
+    let addition_task = tasks
+        .iter()
+        .find(|task| task["name"].as_str() == Some("addition"))
+        .expect("Addition task should exist");
+    let task_id = addition_task["id"].as_str().expect("Task should have an id");
+
+    let mut schedule_ids = Vec::new();
+    for (name, enabled) in [("test-pause-enabled", true), ("test-pause-disabled", false)] {
+        let create_response = client
+            .post(format!("{}/schedules", api_base))
+            .json(&json!({
+                "taskId": task_id,
+                "name": name,
+                "cronExpression": "0 0 * * *",
+                "enabled": enabled
+            }))
+            .send()
+            .await?;
+        assert_eq!(create_response.status(), 201, "Schedule creation should succeed");
+        let created_schedule: Value = create_response.json().await?;
+        schedule_ids.push(
+            created_schedule["id"]
+                .as_str()
+                .expect("Schedule should have an id")
+                .to_string(),
+        );
+    }
+    let (enabled_id, disabled_id) = (&schedule_ids[0], &schedule_ids[1]);
+
+    let pause_response = client
+        .post(format!("{}/schedules/pause-all", api_base))
+        .send()
+        .await?;
+    assert_eq!(pause_response.status(), 200, "Pause-all should succeed");
+
+    let paused: Value = client
+        .get(format!("{}/schedules/{}", api_base, enabled_id))
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(paused["enabled"], false, "Enabled schedule should be paused");
+
+    println!("✅ Paused all schedules");
+
+    let resume_response = client
+        .post(format!("{}/schedules/resume-all", api_base))
+        .send()
+        .await?;
+    assert_eq!(resume_response.status(), 200, "Resume-all should succeed");
+
+    let resumed: Value = client
+        .get(format!("{}/schedules/{}", api_base, enabled_id))
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(resumed["enabled"], true, "Paused schedule should be resumed");
+
+    let still_disabled: Value = client
+        .get(format!("{}/schedules/{}", api_base, disabled_id))
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert_eq!(
+        still_disabled["enabled"], false,
+        "Schedule disabled before the pause should stay disabled"
+    );
+
+    println!("✅ Resume restored the previous enabled states");
+
+    let bulk_response = client
+        .post(format!("{}/schedules/enabled", api_base))
+        .json(&json!({ "scheduleIds": schedule_ids, "enabled": false }))
+        .send()
+        .await?;
+    assert_eq!(bulk_response.status(), 200, "Bulk disable should succeed");
+
+    for schedule_id in &schedule_ids {
+        client
+            .delete(format!("{}/schedules/{}", api_base, schedule_id))
+            .send()
+            .await?;
+    }
+
+    println!("✅ Pause/resume test complete!");
+
+    Ok(())
+}
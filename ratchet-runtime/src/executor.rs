@@ -159,6 +159,7 @@ impl TaskExecutor for ExecutionEngine {
             input_data,
             execution_context: exec_context,
             correlation_id,
+            task_source: None,
         };
 
         // Send task to worker and wait for result
@@ -5,6 +5,7 @@
 
 use log::{debug, error, info, warn};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::process::{Child, Command};
@@ -12,8 +13,9 @@ use tokio::sync::{mpsc, oneshot, Mutex};
 use uuid::Uuid;
 
 use ratchet_ipc::{
-    CoordinatorMessage, IpcError, MessageEnvelope, TaskExecutionResult, TaskValidationResult, WorkerMessage,
-    WorkerStatus,
+    decode_message, encode_message, negotiate_version, CoordinatorMessage, IpcCompression, IpcError, MessageEnvelope,
+    TaskExecutionResult, TaskValidationResult, WorkerError, WorkerMessage, WorkerStatus, IPC_PROTOCOL_VERSION,
+    MIN_IPC_PROTOCOL_VERSION,
 };
 
 /// Configuration for worker processes
@@ -26,6 +28,8 @@ pub struct WorkerConfig {
     pub health_check_interval_seconds: u64,
     pub task_timeout_seconds: u64,
     pub worker_idle_timeout_seconds: Option<u64>,
+    /// Compression of large messages sent to workers that support it
+    pub ipc_compression: IpcCompression,
 }
 
 impl Default for WorkerConfig {
@@ -38,6 +42,7 @@ impl Default for WorkerConfig {
             health_check_interval_seconds: 30,
             task_timeout_seconds: 300,               // 5 minutes
             worker_idle_timeout_seconds: Some(3600), // 1 hour
+            ipc_compression: IpcCompression::default(),
         }
     }
 }
@@ -69,7 +74,7 @@ impl WorkerProcess {
     /// Spawn a new worker process
     pub async fn spawn(
         worker_id: String,
-        _config: &WorkerConfig,
+        config: &WorkerConfig,
         message_tx: mpsc::UnboundedSender<WorkerToManagerMessage>,
     ) -> Result<Self, WorkerProcessError> {
         debug!("Spawning worker process: {}", worker_id);
@@ -106,10 +111,17 @@ impl WorkerProcess {
 
         let (stdin_tx, stdin_rx) = mpsc::unbounded_channel();
 
+        // Protocol version announced by the worker, 0 until its first message arrives
+        let peer_version = Arc::new(AtomicU32::new(0));
+
         // Spawn stdin writer task
         let worker_id_clone = worker_id.clone();
+        let writer_peer_version = peer_version.clone();
+        let compression = config.ipc_compression.clone();
         tokio::spawn(async move {
-            if let Err(e) = Self::stdin_writer_task(worker_id_clone, stdin, stdin_rx).await {
+            if let Err(e) =
+                Self::stdin_writer_task(worker_id_clone, stdin, stdin_rx, writer_peer_version, compression).await
+            {
                 error!("Worker stdin writer failed: {}", e);
             }
         });
@@ -117,7 +129,7 @@ impl WorkerProcess {
         // Spawn stdout reader task
         let worker_id_clone = worker_id.clone();
         tokio::spawn(async move {
-            if let Err(e) = Self::stdout_reader_task(worker_id_clone, stdout, message_tx).await {
+            if let Err(e) = Self::stdout_reader_task(worker_id_clone, stdout, message_tx, peer_version).await {
                 error!("Worker stdout reader failed: {}", e);
             }
         });
@@ -171,6 +183,7 @@ impl WorkerProcess {
             input_data,
             execution_context,
             correlation_id,
+            task_source: None,
         };
 
         self.status = WorkerProcessStatus::Busy;
@@ -186,6 +199,7 @@ impl WorkerProcess {
             started_at: chrono::Utc::now(),
             completed_at: chrono::Utc::now(),
             duration_ms: 100,
+            logs: Vec::new(),
         })
     }
 
@@ -265,13 +279,21 @@ impl WorkerProcess {
         worker_id: String,
         mut stdin: tokio::process::ChildStdin,
         mut rx: mpsc::UnboundedReceiver<WorkerMessage>,
+        peer_version: Arc<AtomicU32>,
+        compression: IpcCompression,
     ) -> Result<(), WorkerProcessError> {
         use tokio::io::AsyncWriteExt;
 
         while let Some(message) = rx.recv().await {
+            // Stay on the oldest protocol until the worker has announced its version
+            let protocol_version = match peer_version.load(Ordering::Acquire) {
+                0 => MIN_IPC_PROTOCOL_VERSION,
+                version => negotiate_version(Some(version)),
+            };
+
             let envelope = MessageEnvelope::new(message);
-            let json =
-                serde_json::to_string(&envelope).map_err(|e| WorkerProcessError::CommunicationError(e.to_string()))?;
+            let json = encode_message(&envelope, protocol_version, &compression)
+                .map_err(|e| WorkerProcessError::CommunicationError(e.to_string()))?;
 
             let line = format!("{}\n", json);
 
@@ -303,10 +325,15 @@ impl WorkerProcess {
     }
 
     /// Stdout reader task
+    ///
+    /// A worker whose messages carry an unsupported protocol version is
+    /// rejected: the manager is told via [`WorkerError::IncompatibleProtocol`]
+    /// and nothing further is read from it.
     async fn stdout_reader_task(
         worker_id: String,
-        stdout: tokio::process::ChildStdout,
+        stdout: impl tokio::io::AsyncRead + Unpin,
         message_tx: mpsc::UnboundedSender<WorkerToManagerMessage>,
+        peer_version: Arc<AtomicU32>,
     ) -> Result<(), WorkerProcessError> {
         use tokio::io::AsyncBufReadExt;
 
@@ -325,8 +352,9 @@ impl WorkerProcess {
                     // Remove newline
                     line.truncate(line.trim_end().len());
 
-                    match serde_json::from_str::<MessageEnvelope<CoordinatorMessage>>(&line) {
+                    match decode_message::<CoordinatorMessage>(&line) {
                         Ok(envelope) => {
+                            peer_version.store(envelope.protocol_version, Ordering::Release);
                             debug!("Received message from worker {}: {:?}", worker_id, envelope.message);
                             // Send message to manager
                             let worker_msg = WorkerToManagerMessage {
@@ -337,6 +365,28 @@ impl WorkerProcess {
                                 error!("Failed to send message to manager: {}", e);
                             }
                         }
+                        Err(IpcError::ProtocolVersionMismatch { actual, .. }) => {
+                            error!(
+                                "Worker {} speaks IPC protocol version {}, but this coordinator supports versions {}-{}; \
+                                 refusing to use it. Make sure the worker and coordinator come from the same release.",
+                                worker_id, actual, MIN_IPC_PROTOCOL_VERSION, IPC_PROTOCOL_VERSION
+                            );
+                            let worker_msg = WorkerToManagerMessage {
+                                worker_id: worker_id.clone(),
+                                message: CoordinatorMessage::Error {
+                                    correlation_id: None,
+                                    error: WorkerError::IncompatibleProtocol {
+                                        worker_version: actual,
+                                        min_supported: MIN_IPC_PROTOCOL_VERSION,
+                                        max_supported: IPC_PROTOCOL_VERSION,
+                                    },
+                                },
+                            };
+                            if let Err(e) = message_tx.send(worker_msg) {
+                                error!("Failed to send message to manager: {}", e);
+                            }
+                            break;
+                        }
                         Err(e) => {
                             warn!(
                                 "Failed to parse message from worker {}: {} - line: {}",
@@ -476,6 +526,13 @@ impl WorkerProcessManager {
                             debug!("Worker {} completed task", worker.id);
                             worker.status = WorkerProcessStatus::Ready;
                         }
+                        CoordinatorMessage::Error {
+                            error: WorkerError::IncompatibleProtocol { .. },
+                            ..
+                        } => {
+                            warn!("Worker {} rejected: incompatible IPC protocol version", worker.id);
+                            worker.status = WorkerProcessStatus::Failed;
+                        }
                         CoordinatorMessage::Error { .. } => {
                             debug!("Worker {} reported error", worker.id);
                             worker.status = WorkerProcessStatus::Ready;
@@ -542,6 +599,7 @@ impl WorkerProcessManager {
                                     started_at: chrono::Utc::now(),
                                     completed_at: chrono::Utc::now(),
                                     duration_ms: 0,
+                                    logs: Vec::new(),
                                 };
                                 if sender.send(error_result).is_err() {
                                     warn!("Failed to send error result - receiver may have been dropped");
@@ -719,6 +777,69 @@ mod tests {
         assert_eq!(worker.id, "test-worker");
     }
 
+    #[tokio::test]
+    async fn test_version_mismatched_worker_is_rejected() {
+        let (message_tx, mut message_rx) = mpsc::unbounded_channel();
+        let peer_version = Arc::new(AtomicU32::new(0));
+
+        // A worker from a newer release announces itself, then keeps talking
+        let mut ready = serde_json::to_value(MessageEnvelope::new(CoordinatorMessage::Ready {
+            worker_id: "future-worker".to_string(),
+        }))
+        .unwrap();
+        ready["protocol_version"] = serde_json::json!(IPC_PROTOCOL_VERSION + 1);
+        let stdout = format!("{}\n{}\n", ready, ready);
+
+        WorkerProcess::stdout_reader_task(
+            "future-worker".to_string(),
+            stdout.as_bytes(),
+            message_tx,
+            peer_version.clone(),
+        )
+        .await
+        .unwrap();
+
+        let rejection = message_rx.recv().await.unwrap();
+        assert!(matches!(
+            rejection.message,
+            CoordinatorMessage::Error {
+                correlation_id: None,
+                error: WorkerError::IncompatibleProtocol { worker_version, .. },
+            } if worker_version == IPC_PROTOCOL_VERSION + 1
+        ));
+        // Nothing else from the worker is forwarded, and no version is negotiated
+        assert!(message_rx.recv().await.is_none());
+        assert_eq!(peer_version.load(Ordering::Acquire), 0);
+
+        // The manager marks the worker as failed so it is never handed work
+        let workers = Arc::new(Mutex::new(vec![WorkerProcess {
+            id: "future-worker".to_string(),
+            pid: None,
+            started_at: chrono::Utc::now(),
+            restart_count: 0,
+            status: WorkerProcessStatus::Starting,
+            child: None,
+            stdin_tx: None,
+            last_health_check: None,
+        }]));
+        let (manager_tx, manager_rx) = mpsc::unbounded_channel();
+        manager_tx.send(rejection).unwrap();
+        drop(manager_tx);
+
+        WorkerProcessManager::process_worker_messages(
+            workers.clone(),
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(HashMap::new())),
+            manager_rx,
+        )
+        .await;
+
+        let workers = workers.lock().await;
+        assert_eq!(workers[0].status, WorkerProcessStatus::Failed);
+        assert!(!workers[0].is_available());
+    }
+
     #[tokio::test]
     async fn test_worker_manager_creation() {
         let config = WorkerConfig::default();
@@ -4,6 +4,7 @@
 //! in isolated processes for thread safety and fault tolerance.
 
 use log::{debug, error, info};
+use tracing::Instrument;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -121,9 +122,21 @@ impl Worker {
                 input_data,
                 execution_context,
                 correlation_id,
+                ..
             } => {
+                // Honour the per-execution log level override for everything logged by this task
+                let span = match execution_context.log_level.as_deref() {
+                    Some(level) => tracing::info_span!(
+                        "task_execution",
+                        task_id = %execution_context.task_id,
+                        task_log_level = level
+                    ),
+                    None => tracing::info_span!("task_execution", task_id = %execution_context.task_id),
+                };
+
                 let result = self
                     .execute_task_impl(job_id, task_id, &task_path, input_data, execution_context)
+                    .instrument(span)
                     .await;
                 Ok(Some(CoordinatorMessage::TaskResult {
                     job_id,
@@ -184,6 +197,7 @@ impl Worker {
                     started_at,
                     completed_at,
                     duration_ms,
+                    logs: Vec::new(),
                 }
             }
             Err(e) => {
@@ -207,6 +221,7 @@ impl Worker {
                     started_at,
                     completed_at,
                     duration_ms,
+                    logs: Vec::new(),
                 }
             }
         }
@@ -7,9 +7,10 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
-use ratchet_api_types::UnifiedTask;
 use ratchet_interfaces::{database::RepositoryFactory, registry::TaskRegistry};
 
 use crate::embedded::{EmbeddedTask, EmbeddedTaskRegistry};
@@ -22,44 +23,88 @@ use crate::embedded::{EmbeddedTask, EmbeddedTaskRegistry};
 pub struct BridgeTaskRegistry {
     service: Arc<ratchet_registry::DefaultRegistryService>,
     repositories: Option<Arc<dyn RepositoryFactory>>,
+    /// Stores registry tasks in the database; tasks are only kept in memory without it
+    database_sync: Option<Arc<ratchet_registry::DatabaseSync>>,
     embedded_registry: EmbeddedTaskRegistry,
 }
 
 // Import the RegistryService trait to access methods
 use ratchet_registry::RegistryService;
 
+/// Repository tasks are cloned from when no Git registry sources are configured
+const SAMPLE_TASKS_REPOSITORY: &str = "https://github.com/ratchet-runner/ratchet-repo-samples.git";
+
+/// Source recorded for tasks built into the server
+const EMBEDDED_TASK_SOURCE: &str = "embedded";
+
 impl BridgeTaskRegistry {
-    pub async fn new(_config: &crate::config::ServerConfig) -> anyhow::Result<Self> {
-        // Create a Git source pointing to the default repository
-        let git_source = ratchet_registry::TaskSource::Git {
-            url: "https://github.com/ratchet-runner/ratchet-repo-samples.git".to_string(),
-            auth: None,
-            config: ratchet_registry::config::GitConfig {
-                branch: "main".to_string(),
-                subdirectory: None,
-                shallow: true,
-                depth: Some(1),
-                sync_strategy: ratchet_registry::config::GitSyncStrategy::Fetch,
-                cleanup_on_error: true,
-                verify_signatures: false,
-                allowed_refs: None,
-                timeout: std::time::Duration::from_secs(300),
-                max_repo_size: None,
-                local_cache_path: None,
-                cache_ttl: std::time::Duration::from_secs(3600),
-                keep_history: false,
-            },
-        };
+    pub async fn new(config: &crate::config::ServerConfig) -> anyhow::Result<Self> {
+        Self::build(config, None).await
+    }
+
+    /// Create a registry whose tasks are synced to `storage`
+    ///
+    /// See [`BridgeTaskRegistry::sync_tasks_to_database`].
+    pub async fn with_storage(
+        config: &crate::config::ServerConfig,
+        storage: Arc<ratchet_storage::seaorm::repositories::RepositoryFactory>,
+    ) -> anyhow::Result<Self> {
+        Self::build(config, Some(Arc::new(ratchet_registry::DatabaseSync::new(storage)))).await
+    }
+
+    async fn build(
+        config: &crate::config::ServerConfig,
+        database_sync: Option<Arc<ratchet_registry::DatabaseSync>>,
+    ) -> anyhow::Result<Self> {
+        let mut sources: Vec<_> = config
+            .registry
+            .git_repositories
+            .iter()
+            .map(|repository| ratchet_registry::TaskSource::Git {
+                url: repository.url.clone(),
+                auth: repository.auth.clone(),
+                config: repository.config.clone(),
+            })
+            .collect();
+        for endpoint in &config.registry.http_endpoints {
+            sources.push(ratchet_registry::TaskSource::Http {
+                url: endpoint.url.clone(),
+                auth: endpoint.auth.clone(),
+                polling_interval: endpoint.polling_interval,
+                discovery: endpoint.discovery.clone(),
+            });
+        }
+        if sources.is_empty() {
+            // Without configured repositories or registries, serve the sample tasks
+            sources.push(ratchet_registry::TaskSource::Git {
+                url: SAMPLE_TASKS_REPOSITORY.to_string(),
+                auth: None,
+                config: ratchet_registry::config::GitConfig::default(),
+            });
+        }
+
+        for path in &config.registry.filesystem_paths {
+            sources.push(ratchet_registry::TaskSource::Filesystem {
+                path: path.clone(),
+                recursive: true,
+                watch: config.registry.watched_paths.contains(path),
+            });
+        }
 
         let registry_config = ratchet_registry::RegistryConfig {
-            sources: vec![git_source],
+            sources,
             sync_interval: std::time::Duration::from_secs(300),
             enable_auto_sync: false,
             enable_validation: true,
             cache_config: ratchet_registry::config::CacheConfig::default(),
+            conflict_resolution: config.registry.conflict_resolution.clone(),
         };
 
-        let service = Arc::new(ratchet_registry::DefaultRegistryService::new(registry_config));
+        let mut service = ratchet_registry::DefaultRegistryService::new(registry_config);
+        if let Some(database_sync) = &database_sync {
+            service = service.with_sync_service(database_sync.clone());
+        }
+        let service = Arc::new(service);
         let embedded_registry = EmbeddedTaskRegistry::new();
 
         // Load embedded tasks first
@@ -114,6 +159,7 @@ impl BridgeTaskRegistry {
         Ok(Self {
             service,
             repositories: None,
+            database_sync,
             embedded_registry,
         })
     }
@@ -123,37 +169,214 @@ impl BridgeTaskRegistry {
         self.repositories = Some(repositories);
     }
 
-    /// Sync discovered tasks to the database
+    /// Reload tasks from watched filesystem paths when they change on disk
+    ///
+    /// A changed task is validated before it replaces the registered version
+    /// and its database record; a task that fails to load or validate keeps
+    /// serving the version registered before the change.
+    pub async fn start_hot_reload(&self) -> anyhow::Result<()> {
+        let events = self.service.subscribe();
+        self.service.start_watching().await.map_err(convert_registry_error)?;
+
+        let reloader = TaskReloader {
+            service: self.service.clone(),
+            repositories: self.repositories.clone(),
+        };
+        tokio::spawn(reloader.run(events));
+
+        Ok(())
+    }
+
+    /// Sync registry tasks to the database
+    ///
+    /// Each source's tasks are synced with the conflict resolution strategy
+    /// configured for that source, and embedded tasks with the default one.
+    /// Does nothing for a registry created without storage.
     pub async fn sync_tasks_to_database(&self) -> anyhow::Result<()> {
-        if let Some(repositories) = &self.repositories {
-            let registry = self.service.registry().await;
-            let tasks = registry.list_tasks().await.map_err(convert_registry_error)?;
+        let Some(database_sync) = &self.database_sync else {
+            return Ok(());
+        };
 
-            let task_repo = repositories.task_repository();
+        let mut result = self.service.sync_to_database().await.map_err(convert_registry_error)?;
 
-            for task in tasks {
-                // Convert registry task to storage task
-                let unified_task = convert_task_definition_to_unified(&task);
+        let registry = self.service.registry().await;
+        let embedded: Vec<_> = registry
+            .list_tasks()
+            .await
+            .map_err(convert_registry_error)?
+            .into_iter()
+            .filter(|task| task.reference.source == EMBEDDED_TASK_SOURCE)
+            .map(|task| (*task).clone())
+            .collect();
+        result.merge(
+            database_sync
+                .sync_source_definitions(embedded, Default::default())
+                .await
+                .map_err(convert_registry_error)?,
+        );
+
+        for conflict in &result.conflicts {
+            tracing::warn!(
+                "Task {} v{} changed in the registry and the database; keeping the database copy until resolved",
+                conflict.task_ref.name,
+                conflict.task_ref.version
+            );
+        }
+        for error in &result.errors {
+            tracing::warn!(
+                "Failed to sync task {} to database: {}",
+                error.task_ref.name,
+                error.error
+            );
+        }
+        tracing::info!(
+            "Synced registry tasks to database: {} added, {} updated, {} conflicts, {} errors",
+            result.tasks_added,
+            result.tasks_updated,
+            result.conflicts.len(),
+            result.errors.len()
+        );
+
+        Ok(())
+    }
+}
+
+/// Applies changes of watched registry tasks to the registry and the database
+struct TaskReloader {
+    service: Arc<ratchet_registry::DefaultRegistryService>,
+    repositories: Option<Arc<dyn RepositoryFactory>>,
+}
 
-                // Check if task already exists in database
-                if let Ok(Some(_existing)) = task_repo.find_by_uuid(task.metadata.uuid).await {
-                    tracing::debug!("Task {} already exists in database, skipping", task.metadata.name);
-                    continue;
+impl TaskReloader {
+    async fn run(self, mut events: broadcast::Receiver<ratchet_registry::RegistryEvent>) {
+        loop {
+            match events.recv().await {
+                Ok(event) => self.apply(event).await,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Task reloader fell behind and missed {} registry events", skipped);
                 }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
 
-                // Create new task in database
-                match task_repo.create(unified_task).await {
-                    Ok(_) => {
-                        tracing::info!("Successfully synced task {} to database", task.metadata.name);
-                    }
-                    Err(e) => {
-                        tracing::warn!("Failed to sync task {} to database: {:?}", task.metadata.name, e);
-                    }
+    async fn apply(&self, event: ratchet_registry::RegistryEvent) {
+        use ratchet_registry::RegistryEvent;
+
+        match event {
+            RegistryEvent::TaskAdded(discovered) | RegistryEvent::TaskUpdated(discovered) => {
+                if let Err(e) = self.reload(&discovered.task_ref).await {
+                    tracing::warn!(
+                        "Keeping the registered version of task {}; reload from {} failed: {}",
+                        discovered.task_ref.name,
+                        discovered.task_ref.source,
+                        e
+                    );
+                }
+            }
+            RegistryEvent::TaskRemoved(task_ref) => {
+                if let Err(e) = self.remove(&task_ref).await {
+                    tracing::warn!("Failed to remove task {}: {}", task_ref.name, e);
                 }
             }
+            RegistryEvent::Error(message) => tracing::warn!("Task watcher error: {}", message),
+            RegistryEvent::BulkSync(_) => {}
+        }
+    }
+
+    /// Load, validate and apply the task at `task_ref`
+    async fn reload(&self, task_ref: &ratchet_registry::TaskReference) -> anyhow::Result<()> {
+        let task = self.service.load_task(task_ref).await.map_err(convert_registry_error)?;
+        validate_task_source_directory(task_ref).await?;
+
+        let registry = self.service.registry().await;
+        registry
+            .replace_task(task.clone())
+            .await
+            .map_err(convert_registry_error)?;
+        self.store(&task).await?;
+
+        tracing::info!(
+            "Reloaded task {} v{} from {}",
+            task.metadata.name,
+            task.metadata.version,
+            task_ref.source
+        );
+        Ok(())
+    }
+
+    /// Drop a task removed from disk from the registry and disable its database record
+    async fn remove(&self, task_ref: &ratchet_registry::TaskReference) -> anyhow::Result<()> {
+        let registry = self.service.registry().await;
+        registry
+            .remove_task_reference(task_ref)
+            .await
+            .map_err(convert_registry_error)?;
+
+        let Some(repositories) = &self.repositories else {
+            return Ok(());
+        };
+        let task_repo = repositories.task_repository();
+        let existing = task_repo
+            .find_by_name(&task_ref.name)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to look up task: {}", e))?;
+
+        if let Some(existing) = existing.filter(|task| task.version == task_ref.version && task.enabled) {
+            task_repo
+                .set_enabled(existing.id, false)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to disable task: {}", e))?;
+            tracing::info!(
+                "Disabled task {} v{} removed from disk",
+                task_ref.name,
+                task_ref.version
+            );
         }
+
         Ok(())
     }
+
+    /// Create or update the database record of a reloaded task
+    ///
+    /// The task goes through the registry's database sync, so a record also
+    /// changed in the database is resolved with its source's strategy.
+    async fn store(&self, task: &ratchet_registry::TaskDefinition) -> anyhow::Result<()> {
+        if !self.service.has_sync_service() {
+            return Ok(());
+        }
+
+        let result = self
+            .service
+            .sync_reloaded_task(task.clone())
+            .await
+            .map_err(convert_registry_error)?;
+        if let Some(error) = result.errors.first() {
+            return Err(anyhow::anyhow!("Failed to store task: {}", error.error));
+        }
+        if !result.conflicts.is_empty() {
+            tracing::warn!(
+                "Task {} v{} was also changed in the database; keeping the database copy until resolved",
+                task.metadata.name,
+                task.metadata.version
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Compile a reloaded task and check its schemas without executing it
+async fn validate_task_source_directory(task_ref: &ratchet_registry::TaskReference) -> anyhow::Result<()> {
+    let path = PathBuf::from(task_ref.source.strip_prefix("file://").unwrap_or(&task_ref.source));
+    let result = tokio::task::spawn_blocking(move || ratchet_js::validate_task_directory(path)).await?;
+
+    if result.valid {
+        return Ok(());
+    }
+
+    let errors: Vec<String> = result.errors.iter().map(ToString::to_string).collect();
+    Err(anyhow::anyhow!("validation failed: {}", errors.join("; ")))
 }
 
 #[async_trait]
@@ -338,15 +561,15 @@ impl ratchet_interfaces::TaskValidator for BridgeTaskValidator {
 
     async fn validate_content(
         &self,
-        _content: &str,
+        content: &str,
         _metadata: &ratchet_interfaces::TaskMetadata,
     ) -> Result<ratchet_interfaces::ValidationResult, ratchet_interfaces::RegistryError> {
-        // Basic validation - all content is considered valid for now
-        Ok(ratchet_interfaces::ValidationResult {
-            valid: true,
-            errors: vec![],
-            warnings: vec![],
-        })
+        // Compile the source without executing it, as an ES module when it imports or exports.
+        // Stored tasks are re-checked through this as well, see `ratchet_storage::revalidation`
+        let content = content.to_string();
+        tokio::task::spawn_blocking(move || ratchet_js::validate_task_source(&content, None))
+            .await
+            .map_err(|e| ratchet_interfaces::RegistryError::Unavailable { message: e.to_string() })
     }
 
     async fn validate_input(
@@ -363,6 +586,46 @@ impl ratchet_interfaces::TaskValidator for BridgeTaskValidator {
     }
 }
 
+// =============================================================================
+// Logging Bridge Implementation
+// =============================================================================
+
+/// Structured logger that writes events to the server's tracing subscriber
+///
+/// Task console output reaches the configured console and file log layers tagged
+/// with the task and execution it came from.
+pub struct TracingStructuredLogger;
+
+impl ratchet_interfaces::StructuredLogger for TracingStructuredLogger {
+    fn log(&self, event: ratchet_interfaces::LogEvent) {
+        let field = |name: &str| {
+            event
+                .context
+                .get(name)
+                .and_then(|value| value.as_str())
+                .unwrap_or_default()
+        };
+        let source = event.source.as_deref().unwrap_or_default();
+        let task_id = field("task_id");
+        let execution_id = field("execution_id");
+        let message = &event.message;
+
+        match event.level {
+            ratchet_interfaces::LogLevel::Trace => {
+                tracing::trace!(source, task_id, execution_id, "{}", message)
+            }
+            ratchet_interfaces::LogLevel::Debug => {
+                tracing::debug!(source, task_id, execution_id, "{}", message)
+            }
+            ratchet_interfaces::LogLevel::Info => tracing::info!(source, task_id, execution_id, "{}", message),
+            ratchet_interfaces::LogLevel::Warn => tracing::warn!(source, task_id, execution_id, "{}", message),
+            ratchet_interfaces::LogLevel::Error => {
+                tracing::error!(source, task_id, execution_id, "{}", message)
+            }
+        }
+    }
+}
+
 // =============================================================================
 // Helper conversion functions
 // =============================================================================
@@ -422,50 +685,6 @@ fn convert_task_metadata(metadata: &ratchet_registry::TaskMetadata) -> ratchet_i
     }
 }
 
-fn convert_task_definition_to_unified(task_def: &ratchet_registry::TaskDefinition) -> UnifiedTask {
-    use ratchet_api_types::{ApiId, UnifiedTask};
-
-    UnifiedTask {
-        id: ApiId::from_i32(0), // Will be auto-generated by database
-        uuid: task_def.metadata.uuid,
-        name: task_def.metadata.name.clone(),
-        description: task_def.metadata.description.clone(),
-        version: task_def.metadata.version.clone(),
-        enabled: true,
-        registry_source: true,
-        available_versions: vec![task_def.metadata.version.clone()],
-        created_at: task_def.metadata.created_at,
-        updated_at: task_def.metadata.updated_at,
-        validated_at: Some(chrono::Utc::now()),
-        in_sync: true,
-        // New required fields
-        source_code: task_def.script.clone(),
-        source_type: "javascript".to_string(),
-        repository_info: ratchet_api_types::TaskRepositoryInfo {
-            repository_id: ratchet_api_types::ApiId::from_i32(1), // Default repository
-            repository_name: "registry".to_string(),
-            repository_type: "registry".to_string(),
-            repository_path: format!("{}/{}", task_def.reference.source, task_def.reference.name),
-            branch: None,
-            commit: None,
-            can_push: false,
-            auto_push: false,
-        },
-        is_editable: false, // Registry tasks are read-only
-        sync_status: "synced".to_string(),
-        needs_push: false,
-        last_synced_at: Some(chrono::Utc::now()),
-        input_schema: task_def.input_schema.clone(),
-        output_schema: task_def.output_schema.clone(),
-        metadata: Some(serde_json::json!({
-            "source": task_def.reference.source,
-            "script_length": task_def.script.len(),
-            "dependencies": task_def.dependencies,
-            "environment": task_def.environment
-        })),
-    }
-}
-
 /// Load an embedded task into the registry
 async fn load_embedded_task_into_registry(
     registry: Arc<ratchet_registry::DefaultTaskRegistry>,
@@ -513,11 +732,12 @@ async fn load_embedded_task_into_registry(
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
         checksum: None,
+        commit: None,
     };
 
     // Create task reference for embedded tasks
     let task_ref = ratchet_registry::TaskReference {
-        source: "embedded".to_string(),
+        source: EMBEDDED_TASK_SOURCE.to_string(),
         name: embedded_task.name.clone(),
         version: task_metadata.version.clone(),
     };
@@ -17,7 +17,7 @@ pub use repository_config::{
     RepositoryConfig, ConfigProfile, SyncConfig, SecurityConfig, 
     PerformanceConfig, MonitoringConfig, EnvironmentConfig, 
     ConfigMetadata, AuthType, EncryptionAlgorithm, Permission, UserRole,
-    ConfigValidationResult, ConflictResolutionStrategy
+    ConfigValidationResult, SyncConflictStrategy
 };
 
 use anyhow::{Context, Result};
@@ -81,7 +81,7 @@ pub struct SyncConfig {
     /// Enable bidirectional sync
     pub bidirectional: bool,
     /// Conflict resolution strategy
-    pub conflict_resolution: ConflictResolutionStrategy,
+    pub conflict_resolution: SyncConflictStrategy,
     /// Maximum sync timeout in seconds
     pub timeout_seconds: u32,
     /// Number of retry attempts
@@ -106,7 +106,7 @@ impl Default for SyncConfig {
             auto_sync: true,
             sync_interval_minutes: 15,
             bidirectional: true,
-            conflict_resolution: ConflictResolutionStrategy::TakeLocal,
+            conflict_resolution: SyncConflictStrategy::TakeLocal,
             timeout_seconds: 300,
             retry_attempts: 3,
             retry_delay_seconds: 5,
@@ -119,9 +119,12 @@ impl Default for SyncConfig {
     }
 }
 
-/// Conflict resolution strategies
+/// How a repository sync treats files changed both locally and in the repository
+///
+/// Distinct from the registry's `ConflictResolutionStrategy`, which decides
+/// between registry and database copies of a task.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum ConflictResolutionStrategy {
+pub enum SyncConflictStrategy {
     TakeLocal,
     TakeRemote,
     Merge,
@@ -1,6 +1,7 @@
 //! Server configuration
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::net::SocketAddr;
 
 /// Complete server configuration combining all subsystems
@@ -14,6 +15,20 @@ pub struct ServerConfig {
     pub database: DatabaseConfig,
     pub registry: RegistryConfig,
     pub heartbeat: HeartbeatConfig,
+    #[serde(default)]
+    pub features: ratchet_config::FeatureFlags,
+    #[serde(default)]
+    pub alerting: ratchet_config::AlertingConfig,
+    #[serde(default)]
+    pub audit: ratchet_config::AuditConfig,
+    #[serde(default)]
+    pub output: ratchet_config::OutputConfig,
+    #[serde(default)]
+    pub execution: ratchet_config::ExecutionConfig,
+    #[serde(default)]
+    pub http: ratchet_config::HttpConfig,
+    #[serde(default)]
+    pub plugins: ratchet_config::PluginsConfig,
 }
 
 /// HTTP server configuration
@@ -24,7 +39,19 @@ pub struct HttpServerConfig {
     pub enable_request_id: bool,
     pub enable_tracing: bool,
     pub shutdown_timeout_seconds: u64,
+    #[serde(default = "default_max_request_body_bytes")]
+    pub max_request_body_bytes: usize,
     pub tls: Option<TlsConfig>,
+    #[serde(default)]
+    pub rate_limit: ratchet_config::domains::server::RateLimitConfig,
+    #[serde(default)]
+    pub auth: Option<ratchet_config::domains::server::AuthConfig>,
+    #[serde(default)]
+    pub compression: ratchet_config::domains::server::CompressionConfig,
+}
+
+fn default_max_request_body_bytes() -> usize {
+    ratchet_web::middleware::DEFAULT_MAX_BODY_BYTES
 }
 
 /// TLS configuration for HTTPS
@@ -69,6 +96,22 @@ pub struct McpApiConfig {
     pub session_timeout_minutes: u32,
     pub max_events_per_session: usize,
     pub cors_origins: Vec<String>,
+    #[serde(default = "default_true")]
+    pub progress_streaming: bool,
+    /// Backpressure of the progress notification channel
+    #[serde(default)]
+    pub progress: ratchet_config::domains::mcp::ProgressChannelConfig,
+    /// Longest chain of dependent requests a batch may contain
+    #[serde(default = "default_max_dependency_depth")]
+    pub max_dependency_depth: usize,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_dependency_depth() -> usize {
+    ratchet_config::domains::execution::DEFAULT_MAX_DEPENDENCY_DEPTH
 }
 
 /// MCP transport mode configuration
@@ -101,18 +144,51 @@ pub struct DatabaseConfig {
     pub min_connections: u32,
     pub connection_timeout_seconds: u64,
     pub enable_migrations: bool,
+    /// Retrying of queries that fail because SQLite is busy or locked
+    #[serde(default)]
+    pub busy_retry: ratchet_storage::BusyRetryConfig,
 }
 
 /// Registry configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegistryConfig {
     pub filesystem_paths: Vec<String>,
-    pub http_endpoints: Vec<String>,
+    /// Filesystem paths whose tasks are reloaded when they change on disk
+    #[serde(default)]
+    pub watched_paths: Vec<String>,
+    /// Git repositories whose tasks are cloned into the registry
+    #[serde(default)]
+    pub git_repositories: Vec<GitRepositorySource>,
+    /// Conflict resolution strategy of each registry source, by source path or URL
+    #[serde(default)]
+    pub conflict_resolution: HashMap<String, ratchet_registry::ConflictResolutionStrategy>,
+    /// HTTP registries whose tasks are loaded into the registry
+    #[serde(default)]
+    pub http_endpoints: Vec<HttpRegistrySource>,
     pub sync_interval_seconds: u64,
     pub enable_auto_sync: bool,
     pub enable_validation: bool,
 }
 
+/// A Git repository tasks are loaded from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitRepositorySource {
+    pub url: String,
+    pub config: ratchet_registry::config::GitConfig,
+    pub auth: Option<ratchet_registry::config::GitAuth>,
+}
+
+/// An HTTP registry tasks are loaded from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpRegistrySource {
+    pub url: String,
+    pub auth: Option<ratchet_registry::config::HttpAuth>,
+    /// How often the registry is polled for changed tasks
+    pub polling_interval: std::time::Duration,
+    /// Paging and filtering applied when discovering tasks
+    pub discovery: ratchet_registry::config::HttpDiscoveryConfig,
+}
+
 /// Heartbeat configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeartbeatConfig {
@@ -130,7 +206,11 @@ impl Default for HttpServerConfig {
             enable_request_id: true,
             enable_tracing: true,
             shutdown_timeout_seconds: 30,
+            max_request_body_bytes: default_max_request_body_bytes(),
             tls: None,
+            rate_limit: Default::default(),
+            auth: None,
+            compression: Default::default(),
         }
     }
 }
@@ -186,6 +266,9 @@ impl Default for McpApiConfig {
                 "https://claude.ai".to_string(),
                 "http://localhost:3000".to_string(),
             ],
+            progress_streaming: true,
+            progress: Default::default(),
+            max_dependency_depth: default_max_dependency_depth(),
         }
     }
 }
@@ -210,6 +293,7 @@ impl Default for DatabaseConfig {
             min_connections: 1,
             connection_timeout_seconds: 30,
             enable_migrations: true,
+            busy_retry: ratchet_storage::BusyRetryConfig::default(),
         }
     }
 }
@@ -218,6 +302,9 @@ impl Default for RegistryConfig {
     fn default() -> Self {
         Self {
             filesystem_paths: vec!["./tasks".to_string()],
+            watched_paths: vec![],
+            git_repositories: vec![],
+            conflict_resolution: HashMap::new(),
             http_endpoints: vec![],
             sync_interval_seconds: 300,
             enable_auto_sync: true,
@@ -248,6 +335,11 @@ impl ServerConfig {
             .parse()
             .map_err(|e| anyhow::anyhow!("Invalid bind address: {}", e))?;
 
+        let (filesystem_paths, watched_paths) = filesystem_registry_paths(config.registry.as_ref());
+        let git_repositories = git_registry_sources(config.registry.as_ref());
+        let http_endpoints = http_registry_sources(config.registry.as_ref());
+        let conflict_resolution = registry_conflict_resolution(config.registry.as_ref());
+
         Ok(Self {
             server: HttpServerConfig {
                 bind_address,
@@ -255,7 +347,11 @@ impl ServerConfig {
                 enable_request_id: true,      // Default enabled
                 enable_tracing: true,         // Default enabled
                 shutdown_timeout_seconds: 30, // Default value
+                max_request_body_bytes: server_config.max_request_body_bytes,
                 tls: None,                    // TODO: Extract from config if available
+                rate_limit: server_config.rate_limit.clone(),
+                auth: server_config.auth.clone(),
+                compression: server_config.compression.clone(),
             },
             rest_api: RestApiConfig {
                 enabled: true,                 // Default enabled
@@ -268,21 +364,25 @@ impl ServerConfig {
                 enabled: true,                    // Default enabled
                 endpoint: "/graphql".to_string(), // Default endpoint
                 enable_playground: true,          // Default enabled
-                enable_introspection: true,
-                max_query_depth: Some(15),
-                max_query_complexity: Some(1000),
+                enable_introspection: server_config.graphql.enable_introspection,
+                max_query_depth: server_config.graphql.max_depth,
+                max_query_complexity: server_config.graphql.max_complexity,
                 enable_apollo_tracing: false,
             },
             mcp_api: McpApiConfig {
                 enabled: config.mcp.as_ref().is_none_or(|mcp| mcp.enabled), // Default enabled unless explicitly disabled
-                transport: config.mcp.as_ref().map_or(McpTransportMode::Both, |mcp| {
-                    match mcp.transport.as_str() {
-                        "sse" => McpTransportMode::Sse,
-                        "streamable_http" => McpTransportMode::StreamableHttp,
-                        "both" => McpTransportMode::Both,
-                        _ => McpTransportMode::Both, // Default fallback
-                    }
-                }),
+                transport: if config.features.mcp_streamable_http {
+                    config.mcp.as_ref().map_or(McpTransportMode::Both, |mcp| {
+                        match mcp.transport.as_str() {
+                            "sse" => McpTransportMode::Sse,
+                            "streamable_http" => McpTransportMode::StreamableHttp,
+                            "both" => McpTransportMode::Both,
+                            _ => McpTransportMode::Both, // Default fallback
+                        }
+                    })
+                } else {
+                    McpTransportMode::Sse // Streamable HTTP disabled by feature flag
+                },
                 host: config
                     .mcp
                     .as_ref()
@@ -296,6 +396,9 @@ impl ServerConfig {
                     "https://claude.ai".to_string(),
                     "http://localhost:3000".to_string(),
                 ],
+                progress_streaming: config.features.progress_streaming,
+                progress: config.mcp.as_ref().map(|mcp| mcp.progress.clone()).unwrap_or_default(),
+                max_dependency_depth: config.execution.max_dependency_depth,
             },
             logging: LoggingConfig {
                 level: format!("{:?}", config.logging.level).to_lowercase(),
@@ -310,15 +413,463 @@ impl ServerConfig {
                 min_connections: 1,
                 connection_timeout_seconds: server_config.database.connection_timeout.as_secs(),
                 enable_migrations: true,
+                busy_retry: sqlite_busy_retry(&server_config.database.database_specific.sqlite),
             },
             registry: RegistryConfig {
-                filesystem_paths: vec!["./tasks".to_string()], // Default value
-                http_endpoints: Vec::new(),                    // Default empty
-                sync_interval_seconds: 300,                    // Default 5 minutes
-                enable_auto_sync: true,                        // Default enabled
-                enable_validation: true,                       // Default enabled
+                filesystem_paths,
+                watched_paths,
+                git_repositories,
+                conflict_resolution,
+                http_endpoints,
+                sync_interval_seconds: 300, // Default 5 minutes
+                enable_auto_sync: true,     // Default enabled
+                enable_validation: true,    // Default enabled
             },
             heartbeat: HeartbeatConfig::default(),
+            features: config.features,
+            alerting: config.alerting,
+            audit: config.audit,
+            output: config.output,
+            execution: config.execution,
+            http: config.http,
+            plugins: config.plugins,
+        })
+    }
+}
+
+/// Paths of the enabled filesystem registry sources, and those of them watched for changes
+///
+/// Without filesystem sources, tasks are read from `./tasks` and not watched.
+fn filesystem_registry_paths(registry: Option<&ratchet_config::RegistryConfig>) -> (Vec<String>, Vec<String>) {
+    use ratchet_config::domains::registry::RegistrySourceType;
+
+    let sources: Vec<_> = registry
+        .map(|registry| {
+            registry
+                .sources
+                .iter()
+                .filter(|source| source.enabled && source.source_type == RegistrySourceType::Filesystem)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if sources.is_empty() {
+        return (vec!["./tasks".to_string()], Vec::new());
+    }
+
+    let path = |uri: &str| uri.strip_prefix("file://").unwrap_or(uri).to_string();
+    let filesystem_paths = sources.iter().map(|source| path(&source.uri)).collect();
+    let watched_paths = sources
+        .iter()
+        .filter(|source| source.config.filesystem.watch_changes)
+        .map(|source| path(&source.uri))
+        .collect();
+
+    (filesystem_paths, watched_paths)
+}
+
+/// Enabled Git registry sources, with their credentials resolved from the auth configuration
+fn git_registry_sources(registry: Option<&ratchet_config::RegistryConfig>) -> Vec<GitRepositorySource> {
+    use ratchet_config::domains::registry::RegistrySourceType;
+    use ratchet_registry::config::GitConfig;
+
+    let Some(registry) = registry else {
+        return Vec::new();
+    };
+
+    registry
+        .sources
+        .iter()
+        .filter(|source| source.enabled && source.source_type == RegistrySourceType::Git)
+        .map(|source| {
+            let git = &source.config.git;
+            let auth = source.auth_name.as_deref().and_then(|auth_name| {
+                let auth = registry_git_auth(registry.auth.get(auth_name));
+                if auth.is_none() {
+                    tracing::warn!(
+                        "Registry source '{}' references auth '{}', which is not a Git credential",
+                        source.name,
+                        auth_name
+                    );
+                }
+                auth
+            });
+
+            GitRepositorySource {
+                url: source.uri.clone(),
+                config: GitConfig {
+                    branch: git.branch.clone(),
+                    subdirectory: git.subdirectory.clone(),
+                    shallow: git.shallow,
+                    depth: git.depth.or(GitConfig::default().depth),
+                    cleanup_on_error: git.cleanup_on_error,
+                    verify_signatures: git.verify_signatures,
+                    allowed_refs: git.allowed_refs.clone(),
+                    timeout: git.timeout,
+                    max_repo_size: git.max_repo_size.clone(),
+                    local_cache_path: git.local_cache_path.clone(),
+                    cache_ttl: git.cache_ttl,
+                    keep_history: git.keep_history,
+                },
+                auth,
+            }
         })
+        .collect()
+}
+
+/// Enabled HTTP registry sources, with their credentials resolved from the auth configuration
+fn http_registry_sources(registry: Option<&ratchet_config::RegistryConfig>) -> Vec<HttpRegistrySource> {
+    use ratchet_config::domains::registry::RegistrySourceType;
+    use ratchet_registry::config::HttpDiscoveryConfig;
+
+    let Some(registry) = registry else {
+        return Vec::new();
+    };
+
+    registry
+        .sources
+        .iter()
+        .filter(|source| source.enabled && source.source_type == RegistrySourceType::Http)
+        .map(|source| {
+            let auth = source.auth_name.as_deref().and_then(|auth_name| {
+                let auth = registry_http_auth(registry.auth.get(auth_name));
+                if auth.is_none() {
+                    tracing::warn!(
+                        "Registry source '{}' references auth '{}', which is not an HTTP credential",
+                        source.name,
+                        auth_name
+                    );
+                }
+                auth
+            });
+
+            let http = &source.config.http;
+            HttpRegistrySource {
+                url: source.uri.clone(),
+                auth,
+                polling_interval: source.polling_interval.unwrap_or(registry.default_polling_interval),
+                discovery: HttpDiscoveryConfig {
+                    page_size: http.page_size,
+                    tags: http.tags.clone(),
+                    category: http.category.clone(),
+                },
+            }
+        })
+        .collect()
+}
+
+/// Busy/locked retry settings for the storage connection
+fn sqlite_busy_retry(sqlite: &ratchet_config::domains::database::SqliteConfig) -> ratchet_storage::BusyRetryConfig {
+    ratchet_storage::BusyRetryConfig {
+        max_retries: sqlite.busy_retries,
+        base_delay: std::time::Duration::from_millis(sqlite.busy_retry_base_delay_ms),
+        max_delay: std::time::Duration::from_millis(sqlite.busy_retry_max_delay_ms),
     }
-}
\ No newline at end of file
+}
+
+/// Conflict resolution strategy of each enabled registry source, by the path or URL it is loaded from
+fn registry_conflict_resolution(
+    registry: Option<&ratchet_config::RegistryConfig>,
+) -> HashMap<String, ratchet_registry::ConflictResolutionStrategy> {
+    use ratchet_config::domains::registry::{ConflictResolutionStrategy, RegistrySourceType};
+    use ratchet_registry::ConflictResolutionStrategy as RegistryConflictResolutionStrategy;
+
+    let Some(registry) = registry else {
+        return HashMap::new();
+    };
+
+    registry
+        .sources
+        .iter()
+        .filter(|source| source.enabled)
+        .map(|source| {
+            let location = match source.source_type {
+                RegistrySourceType::Filesystem => source.uri.strip_prefix("file://").unwrap_or(&source.uri),
+                _ => &source.uri,
+            };
+            let strategy = match source.conflict_resolution {
+                ConflictResolutionStrategy::RegistryWins => RegistryConflictResolutionStrategy::RegistryWins,
+                ConflictResolutionStrategy::KeepLocal => RegistryConflictResolutionStrategy::KeepLocal,
+                ConflictResolutionStrategy::PreferNewer => RegistryConflictResolutionStrategy::PreferNewer,
+                ConflictResolutionStrategy::Manual => RegistryConflictResolutionStrategy::Manual,
+            };
+            (location.to_string(), strategy)
+        })
+        .collect()
+}
+
+/// Git credentials for a registry auth entry, if it is one Git can use
+fn registry_git_auth(
+    auth: Option<&ratchet_config::domains::registry::RegistryAuthConfig>,
+) -> Option<ratchet_registry::config::GitAuth> {
+    use ratchet_config::domains::registry::RegistryAuthConfig;
+    use ratchet_registry::config::GitAuthType;
+
+    let auth_type = match auth? {
+        RegistryAuthConfig::GitToken { token } | RegistryAuthConfig::Bearer { token } => {
+            GitAuthType::Token { token: token.clone() }
+        }
+        RegistryAuthConfig::Basic { username, password } => GitAuthType::Basic {
+            username: username.clone(),
+            password: password.clone(),
+        },
+        RegistryAuthConfig::SshKey {
+            private_key_path,
+            passphrase,
+        } => GitAuthType::SshKey {
+            private_key_path: private_key_path.clone(),
+            passphrase: passphrase.clone(),
+        },
+        RegistryAuthConfig::GitHubApp {
+            app_id,
+            private_key_path,
+            installation_id,
+        } => GitAuthType::GitHubApp {
+            app_id: app_id.clone(),
+            private_key_path: private_key_path.clone(),
+            installation_id: installation_id.clone(),
+        },
+        _ => return None,
+    };
+
+    Some(ratchet_registry::config::GitAuth { auth_type })
+}
+
+/// HTTP credentials for a registry auth entry, if it is one an HTTP registry can use
+fn registry_http_auth(
+    auth: Option<&ratchet_config::domains::registry::RegistryAuthConfig>,
+) -> Option<ratchet_registry::config::HttpAuth> {
+    use ratchet_config::domains::registry::RegistryAuthConfig;
+    use ratchet_registry::config::HttpAuthType;
+
+    let auth_type = match auth? {
+        RegistryAuthConfig::Bearer { token } => HttpAuthType::Bearer { token: token.clone() },
+        RegistryAuthConfig::Basic { username, password } => HttpAuthType::Basic {
+            username: username.clone(),
+            password: password.clone(),
+        },
+        RegistryAuthConfig::ApiKey { header, value } => HttpAuthType::ApiKey {
+            header_name: header.clone(),
+            api_key: value.clone(),
+        },
+        _ => return None,
+    };
+
+    Some(ratchet_registry::config::HttpAuth { auth_type })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratchet_config::domains::registry::{RegistrySourceConfig, RegistrySourceType, SourceSpecificConfig};
+
+    fn source(uri: &str, source_type: RegistrySourceType, enabled: bool, watch_changes: bool) -> RegistrySourceConfig {
+        let mut config = SourceSpecificConfig::default();
+        config.filesystem.watch_changes = watch_changes;
+        RegistrySourceConfig {
+            name: uri.to_string(),
+            uri: uri.to_string(),
+            source_type,
+            polling_interval: None,
+            enabled,
+            auth_name: None,
+            conflict_resolution: Default::default(),
+            config,
+        }
+    }
+
+    #[test]
+    fn test_filesystem_registry_paths() {
+        assert_eq!(
+            filesystem_registry_paths(None),
+            (vec!["./tasks".to_string()], Vec::new())
+        );
+
+        let registry = ratchet_config::RegistryConfig {
+            sources: vec![
+                source("file://./tasks", RegistrySourceType::Filesystem, true, true),
+                source("./vendor/tasks", RegistrySourceType::Filesystem, true, false),
+                source("file://./old-tasks", RegistrySourceType::Filesystem, false, true),
+                source("https://registry.example.com", RegistrySourceType::Http, true, true),
+            ],
+            ..Default::default()
+        };
+
+        let (filesystem_paths, watched_paths) = filesystem_registry_paths(Some(&registry));
+        assert_eq!(
+            filesystem_paths,
+            vec!["./tasks".to_string(), "./vendor/tasks".to_string()]
+        );
+        assert_eq!(watched_paths, vec!["./tasks".to_string()]);
+    }
+
+    #[test]
+    fn test_git_registry_sources() {
+        use ratchet_config::domains::registry::RegistryAuthConfig;
+        use ratchet_registry::config::GitAuthType;
+
+        assert!(git_registry_sources(None).is_empty());
+
+        let mut tasks = source(
+            "https://github.com/acme/tasks.git",
+            RegistrySourceType::Git,
+            true,
+            false,
+        );
+        tasks.auth_name = Some("github".to_string());
+        tasks.config.git.branch = Some("release".to_string());
+        tasks.config.git.subdirectory = Some("ratchet".to_string());
+
+        let mut unknown_auth = source(
+            "git@github.com:acme/more-tasks.git",
+            RegistrySourceType::Git,
+            true,
+            false,
+        );
+        unknown_auth.auth_name = Some("missing".to_string());
+
+        let registry = ratchet_config::RegistryConfig {
+            sources: vec![
+                tasks,
+                unknown_auth,
+                source("https://github.com/acme/old.git", RegistrySourceType::Git, false, false),
+                source("file://./tasks", RegistrySourceType::Filesystem, true, true),
+            ],
+            auth: [(
+                "github".to_string(),
+                RegistryAuthConfig::GitToken {
+                    token: "secret".to_string(),
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let repositories = git_registry_sources(Some(&registry));
+        assert_eq!(repositories.len(), 2);
+
+        assert_eq!(repositories[0].url, "https://github.com/acme/tasks.git");
+        assert_eq!(repositories[0].config.branch.as_deref(), Some("release"));
+        assert_eq!(repositories[0].config.subdirectory.as_deref(), Some("ratchet"));
+        assert!(repositories[0].config.shallow);
+        assert_eq!(repositories[0].config.depth, Some(1));
+        assert!(matches!(
+            repositories[0].auth.as_ref().map(|auth| &auth.auth_type),
+            Some(GitAuthType::Token { token }) if token == "secret"
+        ));
+
+        assert_eq!(repositories[1].url, "git@github.com:acme/more-tasks.git");
+        assert!(repositories[1].auth.is_none());
+    }
+
+    #[test]
+    fn test_http_registry_sources() {
+        use ratchet_config::domains::registry::RegistryAuthConfig;
+        use ratchet_registry::config::HttpAuthType;
+        use std::time::Duration;
+
+        assert!(http_registry_sources(None).is_empty());
+
+        let mut registry_source = source("https://registry.example.com", RegistrySourceType::Http, true, false);
+        registry_source.auth_name = Some("registry".to_string());
+        registry_source.polling_interval = Some(Duration::from_secs(60));
+        registry_source.config.http.page_size = 25;
+        registry_source.config.http.tags = vec!["etl".to_string()];
+        registry_source.config.http.category = Some("data".to_string());
+
+        let mut git_auth = source("https://tasks.example.com", RegistrySourceType::Http, true, false);
+        git_auth.auth_name = Some("ssh".to_string());
+
+        let registry = ratchet_config::RegistryConfig {
+            sources: vec![
+                registry_source,
+                git_auth,
+                source("https://old.example.com", RegistrySourceType::Http, false, false),
+                source(
+                    "https://github.com/acme/tasks.git",
+                    RegistrySourceType::Git,
+                    true,
+                    false,
+                ),
+            ],
+            auth: [
+                (
+                    "registry".to_string(),
+                    RegistryAuthConfig::ApiKey {
+                        header: "X-Api-Key".to_string(),
+                        value: "secret".to_string(),
+                    },
+                ),
+                (
+                    "ssh".to_string(),
+                    RegistryAuthConfig::SshKey {
+                        private_key_path: "~/.ssh/id_ed25519".to_string(),
+                        passphrase: None,
+                    },
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            default_polling_interval: Duration::from_secs(300),
+            ..Default::default()
+        };
+
+        let endpoints = http_registry_sources(Some(&registry));
+        assert_eq!(endpoints.len(), 2);
+
+        assert_eq!(endpoints[0].url, "https://registry.example.com");
+        assert_eq!(endpoints[0].polling_interval, Duration::from_secs(60));
+        assert!(matches!(
+            endpoints[0].auth.as_ref().map(|auth| &auth.auth_type),
+            Some(HttpAuthType::ApiKey { header_name, api_key }) if header_name == "X-Api-Key" && api_key == "secret"
+        ));
+
+        assert_eq!(endpoints[1].url, "https://tasks.example.com");
+        assert_eq!(endpoints[1].polling_interval, Duration::from_secs(300));
+        assert!(endpoints[1].auth.is_none());
+
+        assert_eq!(endpoints[0].discovery.page_size, 25);
+        assert_eq!(endpoints[0].discovery.tags, vec!["etl".to_string()]);
+        assert_eq!(endpoints[0].discovery.category.as_deref(), Some("data"));
+        assert_eq!(endpoints[1].discovery.page_size, 100);
+        assert!(endpoints[1].discovery.tags.is_empty());
+    }
+
+    #[test]
+    fn test_registry_conflict_resolution() {
+        use ratchet_config::domains::registry::ConflictResolutionStrategy;
+
+        assert!(registry_conflict_resolution(None).is_empty());
+
+        let mut shared = source(
+            "https://github.com/acme/tasks.git",
+            RegistrySourceType::Git,
+            true,
+            false,
+        );
+        shared.conflict_resolution = ConflictResolutionStrategy::Manual;
+        let mut disabled = source("file://./old-tasks", RegistrySourceType::Filesystem, false, false);
+        disabled.conflict_resolution = ConflictResolutionStrategy::KeepLocal;
+
+        let registry = ratchet_config::RegistryConfig {
+            sources: vec![
+                shared,
+                source("file://./tasks", RegistrySourceType::Filesystem, true, true),
+                disabled,
+            ],
+            ..Default::default()
+        };
+
+        let strategies = registry_conflict_resolution(Some(&registry));
+        assert_eq!(strategies.len(), 2);
+        assert_eq!(
+            strategies.get("https://github.com/acme/tasks.git"),
+            Some(&ratchet_registry::ConflictResolutionStrategy::Manual)
+        );
+        assert_eq!(
+            strategies.get("./tasks"),
+            Some(&ratchet_registry::ConflictResolutionStrategy::RegistryWins)
+        );
+    }
+}
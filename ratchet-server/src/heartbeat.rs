@@ -170,6 +170,7 @@ impl HeartbeatService {
             has_last_run: None,
             is_due: None,
             overdue: None,
+            paused: None,
         };
         let pagination = PaginationInput {
             page: Some(1),
@@ -277,6 +278,12 @@ impl HeartbeatService {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             output_destinations: Some(vec![stdout_destination]),
+            serial: false,
+            timezone: None,
+            misfire_policy: Default::default(),
+            max_concurrent: None,
+            jitter_seconds: None,
+            paused: false,
         };
 
         let created_schedule = schedule_repo
@@ -329,6 +336,7 @@ impl HeartbeatService {
             has_last_run: None,
             is_due: None,
             overdue: None,
+            paused: None,
         };
         let pagination = PaginationInput {
             page: Some(1),
@@ -370,6 +378,7 @@ impl HeartbeatService {
             has_last_run: None,
             is_due: None,
             overdue: None,
+            paused: None,
         };
         let pagination = PaginationInput {
             page: Some(1),
@@ -3,15 +3,20 @@
 use async_trait::async_trait;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 use tracing::{debug, error, info, warn};
 
 use chrono::Utc;
-use ratchet_api_types::{ApiId, ExecutionStatus, UnifiedExecution, UnifiedOutputDestination};
+use ratchet_api_types::{ApiId, ExecutionStatus, UnifiedExecution, UnifiedJob, UnifiedOutputDestination, UnifiedTask};
+use ratchet_execution::{ExecutionBridge, ExecutionError, IpcExecutionContext, TaskExecutionResult, TaskSource};
 use ratchet_interfaces::{DatabaseError, RepositoryFactory};
-use ratchet_output::{DeliveryContext, OutputDeliveryManager, OutputDestinationConfig, TaskOutput};
+use ratchet_output::{
+    AlertDispatcher, DeliveryContext, HealthEvent, OutputDeliveryManager, OutputDestinationConfig, TaskOutput,
+};
+use ratchet_resilience::{CircuitBreaker, CircuitState};
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 /// Configuration for the job processor service
 #[derive(Debug, Clone)]
@@ -22,6 +27,8 @@ pub struct JobProcessorConfig {
     pub batch_size: u64,
     /// Enable automatic job processing
     pub enabled: bool,
+    /// Overall time budget of a job, shared by execution and output delivery
+    pub execution_deadline: Duration,
 }
 
 impl Default for JobProcessorConfig {
@@ -30,14 +37,52 @@ impl Default for JobProcessorConfig {
             poll_interval_seconds: 5,
             batch_size: 10,
             enabled: true,
+            execution_deadline: Duration::from_secs(600),
         }
     }
 }
 
+/// Variables destination path, URL and header templates can use for a job's output
+///
+/// Covers the job, its execution and task, plus the completion time split into
+/// `date`, `year`, `month` and `day` for dated paths.
+fn delivery_template_variables(
+    job: &UnifiedJob,
+    execution: &UnifiedExecution,
+    task: Option<&UnifiedTask>,
+    completed_at: chrono::DateTime<Utc>,
+) -> HashMap<String, String> {
+    let mut variables = HashMap::from([
+        ("job_id".to_string(), job.id.to_string()),
+        ("execution_id".to_string(), execution.id.to_string()),
+        ("execution_uuid".to_string(), execution.uuid.to_string()),
+        ("task_id".to_string(), job.task_id.to_string()),
+        ("priority".to_string(), format!("{:?}", job.priority).to_lowercase()),
+        ("timestamp".to_string(), completed_at.to_rfc3339()),
+        ("date".to_string(), completed_at.format("%Y-%m-%d").to_string()),
+        ("year".to_string(), completed_at.format("%Y").to_string()),
+        ("month".to_string(), completed_at.format("%m").to_string()),
+        ("day".to_string(), completed_at.format("%d").to_string()),
+    ]);
+    if let Some(schedule_id) = &job.schedule_id {
+        variables.insert("schedule_id".to_string(), schedule_id.to_string());
+    }
+    if let Some(task) = task {
+        variables.insert("task_name".to_string(), task.name.clone());
+        variables.insert("task_version".to_string(), task.version.clone());
+        variables.insert("task_uuid".to_string(), task.uuid.to_string());
+    }
+    variables
+}
+
 /// Job processor service that polls for queued jobs and creates executions
 pub struct JobProcessorService {
     repositories: Arc<dyn RepositoryFactory>,
     output_manager: Arc<OutputDeliveryManager>,
+    task_executor: Option<Arc<ExecutionBridge>>,
+    alert_dispatcher: Option<Arc<AlertDispatcher>>,
+    /// One breaker per output destination, keyed by [`destination_key`]; opening one raises an alert
+    delivery_circuits: Mutex<HashMap<String, CircuitBreaker>>,
     config: JobProcessorConfig,
     is_running: AtomicBool,
 }
@@ -52,11 +97,26 @@ impl JobProcessorService {
         Self {
             repositories,
             output_manager,
+            task_executor: None,
+            alert_dispatcher: None,
+            delivery_circuits: Mutex::new(HashMap::new()),
             config,
             is_running: AtomicBool::new(false),
         }
     }
 
+    /// Run job tasks on the given worker pool instead of simulating them
+    pub fn with_task_executor(mut self, task_executor: Arc<ExecutionBridge>) -> Self {
+        self.task_executor = Some(task_executor);
+        self
+    }
+
+    /// Raise system alerts through the given dispatcher when output delivery fails or a destination's circuit opens
+    pub fn with_alert_dispatcher(mut self, alert_dispatcher: Arc<AlertDispatcher>) -> Self {
+        self.alert_dispatcher = Some(alert_dispatcher);
+        self
+    }
+
     /// Start the job processor service
     pub async fn start(&self) -> Result<(), DatabaseError> {
         if !self.config.enabled {
@@ -101,7 +161,7 @@ impl JobProcessorService {
     }
 
     /// Process a batch of ready jobs
-    async fn process_batch(&self) -> Result<(), DatabaseError> {
+    pub async fn process_batch(&self) -> Result<(), DatabaseError> {
         debug!("Checking for ready jobs to process");
 
         // Get ready jobs from the repository
@@ -124,14 +184,36 @@ impl JobProcessorService {
             if let Err(e) = self.process_job(&job.id).await {
                 error!("Failed to process job {}: {}", job_id_copy, e);
 
-                // Mark job as failed
-                if let Err(mark_err) = self
+                // A failure that would only repeat, such as a hook veto, skips the remaining retries
+                if e.downcast_ref::<ExecutionError>().is_some_and(|e| !e.is_retryable()) {
+                    match self
+                        .repositories
+                        .job_repository()
+                        .mark_dead_letter(job.id, e.to_string(), None)
+                        .await
+                    {
+                        Ok(()) => warn!(
+                            "Job {} failed without retry and was moved to the dead-letter queue",
+                            job_id_copy
+                        ),
+                        Err(mark_err) => error!("Failed to mark job {} as failed: {}", job_id_copy, mark_err),
+                    }
+                    continue;
+                }
+
+                // Mark job as failed; the repository retries it or moves it to the dead-letter queue
+                match self
                     .repositories
                     .job_repository()
                     .mark_failed(job.id, e.to_string(), None)
                     .await
                 {
-                    error!("Failed to mark job {} as failed: {}", job_id_copy, mark_err);
+                    Ok(true) => debug!("Job {} will be retried", job_id_copy),
+                    Ok(false) => warn!(
+                        "Job {} exhausted its retries and was moved to the dead-letter queue",
+                        job_id_copy
+                    ),
+                    Err(mark_err) => error!("Failed to mark job {} as failed: {}", job_id_copy, mark_err),
                 }
             }
         }
@@ -139,10 +221,12 @@ impl JobProcessorService {
         Ok(())
     }
 
-    /// Process a single job by creating an execution and marking it as completed
-    /// For now, this is a simplified implementation that doesn't actually execute tasks
+    /// Process a single job by creating an execution, running its task and marking both completed
+    ///
+    /// Without a task executor the task is not run and a placeholder output is recorded.
     async fn process_job(&self, job_id: &ApiId) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         debug!("Processing job {}", job_id);
+        let deadline = Instant::now() + self.config.execution_deadline;
 
         // Get the job details
         let job = self
@@ -152,29 +236,51 @@ impl JobProcessorService {
             .await?
             .ok_or("Job not found")?;
 
-        // Create an execution for this job
-        let execution = UnifiedExecution {
-            id: ApiId::from_uuid(uuid::Uuid::new_v4()),
-            uuid: uuid::Uuid::new_v4(),
-            task_id: job.task_id.clone(),
-            status: ExecutionStatus::Pending,
-            input: serde_json::json!({}), // TODO: Get input from job metadata
-            output: None,
-            error_message: None,
-            error_details: None,
-            queued_at: chrono::Utc::now(),
-            started_at: None,
-            completed_at: None,
-            duration_ms: None,
-            http_requests: None,
-            recording_path: None,
-            progress: None,
-            can_retry: false,
-            can_cancel: false,
+        // The job's execution is either one a caller created up front to wait on, or that of an earlier attempt
+        let linked_execution = match job.execution_id.as_ref().and_then(|id| id.as_i32()) {
+            Some(execution_id) => {
+                self.repositories
+                    .execution_repository()
+                    .find_by_id(execution_id)
+                    .await?
+            }
+            None => None,
         };
 
-        // Create the execution in the repository
-        let created_execution = self.repositories.execution_repository().create(execution).await?;
+        // Only a pre-created execution that hasn't run yet is reused; every retry and every requeue
+        // from the dead-letter queue gets an execution of its own, keeping earlier attempts' results
+        let created_execution = match linked_execution {
+            Some(execution) if execution.status == ExecutionStatus::Pending => execution,
+            previous_attempt => {
+                let execution = UnifiedExecution {
+                    id: ApiId::from_uuid(uuid::Uuid::new_v4()),
+                    uuid: uuid::Uuid::new_v4(),
+                    task_id: job.task_id.clone(),
+                    status: ExecutionStatus::Pending,
+                    // TODO: Get input from job metadata for jobs queued without an execution
+                    input: previous_attempt
+                        .as_ref()
+                        .map(|execution| execution.input.clone())
+                        .unwrap_or_else(|| serde_json::json!({})),
+                    output: None,
+                    error_message: None,
+                    error_details: None,
+                    queued_at: chrono::Utc::now(),
+                    started_at: None,
+                    completed_at: None,
+                    duration_ms: None,
+                    http_requests: None,
+                    recording_path: None,
+                    attachments: Vec::new(),
+                    labels: previous_attempt.map(|execution| execution.labels).unwrap_or_default(),
+                    progress: None,
+                    can_retry: false,
+                    can_cancel: false,
+                };
+
+                self.repositories.execution_repository().create(execution).await?
+            }
+        };
 
         // Store IDs before they get moved
         let execution_id = created_execution.id.clone();
@@ -188,10 +294,6 @@ impl JobProcessorService {
 
         info!("Created execution {} for job {}", execution_id, job_id);
 
-        // For now, we'll simulate task execution with a simple success
-        // In a full implementation, this would delegate to a task executor
-        // TODO: Integrate with actual task execution system
-
         // Mark execution as started
         self.repositories
             .execution_repository()
@@ -200,34 +302,72 @@ impl JobProcessorService {
             .map_err(|e| error!("Failed to mark execution {} as started: {}", execution_id, e))
             .ok();
 
-        // Simulate some processing time
-        tokio::time::sleep(Duration::from_millis(100)).await;
-
-        // For heartbeat tasks, create a simple success response
-        let output = if job.task_id.to_string().contains("heartbeat") {
-            serde_json::json!({
-                "status": "success",
-                "message": "Heartbeat completed successfully",
-                "timestamp": chrono::Utc::now().to_rfc3339(),
-                "system_healthy": true
-            })
-        } else {
-            serde_json::json!({
-                "status": "success",
-                "message": "Job completed successfully",
-                "job_id": job_id.to_string()
-            })
+        let (output, duration_ms) = match &self.task_executor {
+            Some(task_executor) => {
+                // The attempt is cut off at the deadline and fails like any other, so it is retried
+                let run = self.run_task(task_executor, &created_execution, job.log_level.as_deref());
+                let result = match tokio::time::timeout_at(deadline.into(), run).await {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(e)) => {
+                        self.fail_execution(&execution_id, &e.to_string(), None).await;
+                        return Err(e);
+                    }
+                    Err(_) => {
+                        let e = ExecutionError::TimeoutError(format!(
+                            "job exceeded its overall time budget of {:?}",
+                            self.config.execution_deadline
+                        ));
+                        self.fail_execution(&execution_id, &e.to_string(), None).await;
+                        return Err(e.into());
+                    }
+                };
+                if !result.logs.is_empty() {
+                    if let Err(e) = self
+                        .repositories
+                        .execution_repository()
+                        .store_logs(execution_id.clone(), result.logs)
+                        .await
+                    {
+                        error!("Failed to store logs of execution {}: {}", execution_id, e);
+                    }
+                }
+                if !result.success {
+                    let message = result
+                        .error_message
+                        .unwrap_or_else(|| "Task execution failed".to_string());
+                    self.fail_execution(&execution_id, &message, result.error_details).await;
+                    return Err(message.into());
+                }
+                (result.output.unwrap_or(serde_json::Value::Null), result.duration_ms)
+            }
+            None => {
+                // Simulate some processing time
+                tokio::time::sleep(Duration::from_millis(100)).await;
+
+                // For heartbeat tasks, create a simple success response
+                let output = if job.task_id.to_string().contains("heartbeat") {
+                    serde_json::json!({
+                        "status": "success",
+                        "message": "Heartbeat completed successfully",
+                        "timestamp": chrono::Utc::now().to_rfc3339(),
+                        "system_healthy": true
+                    })
+                } else {
+                    serde_json::json!({
+                        "status": "success",
+                        "message": "Job completed successfully",
+                        "job_id": job_id.to_string()
+                    })
+                };
+                (output, 100)
+            }
         };
 
         // Mark execution as completed
         if let Err(e) = self
             .repositories
             .execution_repository()
-            .mark_completed(
-                execution_id.clone(),
-                output.clone(),
-                Some(100), // 100ms duration
-            )
+            .mark_completed(execution_id.clone(), output.clone(), Some(duration_ms))
             .await
         {
             error!("Failed to mark execution {} as completed: {}", execution_id, e);
@@ -238,46 +378,163 @@ impl JobProcessorService {
             error!("Failed to mark job {} as completed: {}", job_id, e);
         }
 
-        // Process output destinations if any are configured
-        if let Some(ref output_destinations) = job.output_destinations {
-            self.deliver_job_output(job_id.clone(), execution_id.clone(), output, output_destinations)
-                .await;
-        }
+        // Process output destinations, falling back to the configured default destination
+        let output_destinations = job.output_destinations.as_deref().unwrap_or_default();
+        self.deliver_job_output(
+            &job,
+            &created_execution,
+            output,
+            duration_ms,
+            output_destinations,
+            deadline,
+        )
+        .await;
 
         info!("Successfully processed job {} with execution {}", job_id, execution_id);
         Ok(())
     }
 
+    /// Run the task of a stored execution on the worker pool
+    ///
+    /// The worker runs as that execution, so attachments are filed under its UUID. The task
+    /// logs at the job's level, falling back to the `log_level` in the task's metadata.
+    async fn run_task(
+        &self,
+        task_executor: &ExecutionBridge,
+        execution: &UnifiedExecution,
+        log_level: Option<&str>,
+    ) -> Result<TaskExecutionResult, Box<dyn std::error::Error + Send + Sync>> {
+        let task_id = execution.task_id.as_i32().ok_or("Invalid task ID")?;
+        let task = self
+            .repositories
+            .task_repository()
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+
+        let log_level = log_level
+            .or_else(|| {
+                task.metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.get("log_level"))
+                    .and_then(|level| level.as_str())
+            })
+            .map(str::to_string);
+        let task_source = TaskSource {
+            name: task.name,
+            content: task.source_code,
+            input_schema: task.input_schema.filter(|schema| !schema.is_null()),
+            output_schema: task.output_schema.filter(|schema| !schema.is_null()),
+        };
+        let mut context = IpcExecutionContext::new(execution.uuid, None, task.uuid, task.version);
+        if let Some(log_level) = log_level {
+            context = context.with_log_level(log_level);
+        }
+
+        Ok(task_executor
+            .inner()
+            .execute_task_source(task_id, task_source, execution.input.clone(), Some(context))
+            .await?)
+    }
+
+    /// Record a failed task run on its execution
+    async fn fail_execution(&self, execution_id: &ApiId, message: &str, details: Option<serde_json::Value>) {
+        if let Err(e) = self
+            .repositories
+            .execution_repository()
+            .mark_failed(execution_id.clone(), message.to_string(), details)
+            .await
+        {
+            error!("Failed to mark execution {} as failed: {}", execution_id, e);
+        }
+    }
+
     /// Deliver job output to configured destinations
     async fn deliver_job_output(
         &self,
-        job_id: ApiId,
-        execution_id: ApiId,
+        job: &UnifiedJob,
+        execution: &UnifiedExecution,
         output: serde_json::Value,
+        duration_ms: i32,
         destinations: &[UnifiedOutputDestination],
+        deadline: Instant,
     ) {
+        let job_id = &job.id;
         debug!(
             "Delivering output for job {} to {} destinations",
             job_id,
             destinations.len()
         );
 
+        // Destination templates may refer to the task, so look it up; delivery goes ahead without it
+        let task = match job.task_id.as_i32() {
+            Some(task_id) => match self.repositories.task_repository().find_by_id(task_id).await {
+                Ok(task) => task,
+                Err(e) => {
+                    warn!("Failed to load task {} for output delivery: {}", job.task_id, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
         // Create task output for delivery
+        let completed_at = Utc::now();
         let task_output = TaskOutput {
             job_id: job_id.as_i32().unwrap_or(0),
-            task_id: 0, // Would need to get from job/execution
-            execution_id: execution_id.as_i32().unwrap_or(0),
+            task_id: job.task_id.as_i32().unwrap_or(0),
+            execution_id: execution.id.as_i32().unwrap_or(0),
             output_data: output,
             metadata: HashMap::new(),
-            completed_at: Utc::now(),
-            execution_duration: std::time::Duration::from_millis(100), // Default duration
+            completed_at,
+            execution_duration: Duration::from_millis(duration_ms.max(0) as u64),
         };
 
-        let delivery_context = DeliveryContext::default();
+        // Delivery only gets what the execution left of the job's budget
+        let delivery_context = DeliveryContext {
+            job_id: task_output.job_id,
+            task_name: task.as_ref().map(|task| task.name.clone()).unwrap_or_default(),
+            task_version: task.as_ref().map(|task| task.version.clone()).unwrap_or_default(),
+            timestamp: completed_at,
+            trace_id: execution.uuid.to_string(),
+            template_variables: delivery_template_variables(job, execution, task.as_ref(), completed_at),
+            deadline: Some(deadline),
+            ..DeliveryContext::default()
+        };
+
+        if let Some((destination_id, Err(e))) = self
+            .output_manager
+            .deliver_default(&task_output, &delivery_context, !destinations.is_empty())
+            .await
+        {
+            error!(
+                "Failed to deliver output for job {} to default destination {}: {}",
+                job_id, destination_id, e
+            );
+
+            if let Some(ref alert_dispatcher) = self.alert_dispatcher {
+                alert_dispatcher
+                    .raise(HealthEvent::DestinationUnhealthy {
+                        destination: destination_id,
+                        error: e.to_string(),
+                    })
+                    .await;
+            }
+        }
 
         // Process each destination
         for (index, destination) in destinations.iter().enumerate() {
             let destination_id = format!("job_{}_dest_{}", job_id, index);
+            let destination_name = destination_key(destination);
+            let circuit = self.delivery_circuit(&destination_name);
+            if circuit.is_open() {
+                circuit.record_rejection();
+                warn!(
+                    "Skipping delivery for job {} to {}: circuit is open",
+                    job_id, destination_name
+                );
+                continue;
+            }
 
             // Convert UnifiedOutputDestination to OutputDestinationConfig
             if let Ok(config) = self.convert_unified_to_output_config(destination) {
@@ -298,16 +555,36 @@ impl JobProcessorService {
                     .await
                 {
                     Ok(_) => {
+                        circuit.record_success();
                         info!(
                             "Successfully delivered output for job {} to destination {}",
                             job_id, destination_id
                         );
                     }
                     Err(e) => {
+                        circuit.record_failure();
                         error!(
                             "Failed to deliver output for job {} to destination {}: {}",
                             job_id, destination_id, e
                         );
+
+                        if let Some(ref alert_dispatcher) = self.alert_dispatcher {
+                            alert_dispatcher
+                                .raise(HealthEvent::DestinationUnhealthy {
+                                    destination: destination_name.clone(),
+                                    error: e.to_string(),
+                                })
+                                .await;
+
+                            if circuit.state() == CircuitState::Open {
+                                alert_dispatcher
+                                    .raise(HealthEvent::CircuitOpened {
+                                        circuit: format!("output:{}", destination_name),
+                                        consecutive_failures: circuit.metrics().consecutive_failures,
+                                    })
+                                    .await;
+                            }
+                        }
                     }
                 }
 
@@ -324,6 +601,15 @@ impl JobProcessorService {
         }
     }
 
+    /// Circuit breaker guarding deliveries to a destination, created on first use
+    fn delivery_circuit(&self, destination_name: &str) -> CircuitBreaker {
+        let mut circuits = self.delivery_circuits.lock().unwrap_or_else(|e| e.into_inner());
+        circuits
+            .entry(destination_name.to_string())
+            .or_insert_with(CircuitBreaker::with_defaults)
+            .clone()
+    }
+
     /// Convert between API types and output manager types
     fn convert_output_format(format: &ratchet_api_types::OutputFormat) -> ratchet_output::OutputFormat {
         match format {
@@ -473,3 +759,16 @@ impl JobProcessor for JobProcessorService {
         JobProcessorService::is_running(self)
     }
 }
+
+/// Stable name of a job output destination, shared by every job that delivers to it
+fn destination_key(destination: &UnifiedOutputDestination) -> String {
+    let target = match destination.destination_type.as_str() {
+        "webhook" => destination.webhook.as_ref().map(|webhook| webhook.url.as_str()),
+        "filesystem" => destination.filesystem.as_ref().map(|fs| fs.path.as_str()),
+        _ => None,
+    };
+    match target {
+        Some(target) => format!("{}:{}", destination.destination_type, target),
+        None => destination.destination_type.clone(),
+    }
+}
@@ -10,6 +10,7 @@ pub mod heartbeat;
 pub mod job_processor;
 pub mod mcp_handler;
 pub mod monitoring;
+pub mod plugins;
 pub mod repository_services;
 pub mod scheduler;
 pub mod security;
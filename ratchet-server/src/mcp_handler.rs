@@ -42,7 +42,12 @@ impl McpEndpointState {
     pub fn new(config: McpApiConfig) -> anyhow::Result<Self> {
         // Create MCP server
         let mcp_server_config = McpServerConfig::sse_with_host(config.port, &config.host);
-        let tool_registry = Arc::new(RatchetToolRegistry::new());
+        let tool_registry = Arc::new(
+            RatchetToolRegistry::new()
+                .with_progress_config(config.progress.clone())
+                .with_progress_streaming(config.progress_streaming)
+                .with_max_dependency_depth(config.max_dependency_depth),
+        );
         let auth_manager = Arc::new(McpAuthManager::new(McpAuth::default()));
         let audit_logger = Arc::new(AuditLogger::new(false));
 
@@ -92,12 +97,16 @@ impl McpEndpointState {
         mcp_task_service: Option<Arc<TaskDevelopmentService>>,
         storage_factory: Option<Arc<ratchet_storage::seaorm::repositories::RepositoryFactory>>,
         task_service: Option<Arc<dyn ratchet_interfaces::TaskService>>,
+        task_executor: Arc<ExecutionBridge>,
     ) -> anyhow::Result<Self> {
         // Create MCP server
         let mcp_server_config = McpServerConfig::sse_with_host(config.port, &config.host);
         let tool_registry = Arc::new(
             RatchetToolRegistry::new()
                 .with_repositories(repositories)
+                .with_progress_config(config.progress.clone())
+                .with_progress_streaming(config.progress_streaming)
+                .with_max_dependency_depth(config.max_dependency_depth)
         );
         
         // Configure tool registry with task development service if available
@@ -113,21 +122,9 @@ impl McpEndpointState {
         
         // Create MCP task executor if storage factory and task service are available
         let tool_registry = if let (Some(storage_fact), Some(task_svc)) = (storage_factory, task_service) {
-            // Create an ExecutionBridge as the task executor
-            let executor_config = ratchet_execution::ProcessExecutorConfig::default();
-            let execution_bridge = Arc::new(ExecutionBridge::new(executor_config));
-            
-            // Start the worker processes
-            if let Err(e) = execution_bridge.start().await {
-                tracing::warn!("Failed to start worker processes: {}", e);
-                tracing::info!("Task execution will not be available until workers are started");
-            } else {
-                tracing::info!("Worker processes started successfully for MCP task execution");
-            }
-            
-            // Create the MCP adapter using the ExecutionBridge and unified TaskService
+            // Create the MCP adapter using the server's worker pool and unified TaskService
             let mcp_adapter = ratchet_mcp::server::adapter::RatchetMcpAdapter::with_bridge_executor(
-                execution_bridge,
+                task_executor,
                 task_svc,
                 Arc::new(storage_fact.execution_repository()),
             );
@@ -0,0 +1,156 @@
+//! Dynamic plugins loaded at startup
+//!
+//! Plugin libraries in the configured plugins directory are loaded once when
+//! the server starts and registered with the [`PluginManager`]. A library that
+//! fails to load is logged and recorded, and the remaining libraries still load.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+use ratchet_interfaces::{LoadedPlugin, PluginInventory, PluginLoadFailure};
+use ratchet_plugin::discovery::plugin_libraries;
+use ratchet_plugin::{DynamicPluginLoader, PluginLoader, PluginManager, PluginManagerBuilder, PluginResult};
+
+/// Loads plugin libraries and keeps them mapped for the lifetime of the server
+pub struct PluginHost {
+    manager: Arc<PluginManager>,
+    /// Owns the loaded libraries; dropping it would unload code the plugins run
+    loader: DynamicPluginLoader,
+    /// Library each dynamically loaded plugin came from, by plugin ID
+    sources: RwLock<HashMap<String, String>>,
+    failures: RwLock<Vec<PluginLoadFailure>>,
+}
+
+impl PluginHost {
+    /// Create a plugin host whose plugins see `system_config`
+    pub fn new(system_config: ratchet_config::RatchetConfig) -> Self {
+        let manager = PluginManagerBuilder::new()
+            .with_system_config(system_config)
+            .with_auto_discover(false)
+            .with_auto_load(false)
+            .build();
+
+        Self {
+            manager: Arc::new(manager),
+            loader: DynamicPluginLoader::new(),
+            sources: RwLock::new(HashMap::new()),
+            failures: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Plugin manager the loaded plugins are registered with
+    pub fn manager(&self) -> &Arc<PluginManager> {
+        &self.manager
+    }
+
+    /// Load every plugin library in `directory`, returning how many were registered
+    pub async fn load_directory(&self, directory: &Path) -> usize {
+        let libraries = match plugin_libraries(directory).await {
+            Ok(libraries) => libraries,
+            Err(e) => {
+                warn!("Failed to read plugins directory {}: {}", directory.display(), e);
+                self.record_failure(directory, e.to_string()).await;
+                return 0;
+            }
+        };
+
+        let mut loaded = 0;
+        for path in libraries {
+            match self.load_library(&path).await {
+                Ok(plugin_id) => {
+                    info!("Loaded plugin '{}' from {}", plugin_id, path.display());
+                    loaded += 1;
+                }
+                Err(e) => {
+                    warn!("Failed to load plugin {}: {}", path.display(), e);
+                    self.record_failure(&path, e.to_string()).await;
+                }
+            }
+        }
+
+        loaded
+    }
+
+    async fn load_library(&self, path: &Path) -> PluginResult<String> {
+        let source = path.to_string_lossy().to_string();
+        let plugin = self.loader.load_plugin(&source).await?;
+
+        // Registration rejects plugins built against an API older than MIN_PLUGIN_API_VERSION
+        let plugin_id = self.manager.register_plugin(plugin, serde_json::json!({})).await?;
+        self.sources.write().await.insert(plugin_id.clone(), source);
+
+        Ok(plugin_id)
+    }
+
+    async fn record_failure(&self, path: &Path, error: String) {
+        self.failures.write().await.push(PluginLoadFailure {
+            source: path.to_string_lossy().to_string(),
+            error,
+        });
+    }
+}
+
+#[async_trait]
+impl PluginInventory for PluginHost {
+    async fn loaded_plugins(&self) -> Vec<LoadedPlugin> {
+        let sources = self.sources.read().await;
+        let mut plugins: Vec<LoadedPlugin> = self
+            .manager
+            .list_plugins()
+            .await
+            .into_iter()
+            .map(|info| LoadedPlugin {
+                source: sources.get(&info.metadata.id).cloned(),
+                id: info.metadata.id,
+                name: info.metadata.name,
+                version: info.metadata.version.to_string(),
+                api_version: info.metadata.api_version,
+                plugin_type: info.metadata.plugin_type.to_string(),
+                status: info.status.to_string(),
+                loaded_at: info.loaded_at,
+                error: info.error,
+            })
+            .collect();
+
+        plugins.sort_by(|a, b| a.id.cmp(&b.id));
+        plugins
+    }
+
+    async fn load_failures(&self) -> Vec<PluginLoadFailure> {
+        self.failures.read().await.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_invalid_library_is_recorded_without_aborting() {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::write(directory.path().join("broken.so"), b"not a shared library").unwrap();
+        std::fs::write(directory.path().join("notes.txt"), b"ignored").unwrap();
+
+        let host = PluginHost::new(ratchet_config::RatchetConfig::default());
+        assert_eq!(host.load_directory(directory.path()).await, 0);
+
+        let failures = host.load_failures().await;
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].source.ends_with("broken.so"));
+        assert!(host.loaded_plugins().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_missing_directory_is_recorded() {
+        let directory = tempfile::tempdir().unwrap();
+        let missing = directory.path().join("missing");
+
+        let host = PluginHost::new(ratchet_config::RatchetConfig::default());
+        assert_eq!(host.load_directory(&missing).await, 0);
+        assert_eq!(host.load_failures().await.len(), 1);
+    }
+}
@@ -0,0 +1,153 @@
+//! Per-schedule limit on overlapping executions
+//!
+//! A slow task on a fast cron would otherwise stack up runs. Before a trigger
+//! starts a job, the limiter counts the schedule's active executions and skips
+//! the trigger once `max_concurrent` of them are running. Counting and starting
+//! happen under one lock, so two triggers firing together can't both take the
+//! last free slot.
+
+use std::future::Future;
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use ratchet_api_types::ApiId;
+use ratchet_interfaces::SchedulerError;
+
+/// Admits a schedule's triggers only while it has fewer than `max_concurrent` active executions
+pub struct ConcurrencyLimiter {
+    schedule_id: ApiId,
+    max_concurrent: usize,
+    admission: Mutex<()>,
+}
+
+impl ConcurrencyLimiter {
+    /// Create a limiter allowing at most `max_concurrent` active executions
+    pub fn new(schedule_id: ApiId, max_concurrent: u32) -> Self {
+        Self {
+            schedule_id,
+            max_concurrent: max_concurrent.max(1) as usize,
+            admission: Mutex::new(()),
+        }
+    }
+
+    /// Run `start` unless `count_active` reports the schedule is at its limit
+    ///
+    /// `start` must make the new execution visible to `count_active` before it
+    /// resolves. Returns `None` when the trigger was skipped.
+    pub async fn run_if_below_limit<T>(
+        &self,
+        count_active: impl Future<Output = Result<usize, SchedulerError>>,
+        start: impl Future<Output = Result<T, SchedulerError>>,
+    ) -> Result<Option<T>, SchedulerError> {
+        let _admission = self.admission.lock().await;
+
+        let active = count_active.await?;
+        if active >= self.max_concurrent {
+            debug!(
+                "Skipping trigger for schedule {}: {} of {} executions still active",
+                self.schedule_id, active, self.max_concurrent
+            );
+            return Ok(None);
+        }
+
+        start.await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_long_task_on_fast_cron_does_not_overlap() {
+        let limiter = Arc::new(ConcurrencyLimiter::new(ApiId::from_i32(1), 1));
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let started = Arc::new(AtomicUsize::new(0));
+        let skipped = Arc::new(AtomicUsize::new(0));
+
+        // Triggers every 10ms for a task that takes 45ms, each handled on its own task like the scheduler does
+        let mut triggers = Vec::new();
+        for _ in 0..20 {
+            let limiter = limiter.clone();
+            let active = active.clone();
+            let max_active = max_active.clone();
+            let started = started.clone();
+            let skipped = skipped.clone();
+
+            triggers.push(tokio::spawn(async move {
+                let count_active = {
+                    let active = active.clone();
+                    async move { Ok::<_, SchedulerError>(active.load(Ordering::SeqCst)) }
+                };
+                let start = async {
+                    let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_active.fetch_max(now_active, Ordering::SeqCst);
+                    started.fetch_add(1, Ordering::SeqCst);
+
+                    let active = active.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(Duration::from_millis(45)).await;
+                        active.fetch_sub(1, Ordering::SeqCst);
+                    });
+                    Ok::<_, SchedulerError>(())
+                };
+
+                if limiter.run_if_below_limit(count_active, start).await.unwrap().is_none() {
+                    skipped.fetch_add(1, Ordering::SeqCst);
+                }
+            }));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        for trigger in triggers {
+            trigger.await.unwrap();
+        }
+
+        assert_eq!(max_active.load(Ordering::SeqCst), 1);
+        assert!(started.load(Ordering::SeqCst) >= 2, "later triggers should run once the slot frees up");
+        assert!(skipped.load(Ordering::SeqCst) > 0, "overlapping triggers should be skipped");
+        assert_eq!(started.load(Ordering::SeqCst) + skipped.load(Ordering::SeqCst), 20);
+    }
+
+    #[tokio::test]
+    async fn test_simultaneous_triggers_respect_limit() {
+        let limiter = Arc::new(ConcurrencyLimiter::new(ApiId::from_i32(1), 2));
+        let active = Arc::new(AtomicUsize::new(0));
+
+        let triggers: Vec<_> = (0..10)
+            .map(|_| {
+                let limiter = limiter.clone();
+                let active = active.clone();
+                tokio::spawn(async move {
+                    let count_active = {
+                        let active = active.clone();
+                        async move {
+                            // Yield so racing triggers would interleave between counting and starting
+                            tokio::task::yield_now().await;
+                            Ok::<_, SchedulerError>(active.load(Ordering::SeqCst))
+                        }
+                    };
+                    let start = async move {
+                        active.fetch_add(1, Ordering::SeqCst);
+                        Ok::<_, SchedulerError>(())
+                    };
+                    limiter.run_if_below_limit(count_active, start).await.unwrap()
+                })
+            })
+            .collect();
+
+        let mut admitted = 0;
+        for trigger in triggers {
+            if trigger.await.unwrap().is_some() {
+                admitted += 1;
+            }
+        }
+
+        assert_eq!(admitted, 2);
+        assert_eq!(active.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,142 @@
+//! Catch-up for runs missed while the scheduler was down
+//!
+//! When the scheduler starts, every enabled schedule's `last_run` is compared
+//! with the current time. Occurrences of the cron expression that fall in that
+//! gap were missed, and the schedule's [`MisfirePolicy`] decides whether none,
+//! the latest one, or all of them are run now.
+
+use chrono::{DateTime, Utc};
+use ratchet_api_types::{MisfirePolicy, UnifiedSchedule};
+use ratchet_core::schedule::{preview_schedule, MAX_PREVIEW_COUNT};
+use ratchet_interfaces::SchedulerError;
+
+/// Largest number of missed runs caught up for one schedule under [`MisfirePolicy::FireAll`]
+pub const MAX_CATCH_UP_RUNS: usize = MAX_PREVIEW_COUNT;
+
+/// Run times a schedule should catch up on at `now`, oldest first
+///
+/// Schedules that never ran have nothing to catch up on. Under
+/// [`MisfirePolicy::FireAll`] only the most recent [`MAX_CATCH_UP_RUNS`] missed
+/// runs are returned.
+pub fn catch_up_runs(schedule: &UnifiedSchedule, now: DateTime<Utc>) -> Result<Vec<DateTime<Utc>>, SchedulerError> {
+    let last_run = match (schedule.misfire_policy, schedule.last_run) {
+        (MisfirePolicy::Skip, _) | (_, None) => return Ok(Vec::new()),
+        (_, Some(last_run)) => last_run,
+    };
+
+    let mut missed = missed_runs(schedule, last_run, now)?;
+    match schedule.misfire_policy {
+        MisfirePolicy::Skip => Ok(Vec::new()),
+        MisfirePolicy::FireOnce => Ok(missed.pop().into_iter().collect()),
+        MisfirePolicy::FireAll => {
+            let overflow = missed.len().saturating_sub(MAX_CATCH_UP_RUNS);
+            Ok(missed.split_off(overflow))
+        }
+    }
+}
+
+/// Occurrences after `last_run` up to and including `now`, oldest first
+fn missed_runs(
+    schedule: &UnifiedSchedule,
+    last_run: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<Vec<DateTime<Utc>>, SchedulerError> {
+    let timezone = schedule.timezone.as_deref().unwrap_or("UTC");
+    let mut missed = Vec::new();
+    let mut after = last_run;
+
+    // Previews are capped, so walk the gap in pages, keeping only the latest runs
+    loop {
+        let page = preview_schedule(&schedule.cron_expression, timezone, after, MAX_PREVIEW_COUNT)
+            .map_err(|e| SchedulerError::InvalidCron(e.to_string()))?;
+        let page_len = page.len();
+
+        for run in page {
+            let run = run.with_timezone(&Utc);
+            if run > now {
+                return Ok(missed);
+            }
+            missed.push(run);
+            after = run;
+        }
+
+        if missed.len() > MAX_CATCH_UP_RUNS {
+            missed.drain(..missed.len() - MAX_CATCH_UP_RUNS);
+        }
+        if page_len < MAX_PREVIEW_COUNT {
+            return Ok(missed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use ratchet_api_types::ApiId;
+
+    fn hourly_schedule(policy: MisfirePolicy, last_run: Option<DateTime<Utc>>) -> UnifiedSchedule {
+        UnifiedSchedule {
+            id: ApiId::from_i32(1),
+            task_id: ApiId::from_i32(1),
+            name: "hourly".to_string(),
+            description: None,
+            cron_expression: "0 * * * *".to_string(),
+            enabled: true,
+            next_run: None,
+            last_run,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            output_destinations: None,
+            serial: false,
+            timezone: None,
+            misfire_policy: policy,
+            max_concurrent: None,
+            jitter_seconds: None,
+            paused: false,
+        }
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_catch_up_per_policy() {
+        // Down from just after the 09:00 run until 12:30, missing 10:00, 11:00 and 12:00
+        let last_run = Some(at(9, 0));
+        let now = at(12, 30);
+
+        let skip = catch_up_runs(&hourly_schedule(MisfirePolicy::Skip, last_run), now).unwrap();
+        assert!(skip.is_empty());
+
+        let once = catch_up_runs(&hourly_schedule(MisfirePolicy::FireOnce, last_run), now).unwrap();
+        assert_eq!(once, vec![at(12, 0)]);
+
+        let all = catch_up_runs(&hourly_schedule(MisfirePolicy::FireAll, last_run), now).unwrap();
+        assert_eq!(all, vec![at(10, 0), at(11, 0), at(12, 0)]);
+    }
+
+    #[test]
+    fn test_nothing_to_catch_up() {
+        // Never ran before
+        let schedule = hourly_schedule(MisfirePolicy::FireAll, None);
+        assert!(catch_up_runs(&schedule, at(12, 30)).unwrap().is_empty());
+
+        // Restarted before the next run was due
+        let schedule = hourly_schedule(MisfirePolicy::FireAll, Some(at(12, 0)));
+        assert!(catch_up_runs(&schedule, at(12, 30)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_fire_all_is_capped_to_latest_runs() {
+        let mut schedule = hourly_schedule(MisfirePolicy::FireAll, Some(at(0, 0)));
+        schedule.cron_expression = "* * * * *".to_string();
+
+        // 12 hours of missed minutely runs
+        let runs = catch_up_runs(&schedule, at(12, 0)).unwrap();
+        assert_eq!(runs.len(), MAX_CATCH_UP_RUNS);
+        assert_eq!(runs.last(), Some(&at(12, 0)));
+        assert!(runs.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
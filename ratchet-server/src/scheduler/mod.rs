@@ -1,13 +1,20 @@
 //! Scheduler module for task scheduling and execution
 
+pub mod concurrency;
+pub mod misfire;
 pub mod repository_bridge;
+pub mod serial;
 // TODO: Re-enable when tokio-cron-scheduler storage API is properly implemented
 // pub mod sqlite_storage;
 pub mod sync_scheduler;
+pub mod timezone;
 pub mod tokio_scheduler;
 
 pub use ratchet_interfaces::{ScheduleStatus, SchedulerError, SchedulerService};
+pub use concurrency::ConcurrencyLimiter;
 pub use repository_bridge::RepositoryBridge;
+pub use serial::SerialExecutionQueue;
 // pub use sqlite_storage::SqliteMetadataStore;
 pub use sync_scheduler::{SyncScheduler, SyncSchedulerConfig, ScheduledSyncResult};
+pub use timezone::ZonedTrigger;
 pub use tokio_scheduler::{TokioCronSchedulerConfig, TokioCronSchedulerService};
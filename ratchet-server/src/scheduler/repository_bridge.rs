@@ -3,11 +3,13 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info};
 
 use ratchet_api_types::{ApiId, JobPriority, JobStatus, UnifiedJob, UnifiedSchedule};
-use ratchet_interfaces::RepositoryFactory;
+use ratchet_core::schedule::apply_jitter;
 use ratchet_interfaces::SchedulerError;
+use ratchet_interfaces::{KeysetRepository, RepositoryFactory, ScheduleFilters};
 
 /// Bridge between scheduler and repository layer
 /// This ensures the scheduler only accesses data through repository interfaces
@@ -36,6 +38,31 @@ impl RepositoryBridge {
         Ok(schedules)
     }
 
+    /// Load the disabled schedules that were paused and are waiting to be resumed
+    pub async fn load_paused_schedules(&self) -> Result<Vec<UnifiedSchedule>, SchedulerError> {
+        let filters = ScheduleFilters {
+            enabled: Some(false),
+            paused: Some(true),
+            ..Default::default()
+        };
+        let mut paused: Vec<UnifiedSchedule> = Vec::new();
+
+        loop {
+            let page = self
+                .repositories
+                .schedule_repository()
+                .find_after(filters.clone(), paused.last().map(|schedule| schedule.id.clone()), 100)
+                .await
+                .map_err(|e| SchedulerError::Repository(e.to_string()))?;
+
+            let done = page.len() < 100;
+            paused.extend(page);
+            if done {
+                return Ok(paused);
+            }
+        }
+    }
+
     /// Create a job for a scheduled execution
     pub async fn create_job_for_schedule(
         &self,
@@ -66,9 +93,13 @@ impl RepositoryBridge {
             retry_count: 0,
             max_retries: 3,
             queued_at: execution_time,
-            scheduled_for: Some(execution_time),
+            // Spread runs of schedules sharing a cron so they don't hit the queue at once
+            scheduled_for: Some(apply_jitter(execution_time, schedule.jitter_seconds.unwrap_or(0))),
             error_message: None,
             output_destinations: schedule.output_destinations.clone(),
+            schedule_id: Some(schedule.id.clone()),
+            execution_id: None,
+            log_level: None,
         };
 
         // Store the job through the repository
@@ -87,6 +118,57 @@ impl RepositoryBridge {
         Ok(created_job)
     }
 
+    /// Count a schedule's jobs that are queued, processing or waiting to retry
+    pub async fn count_active_jobs(&self, schedule_id: ApiId) -> Result<usize, SchedulerError> {
+        let jobs = self
+            .repositories
+            .job_repository()
+            .find_active_by_schedule(schedule_id)
+            .await
+            .map_err(|e| SchedulerError::Repository(e.to_string()))?;
+        Ok(jobs.len())
+    }
+
+    /// Poll a job until it reaches a terminal status or the timeout elapses
+    pub async fn wait_for_job_completion(
+        &self,
+        job_id: ApiId,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<JobStatus, SchedulerError> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let id = job_id
+            .as_i32()
+            .ok_or_else(|| SchedulerError::Internal(format!("Job ID {} is not a database ID", job_id)))?;
+
+        loop {
+            let job = self
+                .repositories
+                .job_repository()
+                .find_by_id(id)
+                .await
+                .map_err(|e| SchedulerError::Repository(e.to_string()))?
+                .ok_or_else(|| SchedulerError::Repository(format!("Job {} not found", job_id)))?;
+
+            if matches!(
+                job.status,
+                JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled | JobStatus::DeadLetter
+            ) {
+                debug!("Job {} finished with status {:?}", job_id, job.status);
+                return Ok(job.status);
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(SchedulerError::Internal(format!(
+                    "Timed out after {:?} waiting for job {} to finish",
+                    timeout, job_id
+                )));
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
     /// Update schedule execution metadata
     pub async fn update_schedule_execution(
         &self,
@@ -149,6 +231,19 @@ impl RepositoryBridge {
             .map_err(|e| SchedulerError::Repository(format!("Failed to update schedule: {}", e)))
     }
 
+    /// Enable or disable several schedules in one transaction
+    pub async fn set_schedules_enabled(
+        &self,
+        schedule_ids: Vec<ApiId>,
+        enabled: bool,
+    ) -> Result<Vec<UnifiedSchedule>, SchedulerError> {
+        self.repositories
+            .schedule_repository()
+            .set_enabled_many(schedule_ids, enabled)
+            .await
+            .map_err(|e| SchedulerError::Repository(format!("Failed to update schedules: {}", e)))
+    }
+
     /// Delete a schedule
     pub async fn delete_schedule(&self, schedule_id: ApiId) -> Result<(), SchedulerError> {
         self.repositories
@@ -158,3 +253,54 @@ impl RepositoryBridge {
             .map_err(|e| SchedulerError::Repository(format!("Failed to delete schedule: {}", e)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::DirectRepositoryFactory;
+    use ratchet_interfaces::CrudRepository;
+    use ratchet_storage::seaorm::config::DatabaseConfig;
+    use ratchet_storage::seaorm::connection::DatabaseConnection;
+    use ratchet_storage::seaorm::repositories::RepositoryFactory as StorageRepositoryFactory;
+    use ratchet_storage::testing::builders::{ScheduleBuilder, TaskBuilder};
+
+    #[tokio::test]
+    async fn test_scheduled_jobs_are_spread_over_the_jitter_window() {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            ..Default::default()
+        };
+        let db = DatabaseConnection::new(config).await.unwrap();
+        db.migrate().await.unwrap();
+
+        let storage = Arc::new(StorageRepositoryFactory::new(db));
+        let task = storage
+            .task_repository()
+            .create(TaskBuilder::new().with_name("scheduled-task").build())
+            .await
+            .unwrap();
+        let mut schedule = ScheduleBuilder::new()
+            .with_task_id(task.id)
+            .with_name("nightly-report")
+            .build();
+        schedule.metadata = Some(serde_json::json!({ "jitter_seconds": 30 }));
+        let schedule = storage.schedule_repository().create(schedule).await.unwrap();
+
+        let bridge = RepositoryBridge::new(Arc::new(DirectRepositoryFactory::new(storage)));
+        let fired_at = Utc::now();
+        let mut runs = Vec::new();
+        for _ in 0..10 {
+            let job = bridge
+                .create_job_for_schedule(ApiId::from_i32(schedule.id), fired_at)
+                .await
+                .unwrap();
+            runs.push(job.scheduled_for.unwrap());
+        }
+
+        assert!(runs
+            .iter()
+            .all(|run| *run >= fired_at && *run <= fired_at + chrono::Duration::seconds(30)));
+        assert!(runs.iter().any(|run| *run != runs[0]), "jittered runs should differ");
+    }
+}
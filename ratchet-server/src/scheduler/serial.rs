@@ -0,0 +1,184 @@
+//! Serial execution mode for schedules
+//!
+//! A serial schedule never has two executions in flight. Triggers are queued in
+//! the order they fire and each one is run to completion before the next starts,
+//! so catch-up triggers that arrive in a burst still execute one after another in
+//! trigger order instead of overlapping or being skipped.
+
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, error};
+
+use ratchet_api_types::ApiId;
+use ratchet_interfaces::SchedulerError;
+
+/// Future returned by a serial run handler
+pub type SerialRunFuture = Pin<Box<dyn Future<Output = Result<(), SchedulerError>> + Send>>;
+
+/// FIFO queue that runs a schedule's triggers one at a time
+pub struct SerialExecutionQueue {
+    schedule_id: ApiId,
+    sender: mpsc::UnboundedSender<DateTime<Utc>>,
+    pending: Arc<AtomicUsize>,
+    worker: JoinHandle<()>,
+}
+
+impl SerialExecutionQueue {
+    /// Spawn the queue worker; `run` must only resolve once the execution has finished
+    pub fn spawn<F>(schedule_id: ApiId, run: F) -> Self
+    where
+        F: Fn(DateTime<Utc>) -> SerialRunFuture + Send + Sync + 'static,
+    {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<DateTime<Utc>>();
+        let pending = Arc::new(AtomicUsize::new(0));
+
+        let worker_pending = pending.clone();
+        let worker_schedule_id = schedule_id.clone();
+        let worker = tokio::spawn(async move {
+            while let Some(trigger_time) = receiver.recv().await {
+                debug!(
+                    "Running serial execution for schedule {} triggered at {}",
+                    worker_schedule_id, trigger_time
+                );
+
+                // A failed execution does not stall the queue; later triggers still run in order
+                if let Err(e) = run(trigger_time).await {
+                    error!(
+                        "Serial execution for schedule {} triggered at {} failed: {}",
+                        worker_schedule_id, trigger_time, e
+                    );
+                }
+
+                worker_pending.fetch_sub(1, Ordering::SeqCst);
+            }
+        });
+
+        Self {
+            schedule_id,
+            sender,
+            pending,
+            worker,
+        }
+    }
+
+    /// Queue a trigger behind any executions that are still pending
+    pub fn enqueue(&self, trigger_time: DateTime<Utc>) -> Result<(), SchedulerError> {
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        self.sender.send(trigger_time).map_err(|_| {
+            self.pending.fetch_sub(1, Ordering::SeqCst);
+            SchedulerError::Internal(format!(
+                "Serial execution queue for schedule {} is closed",
+                self.schedule_id
+            ))
+        })
+    }
+
+    /// Number of triggers queued or running
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    /// Stop the worker and discard any queued triggers
+    pub fn shutdown(&self) {
+        self.worker.abort();
+    }
+}
+
+impl Drop for SerialExecutionQueue {
+    fn drop(&mut self) {
+        self.worker.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_catch_up_triggers_run_in_order_without_overlap() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let queue = {
+            let events = events.clone();
+            let running = running.clone();
+            let max_running = max_running.clone();
+            SerialExecutionQueue::spawn(ApiId::from_i32(1), move |trigger_time| {
+                let events = events.clone();
+                let running = running.clone();
+                let max_running = max_running.clone();
+                Box::pin(async move {
+                    let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(now_running, Ordering::SeqCst);
+                    events.lock().unwrap().push(("start", trigger_time));
+
+                    // Earlier triggers take longer, so overlap would reorder completions
+                    let delay = 30 - (trigger_time.timestamp() % 5) as u64 * 5;
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+
+                    events.lock().unwrap().push(("end", trigger_time));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(())
+                })
+            })
+        };
+
+        // Simulate a burst of missed triggers being caught up at once
+        let base = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let triggers: Vec<DateTime<Utc>> = (0..5).map(|i| base + ChronoDuration::seconds(i)).collect();
+        for trigger in &triggers {
+            queue.enqueue(*trigger).unwrap();
+        }
+
+        for _ in 0..100 {
+            if queue.pending() == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(queue.pending(), 0);
+
+        let expected: Vec<(&str, DateTime<Utc>)> = triggers.iter().flat_map(|t| [("start", *t), ("end", *t)]).collect();
+        assert_eq!(*events.lock().unwrap(), expected);
+        assert_eq!(max_running.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_execution_does_not_block_queue() {
+        let completed = Arc::new(AtomicUsize::new(0));
+
+        let queue = {
+            let completed = completed.clone();
+            SerialExecutionQueue::spawn(ApiId::from_i32(2), move |trigger_time| {
+                let completed = completed.clone();
+                Box::pin(async move {
+                    completed.fetch_add(1, Ordering::SeqCst);
+                    if trigger_time.timestamp() == 0 {
+                        return Err(SchedulerError::Internal("boom".to_string()));
+                    }
+                    Ok(())
+                })
+            })
+        };
+
+        queue.enqueue(DateTime::from_timestamp(0, 0).unwrap()).unwrap();
+        queue.enqueue(DateTime::from_timestamp(1, 0).unwrap()).unwrap();
+
+        for _ in 0..100 {
+            if queue.pending() == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(completed.load(Ordering::SeqCst), 2);
+    }
+}
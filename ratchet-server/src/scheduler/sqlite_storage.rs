@@ -34,6 +34,12 @@ impl SqliteMetadataStore {
                 "description": schedule.description,
                 "task_id": schedule.task_id,
                 "enabled": schedule.enabled,
+                "serial": schedule.serial,
+                "timezone": schedule.timezone,
+                "misfire_policy": schedule.misfire_policy,
+                "max_concurrent": schedule.max_concurrent,
+                "jitter_seconds": schedule.jitter_seconds,
+                "paused": schedule.paused,
                 "last_run": schedule.last_run,
                 "next_run": schedule.next_run,
                 "created_at": schedule.created_at,
@@ -81,6 +87,24 @@ impl SqliteMetadataStore {
                 .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(|| Utc::now()),
+            serial: extra.get("serial")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            timezone: extra.get("timezone")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            misfire_policy: extra.get("misfire_policy")
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_default(),
+            max_concurrent: extra.get("max_concurrent")
+                .and_then(|v| v.as_u64())
+                .map(|max| max as u32),
+            jitter_seconds: extra.get("jitter_seconds")
+                .and_then(|v| v.as_u64())
+                .map(|jitter| jitter as u32),
+            paused: extra.get("paused")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
         })
     }
 }
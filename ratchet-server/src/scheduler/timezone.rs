@@ -0,0 +1,101 @@
+//! Timezone-aware schedule triggers
+//!
+//! tokio-cron-scheduler turns a job's timezone into a fixed UTC offset when the
+//! job is created, so a schedule in a zone with daylight saving time would run
+//! an hour off after the next transition. Schedules with a timezone are driven
+//! by a [`ZonedTrigger`] instead, which works out the next occurrence in the
+//! zone before every run.
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use cron::Schedule;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// Background task firing a callback at each occurrence of a cron schedule in a timezone
+pub struct ZonedTrigger {
+    handle: JoinHandle<()>,
+}
+
+impl ZonedTrigger {
+    /// Start calling `on_trigger` with the time of each occurrence
+    pub fn spawn<F>(schedule: Schedule, timezone: Tz, on_trigger: F) -> Self
+    where
+        F: Fn(DateTime<Utc>) + Send + Sync + 'static,
+    {
+        let handle = tokio::spawn(async move {
+            let mut after = Utc::now();
+            while let Some(next) = next_occurrence(&schedule, timezone, after) {
+                let wait = (next - Utc::now()).to_std().unwrap_or_default();
+                tokio::time::sleep(wait).await;
+
+                debug!("Triggering schedule for {}", next.with_timezone(&timezone));
+                on_trigger(next);
+                after = next;
+            }
+        });
+
+        Self { handle }
+    }
+
+    /// Stop firing the trigger
+    pub fn shutdown(&self) {
+        self.handle.abort();
+    }
+}
+
+impl Drop for ZonedTrigger {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// First occurrence of `schedule` in `timezone` after `after`
+pub fn next_occurrence(schedule: &Schedule, timezone: Tz, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    schedule
+        .after(&after.with_timezone(&timezone))
+        .next()
+        .map(|run| run.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    #[test]
+    fn test_next_occurrence_follows_dst_transition() {
+        // Amsterdam moves from UTC+1 to UTC+2 on Sunday 31 March 2024
+        let schedule = Schedule::from_str("0 0 9 * * *").unwrap();
+        let timezone: Tz = "Europe/Amsterdam".parse().unwrap();
+
+        let before = next_occurrence(&schedule, timezone, Utc.with_ymd_and_hms(2024, 3, 29, 12, 0, 0).unwrap());
+        assert_eq!(before, Some(Utc.with_ymd_and_hms(2024, 3, 30, 8, 0, 0).unwrap()));
+
+        let after = next_occurrence(&schedule, timezone, Utc.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap());
+        assert_eq!(after, Some(Utc.with_ymd_and_hms(2024, 3, 31, 7, 0, 0).unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_trigger_fires_and_stops() {
+        let schedule = Schedule::from_str("* * * * * *").unwrap();
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let trigger = ZonedTrigger::spawn(schedule, chrono_tz::Asia::Tokyo, move |at| {
+            let _ = tx.send(at);
+        });
+
+        let fired = tokio::time::timeout(Duration::from_secs(3), rx.recv()).await.unwrap();
+        assert!(fired.is_some());
+
+        trigger.shutdown();
+        // The callback, and with it the sender, is dropped once the task is aborted
+        let closed = tokio::time::timeout(Duration::from_secs(3), async {
+            while rx.recv().await.is_some() {}
+        })
+        .await;
+        assert!(closed.is_ok());
+    }
+}
@@ -1,16 +1,24 @@
 //! tokio-cron-scheduler implementation of the SchedulerService trait
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use super::concurrency::ConcurrencyLimiter;
+use super::misfire::catch_up_runs;
+use super::serial::SerialExecutionQueue;
+use super::timezone::ZonedTrigger;
 use super::RepositoryBridge;
-use ratchet_api_types::{ApiId, UnifiedSchedule};
+use chrono_tz::Tz;
+use ratchet_api_types::{ApiId, UnifiedJob, UnifiedSchedule};
+use ratchet_core::schedule::{normalize_cron_expression, parse_cron_expression, parse_timezone, preview_schedule};
 use ratchet_interfaces::{RepositoryFactory, ScheduleStatus, SchedulerError, SchedulerService};
 
 /// Configuration for the tokio-cron-scheduler service
@@ -22,6 +30,8 @@ pub struct TokioCronSchedulerConfig {
     pub job_timeout_seconds: u64,
     /// Enable job notifications
     pub enable_notifications: bool,
+    /// How often serial schedules poll for completion of their current job, in seconds
+    pub serial_poll_interval_seconds: u64,
 }
 
 impl Default for TokioCronSchedulerConfig {
@@ -30,6 +40,7 @@ impl Default for TokioCronSchedulerConfig {
             max_concurrent_jobs: 100,
             job_timeout_seconds: 3600,
             enable_notifications: false,
+            serial_poll_interval_seconds: 1,
         }
     }
 }
@@ -40,6 +51,8 @@ pub struct TokioCronSchedulerService {
     repository_bridge: Arc<RepositoryBridge>,
     config: TokioCronSchedulerConfig,
     is_running: AtomicBool,
+    serial_queues: Mutex<HashMap<ApiId, Arc<SerialExecutionQueue>>>,
+    zoned_triggers: Mutex<HashMap<ApiId, ZonedTrigger>>,
 }
 
 impl TokioCronSchedulerService {
@@ -67,19 +80,51 @@ impl TokioCronSchedulerService {
             repository_bridge,
             config,
             is_running: AtomicBool::new(false),
+            serial_queues: Mutex::new(HashMap::new()),
+            zoned_triggers: Mutex::new(HashMap::new()),
         })
     }
 
     /// Create a job execution handler for schedule execution
-    fn create_job_execution_handler(&self, schedule_id: ApiId) -> impl Fn(Uuid) + Send + Sync + Clone {
+    ///
+    /// Serial schedules hand their triggers to a per-schedule FIFO queue instead of spawning them directly.
+    /// Schedules with `max_concurrent` skip triggers while that many of their jobs are still active.
+    async fn create_job_execution_handler(&self, schedule: &UnifiedSchedule) -> impl Fn(Uuid) + Send + Sync + Clone {
         let bridge = self.repository_bridge.clone();
+        let schedule_id = schedule.id.clone();
+        let serial_queue = if schedule.serial {
+            Some(self.serial_queue_for(schedule_id.clone()).await)
+        } else {
+            None
+        };
+        let limiter = schedule
+            .max_concurrent
+            .map(|max_concurrent| Arc::new(ConcurrencyLimiter::new(schedule_id.clone(), max_concurrent)));
 
         move |_job_id: Uuid| {
+            if let Some(queue) = &serial_queue {
+                if let Err(e) = queue.enqueue(Utc::now()) {
+                    error!("Failed to queue serial execution for schedule {}: {}", schedule_id, e);
+                }
+                return;
+            }
+
             let bridge = bridge.clone();
+            let limiter = limiter.clone();
             let schedule_id_for_exec = schedule_id.clone();
             let schedule_id_for_log = schedule_id.clone();
             tokio::spawn(async move {
-                if let Err(e) = Self::execute_scheduled_job(bridge, schedule_id_for_exec).await {
+                let result = match limiter {
+                    Some(limiter) => limiter
+                        .run_if_below_limit(
+                            bridge.count_active_jobs(schedule_id_for_exec.clone()),
+                            Self::execute_scheduled_job(bridge.clone(), schedule_id_for_exec),
+                        )
+                        .await
+                        .map(|_| ()),
+                    None => Self::execute_scheduled_job(bridge, schedule_id_for_exec).await,
+                };
+                if let Err(e) = result {
                     error!(
                         "Failed to execute scheduled job for schedule {}: {}",
                         schedule_id_for_log, e
@@ -89,10 +134,44 @@ impl TokioCronSchedulerService {
         }
     }
 
+    /// Get or create the serial execution queue for a schedule
+    async fn serial_queue_for(&self, schedule_id: ApiId) -> Arc<SerialExecutionQueue> {
+        let mut queues = self.serial_queues.lock().await;
+        if let Some(queue) = queues.get(&schedule_id) {
+            return queue.clone();
+        }
+
+        let bridge = self.repository_bridge.clone();
+        let poll_interval = Duration::from_secs(self.config.serial_poll_interval_seconds.max(1));
+        let timeout = Duration::from_secs(self.config.job_timeout_seconds);
+        let queue_schedule_id = schedule_id.clone();
+
+        let queue = Arc::new(SerialExecutionQueue::spawn(schedule_id.clone(), move |trigger_time| {
+            let bridge = bridge.clone();
+            let schedule_id = queue_schedule_id.clone();
+            Box::pin(async move {
+                let job = Self::create_scheduled_job(&bridge, schedule_id, trigger_time).await?;
+                bridge.wait_for_job_completion(job.id, poll_interval, timeout).await?;
+                Ok(())
+            })
+        }));
+
+        queues.insert(schedule_id, queue.clone());
+        queue
+    }
+
     /// Execute a scheduled job by creating a job in the repository
     async fn execute_scheduled_job(bridge: Arc<RepositoryBridge>, schedule_id: ApiId) -> Result<(), SchedulerError> {
-        let execution_time = Utc::now();
+        Self::create_scheduled_job(&bridge, schedule_id, Utc::now()).await?;
+        Ok(())
+    }
 
+    /// Create the job for a trigger and record the run on the schedule
+    async fn create_scheduled_job(
+        bridge: &RepositoryBridge,
+        schedule_id: ApiId,
+        execution_time: DateTime<Utc>,
+    ) -> Result<UnifiedJob, SchedulerError> {
         debug!("Executing scheduled job for schedule: {}", schedule_id);
 
         // Create job through repository pattern
@@ -111,7 +190,7 @@ impl TokioCronSchedulerService {
             schedule_id, created_job.id
         );
 
-        Ok(())
+        Ok(created_job)
     }
 
     /// Load existing schedules from the repository and add them to the scheduler
@@ -132,32 +211,102 @@ impl TokioCronSchedulerService {
                 schedule.name, schedule.cron_expression
             );
 
-            // Create job with our execution handler
-            let schedule_id = schedule.id.clone();
-            let cron_expression = schedule.cron_expression.clone();
-            let execution_handler = self.create_job_execution_handler(schedule_id);
+            self.register_schedule(&schedule).await?;
+            info!("Successfully added schedule to scheduler: {}", schedule.name);
+
+            if let Err(e) = self.catch_up_schedule(&schedule).await {
+                error!("Failed to catch up missed runs for schedule {}: {}", schedule.name, e);
+            }
+        }
 
-            let job = Job::new_async(cron_expression.as_str(), move |uuid, _| {
-                execution_handler(uuid);
-                Box::pin(async {})
-            })
-            .map_err(|e| {
-                error!("Failed to create job for schedule {}: {}", schedule.name, e);
-                SchedulerError::InvalidCron(format!("Invalid cron expression '{}': {}", cron_expression, e))
-            })?;
+        Ok(())
+    }
 
-            // Add job to scheduler
-            let scheduler = self.scheduler.lock().await;
-            scheduler.add(job).await.map_err(|e| {
-                error!("Failed to add job to scheduler: {}", e);
-                SchedulerError::Internal(format!("Failed to add job to scheduler: {}", e))
-            })?;
+    /// Run the triggers a schedule missed while the scheduler was down, as its misfire policy allows
+    async fn catch_up_schedule(&self, schedule: &UnifiedSchedule) -> Result<(), SchedulerError> {
+        let runs = catch_up_runs(schedule, Utc::now())?;
+        if runs.is_empty() {
+            return Ok(());
+        }
 
-            info!("Successfully added schedule to scheduler: {}", schedule.name);
+        info!(
+            "Catching up {} missed run(s) for schedule {} ({:?})",
+            runs.len(),
+            schedule.name,
+            schedule.misfire_policy
+        );
+
+        if schedule.serial {
+            let queue = self.serial_queue_for(schedule.id.clone()).await;
+            for run in runs {
+                queue.enqueue(run)?;
+            }
+            return Ok(());
         }
 
+        for run in runs {
+            Self::create_scheduled_job(&self.repository_bridge, schedule.id.clone(), run).await?;
+        }
         Ok(())
     }
+
+    /// Start triggering a schedule
+    ///
+    /// Schedules with a timezone get a [`ZonedTrigger`] so their run times follow daylight saving
+    /// transitions; all others are added to tokio-cron-scheduler, which evaluates them in UTC.
+    async fn register_schedule(&self, schedule: &UnifiedSchedule) -> Result<(), SchedulerError> {
+        let cron_expression = scheduler_cron_expression(schedule)?;
+        let execution_handler = self.create_job_execution_handler(schedule).await;
+
+        if let Some(timezone) = schedule_timezone(schedule)? {
+            let cron_schedule =
+                parse_cron_expression(&cron_expression).map_err(|e| SchedulerError::InvalidCron(e.to_string()))?;
+            let trigger = ZonedTrigger::spawn(cron_schedule, timezone, move |_| execution_handler(Uuid::new_v4()));
+            if let Some(previous) = self.zoned_triggers.lock().await.insert(schedule.id.clone(), trigger) {
+                previous.shutdown();
+            }
+            return Ok(());
+        }
+
+        let job = Job::new_async(cron_expression.as_str(), move |uuid, _| {
+            execution_handler(uuid);
+            Box::pin(async {})
+        })
+        .map_err(|e| {
+            error!("Failed to create job for schedule {}: {}", schedule.name, e);
+            SchedulerError::InvalidCron(format!("Invalid cron expression '{}': {}", cron_expression, e))
+        })?;
+
+        // Add job to scheduler
+        let scheduler = self.scheduler.lock().await;
+        scheduler.add(job).await.map_err(|e| {
+            error!("Failed to add job to scheduler: {}", e);
+            SchedulerError::Internal(format!("Failed to add job to scheduler: {}", e))
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Parse a schedule's timezone, `None` for schedules evaluated in UTC
+fn schedule_timezone(schedule: &UnifiedSchedule) -> Result<Option<Tz>, SchedulerError> {
+    match schedule.timezone.as_deref() {
+        None => Ok(None),
+        Some(name) => match parse_timezone(name).map_err(|e| SchedulerError::Internal(e.to_string()))? {
+            Tz::UTC => Ok(None),
+            timezone => Ok(Some(timezone)),
+        },
+    }
+}
+
+/// Validate a schedule's cron expression and return it in the 6-field form the job scheduler expects
+fn scheduler_cron_expression(schedule: &UnifiedSchedule) -> Result<String, SchedulerError> {
+    parse_cron_expression(&schedule.cron_expression)
+        .and_then(|_| normalize_cron_expression(&schedule.cron_expression))
+        .map_err(|e| {
+            error!("Invalid cron expression for schedule {}: {}", schedule.name, e);
+            SchedulerError::InvalidCron(e.to_string())
+        })
 }
 
 #[async_trait]
@@ -229,6 +378,9 @@ impl SchedulerService for TokioCronSchedulerService {
             SchedulerError::Internal(format!("Failed to stop scheduler: {}", e))
         })?;
 
+        // Dropping the zoned triggers stops them
+        self.zoned_triggers.lock().await.clear();
+
         self.is_running.store(false, Ordering::Relaxed);
         info!("tokio-cron-scheduler service stopped successfully");
 
@@ -250,26 +402,7 @@ impl SchedulerService for TokioCronSchedulerService {
             return Ok(());
         }
 
-        // Create job with our execution handler
-        let schedule_id = schedule.id.clone();
-        let cron_expression = schedule.cron_expression.clone();
-        let execution_handler = self.create_job_execution_handler(schedule_id);
-
-        let job = Job::new_async(cron_expression.as_str(), move |uuid, _| {
-            execution_handler(uuid);
-            Box::pin(async {})
-        })
-        .map_err(|e| {
-            error!("Failed to create job for schedule {}: {}", schedule.name, e);
-            SchedulerError::InvalidCron(format!("Invalid cron expression '{}': {}", cron_expression, e))
-        })?;
-
-        // Add job to scheduler
-        let scheduler = self.scheduler.lock().await;
-        scheduler.add(job).await.map_err(|e| {
-            error!("Failed to add job to scheduler: {}", e);
-            SchedulerError::Internal(format!("Failed to add job to scheduler: {}", e))
-        })?;
+        self.register_schedule(&schedule).await?;
 
         info!("Successfully added schedule to scheduler: {}", schedule.name);
         Ok(())
@@ -279,6 +412,18 @@ impl SchedulerService for TokioCronSchedulerService {
     async fn remove_schedule(&self, schedule_id: ApiId) -> Result<(), SchedulerError> {
         info!("Removing schedule from scheduler: {}", schedule_id);
 
+        // Discard any serial triggers still waiting to run
+        if let Some(queue) = self.serial_queues.lock().await.remove(&schedule_id) {
+            queue.shutdown();
+        }
+
+        // Zoned schedules are not known to tokio-cron-scheduler
+        if let Some(trigger) = self.zoned_triggers.lock().await.remove(&schedule_id) {
+            trigger.shutdown();
+            info!("Successfully removed schedule from scheduler: {}", schedule_id);
+            return Ok(());
+        }
+
         let job_uuid = schedule_id
             .as_uuid()
             .ok_or_else(|| SchedulerError::Internal(format!("Cannot convert schedule_id to UUID: {}", schedule_id)))?;
@@ -325,12 +470,94 @@ impl SchedulerService for TokioCronSchedulerService {
             id: final_schedule_id,
             enabled: schedule.enabled,
             last_run: schedule.last_run,
-            next_run: schedule.next_run,
+            next_run: schedule.next_run.or_else(|| {
+                let timezone = schedule.timezone.as_deref().unwrap_or("UTC");
+                preview_schedule(&schedule.cron_expression, timezone, Utc::now(), 1)
+                    .ok()
+                    .and_then(|runs| runs.into_iter().next())
+                    .map(|run| run.with_timezone(&Utc))
+            }),
             is_running: self.is_running.load(Ordering::Relaxed) && schedule.enabled,
             run_count: 0, // TODO: Track this separately if needed
         })
     }
 
+    /// Pause every enabled schedule, marking it for `resume_all`
+    async fn pause_all(&self) -> Result<Vec<ApiId>, SchedulerError> {
+        let schedules = self.repository_bridge.load_all_schedules().await?;
+        info!("Pausing {} enabled schedules", schedules.len());
+
+        let mut paused = Vec::with_capacity(schedules.len());
+        for mut schedule in schedules {
+            if let Err(e) = self.remove_schedule(schedule.id.clone()).await {
+                warn!(
+                    "Failed to remove paused schedule {} from scheduler: {}",
+                    schedule.name, e
+                );
+            }
+
+            schedule.enabled = false;
+            schedule.paused = true;
+            schedule.updated_at = Utc::now();
+            let schedule = self.repository_bridge.update_schedule(schedule).await?;
+            paused.push(schedule.id);
+        }
+
+        Ok(paused)
+    }
+
+    /// Re-enable the schedules that `pause_all` disabled
+    async fn resume_all(&self) -> Result<Vec<ApiId>, SchedulerError> {
+        let schedules = self.repository_bridge.load_paused_schedules().await?;
+        info!("Resuming {} paused schedules", schedules.len());
+
+        let mut resumed = Vec::with_capacity(schedules.len());
+        for mut schedule in schedules {
+            schedule.enabled = true;
+            schedule.paused = false;
+            schedule.updated_at = Utc::now();
+            let schedule = self.repository_bridge.update_schedule(schedule).await?;
+
+            resumed.push(schedule.id.clone());
+            if let Err(e) = self.add_schedule(schedule).await {
+                warn!("Failed to add resumed schedule to scheduler: {}", e);
+            }
+        }
+
+        Ok(resumed)
+    }
+
+    /// Enable or disable several schedules, updating none unless they all exist
+    async fn set_enabled(&self, schedule_ids: Vec<ApiId>, enabled: bool) -> Result<(), SchedulerError> {
+        for schedule_id in &schedule_ids {
+            if self
+                .repository_bridge
+                .find_schedule(schedule_id.clone())
+                .await?
+                .is_none()
+            {
+                return Err(SchedulerError::ScheduleNotFound(schedule_id.clone()));
+            }
+        }
+
+        // The repository applies the whole batch in one transaction
+        let schedules = self
+            .repository_bridge
+            .set_schedules_enabled(schedule_ids, enabled)
+            .await?;
+
+        for schedule in schedules {
+            if let Err(e) = self.remove_schedule(schedule.id.clone()).await {
+                debug!("Schedule {} was not active in scheduler: {}", schedule.name, e);
+            }
+
+            // add_schedule skips disabled schedules
+            self.add_schedule(schedule).await?;
+        }
+
+        Ok(())
+    }
+
     /// Check if the scheduler is running
     fn is_running(&self) -> bool {
         self.is_running.load(Ordering::Relaxed)
@@ -343,3 +570,113 @@ impl SchedulerService for TokioCronSchedulerService {
         Ok(schedules.len())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::DirectRepositoryFactory;
+    use ratchet_interfaces::CrudRepository;
+    use ratchet_storage::seaorm::config::DatabaseConfig;
+    use ratchet_storage::seaorm::connection::DatabaseConnection;
+    use ratchet_storage::seaorm::repositories::RepositoryFactory as StorageRepositoryFactory;
+    use ratchet_storage::testing::builders::{ScheduleBuilder, TaskBuilder};
+
+    /// Build a scheduler over an in-memory database holding the given `(name, enabled)` schedules
+    async fn scheduler_with(
+        schedules: &[(&str, bool)],
+    ) -> (TokioCronSchedulerService, Arc<dyn RepositoryFactory>, Vec<ApiId>) {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            ..Default::default()
+        };
+        let db = DatabaseConnection::new(config).await.unwrap();
+        db.migrate().await.unwrap();
+
+        let storage = Arc::new(StorageRepositoryFactory::new(db));
+        let task = storage
+            .task_repository()
+            .create(TaskBuilder::new().with_name("scheduled-task").build())
+            .await
+            .unwrap();
+
+        let mut ids = Vec::new();
+        for (name, enabled) in schedules {
+            let schedule = ScheduleBuilder::new()
+                .with_task_id(task.id)
+                .with_name(*name)
+                .enabled(*enabled)
+                .build();
+            let schedule = storage.schedule_repository().create(schedule).await.unwrap();
+            ids.push(ApiId::from_i32(schedule.id));
+        }
+
+        let repositories: Arc<dyn RepositoryFactory> = Arc::new(DirectRepositoryFactory::new(storage));
+        let scheduler = TokioCronSchedulerService::new(repositories.clone(), TokioCronSchedulerConfig::default())
+            .await
+            .unwrap();
+        (scheduler, repositories, ids)
+    }
+
+    async fn schedule(repositories: &Arc<dyn RepositoryFactory>, id: &ApiId) -> UnifiedSchedule {
+        repositories
+            .schedule_repository()
+            .find_by_id(id.as_i32().unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_resume_all_only_restores_paused_schedules() {
+        let (scheduler, repositories, ids) = scheduler_with(&[
+            ("nightly-report", true),
+            ("hourly-sync", true),
+            ("manual-backup", false),
+        ])
+        .await;
+
+        let mut paused = scheduler.pause_all().await.unwrap();
+        paused.sort_by_key(|id| id.as_i32());
+        assert_eq!(paused, ids[..2].to_vec());
+        for id in &ids[..2] {
+            let schedule = schedule(&repositories, id).await;
+            assert!(!schedule.enabled);
+            assert!(schedule.paused);
+        }
+
+        let mut resumed = scheduler.resume_all().await.unwrap();
+        resumed.sort_by_key(|id| id.as_i32());
+        assert_eq!(resumed, ids[..2].to_vec());
+        for id in &ids[..2] {
+            let schedule = schedule(&repositories, id).await;
+            assert!(schedule.enabled);
+            assert!(!schedule.paused);
+        }
+
+        // Disabled by hand, so resume_all leaves it alone
+        let untouched = schedule(&repositories, &ids[2]).await;
+        assert!(!untouched.enabled);
+        assert!(!untouched.paused);
+    }
+
+    #[tokio::test]
+    async fn test_set_enabled_updates_all_schedules_or_none() {
+        let (scheduler, repositories, ids) = scheduler_with(&[("nightly-report", true), ("hourly-sync", true)]).await;
+
+        let missing = ApiId::from_i32(9999);
+        let result = scheduler.set_enabled(vec![ids[0].clone(), missing], false).await;
+        assert!(matches!(result, Err(SchedulerError::ScheduleNotFound(_))));
+        assert!(schedule(&repositories, &ids[0]).await.enabled);
+
+        scheduler.set_enabled(ids.clone(), false).await.unwrap();
+        for id in &ids {
+            assert!(!schedule(&repositories, id).await.enabled);
+        }
+
+        scheduler.set_enabled(ids.clone(), true).await.unwrap();
+        for id in &ids {
+            assert!(schedule(&repositories, id).await.enabled);
+        }
+    }
+}
@@ -5,10 +5,13 @@
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use ratchet_interfaces::{AuditError, AuditLogExport};
+use ratchet_output::{DeliveryContext, OutputDeliveryManager, TaskOutput};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs::{File, OpenOptions};
 use tokio::io::{AsyncWriteExt, BufWriter};
 use tokio::sync::{mpsc, RwLock};
@@ -70,8 +73,8 @@ pub trait AuditStorage: Send + Sync {
     /// Export audit logs
     async fn export(&self, format: AuditExportFormat, query: &AuditQuery) -> Result<Vec<u8>>;
     
-    /// Cleanup old audit logs
-    async fn cleanup(&self, retention_days: u32) -> Result<u64>;
+    /// Delete entries recorded at or before `cutoff`, returning how many were removed
+    async fn cleanup(&self, cutoff: DateTime<Utc>) -> Result<u64>;
 }
 
 /// Audit query parameters
@@ -311,8 +314,10 @@ impl AuditStorage for FileAuditStorage {
         }
     }
 
-    async fn cleanup(&self, retention_days: u32) -> Result<u64> {
-        let cutoff_date = Utc::now() - chrono::Duration::days(retention_days as i64);
+    async fn cleanup(&self, cutoff: DateTime<Utc>) -> Result<u64> {
+        // Hold the writer so no entry is appended while files are rewritten
+        let _writer = self.writer.write().await;
+        let current_file = self.current_file.read().await.clone();
         let mut deleted_count = 0;
 
         let mut dir_entries = tokio::fs::read_dir(&self.base_path).await
@@ -320,19 +325,41 @@ impl AuditStorage for FileAuditStorage {
 
         while let Some(entry) = dir_entries.next_entry().await? {
             let file_path = entry.path();
-            if file_path.extension().and_then(|s| s.to_str()) == Some("jsonl") {
-                if let Ok(metadata) = entry.metadata().await {
-                    if let Ok(modified) = metadata.modified() {
-                        let modified_datetime: DateTime<Utc> = modified.into();
-                        if modified_datetime < cutoff_date {
-                            if tokio::fs::remove_file(&file_path).await.is_ok() {
-                                deleted_count += 1;
-                                info!("Deleted old audit log file: {:?}", file_path);
-                            }
-                        }
+            if file_path.extension().and_then(|s| s.to_str()) != Some("jsonl") {
+                continue;
+            }
+
+            let content = tokio::fs::read_to_string(&file_path).await
+                .context("Failed to read audit log file")?;
+
+            let mut retained = String::new();
+            let mut expired = 0;
+            for line in content.lines().filter(|line| !line.trim().is_empty()) {
+                match serde_json::from_str::<AuditLogEntry>(line) {
+                    Ok(entry) if entry.timestamp <= cutoff => expired += 1,
+                    // Keep entries that are still retained or cannot be parsed
+                    _ => {
+                        retained.push_str(line);
+                        retained.push('\n');
                     }
                 }
             }
+
+            if expired == 0 {
+                continue;
+            }
+
+            // The current file stays in place because the writer still appends to it
+            if retained.is_empty() && current_file.as_ref() != Some(&file_path) {
+                tokio::fs::remove_file(&file_path).await
+                    .context("Failed to delete audit log file")?;
+            } else {
+                tokio::fs::write(&file_path, retained).await
+                    .context("Failed to rewrite audit log file")?;
+            }
+
+            deleted_count += expired;
+            info!("Deleted {} expired audit log entries from {:?}", expired, file_path);
         }
 
         Ok(deleted_count)
@@ -390,6 +417,12 @@ impl FileAuditStorage {
     }
 }
 
+/// Output destination expired audit entries are archived to
+struct AuditArchive {
+    manager: Arc<OutputDeliveryManager>,
+    destination: String,
+}
+
 /// Audit logger for security events
 pub struct AuditLogger {
     /// Storage backend
@@ -398,6 +431,8 @@ pub struct AuditLogger {
     sender: mpsc::UnboundedSender<AuditLogEntry>,
     /// Configuration
     config: Arc<RwLock<AuditConfig>>,
+    /// Archive for expired entries; they are only deleted when unset
+    archive: Option<AuditArchive>,
 }
 
 impl AuditLogger {
@@ -419,9 +454,19 @@ impl AuditLogger {
             storage,
             sender,
             config: Arc::new(RwLock::new(config)),
+            archive: None,
         }
     }
 
+    /// Deliver expired entries to `destination` on `manager` before they are deleted
+    pub fn with_archive(mut self, manager: Arc<OutputDeliveryManager>, destination: impl Into<String>) -> Self {
+        self.archive = Some(AuditArchive {
+            manager,
+            destination: destination.into(),
+        });
+        self
+    }
+
     /// Log a security event
     pub async fn log_event(&self, event: SecurityEvent) -> Result<()> {
         let config = self.config.read().await;
@@ -467,10 +512,68 @@ impl AuditLogger {
         self.storage.export(format, &query).await
     }
 
-    /// Clean up old audit logs
+    /// Apply the retention policy, returning the number of deleted entries
+    ///
+    /// With an archive configured, expired entries are delivered there first and
+    /// are kept when the delivery fails.
     pub async fn cleanup(&self) -> Result<u64> {
-        let config = self.config.read().await;
-        self.storage.cleanup(config.retention_days).await
+        let retention_days = self.config.read().await.retention_days;
+        let cutoff = Utc::now() - chrono::Duration::days(retention_days as i64);
+
+        if let Some(archive) = &self.archive {
+            let query = AuditQuery {
+                end_date: Some(cutoff),
+                ..Default::default()
+            };
+            let mut expired = self.storage.query(&query).await?;
+            if !expired.is_empty() {
+                expired.reverse();
+                self.archive_entries(archive, cutoff, &expired).await?;
+            }
+        }
+
+        self.storage.cleanup(cutoff).await
+    }
+
+    /// Deliver expired entries, oldest first, to the archive destination
+    async fn archive_entries(
+        &self,
+        archive: &AuditArchive,
+        cutoff: DateTime<Utc>,
+        entries: &[AuditLogEntry],
+    ) -> Result<()> {
+        let output = TaskOutput {
+            job_id: 0,
+            task_id: 0,
+            execution_id: 0,
+            output_data: serde_json::to_value(entries)
+                .context("Failed to serialize expired audit entries")?,
+            metadata: HashMap::from([
+                ("audit_entries".to_string(), serde_json::json!(entries.len())),
+                ("audit_cutoff".to_string(), serde_json::json!(cutoff)),
+            ]),
+            completed_at: Utc::now(),
+            execution_duration: Duration::ZERO,
+        };
+        let context = DeliveryContext {
+            task_name: "audit:archive".to_string(),
+            timestamp: output.completed_at,
+            trace_id: Uuid::new_v4().to_string(),
+            ..DeliveryContext::default()
+        };
+
+        archive
+            .manager
+            .deliver_output(&archive.destination, &output, &context)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to archive expired audit entries: {}", e))?;
+
+        info!(
+            "Archived {} expired audit log entries to {}",
+            entries.len(),
+            archive.destination
+        );
+        Ok(())
     }
 
     /// Update audit configuration
@@ -526,6 +629,30 @@ impl AuditLogger {
     }
 }
 
+#[async_trait::async_trait]
+impl AuditLogExport for AuditLogger {
+    async fn export_range(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        format: ratchet_interfaces::AuditExportFormat,
+    ) -> std::result::Result<Vec<u8>, AuditError> {
+        let format = match format {
+            ratchet_interfaces::AuditExportFormat::Json => AuditExportFormat::JSON,
+            ratchet_interfaces::AuditExportFormat::Csv => AuditExportFormat::CSV,
+        };
+        let query = AuditQuery {
+            start_date: from,
+            end_date: to,
+            ..Default::default()
+        };
+
+        self.export(format, query)
+            .await
+            .map_err(|e| AuditError::Export(e.to_string()))
+    }
+}
+
 /// Audit statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditStatistics {
@@ -581,6 +708,114 @@ mod tests {
         assert_eq!(entries[0].id, entry.id);
     }
 
+    fn entry_at(timestamp: DateTime<Utc>, message: &str) -> AuditLogEntry {
+        AuditLogEntry {
+            id: Uuid::new_v4().to_string(),
+            timestamp,
+            event: SecurityEvent::new(
+                SecurityEventType::Authentication,
+                SecurityEventSeverity::Info,
+                message.to_string(),
+                SecurityContext::system(),
+            ),
+            metadata: AuditMetadata::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_date_range_and_retention() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(FileAuditStorage::new(temp_dir.path().to_path_buf()).await.unwrap());
+        let now = Utc::now();
+
+        for (days_ago, message) in [(400, "expired login"), (10, "recent login"), (1, "latest login")] {
+            storage
+                .store(&entry_at(now - chrono::Duration::days(days_ago), message))
+                .await
+                .unwrap();
+        }
+
+        let logger = AuditLogger::new(storage.clone(), AuditConfig::default());
+
+        // Export only the entries of the last month
+        let json = logger
+            .export_range(
+                Some(now - chrono::Duration::days(30)),
+                Some(now),
+                ratchet_interfaces::AuditExportFormat::Json,
+            )
+            .await
+            .unwrap();
+        let exported: Vec<AuditLogEntry> = serde_json::from_slice(&json).unwrap();
+        let messages: Vec<&str> = exported.iter().map(|entry| entry.event.message.as_str()).collect();
+        assert_eq!(messages, vec!["latest login", "recent login"]);
+
+        let csv = logger
+            .export_range(
+                Some(now - chrono::Duration::days(5)),
+                None,
+                ratchet_interfaces::AuditExportFormat::Csv,
+            )
+            .await
+            .unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert!(csv.contains("latest login"));
+
+        // The default retention of 365 days removes only the oldest entry
+        assert_eq!(logger.cleanup().await.unwrap(), 1);
+        let remaining = storage.query(&AuditQuery::default()).await.unwrap();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.iter().all(|entry| entry.event.message != "expired login"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_archived_before_deletion() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(FileAuditStorage::new(temp_dir.path().join("audit")).await.unwrap());
+        let now = Utc::now();
+        for (days_ago, message) in [(400, "expired login"), (1, "latest login")] {
+            storage
+                .store(&entry_at(now - chrono::Duration::days(days_ago), message))
+                .await
+                .unwrap();
+        }
+
+        let archive_path = temp_dir.path().join("archive.json");
+        let manager = Arc::new(OutputDeliveryManager::new());
+        manager
+            .add_destination(
+                "audit-archive".to_string(),
+                ratchet_output::OutputDestinationConfig::Filesystem {
+                    path: archive_path.to_string_lossy().into_owned(),
+                    format: ratchet_output::OutputFormat::Json,
+                    permissions: 0o644,
+                    create_dirs: true,
+                    overwrite: true,
+                    backup_existing: false,
+                },
+            )
+            .await
+            .unwrap();
+
+        // Without a deliverable archive nothing is deleted
+        let unreachable =
+            AuditLogger::new(storage.clone(), AuditConfig::default()).with_archive(manager.clone(), "missing-archive");
+        assert!(unreachable.cleanup().await.is_err());
+        assert_eq!(storage.query(&AuditQuery::default()).await.unwrap().len(), 2);
+
+        let logger = AuditLogger::new(storage.clone(), AuditConfig::default()).with_archive(manager, "audit-archive");
+        assert_eq!(logger.cleanup().await.unwrap(), 1);
+
+        let archived: Vec<AuditLogEntry> = serde_json::from_slice(&std::fs::read(&archive_path).unwrap()).unwrap();
+        let messages: Vec<&str> = archived.iter().map(|entry| entry.event.message.as_str()).collect();
+        assert_eq!(messages, vec!["expired login"]);
+
+        let remaining = storage.query(&AuditQuery::default()).await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].event.message, "latest login");
+    }
+
     #[tokio::test]
     async fn test_audit_logger() {
         let temp_dir = TempDir::new().unwrap();
@@ -4,11 +4,13 @@ use anyhow::Result;
 use async_trait::async_trait;
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 use ratchet_interfaces::{
-    CrudRepository, DatabaseError, ExecutionFilters, ExecutionRepository, FilteredRepository, JobFilters,
-    JobRepository, RegistryError, RegistryManager, Repository, RepositoryFactory, ScheduleFilters, ScheduleRepository,
-    SyncResult, TaskFilters, TaskMetadata, TaskRegistry, TaskRepository, TaskService, TaskValidator, ValidationResult,
+    CrudRepository, DatabaseError, ExecutionFilters, ExecutionHistoryBucket, ExecutionRepository, FilteredRepository,
+    JobFilters, JobRepository, KeysetRepository, LogEvent, RegistryError, RegistryManager, Repository,
+    RepositoryFactory, ScheduleFilters, ScheduleRepository, SyncResult, TaskFilters, TaskMetadata, TaskRegistry,
+    TaskRepository, TaskService, TaskValidator, ValidationResult,
 };
 // Import storage repository trait for health checks (unused for now)
 // use ratchet_storage::seaorm::repositories::Repository as StorageRepositoryTrait;
@@ -16,18 +18,23 @@ use ratchet_api_types::{
     ApiId, ListResponse, PaginationInput, UnifiedExecution, UnifiedJob, UnifiedSchedule, UnifiedTask,
 };
 use ratchet_graphql_api::context::GraphQLContext;
+use ratchet_graphql_api::events::EventBroadcaster;
 use ratchet_http::HttpManager;
 use ratchet_mcp::server::task_dev_tools::TaskDevelopmentService;
 use ratchet_rest_api::context::TasksContext;
+use ratchet_storage::AttachmentStore;
 use uuid::Uuid;
 
-use crate::bridges::{BridgeRegistryManager, BridgeTaskRegistry, BridgeTaskValidator};
+use crate::bridges::{BridgeRegistryManager, BridgeTaskRegistry, BridgeTaskValidator, TracingStructuredLogger};
 use crate::config::ServerConfig;
 use crate::heartbeat::HeartbeatService;
 use crate::job_processor::{JobProcessor, JobProcessorConfig, JobProcessorService};
+use crate::plugins::PluginHost;
 use crate::scheduler::{SchedulerService, TokioCronSchedulerConfig, TokioCronSchedulerService};
 use crate::task_service::UnifiedTaskService;
-use ratchet_output::OutputDeliveryManager;
+use ratchet_caching::CacheRegistry;
+use ratchet_execution::{ExecutionBridge, ExecutionConfigAdapter};
+use ratchet_output::{AlertDispatcher, CrashLoopDetector, OutputDeliveryManager};
 
 // Enhanced services for repository management
 use crate::repository_services::{EnhancedRepositoryService, TaskAssignmentService, SeaOrmDatabaseInterface};
@@ -53,9 +60,18 @@ pub struct ServiceContainer {
     pub task_service: Arc<dyn TaskService>,
     pub mcp_task_service: Option<Arc<TaskDevelopmentService>>,
     pub output_manager: Arc<OutputDeliveryManager>,
+    pub attachment_store: Arc<AttachmentStore>,
+    pub cache_registry: Arc<CacheRegistry>,
+    pub alert_dispatcher: Arc<AlertDispatcher>,
+    /// Worker pool every task execution goes through
+    pub task_executor: Arc<ExecutionBridge>,
+    /// Fans execution changes out to GraphQL subscribers
+    pub event_broadcaster: Arc<EventBroadcaster>,
     pub scheduler_service: Option<Arc<dyn SchedulerService>>,
     pub job_processor_service: Option<Arc<dyn JobProcessor>>,
     pub heartbeat_service: Arc<HeartbeatService>,
+    /// Dynamic plugins, loaded from the plugins directory when the server starts
+    pub plugin_host: Arc<PluginHost>,
     pub storage_factory: Option<Arc<ratchet_storage::seaorm::repositories::RepositoryFactory>>,
     // Enhanced repository management services
     pub enhanced_repository_service: Option<Arc<EnhancedRepositoryService>>,
@@ -79,8 +95,21 @@ impl ServiceContainer {
         // In the future, these would be replaced with the new modular implementations
 
         // This is a bridge implementation during the migration
-        let (repositories, mcp_task_service, seaorm_factory) = create_repository_factory_with_mcp(config).await?;
-        let registry = create_task_registry(config, repositories.clone()).await?;
+        let event_broadcaster = Arc::new(EventBroadcaster::new());
+
+        // Create store for files attached to executions
+        let attachment_store = Arc::new(
+            AttachmentStore::new(&config.execution.attachments.directory)
+                .with_max_size(config.execution.attachments.max_size_bytes),
+        );
+
+        let (repositories, mcp_task_service, seaorm_factory) = create_repository_factory_with_mcp(
+            config,
+            event_broadcaster.execution_sender(),
+            attachment_store.clone(),
+        )
+        .await?;
+        let registry = create_task_registry(config, repositories.clone(), seaorm_factory.clone()).await?;
         let registry_manager = create_registry_manager(config).await?;
         let validator = create_task_validator(config).await?;
         
@@ -91,7 +120,45 @@ impl ServiceContainer {
         ));
 
         // Create output delivery manager
-        let output_manager = Arc::new(OutputDeliveryManager::new());
+        let output_manager = Arc::new(OutputDeliveryManager::with_max_concurrent_deliveries(
+            config.output.max_concurrent_deliveries,
+        ));
+        output_manager
+            .configure_default_destination(&config.output)
+            .await
+            .map_err(|e| anyhow::anyhow!("Invalid default output destination: {}", e))?;
+
+        // Caches register here to be inspected and flushed through the admin API
+        let cache_registry = Arc::new(CacheRegistry::new());
+
+        // Create alert dispatcher for system health events
+        let alert_dispatcher = Arc::new(
+            AlertDispatcher::from_config(&config.alerting, output_manager.clone())
+                .await
+                .map_err(|e| anyhow::anyhow!("Invalid alerting configuration: {}", e))?,
+        );
+
+        // Create plugin host; plugins see the subsystem settings the server was configured with
+        let plugin_system_config = ratchet_config::RatchetConfig {
+            execution: config.execution.clone(),
+            output: config.output.clone(),
+            features: config.features.clone(),
+            alerting: config.alerting.clone(),
+            audit: config.audit.clone(),
+            plugins: config.plugins.clone(),
+            ..Default::default()
+        };
+        let plugin_host = Arc::new(PluginHost::new(plugin_system_config.clone()));
+
+        // Create the worker pool tasks run on; crash-looping workers raise alerts and
+        // the hooks plugins register run around every execution
+        let task_executor = create_task_executor(
+            config,
+            alert_dispatcher.clone(),
+            plugin_host.manager().hooks().clone(),
+            plugin_system_config,
+        )
+        .await;
 
         // Create scheduler service (using new tokio-cron-scheduler implementation)
         let scheduler_config = TokioCronSchedulerConfig::default();
@@ -100,12 +167,17 @@ impl ServiceContainer {
         ));
 
         // Create job processor service
-        let job_processor_config = JobProcessorConfig::default();
+        let job_processor_config = JobProcessorConfig {
+            execution_deadline: config.execution.max_total_duration,
+            ..JobProcessorConfig::default()
+        };
         let job_processor_service: Option<Arc<dyn JobProcessor>> = Some(Arc::new(JobProcessorService::new(
             repositories.clone(),
             output_manager.clone(),
             job_processor_config,
-        )));
+        )
+        .with_task_executor(task_executor.clone())
+        .with_alert_dispatcher(alert_dispatcher.clone())));
 
         // Create heartbeat service
         let heartbeat_service = Arc::new(HeartbeatService::new(
@@ -187,8 +259,20 @@ impl ServiceContainer {
                     return Err(e.into());
                 }
             };
-            let audit_config = crate::config::AuditConfig::default();
-            let audit_log = Arc::new(AuditLogger::new(audit_storage, audit_config));
+            let audit_config = crate::config::AuditConfig {
+                retention_days: config.audit.retention_days,
+                ..Default::default()
+            };
+            let mut audit_log = AuditLogger::new(audit_storage, audit_config);
+            if config.audit.retention_action == ratchet_config::domains::audit::AuditRetentionAction::Archive {
+                let destination = config.audit.archive_destination.clone().unwrap_or_default();
+                output_manager
+                    .add_global_destination(&config.output, &destination, "audit.archive_destination")
+                    .await
+                    .map_err(|e| anyhow::anyhow!("Invalid audit archive destination: {}", e))?;
+                audit_log = audit_log.with_archive(output_manager.clone(), destination);
+            }
+            let audit_log = Arc::new(audit_log);
 
             // Create access control service
             let access_config = crate::config::AccessControlConfig::default();
@@ -216,9 +300,15 @@ impl ServiceContainer {
             task_service,
             mcp_task_service,
             output_manager,
+            attachment_store,
+            cache_registry,
+            alert_dispatcher,
+            task_executor,
+            event_broadcaster,
             scheduler_service,
             job_processor_service,
             heartbeat_service,
+            plugin_host,
             storage_factory: Some(seaorm_factory),
             enhanced_repository_service,
             task_assignment_service,
@@ -248,7 +338,7 @@ impl ServiceContainer {
 
     /// Create REST API context from service container
     pub fn rest_context(&self) -> TasksContext {
-        if let (Some(mcp), Some(scheduler)) = (&self.mcp_task_service, &self.scheduler_service) {
+        let context = if let (Some(mcp), Some(scheduler)) = (&self.mcp_task_service, &self.scheduler_service) {
             TasksContext::with_all_services(
                 self.repositories.clone(),
                 self.registry.clone(),
@@ -280,16 +370,27 @@ impl ServiceContainer {
                 self.registry_manager.clone(),
                 self.validator.clone(),
             )
+        };
+
+        let context = context
+            .with_attachment_store(self.attachment_store.clone())
+            .with_cache_registry(self.cache_registry.clone())
+            .with_plugin_inventory(self.plugin_host.clone());
+
+        match &self.audit_logger {
+            Some(audit_logger) => context.with_audit_log(audit_logger.clone()),
+            None => context,
         }
     }
 
     /// Create GraphQL context from service container
     pub fn graphql_context(&self) -> GraphQLContext {
-        GraphQLContext::new(
+        GraphQLContext::with_event_broadcaster(
             self.repositories.clone(),
             self.registry.clone(),
             self.registry_manager.clone(),
             self.validator.clone(),
+            self.event_broadcaster.clone(),
         )
     }
 
@@ -345,6 +446,18 @@ impl DirectRepositoryFactory {
         }
     }
 
+    /// Publish execution changes made through this factory to `updates`
+    pub fn with_execution_updates(mut self, updates: broadcast::Sender<UnifiedExecution>) -> Self {
+        self.execution_repository.updates = Some(updates);
+        self
+    }
+
+    /// List the files each execution attached from `store` when executions are read
+    pub fn with_attachment_store(mut self, store: Arc<AttachmentStore>) -> Self {
+        self.execution_repository.attachments = Some(store);
+        self
+    }
+
     /// Get access to the underlying storage factory (for MCP service creation)
     pub fn storage_factory(&self) -> &Arc<ratchet_storage::seaorm::repositories::RepositoryFactory> {
         &self.storage_factory
@@ -528,6 +641,27 @@ impl FilteredRepository<UnifiedTask, TaskFilters> for DirectTaskRepository {
     }
 }
 
+#[async_trait]
+impl KeysetRepository<UnifiedTask, TaskFilters> for DirectTaskRepository {
+    async fn find_after(
+        &self,
+        filters: TaskFilters,
+        after: Option<ApiId>,
+        limit: u64,
+    ) -> Result<Vec<UnifiedTask>, DatabaseError> {
+        let tasks = self
+            .storage_repo
+            .find_after(
+                convert_interface_filters_to_storage(filters),
+                after.and_then(|id| id.as_i32()),
+                limit,
+            )
+            .await
+            .map_err(convert_storage_error)?;
+        Ok(tasks.into_iter().map(convert_storage_task_to_unified).collect())
+    }
+}
+
 #[async_trait]
 impl TaskRepository for DirectTaskRepository {
     async fn find_enabled(&self) -> Result<Vec<UnifiedTask>, DatabaseError> {
@@ -537,6 +671,14 @@ impl TaskRepository for DirectTaskRepository {
         }
     }
 
+    async fn find_by_ids(&self, ids: Vec<ApiId>) -> Result<Vec<UnifiedTask>, DatabaseError> {
+        let i32_ids: Vec<i32> = ids.iter().filter_map(|id| id.as_i32()).collect();
+        match self.storage_repo.find_by_ids(&i32_ids).await {
+            Ok(tasks) => Ok(tasks.into_iter().map(convert_storage_task_to_unified).collect()),
+            Err(e) => Err(convert_storage_error(e)),
+        }
+    }
+
     async fn find_by_name(&self, name: &str) -> Result<Option<UnifiedTask>, DatabaseError> {
         match self.storage_repo.find_by_name(name).await {
             Ok(Some(task)) => Ok(Some(convert_storage_task_to_unified(task))),
@@ -573,11 +715,53 @@ impl TaskRepository for DirectTaskRepository {
 // Placeholder implementations for other repositories (will need to be completed)
 pub struct DirectExecutionRepository {
     storage_repo: Arc<ratchet_storage::seaorm::repositories::ExecutionRepository>,
+    updates: Option<broadcast::Sender<UnifiedExecution>>,
+    attachments: Option<Arc<AttachmentStore>>,
 }
 
 impl DirectExecutionRepository {
     pub fn new(storage_repo: Arc<ratchet_storage::seaorm::repositories::ExecutionRepository>) -> Self {
-        Self { storage_repo }
+        Self {
+            storage_repo,
+            updates: None,
+            attachments: None,
+        }
+    }
+
+    /// Convert a stored execution, listing the files it attached under its UUID
+    async fn load(&self, execution: ratchet_storage::seaorm::entities::executions::Model) -> UnifiedExecution {
+        let mut execution = convert_execution_from_storage(execution);
+        if let Some(store) = &self.attachments {
+            match store.list(&execution.uuid.to_string()).await {
+                Ok(attachments) => execution.attachments = attachments,
+                Err(e) => tracing::warn!("Failed to list attachments for execution {}: {}", execution.uuid, e),
+            }
+        }
+        execution
+    }
+
+    /// Convert a page of stored executions with their attachments
+    async fn load_all(&self, executions: Vec<ratchet_storage::seaorm::entities::executions::Model>) -> Vec<UnifiedExecution> {
+        let mut loaded = Vec::with_capacity(executions.len());
+        for execution in executions {
+            loaded.push(self.load(execution).await);
+        }
+        loaded
+    }
+
+    /// Send the execution's current state to update subscribers, if there are any
+    async fn publish(&self, id: i32) {
+        let Some(updates) = self.updates.as_ref().filter(|updates| updates.receiver_count() > 0) else {
+            return;
+        };
+
+        match self.storage_repo.find_by_id(id).await {
+            Ok(Some(execution)) => {
+                let _ = updates.send(convert_execution_from_storage(execution));
+            }
+            Ok(None) => {}
+            Err(e) => tracing::debug!("Failed to load execution {} for update subscribers: {}", id, e),
+        }
     }
 }
 
@@ -612,6 +796,9 @@ impl CrudRepository<UnifiedExecution> for DirectExecutionRepository {
             duration_ms: entity.duration_ms,
             http_requests: entity.http_requests,
             recording_path: entity.recording_path,
+            labels: labels_to_json(&entity.labels),
+            logs: None,
+            progress: entity.progress,
         };
 
         let created = self
@@ -620,7 +807,11 @@ impl CrudRepository<UnifiedExecution> for DirectExecutionRepository {
             .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
 
-        Ok(convert_execution_from_storage(created))
+        let created = convert_execution_from_storage(created);
+        if let Some(updates) = &self.updates {
+            let _ = updates.send(created.clone());
+        }
+        Ok(created)
     }
 
     async fn find_by_id(&self, id: i32) -> Result<Option<UnifiedExecution>, DatabaseError> {
@@ -630,7 +821,10 @@ impl CrudRepository<UnifiedExecution> for DirectExecutionRepository {
             .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
 
-        Ok(execution.map(convert_execution_from_storage))
+        Ok(match execution {
+            Some(execution) => Some(self.load(execution).await),
+            None => None,
+        })
     }
 
     async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<UnifiedExecution>, DatabaseError> {
@@ -640,7 +834,10 @@ impl CrudRepository<UnifiedExecution> for DirectExecutionRepository {
             .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
 
-        Ok(execution.map(convert_execution_from_storage))
+        Ok(match execution {
+            Some(execution) => Some(self.load(execution).await),
+            None => None,
+        })
     }
 
     async fn update(&self, entity: UnifiedExecution) -> Result<UnifiedExecution, DatabaseError> {
@@ -660,6 +857,9 @@ impl CrudRepository<UnifiedExecution> for DirectExecutionRepository {
             duration_ms: entity.duration_ms,
             http_requests: entity.http_requests,
             recording_path: entity.recording_path,
+            labels: labels_to_json(&entity.labels),
+            logs: None,
+            progress: entity.progress,
         };
 
         let updated = self
@@ -708,8 +908,7 @@ impl FilteredRepository<UnifiedExecution, ExecutionFilters> for DirectExecutionR
             .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
 
-        let unified_executions: Vec<UnifiedExecution> =
-            executions.into_iter().map(convert_execution_from_storage).collect();
+        let unified_executions = self.load_all(executions).await;
 
         let meta = ratchet_api_types::pagination::PaginationMeta {
             page: pagination.page.unwrap_or(1),
@@ -744,6 +943,27 @@ impl FilteredRepository<UnifiedExecution, ExecutionFilters> for DirectExecutionR
     }
 }
 
+#[async_trait]
+impl KeysetRepository<UnifiedExecution, ExecutionFilters> for DirectExecutionRepository {
+    async fn find_after(
+        &self,
+        filters: ExecutionFilters,
+        after: Option<ApiId>,
+        limit: u64,
+    ) -> Result<Vec<UnifiedExecution>, DatabaseError> {
+        let executions = self
+            .storage_repo
+            .find_after(
+                convert_interface_execution_filters_to_storage(filters),
+                after.and_then(|id| id.as_i32()),
+                limit,
+            )
+            .await
+            .map_err(convert_storage_error)?;
+        Ok(self.load_all(executions).await)
+    }
+}
+
 #[async_trait]
 impl ExecutionRepository for DirectExecutionRepository {
     async fn find_by_task_id(&self, task_id: ApiId) -> Result<Vec<UnifiedExecution>, DatabaseError> {
@@ -754,7 +974,7 @@ impl ExecutionRepository for DirectExecutionRepository {
             .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
 
-        Ok(executions.into_iter().map(convert_execution_from_storage).collect())
+        Ok(self.load_all(executions).await)
     }
 
     async fn find_by_status(
@@ -778,7 +998,9 @@ impl ExecutionRepository for DirectExecutionRepository {
         self.storage_repo
             .update_status(storage_id, storage_status)
             .await
-            .map_err(|e| DatabaseError::Internal { message: e.to_string() })
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.publish(storage_id).await;
+        Ok(())
     }
 
     async fn mark_started(&self, id: ApiId) -> Result<(), DatabaseError> {
@@ -786,7 +1008,9 @@ impl ExecutionRepository for DirectExecutionRepository {
         self.storage_repo
             .mark_started(storage_id)
             .await
-            .map_err(|e| DatabaseError::Internal { message: e.to_string() })
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.publish(storage_id).await;
+        Ok(())
     }
 
     async fn mark_completed(
@@ -799,7 +1023,9 @@ impl ExecutionRepository for DirectExecutionRepository {
         self.storage_repo
             .mark_completed(storage_id, output, duration_ms)
             .await
-            .map_err(|e| DatabaseError::Internal { message: e.to_string() })
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.publish(storage_id).await;
+        Ok(())
     }
 
     async fn mark_failed(
@@ -812,7 +1038,9 @@ impl ExecutionRepository for DirectExecutionRepository {
         self.storage_repo
             .mark_failed(storage_id, error_message, error_details)
             .await
-            .map_err(|e| DatabaseError::Internal { message: e.to_string() })
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.publish(storage_id).await;
+        Ok(())
     }
 
     async fn mark_cancelled(&self, id: ApiId) -> Result<(), DatabaseError> {
@@ -821,7 +1049,9 @@ impl ExecutionRepository for DirectExecutionRepository {
         self.storage_repo
             .update_status(storage_id, storage_status)
             .await
-            .map_err(|e| DatabaseError::Internal { message: e.to_string() })
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.publish(storage_id).await;
+        Ok(())
     }
 
     async fn update_progress(&self, id: ApiId, progress: f32) -> Result<(), DatabaseError> {
@@ -829,8 +1059,65 @@ impl ExecutionRepository for DirectExecutionRepository {
         self.storage_repo
             .update_progress(storage_id, progress)
             .await
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.publish(storage_id).await;
+        Ok(())
+    }
+
+    async fn store_logs(&self, id: ApiId, logs: Vec<LogEvent>) -> Result<(), DatabaseError> {
+        let storage_id = id.as_i32().unwrap_or(0);
+        let logs = serde_json::to_value(logs).map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+        self.storage_repo
+            .store_logs(storage_id, logs)
+            .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })
     }
+
+    async fn find_logs(&self, id: ApiId) -> Result<Vec<LogEvent>, DatabaseError> {
+        let storage_id = id.as_i32().unwrap_or(0);
+        let execution = self
+            .storage_repo
+            .find_by_id(storage_id)
+            .await
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?
+            .ok_or_else(|| DatabaseError::NotFound {
+                entity: "execution".to_string(),
+                id: id.to_string(),
+            })?;
+
+        match execution.logs {
+            Some(logs) => serde_json::from_value(logs).map_err(|e| DatabaseError::Internal { message: e.to_string() }),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn history(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        interval_seconds: i64,
+    ) -> Result<Vec<ExecutionHistoryBucket>, DatabaseError> {
+        let buckets = self
+            .storage_repo
+            .history(from, to, interval_seconds)
+            .await
+            .map_err(|e| DatabaseError::Internal { message: e.to_string() })?;
+
+        Ok(buckets
+            .into_iter()
+            .map(|bucket| ExecutionHistoryBucket {
+                start: bucket.start,
+                pending: bucket.pending,
+                running: bucket.running,
+                completed: bucket.completed,
+                failed: bucket.failed,
+                cancelled: bucket.cancelled,
+                duration_p50_ms: bucket.duration_p50_ms,
+                duration_p90_ms: bucket.duration_p90_ms,
+                duration_p99_ms: bucket.duration_p99_ms,
+            })
+            .collect())
+    }
 }
 
 pub struct DirectJobRepository {
@@ -956,6 +1243,27 @@ impl FilteredRepository<UnifiedJob, JobFilters> for DirectJobRepository {
     }
 }
 
+#[async_trait]
+impl KeysetRepository<UnifiedJob, JobFilters> for DirectJobRepository {
+    async fn find_after(
+        &self,
+        filters: JobFilters,
+        after: Option<ApiId>,
+        limit: u64,
+    ) -> Result<Vec<UnifiedJob>, DatabaseError> {
+        let jobs = self
+            .storage_repo
+            .find_after(
+                convert_interface_job_filters_to_storage(filters),
+                after.and_then(|id| id.as_i32()),
+                limit,
+            )
+            .await
+            .map_err(convert_storage_error)?;
+        Ok(jobs.into_iter().map(convert_storage_job_to_unified).collect())
+    }
+}
+
 #[async_trait]
 impl JobRepository for DirectJobRepository {
     async fn find_ready_for_processing(&self, limit: u64) -> Result<Vec<UnifiedJob>, DatabaseError> {
@@ -977,6 +1285,18 @@ impl JobRepository for DirectJobRepository {
         Ok(jobs.into_iter().map(convert_storage_job_to_unified).collect())
     }
 
+    async fn find_active_by_schedule(&self, schedule_id: ApiId) -> Result<Vec<UnifiedJob>, DatabaseError> {
+        let storage_id = schedule_id.as_i32().ok_or_else(|| DatabaseError::Validation {
+            message: "Invalid schedule ID".to_string(),
+        })?;
+        let jobs = self
+            .storage_repo
+            .find_active_by_schedule(storage_id)
+            .await
+            .map_err(convert_storage_error)?;
+        Ok(jobs.into_iter().map(convert_storage_job_to_unified).collect())
+    }
+
     async fn mark_processing(&self, id: ApiId, execution_id: ApiId) -> Result<(), DatabaseError> {
         let storage_id = id.as_i32().ok_or_else(|| DatabaseError::Validation {
             message: "Invalid job ID".to_string(),
@@ -1015,6 +1335,21 @@ impl JobRepository for DirectJobRepository {
             .map_err(convert_storage_error)
     }
 
+    async fn mark_dead_letter(
+        &self,
+        id: ApiId,
+        error: String,
+        details: Option<serde_json::Value>,
+    ) -> Result<(), DatabaseError> {
+        let storage_id = id.as_i32().ok_or_else(|| DatabaseError::Validation {
+            message: "Invalid job ID".to_string(),
+        })?;
+        self.storage_repo
+            .mark_dead_letter(storage_id, error, details)
+            .await
+            .map_err(convert_storage_error)
+    }
+
     async fn schedule_retry(&self, id: ApiId, retry_at: chrono::DateTime<chrono::Utc>) -> Result<(), DatabaseError> {
         let storage_id = id.as_i32().ok_or_else(|| DatabaseError::Validation {
             message: "Invalid job ID".to_string(),
@@ -1034,6 +1369,16 @@ impl JobRepository for DirectJobRepository {
             .await
             .map_err(convert_storage_error)
     }
+
+    async fn requeue_dead_letter(&self, id: ApiId) -> Result<bool, DatabaseError> {
+        let storage_id = id.as_i32().ok_or_else(|| DatabaseError::Validation {
+            message: "Invalid job ID".to_string(),
+        })?;
+        self.storage_repo
+            .requeue_dead_letter(storage_id)
+            .await
+            .map_err(convert_storage_error)
+    }
 }
 
 pub struct DirectScheduleRepository {
@@ -1171,6 +1516,27 @@ impl FilteredRepository<UnifiedSchedule, ScheduleFilters> for DirectScheduleRepo
     }
 }
 
+#[async_trait]
+impl KeysetRepository<UnifiedSchedule, ScheduleFilters> for DirectScheduleRepository {
+    async fn find_after(
+        &self,
+        filters: ScheduleFilters,
+        after: Option<ApiId>,
+        limit: u64,
+    ) -> Result<Vec<UnifiedSchedule>, DatabaseError> {
+        let schedules = self
+            .storage_repo
+            .find_after(
+                convert_interface_schedule_filters_to_storage(filters),
+                after.and_then(|id| id.as_i32()),
+                limit,
+            )
+            .await
+            .map_err(convert_storage_error)?;
+        Ok(schedules.into_iter().map(convert_storage_schedule_to_unified).collect())
+    }
+}
+
 #[async_trait]
 impl ScheduleRepository for DirectScheduleRepository {
     async fn find_enabled(&self) -> Result<Vec<UnifiedSchedule>, DatabaseError> {
@@ -1210,6 +1576,16 @@ impl ScheduleRepository for DirectScheduleRepository {
             .await
             .map_err(|e| DatabaseError::Internal { message: e.to_string() })
     }
+
+    async fn set_enabled_many(&self, ids: Vec<ApiId>, enabled: bool) -> Result<Vec<UnifiedSchedule>, DatabaseError> {
+        let ids: Vec<i32> = ids.iter().map(|id| id.as_i32().unwrap_or(0)).collect();
+        let schedules = self
+            .storage_repo
+            .set_enabled_many(&ids, enabled)
+            .await
+            .map_err(convert_storage_error)?;
+        Ok(schedules.into_iter().map(convert_storage_schedule_to_unified).collect())
+    }
 }
 
 // Conversion functions (simplified - reuse from bridges for now)
@@ -1264,9 +1640,15 @@ fn convert_unified_schedule_to_storage(schedule: UnifiedSchedule) -> ratchet_sto
         execution_count: 0,   // Default to 0
         max_executions: None, // No limit by default
         metadata: Some(serde_json::json!({
-            "description": schedule.description
+            "description": schedule.description,
+            "serial": schedule.serial,
+            "misfire_policy": schedule.misfire_policy,
+            "max_concurrent": schedule.max_concurrent,
+            "jitter_seconds": schedule.jitter_seconds,
+            "paused": schedule.paused
         })),
         output_destinations: output_destinations_json,
+        timezone: schedule.timezone,
         created_at: schedule.created_at,
         updated_at: schedule.updated_at,
     }
@@ -1298,6 +1680,37 @@ fn convert_storage_schedule_to_unified(schedule: ratchet_storage::seaorm::entiti
         created_at: schedule.created_at,
         updated_at: schedule.updated_at,
         output_destinations,
+        serial: schedule
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get("serial"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        timezone: schedule.timezone,
+        misfire_policy: schedule
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get("misfire_policy"))
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default(),
+        max_concurrent: schedule
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get("max_concurrent"))
+            .and_then(|v| v.as_u64())
+            .map(|max| max as u32),
+        jitter_seconds: schedule
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get("jitter_seconds"))
+            .and_then(|v| v.as_u64())
+            .map(|jitter| jitter as u32),
+        paused: schedule
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get("paused"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
     }
 }
 
@@ -1343,8 +1756,8 @@ fn convert_unified_job_to_storage(job: UnifiedJob) -> ratchet_storage::seaorm::e
         id: job.id.as_i32().unwrap_or(0),
         uuid: job.id.as_uuid().unwrap_or_else(uuid::Uuid::new_v4),
         task_id: job.task_id.as_i32().unwrap_or(0),
-        execution_id: None, // Not set until execution starts
-        schedule_id: None,  // Would need to be provided if job is from a schedule
+        execution_id: job.execution_id.and_then(|id| id.as_i32()),
+        schedule_id: job.schedule_id.and_then(|id| id.as_i32()),
         priority: convert_api_job_priority_to_storage(job.priority),
         status: convert_api_job_status_to_storage(job.status),
         input_data: serde_json::Value::Null, // Default empty input
@@ -1357,7 +1770,7 @@ fn convert_unified_job_to_storage(job: UnifiedJob) -> ratchet_storage::seaorm::e
         process_at: job.scheduled_for,
         started_at: None,
         completed_at: None,
-        metadata: None,
+        metadata: job.log_level.map(|level| serde_json::json!({ "log_level": level })),
         output_destinations: job
             .output_destinations
             .map(|destinations| serde_json::to_value(destinations).unwrap_or(serde_json::Value::Null)),
@@ -1376,6 +1789,14 @@ fn convert_storage_job_to_unified(job: ratchet_storage::seaorm::entities::Job) -
         scheduled_for: job.process_at,
         error_message: job.error_message,
         output_destinations: job.output_destinations.and_then(|v| serde_json::from_value(v).ok()),
+        schedule_id: job.schedule_id.map(ApiId::from_i32),
+        execution_id: job.execution_id.map(ApiId::from_i32),
+        log_level: job
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get("log_level"))
+            .and_then(|level| level.as_str())
+            .map(str::to_string),
     }
 }
 
@@ -1411,6 +1832,7 @@ fn convert_api_job_status_to_storage(
         ratchet_api_types::JobStatus::Failed => ratchet_storage::seaorm::entities::jobs::JobStatus::Failed,
         ratchet_api_types::JobStatus::Cancelled => ratchet_storage::seaorm::entities::jobs::JobStatus::Cancelled,
         ratchet_api_types::JobStatus::Retrying => ratchet_storage::seaorm::entities::jobs::JobStatus::Retrying,
+        ratchet_api_types::JobStatus::DeadLetter => ratchet_storage::seaorm::entities::jobs::JobStatus::DeadLetter,
     }
 }
 
@@ -1424,6 +1846,7 @@ fn convert_storage_job_status_to_api(
         ratchet_storage::seaorm::entities::jobs::JobStatus::Failed => ratchet_api_types::JobStatus::Failed,
         ratchet_storage::seaorm::entities::jobs::JobStatus::Cancelled => ratchet_api_types::JobStatus::Cancelled,
         ratchet_storage::seaorm::entities::jobs::JobStatus::Retrying => ratchet_api_types::JobStatus::Retrying,
+        ratchet_storage::seaorm::entities::jobs::JobStatus::DeadLetter => ratchet_api_types::JobStatus::DeadLetter,
     }
 }
 
@@ -1463,6 +1886,7 @@ fn convert_interface_filters_to_storage(
         enabled: filters.enabled,
         has_validation: filters.validated_after.map(|_| true), // Convert validated_after to has_validation
         version: None,                                         // Not supported in current interface
+        search: filters.search,
     }
 }
 
@@ -1485,7 +1909,45 @@ fn convert_interface_job_filters_to_storage(
         status: filters.status.map(convert_api_job_status_to_storage),
         priority: filters.priority.map(convert_api_job_priority_to_storage),
         queued_after: filters.queued_after,
+        queued_before: filters.queued_before,
         scheduled_after: filters.scheduled_after,
+        scheduled_before: filters.scheduled_before,
+        completed_after: filters.completed_after,
+        completed_before: filters.completed_before,
+    }
+}
+
+fn convert_interface_schedule_filters_to_storage(
+    filters: ScheduleFilters,
+) -> ratchet_storage::seaorm::repositories::schedule_repository::ScheduleFilters {
+    let ids = |ids: Option<Vec<ApiId>>| ids.map(|ids| ids.iter().filter_map(ApiId::as_i32).collect());
+    // Only the positive forms of the due filters narrow the query
+    let overdue = filters.overdue == Some(true);
+    let due_at = (filters.is_due == Some(true) || overdue).then(chrono::Utc::now);
+
+    ratchet_storage::seaorm::repositories::schedule_repository::ScheduleFilters {
+        task_id: filters.task_id.and_then(|id| id.as_i32()),
+        task_id_in: ids(filters.task_id_in),
+        id_in: ids(filters.id_in),
+        enabled: if overdue { Some(true) } else { filters.enabled },
+        name: filters.name_exact,
+        name_contains: filters.name_contains,
+        name_starts_with: filters.name_starts_with,
+        name_ends_with: filters.name_ends_with,
+        cron_expression: filters.cron_expression_exact,
+        cron_expression_contains: filters.cron_expression_contains,
+        next_run_after: filters.next_run_after,
+        next_run_before: filters.next_run_before,
+        last_run_after: filters.last_run_after,
+        last_run_before: filters.last_run_before,
+        created_after: filters.created_after,
+        created_before: filters.created_before,
+        updated_after: filters.updated_after,
+        updated_before: filters.updated_before,
+        has_next_run: filters.has_next_run,
+        has_last_run: filters.has_last_run,
+        due_at,
+        paused: filters.paused,
     }
 }
 
@@ -1507,7 +1969,12 @@ fn convert_interface_execution_filters_to_storage(
         task_id: filters.task_id.and_then(|id| id.as_i32()),
         status: filters.status.map(convert_execution_status_to_storage),
         queued_after: filters.queued_after,
+        queued_before: filters.queued_before,
+        started_after: filters.started_after,
+        started_before: filters.started_before,
         completed_after: filters.completed_after,
+        completed_before: filters.completed_before,
+        labels: filters.labels.unwrap_or_default(),
     }
 }
 
@@ -1523,11 +1990,18 @@ fn convert_interface_execution_pagination_to_storage(
 }
 
 // Execution conversion functions
+fn labels_to_json(labels: &std::collections::HashMap<String, String>) -> Option<serde_json::Value> {
+    if labels.is_empty() {
+        None
+    } else {
+        serde_json::to_value(labels).ok()
+    }
+}
+
 fn convert_execution_from_storage(execution: ratchet_storage::seaorm::entities::executions::Model) -> UnifiedExecution {
     let (can_retry, can_cancel) = ratchet_api_types::conversions::compute_execution_capabilities(
         convert_storage_execution_status_to_api(execution.status),
     );
-
     UnifiedExecution {
         id: ApiId::from_i32(execution.id),
         uuid: execution.uuid,
@@ -1543,9 +2017,15 @@ fn convert_execution_from_storage(execution: ratchet_storage::seaorm::entities::
         duration_ms: execution.duration_ms,
         http_requests: execution.http_requests,
         recording_path: execution.recording_path,
+        attachments: Vec::new(),
+        labels: execution
+            .labels
+            .as_ref()
+            .map(ratchet_storage::seaorm::repositories::execution_repository::labels_from_json)
+            .unwrap_or_default(),
         can_retry,
         can_cancel,
-        progress: None, // Progress would be extracted from output JSON if available
+        progress: execution.progress,
     }
 }
 
@@ -1595,12 +2075,19 @@ fn convert_storage_execution_status_to_api(
 
 /// Create repository factory from configuration
 async fn create_repository_factory(config: &ServerConfig) -> Result<Arc<dyn RepositoryFactory>> {
-    let (repos, _, _) = create_repository_factory_with_mcp(config).await?;
+    let attachment_store = Arc::new(
+        AttachmentStore::new(&config.execution.attachments.directory)
+            .with_max_size(config.execution.attachments.max_size_bytes),
+    );
+    let (repos, _, _) =
+        create_repository_factory_with_mcp(config, EventBroadcaster::new().execution_sender(), attachment_store).await?;
     Ok(repos)
 }
 
 async fn create_repository_factory_with_mcp(
     config: &ServerConfig,
+    execution_updates: broadcast::Sender<UnifiedExecution>,
+    attachment_store: Arc<AttachmentStore>,
 ) -> Result<(
     Arc<dyn RepositoryFactory>,
     Option<Arc<TaskDevelopmentService>>,
@@ -1611,6 +2098,7 @@ async fn create_repository_factory_with_mcp(
         url: config.database.url.clone(),
         max_connections: config.database.max_connections,
         connection_timeout: std::time::Duration::from_secs(config.database.connection_timeout_seconds),
+        busy_retry: config.database.busy_retry.clone(),
     };
 
     let db_connection = ratchet_storage::seaorm::connection::DatabaseConnection::new(storage_config).await?;
@@ -1619,12 +2107,14 @@ async fn create_repository_factory_with_mcp(
     ));
 
     // Create the DirectRepositoryFactory
-    let direct_factory = DirectRepositoryFactory::new(storage_factory.clone());
+    let direct_factory = DirectRepositoryFactory::new(storage_factory.clone())
+        .with_execution_updates(execution_updates)
+        .with_attachment_store(attachment_store);
 
     // Create MCP task development service if MCP is enabled
     let mcp_task_service = if config.mcp_api.enabled {
         // Create HTTP manager for task development service
-        let http_manager = HttpManager::new();
+        let http_manager = HttpManager::with_config(config.http.clone().into());
 
         // Get the database connection from storage factory
         let storage_db = storage_factory.database();
@@ -1660,14 +2150,21 @@ async fn create_repository_factory_with_mcp(
 async fn create_task_registry(
     config: &ServerConfig,
     repositories: Arc<dyn RepositoryFactory>,
+    storage: Arc<ratchet_storage::seaorm::repositories::RepositoryFactory>,
 ) -> Result<Arc<dyn TaskRegistry>> {
     // Create functional task registry using ratchet-registry
-    let mut bridge_registry = BridgeTaskRegistry::new(config).await?;
+    let mut bridge_registry = BridgeTaskRegistry::with_storage(config, storage).await?;
     bridge_registry.set_repositories(repositories);
 
     // Sync discovered tasks to database
     bridge_registry.sync_tasks_to_database().await?;
 
+    if !config.registry.watched_paths.is_empty() {
+        if let Err(e) = bridge_registry.start_hot_reload().await {
+            tracing::warn!("Task hot-reload is unavailable: {}", e);
+        }
+    }
+
     Ok(Arc::new(bridge_registry))
 }
 
@@ -1684,6 +2181,56 @@ async fn create_task_validator(_config: &ServerConfig) -> Result<Arc<dyn TaskVal
     Ok(Arc::new(BridgeTaskValidator::new()))
 }
 
+/// Create the worker pool that executes tasks and start its workers
+///
+/// Worker crashes feed a crash-loop detector; a worker that crashes too often
+/// within the configured window raises a crash-loop alert.
+async fn create_task_executor(
+    config: &ServerConfig,
+    alert_dispatcher: Arc<AlertDispatcher>,
+    hooks: Arc<ratchet_plugin::HookRegistry>,
+    plugin_system_config: ratchet_config::RatchetConfig,
+) -> Arc<ExecutionBridge> {
+    let task_executor = Arc::new(
+        ExecutionConfigAdapter::from_execution_config(&config.execution).with_hooks(hooks, plugin_system_config),
+    );
+    task_executor.inner().set_http_config(config.http.clone().into()).await;
+    task_executor.inner().set_logger(Arc::new(TracingStructuredLogger)).await;
+    let limits = &config.execution.limits;
+    task_executor
+        .inner()
+        .set_limits(ratchet_js::ResourceLimits {
+            max_heap_bytes: limits.max_heap_bytes,
+            max_loop_iterations: limits.max_loop_iterations,
+            max_call_depth: limits.max_call_depth,
+        })
+        .await;
+
+    let detector = std::sync::Mutex::new(CrashLoopDetector::from_config(&config.alerting.crash_loop));
+    task_executor
+        .inner()
+        .set_crash_listener(Arc::new(move |worker_id: &str| {
+            let event = detector
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record_crash(worker_id);
+            if let (Some(event), Ok(runtime)) = (event, tokio::runtime::Handle::try_current()) {
+                let alert_dispatcher = alert_dispatcher.clone();
+                runtime.spawn(async move {
+                    alert_dispatcher.raise(event).await;
+                });
+            }
+        }))
+        .await;
+
+    if let Err(e) = task_executor.start().await {
+        tracing::warn!("Failed to start worker processes: {}", e);
+        tracing::info!("Task execution will not be available until workers are started");
+    }
+
+    task_executor
+}
+
 /// Initialize logging system
 pub async fn init_logging(config: &ServerConfig) -> Result<()> {
     use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -5,6 +5,7 @@ use axum::{response::Html, routing::get, Router};
 // use tower_http::{
 //     trace::TraceLayer,
 // };
+use ratchet_resilience::ShutdownCoordinator;
 use std::fs;
 use std::sync::Arc;
 
@@ -12,7 +13,7 @@ use std::sync::Arc;
 
 use ratchet_graphql_api::{
     context::{GraphQLConfig, GraphQLContext},
-    schema::{configure_schema, create_schema, graphql_handler, graphql_playground},
+    schema::{configure_schema, create_schema, graphql_handler, graphql_playground, graphql_subscription_service},
 };
 use ratchet_rest_api::app::{create_rest_app, AppConfig as RestAppConfig, AppContext as RestAppContext};
 // use ratchet_web::middleware::{cors_layer, request_id_layer, error_handler_layer};
@@ -42,7 +43,11 @@ impl Server {
     pub async fn build_app(&self) -> Router<()> {
         // Create REST API context
         let rest_context = RestAppContext {
-            tasks: self.services.rest_context(),
+            tasks: self
+                .services
+                .rest_context()
+                .with_feature_flags(self.config.features.as_map())
+                .with_max_output_destinations(self.config.output.max_destinations_per_job),
             executions: ratchet_rest_api::context::ExecutionsContext::new(self.services.repositories.clone()),
             jobs: ratchet_rest_api::context::JobsContext::new(self.services.repositories.clone()),
             schedules: ratchet_rest_api::context::SchedulesContext::new(self.services.repositories.clone()),
@@ -56,12 +61,22 @@ impl Server {
             enable_tracing: self.config.server.enable_tracing,
             enable_security_headers: true,
             enable_audit_logging: true,
-            enable_rate_limiting: true,
+            enable_rate_limiting: self.config.server.rate_limit.enabled,
             enable_session_management: true,
+            enable_etags: true,
+            max_body_bytes: self.config.server.max_request_body_bytes,
+            enable_compression: self.config.server.compression.enabled,
+            compression_config: rest_compression_config(&self.config.server.compression),
+            enable_idempotency: true,
+            idempotency_config: ratchet_web::middleware::IdempotencyConfig {
+                max_body_bytes: self.config.server.max_request_body_bytes,
+                ..Default::default()
+            },
             security_config: ratchet_web::middleware::SecurityConfig::development(),
             audit_config: ratchet_web::middleware::AuditConfig::development(),
-            rate_limit_config: ratchet_web::middleware::RateLimitConfig::permissive(),
+            rate_limit_config: rest_rate_limit_config(&self.config.server.rate_limit),
             session_config: ratchet_web::middleware::SessionConfig::development(),
+            auth_config: Some(rest_auth_config(self.config.server.auth.as_ref())),
         };
 
         // Always create the REST app (even if disabled, we use its context)
@@ -82,12 +97,14 @@ impl Server {
             tracing::info!("GraphQL API enabled, creating schema and routes");
 
             // Create GraphQL context
-            let graphql_context = GraphQLContext::new(
+            let graphql_context = GraphQLContext::with_event_broadcaster(
                 self.services.repositories.clone(),
                 self.services.registry.clone(),
                 self.services.registry_manager.clone(),
                 self.services.validator.clone(),
-            );
+                self.services.event_broadcaster.clone(),
+            )
+            .with_max_output_destinations(self.config.output.max_destinations_per_job);
 
             // Create GraphQL configuration
             let graphql_config = GraphQLConfig {
@@ -99,8 +116,13 @@ impl Server {
                 enable_apollo_tracing: self.config.graphql_api.enable_apollo_tracing,
             };
 
-            // Create and configure the GraphQL schema
-            let schema = configure_schema(create_schema(), &graphql_config);
+            // Create and configure the GraphQL schema; subscriptions take the context from schema data
+            let schema = configure_schema(
+                create_schema()
+                    .data(graphql_context.task_loader.clone())
+                    .data(graphql_context.clone()),
+                &graphql_config,
+            );
 
             // Create a separate router for GraphQL with the required extensions
             let graphql_router = Router::new()
@@ -108,6 +130,10 @@ impl Server {
                     &self.config.graphql_api.endpoint,
                     axum::routing::get(graphql_handler).post(graphql_handler),
                 )
+                .route_service(
+                    &format!("{}/ws", self.config.graphql_api.endpoint),
+                    graphql_subscription_service(schema.clone()),
+                )
                 .layer(axum::extract::Extension(graphql_context.clone()))
                 .layer(axum::extract::Extension(schema));
 
@@ -137,6 +163,7 @@ impl Server {
                     self.services.mcp_task_service.clone(),
                     self.services.storage_factory.clone(),
                     Some(self.services.task_service.clone()),
+                    self.services.task_executor.clone(),
                 ).await {
                     Ok(state) => state,
                     Err(e) => {
@@ -248,6 +275,7 @@ impl Server {
             has_last_run: None,
             is_due: None,
             overdue: None,
+            paused: None,
         };
         let pagination = PaginationInput {
             page: Some(1),
@@ -283,6 +311,12 @@ impl Server {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             output_destinations: None,
+            serial: false,
+            timezone: None,
+            misfire_policy: Default::default(),
+            max_concurrent: None,
+            jitter_seconds: None,
+            paused: false,
         };
 
         // Create the schedule in the repository
@@ -321,19 +355,30 @@ impl Server {
             // Don't fail server startup for this
         }
 
+        // Load dynamic plugins; a plugin that fails to load is reported, not fatal
+        if let Some(directory) = &self.config.plugins.directory {
+            let loaded = self.services.plugin_host.load_directory(std::path::Path::new(directory)).await;
+            tracing::info!("Loaded {} plugin(s) from {}", loaded, directory);
+        }
+
         // Initialize default schedules from registry BEFORE starting scheduler
         if let Err(e) = self.initialize_default_schedules().await {
             tracing::warn!("Failed to initialize default schedules: {}", e);
             // Don't fail server startup for this
         }
 
-        // Create shutdown channel to coordinate background services
-        let (shutdown_tx, _) = tokio::sync::broadcast::channel::<()>(1);
-        
+        // Background services count as active tasks, so shutdown waits for them to stop
+        let shutdown = Arc::new(ShutdownCoordinator::with_timeouts(
+            std::time::Duration::from_secs(self.config.server.shutdown_timeout_seconds),
+            std::time::Duration::from_secs(10),
+        ));
+
         // Start scheduler service as background task AFTER schedules are initialized
         if let Some(scheduler_service) = &self.services.scheduler_service {
             let scheduler_clone = scheduler_service.clone();
-            let mut shutdown_rx = shutdown_tx.subscribe();
+            let mut shutdown_rx = shutdown.subscribe();
+            let coordinator = shutdown.clone();
+            coordinator.task_started().await;
             tokio::spawn(async move {
                 tokio::select! {
                     result = scheduler_clone.start() => {
@@ -348,6 +393,7 @@ impl Server {
                         }
                     }
                 }
+                coordinator.task_completed().await;
             });
             tracing::info!("Started background scheduler service");
         }
@@ -355,7 +401,9 @@ impl Server {
         // Start job processor service as background task
         if let Some(job_processor_service) = &self.services.job_processor_service {
             let job_processor_clone = job_processor_service.clone();
-            let mut shutdown_rx = shutdown_tx.subscribe();
+            let mut shutdown_rx = shutdown.subscribe();
+            let coordinator = shutdown.clone();
+            coordinator.task_started().await;
             tokio::spawn(async move {
                 tokio::select! {
                     result = job_processor_clone.start() => {
@@ -368,20 +416,50 @@ impl Server {
                         job_processor_clone.stop().await;
                     }
                 }
+                coordinator.task_completed().await;
             });
             tracing::info!("Started background job processor service");
         }
 
+        // Apply the audit log retention policy periodically
+        if let Some(audit_logger) = &self.services.audit_logger {
+            let audit_logger = audit_logger.clone();
+            let retention_interval = self.config.audit.retention_interval;
+            let mut shutdown_rx = shutdown.subscribe();
+            let coordinator = shutdown.clone();
+            coordinator.task_started().await;
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(retention_interval);
+                loop {
+                    tokio::select! {
+                        _ = interval.tick() => {
+                            if let Err(e) = audit_logger.cleanup().await {
+                                tracing::error!("Failed to apply audit log retention: {}", e);
+                            }
+                        }
+                        _ = shutdown_rx.recv() => break,
+                    }
+                }
+                coordinator.task_completed().await;
+            });
+            tracing::info!("Started audit log retention every {:?}", retention_interval);
+        }
+
         // Print configuration summary
         self.log_config_summary();
 
         // Start the server with TLS if configured
         if let Some(tls_config) = &self.config.server.tls {
             tracing::info!("Starting HTTPS server with TLS on {}", addr);
-            self.start_tls_server(app, addr, tls_config, shutdown_tx).await?;
+            self.start_tls_server(app, addr, tls_config, shutdown).await?;
         } else {
             tracing::info!("Starting HTTP server on {}", addr);
-            self.start_http_server(app, addr, shutdown_tx).await?;
+            self.start_http_server(app, addr, shutdown).await?;
+        }
+
+        // Let plugins shut down while their libraries are still loaded
+        if let Err(e) = self.services.plugin_host.manager().shutdown().await {
+            tracing::warn!("Failed to shut down plugins: {}", e);
         }
 
         tracing::info!("Server shutdown complete");
@@ -389,10 +467,16 @@ impl Server {
     }
 
     /// Start HTTP server
-    async fn start_http_server(&self, app: Router<()>, addr: std::net::SocketAddr, shutdown_tx: tokio::sync::broadcast::Sender<()>) -> Result<()> {
+    async fn start_http_server(
+        &self,
+        app: Router<()>,
+        addr: std::net::SocketAddr,
+        shutdown: Arc<ShutdownCoordinator>,
+    ) -> Result<()> {
         let listener = tokio::net::TcpListener::bind(&addr).await?;
-        axum::serve(listener, app)
-            .with_graceful_shutdown(shutdown_signal_with_services(shutdown_tx))
+        // Connection info lets rate limiting and idempotency keys tell anonymous clients apart
+        axum::serve(listener, app.into_make_service_with_connect_info::<std::net::SocketAddr>())
+            .with_graceful_shutdown(shutdown_signal_with_services(shutdown))
             .await?;
         Ok(())
     }
@@ -403,7 +487,7 @@ impl Server {
         app: Router<()>,
         addr: std::net::SocketAddr,
         tls_config: &crate::config::TlsConfig,
-        shutdown_tx: tokio::sync::broadcast::Sender<()>,
+        shutdown: Arc<ShutdownCoordinator>,
     ) -> Result<()> {
         // Load TLS certificates
         let cert_pem = fs::read(&tls_config.cert_path)
@@ -432,13 +516,13 @@ impl Server {
 
         // Start HTTPS server using axum-server with shutdown coordination
         let server_future = axum_server::bind_rustls(addr, axum_tls_config)
-            .serve(app.into_make_service());
+            .serve(app.into_make_service_with_connect_info::<std::net::SocketAddr>());
         
         tokio::select! {
             result = server_future => {
                 result.map_err(|e| anyhow::anyhow!("HTTPS server error: {}", e))?;
             }
-            _ = shutdown_signal_with_services(shutdown_tx) => {
+            _ = shutdown_signal_with_services(shutdown) => {
                 tracing::info!("HTTPS server shutting down due to signal");
             }
         }
@@ -730,6 +814,86 @@ impl Server {
     }
 }
 
+/// REST rate limiting from the server's `rate_limit` settings
+fn rest_rate_limit_config(
+    config: &ratchet_config::domains::server::RateLimitConfig,
+) -> ratchet_web::middleware::RateLimitConfig {
+    use ratchet_config::domains::server::RateLimitKeyBy;
+    use ratchet_web::middleware::{RateLimitKey, RateLimitQuota, UserQuotas};
+
+    // `requests_per_minute` requests are allowed per `time_window`; the
+    // limiter counts per minute, so rescale when the window is not a minute
+    let per_minute = |requests: u32| -> u32 {
+        let window_secs = config.time_window.as_secs_f64().max(1.0);
+        ((requests as f64 * 60.0 / window_secs).round() as u32).max(1)
+    };
+
+    let mut rate_limit = ratchet_web::middleware::RateLimitConfig::default();
+    rate_limit.enabled = config.enabled;
+    rate_limit.quotas = UserQuotas::scaled_from_anonymous(per_minute(config.requests_per_minute), config.burst_size);
+    rate_limit.window_size = config.time_window;
+    rate_limit.key_by = match config.key_by {
+        RateLimitKeyBy::Identity => RateLimitKey::Identity,
+        RateLimitKeyBy::Ip => RateLimitKey::Ip,
+    };
+    rate_limit.client_quotas = config
+        .client_quotas
+        .iter()
+        .map(|(client_id, quota)| {
+            (
+                client_id.clone(),
+                RateLimitQuota {
+                    requests_per_minute: quota.requests_per_minute,
+                    burst_size: quota.burst_size,
+                    daily_limit: quota.daily_limit,
+                },
+            )
+        })
+        .collect();
+    rate_limit
+}
+
+/// REST response compression from the server's `compression` settings
+fn rest_compression_config(
+    config: &ratchet_config::domains::server::CompressionConfig,
+) -> ratchet_web::middleware::CompressionConfig {
+    use ratchet_config::domains::server::CompressionAlgorithm;
+
+    ratchet_web::middleware::CompressionConfig {
+        algorithms: config
+            .algorithms
+            .iter()
+            .map(|algorithm| match algorithm {
+                CompressionAlgorithm::Brotli => ratchet_web::middleware::CompressionAlgorithm::Brotli,
+                CompressionAlgorithm::Gzip => ratchet_web::middleware::CompressionAlgorithm::Gzip,
+            })
+            .collect(),
+        min_size_bytes: config.min_size_bytes,
+    }
+}
+
+/// REST authentication from the server's `auth` settings
+///
+/// Authentication stays optional; it identifies callers for rate limiting and
+/// the admin endpoints. Without an `auth` section only API keys are accepted:
+/// the JWT secret is random, so no token verifies.
+fn rest_auth_config(
+    config: Option<&ratchet_config::domains::server::AuthConfig>,
+) -> ratchet_web::middleware::AuthConfig {
+    let mut auth = ratchet_web::middleware::AuthConfig {
+        jwt_secret: uuid::Uuid::new_v4().to_string(),
+        require_auth: false,
+        ..Default::default()
+    };
+    if let Some(config) = config {
+        auth.jwt_secret = config.jwt_secret.clone();
+        auth.jwt_issuer = config.issuer.clone();
+        auth.jwt_audience = config.audience.clone();
+        auth.token_expiry_hours = (config.token_expiration.as_secs() / 3600).max(1) as i64;
+    }
+    auth
+}
+
 /// Root handler
 async fn root_handler() -> axum::response::Json<serde_json::Value> {
     axum::Json(serde_json::json!({
@@ -812,18 +976,17 @@ async fn shutdown_signal() {
     tracing::info!("Shutdown signal received, starting graceful shutdown...");
 }
 
-async fn shutdown_signal_with_services(shutdown_tx: tokio::sync::broadcast::Sender<()>) {
+async fn shutdown_signal_with_services(shutdown: Arc<ShutdownCoordinator>) {
     // Wait for shutdown signal
     shutdown_signal().await;
-    
-    // Signal background services to stop
+
+    // Signal background services to stop and wait for them, escalating if they take too long.
+    // Signals can't be sent without a receiver, and no background service may be running
+    let _signals = shutdown.subscribe();
     tracing::info!("Signaling background services to shutdown...");
-    if let Err(e) = shutdown_tx.send(()) {
-        tracing::warn!("Failed to send shutdown signal to background services: {}", e);
+    if let Err(e) = shutdown.shutdown().await {
+        tracing::warn!("Background services did not shut down cleanly: {}", e);
     }
-    
-    // Give services a moment to shut down cleanly
-    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
     tracing::info!("Background services shutdown coordination complete");
 }
 
@@ -214,6 +214,7 @@ impl TaskService for UnifiedTaskService {
                 name_contains: None,
                 name_starts_with: None,
                 name_ends_with: None,
+                search: None,
                 version: None,
                 version_in: None,
                 created_after: None,
@@ -0,0 +1,264 @@
+//! Filesystem storage for execution attachments
+//!
+//! Tasks can attach files (reports, exports, images, ...) to the execution that
+//! produced them. Attachments are stored on disk below a root directory, with one
+//! directory per execution:
+//!
+//! ```text
+//! <root>/<execution_id>/files/<name>
+//! <root>/<execution_id>/meta/<name>.json
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use ratchet_api_types::UnifiedExecutionAttachment;
+use tokio::fs;
+
+use crate::error::{StorageError, StorageResult};
+
+/// Default maximum attachment size (10MB)
+pub const DEFAULT_MAX_ATTACHMENT_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Maximum length of an attachment name
+const MAX_NAME_LENGTH: usize = 255;
+
+/// Stores and retrieves files attached to executions
+#[derive(Debug, Clone)]
+pub struct AttachmentStore {
+    root: PathBuf,
+    max_size_bytes: u64,
+}
+
+impl AttachmentStore {
+    /// Create a store rooted at the given directory
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            max_size_bytes: DEFAULT_MAX_ATTACHMENT_SIZE,
+        }
+    }
+
+    /// Set the maximum size of a single attachment
+    pub fn with_max_size(mut self, max_size_bytes: u64) -> Self {
+        self.max_size_bytes = max_size_bytes;
+        self
+    }
+
+    /// Root directory of the store
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Maximum size of a single attachment in bytes
+    pub fn max_size_bytes(&self) -> u64 {
+        self.max_size_bytes
+    }
+
+    /// Store an attachment, replacing any existing attachment with the same name
+    pub async fn put(
+        &self,
+        execution_id: &str,
+        name: &str,
+        mime_type: &str,
+        data: &[u8],
+    ) -> StorageResult<UnifiedExecutionAttachment> {
+        validate_component(execution_id, "execution id")?;
+        validate_component(name, "attachment name")?;
+
+        if mime_type.trim().is_empty() {
+            return Err(StorageError::ValidationFailed(
+                "attachment mime type cannot be empty".to_string(),
+            ));
+        }
+
+        let size_bytes = data.len() as u64;
+        if size_bytes > self.max_size_bytes {
+            return Err(StorageError::ValidationFailed(format!(
+                "attachment '{}' is {} bytes, exceeding the {} byte limit",
+                name, size_bytes, self.max_size_bytes
+            )));
+        }
+
+        let execution_dir = self.root.join(execution_id);
+        fs::create_dir_all(execution_dir.join("files")).await?;
+        fs::create_dir_all(execution_dir.join("meta")).await?;
+
+        let attachment = UnifiedExecutionAttachment {
+            name: name.to_string(),
+            mime_type: mime_type.to_string(),
+            size_bytes,
+            created_at: Utc::now(),
+        };
+
+        fs::write(self.file_path(execution_id, name), data).await?;
+        fs::write(
+            self.meta_path(execution_id, name),
+            serde_json::to_vec_pretty(&attachment)?,
+        )
+        .await?;
+
+        Ok(attachment)
+    }
+
+    /// Fetch an attachment and its contents
+    pub async fn get(&self, execution_id: &str, name: &str) -> StorageResult<(UnifiedExecutionAttachment, Vec<u8>)> {
+        validate_component(execution_id, "execution id")?;
+        validate_component(name, "attachment name")?;
+
+        let metadata = match fs::read(self.meta_path(execution_id, name)).await {
+            Ok(bytes) => serde_json::from_slice::<UnifiedExecutionAttachment>(&bytes)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(StorageError::NotFound),
+            Err(e) => return Err(e.into()),
+        };
+
+        let data = match fs::read(self.file_path(execution_id, name)).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(StorageError::NotFound),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok((metadata, data))
+    }
+
+    /// List the attachments of an execution, ordered by name
+    pub async fn list(&self, execution_id: &str) -> StorageResult<Vec<UnifiedExecutionAttachment>> {
+        validate_component(execution_id, "execution id")?;
+
+        let mut entries = match fs::read_dir(self.root.join(execution_id).join("meta")).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut attachments = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let bytes = fs::read(entry.path()).await?;
+            attachments.push(serde_json::from_slice::<UnifiedExecutionAttachment>(&bytes)?);
+        }
+
+        attachments.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(attachments)
+    }
+
+    /// Remove all attachments of an execution
+    pub async fn remove_all(&self, execution_id: &str) -> StorageResult<()> {
+        validate_component(execution_id, "execution id")?;
+
+        match fs::remove_dir_all(self.root.join(execution_id)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn file_path(&self, execution_id: &str, name: &str) -> PathBuf {
+        self.root.join(execution_id).join("files").join(name)
+    }
+
+    fn meta_path(&self, execution_id: &str, name: &str) -> PathBuf {
+        self.root.join(execution_id).join("meta").join(format!("{}.json", name))
+    }
+}
+
+/// Ensure a value is safe to use as a single path component on every platform
+fn validate_component(value: &str, what: &str) -> StorageResult<()> {
+    if value.is_empty() || value.len() > MAX_NAME_LENGTH {
+        return Err(StorageError::ValidationFailed(format!(
+            "{} must be between 1 and {} characters",
+            what, MAX_NAME_LENGTH
+        )));
+    }
+
+    if value == "." || value == ".." || value.starts_with('.') {
+        return Err(StorageError::ValidationFailed(format!(
+            "{} '{}' cannot start with '.'",
+            what, value
+        )));
+    }
+
+    let invalid = |c: char| matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') || c.is_control();
+    if value.chars().any(invalid) {
+        return Err(StorageError::ValidationFailed(format!(
+            "{} '{}' contains invalid characters",
+            what, value
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store() -> AttachmentStore {
+        AttachmentStore::new(std::env::temp_dir().join(format!("ratchet-attachments-{}", uuid::Uuid::new_v4())))
+    }
+
+    #[tokio::test]
+    async fn test_attach_and_retrieve() {
+        let store = temp_store();
+        let execution_id = uuid::Uuid::new_v4().to_string();
+
+        let stored = store
+            .put(&execution_id, "report.csv", "text/csv", b"id,value\n1,42\n")
+            .await
+            .unwrap();
+        assert_eq!(stored.size_bytes, 14);
+
+        let (metadata, data) = store.get(&execution_id, "report.csv").await.unwrap();
+        assert_eq!(metadata, stored);
+        assert_eq!(metadata.mime_type, "text/csv");
+        assert_eq!(data, b"id,value\n1,42\n");
+
+        store
+            .put(&execution_id, "chart.png", "image/png", &[0x89, 0x50])
+            .await
+            .unwrap();
+        let names: Vec<_> = store
+            .list(&execution_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|a| a.name)
+            .collect();
+        assert_eq!(names, vec!["chart.png", "report.csv"]);
+
+        assert!(matches!(
+            store.get(&execution_id, "missing.txt").await,
+            Err(StorageError::NotFound)
+        ));
+
+        store.remove_all(&execution_id).await.unwrap();
+        assert!(store.list(&execution_id).await.unwrap().is_empty());
+        let _ = std::fs::remove_dir_all(store.root());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_attachments() {
+        let store = temp_store().with_max_size(4);
+
+        for name in ["", "..", "../escape", "nested/file.txt", "nested\\file.txt", ".hidden"] {
+            assert!(
+                matches!(
+                    store.put("exec", name, "text/plain", b"ok").await,
+                    Err(StorageError::ValidationFailed(_))
+                ),
+                "name {:?} should be rejected",
+                name
+            );
+        }
+
+        assert!(matches!(
+            store
+                .put("exec", "big.bin", "application/octet-stream", b"too big")
+                .await,
+            Err(StorageError::ValidationFailed(_))
+        ));
+        assert!(!store.root().exists());
+    }
+}
@@ -158,6 +158,30 @@ pub struct MigrationConfig {
     pub retry_delay: Duration,
 }
 
+/// Retry configuration for transient SQLite "database is locked" errors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BusyRetryConfig {
+    /// Maximum number of retries after the initial attempt (0 disables retrying)
+    pub max_retries: u32,
+
+    /// Delay before the first retry; subsequent retries back off exponentially
+    pub base_delay: Duration,
+
+    /// Upper bound for a single retry delay
+    pub max_delay: Duration,
+}
+
+impl Default for BusyRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(2),
+        }
+    }
+}
+
 impl Default for StorageConfig {
     fn default() -> Self {
         Self {
@@ -26,11 +26,13 @@
 //! - Builder patterns for creating test entities
 //! - Test fixtures and utilities
 
+pub mod attachments;
 pub mod config;
 pub mod error;
 pub mod filters;
 pub mod migrations;
 pub mod repositories;
+pub mod revalidation;
 
 // SeaORM implementation (feature-gated)
 #[cfg(feature = "seaorm")]
@@ -51,9 +53,11 @@ pub mod migration;
 // Repository adapters removed - unified interface approach abandoned
 
 // Re-export core types for convenience
-pub use config::StorageConfig;
+pub use attachments::AttachmentStore;
+pub use config::{BusyRetryConfig, StorageConfig};
 pub use error::{StorageError, StorageResult};
 pub use filters::SafeFilterBuilder;
+pub use revalidation::{revalidate_tasks, TaskRevalidationFailure, TaskRevalidationReport};
 
 // Legacy repository and entity exports removed - use SeaORM implementation
 // For SeaORM repositories, use: ratchet_storage::seaorm::repositories::
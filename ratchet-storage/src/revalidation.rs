@@ -0,0 +1,269 @@
+//! Bulk re-validation of stored tasks
+//!
+//! After an upgrade or a change to the schema library, tasks that validated when
+//! they were stored may no longer do so. [`revalidate_tasks`] re-checks every
+//! stored task, refreshes `validated_at` for the ones that still pass and
+//! reports the ones that don't.
+
+use futures::stream::{self, StreamExt};
+use ratchet_api_types::{ApiId, PaginationInput, UnifiedTask};
+use ratchet_interfaces::{DatabaseError, TaskFilters, TaskMetadata, TaskRepository, TaskValidator};
+use serde::{Deserialize, Serialize};
+
+/// Default number of tasks validated at the same time
+pub const DEFAULT_REVALIDATION_CONCURRENCY: usize = 8;
+
+/// Number of tasks loaded per page
+const PAGE_SIZE: u32 = 100;
+
+/// Outcome of a bulk re-validation run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRevalidationReport {
+    /// Number of tasks checked
+    pub total: usize,
+    /// Number of tasks that passed and had `validated_at` refreshed
+    pub validated: usize,
+    /// Tasks that failed validation, ordered by name
+    pub failures: Vec<TaskRevalidationFailure>,
+}
+
+impl TaskRevalidationReport {
+    /// Whether every task passed
+    pub fn all_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A task that failed re-validation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRevalidationFailure {
+    pub task_id: ApiId,
+    pub name: String,
+    pub version: String,
+    pub errors: Vec<String>,
+}
+
+/// Re-validate all stored tasks, checking at most `concurrency` tasks at a time
+///
+/// Each task must have non-empty source, compilable input and output schemas,
+/// and pass the `validator`'s metadata and content checks.
+pub async fn revalidate_tasks(
+    tasks: &dyn TaskRepository,
+    validator: &dyn TaskValidator,
+    concurrency: usize,
+) -> Result<TaskRevalidationReport, DatabaseError> {
+    let all_tasks = load_all_tasks(tasks).await?;
+    let total = all_tasks.len();
+
+    let outcomes: Vec<Result<Option<TaskRevalidationFailure>, DatabaseError>> = stream::iter(all_tasks)
+        .map(|task| async move {
+            let errors = check_task(&task, validator).await;
+            if errors.is_empty() {
+                tasks.mark_validated(task.id).await?;
+                Ok(None)
+            } else {
+                Ok(Some(TaskRevalidationFailure {
+                    task_id: task.id,
+                    name: task.name,
+                    version: task.version,
+                    errors,
+                }))
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut report = TaskRevalidationReport {
+        total,
+        ..Default::default()
+    };
+    for outcome in outcomes {
+        match outcome? {
+            Some(failure) => report.failures.push(failure),
+            None => report.validated += 1,
+        }
+    }
+    report.failures.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(report)
+}
+
+async fn load_all_tasks(tasks: &dyn TaskRepository) -> Result<Vec<UnifiedTask>, DatabaseError> {
+    let mut all_tasks = Vec::new();
+    for page in 1.. {
+        let pagination = PaginationInput {
+            page: Some(page),
+            limit: Some(PAGE_SIZE),
+            offset: None,
+        };
+        let response = tasks.find_with_filters(TaskFilters::default(), pagination).await?;
+        let fetched = response.items.len();
+        all_tasks.extend(response.items);
+
+        if fetched < PAGE_SIZE as usize || all_tasks.len() as u64 >= response.meta.total {
+            break;
+        }
+    }
+    Ok(all_tasks)
+}
+
+async fn check_task(task: &UnifiedTask, validator: &dyn TaskValidator) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if task.source_code.trim().is_empty() {
+        errors.push("Task source code is empty".to_string());
+    }
+
+    for (field, schema) in [
+        ("input_schema", &task.input_schema),
+        ("output_schema", &task.output_schema),
+    ] {
+        if let Some(schema) = schema {
+            if let Err(e) = ratchet_core::validation::validate_schema(schema) {
+                errors.push(format!("{}: {}", field, e));
+            }
+        }
+    }
+
+    let metadata = TaskMetadata {
+        name: task.name.clone(),
+        version: task.version.clone(),
+        description: task.description.clone(),
+        input_schema: task.input_schema.clone(),
+        output_schema: task.output_schema.clone(),
+        metadata: task.metadata.clone(),
+    };
+
+    let results = [
+        validator.validate_metadata(&metadata).await,
+        validator.validate_content(&task.source_code, &metadata).await,
+    ];
+    for result in results {
+        match result {
+            Ok(result) => errors.extend(result.errors.into_iter().map(|error| match error.field {
+                Some(field) => format!("{}: {}", field, error.message),
+                None => error.message,
+            })),
+            Err(e) => errors.push(format!("Validator failed: {}", e)),
+        }
+    }
+
+    errors
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::testing::mocks::MockTaskRepo;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use ratchet_api_types::{ListResponse, TaskRepositoryInfo};
+    use ratchet_interfaces::{RegistryError, ValidationResult};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    /// Validator accepting everything, leaving the built-in checks to decide
+    struct AcceptAll;
+
+    #[async_trait]
+    impl TaskValidator for AcceptAll {
+        async fn validate_metadata(&self, _metadata: &TaskMetadata) -> Result<ValidationResult, RegistryError> {
+            Ok(valid())
+        }
+
+        async fn validate_content(
+            &self,
+            _content: &str,
+            _metadata: &TaskMetadata,
+        ) -> Result<ValidationResult, RegistryError> {
+            Ok(valid())
+        }
+
+        async fn validate_input(
+            &self,
+            _input: &serde_json::Value,
+            _metadata: &TaskMetadata,
+        ) -> Result<ValidationResult, RegistryError> {
+            Ok(valid())
+        }
+    }
+
+    fn valid() -> ValidationResult {
+        ValidationResult {
+            valid: true,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn task(id: i32, name: &str, input_schema: serde_json::Value) -> UnifiedTask {
+        UnifiedTask {
+            id: ApiId::from_i32(id),
+            uuid: uuid::Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            version: "1.0.0".to_string(),
+            enabled: true,
+            registry_source: false,
+            available_versions: vec!["1.0.0".to_string()],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            validated_at: None,
+            in_sync: true,
+            source_code: "(function(input) { return input; })".to_string(),
+            source_type: "javascript".to_string(),
+            repository_info: TaskRepositoryInfo {
+                repository_id: ApiId::from_i32(1),
+                repository_name: "default".to_string(),
+                repository_type: "filesystem".to_string(),
+                repository_path: format!("{}.js", name),
+                branch: None,
+                commit: None,
+                can_push: false,
+                auto_push: false,
+            },
+            is_editable: true,
+            sync_status: "synced".to_string(),
+            needs_push: false,
+            last_synced_at: None,
+            input_schema: Some(input_schema),
+            output_schema: Some(json!({"type": "object"})),
+            metadata: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revalidation_reports_broken_task() {
+        let tasks = vec![
+            task(1, "addition", json!({"type": "object"})),
+            task(2, "broken", json!({"type": 12})),
+            task(3, "weather", json!({"type": "object", "required": ["city"]})),
+        ];
+
+        let mut repo = MockTaskRepo::new();
+        repo.expect_find_with_filters()
+            .returning(move |_, pagination| Ok(ListResponse::new(tasks.clone(), &pagination, 3)));
+        let validated = Arc::new(Mutex::new(Vec::new()));
+        let marked = validated.clone();
+        repo.expect_mark_validated().returning(move |id| {
+            marked.lock().unwrap().push(id.as_i32().unwrap());
+            Ok(())
+        });
+
+        let report = revalidate_tasks(&repo, &AcceptAll, 2).await.unwrap();
+
+        assert_eq!(report.total, 3);
+        assert_eq!(report.validated, 2);
+        assert!(!report.all_valid());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].name, "broken");
+        assert!(report.failures[0].errors[0].starts_with("input_schema:"));
+
+        let mut validated = validated.lock().unwrap().clone();
+        validated.sort();
+        assert_eq!(validated, vec![1, 3]);
+    }
+}
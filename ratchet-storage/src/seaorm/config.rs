@@ -1,3 +1,4 @@
+use crate::config::BusyRetryConfig;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -13,6 +14,9 @@ pub struct DatabaseConfig {
 
     /// Connection timeout
     pub connection_timeout: Duration,
+
+    /// Retrying of queries that fail because SQLite is busy or locked
+    pub busy_retry: BusyRetryConfig,
 }
 
 impl Default for DatabaseConfig {
@@ -21,6 +25,7 @@ impl Default for DatabaseConfig {
             url: "sqlite::memory:".to_string(),
             max_connections: 10,
             connection_timeout: Duration::from_secs(30),
+            busy_retry: BusyRetryConfig::default(),
         }
     }
 }
@@ -1,4 +1,5 @@
 use super::config::DatabaseConfig;
+use crate::config::BusyRetryConfig;
 use sea_orm::{ConnectOptions, Database, DatabaseConnection as SeaConnection, DbErr};
 use std::time::Duration;
 use thiserror::Error;
@@ -77,7 +78,10 @@ impl DatabaseConnection {
             config.max_connections
         );
 
-        let db = Self { connection, config };
+        let db = Self {
+            connection,
+            config,
+        };
 
         // Automatically run migrations when establishing connection
         info!("Running database migrations automatically");
@@ -161,6 +165,11 @@ impl DatabaseConnection {
         &self.config
     }
 
+    /// Get the busy retry configuration
+    pub fn get_busy_retry(&self) -> &BusyRetryConfig {
+        &self.config.busy_retry
+    }
+
     /// Run database migrations
     pub async fn migrate(&self) -> Result<(), DatabaseError> {
         use sea_orm_migration::MigratorTrait;
@@ -228,6 +237,7 @@ mod tests {
             url: "sqlite::memory:".to_string(),
             max_connections: 5,
             connection_timeout: Duration::from_secs(10),
+            ..Default::default()
         }
     }
 
@@ -266,6 +276,7 @@ mod tests {
             url: "sqlite::memory:".to_string(),
             max_connections: 5,
             connection_timeout: Duration::from_secs(10),
+            ..Default::default()
         };
 
         let db = DatabaseConnection::new(config).await;
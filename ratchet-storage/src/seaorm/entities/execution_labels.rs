@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Execution label entity, one row per key/value label on an execution
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "execution_labels")]
+pub struct Model {
+    /// Primary key
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    /// Foreign key to executions table
+    pub execution_id: i32,
+
+    /// Label key
+    pub key: String,
+
+    /// Label value
+    pub value: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::executions::Entity",
+        from = "Column::ExecutionId",
+        to = "super::executions::Column::Id"
+    )]
+    Execution,
+}
+
+impl Related<super::executions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Execution.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
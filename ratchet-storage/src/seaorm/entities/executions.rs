@@ -65,6 +65,15 @@ pub struct Model {
 
     /// Recording directory path if recording was enabled
     pub recording_path: Option<String>,
+
+    /// Key/value labels as a JSON object (null if unlabelled)
+    pub labels: Option<Json>,
+
+    /// Log events captured while the task ran, as a JSON array (null if none were stored)
+    pub logs: Option<Json>,
+
+    /// Progress reported while the task runs, from 0.0 to 1.0 (null if none was reported)
+    pub progress: Option<f32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -103,6 +112,9 @@ impl Model {
             duration_ms: None,
             http_requests: None,
             recording_path: None,
+            labels: None,
+            logs: None,
+            progress: None,
         }
     }
 
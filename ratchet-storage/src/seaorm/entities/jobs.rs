@@ -36,6 +36,8 @@ pub enum JobStatus {
     Cancelled,
     #[sea_orm(string_value = "retrying")]
     Retrying,
+    #[sea_orm(string_value = "dead_letter")]
+    DeadLetter,
 }
 
 /// Job entity representing a queued task execution job
@@ -194,6 +196,7 @@ impl FromStr for JobStatus {
             "failed" => Ok(JobStatus::Failed),
             "cancelled" => Ok(JobStatus::Cancelled),
             "retrying" => Ok(JobStatus::Retrying),
+            "dead_letter" => Ok(JobStatus::DeadLetter),
             _ => Err(format!("Invalid status: {}", s)),
         }
     }
@@ -264,8 +267,8 @@ impl Model {
             self.process_at = Some(chrono::Utc::now() + chrono::Duration::seconds(delay_seconds as i64));
             true // Will retry
         } else {
-            // No more retries
-            self.status = JobStatus::Failed;
+            // No more retries, park the job in the dead-letter queue
+            self.status = JobStatus::DeadLetter;
             self.completed_at = Some(chrono::Utc::now());
             false // No more retries
         }
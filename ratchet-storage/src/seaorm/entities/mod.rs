@@ -1,5 +1,6 @@
 pub mod api_keys;
 pub mod delivery_results;
+pub mod execution_labels;
 pub mod executions;
 pub mod jobs;
 pub mod schedules;
@@ -16,6 +17,10 @@ pub use delivery_results::{
     ActiveModel as DeliveryResultActiveModel, Column as DeliveryResultColumn, Entity as DeliveryResults,
     Model as DeliveryResult,
 };
+pub use execution_labels::{
+    ActiveModel as ExecutionLabelActiveModel, Column as ExecutionLabelColumn, Entity as ExecutionLabels,
+    Model as ExecutionLabel,
+};
 pub use executions::{
     ActiveModel as ExecutionActiveModel, Column as ExecutionColumn, Entity as Executions, ExecutionStatus,
     Model as Execution,
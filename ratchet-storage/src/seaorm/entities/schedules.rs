@@ -46,6 +46,9 @@ pub struct Model {
     /// Output destinations configuration as JSON
     pub output_destinations: Option<Json>,
 
+    /// IANA timezone the cron expression is evaluated in (null for UTC)
+    pub timezone: Option<String>,
+
     /// When the schedule was created
     pub created_at: ChronoDateTimeUtc,
 
@@ -88,6 +91,7 @@ impl Model {
             max_executions: None,
             metadata: None,
             output_destinations: None,
+            timezone: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         }
@@ -111,17 +115,16 @@ impl Model {
 
     /// Parse cron expression and get next run time
     pub fn calculate_next_run(&self) -> Result<Option<chrono::DateTime<chrono::Utc>>, String> {
-        use cron::Schedule;
-        use std::str::FromStr;
-
         if !self.enabled || self.is_exhausted() {
             return Ok(None);
         }
 
-        let schedule =
-            Schedule::from_str(&self.cron_expression).map_err(|e| format!("Invalid cron expression: {}", e))?;
-
-        let next = schedule.upcoming(chrono::Utc).next();
+        let timezone = self.timezone.as_deref().unwrap_or("UTC");
+        let next = ratchet_core::schedule::preview_schedule(&self.cron_expression, timezone, chrono::Utc::now(), 1)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .next()
+            .map(|run| run.with_timezone(&chrono::Utc));
         Ok(next)
     }
 }
@@ -1,13 +1,47 @@
-use sea_orm::sea_query::SimpleExpr;
-use sea_orm::{ColumnTrait, Condition, EntityTrait, Value};
+use sea_orm::sea_query::{BinOper, Expr, SimpleExpr};
+use sea_orm::{ColumnTrait, Condition, DbBackend, EntityTrait, Value};
+
+use super::entities::tasks;
 
 /// Safe filter builder for database queries
 /// Prevents SQL injection by properly escaping and parameterizing queries
 pub struct SafeFilterBuilder<E: EntityTrait> {
     conditions: Vec<SimpleExpr>,
+    backend: DbBackend,
     _phantom: std::marker::PhantomData<E>,
 }
 
+/// Escape LIKE wildcards so user input is matched literally
+fn escape_like_pattern(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// Build a parameterized case-insensitive "contains" match for the given backend
+///
+/// Produces `col ILIKE ?` on PostgreSQL and `LOWER(col) LIKE LOWER(?)` elsewhere.
+/// The value is always bound as a parameter and its wildcards are escaped.
+pub fn ilike<C>(backend: DbBackend, column: C, value: &str) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    ilike_expr(backend, SimpleExpr::from(column.into_expr()), value)
+}
+
+/// [`ilike`] for an arbitrary expression rather than a plain column
+fn ilike_expr(backend: DbBackend, column_expr: SimpleExpr, value: &str) -> SimpleExpr {
+    let pattern = format!("%{}%", escape_like_pattern(value));
+
+    match backend {
+        DbBackend::Postgres => column_expr.binary(BinOper::Custom("ILIKE"), Expr::val(pattern)),
+        // MySQL already treats backslash as the default LIKE escape character
+        DbBackend::MySql => Expr::cust_with_exprs("LOWER(?) LIKE LOWER(?)", [column_expr, Expr::val(pattern).into()]),
+        DbBackend::Sqlite => Expr::cust_with_exprs(
+            "LOWER(?) LIKE LOWER(?) ESCAPE '\\'",
+            [column_expr, Expr::val(pattern).into()],
+        ),
+    }
+}
+
 impl<E: EntityTrait> Default for SafeFilterBuilder<E> {
     fn default() -> Self {
         Self::new()
@@ -16,8 +50,14 @@ impl<E: EntityTrait> Default for SafeFilterBuilder<E> {
 
 impl<E: EntityTrait> SafeFilterBuilder<E> {
     pub fn new() -> Self {
+        Self::for_backend(DbBackend::Sqlite)
+    }
+
+    /// Create a builder that emits backend-specific expressions where needed
+    pub fn for_backend(backend: DbBackend) -> Self {
         Self {
             conditions: Vec::new(),
+            backend,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -29,7 +69,7 @@ impl<E: EntityTrait> SafeFilterBuilder<E> {
     {
         if !value.is_empty() {
             // Escape special characters in LIKE patterns
-            let escaped = value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+            let escaped = escape_like_pattern(value);
 
             // Use contains for safety (adds % on both sides)
             self.conditions.push(column.contains(&escaped));
@@ -37,6 +77,17 @@ impl<E: EntityTrait> SafeFilterBuilder<E> {
         self
     }
 
+    /// Add a case-insensitive "contains" filter with proper escaping
+    pub fn add_ilike_filter<C>(&mut self, column: C, value: &str) -> &mut Self
+    where
+        C: ColumnTrait,
+    {
+        if !value.is_empty() {
+            self.conditions.push(ilike(self.backend, column, value));
+        }
+        self
+    }
+
     /// Add an exact match filter
     pub fn add_exact_filter<C, V>(&mut self, column: C, value: V) -> &mut Self
     where
@@ -102,6 +153,47 @@ impl<E: EntityTrait> SafeFilterBuilder<E> {
     }
 }
 
+/// A task's tags as JSON text, for substring matching
+fn task_tags_text(backend: DbBackend) -> SimpleExpr {
+    let metadata = SimpleExpr::from(tasks::Column::Metadata.into_expr());
+
+    match backend {
+        DbBackend::Postgres => Expr::cust_with_exprs("CAST((? -> 'tags') AS TEXT)", [metadata]),
+        DbBackend::MySql => Expr::cust_with_exprs("JSON_EXTRACT(?, '$.tags')", [metadata]),
+        DbBackend::Sqlite => Expr::cust_with_exprs("json_extract(?, '$.tags')", [metadata]),
+    }
+}
+
+/// Relevance of a task to a free-text search, lower is better
+///
+/// Name matches rank before description matches, which rank before tag matches.
+pub fn task_search_rank(backend: DbBackend, value: &str) -> SimpleExpr {
+    Expr::case(ilike(backend, tasks::Column::Name, value), 0)
+        .case(ilike(backend, tasks::Column::Description, value), 1)
+        .finally(2)
+        .into()
+}
+
+impl SafeFilterBuilder<tasks::Entity> {
+    /// Match tasks whose name contains `value`, ignoring case
+    pub fn name_contains_ci(&mut self, value: &str) -> &mut Self {
+        self.add_ilike_filter(tasks::Column::Name, value)
+    }
+
+    /// Match tasks whose name, description or tags contain `value`, ignoring case
+    pub fn search_ci(&mut self, value: &str) -> &mut Self {
+        if !value.is_empty() {
+            let backend = self.backend;
+            self.conditions.push(
+                ilike(backend, tasks::Column::Name, value)
+                    .or(ilike(backend, tasks::Column::Description, value))
+                    .or(ilike_expr(backend, task_tags_text(backend), value)),
+            );
+        }
+        self
+    }
+}
+
 /// Input validation for preventing SQL injection
 pub mod validation {
     use thiserror::Error;
@@ -211,4 +303,57 @@ mod tests {
         assert_eq!(validation::sanitize_input("test@example.com"), "test@example.com");
         assert_eq!(validation::sanitize_input("'; DROP TABLE--"), " DROP TABLE--");
     }
+
+    fn task_query_sql(backend: DbBackend, value: &str) -> String {
+        use sea_orm::{QueryFilter, QueryTrait};
+
+        let mut builder = SafeFilterBuilder::<tasks::Entity>::for_backend(backend);
+        builder.name_contains_ci(value);
+        tasks::Entity::find().filter(builder.build()).build(backend).to_string()
+    }
+
+    #[test]
+    fn test_ilike_backend_specific_sql() {
+        let sqlite = task_query_sql(DbBackend::Sqlite, "MyTask");
+        assert!(sqlite.contains(r#"LOWER("tasks"."name") LIKE LOWER('%MyTask%') ESCAPE '\'"#));
+
+        let postgres = task_query_sql(DbBackend::Postgres, "MyTask");
+        assert!(postgres.contains(r#""tasks"."name" ILIKE '%MyTask%'"#));
+    }
+
+    #[test]
+    fn test_ilike_parameterizes_and_escapes_input() {
+        use sea_orm::{QueryFilter, QueryTrait};
+
+        let mut builder = SafeFilterBuilder::<tasks::Entity>::for_backend(DbBackend::Sqlite);
+        builder.name_contains_ci("50%_' OR 1=1 --");
+        let statement = tasks::Entity::find().filter(builder.build()).build(DbBackend::Sqlite);
+
+        // User input never appears in the SQL text, only as a bound value
+        assert!(!statement.sql.contains("OR 1=1"));
+        let values = statement.values.expect("expected bound values").0;
+        assert_eq!(values, vec![Value::from(r"%50\%\_' OR 1=1 --%".to_string())]);
+    }
+
+    #[test]
+    fn test_search_matches_name_description_and_tags() {
+        use sea_orm::{QueryFilter, QueryTrait};
+
+        let mut builder = SafeFilterBuilder::<tasks::Entity>::for_backend(DbBackend::Postgres);
+        builder.search_ci("report");
+        let sql = tasks::Entity::find()
+            .filter(builder.build())
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(sql.contains(r#""tasks"."name" ILIKE '%report%'"#));
+        assert!(sql.contains(r#""tasks"."description" ILIKE '%report%'"#));
+        assert!(sql.contains(r#"CAST(("tasks"."metadata" -> 'tags') AS TEXT)"#));
+    }
+
+    #[test]
+    fn test_ilike_empty_value_adds_no_condition() {
+        let sql = task_query_sql(DbBackend::Sqlite, "");
+        assert!(!sql.contains("LIKE"));
+    }
 }
@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add labels column to executions table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Executions::Table)
+                    .add_column(ColumnDef::new(Executions::Labels).json().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create execution_labels table for filtering executions by label
+        manager
+            .create_table(
+                Table::create()
+                    .table(ExecutionLabels::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ExecutionLabels::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ExecutionLabels::ExecutionId).integer().not_null())
+                    .col(ColumnDef::new(ExecutionLabels::Key).string_len(255).not_null())
+                    .col(ColumnDef::new(ExecutionLabels::Value).string_len(255).not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_execution_labels_execution_id")
+                            .from(ExecutionLabels::Table, ExecutionLabels::ExecutionId)
+                            .to(Executions::Table, Executions::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Each execution has at most one value per label key
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_execution_labels_execution_key")
+                    .table(ExecutionLabels::Table)
+                    .col(ExecutionLabels::ExecutionId)
+                    .col(ExecutionLabels::Key)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Index on (key, value) for label filtering
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name("idx_execution_labels_key_value")
+                    .table(ExecutionLabels::Table)
+                    .col(ExecutionLabels::Key)
+                    .col(ExecutionLabels::Value)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Drop execution_labels table
+        manager
+            .drop_table(Table::drop().table(ExecutionLabels::Table).to_owned())
+            .await?;
+
+        // Remove labels column from executions table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Executions::Table)
+                    .drop_column(Executions::Labels)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Executions {
+    Table,
+    Id,
+    Labels,
+}
+
+#[derive(DeriveIden)]
+enum ExecutionLabels {
+    Table,
+    Id,
+    ExecutionId,
+    Key,
+    Value,
+}
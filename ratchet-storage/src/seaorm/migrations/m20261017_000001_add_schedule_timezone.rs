@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Add timezone column to schedules table, existing schedules keep running in UTC
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Schedules::Table)
+                    .add_column(ColumnDef::new(Schedules::Timezone).string_len(64).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Remove timezone column from schedules table
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Schedules::Table)
+                    .drop_column(Schedules::Timezone)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Schedules {
+    Table,
+    Timezone,
+}
@@ -7,6 +7,10 @@ mod m20241201_000004_create_jobs_table;
 mod m20241201_000005_create_indexes;
 mod m20250106_000001_add_output_destinations;
 mod m20250702_000001_full_task_storage;
+mod m20251017_000001_add_execution_labels;
+mod m20261017_000001_add_schedule_timezone;
+mod m20261017_000002_add_execution_logs;
+mod m20261017_000003_add_execution_progress;
 
 pub struct Migrator;
 
@@ -21,6 +25,10 @@ impl MigratorTrait for Migrator {
             Box::new(m20241201_000005_create_indexes::Migration),
             Box::new(m20250106_000001_add_output_destinations::Migration),
             Box::new(m20250702_000001_full_task_storage::Migration),
+            Box::new(m20251017_000001_add_execution_labels::Migration),
+            Box::new(m20261017_000001_add_schedule_timezone::Migration),
+            Box::new(m20261017_000002_add_execution_logs::Migration),
+            Box::new(m20261017_000003_add_execution_progress::Migration),
         ]
     }
 }
@@ -16,6 +16,8 @@ pub mod migrations;
 #[cfg(feature = "seaorm")]
 pub mod repositories;
 #[cfg(feature = "seaorm")]
+pub mod retry;
+#[cfg(feature = "seaorm")]
 pub mod safe_errors;
 
 #[cfg(feature = "seaorm")]
@@ -27,6 +29,8 @@ pub use entities::*;
 #[cfg(feature = "seaorm")]
 pub use filters::{validation, SafeFilterBuilder};
 #[cfg(feature = "seaorm")]
+pub use retry::is_busy_error;
+#[cfg(feature = "seaorm")]
 pub use safe_errors::SafeDatabaseError;
 
 // Re-export common SeaORM types for convenience
@@ -1,12 +1,18 @@
 use crate::database::{
-    entities::{executions, Execution, ExecutionActiveModel, ExecutionStatus, Executions},
+    entities::{
+        execution_labels, executions, Execution, ExecutionActiveModel, ExecutionLabelActiveModel, ExecutionLabels,
+        ExecutionStatus, Executions,
+    },
     DatabaseConnection, DatabaseError,
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Alias, Expr, Query};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbBackend, DbErr, EntityTrait, Order,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait, Select, Set,
 };
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Filters for execution queries
@@ -15,7 +21,64 @@ pub struct ExecutionFilters {
     pub task_id: Option<i32>,
     pub status: Option<ExecutionStatus>,
     pub queued_after: Option<DateTime<Utc>>,
+    pub queued_before: Option<DateTime<Utc>>,
+    pub started_after: Option<DateTime<Utc>>,
+    pub started_before: Option<DateTime<Utc>>,
     pub completed_after: Option<DateTime<Utc>>,
+    pub completed_before: Option<DateTime<Utc>>,
+    /// Only executions carrying all of these `(key, value)` labels
+    pub labels: Vec<(String, String)>,
+}
+
+impl ExecutionFilters {
+    fn apply(&self, mut query: Select<Executions>) -> Select<Executions> {
+        if let Some(task_id) = self.task_id {
+            query = query.filter(executions::Column::TaskId.eq(task_id));
+        }
+
+        if let Some(status) = self.status {
+            query = query.filter(executions::Column::Status.eq(status));
+        }
+
+        if let Some(queued_after) = self.queued_after {
+            query = query.filter(executions::Column::QueuedAt.gte(queued_after));
+        }
+
+        if let Some(queued_before) = self.queued_before {
+            query = query.filter(executions::Column::QueuedAt.lt(queued_before));
+        }
+
+        if let Some(started_after) = self.started_after {
+            query = query.filter(executions::Column::StartedAt.gte(Some(started_after)));
+        }
+
+        if let Some(started_before) = self.started_before {
+            query = query.filter(executions::Column::StartedAt.lt(Some(started_before)));
+        }
+
+        if let Some(completed_after) = self.completed_after {
+            query = query.filter(executions::Column::CompletedAt.gte(Some(completed_after)));
+        }
+
+        if let Some(completed_before) = self.completed_before {
+            query = query.filter(executions::Column::CompletedAt.lt(Some(completed_before)));
+        }
+
+        for (key, value) in &self.labels {
+            query = query.filter(
+                executions::Column::Id.in_subquery(
+                    Query::select()
+                        .column(execution_labels::Column::ExecutionId)
+                        .from(ExecutionLabels)
+                        .and_where(execution_labels::Column::Key.eq(key.as_str()))
+                        .and_where(execution_labels::Column::Value.eq(value.as_str()))
+                        .to_owned(),
+                ),
+            );
+        }
+
+        query
+    }
 }
 
 /// Pagination settings for execution queries  
@@ -39,8 +102,9 @@ impl ExecutionRepository {
         Self { db }
     }
 
-    /// Create a new execution
+    /// Create a new execution, indexing its labels for filtering
     pub async fn create(&self, execution: Execution) -> Result<Execution, DatabaseError> {
+        let labels = execution.labels.as_ref().map(labels_from_json).unwrap_or_default();
         let active_model = ExecutionActiveModel {
             uuid: Set(execution.uuid),
             task_id: Set(execution.task_id),
@@ -55,54 +119,85 @@ impl ExecutionRepository {
             duration_ms: Set(execution.duration_ms),
             http_requests: Set(execution.http_requests),
             recording_path: Set(execution.recording_path),
+            labels: Set(execution.labels),
+            logs: Set(execution.logs),
+            progress: Set(execution.progress),
             ..Default::default()
         };
 
-        let result = active_model.insert(self.db.get_connection()).await?;
-        Ok(result)
+        self.db
+            .retry_transaction(|txn| {
+                let active_model = active_model.clone();
+                let labels = labels.clone();
+                Box::pin(async move {
+                    let result = active_model.insert(txn).await?;
+                    replace_labels(txn, result.id, labels).await?;
+                    Ok(result)
+                })
+            })
+            .await
     }
 
     /// Find execution by ID
     pub async fn find_by_id(&self, id: i32) -> Result<Option<Execution>, DatabaseError> {
-        let execution = Executions::find_by_id(id).one(self.db.get_connection()).await?;
+        let execution = self
+            .db
+            .retry_read(|| Executions::find_by_id(id).one(self.db.get_connection()))
+            .await?;
         Ok(execution)
     }
 
     /// Find execution by UUID
     pub async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Execution>, DatabaseError> {
-        let execution = Executions::find()
-            .filter(executions::Column::Uuid.eq(uuid.to_string()))
-            .one(self.db.get_connection())
+        let execution = self
+            .db
+            .retry_read(|| {
+                Executions::find()
+                    .filter(executions::Column::Uuid.eq(uuid.to_string()))
+                    .one(self.db.get_connection())
+            })
             .await?;
         Ok(execution)
     }
 
     /// Find executions by task ID
     pub async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<Execution>, DatabaseError> {
-        let executions = Executions::find()
-            .filter(executions::Column::TaskId.eq(task_id))
-            .order_by(executions::Column::QueuedAt, Order::Desc)
-            .all(self.db.get_connection())
+        let executions = self
+            .db
+            .retry_read(|| {
+                Executions::find()
+                    .filter(executions::Column::TaskId.eq(task_id))
+                    .order_by(executions::Column::QueuedAt, Order::Desc)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(executions)
     }
 
     /// Find executions by status
     pub async fn find_by_status(&self, status: ExecutionStatus) -> Result<Vec<Execution>, DatabaseError> {
-        let executions = Executions::find()
-            .filter(executions::Column::Status.eq(status))
-            .order_by(executions::Column::QueuedAt, Order::Desc)
-            .all(self.db.get_connection())
+        let executions = self
+            .db
+            .retry_read(|| {
+                Executions::find()
+                    .filter(executions::Column::Status.eq(status))
+                    .order_by(executions::Column::QueuedAt, Order::Desc)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(executions)
     }
 
     /// Find recent executions (limit)
     pub async fn find_recent(&self, limit: u64) -> Result<Vec<Execution>, DatabaseError> {
-        let executions = Executions::find()
-            .order_by(executions::Column::QueuedAt, Order::Desc)
-            .limit(limit)
-            .all(self.db.get_connection())
+        let executions = self
+            .db
+            .retry_read(|| {
+                Executions::find()
+                    .order_by(executions::Column::QueuedAt, Order::Desc)
+                    .limit(limit)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(executions)
     }
@@ -119,9 +214,20 @@ impl ExecutionRepository {
 
     /// Update execution
     pub async fn update(&self, execution: Execution) -> Result<Execution, DatabaseError> {
+        let labels = execution.labels.as_ref().map(labels_from_json).unwrap_or_default();
         let active_model: ExecutionActiveModel = execution.into();
-        let updated_execution = active_model.update(self.db.get_connection()).await?;
-        Ok(updated_execution)
+
+        self.db
+            .retry_transaction(|txn| {
+                let active_model = active_model.clone();
+                let labels = labels.clone();
+                Box::pin(async move {
+                    let updated_execution = active_model.update(txn).await?;
+                    replace_labels(txn, updated_execution.id, labels).await?;
+                    Ok(updated_execution)
+                })
+            })
+            .await
     }
 
     /// Update execution status
@@ -207,59 +313,75 @@ impl ExecutionRepository {
         Ok(())
     }
 
+    /// Store the log events captured while the execution ran, replacing any stored before
+    pub async fn store_logs(&self, id: i32, logs: serde_json::Value) -> Result<(), DatabaseError> {
+        let active_model = ExecutionActiveModel {
+            id: Set(id),
+            logs: Set(Some(logs)),
+            ..Default::default()
+        };
+
+        active_model.update(self.db.get_connection()).await?;
+        Ok(())
+    }
+
     /// Delete execution
     pub async fn delete(&self, id: i32) -> Result<(), DatabaseError> {
-        Executions::delete_by_id(id).exec(self.db.get_connection()).await?;
-        Ok(())
+        self.db
+            .retry_transaction(|txn| {
+                Box::pin(async move {
+                    replace_labels(txn, id, HashMap::new()).await?;
+                    Executions::delete_by_id(id).exec(txn).await?;
+                    Ok(())
+                })
+            })
+            .await
     }
 
     /// Count executions
     pub async fn count(&self) -> Result<u64, DatabaseError> {
-        let count = Executions::find().count(self.db.get_connection()).await?;
+        let count = self
+            .db
+            .retry_read(|| Executions::find().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
     /// Count executions by status
     pub async fn count_by_status(&self, status: ExecutionStatus) -> Result<u64, DatabaseError> {
-        let count = Executions::find()
-            .filter(executions::Column::Status.eq(status))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Executions::find()
+                    .filter(executions::Column::Status.eq(status))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count)
     }
 
     /// Count executions by task
     pub async fn count_by_task(&self, task_id: i32) -> Result<u64, DatabaseError> {
-        let count = Executions::find()
-            .filter(executions::Column::TaskId.eq(task_id))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Executions::find()
+                    .filter(executions::Column::TaskId.eq(task_id))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count)
     }
 
     /// Update execution progress (for progress tracking)
     pub async fn update_progress(&self, id: i32, progress: f32) -> Result<(), DatabaseError> {
-        // Note: Progress tracking would require adding a progress column to the execution table
-        // For now, we'll store progress in the output JSON as a temporary solution
-        if let Ok(Some(execution)) = self.find_by_id(id).await {
-            let mut output = execution.output.unwrap_or_else(|| serde_json::json!({}));
-            if let Some(output_obj) = output.as_object_mut() {
-                output_obj.insert(
-                    "progress".to_string(),
-                    serde_json::Value::Number(
-                        serde_json::Number::from_f64(progress as f64).unwrap_or(serde_json::Number::from(0)),
-                    ),
-                );
-            }
-
-            let active_model = ExecutionActiveModel {
-                id: Set(id),
-                output: Set(Some(output)),
-                ..Default::default()
-            };
+        let active_model = ExecutionActiveModel {
+            id: Set(id),
+            progress: Set(Some(progress)),
+            ..Default::default()
+        };
 
-            active_model.update(self.db.get_connection()).await?;
-        }
+        active_model.update(self.db.get_connection()).await?;
         Ok(())
     }
 
@@ -269,24 +391,7 @@ impl ExecutionRepository {
         filters: ExecutionFilters,
         pagination: ExecutionPagination,
     ) -> Result<Vec<Execution>, DatabaseError> {
-        let mut query = Executions::find();
-
-        // Apply filters
-        if let Some(task_id) = filters.task_id {
-            query = query.filter(executions::Column::TaskId.eq(task_id));
-        }
-
-        if let Some(status) = filters.status {
-            query = query.filter(executions::Column::Status.eq(status));
-        }
-
-        if let Some(queued_after) = filters.queued_after {
-            query = query.filter(executions::Column::QueuedAt.gte(queued_after));
-        }
-
-        if let Some(completed_after) = filters.completed_after {
-            query = query.filter(executions::Column::CompletedAt.gte(Some(completed_after)));
-        }
+        let mut query = filters.apply(Executions::find());
 
         // Apply pagination
         if let Some(limit) = pagination.limit {
@@ -307,32 +412,41 @@ impl ExecutionRepository {
             },
         );
 
-        let executions = query.all(self.db.get_connection()).await?;
+        let executions = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
         Ok(executions)
     }
 
-    /// Count executions with filters
-    pub async fn count_with_filters(&self, filters: ExecutionFilters) -> Result<u64, DatabaseError> {
-        let mut query = Executions::find();
-
-        // Apply same filters as find_with_filters
-        if let Some(task_id) = filters.task_id {
-            query = query.filter(executions::Column::TaskId.eq(task_id));
-        }
-
-        if let Some(status) = filters.status {
-            query = query.filter(executions::Column::Status.eq(status));
+    /// Find up to `limit` executions matching `filters` with an ID after `after_id`, in ID order
+    pub async fn find_after(
+        &self,
+        filters: ExecutionFilters,
+        after_id: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<Execution>, DatabaseError> {
+        let mut query = filters.apply(Executions::find());
+        if let Some(after_id) = after_id {
+            query = query.filter(executions::Column::Id.gt(after_id));
         }
+        let query = query.order_by_asc(executions::Column::Id).limit(limit);
 
-        if let Some(queued_after) = filters.queued_after {
-            query = query.filter(executions::Column::QueuedAt.gte(queued_after));
-        }
+        let executions = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
+        Ok(executions)
+    }
 
-        if let Some(completed_after) = filters.completed_after {
-            query = query.filter(executions::Column::CompletedAt.gte(Some(completed_after)));
-        }
+    /// Count executions with filters
+    pub async fn count_with_filters(&self, filters: ExecutionFilters) -> Result<u64, DatabaseError> {
+        let query = filters.apply(Executions::find());
 
-        let count = query.count(self.db.get_connection()).await?;
+        let count = self
+            .db
+            .retry_read(|| query.clone().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
@@ -352,6 +466,204 @@ impl ExecutionRepository {
             failed,
         })
     }
+
+    /// Execution counts by status and duration percentiles per time bucket
+    ///
+    /// Executions queued in `[from, to)` are grouped into buckets of
+    /// `interval_seconds` (at least one second), aligned to the Unix epoch. Every
+    /// bucket overlapping the range is returned, oldest first, including empty ones.
+    pub async fn history(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval_seconds: i64,
+    ) -> Result<Vec<ExecutionHistoryBucket>, DatabaseError> {
+        let interval_seconds = interval_seconds.max(1);
+        let first = from.timestamp().div_euclid(interval_seconds) * interval_seconds;
+        let end = to.timestamp() + i64::from(to.timestamp_subsec_nanos() > 0);
+        let mut buckets: Vec<ExecutionHistoryBucket> = (first..end.max(first + 1))
+            .step_by(interval_seconds as usize)
+            .map(|start| ExecutionHistoryBucket {
+                start: DateTime::from_timestamp(start, 0).unwrap_or_default(),
+                ..Default::default()
+            })
+            .collect();
+        let index = |bucket: i64| ((bucket - first) / interval_seconds) as usize;
+
+        let backend = self.db.get_connection().get_database_backend();
+        let bucket_sql = history_bucket(backend, interval_seconds);
+        let in_range = || {
+            Executions::find()
+                .select_only()
+                .column_as(Expr::cust(bucket_sql.clone()), "bucket")
+                .filter(executions::Column::QueuedAt.gte(from))
+                .filter(executions::Column::QueuedAt.lt(to))
+        };
+
+        let counts: Vec<(i64, ExecutionStatus, i64)> = self
+            .db
+            .retry_read(|| {
+                in_range()
+                    .column(executions::Column::Status)
+                    .column_as(executions::Column::Id.count(), "count")
+                    .group_by(Expr::cust(bucket_sql.clone()))
+                    .group_by(executions::Column::Status)
+                    .into_tuple()
+                    .all(self.db.get_connection())
+            })
+            .await?;
+
+        for (bucket, status, count) in counts {
+            let Some(bucket) = buckets.get_mut(index(bucket)) else {
+                continue;
+            };
+            let count = count as u64;
+            match status {
+                ExecutionStatus::Pending => bucket.pending += count,
+                ExecutionStatus::Running => bucket.running += count,
+                ExecutionStatus::Completed => bucket.completed += count,
+                ExecutionStatus::Failed => bucket.failed += count,
+                ExecutionStatus::Cancelled => bucket.cancelled += count,
+            }
+        }
+
+        // Rank durations within each bucket and keep only the rows at the
+        // nearest-rank percentiles, so at most three rows per bucket come back
+        let ranked = in_range()
+            .column(executions::Column::DurationMs)
+            .column_as(
+                Expr::cust(as_integer(
+                    backend,
+                    &format!("ROW_NUMBER() OVER (PARTITION BY {bucket_sql} ORDER BY duration_ms)"),
+                )),
+                "duration_rank",
+            )
+            .column_as(
+                Expr::cust(as_integer(
+                    backend,
+                    &format!("COUNT(*) OVER (PARTITION BY {bucket_sql})"),
+                )),
+                "bucket_size",
+            )
+            .filter(executions::Column::DurationMs.is_not_null())
+            .into_query();
+        let percentile_rank =
+            |percent: u32| format!("({percent} * bucket_size + 99) {} 100", integer_division(backend));
+        let query = Query::select()
+            .columns([
+                Alias::new("bucket"),
+                Alias::new("duration_ms"),
+                Alias::new("duration_rank"),
+                Alias::new("bucket_size"),
+            ])
+            .from_subquery(ranked, Alias::new("ranked"))
+            .and_where(Expr::cust(format!(
+                "duration_rank IN ({}, {}, {})",
+                percentile_rank(50),
+                percentile_rank(90),
+                percentile_rank(99)
+            )))
+            .to_owned();
+
+        let rows = self
+            .db
+            .retry_read(|| self.db.get_connection().query_all(backend.build(&query)))
+            .await?;
+        for row in rows {
+            let bucket: i64 = row.try_get("", "bucket")?;
+            let duration: i32 = row.try_get("", "duration_ms")?;
+            let rank: i64 = row.try_get("", "duration_rank")?;
+            let size: i64 = row.try_get("", "bucket_size")?;
+            let Some(bucket) = buckets.get_mut(index(bucket)) else {
+                continue;
+            };
+            let duration = Some(duration as i64);
+            if rank == percentile_position(size, 50) {
+                bucket.duration_p50_ms = duration;
+            }
+            if rank == percentile_position(size, 90) {
+                bucket.duration_p90_ms = duration;
+            }
+            if rank == percentile_position(size, 99) {
+                bucket.duration_p99_ms = duration;
+            }
+        }
+
+        Ok(buckets)
+    }
+}
+
+/// SQL for the start of the epoch-aligned bucket an execution was queued in, in Unix seconds
+fn history_bucket(backend: DbBackend, interval_seconds: i64) -> String {
+    let epoch = match backend {
+        DbBackend::Postgres => "FLOOR(EXTRACT(EPOCH FROM queued_at))",
+        DbBackend::MySql => "UNIX_TIMESTAMP(queued_at)",
+        DbBackend::Sqlite => "strftime('%s', queued_at)",
+    };
+    format!(
+        "({} {} {interval_seconds}) * {interval_seconds}",
+        as_integer(backend, epoch),
+        integer_division(backend)
+    )
+}
+
+/// `expr` cast to a 64-bit signed integer
+fn as_integer(backend: DbBackend, expr: &str) -> String {
+    match backend {
+        DbBackend::Postgres => format!("CAST({expr} AS BIGINT)"),
+        DbBackend::MySql => format!("CAST({expr} AS SIGNED)"),
+        DbBackend::Sqlite => format!("CAST({expr} AS INTEGER)"),
+    }
+}
+
+/// Operator dividing two integers into an integer
+fn integer_division(backend: DbBackend) -> &'static str {
+    match backend {
+        DbBackend::MySql => "DIV",
+        DbBackend::Postgres | DbBackend::Sqlite => "/",
+    }
+}
+
+/// 1-based nearest-rank position of a percentile among `size` ascending values
+fn percentile_position(size: i64, percent: i64) -> i64 {
+    (percent * size + 99) / 100
+}
+
+/// Replace the indexed label rows of an execution
+async fn replace_labels(
+    txn: &DatabaseTransaction,
+    execution_id: i32,
+    labels: HashMap<String, String>,
+) -> Result<(), DbErr> {
+    ExecutionLabels::delete_many()
+        .filter(execution_labels::Column::ExecutionId.eq(execution_id))
+        .exec(txn)
+        .await?;
+
+    if !labels.is_empty() {
+        let label_models = labels.into_iter().map(|(key, value)| ExecutionLabelActiveModel {
+            execution_id: Set(execution_id),
+            key: Set(key),
+            value: Set(value),
+            ..Default::default()
+        });
+        ExecutionLabels::insert_many(label_models).exec(txn).await?;
+    }
+
+    Ok(())
+}
+
+/// Read labels stored as a JSON object, ignoring non-string values
+pub fn labels_from_json(labels: &serde_json::Value) -> HashMap<String, String> {
+    labels
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .filter_map(|(key, value)| value.as_str().map(|value| (key.clone(), value.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
 /// Execution statistics
@@ -364,6 +676,20 @@ pub struct ExecutionStats {
     pub failed: u64,
 }
 
+/// Executions queued within one history bucket
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExecutionHistoryBucket {
+    pub start: DateTime<Utc>,
+    pub pending: u64,
+    pub running: u64,
+    pub completed: u64,
+    pub failed: u64,
+    pub cancelled: u64,
+    pub duration_p50_ms: Option<i64>,
+    pub duration_p90_ms: Option<i64>,
+    pub duration_p99_ms: Option<i64>,
+}
+
 #[async_trait(?Send)]
 impl super::Repository for ExecutionRepository {
     async fn health_check(&self) -> Result<(), DatabaseError> {
@@ -372,3 +698,323 @@ impl super::Repository for ExecutionRepository {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::entities::Task;
+    use crate::database::repositories::TaskRepository;
+    use crate::seaorm::config::DatabaseConfig;
+
+    use serde_json::json;
+    use std::time::Duration;
+
+    async fn create_test_db() -> DatabaseConnection {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 5,
+            connection_timeout: Duration::from_secs(10),
+            ..Default::default()
+        };
+
+        let db = DatabaseConnection::new(config).await.unwrap();
+        db.migrate().await.unwrap();
+        db
+    }
+
+    async fn create_task(db: &DatabaseConnection) -> i32 {
+        let now = chrono::Utc::now();
+        let task = Task {
+            id: 0,
+            uuid: Uuid::new_v4(),
+            name: "labelled-task".to_string(),
+            description: None,
+            version: "1.0.0".to_string(),
+            path: Some("/labelled-task".to_string()),
+            metadata: json!({}),
+            input_schema: json!({"type": "object"}),
+            output_schema: json!({"type": "object"}),
+            enabled: true,
+            source_code: "(function(input) { return input; })".to_string(),
+            source_type: "javascript".to_string(),
+            storage_type: "database".to_string(),
+            file_path: None,
+            checksum: "labels".to_string(),
+            repository_id: 1,
+            repository_path: "/labelled-task".to_string(),
+            last_synced_at: None,
+            sync_status: "synced".to_string(),
+            is_editable: true,
+            created_from: "test".to_string(),
+            needs_push: false,
+            created_at: now,
+            updated_at: now,
+            source_modified_at: None,
+            validated_at: None,
+        };
+
+        TaskRepository::new(db.clone()).create(task).await.unwrap().id
+    }
+
+    fn labelled_execution(task_id: i32, labels: serde_json::Value) -> Execution {
+        let mut execution = Execution::new(task_id, json!({}));
+        execution.labels = Some(labels);
+        execution
+    }
+
+    fn label(key: &str, value: &str) -> (String, String) {
+        (key.to_string(), value.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_find_after_pages_by_id() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = ExecutionRepository::new(db);
+
+        let mut ids = Vec::new();
+        for status in [
+            ExecutionStatus::Completed,
+            ExecutionStatus::Failed,
+            ExecutionStatus::Completed,
+            ExecutionStatus::Completed,
+        ] {
+            let mut execution = Execution::new(task_id, json!({}));
+            execution.status = status;
+            ids.push(repo.create(execution).await.unwrap().id);
+        }
+        let page_ids = |page: Vec<Execution>| page.into_iter().map(|e| e.id).collect::<Vec<_>>();
+
+        let first = repo.find_after(ExecutionFilters::default(), None, 2).await.unwrap();
+        assert_eq!(page_ids(first), vec![ids[0], ids[1]]);
+
+        let second = repo
+            .find_after(ExecutionFilters::default(), Some(ids[1]), 2)
+            .await
+            .unwrap();
+        assert_eq!(page_ids(second), vec![ids[2], ids[3]]);
+
+        // Filters apply alongside the cursor
+        let completed = ExecutionFilters {
+            status: Some(ExecutionStatus::Completed),
+            ..Default::default()
+        };
+        let page = repo.find_after(completed, Some(ids[0]), 10).await.unwrap();
+        assert_eq!(page_ids(page), vec![ids[2], ids[3]]);
+    }
+
+    #[tokio::test]
+    async fn test_update_progress_leaves_output_untouched() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = ExecutionRepository::new(db);
+
+        let mut execution = Execution::new(task_id, json!({}));
+        execution.output = Some(json!({"result": 1}));
+        let execution = repo.create(execution).await.unwrap();
+        assert_eq!(execution.progress, None);
+
+        repo.update_progress(execution.id, 0.5).await.unwrap();
+
+        let stored = repo.find_by_id(execution.id).await.unwrap().unwrap();
+        assert_eq!(stored.progress, Some(0.5));
+        assert_eq!(stored.output, Some(json!({"result": 1})));
+    }
+
+    #[tokio::test]
+    async fn test_filter_executions_by_label() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = ExecutionRepository::new(db);
+
+        let prod_eu = repo
+            .create(labelled_execution(task_id, json!({"env": "prod", "region": "eu"})))
+            .await
+            .unwrap();
+        let prod_us = repo
+            .create(labelled_execution(task_id, json!({"env": "prod", "region": "us"})))
+            .await
+            .unwrap();
+        repo.create(labelled_execution(task_id, json!({"env": "staging"})))
+            .await
+            .unwrap();
+        repo.create(Execution::new(task_id, json!({}))).await.unwrap();
+
+        let pagination = || ExecutionPagination {
+            limit: None,
+            offset: None,
+            order_by: Some(executions::Column::Id),
+            order_desc: Some(false),
+        };
+
+        let filters = ExecutionFilters {
+            labels: vec![label("env", "prod")],
+            ..Default::default()
+        };
+        let found = repo.find_with_filters(filters.clone(), pagination()).await.unwrap();
+        assert_eq!(
+            found.iter().map(|e| e.id).collect::<Vec<_>>(),
+            vec![prod_eu.id, prod_us.id]
+        );
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 2);
+        assert_eq!(labels_from_json(found[0].labels.as_ref().unwrap())["region"], "eu");
+
+        // Multiple labels must all match
+        let filters = ExecutionFilters {
+            labels: vec![label("env", "prod"), label("region", "us")],
+            ..Default::default()
+        };
+        let found = repo.find_with_filters(filters, pagination()).await.unwrap();
+        assert_eq!(found.iter().map(|e| e.id).collect::<Vec<_>>(), vec![prod_us.id]);
+
+        let filters = ExecutionFilters {
+            labels: vec![label("env", "dev")],
+            ..Default::default()
+        };
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 0);
+
+        // Deleting an execution removes its labels
+        repo.delete(prod_eu.id).await.unwrap();
+        let filters = ExecutionFilters {
+            labels: vec![label("region", "eu")],
+            ..Default::default()
+        };
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_filter_executions_by_started_window() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = ExecutionRepository::new(db);
+
+        let base = DateTime::parse_from_rfc3339("2024-03-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        for minutes in [0, 10, 20] {
+            let mut execution = Execution::new(task_id, json!({}));
+            execution.started_at = Some(base + chrono::Duration::minutes(minutes));
+            repo.create(execution).await.unwrap();
+        }
+        repo.create(Execution::new(task_id, json!({}))).await.unwrap();
+
+        let filters = ExecutionFilters {
+            started_after: Some(base + chrono::Duration::minutes(5)),
+            started_before: Some(base + chrono::Duration::minutes(15)),
+            ..Default::default()
+        };
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 1);
+
+        let filters = ExecutionFilters {
+            started_before: Some(base + chrono::Duration::minutes(15)),
+            ..Default::default()
+        };
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_history_buckets_by_status() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = ExecutionRepository::new(db);
+
+        let base = DateTime::parse_from_rfc3339("2024-03-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let at = |minutes: i64| base + chrono::Duration::minutes(minutes);
+        let execution = |minutes: i64, status: ExecutionStatus, duration_ms: Option<i32>| {
+            let mut execution = Execution::new(task_id, json!({}));
+            execution.queued_at = at(minutes);
+            execution.status = status;
+            execution.duration_ms = duration_ms;
+            execution
+        };
+
+        let dataset = [
+            // Before the range
+            execution(-5, ExecutionStatus::Completed, Some(1)),
+            // 10:00 - 11:00
+            execution(0, ExecutionStatus::Completed, Some(100)),
+            execution(10, ExecutionStatus::Completed, Some(300)),
+            execution(20, ExecutionStatus::Completed, Some(200)),
+            execution(30, ExecutionStatus::Failed, Some(400)),
+            execution(59, ExecutionStatus::Cancelled, None),
+            // 11:00 - 12:00 stays empty
+            // 12:00 - 13:00
+            execution(120, ExecutionStatus::Pending, None),
+            execution(150, ExecutionStatus::Running, None),
+            execution(170, ExecutionStatus::Completed, Some(50)),
+            // At the end of the range, excluded
+            execution(180, ExecutionStatus::Failed, Some(1)),
+        ];
+        for execution in dataset {
+            repo.create(execution).await.unwrap();
+        }
+
+        let buckets = repo.history(at(0), at(180), 3600).await.unwrap();
+        assert_eq!(
+            buckets.iter().map(|b| b.start).collect::<Vec<_>>(),
+            vec![at(0), at(60), at(120)]
+        );
+
+        assert_eq!(
+            buckets[0],
+            ExecutionHistoryBucket {
+                start: at(0),
+                completed: 3,
+                failed: 1,
+                cancelled: 1,
+                duration_p50_ms: Some(200),
+                duration_p90_ms: Some(400),
+                duration_p99_ms: Some(400),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            buckets[1],
+            ExecutionHistoryBucket {
+                start: at(60),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            buckets[2],
+            ExecutionHistoryBucket {
+                start: at(120),
+                pending: 1,
+                running: 1,
+                completed: 1,
+                duration_p50_ms: Some(50),
+                duration_p90_ms: Some(50),
+                duration_p99_ms: Some(50),
+                ..Default::default()
+            }
+        );
+
+        // Buckets align to the epoch rather than to `from`
+        let buckets = repo.history(at(15), at(45), 1800).await.unwrap();
+        assert_eq!(
+            buckets
+                .iter()
+                .map(|b| (b.start, b.completed, b.failed))
+                .collect::<Vec<_>>(),
+            vec![(at(0), 1, 0), (at(30), 0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_history_bucket_backend_specific_sql() {
+        assert_eq!(
+            history_bucket(DbBackend::Sqlite, 60),
+            "(CAST(strftime('%s', queued_at) AS INTEGER) / 60) * 60"
+        );
+        assert_eq!(
+            history_bucket(DbBackend::Postgres, 60),
+            "(CAST(FLOOR(EXTRACT(EPOCH FROM queued_at)) AS BIGINT) / 60) * 60"
+        );
+        assert_eq!(
+            history_bucket(DbBackend::MySql, 60),
+            "(CAST(UNIX_TIMESTAMP(queued_at) AS SIGNED) DIV 60) * 60"
+        );
+    }
+}
@@ -4,10 +4,24 @@ use crate::database::{
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, SimpleExpr};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    ActiveModelTrait, ColumnTrait, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select,
+    Set,
 };
 
+/// Dequeue rank of a job's priority, highest first
+///
+/// Priorities are stored as strings, so ordering by the column directly would
+/// sort them alphabetically.
+fn priority_rank() -> SimpleExpr {
+    Expr::case(jobs::Column::Priority.eq(JobPriority::Urgent), 3)
+        .case(jobs::Column::Priority.eq(JobPriority::High), 2)
+        .case(jobs::Column::Priority.eq(JobPriority::Normal), 1)
+        .finally(0)
+        .into()
+}
+
 /// Filters for job queries
 #[derive(Debug, Clone, Default)]
 pub struct JobFilters {
@@ -15,7 +29,53 @@ pub struct JobFilters {
     pub status: Option<JobStatus>,
     pub priority: Option<JobPriority>,
     pub queued_after: Option<DateTime<Utc>>,
+    pub queued_before: Option<DateTime<Utc>>,
     pub scheduled_after: Option<DateTime<Utc>>,
+    pub scheduled_before: Option<DateTime<Utc>>,
+    pub completed_after: Option<DateTime<Utc>>,
+    pub completed_before: Option<DateTime<Utc>>,
+}
+
+impl JobFilters {
+    fn apply(&self, mut query: Select<Jobs>) -> Select<Jobs> {
+        if let Some(task_id) = self.task_id {
+            query = query.filter(jobs::Column::TaskId.eq(task_id));
+        }
+
+        if let Some(status) = self.status {
+            query = query.filter(jobs::Column::Status.eq(status));
+        }
+
+        if let Some(priority) = self.priority {
+            query = query.filter(jobs::Column::Priority.eq(priority));
+        }
+
+        if let Some(queued_after) = self.queued_after {
+            query = query.filter(jobs::Column::QueuedAt.gte(queued_after));
+        }
+
+        if let Some(queued_before) = self.queued_before {
+            query = query.filter(jobs::Column::QueuedAt.lt(queued_before));
+        }
+
+        if let Some(scheduled_after) = self.scheduled_after {
+            query = query.filter(jobs::Column::ProcessAt.gte(Some(scheduled_after)));
+        }
+
+        if let Some(scheduled_before) = self.scheduled_before {
+            query = query.filter(jobs::Column::ProcessAt.lt(Some(scheduled_before)));
+        }
+
+        if let Some(completed_after) = self.completed_after {
+            query = query.filter(jobs::Column::CompletedAt.gte(Some(completed_after)));
+        }
+
+        if let Some(completed_before) = self.completed_before {
+            query = query.filter(jobs::Column::CompletedAt.lt(Some(completed_before)));
+        }
+
+        query
+    }
 }
 
 /// Pagination settings for job queries
@@ -69,15 +129,22 @@ impl JobRepository {
 
     /// Find job by ID
     pub async fn find_by_id(&self, id: i32) -> Result<Option<Job>, DatabaseError> {
-        let job = Jobs::find_by_id(id).one(self.db.get_connection()).await?;
+        let job = self
+            .db
+            .retry_read(|| Jobs::find_by_id(id).one(self.db.get_connection()))
+            .await?;
         Ok(job)
     }
 
     /// Find job by UUID
     pub async fn find_by_uuid(&self, uuid: uuid::Uuid) -> Result<Option<Job>, DatabaseError> {
-        let job = Jobs::find()
-            .filter(jobs::Column::Uuid.eq(uuid))
-            .one(self.db.get_connection())
+        let job = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::Uuid.eq(uuid))
+                    .one(self.db.get_connection())
+            })
             .await?;
         Ok(job)
     }
@@ -85,33 +152,65 @@ impl JobRepository {
     /// Find jobs ready for processing (prioritized queue)
     pub async fn find_ready_for_processing(&self, limit: u64) -> Result<Vec<Job>, DatabaseError> {
         let now = chrono::Utc::now();
-        let jobs = Jobs::find()
-            .filter(jobs::Column::Status.is_in(vec![JobStatus::Queued, JobStatus::Retrying]))
-            .filter(jobs::Column::ProcessAt.is_null().or(jobs::Column::ProcessAt.lte(now)))
-            .order_by(jobs::Column::Priority, Order::Desc) // Higher priority first
-            .order_by(jobs::Column::QueuedAt, Order::Asc) // FIFO within same priority
-            .limit(limit)
-            .all(self.db.get_connection())
+        let jobs = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::Status.is_in(vec![JobStatus::Queued, JobStatus::Retrying]))
+                    .filter(jobs::Column::ProcessAt.is_null().or(jobs::Column::ProcessAt.lte(now)))
+                    .order_by(priority_rank(), Order::Desc) // Higher priority first
+                    .order_by(jobs::Column::QueuedAt, Order::Asc) // FIFO within same priority
+                    .order_by(jobs::Column::Id, Order::Asc)
+                    .limit(limit)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(jobs)
     }
 
     /// Find jobs by status
     pub async fn find_by_status(&self, status: JobStatus) -> Result<Vec<Job>, DatabaseError> {
-        let jobs = Jobs::find()
-            .filter(jobs::Column::Status.eq(status))
-            .order_by(jobs::Column::QueuedAt, Order::Desc)
-            .all(self.db.get_connection())
+        let jobs = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::Status.eq(status))
+                    .order_by(jobs::Column::QueuedAt, Order::Desc)
+                    .all(self.db.get_connection())
+            })
+            .await?;
+        Ok(jobs)
+    }
+
+    /// Find a schedule's jobs that are queued, processing or waiting to retry
+    pub async fn find_active_by_schedule(&self, schedule_id: i32) -> Result<Vec<Job>, DatabaseError> {
+        let jobs = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::ScheduleId.eq(schedule_id))
+                    .filter(jobs::Column::Status.is_in(vec![
+                        JobStatus::Queued,
+                        JobStatus::Processing,
+                        JobStatus::Retrying,
+                    ]))
+                    .order_by(jobs::Column::QueuedAt, Order::Asc)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(jobs)
     }
 
     /// Find jobs by task ID
     pub async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<Job>, DatabaseError> {
-        let jobs = Jobs::find()
-            .filter(jobs::Column::TaskId.eq(task_id))
-            .order_by(jobs::Column::QueuedAt, Order::Desc)
-            .all(self.db.get_connection())
+        let jobs = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::TaskId.eq(task_id))
+                    .order_by(jobs::Column::QueuedAt, Order::Desc)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(jobs)
     }
@@ -136,7 +235,7 @@ impl JobRepository {
             JobStatus::Processing => {
                 active_model.started_at = Set(Some(chrono::Utc::now()));
             }
-            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled => {
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled | JobStatus::DeadLetter => {
                 active_model.completed_at = Set(Some(chrono::Utc::now()));
             }
             _ => {}
@@ -166,6 +265,9 @@ impl JobRepository {
     }
 
     /// Mark job as failed and increment retry count
+    ///
+    /// Returns true if the job will be retried; once `max_retries` is reached it is
+    /// moved to the dead-letter queue instead.
     pub async fn mark_failed(
         &self,
         id: i32,
@@ -195,6 +297,27 @@ impl JobRepository {
         }
     }
 
+    /// Mark job as failed without retrying it, moving it straight to the dead-letter queue
+    pub async fn mark_dead_letter(
+        &self,
+        id: i32,
+        error: String,
+        details: Option<serde_json::Value>,
+    ) -> Result<(), DatabaseError> {
+        let active_model = JobActiveModel {
+            id: Set(id),
+            status: Set(JobStatus::DeadLetter),
+            error_message: Set(Some(error)),
+            error_details: Set(details),
+            process_at: Set(None),
+            completed_at: Set(Some(Utc::now())),
+            ..Default::default()
+        };
+
+        active_model.update(self.db.get_connection()).await?;
+        Ok(())
+    }
+
     /// Delete job
     pub async fn delete(&self, id: i32) -> Result<(), DatabaseError> {
         Jobs::delete_by_id(id).exec(self.db.get_connection()).await?;
@@ -203,15 +326,22 @@ impl JobRepository {
 
     /// Count jobs
     pub async fn count(&self) -> Result<u64, DatabaseError> {
-        let count = Jobs::find().count(self.db.get_connection()).await?;
+        let count = self
+            .db
+            .retry_read(|| Jobs::find().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
     /// Count jobs by status
     pub async fn count_by_status(&self, status: JobStatus) -> Result<u64, DatabaseError> {
-        let count = Jobs::find()
-            .filter(jobs::Column::Status.eq(status))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::Status.eq(status))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count)
     }
@@ -247,12 +377,33 @@ impl JobRepository {
         Ok(())
     }
 
+    /// Put a dead-lettered job back in the queue with a fresh retry budget
+    ///
+    /// Returns false when the job does not exist or is not dead-lettered.
+    pub async fn requeue_dead_letter(&self, id: i32) -> Result<bool, DatabaseError> {
+        let result = Jobs::update_many()
+            .col_expr(jobs::Column::Status, Expr::value(JobStatus::Queued))
+            .col_expr(jobs::Column::RetryCount, Expr::value(0))
+            .col_expr(jobs::Column::ProcessAt, Expr::value(Option::<DateTime<Utc>>::None))
+            .col_expr(jobs::Column::CompletedAt, Expr::value(Option::<DateTime<Utc>>::None))
+            .col_expr(jobs::Column::QueuedAt, Expr::value(Utc::now()))
+            .filter(jobs::Column::Id.eq(id))
+            .filter(jobs::Column::Status.eq(JobStatus::DeadLetter))
+            .exec(self.db.get_connection())
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
     /// Find jobs by priority
     pub async fn find_by_priority(&self, priority: JobPriority) -> Result<Vec<Job>, DatabaseError> {
-        let jobs = Jobs::find()
-            .filter(jobs::Column::Priority.eq(priority))
-            .order_by(jobs::Column::QueuedAt, Order::Asc)
-            .all(self.db.get_connection())
+        let jobs = self
+            .db
+            .retry_read(|| {
+                Jobs::find()
+                    .filter(jobs::Column::Priority.eq(priority))
+                    .order_by(jobs::Column::QueuedAt, Order::Asc)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(jobs)
     }
@@ -263,28 +414,7 @@ impl JobRepository {
         filters: JobFilters,
         pagination: JobPagination,
     ) -> Result<Vec<Job>, DatabaseError> {
-        let mut query = Jobs::find();
-
-        // Apply filters
-        if let Some(task_id) = filters.task_id {
-            query = query.filter(jobs::Column::TaskId.eq(task_id));
-        }
-
-        if let Some(status) = filters.status {
-            query = query.filter(jobs::Column::Status.eq(status));
-        }
-
-        if let Some(priority) = filters.priority {
-            query = query.filter(jobs::Column::Priority.eq(priority));
-        }
-
-        if let Some(queued_after) = filters.queued_after {
-            query = query.filter(jobs::Column::QueuedAt.gte(queued_after));
-        }
-
-        if let Some(scheduled_after) = filters.scheduled_after {
-            query = query.filter(jobs::Column::ProcessAt.gte(Some(scheduled_after)));
-        }
+        let mut query = filters.apply(Jobs::find());
 
         // Apply pagination
         if let Some(limit) = pagination.limit {
@@ -310,36 +440,41 @@ impl JobRepository {
             query = query.order_by(jobs::Column::QueuedAt, Order::Asc);
         }
 
-        let jobs = query.all(self.db.get_connection()).await?;
+        let jobs = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
         Ok(jobs)
     }
 
-    /// Count jobs with filters
-    pub async fn count_with_filters(&self, filters: JobFilters) -> Result<u64, DatabaseError> {
-        let mut query = Jobs::find();
-
-        // Apply same filters as find_with_filters
-        if let Some(task_id) = filters.task_id {
-            query = query.filter(jobs::Column::TaskId.eq(task_id));
-        }
-
-        if let Some(status) = filters.status {
-            query = query.filter(jobs::Column::Status.eq(status));
-        }
-
-        if let Some(priority) = filters.priority {
-            query = query.filter(jobs::Column::Priority.eq(priority));
+    /// Find up to `limit` jobs matching `filters` with an ID after `after_id`, in ID order
+    pub async fn find_after(
+        &self,
+        filters: JobFilters,
+        after_id: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<Job>, DatabaseError> {
+        let mut query = filters.apply(Jobs::find());
+        if let Some(after_id) = after_id {
+            query = query.filter(jobs::Column::Id.gt(after_id));
         }
+        let query = query.order_by_asc(jobs::Column::Id).limit(limit);
 
-        if let Some(queued_after) = filters.queued_after {
-            query = query.filter(jobs::Column::QueuedAt.gte(queued_after));
-        }
+        let jobs = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
+        Ok(jobs)
+    }
 
-        if let Some(scheduled_after) = filters.scheduled_after {
-            query = query.filter(jobs::Column::ProcessAt.gte(Some(scheduled_after)));
-        }
+    /// Count jobs with filters
+    pub async fn count_with_filters(&self, filters: JobFilters) -> Result<u64, DatabaseError> {
+        let query = filters.apply(Jobs::find());
 
-        let count = query.count(self.db.get_connection()).await?;
+        let count = self
+            .db
+            .retry_read(|| query.clone().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
@@ -351,6 +486,7 @@ impl JobRepository {
         let completed = self.count_by_status(JobStatus::Completed).await?;
         let failed = self.count_by_status(JobStatus::Failed).await?;
         let retrying = self.count_by_status(JobStatus::Retrying).await?;
+        let dead_letter = self.count_by_status(JobStatus::DeadLetter).await?;
 
         Ok(JobQueueStats {
             total,
@@ -359,6 +495,7 @@ impl JobRepository {
             completed,
             failed,
             retrying,
+            dead_letter,
         })
     }
 }
@@ -372,6 +509,7 @@ pub struct JobQueueStats {
     pub completed: u64,
     pub failed: u64,
     pub retrying: u64,
+    pub dead_letter: u64,
 }
 
 #[async_trait(?Send)]
@@ -381,3 +519,144 @@ impl super::Repository for JobRepository {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::database::repositories::TaskRepository;
+    use crate::seaorm::config::DatabaseConfig;
+    use crate::testing::builders::TaskBuilder;
+
+    use serde_json::json;
+    use std::time::Duration;
+
+    async fn create_test_db() -> DatabaseConnection {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 5,
+            connection_timeout: Duration::from_secs(10),
+            ..Default::default()
+        };
+
+        let db = DatabaseConnection::new(config).await.unwrap();
+        db.migrate().await.unwrap();
+        db
+    }
+
+    async fn create_task(db: &DatabaseConnection) -> i32 {
+        let task = TaskBuilder::new().with_name("queued-task").build();
+        TaskRepository::new(db.clone()).create(task).await.unwrap().id
+    }
+
+    #[tokio::test]
+    async fn test_ready_jobs_dequeue_by_priority_then_fifo() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = JobRepository::new(db);
+
+        // Enqueued a second apart so FIFO order within a priority is unambiguous
+        let start = Utc::now() - chrono::Duration::minutes(1);
+        let priorities = [
+            JobPriority::Low,
+            JobPriority::High,
+            JobPriority::Normal,
+            JobPriority::Urgent,
+            JobPriority::High,
+            JobPriority::Low,
+        ];
+        let mut ids = Vec::new();
+        for (offset, priority) in priorities.into_iter().enumerate() {
+            let mut job = Job::new(task_id, json!({}), priority);
+            job.queued_at = start + chrono::Duration::seconds(offset as i64);
+            ids.push(repo.create(job).await.unwrap().id);
+        }
+
+        let ready = repo.find_ready_for_processing(10).await.unwrap();
+        assert_eq!(
+            ready.iter().map(|job| job.id).collect::<Vec<_>>(),
+            vec![ids[3], ids[1], ids[4], ids[2], ids[0], ids[5]]
+        );
+
+        // The limit takes the highest priorities
+        let ready = repo.find_ready_for_processing(2).await.unwrap();
+        assert_eq!(ready.iter().map(|job| job.id).collect::<Vec<_>>(), vec![ids[3], ids[1]]);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_job_is_dead_lettered_and_requeued() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = JobRepository::new(db);
+
+        let mut job = Job::new(task_id, json!({}), JobPriority::Normal);
+        job.max_retries = 2;
+        let job = repo.create(job).await.unwrap();
+
+        assert!(repo.mark_failed(job.id, "first".to_string(), None).await.unwrap());
+        assert_eq!(repo.find_by_id(job.id).await.unwrap().unwrap().status, JobStatus::Retrying);
+
+        assert!(!repo.mark_failed(job.id, "final".to_string(), None).await.unwrap());
+        let dead = repo.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(dead.status, JobStatus::DeadLetter);
+        assert_eq!(dead.error_message.as_deref(), Some("final"));
+        assert!(repo.find_ready_for_processing(10).await.unwrap().is_empty());
+
+        assert!(repo.requeue_dead_letter(job.id).await.unwrap());
+        let requeued = repo.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(requeued.status, JobStatus::Queued);
+        assert_eq!(requeued.retry_count, 0);
+        assert_eq!(repo.find_ready_for_processing(10).await.unwrap().len(), 1);
+
+        // Only dead-lettered jobs can be requeued
+        assert!(!repo.requeue_dead_letter(job.id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mark_dead_letter_skips_remaining_retries() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = JobRepository::new(db);
+
+        let job = repo
+            .create(Job::new(task_id, json!({}), JobPriority::Normal))
+            .await
+            .unwrap();
+        repo.mark_dead_letter(job.id, "vetoed".to_string(), None).await.unwrap();
+
+        let dead = repo.find_by_id(job.id).await.unwrap().unwrap();
+        assert_eq!(dead.status, JobStatus::DeadLetter);
+        assert_eq!(dead.error_message.as_deref(), Some("vetoed"));
+        assert!(dead.retry_count < dead.max_retries);
+        assert!(repo.find_ready_for_processing(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_filter_jobs_by_scheduled_window() {
+        let db = create_test_db().await;
+        let task_id = create_task(&db).await;
+        let repo = JobRepository::new(db);
+
+        let base = Utc::now();
+        for hours in [1, 2, 3] {
+            let mut job = Job::new(task_id, json!({}), JobPriority::Normal);
+            job.process_at = Some(base + chrono::Duration::hours(hours));
+            repo.create(job).await.unwrap();
+        }
+        repo.create(Job::new(task_id, json!({}), JobPriority::Normal))
+            .await
+            .unwrap();
+
+        let filters = JobFilters {
+            scheduled_after: Some(base + chrono::Duration::minutes(90)),
+            scheduled_before: Some(base + chrono::Duration::minutes(150)),
+            ..Default::default()
+        };
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 1);
+
+        let filters = JobFilters {
+            scheduled_before: Some(base + chrono::Duration::minutes(150)),
+            ..Default::default()
+        };
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 2);
+    }
+}
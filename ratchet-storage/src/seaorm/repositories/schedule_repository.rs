@@ -3,7 +3,158 @@ use crate::database::{
     DatabaseConnection, DatabaseError,
 };
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder, Set};
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Expr, SimpleExpr};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbBackend, DbErr, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Select, Set,
+};
+
+/// Filters for schedule queries
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleFilters {
+    pub task_id: Option<i32>,
+    pub task_id_in: Option<Vec<i32>>,
+    pub id_in: Option<Vec<i32>>,
+    pub enabled: Option<bool>,
+    pub name: Option<String>,
+    pub name_contains: Option<String>,
+    pub name_starts_with: Option<String>,
+    pub name_ends_with: Option<String>,
+    pub cron_expression: Option<String>,
+    pub cron_expression_contains: Option<String>,
+    pub next_run_after: Option<DateTime<Utc>>,
+    pub next_run_before: Option<DateTime<Utc>>,
+    pub last_run_after: Option<DateTime<Utc>>,
+    pub last_run_before: Option<DateTime<Utc>>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+    pub has_next_run: Option<bool>,
+    pub has_last_run: Option<bool>,
+    /// Only schedules whose next run is due at this instant
+    pub due_at: Option<DateTime<Utc>>,
+    /// Only schedules whose `paused` metadata flag matches
+    pub paused: Option<bool>,
+}
+
+impl ScheduleFilters {
+    fn apply(&self, mut query: Select<Schedules>, backend: DbBackend) -> Select<Schedules> {
+        if let Some(task_id) = self.task_id {
+            query = query.filter(schedules::Column::TaskId.eq(task_id));
+        }
+
+        if let Some(ref task_ids) = self.task_id_in {
+            query = query.filter(schedules::Column::TaskId.is_in(task_ids.iter().copied()));
+        }
+
+        if let Some(ref ids) = self.id_in {
+            query = query.filter(schedules::Column::Id.is_in(ids.iter().copied()));
+        }
+
+        if let Some(enabled) = self.enabled {
+            query = query.filter(schedules::Column::Enabled.eq(enabled));
+        }
+
+        if let Some(ref name) = self.name {
+            query = query.filter(schedules::Column::Name.eq(name.as_str()));
+        }
+
+        if let Some(ref name) = self.name_contains {
+            query = query.filter(schedules::Column::Name.contains(name));
+        }
+
+        if let Some(ref name) = self.name_starts_with {
+            query = query.filter(schedules::Column::Name.starts_with(name));
+        }
+
+        if let Some(ref name) = self.name_ends_with {
+            query = query.filter(schedules::Column::Name.ends_with(name));
+        }
+
+        if let Some(ref cron_expression) = self.cron_expression {
+            query = query.filter(schedules::Column::CronExpression.eq(cron_expression.as_str()));
+        }
+
+        if let Some(ref cron_expression) = self.cron_expression_contains {
+            query = query.filter(schedules::Column::CronExpression.contains(cron_expression));
+        }
+
+        if let Some(next_run_after) = self.next_run_after {
+            query = query.filter(schedules::Column::NextRunAt.gte(Some(next_run_after)));
+        }
+
+        if let Some(next_run_before) = self.next_run_before {
+            query = query.filter(schedules::Column::NextRunAt.lt(Some(next_run_before)));
+        }
+
+        if let Some(last_run_after) = self.last_run_after {
+            query = query.filter(schedules::Column::LastRunAt.gte(Some(last_run_after)));
+        }
+
+        if let Some(last_run_before) = self.last_run_before {
+            query = query.filter(schedules::Column::LastRunAt.lt(Some(last_run_before)));
+        }
+
+        if let Some(created_after) = self.created_after {
+            query = query.filter(schedules::Column::CreatedAt.gte(created_after));
+        }
+
+        if let Some(created_before) = self.created_before {
+            query = query.filter(schedules::Column::CreatedAt.lt(created_before));
+        }
+
+        if let Some(updated_after) = self.updated_after {
+            query = query.filter(schedules::Column::UpdatedAt.gte(updated_after));
+        }
+
+        if let Some(updated_before) = self.updated_before {
+            query = query.filter(schedules::Column::UpdatedAt.lt(updated_before));
+        }
+
+        if let Some(has_next_run) = self.has_next_run {
+            query = if has_next_run {
+                query.filter(schedules::Column::NextRunAt.is_not_null())
+            } else {
+                query.filter(schedules::Column::NextRunAt.is_null())
+            };
+        }
+
+        if let Some(has_last_run) = self.has_last_run {
+            query = if has_last_run {
+                query.filter(schedules::Column::LastRunAt.is_not_null())
+            } else {
+                query.filter(schedules::Column::LastRunAt.is_null())
+            };
+        }
+
+        if let Some(due_at) = self.due_at {
+            query = query.filter(schedules::Column::NextRunAt.lte(Some(due_at)));
+        }
+
+        if let Some(paused) = self.paused {
+            let is_paused = schedule_paused(backend);
+            query = query.filter(if paused { is_paused } else { is_paused.not() });
+        }
+
+        query
+    }
+}
+
+/// Whether a schedule's `paused` metadata flag is set, false when it is missing
+fn schedule_paused(backend: DbBackend) -> SimpleExpr {
+    let metadata = SimpleExpr::from(schedules::Column::Metadata.into_expr());
+
+    match backend {
+        DbBackend::Postgres => Expr::cust_with_exprs("COALESCE(? ->> 'paused', 'false') = 'true'", [metadata]),
+        DbBackend::MySql => Expr::cust_with_exprs(
+            "COALESCE(JSON_UNQUOTE(JSON_EXTRACT(?, '$.paused')), 'false') = 'true'",
+            [metadata],
+        ),
+        DbBackend::Sqlite => Expr::cust_with_exprs("COALESCE(json_extract(?, '$.paused'), 0) = 1", [metadata]),
+    }
+}
 
 /// Repository for schedule-related database operations
 #[derive(Clone)]
@@ -32,6 +183,7 @@ impl ScheduleRepository {
             max_executions: Set(schedule.max_executions),
             metadata: Set(schedule.metadata),
             output_destinations: Set(schedule.output_destinations),
+            timezone: Set(schedule.timezone),
             created_at: Set(schedule.created_at),
             updated_at: Set(schedule.updated_at),
             ..Default::default()
@@ -43,24 +195,56 @@ impl ScheduleRepository {
 
     /// Find schedule by ID
     pub async fn find_by_id(&self, id: i32) -> Result<Option<Schedule>, DatabaseError> {
-        let schedule = Schedules::find_by_id(id).one(self.db.get_connection()).await?;
+        let schedule = self
+            .db
+            .retry_read(|| Schedules::find_by_id(id).one(self.db.get_connection()))
+            .await?;
         Ok(schedule)
     }
 
+    /// Find up to `limit` schedules matching `filters` with an ID after `after_id`, in ID order
+    pub async fn find_after(
+        &self,
+        filters: ScheduleFilters,
+        after_id: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<Schedule>, DatabaseError> {
+        let backend = self.db.get_connection().get_database_backend();
+        let mut query = filters.apply(Schedules::find(), backend);
+        if let Some(after_id) = after_id {
+            query = query.filter(schedules::Column::Id.gt(after_id));
+        }
+        let query = query.order_by_asc(schedules::Column::Id).limit(limit);
+
+        let schedules = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
+        Ok(schedules)
+    }
+
     /// Find schedules by task ID
     pub async fn find_by_task_id(&self, task_id: i32) -> Result<Vec<Schedule>, DatabaseError> {
-        let schedules = Schedules::find()
-            .filter(schedules::Column::TaskId.eq(task_id))
-            .all(self.db.get_connection())
+        let schedules = self
+            .db
+            .retry_read(|| {
+                Schedules::find()
+                    .filter(schedules::Column::TaskId.eq(task_id))
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(schedules)
     }
 
     /// Find enabled schedules
     pub async fn find_enabled(&self) -> Result<Vec<Schedule>, DatabaseError> {
-        let schedules = Schedules::find()
-            .filter(schedules::Column::Enabled.eq(true))
-            .all(self.db.get_connection())
+        let schedules = self
+            .db
+            .retry_read(|| {
+                Schedules::find()
+                    .filter(schedules::Column::Enabled.eq(true))
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(schedules)
     }
@@ -68,11 +252,15 @@ impl ScheduleRepository {
     /// Find schedules ready to run
     pub async fn find_ready_to_run(&self) -> Result<Vec<Schedule>, DatabaseError> {
         let now = chrono::Utc::now();
-        let schedules = Schedules::find()
-            .filter(schedules::Column::Enabled.eq(true))
-            .filter(schedules::Column::NextRunAt.lte(now))
-            .order_by(schedules::Column::NextRunAt, Order::Asc)
-            .all(self.db.get_connection())
+        let schedules = self
+            .db
+            .retry_read(|| {
+                Schedules::find()
+                    .filter(schedules::Column::Enabled.eq(true))
+                    .filter(schedules::Column::NextRunAt.lte(now))
+                    .order_by(schedules::Column::NextRunAt, Order::Asc)
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(schedules)
     }
@@ -136,6 +324,38 @@ impl ScheduleRepository {
         Ok(())
     }
 
+    /// Enable or disable several schedules in one transaction, clearing their `paused` flag
+    ///
+    /// Nothing is changed unless every schedule exists. Returns the updated schedules.
+    pub async fn set_enabled_many(&self, ids: &[i32], enabled: bool) -> Result<Vec<Schedule>, DatabaseError> {
+        self.db
+            .retry_transaction(|txn| {
+                let ids = ids.to_vec();
+                Box::pin(async move {
+                    let mut updated = Vec::with_capacity(ids.len());
+                    for id in ids {
+                        let schedule = Schedules::find_by_id(id)
+                            .one(txn)
+                            .await?
+                            .ok_or_else(|| DbErr::RecordNotFound(format!("schedule {}", id)))?;
+
+                        let mut metadata = schedule.metadata.clone().unwrap_or_else(|| serde_json::json!({}));
+                        if let Some(metadata) = metadata.as_object_mut() {
+                            metadata.insert("paused".to_string(), serde_json::Value::Bool(false));
+                        }
+
+                        let mut active_model: ScheduleActiveModel = schedule.into();
+                        active_model.enabled = Set(enabled);
+                        active_model.metadata = Set(Some(metadata));
+                        active_model.updated_at = Set(chrono::Utc::now());
+                        updated.push(active_model.update(txn).await?);
+                    }
+                    Ok(updated)
+                })
+            })
+            .await
+    }
+
     /// Delete schedule
     pub async fn delete(&self, id: i32) -> Result<(), DatabaseError> {
         Schedules::delete_by_id(id).exec(self.db.get_connection()).await?;
@@ -144,15 +364,22 @@ impl ScheduleRepository {
 
     /// Count schedules
     pub async fn count(&self) -> Result<u64, DatabaseError> {
-        let count = Schedules::find().count(self.db.get_connection()).await?;
+        let count = self
+            .db
+            .retry_read(|| Schedules::find().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
     /// Count enabled schedules
     pub async fn count_enabled(&self) -> Result<u64, DatabaseError> {
-        let count = Schedules::find()
-            .filter(schedules::Column::Enabled.eq(true))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Schedules::find()
+                    .filter(schedules::Column::Enabled.eq(true))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count)
     }
@@ -165,3 +392,119 @@ impl super::Repository for ScheduleRepository {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::database::repositories::TaskRepository;
+    use crate::seaorm::config::DatabaseConfig;
+    use crate::testing::builders::{ScheduleBuilder, TaskBuilder};
+
+    use std::time::Duration;
+
+    async fn create_test_db() -> DatabaseConnection {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 5,
+            connection_timeout: Duration::from_secs(10),
+            ..Default::default()
+        };
+
+        let db = DatabaseConnection::new(config).await.unwrap();
+        db.migrate().await.unwrap();
+        db
+    }
+
+    #[tokio::test]
+    async fn test_find_after_applies_filters() {
+        let db = create_test_db().await;
+        let task = TaskBuilder::new().with_name("scheduled-task").build();
+        let task_id = TaskRepository::new(db.clone()).create(task).await.unwrap().id;
+        let repo = ScheduleRepository::new(db);
+
+        let mut ids = Vec::new();
+        for (name, enabled) in [
+            ("nightly-report", true),
+            ("hourly-sync", true),
+            ("nightly-backup", false),
+            ("nightly-cleanup", true),
+        ] {
+            let schedule = ScheduleBuilder::new()
+                .with_task_id(task_id)
+                .with_name(name)
+                .enabled(enabled)
+                .build();
+            ids.push(repo.create(schedule).await.unwrap().id);
+        }
+
+        let filters = ScheduleFilters {
+            enabled: Some(true),
+            name_starts_with: Some("nightly".to_string()),
+            ..Default::default()
+        };
+        let page = repo.find_after(filters.clone(), None, 1).await.unwrap();
+        assert_eq!(page.iter().map(|s| s.id).collect::<Vec<_>>(), vec![ids[0]]);
+
+        let page = repo.find_after(filters, Some(ids[0]), 10).await.unwrap();
+        assert_eq!(page.iter().map(|s| s.id).collect::<Vec<_>>(), vec![ids[3]]);
+    }
+
+    #[tokio::test]
+    async fn test_find_after_filters_by_paused_flag() {
+        let db = create_test_db().await;
+        let task = TaskBuilder::new().with_name("scheduled-task").build();
+        let task_id = TaskRepository::new(db.clone()).create(task).await.unwrap().id;
+        let repo = ScheduleRepository::new(db);
+
+        let mut ids = Vec::new();
+        for metadata in [
+            Some(serde_json::json!({ "paused": true })),
+            Some(serde_json::json!({ "paused": false })),
+            None,
+        ] {
+            let mut schedule = ScheduleBuilder::new().with_task_id(task_id).enabled(false).build();
+            schedule.metadata = metadata;
+            ids.push(repo.create(schedule).await.unwrap().id);
+        }
+
+        let paused = |paused| ScheduleFilters {
+            paused: Some(paused),
+            ..Default::default()
+        };
+        let page = repo.find_after(paused(true), None, 10).await.unwrap();
+        assert_eq!(page.iter().map(|s| s.id).collect::<Vec<_>>(), vec![ids[0]]);
+
+        let page = repo.find_after(paused(false), None, 10).await.unwrap();
+        assert_eq!(page.iter().map(|s| s.id).collect::<Vec<_>>(), vec![ids[1], ids[2]]);
+    }
+
+    #[tokio::test]
+    async fn test_set_enabled_many_is_all_or_nothing() {
+        let db = create_test_db().await;
+        let task = TaskBuilder::new().with_name("scheduled-task").build();
+        let task_id = TaskRepository::new(db.clone()).create(task).await.unwrap().id;
+        let repo = ScheduleRepository::new(db);
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            let mut schedule = ScheduleBuilder::new().with_task_id(task_id).enabled(false).build();
+            schedule.metadata = Some(serde_json::json!({ "paused": true, "serial": true }));
+            ids.push(repo.create(schedule).await.unwrap().id);
+        }
+
+        // A missing schedule rolls back the ones before it
+        assert!(repo.set_enabled_many(&[ids[0], ids[1] + 100], true).await.is_err());
+        assert!(!repo.find_by_id(ids[0]).await.unwrap().unwrap().enabled);
+
+        let updated = repo.set_enabled_many(&ids, true).await.unwrap();
+        assert_eq!(updated.len(), 2);
+        for id in ids {
+            let schedule = repo.find_by_id(id).await.unwrap().unwrap();
+            assert!(schedule.enabled);
+            assert_eq!(
+                schedule.metadata,
+                Some(serde_json::json!({ "paused": false, "serial": true }))
+            );
+        }
+    }
+}
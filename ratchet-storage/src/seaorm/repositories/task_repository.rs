@@ -1,10 +1,13 @@
 use crate::seaorm::{
     connection::{DatabaseConnection, DatabaseError},
     entities::{tasks, Task, TaskActiveModel, Tasks},
-    filters::{validation, SafeFilterBuilder},
+    filters::{task_search_rank, validation, SafeFilterBuilder},
 };
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, Order, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select, Set,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -15,6 +18,8 @@ pub struct TaskFilters {
     pub enabled: Option<bool>,
     pub has_validation: Option<bool>,
     pub version: Option<String>,
+    /// Case-insensitive text matched against name, description and tags
+    pub search: Option<String>,
 }
 
 /// Pagination parameters
@@ -50,9 +55,22 @@ impl TaskRepository {
             input_schema: Set(task.input_schema),
             output_schema: Set(task.output_schema),
             enabled: Set(task.enabled),
+            source_code: Set(task.source_code),
+            source_type: Set(task.source_type),
+            storage_type: Set(task.storage_type),
+            file_path: Set(task.file_path),
+            checksum: Set(task.checksum),
+            repository_id: Set(task.repository_id),
+            repository_path: Set(task.repository_path),
+            last_synced_at: Set(task.last_synced_at),
+            sync_status: Set(task.sync_status),
+            is_editable: Set(task.is_editable),
+            created_from: Set(task.created_from),
+            needs_push: Set(task.needs_push),
             created_at: Set(task.created_at),
             updated_at: Set(task.updated_at),
             validated_at: Set(task.validated_at),
+            source_modified_at: Set(task.source_modified_at),
             ..Default::default()
         };
 
@@ -62,39 +80,74 @@ impl TaskRepository {
 
     /// Find task by ID
     pub async fn find_by_id(&self, id: i32) -> Result<Option<Task>, DatabaseError> {
-        let task = Tasks::find_by_id(id).one(self.db.get_connection()).await?;
+        let task = self
+            .db
+            .retry_read(|| Tasks::find_by_id(id).one(self.db.get_connection()))
+            .await?;
         Ok(task)
     }
 
+    /// Find tasks by ID in a single query, skipping IDs that don't exist
+    pub async fn find_by_ids(&self, ids: &[i32]) -> Result<Vec<Task>, DatabaseError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tasks = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Id.is_in(ids.iter().copied()))
+                    .all(self.db.get_connection())
+            })
+            .await?;
+        Ok(tasks)
+    }
+
     /// Find task by UUID
     pub async fn find_by_uuid(&self, uuid: Uuid) -> Result<Option<Task>, DatabaseError> {
-        let task = Tasks::find()
-            .filter(tasks::Column::Uuid.eq(uuid))
-            .one(self.db.get_connection())
+        let task = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Uuid.eq(uuid))
+                    .one(self.db.get_connection())
+            })
             .await?;
         Ok(task)
     }
 
     /// Find task by name
     pub async fn find_by_name(&self, name: &str) -> Result<Option<Task>, DatabaseError> {
-        let task = Tasks::find()
-            .filter(tasks::Column::Name.eq(name))
-            .one(self.db.get_connection())
+        let task = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Name.eq(name))
+                    .one(self.db.get_connection())
+            })
             .await?;
         Ok(task)
     }
 
     /// Find all tasks
     pub async fn find_all(&self) -> Result<Vec<Task>, DatabaseError> {
-        let tasks = Tasks::find().all(self.db.get_connection()).await?;
+        let tasks = self
+            .db
+            .retry_read(|| Tasks::find().all(self.db.get_connection()))
+            .await?;
         Ok(tasks)
     }
 
     /// Find all enabled tasks
     pub async fn find_enabled(&self) -> Result<Vec<Task>, DatabaseError> {
-        let tasks = Tasks::find()
-            .filter(tasks::Column::Enabled.eq(true))
-            .all(self.db.get_connection())
+        let tasks = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Enabled.eq(true))
+                    .all(self.db.get_connection())
+            })
             .await?;
         Ok(tasks)
     }
@@ -148,6 +201,25 @@ impl TaskRepository {
         Ok(())
     }
 
+    /// Record that a task was just synced from its source
+    ///
+    /// `last_synced_at` and `updated_at` get the same timestamp, so the task
+    /// does not look locally modified to the next sync.
+    pub async fn mark_synced(&self, id: i32) -> Result<(), DatabaseError> {
+        let now = chrono::Utc::now();
+        let active_model = TaskActiveModel {
+            id: Set(id),
+            last_synced_at: Set(Some(now)),
+            sync_status: Set("synced".to_string()),
+            needs_push: Set(false),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        active_model.update(self.db.get_connection()).await?;
+        Ok(())
+    }
+
     /// Enable or disable a task
     pub async fn set_enabled(&self, id: i32, enabled: bool) -> Result<(), DatabaseError> {
         let active_model = TaskActiveModel {
@@ -204,43 +276,54 @@ impl TaskRepository {
 
     /// Count total tasks
     pub async fn count(&self) -> Result<u64, DatabaseError> {
-        let count = Tasks::find().count(self.db.get_connection()).await?;
+        let count = self
+            .db
+            .retry_read(|| Tasks::find().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
     /// Count enabled tasks
     pub async fn count_enabled(&self) -> Result<u64, DatabaseError> {
-        let count = Tasks::find()
-            .filter(tasks::Column::Enabled.eq(true))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Enabled.eq(true))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count)
     }
 
     /// Check if task name exists
     pub async fn name_exists(&self, name: &str) -> Result<bool, DatabaseError> {
-        let count = Tasks::find()
-            .filter(tasks::Column::Name.eq(name))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Name.eq(name))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count > 0)
     }
 
     /// Check if task UUID exists
     pub async fn uuid_exists(&self, uuid: Uuid) -> Result<bool, DatabaseError> {
-        let count = Tasks::find()
-            .filter(tasks::Column::Uuid.eq(uuid))
-            .count(self.db.get_connection())
+        let count = self
+            .db
+            .retry_read(|| {
+                Tasks::find()
+                    .filter(tasks::Column::Uuid.eq(uuid))
+                    .count(self.db.get_connection())
+            })
             .await?;
         Ok(count > 0)
     }
 
-    /// Find tasks with safe filtering and pagination
-    pub async fn find_with_filters(
-        &self,
-        filters: TaskFilters,
-        pagination: Pagination,
-    ) -> Result<Vec<Task>, DatabaseError> {
+    /// Build a query selecting the tasks that match `filters`
+    fn filtered_query(&self, filters: TaskFilters) -> Result<Select<Tasks>, DatabaseError> {
         // Validate filter inputs
         if let Some(ref name) = filters.name {
             validation::validate_query_input(name)?;
@@ -249,12 +332,12 @@ impl TaskRepository {
             validation::validate_query_input(version)?;
         }
 
-        let mut query = Tasks::find();
-        let mut filter_builder = SafeFilterBuilder::<tasks::Entity>::new();
+        let mut filter_builder =
+            SafeFilterBuilder::<tasks::Entity>::for_backend(self.db.get_connection().get_database_backend());
 
         // Apply filters safely
         if let Some(name) = filters.name {
-            filter_builder.add_like_filter(tasks::Column::Name, &name);
+            filter_builder.name_contains_ci(&name);
         }
 
         filter_builder.add_optional_filter(tasks::Column::Enabled, filters.enabled);
@@ -263,6 +346,11 @@ impl TaskRepository {
             filter_builder.add_exact_filter(tasks::Column::Version, version);
         }
 
+        // Search text is bound as a parameter, so free text isn't screened like `name`
+        if let Some(search) = filters.search {
+            filter_builder.search_ci(&search);
+        }
+
         if let Some(has_validation) = filters.has_validation {
             if has_validation {
                 filter_builder.add_condition(tasks::Column::ValidatedAt.is_not_null());
@@ -271,7 +359,17 @@ impl TaskRepository {
             }
         }
 
-        query = query.filter(filter_builder.build());
+        Ok(Tasks::find().filter(filter_builder.build()))
+    }
+
+    /// Find tasks with safe filtering and pagination
+    pub async fn find_with_filters(
+        &self,
+        filters: TaskFilters,
+        pagination: Pagination,
+    ) -> Result<Vec<Task>, DatabaseError> {
+        let search = filters.search.clone().filter(|search| !search.is_empty());
+        let mut query = self.filtered_query(filters)?;
 
         // Apply ordering
         if let Some(order_by) = pagination.order_by {
@@ -303,6 +401,11 @@ impl TaskRepository {
                 }
             }
         } else {
+            // Search results are ranked by where the text matched, with ID as the tie-breaker
+            if let Some(search) = search {
+                let backend = self.db.get_connection().get_database_backend();
+                query = query.order_by(task_search_rank(backend, &search), Order::Asc);
+            }
             query = query.order_by_asc(tasks::Column::Id);
         }
 
@@ -314,44 +417,40 @@ impl TaskRepository {
             query = query.offset(offset);
         }
 
-        let tasks = query.all(self.db.get_connection()).await?;
+        let tasks = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
         Ok(tasks)
     }
 
-    /// Count tasks with safe filtering
-    pub async fn count_with_filters(&self, filters: TaskFilters) -> Result<u64, DatabaseError> {
-        // Validate filter inputs
-        if let Some(ref name) = filters.name {
-            validation::validate_query_input(name)?;
-        }
-        if let Some(ref version) = filters.version {
-            validation::validate_query_input(version)?;
-        }
-
-        let mut query = Tasks::find();
-        let mut filter_builder = SafeFilterBuilder::<tasks::Entity>::new();
-
-        // Apply same filters as find_with_filters
-        if let Some(name) = filters.name {
-            filter_builder.add_like_filter(tasks::Column::Name, &name);
-        }
-
-        filter_builder.add_optional_filter(tasks::Column::Enabled, filters.enabled);
-
-        if let Some(version) = filters.version {
-            filter_builder.add_exact_filter(tasks::Column::Version, version);
+    /// Find up to `limit` tasks matching `filters` with an ID after `after_id`, in ID order
+    pub async fn find_after(
+        &self,
+        filters: TaskFilters,
+        after_id: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<Task>, DatabaseError> {
+        let mut query = self.filtered_query(filters)?;
+        if let Some(after_id) = after_id {
+            query = query.filter(tasks::Column::Id.gt(after_id));
         }
+        let query = query.order_by_asc(tasks::Column::Id).limit(limit);
 
-        if let Some(has_validation) = filters.has_validation {
-            if has_validation {
-                filter_builder.add_condition(tasks::Column::ValidatedAt.is_not_null());
-            } else {
-                filter_builder.add_condition(tasks::Column::ValidatedAt.is_null());
-            }
-        }
+        let tasks = self
+            .db
+            .retry_read(|| query.clone().all(self.db.get_connection()))
+            .await?;
+        Ok(tasks)
+    }
 
-        query = query.filter(filter_builder.build());
-        let count = query.count(self.db.get_connection()).await?;
+    /// Count tasks with safe filtering
+    pub async fn count_with_filters(&self, filters: TaskFilters) -> Result<u64, DatabaseError> {
+        let query = self.filtered_query(filters)?;
+        let count = self
+            .db
+            .retry_read(|| query.clone().count(self.db.get_connection()))
+            .await?;
         Ok(count)
     }
 
@@ -388,6 +487,7 @@ mod tests {
             url: "sqlite::memory:".to_string(),
             max_connections: 5,
             connection_timeout: Duration::from_secs(10),
+            ..Default::default()
         };
 
         let db = DatabaseConnection::new(config).await.unwrap();
@@ -443,7 +543,10 @@ mod tests {
         // Find by ID
         let found_task = repo.find_by_id(created_task.id).await.unwrap();
         assert!(found_task.is_some());
-        assert_eq!(found_task.unwrap().uuid, task_uuid);
+        let found_task = found_task.unwrap();
+        assert_eq!(found_task.uuid, task_uuid);
+        assert_eq!(found_task.source_code, "console.log('Hello from test-task');");
+        assert_eq!(found_task.created_from, "test");
 
         // Find by UUID
         let found_task = repo.find_by_uuid(task_uuid).await.unwrap();
@@ -451,6 +554,71 @@ mod tests {
         assert_eq!(found_task.unwrap().name, "test-task");
     }
 
+    #[tokio::test]
+    async fn test_find_by_ids() {
+        let db = create_test_db().await;
+        let repo = TaskRepository::new(db);
+
+        let mut ids = Vec::new();
+        for name in ["first-task", "second-task", "third-task"] {
+            let mut task = create_sample_task();
+            task.name = name.to_string();
+            ids.push(repo.create(task).await.unwrap().id);
+        }
+
+        // Missing IDs are skipped rather than failing the batch
+        let mut found: Vec<_> = repo
+            .find_by_ids(&[ids[0], ids[2], 9999])
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.name)
+            .collect();
+        found.sort();
+        assert_eq!(found, vec!["first-task", "third-task"]);
+
+        assert!(repo.find_by_ids(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_after_pages_by_id() {
+        let db = create_test_db().await;
+        let repo = TaskRepository::new(db);
+
+        for i in 0..5 {
+            let mut task = create_sample_task();
+            task.name = format!("task-{}", i);
+            task.enabled = i != 2;
+            repo.create(task).await.unwrap();
+        }
+
+        let enabled = || TaskFilters {
+            name: None,
+            enabled: Some(true),
+            has_validation: None,
+            version: None,
+            search: None,
+        };
+
+        let first_page = repo.find_after(enabled(), None, 2).await.unwrap();
+        let names: Vec<_> = first_page.iter().map(|task| task.name.as_str()).collect();
+        assert_eq!(names, vec!["task-0", "task-1"]);
+
+        // The disabled task is skipped without shifting the next page
+        let second_page = repo
+            .find_after(enabled(), Some(first_page[1].id), 2)
+            .await
+            .unwrap();
+        let names: Vec<_> = second_page.iter().map(|task| task.name.as_str()).collect();
+        assert_eq!(names, vec!["task-3", "task-4"]);
+
+        let last_page = repo
+            .find_after(enabled(), Some(second_page[1].id), 2)
+            .await
+            .unwrap();
+        assert!(last_page.is_empty());
+    }
+
     #[tokio::test]
     async fn test_update_task() {
         let db = create_test_db().await;
@@ -488,6 +656,21 @@ mod tests {
         assert!(found_task.enabled);
     }
 
+    #[tokio::test]
+    async fn test_mark_synced() {
+        let db = create_test_db().await;
+        let repo = TaskRepository::new(db);
+
+        let mut task = create_sample_task();
+        task.needs_push = true;
+        let created_task = repo.create(task).await.unwrap();
+
+        repo.mark_synced(created_task.id).await.unwrap();
+        let found_task = repo.find_by_id(created_task.id).await.unwrap().unwrap();
+        assert_eq!(found_task.last_synced_at, Some(found_task.updated_at));
+        assert!(!found_task.needs_push);
+    }
+
     #[tokio::test]
     async fn test_count_and_exists() {
         let db = create_test_db().await;
@@ -515,4 +698,83 @@ mod tests {
 
         assert!(repo.health_check().await.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_find_with_filters_name_is_case_insensitive() {
+        let db = create_test_db().await;
+        let repo = TaskRepository::new(db);
+
+        for name in ["MyTask-Alpha", "mytask-beta", "MYTASK_gamma", "other-task", "100%-done"] {
+            let mut task = create_sample_task();
+            task.uuid = Uuid::new_v4();
+            task.name = name.to_string();
+            repo.create(task).await.unwrap();
+        }
+
+        let filters = |name: &str| TaskFilters {
+            name: Some(name.to_string()),
+            enabled: None,
+            has_validation: None,
+            version: None,
+            search: None,
+        };
+        let pagination = || Pagination {
+            limit: None,
+            offset: None,
+            order_by: Some("name".to_string()),
+            order_desc: None,
+        };
+
+        let found = repo.find_with_filters(filters("mYtAsK"), pagination()).await.unwrap();
+        let names: Vec<_> = found.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["MYTASK_gamma", "MyTask-Alpha", "mytask-beta"]);
+        assert_eq!(repo.count_with_filters(filters("MYTASK")).await.unwrap(), 3);
+
+        // LIKE wildcards in the search value are matched literally
+        let found = repo.find_with_filters(filters("K_G"), pagination()).await.unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "MYTASK_gamma");
+        assert_eq!(repo.count_with_filters(filters("0%-D")).await.unwrap(), 1);
+        assert_eq!(repo.count_with_filters(filters("%")).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_matches_name_description_and_tags_ranked() {
+        let db = create_test_db().await;
+        let repo = TaskRepository::new(db);
+
+        let tasks = [
+            ("by-tag", "Sends mail", json!({"tags": ["Reporting"]})),
+            ("by-description", "Builds the weekly REPORT", json!({})),
+            ("unrelated", "Sends mail", json!({"tags": ["mail"]})),
+            ("report-builder", "Builds things", json!({})),
+        ];
+        for (name, description, metadata) in tasks {
+            let mut task = create_sample_task();
+            task.uuid = Uuid::new_v4();
+            task.name = name.to_string();
+            task.description = Some(description.to_string());
+            task.metadata = metadata;
+            repo.create(task).await.unwrap();
+        }
+
+        let filters = TaskFilters {
+            name: None,
+            enabled: None,
+            has_validation: None,
+            version: None,
+            search: Some("report".to_string()),
+        };
+        let pagination = Pagination {
+            limit: None,
+            offset: None,
+            order_by: None,
+            order_desc: None,
+        };
+
+        let found = repo.find_with_filters(filters.clone(), pagination).await.unwrap();
+        let names: Vec<_> = found.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["report-builder", "by-description", "by-tag"]);
+        assert_eq!(repo.count_with_filters(filters).await.unwrap(), 3);
+    }
 }
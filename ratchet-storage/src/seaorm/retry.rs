@@ -0,0 +1,271 @@
+//! Retry-on-busy support for SQLite-backed repositories
+//!
+//! SQLite allows a single writer at a time; concurrent writers surface as
+//! `SQLITE_BUSY` / `SQLITE_LOCKED` ("database is locked") errors. These helpers
+//! retry such failures with backoff using `ratchet-resilience`. Only idempotent
+//! reads and writes wrapped in a transaction should be retried, since a failed
+//! transaction is rolled back as a whole before the next attempt.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use ratchet_resilience::{BackoffStrategy, RetryExecutor, RetryPolicy, Retryable};
+use sea_orm::{DatabaseTransaction, DbErr, RuntimeErr, TransactionTrait};
+use tracing::debug;
+
+use super::connection::{DatabaseConnection, DatabaseError};
+use crate::config::BusyRetryConfig;
+
+/// SQLite primary result code for a busy database
+const SQLITE_BUSY: i64 = 5;
+
+/// SQLite primary result code for a locked table
+const SQLITE_LOCKED: i64 = 6;
+
+/// Check whether a database error is a transient SQLite busy/locked error
+pub fn is_busy_error(error: &DbErr) -> bool {
+    let database_error = match error {
+        DbErr::Conn(RuntimeErr::SqlxError(sqlx::Error::Database(e)))
+        | DbErr::Exec(RuntimeErr::SqlxError(sqlx::Error::Database(e)))
+        | DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(e))) => Some(e),
+        _ => None,
+    };
+
+    if let Some(code) = database_error
+        .and_then(|e| e.code())
+        .and_then(|code| code.parse::<i64>().ok())
+    {
+        // Extended result codes keep the primary code in the low byte
+        return matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED);
+    }
+
+    let message = error.to_string().to_lowercase();
+    message.contains("database is locked")
+        || message.contains("database table is locked")
+        || message.contains("database is busy")
+}
+
+impl BusyRetryConfig {
+    /// Build the retry policy used for busy/locked errors
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.max_retries.saturating_add(1),
+            initial_delay: self.base_delay,
+            max_delay: self.max_delay,
+            backoff_strategy: BackoffStrategy::Exponential { base: 2.0 },
+            jitter: true,
+        }
+    }
+}
+
+/// Cloneable summary of a failed attempt, as required by `RetryExecutor`
+#[derive(Debug, Clone)]
+struct AttemptFailure {
+    busy: bool,
+    message: String,
+}
+
+impl std::fmt::Display for AttemptFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Retryable for AttemptFailure {
+    fn is_retryable(&self) -> bool {
+        self.busy
+    }
+}
+
+/// Boxed future returned by a transactional operation
+pub type TransactionFuture<'c, T> = Pin<Box<dyn Future<Output = Result<T, DbErr>> + Send + 'c>>;
+
+impl DatabaseConnection {
+    /// Run an idempotent read, retrying on SQLite busy/locked errors
+    pub async fn retry_read<F, Fut, T>(&self, mut operation: F) -> Result<T, DatabaseError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, DbErr>>,
+    {
+        // DbErr is not Clone, so keep the last error aside and hand the executor a summary
+        let last_error: Mutex<Option<DbErr>> = Mutex::new(None);
+        let executor = RetryExecutor::new(self.get_busy_retry().retry_policy());
+
+        let result = executor
+            .execute(|| {
+                let attempt = operation();
+                let last_error = &last_error;
+                async move {
+                    attempt.await.map_err(|error| {
+                        let failure = AttemptFailure {
+                            busy: is_busy_error(&error),
+                            message: error.to_string(),
+                        };
+                        if failure.busy {
+                            debug!("Database busy, operation will be retried: {}", failure.message);
+                        }
+                        *last_error.lock().unwrap_or_else(|e| e.into_inner()) = Some(error);
+                        failure
+                    })
+                }
+            })
+            .await;
+
+        result.map_err(|retry_error| {
+            let error = last_error
+                .into_inner()
+                .unwrap_or_else(|e| e.into_inner())
+                .unwrap_or_else(|| DbErr::Custom(retry_error.to_string()));
+            DatabaseError::DbError(error)
+        })
+    }
+
+    /// Run a write inside a transaction, retrying the whole transaction on SQLite busy/locked errors
+    ///
+    /// The transaction is rolled back when the operation fails, so every attempt starts clean.
+    pub async fn retry_transaction<F, T>(&self, operation: F) -> Result<T, DatabaseError>
+    where
+        F: for<'c> Fn(&'c DatabaseTransaction) -> TransactionFuture<'c, T>,
+    {
+        self.retry_read(|| async {
+            let txn = self.get_connection().begin().await?;
+            let result = operation(&txn).await?;
+            txn.commit().await?;
+            Ok(result)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::seaorm::config::DatabaseConfig;
+    use crate::seaorm::entities::Tasks;
+    use sea_orm::{ConnectionTrait, EntityTrait, PaginatorTrait, Statement};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    async fn create_test_db(max_retries: u32) -> DatabaseConnection {
+        let config = DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            max_connections: 1,
+            connection_timeout: Duration::from_secs(10),
+            busy_retry: BusyRetryConfig {
+                max_retries,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+            },
+        };
+
+        DatabaseConnection::new(config).await.unwrap()
+    }
+
+    #[test]
+    fn test_is_busy_error_detection() {
+        assert!(is_busy_error(&DbErr::Custom("database is locked".to_string())));
+        assert!(is_busy_error(&DbErr::Custom(
+            "Database table is locked: tasks".to_string()
+        )));
+        assert!(!is_busy_error(&DbErr::Custom("UNIQUE constraint failed".to_string())));
+        assert!(!is_busy_error(&DbErr::RecordNotFound("task".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_retry_read_recovers_from_busy() {
+        let db = create_test_db(3).await;
+        let attempts = AtomicU32::new(0);
+
+        let count = db
+            .retry_read(|| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                let conn = db.get_connection();
+                async move {
+                    if attempt < 2 {
+                        Err(DbErr::Custom("database is locked".to_string()))
+                    } else {
+                        Tasks::find().count(conn).await
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(count, 0);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_read_gives_up_and_preserves_error() {
+        let db = create_test_db(2).await;
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), DatabaseError> = db
+            .retry_read(|| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err(DbErr::Custom("database is locked".to_string())) }
+            })
+            .await;
+
+        assert!(matches!(result, Err(DatabaseError::DbError(DbErr::Custom(ref m))) if m == "database is locked"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_non_busy_errors_are_not_retried() {
+        let db = create_test_db(5).await;
+        let attempts = AtomicU32::new(0);
+
+        let result: Result<(), DatabaseError> = db
+            .retry_read(|| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err(DbErr::RecordNotFound("task".to_string())) }
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_transaction_rolls_back_failed_attempts() {
+        let db = create_test_db(3).await;
+        let attempts = AtomicU32::new(0);
+
+        db.get_connection()
+            .execute_unprepared("CREATE TABLE retry_probe (name TEXT NOT NULL)")
+            .await
+            .unwrap();
+
+        db.retry_transaction(|txn| {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                txn.execute_unprepared(&format!(
+                    "INSERT INTO retry_probe (name) VALUES ('attempt-{}')",
+                    attempt
+                ))
+                .await?;
+
+                if attempt == 0 {
+                    return Err(DbErr::Custom("database is locked".to_string()));
+                }
+                Ok(())
+            })
+        })
+        .await
+        .unwrap();
+
+        // The first attempt's insert was rolled back, only the retried one was committed
+        let rows = db
+            .get_connection()
+            .query_all(Statement::from_string(
+                db.get_connection().get_database_backend(),
+                "SELECT name FROM retry_probe",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].try_get::<String>("", "name").unwrap(), "attempt-1");
+    }
+}
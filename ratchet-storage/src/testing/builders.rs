@@ -175,6 +175,9 @@ impl ExecutionBuilder {
                 duration_ms: None,
                 http_requests: None,
                 recording_path: None,
+                labels: None,
+                logs: None,
+                progress: None,
             },
         }
     }
@@ -246,6 +249,12 @@ impl ExecutionBuilder {
         self
     }
 
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let labels = self.execution.labels.get_or_insert_with(|| json!({}));
+        labels[key.into()] = serde_json::Value::String(value.into());
+        self
+    }
+
     pub fn build(self) -> Execution {
         self.execution
     }
@@ -267,6 +276,9 @@ impl ExecutionBuilder {
             duration_ms: Set(execution.duration_ms),
             http_requests: Set(execution.http_requests),
             recording_path: Set(execution.recording_path),
+            labels: Set(execution.labels),
+            logs: Set(execution.logs),
+            progress: Set(execution.progress),
         }
     }
 }
@@ -434,6 +446,7 @@ impl ScheduleBuilder {
                 max_executions: None,
                 metadata: None,
                 output_destinations: None,
+                timezone: None,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
             },
@@ -531,6 +544,7 @@ impl ScheduleBuilder {
             max_executions: Set(schedule.max_executions),
             metadata: Set(schedule.metadata),
             output_destinations: Set(schedule.output_destinations),
+            timezone: Set(schedule.timezone),
             created_at: Set(schedule.created_at),
             updated_at: Set(schedule.updated_at),
         }
@@ -49,6 +49,7 @@ impl TestDatabase {
             url: db_url,
             max_connections: 1,
             connection_timeout: std::time::Duration::from_secs(5),
+            ..Default::default()
         };
 
         let ratchet_connection = RatchetDatabaseConnection::new(config)
@@ -80,6 +81,7 @@ impl TestDatabase {
             url: db_url.to_string(),
             max_connections: 1,
             connection_timeout: std::time::Duration::from_secs(5),
+            ..Default::default()
         };
 
         let ratchet_connection = RatchetDatabaseConnection::new(config)
@@ -19,9 +19,12 @@ use ratchet_api_types::{
 };
 #[cfg(feature = "testing")]
 use ratchet_interfaces::database::{
-    CrudRepository, DatabaseError, ExecutionFilters, ExecutionRepository, FilteredRepository, JobFilters,
-    JobRepository, Repository, ScheduleFilters, ScheduleRepository, TaskFilters, TaskRepository,
+    CrudRepository, DatabaseError, ExecutionFilters, ExecutionHistoryBucket, ExecutionRepository, FilteredRepository,
+    JobFilters, JobRepository, KeysetRepository, Repository, ScheduleFilters, ScheduleRepository, TaskFilters,
+    TaskRepository,
 };
+#[cfg(feature = "testing")]
+use ratchet_interfaces::logging::LogEvent;
 
 // Mock repository implementations using mockall
 
@@ -51,9 +54,20 @@ mock! {
         async fn count_with_filters(&self, filters: TaskFilters) -> Result<u64, DatabaseError>;
     }
 
+    #[async_trait]
+    impl KeysetRepository<UnifiedTask, TaskFilters> for TaskRepo {
+        async fn find_after(
+            &self,
+            filters: TaskFilters,
+            after: Option<ApiId>,
+            limit: u64,
+        ) -> Result<Vec<UnifiedTask>, DatabaseError>;
+    }
+
     #[async_trait]
     impl TaskRepository for TaskRepo {
         async fn find_enabled(&self) -> Result<Vec<UnifiedTask>, DatabaseError>;
+        async fn find_by_ids(&self, ids: Vec<ApiId>) -> Result<Vec<UnifiedTask>, DatabaseError>;
         async fn find_by_name(&self, name: &str) -> Result<Option<UnifiedTask>, DatabaseError>;
         async fn mark_validated(&self, id: ApiId) -> Result<(), DatabaseError>;
         async fn set_enabled(&self, id: ApiId, enabled: bool) -> Result<(), DatabaseError>;
@@ -87,6 +101,16 @@ mock! {
         async fn count_with_filters(&self, filters: ExecutionFilters) -> Result<u64, DatabaseError>;
     }
 
+    #[async_trait]
+    impl KeysetRepository<UnifiedExecution, ExecutionFilters> for ExecutionRepo {
+        async fn find_after(
+            &self,
+            filters: ExecutionFilters,
+            after: Option<ApiId>,
+            limit: u64,
+        ) -> Result<Vec<UnifiedExecution>, DatabaseError>;
+    }
+
     #[async_trait]
     impl ExecutionRepository for ExecutionRepo {
         async fn find_by_task_id(&self, task_id: ApiId) -> Result<Vec<UnifiedExecution>, DatabaseError>;
@@ -97,6 +121,9 @@ mock! {
         async fn mark_failed(&self, id: ApiId, error_message: String, error_details: Option<serde_json::Value>) -> Result<(), DatabaseError>;
         async fn mark_cancelled(&self, id: ApiId) -> Result<(), DatabaseError>;
         async fn update_progress(&self, id: ApiId, progress: f32) -> Result<(), DatabaseError>;
+        async fn store_logs(&self, id: ApiId, logs: Vec<LogEvent>) -> Result<(), DatabaseError>;
+        async fn find_logs(&self, id: ApiId) -> Result<Vec<LogEvent>, DatabaseError>;
+        async fn history(&self, from: chrono::DateTime<chrono::Utc>, to: chrono::DateTime<chrono::Utc>, interval_seconds: i64) -> Result<Vec<ExecutionHistoryBucket>, DatabaseError>;
     }
 }
 
@@ -126,15 +153,28 @@ mock! {
         async fn count_with_filters(&self, filters: JobFilters) -> Result<u64, DatabaseError>;
     }
 
+    #[async_trait]
+    impl KeysetRepository<UnifiedJob, JobFilters> for JobRepo {
+        async fn find_after(
+            &self,
+            filters: JobFilters,
+            after: Option<ApiId>,
+            limit: u64,
+        ) -> Result<Vec<UnifiedJob>, DatabaseError>;
+    }
+
     #[async_trait]
     impl JobRepository for JobRepo {
         async fn find_ready_for_processing(&self, limit: u64) -> Result<Vec<UnifiedJob>, DatabaseError>;
         async fn find_by_status(&self, status: JobStatus) -> Result<Vec<UnifiedJob>, DatabaseError>;
+        async fn find_active_by_schedule(&self, schedule_id: ApiId) -> Result<Vec<UnifiedJob>, DatabaseError>;
         async fn mark_processing(&self, id: ApiId, execution_id: ApiId) -> Result<(), DatabaseError>;
         async fn mark_completed(&self, id: ApiId) -> Result<(), DatabaseError>;
         async fn mark_failed(&self, id: ApiId, error: String, details: Option<serde_json::Value>) -> Result<bool, DatabaseError>;
+        async fn mark_dead_letter(&self, id: ApiId, error: String, details: Option<serde_json::Value>) -> Result<(), DatabaseError>;
         async fn schedule_retry(&self, id: ApiId, retry_at: chrono::DateTime<chrono::Utc>) -> Result<(), DatabaseError>;
         async fn cancel(&self, id: ApiId) -> Result<(), DatabaseError>;
+        async fn requeue_dead_letter(&self, id: ApiId) -> Result<bool, DatabaseError>;
     }
 }
 
@@ -164,6 +204,16 @@ mock! {
         async fn count_with_filters(&self, filters: ScheduleFilters) -> Result<u64, DatabaseError>;
     }
 
+    #[async_trait]
+    impl KeysetRepository<UnifiedSchedule, ScheduleFilters> for ScheduleRepo {
+        async fn find_after(
+            &self,
+            filters: ScheduleFilters,
+            after: Option<ApiId>,
+            limit: u64,
+        ) -> Result<Vec<UnifiedSchedule>, DatabaseError>;
+    }
+
     #[async_trait]
     impl ScheduleRepository for ScheduleRepo {
         async fn find_enabled(&self) -> Result<Vec<UnifiedSchedule>, DatabaseError>;
@@ -171,6 +221,7 @@ mock! {
         async fn record_execution(&self, id: ApiId, execution_id: ApiId) -> Result<(), DatabaseError>;
         async fn update_next_run(&self, id: ApiId, next_run: chrono::DateTime<chrono::Utc>) -> Result<(), DatabaseError>;
         async fn set_enabled(&self, id: ApiId, enabled: bool) -> Result<(), DatabaseError>;
+        async fn set_enabled_many(&self, ids: Vec<ApiId>, enabled: bool) -> Result<Vec<UnifiedSchedule>, DatabaseError>;
     }
 }
 
@@ -31,6 +31,9 @@ pub enum WebError {
     #[error("Conflict: {message}")]
     Conflict { message: String },
 
+    #[error("Payload too large: {message}")]
+    PayloadTooLarge { message: String },
+
     #[error("Too many requests: {message}")]
     TooManyRequests { message: String },
 
@@ -70,6 +73,7 @@ impl WebError {
             WebError::Forbidden { .. } => StatusCode::FORBIDDEN,
             WebError::NotFound { .. } => StatusCode::NOT_FOUND,
             WebError::Conflict { .. } => StatusCode::CONFLICT,
+            WebError::PayloadTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
             WebError::TooManyRequests { .. } | WebError::RateLimit => StatusCode::TOO_MANY_REQUESTS,
             WebError::Timeout => StatusCode::REQUEST_TIMEOUT,
             WebError::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
@@ -85,6 +89,7 @@ impl WebError {
             WebError::Forbidden { .. } => "FORBIDDEN",
             WebError::NotFound { .. } => "NOT_FOUND",
             WebError::Conflict { .. } => "CONFLICT",
+            WebError::PayloadTooLarge { .. } => "PAYLOAD_TOO_LARGE",
             WebError::TooManyRequests { .. } | WebError::RateLimit => "RATE_LIMITED",
             WebError::Timeout => "TIMEOUT",
             WebError::ServiceUnavailable { .. } => "SERVICE_UNAVAILABLE",
@@ -117,7 +122,7 @@ impl IntoResponse for WebError {
                     "error": {
                         "code": error_code,
                         "message": safe_message,
-                        "details": errors
+                        "errors": errors
                     }
                 })
             }
@@ -146,6 +151,7 @@ impl From<WebError> for ApiError {
             WebError::Forbidden { message } => ("FORBIDDEN".to_string(), message.clone()),
             WebError::NotFound { message } => ("NOT_FOUND".to_string(), message.clone()),
             WebError::Conflict { message } => ("CONFLICT".to_string(), message.clone()),
+            WebError::PayloadTooLarge { message } => ("PAYLOAD_TOO_LARGE".to_string(), message.clone()),
             WebError::TooManyRequests { message } => ("RATE_LIMITED".to_string(), message.clone()),
             WebError::ServiceUnavailable { message } => ("SERVICE_UNAVAILABLE".to_string(), message.clone()),
             WebError::RateLimit => ("RATE_LIMITED".to_string(), "Rate limit exceeded".to_string()),
@@ -189,6 +195,9 @@ impl From<ApiError> for WebError {
             "CONFLICT" => WebError::Conflict {
                 message: api_error.message,
             },
+            "PAYLOAD_TOO_LARGE" => WebError::PayloadTooLarge {
+                message: api_error.message,
+            },
             "RATE_LIMITED" => WebError::TooManyRequests {
                 message: api_error.message,
             },
@@ -249,6 +258,12 @@ impl WebError {
         }
     }
 
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        WebError::PayloadTooLarge {
+            message: message.into(),
+        }
+    }
+
     pub fn internal(message: impl Into<String>) -> Self {
         WebError::Internal {
             message: message.into(),
@@ -4,6 +4,8 @@ use ratchet_api_types::{ExecutionStatus, JobPriority, JobStatus};
 use ratchet_interfaces::{ExecutionFilters, JobFilters, ScheduleFilters, TaskFilters};
 use std::collections::HashMap;
 
+use crate::errors::{ValidationError, WebError, WebResult};
+
 /// Helper function to parse ApiId from string
 fn parse_api_id(s: &str) -> Option<ApiId> {
     Some(ApiId::from_string(s.to_string()))
@@ -32,6 +34,7 @@ fn parse_job_status(s: &str) -> Option<JobStatus> {
         "FAILED" => Some(JobStatus::Failed),
         "CANCELLED" => Some(JobStatus::Cancelled),
         "RETRYING" => Some(JobStatus::Retrying),
+        "DEAD_LETTER" => Some(JobStatus::DeadLetter),
         _ => None,
     }
 }
@@ -48,6 +51,57 @@ fn parse_job_priority(s: &str) -> Option<JobPriority> {
     }
 }
 
+/// Parse `key:value` labels, comma-separated when filtering on several
+fn parse_labels(s: &str) -> Vec<(String, String)> {
+    s.split(',')
+        .filter_map(|label| label.split_once(':'))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+/// Parse an RFC 3339 date filter, recording an error when it is malformed
+fn parse_date_filter(
+    filters: &HashMap<String, String>,
+    field: &str,
+    errors: &mut Vec<ValidationError>,
+) -> Option<DateTime<Utc>> {
+    let value = filters.get(field)?;
+    match DateTime::parse_from_rfc3339(value) {
+        Ok(date) => Some(date.with_timezone(&Utc)),
+        Err(_) => {
+            errors.push(ValidationError {
+                field: Some(field.to_string()),
+                message: format!("{} must be an RFC 3339 date, got '{}'", field, value),
+                code: "INVALID_DATE".to_string(),
+            });
+            None
+        }
+    }
+}
+
+/// `(field, after, before)` bounds of a date window filter
+type DateRange<'a> = (&'a str, Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// Reject `<field>_after`/`<field>_before` windows that end before they start
+fn check_date_ranges(ranges: &[DateRange<'_>]) -> WebResult<()> {
+    let errors: Vec<ValidationError> = ranges
+        .iter()
+        .filter(|(_, after, before)| matches!((after, before), (Some(after), Some(before)) if after > before))
+        .map(|(field, _, _)| ValidationError {
+            field: Some(format!("{}_after", field)),
+            message: format!("{field}_after must not be later than {field}_before"),
+            code: "INVALID_RANGE".to_string(),
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(WebError::validation(errors))
+    }
+}
+
 /// Extract filters from query parameters for TaskFilters
 pub fn extract_task_filters(filters: &HashMap<String, String>) -> TaskFilters {
     TaskFilters {
@@ -65,6 +119,12 @@ pub fn extract_task_filters(filters: &HashMap<String, String>) -> TaskFilters {
         name_starts_with: filters.get("name_starts_with").cloned(),
         name_ends_with: filters.get("name_ends_with").cloned(),
 
+        // Free-text search
+        search: filters
+            .get("search")
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty()),
+
         // Version filtering
         version: filters.get("version").cloned(),
         version_in: filters
@@ -104,17 +164,16 @@ pub fn extract_task_filters(filters: &HashMap<String, String>) -> TaskFilters {
 }
 
 /// Extract filters from query parameters for ExecutionFilters
-pub fn extract_execution_filters(filters: &HashMap<String, String>) -> ExecutionFilters {
-    ExecutionFilters {
+///
+/// Fails when a date window's `_after` bound is later than its `_before` bound.
+pub fn extract_execution_filters(filters: &HashMap<String, String>) -> WebResult<ExecutionFilters> {
+    let mut errors = Vec::new();
+    let filters = ExecutionFilters {
         // Basic filters
         task_id: filters.get("task_id").and_then(|v| parse_api_id(v)),
         status: filters.get("status").and_then(|v| parse_execution_status(v)),
-        queued_after: filters
-            .get("queued_after")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
-        completed_after: filters
-            .get("completed_after")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
+        queued_after: parse_date_filter(filters, "queued_after", &mut errors),
+        completed_after: parse_date_filter(filters, "completed_after", &mut errors),
 
         // Advanced ID filtering
         task_id_in: filters
@@ -131,18 +190,10 @@ pub fn extract_execution_filters(filters: &HashMap<String, String>) -> Execution
         status_not: filters.get("status_ne").and_then(|v| parse_execution_status(v)),
 
         // Extended date filtering
-        queued_before: filters
-            .get("queued_before")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
-        started_after: filters
-            .get("started_after")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
-        started_before: filters
-            .get("started_before")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
-        completed_before: filters
-            .get("completed_before")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
+        queued_before: parse_date_filter(filters, "queued_before", &mut errors),
+        started_after: parse_date_filter(filters, "started_after", &mut errors),
+        started_before: parse_date_filter(filters, "started_before", &mut errors),
+        completed_before: parse_date_filter(filters, "completed_before", &mut errors),
 
         // Duration filtering
         duration_min_ms: filters.get("duration_gte").and_then(|v| v.parse().ok()),
@@ -160,22 +211,35 @@ pub fn extract_execution_filters(filters: &HashMap<String, String>) -> Execution
         // Advanced boolean filtering
         can_retry: filters.get("can_retry").and_then(|v| v.parse().ok()),
         can_cancel: filters.get("can_cancel").and_then(|v| v.parse().ok()),
+
+        // Label filtering
+        labels: filters.get("label").map(|v| parse_labels(v)),
+    };
+    if !errors.is_empty() {
+        return Err(WebError::validation(errors));
     }
+
+    check_date_ranges(&[
+        ("queued", filters.queued_after, filters.queued_before),
+        ("started", filters.started_after, filters.started_before),
+        ("completed", filters.completed_after, filters.completed_before),
+    ])?;
+    Ok(filters)
 }
 
 /// Extract filters from query parameters for JobFilters
-pub fn extract_job_filters(filters: &HashMap<String, String>) -> JobFilters {
-    JobFilters {
+///
+/// Fails when a date filter isn't an RFC 3339 date, or a date window's
+/// `_after` bound is later than its `_before` bound.
+pub fn extract_job_filters(filters: &HashMap<String, String>) -> WebResult<JobFilters> {
+    let mut errors = Vec::new();
+    let filters = JobFilters {
         // Basic filters
         task_id: filters.get("task_id").and_then(|v| parse_api_id(v)),
         status: filters.get("status").and_then(|v| parse_job_status(v)),
         priority: filters.get("priority").and_then(|v| parse_job_priority(v)),
-        queued_after: filters
-            .get("queued_after")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
-        scheduled_before: filters
-            .get("scheduled_before")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
+        queued_after: parse_date_filter(filters, "queued_after", &mut errors),
+        scheduled_before: parse_date_filter(filters, "scheduled_before", &mut errors),
 
         // Advanced ID filtering
         task_id_in: filters
@@ -198,12 +262,10 @@ pub fn extract_job_filters(filters: &HashMap<String, String>) -> JobFilters {
         priority_min: filters.get("priority_gte").and_then(|v| parse_job_priority(v)),
 
         // Extended date filtering
-        queued_before: filters
-            .get("queued_before")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
-        scheduled_after: filters
-            .get("scheduled_after")
-            .and_then(|v| DateTime::parse_from_rfc3339(v).ok().map(|dt| dt.with_timezone(&Utc))),
+        queued_before: parse_date_filter(filters, "queued_before", &mut errors),
+        scheduled_after: parse_date_filter(filters, "scheduled_after", &mut errors),
+        completed_after: parse_date_filter(filters, "completed_after", &mut errors),
+        completed_before: parse_date_filter(filters, "completed_before", &mut errors),
 
         // Retry filtering
         retry_count_min: filters.get("retry_count_gte").and_then(|v| v.parse().ok()),
@@ -219,7 +281,17 @@ pub fn extract_job_filters(filters: &HashMap<String, String>) -> JobFilters {
         // Scheduling filtering
         is_scheduled: filters.get("is_scheduled").and_then(|v| v.parse().ok()),
         due_now: filters.get("due_now").and_then(|v| v.parse().ok()),
+    };
+    if !errors.is_empty() {
+        return Err(WebError::validation(errors));
     }
+
+    check_date_ranges(&[
+        ("queued", filters.queued_after, filters.queued_before),
+        ("scheduled", filters.scheduled_after, filters.scheduled_before),
+        ("completed", filters.completed_after, filters.completed_before),
+    ])?;
+    Ok(filters)
 }
 
 /// Extract filters from query parameters for ScheduleFilters
@@ -280,5 +352,88 @@ pub fn extract_schedule_filters(filters: &HashMap<String, String>) -> ScheduleFi
         has_last_run: filters.get("has_last_run").and_then(|v| v.parse().ok()),
         is_due: filters.get("is_due").and_then(|v| v.parse().ok()),
         overdue: filters.get("overdue").and_then(|v| v.parse().ok()),
+        paused: filters.get("paused").and_then(|v| v.parse().ok()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_execution_label_filters() {
+        let mut query = HashMap::new();
+        query.insert("label".to_string(), "env:prod, region:eu-west:1,invalid".to_string());
+
+        let filters = extract_execution_filters(&query).unwrap();
+        assert_eq!(
+            filters.labels,
+            Some(vec![
+                ("env".to_string(), "prod".to_string()),
+                ("region".to_string(), "eu-west:1".to_string()),
+            ])
+        );
+
+        assert!(extract_execution_filters(&HashMap::new()).unwrap().labels.is_none());
+    }
+
+    #[test]
+    fn test_extract_date_range_filters() {
+        let mut query = HashMap::new();
+        query.insert("queued_after".to_string(), "2024-03-01T00:00:00Z".to_string());
+        query.insert("queued_before".to_string(), "2024-03-02T00:00:00Z".to_string());
+        query.insert("completed_after".to_string(), "2024-03-01T12:00:00Z".to_string());
+
+        let filters = extract_execution_filters(&query).unwrap();
+        assert_eq!(filters.queued_after.unwrap().to_rfc3339(), "2024-03-01T00:00:00+00:00");
+        assert_eq!(filters.queued_before.unwrap().to_rfc3339(), "2024-03-02T00:00:00+00:00");
+        assert!(filters.completed_before.is_none());
+
+        let filters = extract_job_filters(&query).unwrap();
+        assert!(filters.completed_after.is_some());
+    }
+
+    #[test]
+    fn test_inverted_date_range_is_rejected() {
+        let mut query = HashMap::new();
+        query.insert("completed_after".to_string(), "2024-03-02T00:00:00Z".to_string());
+        query.insert("completed_before".to_string(), "2024-03-01T00:00:00Z".to_string());
+
+        for result in [
+            extract_execution_filters(&query).map(|_| ()),
+            extract_job_filters(&query).map(|_| ()),
+        ] {
+            match result {
+                Err(WebError::Validation { errors }) => {
+                    assert_eq!(errors.len(), 1);
+                    assert_eq!(errors[0].field.as_deref(), Some("completed_after"));
+                    assert_eq!(errors[0].code, "INVALID_RANGE");
+                }
+                other => panic!("expected a validation error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_date_filter_is_rejected() {
+        let mut query = HashMap::new();
+        query.insert("started_after".to_string(), "yesterday".to_string());
+        query.insert("scheduled_before".to_string(), "2024-13-01".to_string());
+
+        match extract_execution_filters(&query) {
+            Err(WebError::Validation { errors }) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field.as_deref(), Some("started_after"));
+                assert_eq!(errors[0].code, "INVALID_DATE");
+            }
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+        match extract_job_filters(&query) {
+            Err(WebError::Validation { errors }) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field.as_deref(), Some("scheduled_before"));
+            }
+            other => panic!("expected a validation error, got {:?}", other),
+        }
     }
 }
@@ -1,9 +1,11 @@
 pub mod auth;
 pub mod filter_extraction;
 pub mod query;
+pub mod wait;
 
 // Re-export commonly used extractors
 pub use filter_extraction::{
     extract_execution_filters, extract_job_filters, extract_schedule_filters, extract_task_filters,
 };
-pub use query::{FilterQuery, ListQuery, PaginationParams, PaginationQuery, QueryParams, SortQuery};
+pub use query::{CursorPage, FilterQuery, ListQuery, PaginationParams, PaginationQuery, QueryParams, SortQuery};
+pub use wait::{WaitParams, WaitQuery};
@@ -214,12 +214,55 @@ pub struct ListQuery {
     /// Sort order (ASC/DESC)
     #[serde(rename = "_order")]
     pub order: Option<String>,
+    /// Opt-in cursor pagination: the `next_cursor` of the previous page, or empty for the first page
+    pub cursor: Option<String>,
     /// Generic filter fields (field_name=value)
     #[serde(flatten)]
     pub filters: std::collections::HashMap<String, String>,
 }
 
+/// A keyset page requested with `?cursor=...&limit=...`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage {
+    /// Return rows after this ID; `None` starts from the first row
+    pub after: Option<ratchet_api_types::ApiId>,
+    /// Rows per page
+    pub limit: u32,
+}
+
+impl CursorPage {
+    /// Rows to fetch: one more than the page size, to tell whether another page follows
+    pub fn fetch_limit(&self) -> u64 {
+        self.limit as u64 + 1
+    }
+
+    /// Trim a fetched page to size and wrap it in a response carrying the next cursor
+    pub fn into_response<T>(
+        &self,
+        mut items: Vec<T>,
+        id: impl Fn(&T) -> &ratchet_api_types::ApiId,
+    ) -> crate::utils::ApiResponse<Vec<T>> {
+        let next_cursor = if items.len() > self.limit as usize {
+            items.truncate(self.limit as usize);
+            items.last().map(|item| id(item).to_string())
+        } else {
+            None
+        };
+        crate::utils::ApiResponse::with_cursor(items, next_cursor)
+    }
+}
+
 impl ListQuery {
+    /// The requested keyset page, when the client opted into cursor pagination
+    pub fn cursor_page(&self) -> Option<CursorPage> {
+        self.cursor.as_ref().map(|cursor| CursorPage {
+            after: Some(cursor.trim())
+                .filter(|cursor| !cursor.is_empty())
+                .map(ratchet_api_types::ApiId::from),
+            limit: self.limit.unwrap_or(25),
+        })
+    }
+
     /// Convert to standard list input
     pub fn to_list_input(&self) -> ratchet_api_types::pagination::ListInput {
         let pagination_input = if let (Some(start), Some(end)) = (self.start, self.end) {
@@ -275,6 +318,23 @@ impl ListQuery {
             }
         }
 
+        // Cursor pages are ordered by ID, so offsets and custom sorting don't apply
+        if self.cursor.is_some() && (self.page.is_some() || self.start.is_some() || self.sort.is_some()) {
+            return Err(WebError::bad_request(
+                "Invalid pagination: cursor cannot be combined with page, _start or _sort",
+            ));
+        }
+
+        // Cursors are the IDs handed out as `next_cursor`
+        if let Some(cursor) = self.cursor.as_deref().map(str::trim) {
+            if !cursor.is_empty() && cursor.parse::<i32>().is_err() {
+                return Err(WebError::bad_request(format!(
+                    "Invalid pagination: malformed cursor '{}'",
+                    cursor
+                )));
+            }
+        }
+
         Ok(())
     }
 
@@ -286,7 +346,7 @@ impl ListQuery {
                 // Skip pagination and sort fields
                 if matches!(
                     field.as_str(),
-                    "_start" | "_end" | "page" | "limit" | "_sort" | "_order"
+                    "_start" | "_end" | "page" | "limit" | "_sort" | "_order" | "cursor"
                 ) {
                     return None;
                 }
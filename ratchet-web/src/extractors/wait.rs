@@ -0,0 +1,105 @@
+use axum::{
+    extract::{FromRequestParts, Query},
+    http::request::Parts,
+};
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::errors::WebError;
+
+/// Longest time a long-poll request may be held open
+pub const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// Raw `wait` query parameter
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WaitQuery {
+    /// Wait duration such as `30s`, `500ms`, `1m` or plain seconds (`30`)
+    pub wait: Option<String>,
+}
+
+impl WaitQuery {
+    /// Parse and validate the wait duration
+    pub fn to_duration(&self) -> Result<Option<Duration>, WebError> {
+        self.wait.as_deref().map(parse_wait).transpose()
+    }
+}
+
+/// Extract the optional long-poll `wait` duration from the query string
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WaitParams(pub Option<Duration>);
+
+impl<S> FromRequestParts<S> for WaitParams
+where
+    S: Send + Sync,
+{
+    type Rejection = WebError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Query(query) = Query::<WaitQuery>::from_request_parts(parts, state)
+            .await
+            .map_err(|err| WebError::bad_request(format!("Invalid query parameters: {}", err)))?;
+
+        Ok(WaitParams(query.to_duration()?))
+    }
+}
+
+/// Parse a wait duration, rejecting values above [`MAX_WAIT`]
+pub fn parse_wait(value: &str) -> Result<Duration, WebError> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| WebError::bad_request(format!("Invalid wait duration '{}'", value)))?;
+
+    let duration = match unit {
+        "ms" => Duration::from_millis(amount),
+        "" | "s" => Duration::from_secs(amount),
+        "m" => Duration::from_secs(amount.saturating_mul(60)),
+        _ => {
+            return Err(WebError::bad_request(format!(
+                "Invalid wait duration '{}', expected a unit of ms, s or m",
+                value
+            )))
+        }
+    };
+
+    if duration > MAX_WAIT {
+        return Err(WebError::bad_request(format!(
+            "Wait duration '{}' exceeds the maximum of {}s",
+            value,
+            MAX_WAIT.as_secs()
+        )));
+    }
+
+    Ok(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_wait("30").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_wait("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_wait("1m").unwrap(), Duration::from_secs(60));
+
+        assert!(parse_wait("").is_err());
+        assert!(parse_wait("soon").is_err());
+        assert!(parse_wait("10h").is_err());
+        assert!(parse_wait("-5s").is_err());
+        assert!(parse_wait("2m").is_err());
+    }
+
+    #[test]
+    fn test_wait_query_is_optional() {
+        assert_eq!(WaitQuery::default().to_duration().unwrap(), None);
+        let query = WaitQuery {
+            wait: Some("5s".to_string()),
+        };
+        assert_eq!(query.to_duration().unwrap(), Some(Duration::from_secs(5)));
+    }
+}
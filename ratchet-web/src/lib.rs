@@ -6,7 +6,7 @@
 //!
 //! ## Features
 //!
-//! - **Middleware**: CORS, error handling, rate limiting, request IDs, pagination
+//! - **Middleware**: CORS, error handling, rate limiting, request IDs, pagination, ETags
 //! - **Extractors**: Query parameter extraction with validation and filtering
 //! - **Utilities**: Response helpers, error conversion, and common patterns
 //!
@@ -47,8 +47,11 @@ pub mod utils;
 // Re-export commonly used types and functions
 pub use errors::{WebError, WebResult};
 pub use extractors::{
-    extract_execution_filters, extract_job_filters, extract_schedule_filters, extract_task_filters, FilterQuery,
-    PaginationQuery, QueryParams, SortQuery,
+    extract_execution_filters, extract_job_filters, extract_schedule_filters, extract_task_filters, CursorPage,
+    FilterQuery, PaginationQuery, QueryParams, SortQuery, WaitParams,
 };
-pub use middleware::{cors_layer, error_handler_layer, pagination_response_layer, rate_limit_layer, request_id_layer};
-pub use utils::{ApiResponse, ResponseBuilder};
+pub use middleware::{
+    body_limit_layer, compression_layer, cors_layer, error_handler_layer, pagination_response_layer, rate_limit_layer,
+    request_id_layer,
+};
+pub use utils::{long_poll, ApiResponse, ResponseBuilder};
@@ -69,6 +69,8 @@ pub struct AuthContext {
     pub session_id: String,
     /// Whether this is an authenticated request
     pub is_authenticated: bool,
+    /// ID of the API key the request authenticated with, if any
+    pub api_key_id: Option<String>,
 }
 
 impl Default for AuthContext {
@@ -78,6 +80,7 @@ impl Default for AuthContext {
             role: "guest".to_string(),
             session_id: "none".to_string(),
             is_authenticated: false,
+            api_key_id: None,
         }
     }
 }
@@ -90,9 +93,16 @@ impl AuthContext {
             role,
             session_id,
             is_authenticated: true,
+            api_key_id: None,
         }
     }
 
+    /// Record the API key the request authenticated with
+    pub fn with_api_key(mut self, api_key_id: String) -> Self {
+        self.api_key_id = Some(api_key_id);
+        self
+    }
+
     /// Check if user can perform admin operations
     pub fn can_admin(&self) -> bool {
         self.is_authenticated && self.role == "admin"
@@ -250,7 +260,9 @@ impl JwtManager {
     }
 
     /// Validate API key against database
-    async fn validate_api_key(&self, api_key: &str) -> Result<Option<(ApiId, String)>, WebError> {
+    ///
+    /// Returns the key's ID, its owner and the role its permissions map to.
+    async fn validate_api_key(&self, api_key: &str) -> Result<Option<(ApiId, ApiId, String)>, WebError> {
         if let Some(repositories) = &self.repositories {
             let api_key_repo = repositories.api_key_repository();
             let key_hash = self.hash_api_key(api_key);
@@ -277,7 +289,7 @@ impl JwtManager {
                         ratchet_api_types::ApiKeyPermissions::ExecuteOnly => "user",
                     };
 
-                    Ok(Some((api_key_record.user_id, role.to_string())))
+                    Ok(Some((api_key_record.id, api_key_record.user_id, role.to_string())))
                 }
                 Ok(None) => {
                     warn!("API key not found or inactive");
@@ -293,7 +305,11 @@ impl JwtManager {
             if api_key == "demo-api-key" {
                 debug!("API key authentication successful (fallback)");
                 // Use the same user ID format as the test expects
-                Ok(Some((ApiId::from_string("api-user"), "service".to_string())))
+                Ok(Some((
+                    ApiId::from_string("demo-api-key"),
+                    ApiId::from_string("api-user"),
+                    "service".to_string(),
+                )))
             } else {
                 Ok(None)
             }
@@ -301,12 +317,10 @@ impl JwtManager {
     }
 
     /// Authenticate a request
+    ///
+    /// Credentials are checked even when authentication isn't required, so
+    /// callers that present them are still identified (e.g. for rate limiting).
     pub async fn authenticate(&self, headers: &HeaderMap) -> Result<AuthContext, WebError> {
-        // If authentication is not required, allow anonymous access
-        if !self.config.require_auth {
-            return Ok(AuthContext::default());
-        }
-
         // Try JWT authentication first
         if let Some(token) = self.extract_token(headers) {
             match self.verify_token(&token) {
@@ -334,13 +348,14 @@ impl JwtManager {
         // Try API key authentication
         if let Some(api_key) = self.extract_api_key(headers) {
             match self.validate_api_key(&api_key).await {
-                Ok(Some((user_id, role))) => {
+                Ok(Some((key_id, user_id, role))) => {
                     debug!("API key authentication successful for user: {}", user_id);
                     return Ok(AuthContext::authenticated(
                         user_id.to_string(),
                         role,
                         uuid::Uuid::new_v4().to_string(), // Generate session ID for API key access
-                    ));
+                    )
+                    .with_api_key(key_id.to_string()));
                 }
                 Ok(None) => {
                     warn!("API key authentication failed: invalid or inactive key");
@@ -351,6 +366,11 @@ impl JwtManager {
             }
         }
 
+        // If authentication is not required, allow anonymous access
+        if !self.config.require_auth {
+            return Ok(AuthContext::default());
+        }
+
         // No valid authentication found
         Err(WebError::unauthorized("Authentication required"))
     }
@@ -375,14 +395,19 @@ pub async fn auth_middleware(headers: HeaderMap, mut request: Request, next: Nex
 }
 
 /// Optional authentication middleware (doesn't fail on missing auth)
+///
+/// An authenticated context replaces any set by an outer layer (e.g. session
+/// management); an anonymous one is only added when none is set.
 pub async fn optional_auth_middleware(headers: HeaderMap, mut request: Request, next: Next) -> Response {
     // Extract JWT manager from request extensions
-    if let Some(jwt_manager) = request.extensions().get::<Arc<JwtManager>>() {
+    if let Some(jwt_manager) = request.extensions().get::<Arc<JwtManager>>().cloned() {
         // Try to authenticate, but don't fail if it doesn't work
         let auth_context = jwt_manager.authenticate(&headers).await.unwrap_or_default();
 
         // Add auth context to request extensions
-        request.extensions_mut().insert(auth_context);
+        if auth_context.is_authenticated || request.extensions().get::<AuthContext>().is_none() {
+            request.extensions_mut().insert(auth_context);
+        }
     }
 
     // Continue with the request regardless
@@ -532,11 +557,17 @@ mod tests {
         let jwt_manager = JwtManager::new(config);
 
         // Empty headers
-        let headers = HeaderMap::new();
+        let mut headers = HeaderMap::new();
 
         // Should succeed with anonymous context
         let auth_context = jwt_manager.authenticate(&headers).await.unwrap();
         assert!(!auth_context.is_authenticated);
         assert_eq!(auth_context.user_id, "anonymous");
+
+        // Credentials are still honored when presented
+        headers.insert("X-API-Key", HeaderValue::from_str("demo-api-key").unwrap());
+        let auth_context = jwt_manager.authenticate(&headers).await.unwrap();
+        assert!(auth_context.is_authenticated);
+        assert_eq!(auth_context.api_key_id.as_deref(), Some("demo-api-key"));
     }
 }
@@ -0,0 +1,141 @@
+//! Request body size limits
+//!
+//! Requests that declare a `Content-Length` over the limit are rejected with
+//! `413 Payload Too Large` before any of the body is read. Bodies without a
+//! length (chunked uploads) are cut off at the limit while extractors buffer
+//! them, which axum also answers with `413`.
+
+use axum::{
+    extract::DefaultBodyLimit,
+    http::{header::CONTENT_LENGTH, HeaderMap, Request},
+    response::{IntoResponse, Response},
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+use crate::errors::WebError;
+
+pub use ratchet_config::domains::server::DEFAULT_MAX_BODY_BYTES;
+
+/// Limit request bodies to `max_bytes`
+///
+/// Apply it to the whole app for a global limit, and to a route group with a
+/// smaller value to tighten the limit there. An inner limit cannot raise an
+/// outer one, since the outer layer has already rejected the request.
+pub fn body_limit_layer(max_bytes: usize) -> BodyLimitLayer {
+    BodyLimitLayer { max_bytes }
+}
+
+/// Layer created by [`body_limit_layer`]
+#[derive(Debug, Clone, Copy)]
+pub struct BodyLimitLayer {
+    max_bytes: usize,
+}
+
+impl<S> Layer<S> for BodyLimitLayer {
+    type Service = BodyLimit<<DefaultBodyLimit as Layer<S>>::Service>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BodyLimit {
+            // Extractors enforce this limit while buffering bodies without a Content-Length
+            inner: DefaultBodyLimit::max(self.max_bytes).layer(inner),
+            max_bytes: self.max_bytes,
+        }
+    }
+}
+
+/// Service rejecting requests whose declared body exceeds the limit
+#[derive(Debug, Clone)]
+pub struct BodyLimit<S> {
+    inner: S,
+    max_bytes: usize,
+}
+
+impl<S, B> Service<Request<B>> for BodyLimit<S>
+where
+    S: Service<Request<B>, Response = Response>,
+    S::Future: Send + 'static,
+    S::Error: 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        match content_length(request.headers()) {
+            Some(length) if length > self.max_bytes as u64 => {
+                warn!(
+                    "Rejecting {} {} with a {} byte body (limit {})",
+                    request.method(),
+                    request.uri().path(),
+                    length,
+                    self.max_bytes
+                );
+                let response = WebError::payload_too_large(format!(
+                    "Request body of {} bytes exceeds the limit of {} bytes",
+                    length, self.max_bytes
+                ))
+                .into_response();
+                Box::pin(async move { Ok(response) })
+            }
+            _ => Box::pin(self.inner.call(request)),
+        }
+    }
+}
+
+fn content_length(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::StatusCode, routing::post, Router};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/echo", post(|body: String| async move { body }))
+            .layer(body_limit_layer(8))
+    }
+
+    #[tokio::test]
+    async fn test_body_within_limit_is_accepted() {
+        let request = Request::post("/echo").body(Body::from("12345678")).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_declared_length_over_limit_is_rejected() {
+        let request = Request::post("/echo")
+            .header(CONTENT_LENGTH, "9")
+            .body(Body::from("123456789"))
+            .unwrap();
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_undeclared_length_over_limit_is_rejected() {
+        // No Content-Length header, so the limit applies while the extractor reads the body
+        let request = Request::post("/echo").body(Body::from("123456789")).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
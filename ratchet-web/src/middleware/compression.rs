@@ -0,0 +1,323 @@
+//! Response compression
+//!
+//! Responses are compressed with the first algorithm in the configured
+//! preference order that the client accepts. Responses under the size
+//! threshold, images, gRPC and server-sent event streams are sent as is.
+//!
+//! The algorithm is picked by narrowing `Accept-Encoding` just for the
+//! compression layer; handlers and inner middleware see the client's header.
+
+use axum::http::{header::ACCEPT_ENCODING, HeaderValue, Request};
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{layer::util::Stack, Layer, Service};
+use tower_http::compression::{
+    predicate::{And, NotForContentType, Predicate, SizeAbove},
+    CompressionLayer,
+};
+
+/// Compression algorithms the server can use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionAlgorithm {
+    Brotli,
+    Gzip,
+}
+
+impl CompressionAlgorithm {
+    /// Token used for this algorithm in `Accept-Encoding` and `Content-Encoding`
+    pub fn token(&self) -> &'static str {
+        match self {
+            CompressionAlgorithm::Brotli => "br",
+            CompressionAlgorithm::Gzip => "gzip",
+        }
+    }
+}
+
+/// Response compression configuration
+#[derive(Debug, Clone)]
+pub struct CompressionConfig {
+    /// Algorithms to use, most preferred first
+    pub algorithms: Vec<CompressionAlgorithm>,
+    /// Responses with a known size below this many bytes are not compressed
+    pub min_size_bytes: u16,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            algorithms: vec![CompressionAlgorithm::Brotli, CompressionAlgorithm::Gzip],
+            min_size_bytes: 1024,
+        }
+    }
+}
+
+/// Predicate deciding which responses are compressed
+pub type CompressionPredicate = And<And<And<SizeAbove, NotForContentType>, NotForContentType>, NotForContentType>;
+
+/// Layer created by [`compression_layer`]
+pub type ResponseCompressionLayer =
+    Stack<RestoreAcceptEncodingLayer, Stack<CompressionLayer<CompressionPredicate>, PreferredEncodingLayer>>;
+
+/// Compress responses according to the request's `Accept-Encoding` header
+pub fn compression_layer(config: &CompressionConfig) -> ResponseCompressionLayer {
+    let predicate = SizeAbove::new(config.min_size_bytes)
+        .and(NotForContentType::GRPC)
+        .and(NotForContentType::IMAGES)
+        .and(NotForContentType::SSE);
+
+    let compression = CompressionLayer::new()
+        .br(config.algorithms.contains(&CompressionAlgorithm::Brotli))
+        .gzip(config.algorithms.contains(&CompressionAlgorithm::Gzip))
+        .compress_when(predicate);
+
+    Stack::new(
+        RestoreAcceptEncodingLayer,
+        Stack::new(
+            compression,
+            PreferredEncodingLayer {
+                preference: config.algorithms.clone().into(),
+            },
+        ),
+    )
+}
+
+/// The client's `Accept-Encoding`, kept while the narrowed header is in place
+#[derive(Debug, Clone)]
+struct OriginalAcceptEncoding(HeaderValue);
+
+/// Layer narrowing `Accept-Encoding` to the preferred algorithm the client accepts
+///
+/// Left alone, the client's quality values and tower-http's own ordering pick
+/// the algorithm; this makes the server's preference win among the accepted ones.
+/// [`RestoreAcceptEncodingLayer`] puts the client's header back afterwards.
+#[derive(Debug, Clone)]
+pub struct PreferredEncodingLayer {
+    preference: Arc<[CompressionAlgorithm]>,
+}
+
+impl<S> Layer<S> for PreferredEncodingLayer {
+    type Service = PreferredEncoding<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PreferredEncoding {
+            inner,
+            preference: self.preference.clone(),
+        }
+    }
+}
+
+/// Service created by [`PreferredEncodingLayer`]
+#[derive(Debug, Clone)]
+pub struct PreferredEncoding<S> {
+    inner: S,
+    preference: Arc<[CompressionAlgorithm]>,
+}
+
+impl<S, B> Service<Request<B>> for PreferredEncoding<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        let preferred = request
+            .headers()
+            .get(ACCEPT_ENCODING)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|accept_encoding| preferred_encoding(accept_encoding, &self.preference));
+
+        if let Some(algorithm) = preferred {
+            if let Some(original) = request
+                .headers_mut()
+                .insert(ACCEPT_ENCODING, HeaderValue::from_static(algorithm.token()))
+            {
+                request.extensions_mut().insert(OriginalAcceptEncoding(original));
+            }
+        }
+
+        self.inner.call(request)
+    }
+}
+
+/// Layer restoring the `Accept-Encoding` header narrowed by [`PreferredEncodingLayer`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RestoreAcceptEncodingLayer;
+
+impl<S> Layer<S> for RestoreAcceptEncodingLayer {
+    type Service = RestoreAcceptEncoding<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RestoreAcceptEncoding { inner }
+    }
+}
+
+/// Service created by [`RestoreAcceptEncodingLayer`]
+#[derive(Debug, Clone)]
+pub struct RestoreAcceptEncoding<S> {
+    inner: S,
+}
+
+impl<S, B> Service<Request<B>> for RestoreAcceptEncoding<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        if let Some(OriginalAcceptEncoding(original)) = request.extensions_mut().remove::<OriginalAcceptEncoding>() {
+            request.headers_mut().insert(ACCEPT_ENCODING, original);
+        }
+
+        self.inner.call(request)
+    }
+}
+
+/// First algorithm in `preference` that an `Accept-Encoding` value allows
+///
+/// An algorithm listed by name is allowed unless its `q` is 0. `*` only covers
+/// the algorithms that are not listed, so `br;q=0, *` still refuses Brotli.
+fn preferred_encoding(accept_encoding: &str, preference: &[CompressionAlgorithm]) -> Option<CompressionAlgorithm> {
+    // Each listed token with whether it is acceptable
+    let entries: Vec<(&str, bool)> = accept_encoding
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let token = parts.next()?.trim();
+            let refused = parts.any(|param| {
+                param
+                    .trim()
+                    .strip_prefix("q=")
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .is_some_and(|q| q <= 0.0)
+            });
+            Some((token, !refused))
+        })
+        .collect();
+    let wildcard = entries
+        .iter()
+        .find(|(token, _)| *token == "*")
+        .map(|(_, accepted)| *accepted);
+
+    preference.iter().copied().find(|algorithm| {
+        entries
+            .iter()
+            .find(|(token, _)| token.eq_ignore_ascii_case(algorithm.token()))
+            .map(|(_, accepted)| *accepted)
+            .or(wildcard)
+            .unwrap_or(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::header::CONTENT_ENCODING, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn app(config: &CompressionConfig) -> Router {
+        Router::new()
+            .route("/large", get(|| async { "x".repeat(4096) }))
+            .route("/small", get(|| async { "x" }))
+            .route(
+                "/echo",
+                get(|headers: axum::http::HeaderMap| async move {
+                    (
+                        [("x-accept-encoding", headers[ACCEPT_ENCODING].clone())],
+                        "x".repeat(4096),
+                    )
+                }),
+            )
+            .layer(compression_layer(config))
+    }
+
+    async fn content_encoding(config: &CompressionConfig, uri: &str, accept_encoding: &str) -> Option<String> {
+        let request = Request::get(uri)
+            .header(ACCEPT_ENCODING, accept_encoding)
+            .body(Body::empty())
+            .unwrap();
+        let response = app(config).oneshot(request).await.unwrap();
+        response
+            .headers()
+            .get(CONTENT_ENCODING)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_preferred_encoding() {
+        let preference = [CompressionAlgorithm::Brotli, CompressionAlgorithm::Gzip];
+
+        assert_eq!(
+            preferred_encoding("gzip, br", &preference),
+            Some(CompressionAlgorithm::Brotli)
+        );
+        assert_eq!(
+            preferred_encoding("gzip;q=1.0, br;q=0", &preference),
+            Some(CompressionAlgorithm::Gzip)
+        );
+        assert_eq!(preferred_encoding("*", &preference), Some(CompressionAlgorithm::Brotli));
+        assert_eq!(preferred_encoding("deflate", &preference), None);
+
+        // The wildcard does not bring back an algorithm refused by name
+        assert_eq!(
+            preferred_encoding("br;q=0, *", &preference),
+            Some(CompressionAlgorithm::Gzip)
+        );
+        assert_eq!(preferred_encoding("br;q=0, gzip;q=0, *", &preference), None);
+        assert_eq!(
+            preferred_encoding("gzip, *;q=0", &preference),
+            Some(CompressionAlgorithm::Gzip)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_large_responses_use_preferred_algorithm() {
+        let config = CompressionConfig::default();
+        assert_eq!(
+            content_encoding(&config, "/large", "gzip, br").await.as_deref(),
+            Some("br")
+        );
+
+        let config = CompressionConfig {
+            algorithms: vec![CompressionAlgorithm::Gzip, CompressionAlgorithm::Brotli],
+            ..CompressionConfig::default()
+        };
+        assert_eq!(
+            content_encoding(&config, "/large", "gzip, br").await.as_deref(),
+            Some("gzip")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_small_and_unaccepted_responses_are_not_compressed() {
+        let config = CompressionConfig::default();
+
+        assert_eq!(content_encoding(&config, "/small", "gzip, br").await, None);
+        assert_eq!(content_encoding(&config, "/large", "identity").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_handlers_see_the_clients_accept_encoding() {
+        let request = Request::get("/echo")
+            .header(ACCEPT_ENCODING, "identity;q=0.5, gzip")
+            .body(Body::empty())
+            .unwrap();
+        let response = app(&CompressionConfig::default()).oneshot(request).await.unwrap();
+
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+        assert_eq!(response.headers()["x-accept-encoding"], "identity;q=0.5, gzip");
+    }
+}
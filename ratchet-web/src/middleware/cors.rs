@@ -43,10 +43,16 @@ impl Default for CorsConfig {
                 "authorization".to_string(),
                 "accept".to_string(),
                 "x-requested-with".to_string(),
+                "idempotency-key".to_string(),
             ],
             expose_headers: vec![
                 "x-total-count".to_string(),
                 "content-range".to_string(),
+                "x-ratelimit-limit".to_string(),
+                "x-ratelimit-remaining".to_string(),
+                "x-ratelimit-reset".to_string(),
+                "retry-after".to_string(),
+                "idempotent-replayed".to_string(),
             ],
             allow_credentials: false,
             max_age: Some(Duration::from_secs(3600)), // 1 hour
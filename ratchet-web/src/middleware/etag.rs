@@ -0,0 +1,179 @@
+//! Entity tags and conditional GET handling
+//!
+//! Handlers that know a cheap version of what they return (an `updated_at`
+//! timestamp, or a response without volatile metadata) set the `ETag` header
+//! themselves. Any other successful GET gets a tag hashed from its body.
+//!
+//! All tags are weak: they are computed before response compression, so the
+//! same tag covers every content encoding of a representation.
+
+use axum::{
+    body::{Body, HttpBody},
+    http::{
+        header::{CONTENT_LENGTH, CONTENT_TYPE, ETAG, IF_NONE_MATCH},
+        HeaderValue, Method, Request, StatusCode,
+    },
+    middleware::Next,
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Responses larger than this are passed through without a body-derived ETag
+const MAX_HASHED_BODY_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Weak ETag from the SHA-256 of the given bytes
+pub fn hash_etag(bytes: &[u8]) -> String {
+    let digest = Sha256::digest(bytes);
+    let hex: String = digest.iter().take(16).map(|byte| format!("{:02x}", byte)).collect();
+    format!("W/\"{}\"", hex)
+}
+
+/// Weak ETag from the JSON serialization of a value
+///
+/// Handlers use this to tag the stable part of a response when the full body
+/// carries per-request metadata such as a generation timestamp.
+pub fn content_etag<T: Serialize>(value: &T) -> String {
+    hash_etag(&serde_json::to_vec(value).unwrap_or_default())
+}
+
+/// Weak ETag for a resource identified by its last modification time
+pub fn timestamp_etag(updated_at: DateTime<Utc>) -> String {
+    format!("W/\"{}\"", updated_at.timestamp_micros())
+}
+
+/// Whether an `If-None-Match` header value matches the given ETag
+///
+/// Uses the weak comparison RFC 9110 requires for `If-None-Match`.
+pub fn etag_matches(if_none_match: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+    if_none_match
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+/// Middleware adding ETags to GET responses and answering `If-None-Match` with `304 Not Modified`
+pub async fn etag_middleware(request: Request<Body>, next: Next) -> Response {
+    if request.method() != Method::GET && request.method() != Method::HEAD {
+        return next.run(request).await;
+    }
+
+    let if_none_match = request
+        .headers()
+        .get(IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let response = if response.headers().contains_key(ETAG) {
+        response
+    } else {
+        match response.body().size_hint().exact() {
+            Some(size) if size <= MAX_HASHED_BODY_BYTES => with_body_etag(response).await,
+            _ => return response,
+        }
+    };
+
+    let etag = response.headers().get(ETAG).and_then(|value| value.to_str().ok());
+    match (if_none_match, etag) {
+        (Some(if_none_match), Some(etag)) if etag_matches(&if_none_match, etag) => not_modified(response),
+        _ => response,
+    }
+}
+
+/// Buffer the response body and tag it with its hash
+async fn with_body_etag(response: Response) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, MAX_HASHED_BODY_BYTES as usize).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            parts.status = StatusCode::INTERNAL_SERVER_ERROR;
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    if let Ok(etag) = HeaderValue::from_str(&hash_etag(&bytes)) {
+        parts.headers.insert(ETAG, etag);
+    }
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Turn a matching response into a bodiless `304 Not Modified`, keeping its caching headers
+fn not_modified(response: Response) -> Response {
+    let (mut parts, _) = response.into_parts();
+    parts.status = StatusCode::NOT_MODIFIED;
+    parts.headers.remove(CONTENT_LENGTH);
+    parts.headers.remove(CONTENT_TYPE);
+    Response::from_parts(parts, Body::empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::header::HeaderMap;
+    use axum::response::IntoResponse;
+    use axum::{middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/items", get(|| async { "items" }))
+            .route(
+                "/tagged",
+                get(|| async {
+                    let mut headers = HeaderMap::new();
+                    headers.insert(ETAG, HeaderValue::from_static("W/\"42\""));
+                    (headers, "tagged").into_response()
+                }),
+            )
+            .layer(middleware::from_fn(etag_middleware))
+    }
+
+    async fn get_with(uri: &str, if_none_match: Option<&str>) -> Response {
+        let mut request = Request::builder().uri(uri);
+        if let Some(if_none_match) = if_none_match {
+            request = request.header(IF_NONE_MATCH, if_none_match);
+        }
+        app().oneshot(request.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_etag_from_body_hash() {
+        let response = get_with("/items", None).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], hash_etag(b"items").as_str());
+        assert!(hash_etag(b"items").starts_with("W/\""));
+    }
+
+    #[tokio::test]
+    async fn test_matching_if_none_match_returns_not_modified() {
+        let response = get_with("/items", Some(&hash_etag(b"items"))).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert!(axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let response = get_with("/items", Some("\"stale\"")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_handler_etag_is_kept() {
+        let response = get_with("/tagged", None).await;
+        assert_eq!(response.headers()[ETAG], "W/\"42\"");
+
+        // Weak comparison ignores the W/ prefix
+        let response = get_with("/tagged", Some("\"other\", \"42\"")).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], "W/\"42\"");
+    }
+}
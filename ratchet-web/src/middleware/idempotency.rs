@@ -0,0 +1,571 @@
+//! `Idempotency-Key` support for POST endpoints
+//!
+//! A POST carrying an `Idempotency-Key` header is run once. Its successful
+//! response is cached for the configured TTL and replayed, with an
+//! `Idempotent-Replayed: true` header, for any retry that sends the same key
+//! and the same request. Failed responses are not cached, so the client can
+//! retry them. A successful response too large to store is remembered as
+//! done, so a retry is refused rather than run again.
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::ConnectInfo,
+    http::{
+        header::{CONTENT_TYPE, LOCATION},
+        HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use hyper::body::Frame;
+use ratchet_caching::{Cache, CacheRegistry, MokaCache};
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+use crate::errors::WebError;
+use crate::middleware::{hash_etag, AuthContext, DEFAULT_MAX_BODY_BYTES};
+
+/// Request header carrying the client's idempotency key
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Response header marking a replayed response
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Longest idempotency key accepted
+const MAX_KEY_LENGTH: usize = 255;
+
+/// Response headers kept when a response is stored for replay
+const REPLAYED_HEADERS: [HeaderName; 2] = [CONTENT_TYPE, LOCATION];
+
+/// Idempotency configuration
+#[derive(Debug, Clone)]
+pub struct IdempotencyConfig {
+    /// How long a key and its response are remembered
+    pub ttl: Duration,
+    /// Maximum number of keys remembered at once
+    pub max_entries: u64,
+    /// Largest request or response body buffered for a keyed request
+    ///
+    /// Keep this at least the server's request body limit, or keyed requests
+    /// the limit allows are rejected here.
+    pub max_body_bytes: usize,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(24 * 60 * 60),
+            max_entries: 10_000,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        }
+    }
+}
+
+/// State of a key
+#[derive(Debug, Clone)]
+enum IdempotentEntry {
+    /// The first request with this key is still running
+    InProgress { fingerprint: String },
+    /// The first request succeeded with this response
+    Completed {
+        fingerprint: String,
+        response: StoredResponse,
+    },
+    /// The first request succeeded, but its response was too large to store
+    CompletedUnstored { fingerprint: String },
+}
+
+impl IdempotentEntry {
+    fn fingerprint(&self) -> &str {
+        match self {
+            IdempotentEntry::InProgress { fingerprint }
+            | IdempotentEntry::Completed { fingerprint, .. }
+            | IdempotentEntry::CompletedUnstored { fingerprint } => fingerprint,
+        }
+    }
+}
+
+/// Response kept for replay
+#[derive(Debug, Clone)]
+struct StoredResponse {
+    status: StatusCode,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    body: Bytes,
+}
+
+impl StoredResponse {
+    fn replay(&self) -> Response {
+        let mut response = Response::new(Body::from(self.body.clone()));
+        *response.status_mut() = self.status;
+        let headers = response.headers_mut();
+        for (name, value) in &self.headers {
+            headers.insert(name.clone(), value.clone());
+        }
+        headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+        response
+    }
+}
+
+/// Remembers idempotency keys and the responses they produced
+pub struct IdempotencyStore {
+    config: IdempotencyConfig,
+    entries: Arc<dyn Cache<String, IdempotentEntry>>,
+    /// Serializes the check-then-claim step so two requests cannot both claim a key
+    claim_lock: Mutex<()>,
+}
+
+impl IdempotencyStore {
+    pub fn new(config: IdempotencyConfig) -> Self {
+        let entries = MokaCache::builder()
+            .max_capacity(config.max_entries)
+            .time_to_live(config.ttl)
+            .build();
+        Self {
+            config,
+            entries: Arc::new(entries),
+            claim_lock: Mutex::new(()),
+        }
+    }
+
+    /// Make the remembered keys inspectable and flushable through `registry` as `name`
+    pub fn register_cache(&self, registry: &CacheRegistry, name: &str) {
+        registry.register::<String, IdempotentEntry, dyn Cache<String, IdempotentEntry>>(name, self.entries.clone());
+    }
+
+    /// Claim `key` for a request, or return what should be sent instead of running it
+    async fn claim(&self, key: &str, fingerprint: &str) -> Result<(), Response> {
+        let _guard = self.claim_lock.lock().await;
+
+        match self.entries.get(&key.to_string()).await {
+            Ok(Some(entry)) if entry.fingerprint() != fingerprint => {
+                Err(WebError::bad_request("Idempotency-Key was already used for a different request").into_response())
+            }
+            Ok(Some(IdempotentEntry::InProgress { .. })) => {
+                Err(WebError::conflict("A request with this Idempotency-Key is still being processed").into_response())
+            }
+            Ok(Some(IdempotentEntry::Completed { response, .. })) => {
+                debug!("Replaying response for idempotency key {}", key);
+                Err(response.replay())
+            }
+            Ok(Some(IdempotentEntry::CompletedUnstored { .. })) => Err(WebError::conflict(
+                "A request with this Idempotency-Key already succeeded; its response was too large to replay",
+            )
+            .into_response()),
+            Ok(None) => {
+                let entry = IdempotentEntry::InProgress {
+                    fingerprint: fingerprint.to_string(),
+                };
+                if let Err(e) = self.entries.put(key.to_string(), entry).await {
+                    warn!("Failed to record idempotency key: {}", e);
+                }
+                Ok(())
+            }
+            Err(e) => {
+                // Without the store the request runs as if it had no key
+                warn!("Idempotency store lookup failed: {}", e);
+                Ok(())
+            }
+        }
+    }
+
+    async fn complete(&self, key: &str, entry: IdempotentEntry) {
+        if let Err(e) = self.entries.put(key.to_string(), entry).await {
+            warn!("Failed to store response for idempotency key: {}", e);
+        }
+    }
+
+    async fn release(&self, key: &str) {
+        if let Err(e) = self.entries.remove(&key.to_string()).await {
+            warn!("Failed to release idempotency key: {}", e);
+        }
+    }
+}
+
+/// A claimed key, released unless the request it guards completes
+///
+/// Dropping the guard releases the key, so a client that disconnects or a
+/// handler that panics doesn't leave it claimed until the TTL expires.
+struct ClaimGuard {
+    store: Arc<IdempotencyStore>,
+    key: Option<String>,
+}
+
+impl ClaimGuard {
+    /// Record the outcome of the request, keeping the key claimed
+    async fn complete(mut self, entry: IdempotentEntry) {
+        if let Some(key) = self.key.take() {
+            self.store.complete(&key, entry).await;
+        }
+    }
+
+    /// Release the key so the request can be retried
+    async fn release(mut self) {
+        if let Some(key) = self.key.take() {
+            self.store.release(&key).await;
+        }
+    }
+}
+
+impl Drop for ClaimGuard {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            let store = self.store.clone();
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => {
+                    runtime.spawn(async move { store.release(&key).await });
+                }
+                Err(_) => warn!("Idempotency key {} stays claimed until it expires", key),
+            }
+        }
+    }
+}
+
+/// Create the store shared by the idempotency middleware
+pub fn create_idempotency_store(config: IdempotencyConfig) -> Arc<IdempotencyStore> {
+    Arc::new(IdempotencyStore::new(config))
+}
+
+/// Idempotency middleware
+///
+/// Expects an `Arc<IdempotencyStore>` in the request extensions; requests
+/// pass through untouched when there is none. Keys are scoped to the
+/// authenticated user, or to the client IP for anonymous requests, so
+/// clients cannot replay each other's responses. Anonymous requests without
+/// `ConnectInfo` can't be scoped and run as if they had no key.
+pub async fn idempotency_middleware(request: Request<Body>, next: Next) -> Response {
+    if request.method() != Method::POST {
+        return next.run(request).await;
+    }
+    let Some(store) = request.extensions().get::<Arc<IdempotencyStore>>().cloned() else {
+        return next.run(request).await;
+    };
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+
+    let key = match key.to_str() {
+        Ok(key) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => key.to_string(),
+        _ => {
+            return WebError::bad_request(format!(
+                "Idempotency-Key must be 1 to {} visible ASCII characters",
+                MAX_KEY_LENGTH
+            ))
+            .into_response()
+        }
+    };
+    let Some(scope) = key_scope(&request) else {
+        debug!("Ignoring Idempotency-Key of an anonymous request without a client address");
+        return next.run(request).await;
+    };
+    let key = format!("{}:{}", scope, key);
+
+    // Buffer the body so the retry can be checked against the original request
+    let (parts, body) = request.into_parts();
+    let body = match axum::body::to_bytes(body, store.config.max_body_bytes).await {
+        Ok(body) => body,
+        Err(_) => {
+            return WebError::payload_too_large(format!(
+                "Request body exceeds the limit of {} bytes",
+                store.config.max_body_bytes
+            ))
+            .into_response()
+        }
+    };
+    let fingerprint = request_fingerprint(&parts.method, parts.uri.path(), &body);
+
+    if let Err(response) = store.claim(&key, &fingerprint).await {
+        return response;
+    }
+    let guard = ClaimGuard {
+        store: store.clone(),
+        key: Some(key),
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if !response.status().is_success() {
+        guard.release().await;
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match buffer_body(body, store.config.max_body_bytes).await {
+        Ok(Buffered::Complete(body)) => body,
+        Ok(Buffered::TooLarge(body)) => {
+            warn!("Response too large to store for idempotency key replay");
+            guard.complete(IdempotentEntry::CompletedUnstored { fingerprint }).await;
+            return Response::from_parts(parts, body);
+        }
+        Err(e) => {
+            warn!("Failed to buffer response for idempotency key: {}", e);
+            guard.release().await;
+            return WebError::internal("Failed to read response body").into_response();
+        }
+    };
+
+    guard
+        .complete(IdempotentEntry::Completed {
+            fingerprint,
+            response: StoredResponse {
+                status: parts.status,
+                headers: replayed_headers(&parts.headers),
+                body: body.clone(),
+            },
+        })
+        .await;
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Whose keys a request's key is kept among: its user, else its client IP
+fn key_scope(request: &Request<Body>) -> Option<String> {
+    if let Some(auth) = request
+        .extensions()
+        .get::<AuthContext>()
+        .filter(|auth| auth.is_authenticated)
+    {
+        return Some(format!("user:{}", auth.user_id));
+    }
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| format!("ip:{}", addr.ip()))
+}
+
+/// Outcome of buffering a response body
+enum Buffered {
+    /// The whole body
+    Complete(Bytes),
+    /// The body was over the limit; this replays what was read, then the rest
+    TooLarge(Body),
+}
+
+/// Buffer up to `limit` bytes of `body`
+async fn buffer_body(mut body: Body, limit: usize) -> Result<Buffered, axum::Error> {
+    let mut buffered = Vec::new();
+    while let Some(frame) = std::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await {
+        if let Ok(data) = frame?.into_data() {
+            buffered.extend_from_slice(&data);
+            if buffered.len() > limit {
+                return Ok(Buffered::TooLarge(Body::new(PrefixedBody {
+                    prefix: Some(Bytes::from(buffered)),
+                    rest: body,
+                })));
+            }
+        }
+    }
+    Ok(Buffered::Complete(Bytes::from(buffered)))
+}
+
+/// Body whose first bytes were already read from `rest`
+struct PrefixedBody {
+    prefix: Option<Bytes>,
+    rest: Body,
+}
+
+impl HttpBody for PrefixedBody {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_frame(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Frame<Bytes>, axum::Error>>> {
+        if let Some(prefix) = self.prefix.take() {
+            return Poll::Ready(Some(Ok(Frame::data(prefix))));
+        }
+        Pin::new(&mut self.rest).poll_frame(cx)
+    }
+}
+
+/// Identify a request by what it asks for, so a reused key can be told apart from a retry
+fn request_fingerprint(method: &Method, path: &str, body: &[u8]) -> String {
+    let mut bytes = Vec::with_capacity(method.as_str().len() + path.len() + body.len() + 2);
+    bytes.extend_from_slice(method.as_str().as_bytes());
+    bytes.push(b' ');
+    bytes.extend_from_slice(path.as_bytes());
+    bytes.push(b'\n');
+    bytes.extend_from_slice(body);
+    hash_etag(&bytes)
+}
+
+fn replayed_headers(headers: &HeaderMap) -> Vec<(HeaderName, HeaderValue)> {
+    REPLAYED_HEADERS
+        .iter()
+        .filter_map(|name| headers.get(name).map(|value| (name.clone(), value.clone())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::post, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::ServiceExt;
+
+    fn app(calls: Arc<AtomicUsize>) -> Router {
+        app_with_config(calls, IdempotencyConfig::default())
+    }
+
+    /// The first call to `/slow` never finishes; later ones do
+    fn app_with_config(calls: Arc<AtomicUsize>, config: IdempotencyConfig) -> Router {
+        let store = create_idempotency_store(config);
+        let slow_calls = calls.clone();
+        Router::new()
+            .route(
+                "/jobs",
+                post(move |body: String| async move {
+                    let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                    (StatusCode::CREATED, format!("job {} for {}", call, body))
+                }),
+            )
+            .route(
+                "/slow",
+                post(move || async move {
+                    if slow_calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                        std::future::pending::<()>().await;
+                    }
+                    StatusCode::CREATED
+                }),
+            )
+            .layer(axum::middleware::from_fn(idempotency_middleware))
+            .layer(axum::middleware::from_fn(move |mut req: Request<Body>, next: Next| {
+                req.extensions_mut().insert(store.clone());
+                next.run(req)
+            }))
+    }
+
+    fn post_job(key: Option<&str>, body: &'static str) -> Request<Body> {
+        post_from("/jobs", key, body, [10, 0, 0, 1])
+    }
+
+    fn post_from(uri: &str, key: Option<&str>, body: &'static str, ip: [u8; 4]) -> Request<Body> {
+        let mut request = Request::post(uri).extension(ConnectInfo(SocketAddr::from((ip, 8080))));
+        if let Some(key) = key {
+            request = request.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+        request.body(Body::from(body)).unwrap()
+    }
+
+    async fn body_text(response: Response) -> String {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_same_key_replays_response() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(calls.clone());
+
+        let first = app.clone().oneshot(post_job(Some("abc"), "a")).await.unwrap();
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert!(first.headers().get(IDEMPOTENT_REPLAYED_HEADER).is_none());
+        assert_eq!(body_text(first).await, "job 1 for a");
+
+        let retry = app.clone().oneshot(post_job(Some("abc"), "a")).await.unwrap();
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(retry.headers()[IDEMPOTENT_REPLAYED_HEADER], "true");
+        assert_eq!(body_text(retry).await, "job 1 for a");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Without a key every request runs
+        app.clone().oneshot(post_job(None, "a")).await.unwrap();
+        app.oneshot(post_job(None, "a")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_for_different_request_is_rejected() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(calls.clone());
+
+        app.clone().oneshot(post_job(Some("abc"), "a")).await.unwrap();
+        let response = app.oneshot(post_job(Some("abc"), "b")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_keys_are_scoped_to_the_client_address() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(calls.clone());
+
+        app.clone()
+            .oneshot(post_from("/jobs", Some("abc"), "a", [10, 0, 0, 1]))
+            .await
+            .unwrap();
+        let other_client = app
+            .clone()
+            .oneshot(post_from("/jobs", Some("abc"), "a", [10, 0, 0, 2]))
+            .await
+            .unwrap();
+
+        assert!(other_client.headers().get(IDEMPOTENT_REPLAYED_HEADER).is_none());
+        assert_eq!(body_text(other_client).await, "job 2 for a");
+
+        // Without an address there is nothing to scope the key to, so it is ignored
+        let unscoped = || {
+            Request::post("/jobs")
+                .header(IDEMPOTENCY_KEY_HEADER, "abc")
+                .body(Body::from("a"))
+                .unwrap()
+        };
+        app.clone().oneshot(unscoped()).await.unwrap();
+        app.oneshot(unscoped()).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_request_releases_its_key() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(calls.clone());
+
+        // The client gives up on the first request
+        let abandoned = app.clone().oneshot(post_from("/slow", Some("abc"), "", [10, 0, 0, 1]));
+        assert!(tokio::time::timeout(Duration::from_millis(50), abandoned)
+            .await
+            .is_err());
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let retry = app
+            .oneshot(post_from("/slow", Some("abc"), "", [10, 0, 0, 1]))
+            .await
+            .unwrap();
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_response_is_not_run_again() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let config = IdempotencyConfig {
+            max_body_bytes: 8,
+            ..IdempotencyConfig::default()
+        };
+        let app = app_with_config(calls.clone(), config);
+
+        let first = app.clone().oneshot(post_job(Some("abc"), "a")).await.unwrap();
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert_eq!(body_text(first).await, "job 1 for a");
+
+        let retry = app.oneshot(post_job(Some("abc"), "a")).await.unwrap();
+        assert_eq!(retry.status(), StatusCode::CONFLICT);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_registered_keys_can_be_flushed() {
+        let store = IdempotencyStore::new(IdempotencyConfig::default());
+        let registry = CacheRegistry::new();
+        store.register_cache(&registry, "idempotency_keys");
+        assert_eq!(registry.names(), vec!["idempotency_keys"]);
+
+        store.claim("abc", "fingerprint").await.unwrap();
+        assert!(store.claim("abc", "fingerprint").await.is_err());
+
+        assert!(registry.flush_key("idempotency_keys", "abc").await.unwrap());
+        assert!(store.claim("abc", "fingerprint").await.is_ok());
+    }
+}
@@ -1,7 +1,11 @@
 pub mod audit;
 pub mod auth;
+pub mod body_limit;
+pub mod compression;
 pub mod cors;
 pub mod error_handler;
+pub mod etag;
+pub mod idempotency;
 pub mod pagination;
 pub mod rate_limit;
 pub mod request_id;
@@ -17,12 +21,22 @@ pub use auth::{
     auth_layer, auth_middleware, optional_auth_middleware, require_admin, require_auth, require_write, AuthConfig,
     AuthContext, JwtClaims, JwtManager,
 };
+pub use body_limit::{body_limit_layer, BodyLimit, BodyLimitLayer, DEFAULT_MAX_BODY_BYTES};
+pub use compression::{
+    compression_layer, CompressionAlgorithm, CompressionConfig, CompressionPredicate, PreferredEncoding,
+    PreferredEncodingLayer, ResponseCompressionLayer, RestoreAcceptEncoding, RestoreAcceptEncodingLayer,
+};
 pub use cors::cors_layer;
 pub use error_handler::{error_handler_layer, handle_error, handle_not_found, internal_error};
+pub use etag::{content_etag, etag_matches, etag_middleware, hash_etag, timestamp_etag};
+pub use idempotency::{
+    create_idempotency_store, idempotency_middleware, IdempotencyConfig, IdempotencyStore, IDEMPOTENCY_KEY_HEADER,
+    IDEMPOTENT_REPLAYED_HEADER,
+};
 pub use pagination::{add_pagination_headers, pagination_response_layer};
 pub use rate_limit::{
-    create_rate_limit_middleware, rate_limit_layer, rate_limit_middleware, ClientStats, RateLimitConfig,
-    RateLimitQuota, RateLimiter, UserQuotas,
+    create_rate_limit_middleware, rate_limit_layer, rate_limit_middleware, ClientStats, RateLimitConfig, RateLimitKey,
+    RateLimitQuota, RateLimitStatus, RateLimiter, UserQuotas,
 };
 pub use request_id::{request_id_layer, RequestId, RequestIdExt, REQUEST_ID_HEADER};
 pub use security::{security_headers_layer, security_headers_middleware, SecurityConfig, TlsConfig, TlsProtocol};
@@ -1,6 +1,14 @@
-use axum::{extract::ConnectInfo, http::Request, middleware::Next, response::Response};
+use async_trait::async_trait;
+use axum::{
+    extract::ConnectInfo,
+    http::{HeaderMap, HeaderValue, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
 use chrono::{DateTime, Utc};
 use lru::LruCache;
+use ratchet_caching::{CacheRegistry, CacheResult, CacheStats, ManagedCache};
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
@@ -58,6 +66,29 @@ impl Default for UserQuotas {
     }
 }
 
+impl UserQuotas {
+    /// Quotas built from an anonymous (per-IP) rate and burst size
+    ///
+    /// The other roles keep their default multiples of the anonymous quota
+    /// and their default daily limits.
+    pub fn scaled_from_anonymous(requests_per_minute: u32, burst_size: u32) -> Self {
+        let defaults = Self::default();
+        let scaled = |factor: u32, daily_limit: Option<u32>| RateLimitQuota {
+            requests_per_minute: requests_per_minute.saturating_mul(factor),
+            burst_size: burst_size.saturating_mul(factor),
+            daily_limit,
+        };
+
+        Self {
+            anonymous: scaled(1, defaults.anonymous.daily_limit),
+            user: scaled(4, defaults.user.daily_limit),
+            admin: scaled(10, defaults.admin.daily_limit),
+            readonly: scaled(2, defaults.readonly.daily_limit),
+            service: scaled(20, defaults.service.daily_limit),
+        }
+    }
+}
+
 /// Individual rate limit quota
 #[derive(Debug, Clone)]
 pub struct RateLimitQuota {
@@ -66,6 +97,18 @@ pub struct RateLimitQuota {
     pub daily_limit: Option<u32>,
 }
 
+/// What the rate limiter counts requests against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitKey {
+    /// The authenticated API key, else the user, else the client IP
+    ///
+    /// Use this behind a load balancer, where many clients share an IP.
+    #[default]
+    Identity,
+    /// The client IP, whoever is authenticated
+    Ip,
+}
+
 /// Rate limit configuration
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
@@ -73,7 +116,16 @@ pub struct RateLimitConfig {
     pub enabled: bool,
     /// User-based quotas
     pub quotas: UserQuotas,
-    /// Window size for rate limiting
+    /// How requests are grouped into clients
+    pub key_by: RateLimitKey,
+    /// Quotas for individual clients, overriding their role quota
+    ///
+    /// Keyed by client ID: `key:<api key id>`, `user:<user id>` or `ip:<address>`.
+    pub client_quotas: HashMap<String, RateLimitQuota>,
+    /// Window the configured rate was expressed over
+    ///
+    /// Quotas are always per minute; this records the window they were
+    /// converted from.
     pub window_size: Duration,
     /// Cleanup interval for old client data
     pub cleanup_interval: Duration,
@@ -86,6 +138,8 @@ impl Default for RateLimitConfig {
         Self {
             enabled: true,
             quotas: UserQuotas::default(),
+            key_by: RateLimitKey::default(),
+            client_quotas: HashMap::new(),
             window_size: Duration::from_secs(60),
             cleanup_interval: Duration::from_secs(300), // 5 minutes
             max_clients: 10000,
@@ -160,6 +214,12 @@ impl RateLimitConfig {
         config
     }
 
+    /// Give one client its own quota
+    pub fn with_client_quota(mut self, client_id: impl Into<String>, quota: RateLimitQuota) -> Self {
+        self.client_quotas.insert(client_id.into(), quota);
+        self
+    }
+
     /// Disable rate limiting entirely
     pub fn disabled() -> Self {
         let mut config = Self::default();
@@ -219,6 +279,16 @@ impl TokenBucket {
         cloned.tokens
     }
 
+    /// Time until the bucket is full again
+    fn time_until_full(&self) -> Duration {
+        let missing = self.max_tokens - self.remaining_tokens_immutable();
+        if missing <= 0.0 || self.refill_rate <= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(missing / self.refill_rate)
+        }
+    }
+
     fn time_until_available(&mut self) -> Duration {
         self.refill();
 
@@ -274,6 +344,11 @@ impl DailyUsage {
         self.requests += 1;
     }
 
+    fn time_until_reset(&self) -> Duration {
+        let tomorrow = self.date + chrono::Duration::days(1);
+        (tomorrow - Utc::now()).to_std().unwrap_or_default()
+    }
+
     fn can_make_request(&mut self, daily_limit: Option<u32>) -> bool {
         self.reset_if_new_day();
         match daily_limit {
@@ -324,6 +399,75 @@ impl ClientInfo {
     fn record_blocked(&mut self) {
         self.blocked_requests += 1;
     }
+
+    /// Limiter state as reported to the client
+    fn status(&self, quota: &RateLimitQuota) -> RateLimitStatus {
+        let daily_remaining = quota.daily_limit.map(|limit| {
+            if self.daily_usage.is_today() {
+                limit.saturating_sub(self.daily_usage.requests)
+            } else {
+                limit
+            }
+        });
+
+        if daily_remaining == Some(0) {
+            return RateLimitStatus {
+                limit: quota.burst_size,
+                remaining: 0,
+                reset: self.daily_usage.time_until_reset(),
+            };
+        }
+
+        let bucket_remaining = self.bucket.remaining_tokens_immutable().floor() as u32;
+        RateLimitStatus {
+            limit: quota.burst_size,
+            remaining: bucket_remaining.min(daily_remaining.unwrap_or(u32::MAX)),
+            reset: self.bucket.time_until_full(),
+        }
+    }
+}
+
+/// Rate limit state sent to clients in `X-RateLimit-*` headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Requests allowed in a burst
+    pub limit: u32,
+    /// Requests left before the client is limited
+    pub remaining: u32,
+    /// Time until the full limit is available again
+    pub reset: Duration,
+}
+
+impl RateLimitStatus {
+    pub const LIMIT_HEADER: &'static str = "x-ratelimit-limit";
+    pub const REMAINING_HEADER: &'static str = "x-ratelimit-remaining";
+    pub const RESET_HEADER: &'static str = "x-ratelimit-reset";
+
+    /// Add the rate limit headers, with the reset time in whole seconds
+    pub fn apply_headers(&self, headers: &mut HeaderMap) {
+        let reset = self.reset.as_secs() + u64::from(self.reset.subsec_nanos() > 0);
+        headers.insert(Self::LIMIT_HEADER, HeaderValue::from(self.limit));
+        headers.insert(Self::REMAINING_HEADER, HeaderValue::from(self.remaining));
+        headers.insert(Self::RESET_HEADER, HeaderValue::from(reset));
+    }
+}
+
+/// A request rejected by the rate limiter
+#[derive(Debug, Clone, Copy)]
+struct RateLimitRejection {
+    status: RateLimitStatus,
+    retry_after: Duration,
+}
+
+impl IntoResponse for RateLimitRejection {
+    fn into_response(self) -> Response {
+        let mut response = WebError::RateLimit.into_response();
+        let headers = response.headers_mut();
+        self.status.apply_headers(headers);
+        let retry_after = self.retry_after.as_secs() + u64::from(self.retry_after.subsec_nanos() > 0);
+        headers.insert(axum::http::header::RETRY_AFTER, HeaderValue::from(retry_after));
+        response
+    }
 }
 
 /// Rate limiter implementation
@@ -341,12 +485,19 @@ impl RateLimiter {
         }
     }
 
-    async fn check_rate_limit(&self, client_type: ClientType, client_id: &str) -> Result<(), WebError> {
+    /// Consume one request from the client's quota
+    ///
+    /// Returns the remaining quota, or `None` when rate limiting is disabled.
+    async fn check_rate_limit(
+        &self,
+        client_type: ClientType,
+        client_id: &str,
+    ) -> Result<Option<RateLimitStatus>, RateLimitRejection> {
         if !self.config.enabled {
-            return Ok(());
+            return Ok(None);
         }
 
-        let quota = self.get_quota_for_client(&client_type);
+        let quota = self.get_quota_for_client(&client_type, client_id);
         let mut clients = self.clients.write().await;
 
         let client_info =
@@ -359,7 +510,11 @@ impl RateLimiter {
                 "Daily limit exceeded for client: {} (type: {:?})",
                 client_id, client_type
             );
-            return Err(WebError::RateLimit);
+            let status = client_info.status(quota);
+            return Err(RateLimitRejection {
+                status,
+                retry_after: status.reset,
+            });
         }
 
         // Check rate limit (burst + per-minute)
@@ -368,7 +523,7 @@ impl RateLimiter {
                 "Rate limit check passed for client: {} (type: {:?})",
                 client_id, client_type
             );
-            Ok(())
+            Ok(Some(client_info.status(quota)))
         } else {
             client_info.record_blocked();
             let retry_after = client_info.bucket.time_until_available();
@@ -378,11 +533,18 @@ impl RateLimiter {
                 client_id, client_type, retry_after
             );
 
-            Err(WebError::RateLimit)
+            Err(RateLimitRejection {
+                status: client_info.status(quota),
+                retry_after,
+            })
         }
     }
 
-    fn get_quota_for_client(&self, client_type: &ClientType) -> &RateLimitQuota {
+    fn get_quota_for_client(&self, client_type: &ClientType, client_id: &str) -> &RateLimitQuota {
+        if let Some(quota) = self.config.client_quotas.get(client_id) {
+            return quota;
+        }
+
         match client_type {
             ClientType::Anonymous => &self.config.quotas.anonymous,
             ClientType::User(_) => &self.config.quotas.user,
@@ -406,19 +568,32 @@ impl RateLimiter {
                     "service" => ClientType::Service(auth.user_id.clone()),
                     _ => ClientType::User(auth.user_id.clone()),
                 };
-                let client_id = format!("user:{}", auth.user_id);
+                let client_id = match (self.config.key_by, &auth.api_key_id) {
+                    (RateLimitKey::Ip, _) => Self::ip_client_id(connect_info),
+                    (RateLimitKey::Identity, Some(api_key_id)) => format!("key:{}", api_key_id),
+                    (RateLimitKey::Identity, None) => format!("user:{}", auth.user_id),
+                };
                 return (client_type, client_id);
             }
         }
 
         // Fall back to IP address for anonymous users
-        let client_id = if let Some(ConnectInfo(addr)) = connect_info {
+        (ClientType::Anonymous, Self::ip_client_id(connect_info))
+    }
+
+    fn ip_client_id(connect_info: Option<&ConnectInfo<SocketAddr>>) -> String {
+        if let Some(ConnectInfo(addr)) = connect_info {
             format!("ip:{}", addr.ip())
         } else {
             "unknown".to_string()
-        };
+        }
+    }
 
-        (ClientType::Anonymous, client_id)
+    /// Make the tracked clients inspectable through `registry` as `name`
+    ///
+    /// Flushing a client forgets its usage, restoring its full quota.
+    pub fn register_cache(self: &Arc<Self>, registry: &CacheRegistry, name: &str) {
+        registry.register_managed(name, self.clone());
     }
 
     /// Get rate limit statistics for a client
@@ -435,6 +610,24 @@ impl RateLimiter {
     }
 }
 
+#[async_trait]
+impl ManagedCache for RateLimiter {
+    async fn stats(&self) -> CacheResult<CacheStats> {
+        let mut stats = CacheStats::new();
+        stats.entry_count = self.clients.read().await.len();
+        Ok(stats)
+    }
+
+    async fn clear(&self) -> CacheResult<()> {
+        self.clients.write().await.clear();
+        Ok(())
+    }
+
+    async fn remove_key(&self, key: &str) -> CacheResult<bool> {
+        Ok(self.clients.write().await.pop(key).is_some())
+    }
+}
+
 /// Rate limit statistics for monitoring
 #[derive(Debug, Clone)]
 pub struct ClientStats {
@@ -447,6 +640,10 @@ pub struct ClientStats {
 }
 
 /// Rate limiting middleware
+///
+/// Responses carry `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+/// `X-RateLimit-Reset` headers so clients can throttle themselves; rejected
+/// requests also get `Retry-After`.
 pub async fn rate_limit_middleware(
     connect_info: Option<ConnectInfo<SocketAddr>>,
     request: Request<axum::body::Body>,
@@ -464,30 +661,37 @@ pub async fn rate_limit_middleware(
     let (client_type, client_id) = rate_limiter.extract_client_info(auth_context, connect_info.as_ref());
 
     // Check rate limit
-    if let Err(err) = rate_limiter.check_rate_limit(client_type.clone(), &client_id).await {
-        // Log security event for rate limit violations
-        if let Some(audit_config) = request.extensions().get::<crate::middleware::AuditConfig>() {
-            let logger = TracingAuditLogger::new(audit_config.clone());
-            let mut event = AuditEvent::new(
-                AuditEventType::RateLimitExceeded,
-                AuditSeverity::Warning,
-                format!("Rate limit exceeded for client {} (type: {:?})", client_id, client_type),
-            );
-
-            if let Some(auth) = auth_context {
-                if auth.is_authenticated {
-                    event = event.with_user(auth.user_id.clone(), Some(auth.session_id.clone()));
+    let status = match rate_limiter.check_rate_limit(client_type.clone(), &client_id).await {
+        Ok(status) => status,
+        Err(rejection) => {
+            // Log security event for rate limit violations
+            if let Some(audit_config) = request.extensions().get::<crate::middleware::AuditConfig>() {
+                let logger = TracingAuditLogger::new(audit_config.clone());
+                let mut event = AuditEvent::new(
+                    AuditEventType::RateLimitExceeded,
+                    AuditSeverity::Warning,
+                    format!("Rate limit exceeded for client {} (type: {:?})", client_id, client_type),
+                );
+
+                if let Some(auth) = auth_context {
+                    if auth.is_authenticated {
+                        event = event.with_user(auth.user_id.clone(), Some(auth.session_id.clone()));
+                    }
                 }
+
+                logger.log_event(event);
             }
 
-            logger.log_event(event);
+            return Ok(rejection.into_response());
         }
-
-        return Err(err);
-    }
+    };
 
     // If rate limit check passes, continue with the request
-    Ok(next.run(request).await)
+    let mut response = next.run(request).await;
+    if let Some(status) = status {
+        status.apply_headers(response.headers_mut());
+    }
+    Ok(response)
 }
 
 /// Create rate limiting layer with configuration
@@ -533,6 +737,29 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[test]
+    fn test_scaled_quotas_keep_default_role_ratios() {
+        let quotas = UserQuotas::scaled_from_anonymous(30, 5);
+        let defaults = UserQuotas::default();
+
+        for (scaled, default) in [
+            (&quotas.anonymous, &defaults.anonymous),
+            (&quotas.user, &defaults.user),
+            (&quotas.admin, &defaults.admin),
+            (&quotas.readonly, &defaults.readonly),
+            (&quotas.service, &defaults.service),
+        ] {
+            assert_eq!(scaled.requests_per_minute, default.requests_per_minute);
+            assert_eq!(scaled.burst_size, default.burst_size);
+            assert_eq!(scaled.daily_limit, default.daily_limit);
+        }
+
+        let quotas = UserQuotas::scaled_from_anonymous(60, 10);
+        assert_eq!(quotas.anonymous.requests_per_minute, 60);
+        assert_eq!(quotas.user.requests_per_minute, 240);
+        assert_eq!(quotas.service.burst_size, 200);
+    }
+
     #[tokio::test]
     async fn test_token_bucket_refill() {
         let mut bucket = TokenBucket::new(5, 1.0); // 5 tokens, 1 token per second
@@ -549,4 +776,201 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(1100)).await;
         assert!(bucket.try_consume(1.0));
     }
+
+    #[tokio::test]
+    async fn test_rate_limit_headers_decrement_across_requests() {
+        let config = RateLimitConfig {
+            quotas: UserQuotas {
+                anonymous: RateLimitQuota {
+                    requests_per_minute: 1,
+                    burst_size: 3,
+                    daily_limit: None,
+                },
+                ..UserQuotas::default()
+            },
+            ..RateLimitConfig::default()
+        };
+        let rate_limiter = create_rate_limit_middleware(config);
+
+        let app = Router::new()
+            .route("/test", get(test_handler))
+            .layer(axum::middleware::from_fn(
+                move |mut req: Request<axum::body::Body>, next: Next| {
+                    let rate_limiter = rate_limiter.clone();
+                    async move {
+                        let connect_info = req.extensions().get::<ConnectInfo<SocketAddr>>().cloned();
+                        req.extensions_mut().insert(rate_limiter);
+                        match rate_limit_middleware(connect_info, req, next).await {
+                            Ok(response) => response,
+                            Err(err) => err.into_response(),
+                        }
+                    }
+                },
+            ));
+
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8080);
+        let header =
+            |response: &Response, name: &str| -> u64 { response.headers()[name].to_str().unwrap().parse().unwrap() };
+
+        for expected_remaining in [2, 1, 0] {
+            let request = axum::http::Request::builder()
+                .uri("/test")
+                .extension(ConnectInfo(addr))
+                .body(axum::body::Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(header(&response, RateLimitStatus::LIMIT_HEADER), 3);
+            assert_eq!(header(&response, RateLimitStatus::REMAINING_HEADER), expected_remaining);
+            assert!(header(&response, RateLimitStatus::RESET_HEADER) > 0);
+        }
+
+        let request = axum::http::Request::builder()
+            .uri("/test")
+            .extension(ConnectInfo(addr))
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(header(&response, RateLimitStatus::REMAINING_HEADER), 0);
+        assert!(header(&response, "retry-after") > 0);
+    }
+
+    #[test]
+    fn test_client_id_prefers_api_key_then_user_then_ip() {
+        let addr = ConnectInfo(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8080));
+        let user = AuthContext::authenticated("42".to_string(), "service".to_string(), "session".to_string());
+        let key = user.clone().with_api_key("7".to_string());
+
+        let limiter = RateLimiter::new(RateLimitConfig::default());
+        assert_eq!(limiter.extract_client_info(Some(&key), Some(&addr)).1, "key:7");
+        assert_eq!(limiter.extract_client_info(Some(&user), Some(&addr)).1, "user:42");
+        assert_eq!(
+            limiter.extract_client_info(None, Some(&addr)),
+            (ClientType::Anonymous, "ip:10.0.0.1".to_string())
+        );
+
+        let limiter = RateLimiter::new(RateLimitConfig {
+            key_by: RateLimitKey::Ip,
+            ..RateLimitConfig::default()
+        });
+        assert_eq!(
+            limiter.extract_client_info(Some(&key), Some(&addr)),
+            (ClientType::Service("42".to_string()), "ip:10.0.0.1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_quota_overrides_role_quota() {
+        let quota = RateLimitQuota {
+            requests_per_minute: 1,
+            burst_size: 1,
+            daily_limit: None,
+        };
+        let limiter = RateLimiter::new(RateLimitConfig::default().with_client_quota("key:7", quota));
+        let client_type = ClientType::Service("42".to_string());
+
+        let status = limiter
+            .check_rate_limit(client_type.clone(), "key:7")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(status.limit, 1);
+        assert_eq!(status.remaining, 0);
+
+        let rejection = limiter
+            .check_rate_limit(client_type.clone(), "key:7")
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status.remaining, 0);
+        assert!(rejection.retry_after > Duration::ZERO);
+
+        // Another key of the same user still has the service quota
+        let status = limiter.check_rate_limit(client_type, "key:8").await.unwrap().unwrap();
+        assert_eq!(status.limit, UserQuotas::default().service.burst_size);
+    }
+
+    #[tokio::test]
+    async fn test_flushing_registered_client_restores_quota() {
+        let quota = RateLimitQuota {
+            requests_per_minute: 1,
+            burst_size: 1,
+            daily_limit: None,
+        };
+        let limiter = Arc::new(RateLimiter::new(
+            RateLimitConfig::default().with_client_quota("key:7", quota),
+        ));
+        let registry = CacheRegistry::new();
+        limiter.register_cache(&registry, "rate_limit_clients");
+        let client_type = ClientType::Service("42".to_string());
+
+        limiter.check_rate_limit(client_type.clone(), "key:7").await.unwrap();
+        assert!(limiter.check_rate_limit(client_type.clone(), "key:7").await.is_err());
+        assert_eq!(registry.stats().await.unwrap()[0].stats.entry_count, 1);
+
+        assert!(registry.flush_key("rate_limit_clients", "key:7").await.unwrap());
+        assert!(limiter.check_rate_limit(client_type, "key:7").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_api_key_quota_applies_behind_auth_layer() {
+        let quota = RateLimitQuota {
+            requests_per_minute: 1,
+            burst_size: 1,
+            daily_limit: None,
+        };
+        let rate_limiter =
+            create_rate_limit_middleware(RateLimitConfig::default().with_client_quota("key:demo-api-key", quota));
+        let jwt_manager = Arc::new(crate::middleware::JwtManager::new(
+            crate::middleware::AuthConfig::default(),
+        ));
+
+        let app = Router::new()
+            .route("/test", get(test_handler))
+            .layer(axum::middleware::from_fn(
+                move |mut req: Request<axum::body::Body>, next: Next| {
+                    let rate_limiter = rate_limiter.clone();
+                    async move {
+                        let connect_info = req.extensions().get::<ConnectInfo<SocketAddr>>().cloned();
+                        req.extensions_mut().insert(rate_limiter);
+                        match rate_limit_middleware(connect_info, req, next).await {
+                            Ok(response) => response,
+                            Err(err) => err.into_response(),
+                        }
+                    }
+                },
+            ))
+            .layer(axum::middleware::from_fn(
+                move |mut req: Request<axum::body::Body>, next: Next| {
+                    let jwt_manager = jwt_manager.clone();
+                    async move {
+                        let headers = req.headers().clone();
+                        req.extensions_mut().insert(jwt_manager);
+                        crate::middleware::optional_auth_middleware(headers, req, next).await
+                    }
+                },
+            ));
+
+        let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 8080);
+        let request = |api_key: Option<&str>| {
+            let mut builder = axum::http::Request::builder().uri("/test").extension(ConnectInfo(addr));
+            if let Some(api_key) = api_key {
+                builder = builder.header("X-API-Key", api_key);
+            }
+            builder.body(axum::body::Body::empty()).unwrap()
+        };
+
+        let response = app.clone().oneshot(request(Some("demo-api-key"))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[RateLimitStatus::LIMIT_HEADER], "1");
+
+        let response = app.clone().oneshot(request(Some("demo-api-key"))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // Anonymous callers from the same IP are counted separately
+        let response = app.oneshot(request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
 }
@@ -115,6 +115,9 @@ pub struct SessionInfo {
     pub user_agent: Option<String>,
     /// Whether session is still active
     pub is_active: bool,
+    /// ID of the API key the session was opened with, if any
+    #[serde(default)]
+    pub api_key_id: Option<String>,
 }
 
 impl SessionInfo {
@@ -131,6 +134,7 @@ impl SessionInfo {
             ip_address: None,
             user_agent: None,
             is_active: true,
+            api_key_id: None,
         }
     }
 
@@ -171,6 +175,12 @@ impl SessionInfo {
         self.user_agent = user_agent;
         self
     }
+
+    /// Record the API key the session was opened with
+    pub fn with_api_key(mut self, api_key_id: String) -> Self {
+        self.api_key_id = Some(api_key_id);
+        self
+    }
 }
 
 /// Session statistics for monitoring
@@ -527,11 +537,12 @@ pub async fn session_middleware(request: Request<axum::body::Body>, next: Next)
             match manager.validate_session(&session_id).await {
                 Ok(session) => {
                     // Create auth context from session
-                    let auth_context = AuthContext::authenticated(
+                    let mut auth_context = AuthContext::authenticated(
                         session.user_id.clone(),
                         session.role.clone(),
                         session.session_id.clone(),
                     );
+                    auth_context.api_key_id = session.api_key_id.clone();
 
                     // Add auth context to request
                     let mut request = request;
@@ -0,0 +1,82 @@
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+/// Default interval between checks while long-polling
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Repeatedly fetch a resource until it satisfies `is_done` or `wait` elapses
+///
+/// The latest fetched value is returned either way, so callers always respond
+/// with the current state. Fetch errors end the poll immediately.
+pub async fn long_poll<T, E, F, Fut, D>(wait: Duration, interval: Duration, mut fetch: F, is_done: D) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    D: Fn(&T) -> bool,
+{
+    let deadline = Instant::now() + wait;
+
+    loop {
+        let value = fetch().await?;
+
+        let now = Instant::now();
+        if is_done(&value) || now >= deadline {
+            return Ok(value);
+        }
+
+        sleep(interval.min(deadline - now)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_long_poll_returns_when_done() {
+        let calls = AtomicU32::new(0);
+        let started = Instant::now();
+
+        let result: Result<u32, ()> = long_poll(
+            Duration::from_secs(30),
+            Duration::from_millis(100),
+            || async { Ok(calls.fetch_add(1, Ordering::SeqCst) + 1) },
+            |calls| *calls >= 3,
+        )
+        .await;
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(started.elapsed(), Duration::from_millis(200));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_long_poll_times_out_with_latest_value() {
+        let started = Instant::now();
+
+        let result: Result<&str, ()> = long_poll(
+            Duration::from_secs(1),
+            Duration::from_millis(300),
+            || async { Ok("running") },
+            |status| *status == "completed",
+        )
+        .await;
+
+        assert_eq!(result, Ok("running"));
+        assert_eq!(started.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_long_poll_stops_on_error() {
+        let result: Result<(), &str> = long_poll(
+            Duration::from_secs(30),
+            Duration::from_millis(10),
+            || async { Err("database unavailable") },
+            |_| false,
+        )
+        .await;
+
+        assert_eq!(result, Err("database unavailable"));
+    }
+}
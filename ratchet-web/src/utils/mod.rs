@@ -1,4 +1,6 @@
+pub mod long_poll;
 pub mod response;
 
 // Re-export commonly used utilities
+pub use long_poll::{long_poll, DEFAULT_POLL_INTERVAL};
 pub use response::{ApiResponse, ResponseBuilder};
@@ -23,6 +23,9 @@ pub struct ResponseMeta {
     pub request_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// Cursor for the next page in cursor pagination, absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 impl<T> ApiResponse<T> {
@@ -44,6 +47,20 @@ impl<T> ApiResponse<T> {
                 pagination: Some(pagination),
                 request_id: None,
                 timestamp: Some(chrono::Utc::now()),
+                next_cursor: None,
+            }),
+        }
+    }
+
+    /// Create a cursor-paginated response
+    pub fn with_cursor(data: T, next_cursor: Option<String>) -> Self {
+        Self {
+            data,
+            meta: Some(ResponseMeta {
+                pagination: None,
+                request_id: None,
+                timestamp: None,
+                next_cursor,
             }),
         }
     }
@@ -102,6 +119,7 @@ impl<T> ResponseBuilder<T> {
                 pagination: self.pagination,
                 request_id: self.request_id,
                 timestamp: Some(chrono::Utc::now()),
+                next_cursor: None,
             })
         } else {
             None
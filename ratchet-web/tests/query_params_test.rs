@@ -12,6 +12,7 @@ fn test_refine_query_parameters() {
         limit: None,
         sort: None,
         order: None,
+        cursor: None,
         filters: HashMap::new(),
     };
 
@@ -119,6 +120,7 @@ fn test_list_query_to_list_input_conversion() {
         limit: None,
         sort: None,
         order: None,
+        cursor: None,
         filters: HashMap::new(),
     };
 
@@ -138,6 +140,7 @@ fn test_list_query_to_list_input_conversion() {
         limit: Some(20),
         sort: None,
         order: None,
+        cursor: None,
         filters: HashMap::new(),
     };
 
@@ -155,6 +158,7 @@ fn test_list_query_to_list_input_conversion() {
         limit: None,
         sort: None,
         order: None,
+        cursor: None,
         filters: HashMap::new(),
     };
 
@@ -166,3 +170,56 @@ fn test_list_query_to_list_input_conversion() {
 
     println!("✅ SUCCESS: ListQuery to ListInput conversion works correctly!");
 }
+
+#[test]
+fn test_cursor_pagination() {
+    use ratchet_api_types::ApiId;
+    use ratchet_web::extractors::CursorPage;
+
+    // Offset pagination stays the default
+    assert_eq!(ListQuery::default().cursor_page(), None);
+
+    // An empty cursor starts from the first row
+    let query = ListQuery {
+        cursor: Some(String::new()),
+        limit: Some(2),
+        ..Default::default()
+    };
+    assert!(query.validate().is_ok());
+    let page = query.cursor_page().unwrap();
+    assert_eq!(page, CursorPage { after: None, limit: 2 });
+    assert_eq!(page.fetch_limit(), 3);
+
+    // A full page yields the last ID as the next cursor
+    let response = page.into_response(vec![ApiId::from_i32(1), ApiId::from_i32(2), ApiId::from_i32(3)], |id| {
+        id
+    });
+    assert_eq!(response.data.len(), 2);
+    assert_eq!(response.meta.unwrap().next_cursor.as_deref(), Some("2"));
+
+    // A short page is the last one
+    let query = ListQuery {
+        cursor: Some("2".to_string()),
+        limit: Some(2),
+        ..Default::default()
+    };
+    let page = query.cursor_page().unwrap();
+    assert_eq!(page.after, Some(ApiId::from_i32(2)));
+    let response = page.into_response(vec![ApiId::from_i32(3)], |id| id);
+    assert_eq!(response.meta.unwrap().next_cursor, None);
+
+    // Cursors are ordered by ID and can't be mixed with offsets or sorting
+    let query = ListQuery {
+        cursor: Some("2".to_string()),
+        page: Some(2),
+        ..Default::default()
+    };
+    assert!(query.validate().is_err());
+
+    // Only IDs handed out as `next_cursor` are accepted
+    let query = ListQuery {
+        cursor: Some("not-a-cursor".to_string()),
+        ..Default::default()
+    };
+    assert!(query.validate().is_err());
+}
@@ -0,0 +1,124 @@
+//! Execution attachments e2e test
+//!
+//! Runs a queued job through the job processor on a real worker pool and
+//! fetches the attachment the task produced over the REST API.
+
+use axum::body::{to_bytes, Body};
+use http::{header, Request, StatusCode};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use ratchet_execution::{ExecutionBridge, ProcessExecutorConfig};
+use ratchet_interfaces::{RepositoryFactory as RepoFactory, TaskRegistry};
+use ratchet_output::OutputDeliveryManager;
+use ratchet_rest_api::app::{create_rest_app, AppConfig, AppContext};
+use ratchet_server::bridges::{BridgeRegistryManager, BridgeTaskRegistry, BridgeTaskValidator};
+use ratchet_server::job_processor::{JobProcessorConfig, JobProcessorService};
+use ratchet_server::services::DirectRepositoryFactory;
+use ratchet_storage::seaorm::{connection::DatabaseConnection, repositories::RepositoryFactory};
+use ratchet_storage::testing::builders::{JobBuilder, TaskBuilder};
+use ratchet_storage::AttachmentStore;
+
+const REPORT_TASK: &str = r#"
+function main(input) {
+    ratchet.attach("report.csv", "id,value\n1,42\n", "text/csv");
+    return { ok: true };
+}
+"#;
+
+#[tokio::test]
+async fn test_job_attachment_is_served_by_rest_api() {
+    let database = DatabaseConnection::new(ratchet_storage::seaorm::config::DatabaseConfig {
+        url: "sqlite::memory:".to_string(),
+        max_connections: 1,
+        connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
+    })
+    .await
+    .expect("Failed to create test database");
+    database.migrate().await.expect("Failed to run migrations");
+
+    let attachments_dir = tempfile::tempdir().unwrap();
+    let store = Arc::new(AttachmentStore::new(attachments_dir.path()));
+
+    let storage = Arc::new(RepositoryFactory::new(database));
+    let repositories: Arc<dyn RepoFactory> =
+        Arc::new(DirectRepositoryFactory::new(storage.clone()).with_attachment_store(store.clone()));
+
+    let mut task = TaskBuilder::new().with_name("report").build();
+    task.source_code = REPORT_TASK.to_string();
+    let task = storage.task_repository().create(task).await.unwrap();
+    storage
+        .job_repository()
+        .create(JobBuilder::new().with_task_id(task.id).build())
+        .await
+        .unwrap();
+
+    let bridge = Arc::new(ExecutionBridge::new(ProcessExecutorConfig {
+        worker_count: 1,
+        task_timeout_seconds: 30,
+        restart_on_crash: false,
+        max_restart_attempts: 0,
+        attachments: Some((*store).clone()),
+        output_schema_enforcement: Default::default(),
+    }));
+    bridge.start().await.expect("Failed to start workers");
+
+    JobProcessorService::new(
+        repositories.clone(),
+        Arc::new(OutputDeliveryManager::new()),
+        JobProcessorConfig::default(),
+    )
+    .with_task_executor(bridge)
+    .process_batch()
+    .await
+    .expect("Failed to process jobs");
+
+    let executions = storage.execution_repository().find_by_task_id(task.id).await.unwrap();
+    assert_eq!(executions.len(), 1);
+    let execution = &executions[0];
+    assert_eq!(
+        execution.status,
+        ratchet_storage::seaorm::entities::ExecutionStatus::Completed,
+        "execution failed: {:?}",
+        execution.error_message
+    );
+
+    let server_config = ratchet_server::config::ServerConfig::default();
+    let registry: Arc<dyn TaskRegistry> = Arc::new(BridgeTaskRegistry::new(&server_config).await.unwrap());
+    let manager = Arc::new(BridgeRegistryManager::new(&server_config).await.unwrap());
+    let mut context = AppContext::new(repositories, registry, manager, Arc::new(BridgeTaskValidator::new()));
+    context.tasks = context.tasks.with_attachment_store(store);
+    let app = create_rest_app(context, AppConfig::default());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::get(format!("/api/v1/executions/{}", execution.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let listed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(
+        listed.to_string().contains("report.csv"),
+        "attachment not listed: {}",
+        listed
+    );
+
+    let response = app
+        .oneshot(
+            Request::get(format!("/api/v1/executions/{}/attachments/report.csv", execution.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "text/csv");
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(&body[..], b"id,value\n1,42\n");
+}
@@ -0,0 +1,135 @@
+//! Execution logs e2e test
+//!
+//! Runs a queued job through the job processor on a real worker pool and reads
+//! the task's console output back from the REST API.
+
+use axum::body::{to_bytes, Body};
+use http::{Request, StatusCode};
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+use ratchet_execution::{ExecutionBridge, ProcessExecutorConfig};
+use ratchet_interfaces::{LogEvent, RepositoryFactory as RepoFactory, StructuredLogger, TaskRegistry};
+use ratchet_output::OutputDeliveryManager;
+use ratchet_rest_api::app::{create_rest_app, AppConfig, AppContext};
+use ratchet_server::bridges::{BridgeRegistryManager, BridgeTaskRegistry, BridgeTaskValidator};
+use ratchet_server::job_processor::{JobProcessorConfig, JobProcessorService};
+use ratchet_server::services::DirectRepositoryFactory;
+use ratchet_storage::seaorm::{connection::DatabaseConnection, repositories::RepositoryFactory};
+use ratchet_storage::testing::builders::{JobBuilder, TaskBuilder};
+
+const LOGGING_TASK: &str = r#"
+function main(input) {
+    console.log("processing", 3, "items");
+    console.warn("skipped 1 item");
+    return { ok: true };
+}
+"#;
+
+/// Logger keeping every event it receives
+#[derive(Default)]
+struct CollectingLogger {
+    events: Mutex<Vec<LogEvent>>,
+}
+
+impl StructuredLogger for CollectingLogger {
+    fn log(&self, event: LogEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+#[tokio::test]
+async fn test_job_console_output_is_served_by_rest_api() {
+    let database = DatabaseConnection::new(ratchet_storage::seaorm::config::DatabaseConfig {
+        url: "sqlite::memory:".to_string(),
+        max_connections: 1,
+        connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
+    })
+    .await
+    .expect("Failed to create test database");
+    database.migrate().await.expect("Failed to run migrations");
+
+    let storage = Arc::new(RepositoryFactory::new(database));
+    let repositories: Arc<dyn RepoFactory> = Arc::new(DirectRepositoryFactory::new(storage.clone()));
+
+    let mut task = TaskBuilder::new().with_name("logging").build();
+    task.source_code = LOGGING_TASK.to_string();
+    let task = storage.task_repository().create(task).await.unwrap();
+    storage
+        .job_repository()
+        .create(JobBuilder::new().with_task_id(task.id).build())
+        .await
+        .unwrap();
+
+    let bridge = Arc::new(ExecutionBridge::new(ProcessExecutorConfig {
+        worker_count: 1,
+        task_timeout_seconds: 30,
+        restart_on_crash: false,
+        max_restart_attempts: 0,
+        attachments: None,
+        output_schema_enforcement: Default::default(),
+    }));
+    let logger = Arc::new(CollectingLogger::default());
+    bridge.inner().set_logger(logger.clone()).await;
+    bridge.start().await.expect("Failed to start workers");
+
+    JobProcessorService::new(
+        repositories.clone(),
+        Arc::new(OutputDeliveryManager::new()),
+        JobProcessorConfig::default(),
+    )
+    .with_task_executor(bridge)
+    .process_batch()
+    .await
+    .expect("Failed to process jobs");
+
+    let executions = storage.execution_repository().find_by_task_id(task.id).await.unwrap();
+    assert_eq!(executions.len(), 1);
+    let execution = &executions[0];
+
+    // The worker's logger sees the console output as it is written
+    let logged: Vec<String> = logger
+        .events
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|e| e.message.clone())
+        .collect();
+    assert_eq!(logged, vec!["processing 3 items", "skipped 1 item"]);
+
+    let server_config = ratchet_server::config::ServerConfig::default();
+    let registry: Arc<dyn TaskRegistry> = Arc::new(BridgeTaskRegistry::new(&server_config).await.unwrap());
+    let manager = Arc::new(BridgeRegistryManager::new(&server_config).await.unwrap());
+    let context = AppContext::new(repositories, registry, manager, Arc::new(BridgeTaskValidator::new()));
+    let app = create_rest_app(context, AppConfig::default());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::get(format!("/api/v1/executions/{}/logs", execution.id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let logs = body["logs"].as_array().unwrap();
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0]["level"], "Info");
+    assert_eq!(logs[0]["message"], "processing 3 items");
+    assert_eq!(logs[1]["level"], "Warn");
+    assert_eq!(logs[0]["context"]["execution_id"], execution.uuid.to_string());
+
+    let response = app
+        .oneshot(
+            Request::get("/api/v1/executions/999999/logs")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
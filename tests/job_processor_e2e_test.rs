@@ -0,0 +1,258 @@
+//! Job processor e2e test
+//!
+//! Runs queued jobs through the job processor on a real worker pool and checks
+//! the executions recorded for each attempt.
+
+use sea_orm::{ActiveModelTrait, Set};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Layer;
+
+use ratchet_execution::{ExecutionBridge, ProcessExecutorConfig};
+use ratchet_interfaces::RepositoryFactory as RepoFactory;
+use ratchet_logging::TaskLogLevelFilter;
+use ratchet_output::OutputDeliveryManager;
+use ratchet_server::job_processor::{JobProcessorConfig, JobProcessorService};
+use ratchet_server::services::DirectRepositoryFactory;
+use ratchet_storage::seaorm::entities::{executions::ExecutionStatus, jobs};
+use ratchet_storage::seaorm::{connection::DatabaseConnection, repositories::RepositoryFactory};
+use ratchet_storage::testing::builders::{ExecutionBuilder, JobBuilder, TaskBuilder};
+
+const FAILING_TASK: &str = r#"
+function main(input) {
+    throw new Error("upstream unavailable for " + input.city);
+}
+"#;
+
+const ECHO_TASK: &str = r#"
+function main(input) {
+    return { city: input.city };
+}
+"#;
+
+async fn setup(source: &str) -> (Arc<RepositoryFactory>, JobProcessorService, i32) {
+    let database = DatabaseConnection::new(ratchet_storage::seaorm::config::DatabaseConfig {
+        url: "sqlite::memory:".to_string(),
+        max_connections: 1,
+        connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
+    })
+    .await
+    .expect("Failed to create test database");
+    database.migrate().await.expect("Failed to run migrations");
+
+    let storage = Arc::new(RepositoryFactory::new(database));
+    let repositories: Arc<dyn RepoFactory> = Arc::new(DirectRepositoryFactory::new(storage.clone()));
+
+    let mut task = TaskBuilder::new().with_name("weather").build();
+    task.source_code = source.to_string();
+    let task = storage.task_repository().create(task).await.unwrap();
+
+    let bridge = Arc::new(ExecutionBridge::new(ProcessExecutorConfig {
+        worker_count: 1,
+        task_timeout_seconds: 30,
+        restart_on_crash: false,
+        max_restart_attempts: 0,
+        attachments: None,
+        output_schema_enforcement: Default::default(),
+    }));
+    bridge.start().await.expect("Failed to start workers");
+
+    let processor = JobProcessorService::new(
+        repositories,
+        Arc::new(OutputDeliveryManager::new()),
+        JobProcessorConfig::default(),
+    )
+    .with_task_executor(bridge);
+
+    (storage, processor, task.id)
+}
+
+#[tokio::test]
+async fn test_each_retry_gets_its_own_execution() {
+    let (storage, processor, task_id) = setup(FAILING_TASK).await;
+
+    let execution = storage
+        .execution_repository()
+        .create(
+            ExecutionBuilder::new()
+                .with_task_id(task_id)
+                .with_status(ExecutionStatus::Pending)
+                .with_input(serde_json::json!({"city": "Utrecht"}))
+                .build(),
+        )
+        .await
+        .unwrap();
+    let job = storage
+        .job_repository()
+        .create(
+            JobBuilder::new()
+                .with_task_id(task_id)
+                .with_execution_id(execution.id)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    processor.process_batch().await.unwrap();
+
+    // Make the retry due now instead of after its backoff
+    jobs::ActiveModel {
+        id: Set(job.id),
+        process_at: Set(None),
+        ..Default::default()
+    }
+    .update(storage.database().get_connection())
+    .await
+    .unwrap();
+    processor.process_batch().await.unwrap();
+
+    let mut executions = storage.execution_repository().find_by_task_id(task_id).await.unwrap();
+    executions.sort_by_key(|execution| execution.id);
+    assert_eq!(executions.len(), 2);
+    assert_eq!(executions[0].id, execution.id);
+    for attempt in &executions {
+        assert_eq!(attempt.status, ExecutionStatus::Failed);
+        assert_eq!(attempt.input, serde_json::json!({"city": "Utrecht"}));
+        assert!(attempt
+            .error_message
+            .as_deref()
+            .is_some_and(|error| error.contains("upstream unavailable for Utrecht")));
+    }
+
+    let job = storage.job_repository().find_by_id(job.id).await.unwrap().unwrap();
+    assert_eq!(job.retry_count, 2);
+    assert_eq!(job.execution_id, Some(executions[1].id));
+}
+
+#[tokio::test]
+async fn test_pending_execution_created_up_front_is_reused() {
+    let (storage, processor, task_id) = setup(ECHO_TASK).await;
+
+    let execution = storage
+        .execution_repository()
+        .create(
+            ExecutionBuilder::new()
+                .with_task_id(task_id)
+                .with_status(ExecutionStatus::Pending)
+                .with_input(serde_json::json!({"city": "Utrecht"}))
+                .build(),
+        )
+        .await
+        .unwrap();
+    storage
+        .job_repository()
+        .create(
+            JobBuilder::new()
+                .with_task_id(task_id)
+                .with_execution_id(execution.id)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    processor.process_batch().await.unwrap();
+
+    let executions = storage.execution_repository().find_by_task_id(task_id).await.unwrap();
+    assert_eq!(executions.len(), 1);
+    assert_eq!(executions[0].id, execution.id);
+    assert_eq!(executions[0].status, ExecutionStatus::Completed);
+    assert_eq!(executions[0].output, Some(serde_json::json!({"city": "Utrecht"})));
+}
+
+#[tokio::test]
+async fn test_output_path_is_rendered_from_job_variables() {
+    let (storage, processor, task_id) = setup(ECHO_TASK).await;
+    let dir = tempfile::tempdir().unwrap();
+    let template = dir
+        .path()
+        .join("{{task_name}}/{{year}}/job-{{job_id}}-{{execution_id}}.json");
+
+    let mut job = JobBuilder::new()
+        .with_task_id(task_id)
+        .with_input_data(serde_json::json!({"city": "Utrecht"}))
+        .build();
+    job.output_destinations = Some(serde_json::json!([{
+        "destinationType": "filesystem",
+        "filesystem": {"path": template.to_string_lossy(), "format": "JSON"}
+    }]));
+    let job = storage.job_repository().create(job).await.unwrap();
+
+    processor.process_batch().await.unwrap();
+
+    let job = storage.job_repository().find_by_id(job.id).await.unwrap().unwrap();
+    let execution_id = job.execution_id.expect("job should be linked to its execution");
+    let path = dir
+        .path()
+        .join("weather")
+        .join(chrono::Utc::now().format("%Y").to_string())
+        .join(format!("job-{}-{}.json", job.id, execution_id));
+    let written = std::fs::read_to_string(&path).expect("output should be written to the rendered path");
+    assert!(written.contains("Utrecht"));
+}
+
+/// Collects the messages of every event that reaches it
+struct CaptureLayer(Arc<Mutex<Vec<String>>>);
+
+impl<S: tracing::Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _cx: tracing_subscriber::layer::Context<'_, S>) {
+        struct Message(String);
+        impl Visit for Message {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    self.0 = format!("{:?}", value);
+                }
+            }
+        }
+
+        let mut message = Message(String::new());
+        event.record(&mut message);
+        self.0.lock().unwrap().push(message.0);
+    }
+}
+
+#[tokio::test]
+async fn test_job_log_level_applies_to_its_task() {
+    let captured = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry()
+        .with(CaptureLayer(captured.clone()).with_filter(TaskLogLevelFilter::new(LevelFilter::WARN)));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let (storage, processor, task_id) = setup(ECHO_TASK).await;
+    let task_debug_logs = |captured: &Mutex<Vec<String>>| {
+        captured
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|message| message.starts_with("Resolved task: weather"))
+            .count()
+    };
+
+    // Without a level the task logs at the system's WARN
+    let job = JobBuilder::new()
+        .with_task_id(task_id)
+        .with_input_data(serde_json::json!({"city": "Utrecht"}))
+        .build();
+    storage.job_repository().create(job).await.unwrap();
+    processor.process_batch().await.unwrap();
+    assert_eq!(task_debug_logs(&captured), 0);
+
+    // A job asking for debug logs gets the worker's debug output for its task
+    let mut job = JobBuilder::new()
+        .with_task_id(task_id)
+        .with_input_data(serde_json::json!({"city": "Utrecht"}))
+        .build();
+    job.metadata = Some(serde_json::json!({"log_level": "debug"}));
+    storage.job_repository().create(job).await.unwrap();
+    processor.process_batch().await.unwrap();
+    assert_eq!(task_debug_logs(&captured), 1);
+
+    // Outside the task the system level still applies
+    assert!(!captured
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|message| message.starts_with("Processing job")));
+}
@@ -39,6 +39,7 @@ async fn create_test_adapter() -> Result<RatchetMcpAdapter> {
         url: "sqlite::memory:".to_string(),
         max_connections: 1,
         connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
     };
 
     let database = DatabaseConnection::new(db_config.clone())
@@ -73,6 +74,8 @@ async fn create_test_adapter() -> Result<RatchetMcpAdapter> {
         task_timeout_seconds: 30,
         restart_on_crash: true,
         max_restart_attempts: 3,
+        attachments: None,
+        output_schema_enforcement: Default::default(),
     };
     let executor = Arc::new(ProcessTaskExecutor::new(executor_config));
 
@@ -0,0 +1,79 @@
+//! Plugin hooks e2e test
+//!
+//! Builds the server's service container from configuration and checks that
+//! task hooks registered with its plugin manager run around executions on the
+//! container's task executor.
+
+use async_trait::async_trait;
+use ratchet_config::{
+    domains::{database::DatabaseConfig, server::ServerConfig},
+    RatchetConfig,
+};
+use ratchet_interfaces::execution::TaskExecutor;
+use ratchet_plugin::hooks::TaskExecutionData;
+use ratchet_plugin::{Hook, PluginContext, PluginError, PluginResult, TaskHook};
+use ratchet_server::services::ServiceContainer;
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Records the inputs it sees and rejects every execution
+#[derive(Default)]
+struct VetoHook {
+    inputs: Mutex<Vec<Value>>,
+}
+
+#[async_trait]
+impl Hook for VetoHook {
+    fn name(&self) -> &str {
+        "veto"
+    }
+
+    async fn execute(&self, _context: &mut PluginContext, _data: &mut Value) -> PluginResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskHook for VetoHook {
+    async fn pre_execute(&self, _context: &mut PluginContext, data: &mut TaskExecutionData) -> PluginResult<()> {
+        self.inputs.lock().unwrap().push(data.input.clone());
+        Err(PluginError::hook_execution_failed("veto", "executions are disabled"))
+    }
+}
+
+#[tokio::test]
+async fn test_server_executor_runs_plugin_task_hooks() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config = RatchetConfig {
+        server: Some(ServerConfig {
+            database: DatabaseConfig {
+                url: format!("sqlite://{}?mode=rwc", temp_dir.path().join("hooks.db").display()),
+                max_connections: 1,
+                connection_timeout: Duration::from_secs(5),
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut server_config = ratchet_server::config::ServerConfig::from_ratchet_config(config).unwrap();
+    server_config.execution.attachments.directory = temp_dir.path().join("attachments");
+
+    let services = ServiceContainer::new(&server_config).await.unwrap();
+    let hook = Arc::new(VetoHook::default());
+    services
+        .plugin_host
+        .manager()
+        .register_task_hook(hook.clone(), "test-plugin")
+        .await
+        .unwrap();
+
+    let result = services.task_executor.execute_task("1", json!({"n": 1}), None).await;
+
+    let error = result.expect_err("the hook vetoes every execution").to_string();
+    assert!(error.contains("executions are disabled"), "unexpected error: {}", error);
+    assert_eq!(*hook.inputs.lock().unwrap(), vec![json!({"n": 1})]);
+
+    let _ = services.task_executor.shutdown().await;
+}
@@ -183,6 +183,7 @@ async fn create_test_config(_webhook_url: &str, repository_path: &str) -> Result
                 polling_interval: Some(Duration::from_secs(300)),
                 enabled: true,
                 auth_name: None,
+                conflict_resolution: Default::default(),
                 config: Default::default(),
             }],
             default_polling_interval: Duration::from_secs(300),
@@ -234,6 +234,7 @@ async fn create_test_config(temp_dir: &TempDir, _webhook_addr: SocketAddr) -> Re
             polling_interval: None,
             enabled: true,
             auth_name: None,
+            conflict_resolution: Default::default(),
             config: Default::default(),
         }];
         registry.default_polling_interval = Duration::from_secs(300);
@@ -0,0 +1,250 @@
+//! REST batch endpoints e2e test
+//!
+//! Applies batches of task, job and schedule operations against an in-memory
+//! database and checks the per-operation results and the batch size limit.
+
+use axum::body::{to_bytes, Body};
+use axum::Router;
+use http::{Request, StatusCode};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+use ratchet_interfaces::{RepositoryFactory as RepoFactory, TaskRegistry};
+use ratchet_rest_api::app::{create_rest_app, AppConfig, AppContext};
+use ratchet_server::bridges::{BridgeRegistryManager, BridgeTaskRegistry, BridgeTaskValidator};
+use ratchet_server::services::DirectRepositoryFactory;
+use ratchet_storage::seaorm::{connection::DatabaseConnection, repositories::RepositoryFactory};
+use ratchet_storage::testing::builders::{JobBuilder, ScheduleBuilder, TaskBuilder};
+
+/// IDs of the rows seeded for each test
+struct Seeded {
+    task_id: i32,
+    job_id: i32,
+    schedule_id: i32,
+}
+
+async fn setup() -> (Router, Arc<RepositoryFactory>, Seeded) {
+    let database = DatabaseConnection::new(ratchet_storage::seaorm::config::DatabaseConfig {
+        url: "sqlite::memory:".to_string(),
+        max_connections: 1,
+        connection_timeout: std::time::Duration::from_secs(5),
+        ..Default::default()
+    })
+    .await
+    .expect("Failed to create test database");
+    database.migrate().await.expect("Failed to run migrations");
+
+    let storage = Arc::new(RepositoryFactory::new(database));
+    let task = storage
+        .task_repository()
+        .create(TaskBuilder::new().with_name("batched").build())
+        .await
+        .unwrap();
+    let job = storage
+        .job_repository()
+        .create(JobBuilder::new().with_task_id(task.id).build())
+        .await
+        .unwrap();
+    let schedule = storage
+        .schedule_repository()
+        .create(
+            ScheduleBuilder::new()
+                .with_task_id(task.id)
+                .with_name("batched")
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let repositories: Arc<dyn RepoFactory> = Arc::new(DirectRepositoryFactory::new(storage.clone()));
+    let server_config = ratchet_server::config::ServerConfig::default();
+    let registry: Arc<dyn TaskRegistry> = Arc::new(BridgeTaskRegistry::new(&server_config).await.unwrap());
+    let manager = Arc::new(BridgeRegistryManager::new(&server_config).await.unwrap());
+    let context = AppContext::new(repositories, registry, manager, Arc::new(BridgeTaskValidator::new()));
+
+    let seeded = Seeded {
+        task_id: task.id,
+        job_id: job.id,
+        schedule_id: schedule.id,
+    };
+    (create_rest_app(context, AppConfig::default()), storage, seeded)
+}
+
+async fn post_batch(app: &Router, resource: &str, operations: Value) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::post(format!("/api/v1/{}/batch", resource))
+                .header("content-type", "application/json")
+                .body(Body::from(json!({ "operations": operations }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+/// `(id, success)` of each result, in order
+fn outcomes(body: &Value) -> Vec<(String, bool)> {
+    body["data"]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| {
+            (
+                result["id"].as_str().unwrap().to_string(),
+                result["success"].as_bool().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_task_batch_reports_mixed_results() {
+    let (app, storage, seeded) = setup().await;
+    let task_id = seeded.task_id.to_string();
+
+    let (status, body) = post_batch(
+        &app,
+        "tasks",
+        json!([
+            { "id": task_id, "action": "disable" },
+            { "id": "999999", "action": "delete" },
+            { "id": task_id, "action": "cancel" },
+        ]),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        outcomes(&body),
+        vec![
+            (task_id.clone(), true),
+            ("999999".to_string(), false),
+            (task_id.clone(), false)
+        ]
+    );
+    assert_eq!(
+        (body["data"]["succeeded"].as_u64(), body["data"]["failed"].as_u64()),
+        (Some(1), Some(2))
+    );
+    assert!(body["data"]["results"][1]["error"]
+        .as_str()
+        .unwrap()
+        .contains("not found"));
+
+    // The failures after the disable didn't undo it
+    let task = storage
+        .task_repository()
+        .find_by_id(seeded.task_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!task.enabled);
+}
+
+#[tokio::test]
+async fn test_job_batch_reports_mixed_results() {
+    let (app, storage, seeded) = setup().await;
+    let job_id = seeded.job_id.to_string();
+
+    let (status, body) = post_batch(
+        &app,
+        "jobs",
+        json!([
+            { "id": job_id, "action": "enable" },
+            { "id": job_id, "action": "cancel" },
+            { "id": "999999", "action": "delete" },
+        ]),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        outcomes(&body),
+        vec![
+            (job_id.clone(), false),
+            (job_id.clone(), true),
+            ("999999".to_string(), false)
+        ]
+    );
+
+    let job = storage
+        .job_repository()
+        .find_by_id(seeded.job_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        job.status,
+        ratchet_storage::seaorm::entities::jobs::JobStatus::Cancelled
+    );
+}
+
+#[tokio::test]
+async fn test_schedule_batch_reports_mixed_results() {
+    let (app, storage, seeded) = setup().await;
+    let schedule_id = seeded.schedule_id.to_string();
+
+    let (status, body) = post_batch(
+        &app,
+        "schedules",
+        json!([
+            { "id": schedule_id, "action": "disable" },
+            { "id": schedule_id, "action": "cancel" },
+            { "id": "999999", "action": "enable" },
+        ]),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        outcomes(&body),
+        vec![
+            (schedule_id.clone(), true),
+            (schedule_id.clone(), false),
+            ("999999".to_string(), false)
+        ]
+    );
+
+    let schedule = storage
+        .schedule_repository()
+        .find_by_id(seeded.schedule_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!schedule.enabled);
+}
+
+#[tokio::test]
+async fn test_batches_are_limited_in_size() {
+    let (app, storage, seeded) = setup().await;
+
+    let operations = |count: usize| {
+        Value::Array(
+            (0..count)
+                .map(|_| json!({ "id": seeded.task_id.to_string(), "action": "disable" }))
+                .collect(),
+        )
+    };
+
+    for resource in ["tasks", "jobs", "schedules"] {
+        let (status, _) = post_batch(&app, resource, operations(0)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "empty {} batch", resource);
+
+        let (status, _) = post_batch(&app, resource, operations(501)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "oversized {} batch", resource);
+    }
+
+    // Rejected batches apply none of their operations
+    let task = storage
+        .task_repository()
+        .find_by_id(seeded.task_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(task.enabled);
+}